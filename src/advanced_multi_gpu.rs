@@ -460,6 +460,156 @@ impl ThermalBalancer {
     }
 }
 
+/// Links NVML can report per GPU (`NVML_NVLINK_MAX_LINKS`)
+const NVLINK_MAX_LINKS: u32 = 18;
+
+/// One NVLink of a GPU
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NvLinkState {
+    pub gpu: u32,
+    pub link: u32,
+    pub active: bool,
+    pub version: Option<u32>,
+    /// PCI bus id of the device at the other end
+    pub remote_pci_bus_id: Option<String>,
+    /// Index of the peer when it's one of this system's GPUs
+    pub remote_gpu: Option<u32>,
+    /// Cumulative bytes received and sent (utilization counter 0), when
+    /// the counter is configured to count bytes
+    pub rx_bytes: Option<u64>,
+    pub tx_bytes: Option<u64>,
+}
+
+/// NVLink state of every GPU and the links between them
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct NvLinkTopology {
+    /// (index, name) of each GPU
+    pub gpus: Vec<(u32, String)>,
+    /// Links that exist, active or not; empty without NVLink
+    pub links: Vec<NvLinkState>,
+}
+
+impl NvLinkTopology {
+    /// Whether any GPU has NVLink at all
+    pub fn has_nvlink(&self) -> bool {
+        !self.links.is_empty()
+    }
+
+    /// Active links between each pair of GPUs, indexed like `gpus`
+    pub fn link_matrix(&self) -> Vec<Vec<u32>> {
+        let position = |index: u32| self.gpus.iter().position(|(gpu, _)| *gpu == index);
+        let mut matrix = vec![vec![0; self.gpus.len()]; self.gpus.len()];
+        for link in self.links.iter().filter(|l| l.active) {
+            if let (Some(from), Some(to)) = (position(link.gpu), link.remote_gpu.and_then(position))
+            {
+                matrix[from][to] += 1;
+            }
+        }
+        matrix
+    }
+
+    /// Total (rx, tx) bytes per second over active links since `previous`
+    /// was read `elapsed_secs` ago. Counters that went backwards (reset)
+    /// count as zero.
+    pub fn throughput_since(&self, previous: &Self, elapsed_secs: f64) -> (f64, f64) {
+        if elapsed_secs <= 0.0 {
+            return (0.0, 0.0);
+        }
+        let mut rx = 0u64;
+        let mut tx = 0u64;
+        for link in self.links.iter().filter(|l| l.active) {
+            let Some(before) = previous
+                .links
+                .iter()
+                .find(|p| p.gpu == link.gpu && p.link == link.link)
+            else {
+                continue;
+            };
+            let delta = |now: Option<u64>, then: Option<u64>| match (now, then) {
+                (Some(now), Some(then)) => now.saturating_sub(then),
+                _ => 0,
+            };
+            rx += delta(link.rx_bytes, before.rx_bytes);
+            tx += delta(link.tx_bytes, before.tx_bytes);
+        }
+        (rx as f64 / elapsed_secs, tx as f64 / elapsed_secs)
+    }
+}
+
+/// Read every GPU's NVLinks: state, version, peer and byte counters.
+/// GPUs without NVLink contribute no links.
+pub fn get_nvlink_topology() -> NvResult<NvLinkTopology> {
+    use nvml_wrapper::Nvml;
+
+    let nvml = Nvml::init()
+        .map_err(|e| NvControlError::NvmlNotAvailable(format!("NVML init failed: {}", e)))?;
+    let count = nvml.device_count().map_err(|e| {
+        NvControlError::GpuQueryFailed(format!("Failed to get device count: {}", e))
+    })?;
+
+    let bus_id = |p: nvml_wrapper::struct_wrappers::device::PciInfo| {
+        format!("{:04x}:{:02x}:{:02x}.0", p.domain, p.bus, p.device)
+    };
+
+    let mut topology = NvLinkTopology::default();
+    let mut local_buses = Vec::new();
+    for index in 0..count {
+        let Ok(device) = nvml.device_by_index(index) else {
+            continue;
+        };
+        topology.gpus.push((
+            index,
+            device.name().unwrap_or_else(|_| "Unknown".to_string()),
+        ));
+        if let Ok(pci) = device.pci_info() {
+            local_buses.push((index, bus_id(pci)));
+        }
+
+        for link in 0..NVLINK_MAX_LINKS {
+            let wrapper = device.link_wrapper_for(link);
+            // Links past the last one the GPU has report NotSupported/InvalidArg
+            let Ok(active) = wrapper.is_active() else {
+                continue;
+            };
+            let counter = nvlink_byte_counter(&wrapper);
+            topology.links.push(NvLinkState {
+                gpu: index,
+                link,
+                active,
+                version: wrapper.version().ok(),
+                remote_pci_bus_id: wrapper.remote_pci_info().ok().map(bus_id),
+                remote_gpu: None,
+                rx_bytes: counter.map(|c| c.0),
+                tx_bytes: counter.map(|c| c.1),
+            });
+        }
+    }
+
+    for link in &mut topology.links {
+        link.remote_gpu = link.remote_pci_bus_id.as_ref().and_then(|remote| {
+            local_buses
+                .iter()
+                .find(|(_, bus)| bus.eq_ignore_ascii_case(remote))
+                .map(|(index, _)| *index)
+        });
+    }
+    Ok(topology)
+}
+
+/// (rx, tx) of utilization counter 0 when it counts bytes
+/// (`nvmlDeviceGetNvLinkUtilizationCounter`)
+fn nvlink_byte_counter(link: &nvml_wrapper::NvLink) -> Option<(u64, u64)> {
+    use nvml_wrapper::enum_wrappers::nv_link::UtilizationCountUnit;
+    use nvml_wrapper::enums::nv_link::Counter;
+
+    let control = link.utilization_control(Counter::Zero).ok()?;
+    if control.units != UtilizationCountUnit::Bytes {
+        return None;
+    }
+    let counter = link.utilization_counter(Counter::Zero).ok()?;
+    Some((counter.receive, counter.send))
+}
+
 /// Detect NVLink connection between two GPUs using nvidia-smi
 fn detect_nvlink_via_smi(gpu1: u32, gpu2: u32) -> NvResult<bool> {
     let output = std::process::Command::new("nvidia-smi")
@@ -494,6 +644,40 @@ mod tests {
         assert!(coordinator.is_ok() || coordinator.is_err());
     }
 
+    #[test]
+    fn test_nvlink_matrix_and_throughput() {
+        let link = |gpu, link, remote_gpu, active, rx, tx| NvLinkState {
+            gpu,
+            link,
+            active,
+            version: Some(3),
+            remote_pci_bus_id: None,
+            remote_gpu,
+            rx_bytes: Some(rx),
+            tx_bytes: Some(tx),
+        };
+        let before = NvLinkTopology {
+            gpus: vec![(0, "A100".into()), (1, "A100".into())],
+            links: vec![
+                link(0, 0, Some(1), true, 1_000, 2_000),
+                link(0, 1, Some(1), true, 0, 0),
+                link(0, 2, Some(1), false, 0, 0),
+                link(1, 0, Some(0), true, 5_000, 5_000),
+            ],
+        };
+        assert!(before.has_nvlink());
+        assert!(!NvLinkTopology::default().has_nvlink());
+        assert_eq!(before.link_matrix(), vec![vec![0, 2], vec![1, 0]]);
+
+        let mut after = before.clone();
+        after.links[0].rx_bytes = Some(3_000);
+        after.links[0].tx_bytes = Some(6_000);
+        // A reset counter doesn't produce a negative rate
+        after.links[3].rx_bytes = Some(0);
+        assert_eq!(after.throughput_since(&before, 2.0), (1_000.0, 2_000.0));
+        assert_eq!(after.throughput_since(&before, 0.0), (0.0, 0.0));
+    }
+
     #[test]
     fn test_gpu_profile() {
         let profile = GpuProfile {
@@ -0,0 +1,693 @@
+//! JSON API for monitoring a GPU host remotely (`nvctl serve`)
+//!
+//! A sampler thread polls NVML on a fixed interval and keeps the latest
+//! readings in memory, so requests never touch the driver for reads. The
+//! server is read-only unless started with control enabled *and* an
+//! `api_token` in the config; control requests must then send
+//! `Authorization: Bearer <token>`.
+//!
+//! | Method | Path | Body |
+//! |--------|------|------|
+//! | GET | `/gpus` | |
+//! | GET | `/gpus/{i}/metrics` | |
+//! | POST | `/gpus/{i}/fan` | `{"speed": 60}`, `{"fan": 1, "speed": 60}` or `{"auto": true}` |
+//! | POST | `/gpus/{i}/power-limit` | `{"watts": 300}` |
+
+use crate::monitoring::MetricSnapshot;
+use crate::multi_gpu::{GpuInfo, GpuLabel};
+use crate::nvml_backend::SharedNvmlBackend;
+use crate::{NvControlError, NvResult};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Default listen address; pass `--bind 0.0.0.0:8787` to reach it from other hosts
+pub const DEFAULT_BIND: &str = "127.0.0.1:8787";
+
+/// Largest request body accepted
+const MAX_BODY_BYTES: usize = 16 * 1024;
+
+/// Longest request or header line accepted
+const MAX_LINE_BYTES: usize = 8 * 1024;
+
+/// Most headers accepted in one request
+const MAX_HEADERS: usize = 64;
+
+/// Connections served at once; further ones get a 503
+const MAX_CONNECTIONS: usize = 32;
+
+/// Time a client gets to send its whole request, however it trickles in
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether POST endpoints are accepted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Control {
+    ReadOnly,
+    /// Control allowed for requests carrying this bearer token
+    Token(String),
+}
+
+impl Control {
+    /// Control needs both the opt-in flag and a configured token
+    pub fn from_options(allow_control: bool, api_token: &str) -> NvResult<Self> {
+        if !allow_control {
+            return Ok(Control::ReadOnly);
+        }
+        let token = api_token.trim();
+        if token.is_empty() {
+            return Err(NvControlError::ConfigError(
+                "--allow-control needs `api_token` set in config.toml".to_string(),
+            ));
+        }
+        Ok(Control::Token(token.to_string()))
+    }
+}
+
+/// Latest readings from the sampler thread
+#[derive(Debug, Clone, Default)]
+struct Snapshot {
+    gpus: Vec<GpuInfo>,
+    metrics: Vec<MetricSnapshot>,
+}
+
+/// A parsed HTTP request
+#[derive(Debug, Clone, Default)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// Value of `Authorization: Bearer ...`
+    pub token: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// A JSON response
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: serde_json::Value,
+}
+
+impl Response {
+    fn ok(body: serde_json::Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FanRequest {
+    fan: Option<u32>,
+    speed: Option<u8>,
+    #[serde(default)]
+    auto: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct PowerLimitRequest {
+    watts: f32,
+}
+
+/// Routes requests against the sampler's latest snapshot
+pub struct ApiServer {
+    backend: SharedNvmlBackend,
+    control: Control,
+    /// `gpu_labels` from the config, read once at startup
+    labels: BTreeMap<String, GpuLabel>,
+    snapshot: RwLock<Snapshot>,
+}
+
+impl ApiServer {
+    pub fn new(
+        backend: SharedNvmlBackend,
+        control: Control,
+        labels: BTreeMap<String, GpuLabel>,
+    ) -> Self {
+        Self {
+            backend,
+            control,
+            labels,
+            snapshot: RwLock::new(Snapshot::default()),
+        }
+    }
+
+    /// Poll NVML once and replace the cached snapshot
+    pub fn refresh(&self) {
+        let gpus = crate::multi_gpu::detect_gpus_with_backend(&self.backend, &self.labels)
+            .unwrap_or_default();
+        let metrics = (0..gpus.len() as u32)
+            .filter_map(|i| crate::monitoring::collect_device_metrics(&self.backend, i).ok())
+            .collect();
+        if let Ok(mut snapshot) = self.snapshot.write() {
+            *snapshot = Snapshot { gpus, metrics };
+        }
+    }
+
+    pub fn handle(&self, request: &Request) -> Response {
+        let segments: Vec<&str> = request
+            .path
+            .split('?')
+            .next()
+            .unwrap_or("")
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("GET", ["gpus"]) => self.gpus(),
+            ("GET", ["gpus", index, "metrics"]) => self.metrics(index),
+            ("POST", ["gpus", index, action @ ("fan" | "power-limit")]) => {
+                if let Some(denied) = self.authorize(request) {
+                    return denied;
+                }
+                let Ok(index) = index.parse::<u32>() else {
+                    return Response::error(400, format!("Invalid GPU index '{index}'"));
+                };
+                if !self.has_gpu(index) {
+                    return Response::error(404, format!("GPU {index} not found"));
+                }
+                match *action {
+                    "fan" => self.set_fan(index, &request.body),
+                    _ => self.set_power_limit(index, &request.body),
+                }
+            }
+            (_, ["gpus"] | ["gpus", _, "metrics" | "fan" | "power-limit"]) => {
+                Response::error(405, "Method not allowed")
+            }
+            _ => Response::error(404, format!("No endpoint at {}", request.path)),
+        }
+    }
+
+    fn gpus(&self) -> Response {
+        let snapshot = self.snapshot.read().map(|s| s.gpus.clone());
+        match snapshot {
+            Ok(gpus) => Response::ok(json!(gpus)),
+            Err(_) => Response::error(500, "Snapshot unavailable"),
+        }
+    }
+
+    fn metrics(&self, index: &str) -> Response {
+        let Ok(index) = index.parse::<usize>() else {
+            return Response::error(400, format!("Invalid GPU index '{index}'"));
+        };
+        let Ok(snapshot) = self.snapshot.read() else {
+            return Response::error(500, "Snapshot unavailable");
+        };
+        match snapshot.metrics.get(index) {
+            Some(metrics) => Response::ok(json!(metrics)),
+            None => Response::error(404, format!("GPU {index} not found")),
+        }
+    }
+
+    fn has_gpu(&self, index: u32) -> bool {
+        self.backend
+            .device_count()
+            .map(|count| index < count)
+            .unwrap_or(false)
+    }
+
+    /// `None` when the request may change GPU state
+    fn authorize(&self, request: &Request) -> Option<Response> {
+        match &self.control {
+            Control::ReadOnly => Some(Response::error(
+                403,
+                "Control endpoints are disabled; start with `nvctl serve --allow-control`",
+            )),
+            Control::Token(expected) => match &request.token {
+                Some(token) if tokens_match(token, expected) => None,
+                _ => Some(Response::error(401, "Missing or invalid bearer token")),
+            },
+        }
+    }
+
+    fn set_fan(&self, index: u32, body: &[u8]) -> Response {
+        let request: FanRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(e) => return Response::error(400, format!("Invalid fan request: {e}")),
+        };
+        let fan_count = self.backend.get_fan_count(index).unwrap_or(1).max(1);
+        let fans: Vec<u32> = match request.fan {
+            Some(fan) if fan >= fan_count => {
+                return Response::error(
+                    400,
+                    format!("GPU {index} has {fan_count} fan(s); fan {fan} does not exist"),
+                );
+            }
+            Some(fan) => vec![fan],
+            None => (0..fan_count).collect(),
+        };
+
+        for fan in &fans {
+            let fan_id = (index * 10 + fan) as usize;
+            let result = match (request.auto, request.speed) {
+                (true, _) => crate::fan::reset_fan_to_auto(fan_id),
+                (false, Some(speed)) if speed <= 100 => crate::fan::set_fan_speed(fan_id, speed),
+                (false, Some(speed)) => {
+                    return Response::error(400, format!("Fan speed {speed}% is above 100"));
+                }
+                (false, None) => {
+                    return Response::error(400, "Send either \"speed\" or \"auto\": true");
+                }
+            };
+            if let Err(e) = result {
+                log::warn!("API fan request for GPU {index} fan {fan} failed: {e}");
+                return Response::error(500, e.to_string());
+            }
+            if let (false, Some(speed)) = (request.auto, request.speed) {
+                crate::state::LastApplied::record_fan_speed(fan_id, speed);
+            }
+        }
+
+        log::info!(
+            "API set GPU {index} fans {:?} to {}",
+            fans,
+            match request.speed {
+                Some(speed) if !request.auto => format!("{speed}%"),
+                _ => "auto".to_string(),
+            }
+        );
+        Response::ok(
+            json!({ "gpu": index, "fans": fans, "auto": request.auto, "speed": request.speed }),
+        )
+    }
+
+    fn set_power_limit(&self, index: u32, body: &[u8]) -> Response {
+        let request: PowerLimitRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(e) => return Response::error(400, format!("Invalid power limit request: {e}")),
+        };
+        match crate::power::set_power_limit_watts(&self.backend, index, request.watts) {
+            Ok(applied) => {
+                crate::state::LastApplied::record_power_limit(index, applied.applied_w);
+                log::info!(
+                    "API set GPU {index} power limit to {:.0} W",
+                    applied.applied_w
+                );
+                Response::ok(json!({
+                    "gpu": index,
+                    "requested_w": applied.requested_w,
+                    "applied_w": applied.applied_w,
+                    "range": applied.range,
+                }))
+            }
+            Err(e) => {
+                log::warn!("API power limit request for GPU {index} failed: {e}");
+                Response::error(500, e.to_string())
+            }
+        }
+    }
+}
+
+/// Compare without returning early on the first differing byte
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Read one line into `line`, refusing lines longer than [`MAX_LINE_BYTES`]
+fn read_bounded_line(reader: &mut impl BufRead, line: &mut String) -> Result<(), Response> {
+    line.clear();
+    reader
+        .by_ref()
+        .take(MAX_LINE_BYTES as u64 + 1)
+        .read_line(line)
+        .map_err(|_| Response::error(400, "Unreadable request"))?;
+    if line.len() > MAX_LINE_BYTES {
+        return Err(Response::error(431, "Request line or header too long"));
+    }
+    Ok(())
+}
+
+/// Read one HTTP/1.1 request from `reader`
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad = |message: &str| Response::error(400, message);
+
+    let mut line = String::new();
+    read_bounded_line(reader, &mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(bad("Malformed request line"));
+    };
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        ..Request::default()
+    };
+
+    let mut content_length = 0usize;
+    let mut header_count = 0usize;
+    loop {
+        read_bounded_line(reader, &mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        header_count += 1;
+        if header_count > MAX_HEADERS {
+            return Err(Response::error(431, "Too many headers"));
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(bad("Malformed header"));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse().map_err(|_| bad("Invalid Content-Length"))?;
+        } else if name.eq_ignore_ascii_case("authorization") {
+            request.token = value
+                .strip_prefix("Bearer ")
+                .map(|token| token.trim().to_string());
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(413, "Request body too large"));
+    }
+    request.body = vec![0; content_length];
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|_| bad("Truncated body"))?;
+    Ok(request)
+}
+
+fn write_response(stream: &mut impl Write, response: &Response) -> std::io::Result<()> {
+    let body = response.body.to_string();
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Reads from a stream until a fixed deadline. A per-read timeout alone
+/// lets a client hold its connection slot by sending a byte at a time.
+struct DeadlineReader {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(std::io::ErrorKind::TimedOut.into());
+        }
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+fn serve_connection(server: &ApiServer, mut stream: TcpStream) {
+    let _ = stream.set_write_timeout(Some(REQUEST_TIMEOUT));
+    let Ok(read_half) = stream.try_clone() else {
+        return;
+    };
+    let reader = DeadlineReader {
+        stream: read_half,
+        deadline: Instant::now() + REQUEST_TIMEOUT,
+    };
+    let response = match read_request(&mut BufReader::new(reader)) {
+        Ok(request) => {
+            log::debug!("API {} {}", request.method, request.path);
+            server.handle(&request)
+        }
+        Err(response) => response,
+    };
+    let _ = write_response(&mut stream, &response);
+}
+
+/// Bind `addr`, start the sampler and serve requests until the process exits.
+/// GPUs are reported with the nicknames in `labels`.
+pub fn serve(
+    backend: SharedNvmlBackend,
+    addr: &str,
+    control: Control,
+    labels: BTreeMap<String, GpuLabel>,
+    interval: Duration,
+) -> NvResult<()> {
+    let listener = TcpListener::bind(addr)
+        .map_err(|e| NvControlError::RuntimeError(format!("Failed to bind {addr}: {e}")))?;
+    let server = Arc::new(ApiServer::new(backend, control, labels));
+    server.refresh();
+
+    let running = Arc::new(AtomicBool::new(true));
+    {
+        let server = Arc::clone(&server);
+        let running = Arc::clone(&running);
+        thread::spawn(move || {
+            while running.load(Ordering::Relaxed) {
+                thread::sleep(interval);
+                server.refresh();
+            }
+        });
+    }
+
+    log::info!(
+        "🌐 Serving GPU API on http://{addr} ({})",
+        match server.control {
+            Control::ReadOnly => "read-only",
+            Control::Token(_) => "control enabled",
+        }
+    );
+
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        match stream {
+            Ok(mut stream) => {
+                if active.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    let busy = Response::error(503, "Too many connections");
+                    let _ = write_response(&mut stream, &busy);
+                    continue;
+                }
+                let server = Arc::clone(&server);
+                let active = Arc::clone(&active);
+                thread::spawn(move || {
+                    serve_connection(&server, stream);
+                    active.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+            Err(e) => log::warn!("API connection failed: {e}"),
+        }
+    }
+
+    running.store(false, Ordering::Relaxed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(control: Control) -> ApiServer {
+        let server = ApiServer::new(
+            crate::nvml_backend::create_mock_backend(),
+            control,
+            BTreeMap::new(),
+        );
+        server.refresh();
+        server
+    }
+
+    fn request(method: &str, path: &str, token: Option<&str>, body: &str) -> Request {
+        Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            token: token.map(str::to_string),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_read_endpoints_use_snapshot() {
+        let server = server(Control::ReadOnly);
+
+        let gpus = server.handle(&request("GET", "/gpus", None, ""));
+        assert_eq!(gpus.status, 200);
+        assert!(!gpus.body.as_array().unwrap().is_empty());
+
+        let metrics = server.handle(&request("GET", "/gpus/0/metrics", None, ""));
+        assert_eq!(metrics.status, 200);
+        assert!(metrics.body.get("temperature").is_some());
+
+        assert_eq!(
+            server
+                .handle(&request("GET", "/gpus/99/metrics", None, ""))
+                .status,
+            404
+        );
+        assert_eq!(
+            server.handle(&request("GET", "/nope", None, "")).status,
+            404
+        );
+        assert_eq!(
+            server.handle(&request("DELETE", "/gpus", None, "")).status,
+            405
+        );
+    }
+
+    #[test]
+    fn test_control_needs_opt_in_and_token() {
+        assert!(Control::from_options(true, "  ").is_err());
+        assert_eq!(Control::from_options(false, "").unwrap(), Control::ReadOnly);
+
+        let body = r#"{"watts": 250}"#;
+        let read_only = server(Control::ReadOnly);
+        assert_eq!(
+            read_only
+                .handle(&request(
+                    "POST",
+                    "/gpus/0/power-limit",
+                    Some("secret"),
+                    body
+                ))
+                .status,
+            403
+        );
+
+        let control = server(Control::Token("secret".to_string()));
+        for token in [None, Some("wrong")] {
+            assert_eq!(
+                control
+                    .handle(&request("POST", "/gpus/0/power-limit", token, body))
+                    .status,
+                401
+            );
+        }
+        // Authorized requests reach validation (not applied, so no state is recorded)
+        let invalid = control.handle(&request(
+            "POST",
+            "/gpus/0/power-limit",
+            Some("secret"),
+            r#"{"watts": "lots"}"#,
+        ));
+        assert_eq!(invalid.status, 400, "{:?}", invalid.body);
+        assert_eq!(
+            control
+                .handle(&request(
+                    "POST",
+                    "/gpus/9/power-limit",
+                    Some("secret"),
+                    body
+                ))
+                .status,
+            404
+        );
+        assert_eq!(
+            control
+                .handle(&request(
+                    "POST",
+                    "/gpus/0/fan",
+                    Some("secret"),
+                    r#"{"speed": 120}"#
+                ))
+                .status,
+            400
+        );
+    }
+
+    #[test]
+    fn test_fan_request_stays_on_the_addressed_gpu() {
+        let control = server(Control::Token("secret".to_string()));
+        // The mock GPU has 2 fans; fan 12 would otherwise address GPU 1's fan 2
+        for body in [r#"{"fan": 2, "speed": 60}"#, r#"{"fan": 12, "speed": 60}"#] {
+            let response = control.handle(&request("POST", "/gpus/0/fan", Some("secret"), body));
+            assert_eq!(response.status, 400, "{:?}", response.body);
+        }
+        let huge = format!(r#"{{"fan": {}, "speed": 60}}"#, u32::MAX);
+        assert_eq!(
+            control
+                .handle(&request("POST", "/gpus/0/fan", Some("secret"), &huge))
+                .status,
+            400
+        );
+    }
+
+    #[test]
+    fn test_request_read_stops_at_deadline() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+
+        // A client that sends part of a request and then stalls
+        client.write_all(b"GET /gpus HTTP/1.1\r\n").unwrap();
+        let reader = DeadlineReader {
+            stream,
+            deadline: Instant::now() + Duration::from_millis(200),
+        };
+        let started = Instant::now();
+        assert!(read_request(&mut BufReader::new(reader)).is_err());
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_read_request_parses_headers_and_body() {
+        let raw = "POST /gpus/0/fan HTTP/1.1\r\nHost: rig\r\nAuthorization: Bearer abc\r\nContent-Length: 13\r\n\r\n{\"speed\": 60}";
+        let request = read_request(&mut raw.as_bytes()).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/gpus/0/fan");
+        assert_eq!(request.token.as_deref(), Some("abc"));
+        assert_eq!(request.body, br#"{"speed": 60}"#);
+
+        let huge = format!(
+            "POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        assert_eq!(read_request(&mut huge.as_bytes()).unwrap_err().status, 413);
+
+        let long_header = format!(
+            "GET / HTTP/1.1\r\nX-Pad: {}\r\n\r\n",
+            "a".repeat(MAX_LINE_BYTES)
+        );
+        assert_eq!(
+            read_request(&mut long_header.as_bytes())
+                .unwrap_err()
+                .status,
+            431
+        );
+
+        let many_headers = format!(
+            "GET / HTTP/1.1\r\n{}\r\n",
+            "X-Pad: a\r\n".repeat(MAX_HEADERS + 1)
+        );
+        assert_eq!(
+            read_request(&mut many_headers.as_bytes())
+                .unwrap_err()
+                .status,
+            431
+        );
+    }
+}
@@ -302,6 +302,200 @@ impl AsusGpuTweak {
     }
 }
 
+// ==================== OC Scanner ====================
+
+/// Name the scanner saves its result under in the overclock profiles
+pub const OC_SCANNER_PROFILE: &str = "OC Scanner";
+
+/// Core offset step of the scanner's sweep
+pub const OC_SCAN_STEP_MHZ: u32 = 15;
+/// GPU load held after each sweep step
+pub const OC_SCAN_PROBE_DURATION: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Progress reported by `run_oc_scanner`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcScanProgress {
+    /// Power limit the sweep runs at
+    PowerLimit {
+        limit_w: u32,
+        default_w: u32,
+        unlocked: bool,
+    },
+    /// ASUS board whose Power Detector+ reports connector warnings; the
+    /// sweep stays at the default limit
+    ConnectorWarning,
+    Step(crate::overclocking::StagedStep),
+}
+
+/// Outcome of `run_oc_scanner`
+#[derive(Debug, Clone)]
+pub struct OcScanResult {
+    /// ASUS board the scan was tuned for; `None` on other boards
+    pub asus_model: Option<String>,
+    /// Power limit held during the sweep, in watts
+    pub power_limit_w: u32,
+    /// Whether the limit was raised above the board default
+    pub power_unlocked: bool,
+    /// The core offset sweep; its achieved offset is left applied
+    pub sweep: crate::overclocking::StagedApplyResult,
+    /// Profile the result was saved as, when the sweep succeeded
+    pub profile_name: Option<String>,
+}
+
+impl OcScanResult {
+    /// Whether the sweep found a stable offset above stock
+    pub fn successful(&self) -> bool {
+        self.sweep.achieved_offset > 0
+    }
+}
+
+/// Power limit to sweep with. ASUS boards ship a VBIOS ceiling above the
+/// default limit, which GPU Tweak's scanner unlocks; other boards, and ASUS
+/// boards whose connector already reads hot, stay at the default.
+pub fn scan_power_limit_mw(
+    asus: bool,
+    connector: Option<crate::asus_power_detector::PowerHealth>,
+    default_mw: u32,
+    max_mw: u32,
+) -> u32 {
+    use crate::asus_power_detector::PowerHealth;
+
+    let connector_ok = !matches!(
+        connector,
+        Some(PowerHealth::Warning | PowerHealth::Critical)
+    );
+    if asus && connector_ok {
+        max_mw.max(default_mw)
+    } else {
+        default_mw
+    }
+}
+
+/// Sysfs and NVML format PCI domains differently ("0000:01:00.0" vs
+/// "00000000:01:00.0"), so compare bus, device and function only
+pub(crate) fn same_pci_device(a: &str, b: &str) -> bool {
+    let tail = |id: &str| {
+        id.rsplitn(3, ':')
+            .take(2)
+            .collect::<Vec<_>>()
+            .join(":")
+            .to_lowercase()
+    };
+    tail(a) == tail(b)
+}
+
+/// Run `sweep`, then `restore` whatever way the sweep ended
+fn sweep_then_restore<T>(
+    sweep: impl FnOnce() -> NvResult<T>,
+    restore: impl FnOnce() -> NvResult<()>,
+) -> NvResult<T> {
+    let result = sweep();
+    let restored = restore();
+    let value = result?;
+    restored?;
+    Ok(value)
+}
+
+/// Guided one-click OC scan of `gpu_index`. On ASUS ROG boards the power
+/// limit is raised to the VBIOS ceiling for the sweep (unless Power
+/// Detector+ reports connector trouble); other boards sweep at the default
+/// limit. The core offset is ramped in steps with a GPU load held after each
+/// (`overclocking::apply_staged`) and the last stable step is left applied
+/// and saved as the "OC Scanner" profile. The power limit the GPU had before
+/// the scan is restored however the sweep ends.
+pub fn run_oc_scanner(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+    gpu_index: u32,
+    cancel: &std::sync::atomic::AtomicBool,
+    mut on_progress: impl FnMut(OcScanProgress),
+) -> NvResult<OcScanResult> {
+    use crate::asus_power_detector::{AsusPowerDetector, AsusRogModel, detect_asus_gpus};
+    use crate::gpu_profiles::{OcPreset, preset_offsets};
+    use crate::overclocking::OverclockProfile;
+
+    let pci_id = backend.get_pci_bus_id(gpu_index)?;
+    let asus = detect_asus_gpus()
+        .into_iter()
+        .find(|(id, _)| same_pci_device(id, &pci_id));
+    let connector = asus
+        .as_ref()
+        .filter(|(_, model)| model.supports_power_detector())
+        .and_then(|(id, _)| AsusPowerDetector::new(id).ok())
+        .filter(|detector| detector.is_supported())
+        .and_then(|detector| detector.read_power_rails().ok())
+        .map(|status| status.health);
+    let is_asus = asus
+        .as_ref()
+        .is_some_and(|(_, model)| *model != AsusRogModel::NotAsus);
+
+    let previous_mw = backend.get_power_limit(gpu_index)?;
+    let default_mw = backend.get_power_limit_default(gpu_index)?;
+    let (_, max_mw) = backend.get_power_limit_constraints(gpu_index)?;
+    let limit_mw = scan_power_limit_mw(is_asus, connector, default_mw, max_mw);
+    let power_unlocked = limit_mw > default_mw;
+    if is_asus && !power_unlocked {
+        on_progress(OcScanProgress::ConnectorWarning);
+    }
+    on_progress(OcScanProgress::PowerLimit {
+        limit_w: limit_mw / 1000,
+        default_w: default_mw / 1000,
+        unlocked: power_unlocked,
+    });
+
+    // Sweep up to this card's Extreme preset, the top of its known-safe range
+    let gpu_name = backend.get_name(gpu_index).unwrap_or_default();
+    let profile = OverclockProfile {
+        name: OC_SCANNER_PROFILE.to_string(),
+        gpu_clock_offset: preset_offsets(&gpu_name, OcPreset::Extreme).gpu_offset,
+        power_limit: (limit_mw as u64 * 100 / default_mw.max(1) as u64).min(u8::MAX as u64) as u8,
+        ..OverclockProfile::default()
+    };
+    let sweep = sweep_then_restore(
+        || {
+            crate::overclocking::apply_staged(
+                backend,
+                gpu_index,
+                &profile,
+                OC_SCAN_STEP_MHZ,
+                OC_SCAN_PROBE_DURATION,
+                cancel,
+                |step| on_progress(OcScanProgress::Step(step.clone())),
+            )
+        },
+        || {
+            crate::power::set_power_limit_watts(backend, gpu_index, previous_mw as f32 / 1000.0)
+                .map(|_| ())
+        },
+    )?;
+
+    let profile_name = if sweep.achieved_offset > 0 {
+        let mut manager = crate::enhanced_overclock::OverclockProfileManager::new();
+        manager.load()?;
+        manager.set_profile(crate::enhanced_overclock::OverclockProfile {
+            name: OC_SCANNER_PROFILE.to_string(),
+            game_exe: None,
+            gpu_offset_mhz: sweep.achieved_offset,
+            memory_offset_mhz: 0,
+            power_limit_watts: Some(limit_mw / 1000),
+            voltage_curve: None,
+            fan_curve: None,
+            enabled: true,
+        });
+        manager.save()?;
+        Some(OC_SCANNER_PROFILE.to_string())
+    } else {
+        None
+    };
+
+    Ok(OcScanResult {
+        asus_model: asus.map(|(_, model)| model.name().to_string()),
+        power_limit_w: limit_mw / 1000,
+        power_unlocked,
+        sweep,
+        profile_name,
+    })
+}
+
 /// GPU Tweak style monitoring data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AsusMonitoringData {
@@ -349,4 +543,45 @@ mod tests {
         assert_eq!(fan_profile.mode, FanMode::Auto);
         assert_eq!(fan_profile.curve.len(), 3);
     }
+
+    #[test]
+    fn test_scan_power_limit() {
+        use crate::asus_power_detector::PowerHealth;
+
+        assert_eq!(scan_power_limit_mw(true, None, 450_000, 600_000), 600_000);
+        assert_eq!(
+            scan_power_limit_mw(true, Some(PowerHealth::Good), 450_000, 600_000),
+            600_000
+        );
+        assert_eq!(
+            scan_power_limit_mw(true, Some(PowerHealth::Warning), 450_000, 600_000),
+            450_000
+        );
+        assert_eq!(scan_power_limit_mw(false, None, 450_000, 600_000), 450_000);
+        assert!(same_pci_device("0000:01:00.0", "00000000:01:00.0"));
+        assert!(!same_pci_device("0000:01:00.0", "0000:02:00.0"));
+    }
+
+    #[test]
+    fn test_scan_restores_power_limit_on_every_exit() {
+        let mut restored = 0;
+        let ok = sweep_then_restore(
+            || Ok(75),
+            || {
+                restored += 1;
+                Ok(())
+            },
+        );
+        assert_eq!(ok.unwrap(), 75);
+
+        let failed: NvResult<i32> = sweep_then_restore(
+            || Err(NvControlError::RuntimeError("probe failed".to_string())),
+            || {
+                restored += 1;
+                Ok(())
+            },
+        );
+        assert!(failed.is_err());
+        assert_eq!(restored, 2);
+    }
 }
@@ -2,7 +2,7 @@
 // Auto-tune GPU for optimal performance with stability testing
 
 use crate::benchmark::BenchmarkSuite;
-use crate::overclocking::OverclockProfile;
+use crate::overclocking::{OverclockProfile, ProbeOutcome};
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
@@ -363,6 +363,356 @@ impl AutoOverclocker {
     }
 }
 
+// ==================== Efficiency (undervolt) tuning ====================
+//
+// NVIDIA's Linux driver has no direct voltage control. The usual undervolt
+// is done on the V/F curve instead: lock the maximum graphics clock to the
+// stock boost clock and raise the core offset. The GPU then reaches that
+// clock at a lower voltage point, so the highest stable offset under the
+// lock is the lowest stable voltage at stock clocks.
+
+/// Progress events emitted while searching for the efficient offset
+#[derive(Debug, Clone, PartialEq)]
+pub enum EfficiencyProgress {
+    /// Testing a core offset under the locked clock
+    Testing {
+        iteration: usize,
+        offset_mhz: i32,
+    },
+    Stable {
+        offset_mhz: i32,
+    },
+    Unstable {
+        offset_mhz: i32,
+    },
+    /// Search finished with the offset that will be kept
+    Converged {
+        offset_mhz: i32,
+    },
+}
+
+/// Tunables for the efficiency search
+#[derive(Debug, Clone)]
+pub struct EfficiencyConfig {
+    pub gpu_index: u32,
+    /// Offset step per iteration in MHz
+    pub step_mhz: i32,
+    /// Upper bound for the offset search in MHz
+    pub max_offset_mhz: i32,
+    /// Subtracted from the highest stable offset
+    pub safety_margin_mhz: i32,
+    /// Stress test length between steps
+    pub test_duration_secs: u64,
+}
+
+impl Default for EfficiencyConfig {
+    fn default() -> Self {
+        Self {
+            gpu_index: 0,
+            step_mhz: 15,
+            max_offset_mhz: 250,
+            safety_margin_mhz: 15,
+            test_duration_secs: 60,
+        }
+    }
+}
+
+/// Outcome of the pure offset search
+#[derive(Debug, Clone, PartialEq)]
+pub struct EfficiencySearch {
+    /// Highest offset that passed the stress test
+    pub stable_offset_mhz: i32,
+    /// Offset kept after the safety margin
+    pub final_offset_mhz: i32,
+    pub iterations: usize,
+}
+
+/// Step the core offset up until `trial` reports instability, refine once
+/// with a half step, then back off by the safety margin. `trial` returns
+/// whether the GPU was stable at the given offset.
+pub fn search_efficient_offset(
+    config: &EfficiencyConfig,
+    mut trial: impl FnMut(i32) -> NvResult<bool>,
+    mut on_progress: impl FnMut(EfficiencyProgress),
+) -> NvResult<EfficiencySearch> {
+    let step = config.step_mhz.max(1);
+    let mut iterations = 0;
+    let mut stable = 0;
+
+    let mut run = |offset: i32, iterations: &mut usize| -> NvResult<bool> {
+        *iterations += 1;
+        on_progress(EfficiencyProgress::Testing {
+            iteration: *iterations,
+            offset_mhz: offset,
+        });
+        let ok = trial(offset)?;
+        on_progress(if ok {
+            EfficiencyProgress::Stable { offset_mhz: offset }
+        } else {
+            EfficiencyProgress::Unstable { offset_mhz: offset }
+        });
+        Ok(ok)
+    };
+
+    if !run(0, &mut iterations)? {
+        return Err(NvControlError::RuntimeError(
+            "GPU is unstable at stock settings; not undervolting".to_string(),
+        ));
+    }
+
+    let mut failed_at = None;
+    while stable + step <= config.max_offset_mhz {
+        if run(stable + step, &mut iterations)? {
+            stable += step;
+        } else {
+            failed_at = Some(stable + step);
+            break;
+        }
+    }
+
+    // One refinement between the last good and first bad offset
+    let half = step / 2;
+    if failed_at.is_some() && half > 0 && run(stable + half, &mut iterations)? {
+        stable += half;
+    }
+
+    let final_offset = (stable - config.safety_margin_mhz).max(0);
+    on_progress(EfficiencyProgress::Converged {
+        offset_mhz: final_offset,
+    });
+
+    Ok(EfficiencySearch {
+        stable_offset_mhz: stable,
+        final_offset_mhz: final_offset,
+        iterations,
+    })
+}
+
+/// A saved undervolt: clock ceiling plus core offset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EfficiencyProfile {
+    pub name: String,
+    pub gpu_index: u32,
+    /// Graphics clock is locked to at most this (stock boost)
+    pub max_gpu_clock_mhz: u32,
+    /// Core offset applied under the lock
+    pub gpu_clock_offset: i32,
+    /// Highest offset that passed before the safety margin
+    pub stable_offset_mhz: i32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl EfficiencyProfile {
+    fn dir() -> NvResult<std::path::PathBuf> {
+        Ok(dirs::config_dir()
+            .ok_or_else(|| NvControlError::ConfigError("No config directory".to_string()))?
+            .join("nvcontrol")
+            .join("efficiency_profiles"))
+    }
+
+    /// File name for a profile; path separators and other characters that
+    /// aren't safe in a file name are replaced so the name can't leave `dir()`
+    fn file_name(name: &str) -> String {
+        let stem: String = name
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || matches!(c, '-' | '_' | ' ') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!("{}.toml", if stem.is_empty() { "_" } else { &stem })
+    }
+
+    pub fn save(&self) -> NvResult<std::path::PathBuf> {
+        let dir = Self::dir()?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to create {dir:?}: {e}")))?;
+        let path = dir.join(Self::file_name(&self.name));
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to serialize: {e}")))?;
+        std::fs::write(&path, content)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to write {path:?}: {e}")))?;
+        Ok(path)
+    }
+
+    pub fn load(name: &str) -> NvResult<Self> {
+        let path = Self::dir()?.join(Self::file_name(name));
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to read profile '{name}': {e}"))
+        })?;
+        toml::from_str(&content)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to parse profile: {e}")))
+    }
+
+    pub fn list() -> Vec<String> {
+        let Ok(entries) = Self::dir().and_then(|dir| {
+            std::fs::read_dir(dir).map_err(|e| NvControlError::ConfigError(e.to_string()))
+        }) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                (path.extension()? == "toml")
+                    .then(|| path.file_stem()?.to_str().map(str::to_string))
+                    .flatten()
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Lock the clock ceiling and apply the offset. The lock is released
+    /// again if the offset can't be applied.
+    pub fn apply(&self) -> NvResult<()> {
+        lock_max_gpu_clock(self.gpu_index, Some(self.max_gpu_clock_mhz))?;
+        let profile = OverclockProfile {
+            name: self.name.clone(),
+            gpu_clock_offset: self.gpu_clock_offset,
+            ..OverclockProfile::default()
+        };
+        let result = crate::overclocking::apply_overclock_profile_for_gpu(&profile, self.gpu_index);
+        if result.is_err() {
+            let _ = lock_max_gpu_clock(self.gpu_index, None);
+        }
+        result
+    }
+}
+
+/// Stock boost clock of `gpu_index`, the ceiling an undervolt locks to.
+/// `max_clock_info` is the chip's absolute limit and would constrain nothing.
+fn stock_boost_clock(nvml: &nvml_wrapper::Nvml, gpu_index: u32) -> NvResult<u32> {
+    use nvml_wrapper::enum_wrappers::device::Clock;
+
+    let device = nvml
+        .device_by_index(gpu_index)
+        .map_err(|e| NvControlError::GpuQueryFailed(format!("GPU {gpu_index}: {e}")))?;
+    // GeForce boards often don't report a customer boost clock
+    device
+        .max_customer_boost_clock(Clock::Graphics)
+        .or_else(|_| device.default_applications_clock(Clock::Graphics))
+        .map_err(|e| NvControlError::GpuQueryFailed(format!("Stock boost clock: {e}")))
+}
+
+/// Lock the graphics clock to `0..=max_mhz`, or reset the lock with `None`
+fn lock_max_gpu_clock(gpu_index: u32, max_mhz: Option<u32>) -> NvResult<()> {
+    let mut cmd = std::process::Command::new("nvidia-smi");
+    cmd.args(["-i", &gpu_index.to_string()]);
+    match max_mhz {
+        Some(max) => cmd.args(["-lgc", &format!("0,{max}")]),
+        None => cmd.arg("-rgc"),
+    };
+
+    let output = crate::gpu_safe::execute(&mut cmd)
+        .map_err(|e| NvControlError::CommandFailed(format!("nvidia-smi failed: {e}")))?;
+    if !output.status.success() {
+        return Err(NvControlError::CommandFailed(format!(
+            "nvidia-smi could not lock clocks: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// Result of `find_efficient_voltage`
+#[derive(Debug, Clone)]
+pub struct EfficiencyResult {
+    pub profile: EfficiencyProfile,
+    pub iterations: usize,
+    pub time_taken: Duration,
+}
+
+impl AutoOverclocker {
+    /// Find the lowest stable voltage at stock clocks. Each step holds a GPU
+    /// load on `config.gpu_index` with `overclocking::stability_probe`; a
+    /// crash, overheating or new Xid errors count as instability. Probe
+    /// steps stay out of the overclock history; only the result is applied,
+    /// recorded and saved as `name`.
+    pub fn find_efficient_voltage(
+        &self,
+        name: &str,
+        config: &EfficiencyConfig,
+        on_progress: impl FnMut(EfficiencyProgress),
+    ) -> NvResult<EfficiencyResult> {
+        let start = Instant::now();
+
+        let nvml = nvml_wrapper::Nvml::init()
+            .map_err(|e| NvControlError::NvmlNotAvailable(e.to_string()))?;
+        let max_clock = stock_boost_clock(&nvml, config.gpu_index)?;
+
+        lock_max_gpu_clock(config.gpu_index, Some(max_clock))?;
+        let undo = || {
+            let _ =
+                crate::overclocking::apply_offsets(&OverclockProfile::default(), config.gpu_index);
+            let _ = lock_max_gpu_clock(config.gpu_index, None);
+        };
+
+        let backend = crate::nvml_backend::create_real_backend();
+        let cancel = std::sync::atomic::AtomicBool::new(false);
+        let search = search_efficient_offset(
+            config,
+            |offset| {
+                let profile = OverclockProfile {
+                    name: format!("{name} (testing)"),
+                    gpu_clock_offset: offset,
+                    ..OverclockProfile::default()
+                };
+                crate::overclocking::apply_offsets(&profile, config.gpu_index)?;
+
+                match crate::overclocking::stability_probe(
+                    backend.as_ref(),
+                    config.gpu_index,
+                    Duration::from_secs(config.test_duration_secs),
+                    &cancel,
+                )? {
+                    ProbeOutcome::Stable => Ok(true),
+                    ProbeOutcome::Unstable(_) | ProbeOutcome::Cancelled => Ok(false),
+                    ProbeOutcome::Unverified(reason) => Err(NvControlError::RuntimeError(format!(
+                        "Cannot test stability: {reason}"
+                    ))),
+                }
+            },
+            on_progress,
+        );
+
+        let search = match search {
+            Ok(search) => search,
+            Err(e) => {
+                undo();
+                return Err(e);
+            }
+        };
+
+        let profile = EfficiencyProfile {
+            name: name.to_string(),
+            gpu_index: config.gpu_index,
+            max_gpu_clock_mhz: max_clock,
+            gpu_clock_offset: search.final_offset_mhz,
+            stable_offset_mhz: search.stable_offset_mhz,
+            created_at: chrono::Utc::now(),
+        };
+        if let Err(e) = profile.apply() {
+            undo();
+            return Err(e);
+        }
+        if !crate::gpu_safe::is_dry_run() {
+            profile.save()?;
+        }
+
+        Ok(EfficiencyResult {
+            profile,
+            iterations: search.iterations,
+            time_taken: start.elapsed(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,6 +723,71 @@ mod tests {
         assert_eq!(config.target, AutoOCTarget::Balanced);
     }
 
+    fn efficiency_config() -> EfficiencyConfig {
+        EfficiencyConfig {
+            step_mhz: 20,
+            max_offset_mhz: 200,
+            safety_margin_mhz: 15,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_efficiency_search_backs_off_with_margin() {
+        let mut tested = Vec::new();
+        let search = search_efficient_offset(
+            &efficiency_config(),
+            |offset| {
+                tested.push(offset);
+                Ok(offset <= 75)
+            },
+            |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(tested, vec![0, 20, 40, 60, 80, 70]);
+        assert_eq!(search.stable_offset_mhz, 70);
+        assert_eq!(search.final_offset_mhz, 55);
+        assert_eq!(search.iterations, 6);
+    }
+
+    #[test]
+    fn test_efficiency_search_stops_at_max_offset() {
+        let mut events = Vec::new();
+        let search =
+            search_efficient_offset(&efficiency_config(), |_| Ok(true), |e| events.push(e))
+                .unwrap();
+
+        assert_eq!(search.stable_offset_mhz, 200);
+        assert_eq!(
+            events.last(),
+            Some(&EfficiencyProgress::Converged { offset_mhz: 185 })
+        );
+    }
+
+    #[test]
+    fn test_efficiency_search_rejects_unstable_stock() {
+        let result = search_efficient_offset(&efficiency_config(), |_| Ok(false), |_| {});
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_efficiency_profile_file_name_stays_in_dir() {
+        assert_eq!(
+            EfficiencyProfile::file_name("quiet 4090"),
+            "quiet 4090.toml"
+        );
+        assert_eq!(
+            EfficiencyProfile::file_name("../../.bashrc"),
+            "_______bashrc.toml"
+        );
+        assert_eq!(
+            EfficiencyProfile::file_name("/etc/passwd"),
+            "_etc_passwd.toml"
+        );
+        assert_eq!(EfficiencyProfile::file_name(""), "_.toml");
+    }
+
     #[test]
     fn test_step_sizes() {
         let config = AutoOCConfig {
@@ -4,7 +4,8 @@
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +37,407 @@ pub struct BenchmarkComparison {
     pub power_delta: f32,
 }
 
+/// One frame from a MangoHud log, aligned to wall-clock time
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrameSample {
+    pub timestamp_ms: i64,
+    pub fps: f32,
+    pub frametime_ms: f32,
+}
+
+/// GPU telemetry sampled during a correlated run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricSample {
+    pub timestamp_ms: i64,
+    pub gpu_clock_mhz: u32,
+    pub memory_clock_mhz: u32,
+    pub power_w: f32,
+    pub temperature_c: u32,
+    pub gpu_utilization: u32,
+}
+
+/// A metric sample with the average FPS of the frames since the previous sample
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CorrelatedSample {
+    pub metrics: MetricSample,
+    pub fps: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FpsSummary {
+    pub min: f32,
+    pub avg: f32,
+    pub max: f32,
+    /// Average FPS over the slowest 1% of frames
+    pub low_1pct: f32,
+}
+
+/// Result of `nvctl benchmark run`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorrelatedRunReport {
+    /// `None` when no MangoHud log was captured
+    pub fps: Option<FpsSummary>,
+    pub samples: Vec<CorrelatedSample>,
+    #[serde(default)]
+    pub gpu_name: String,
+    #[serde(default)]
+    pub driver_version: String,
+}
+
+/// Averages of one run, the unit that baselines store and compare
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    pub gpu_name: String,
+    pub driver_version: String,
+    pub avg_fps: Option<f32>,
+    pub low_1pct_fps: Option<f32>,
+    pub avg_gpu_clock_mhz: f32,
+    pub avg_temperature_c: f32,
+    pub avg_power_w: f32,
+}
+
+/// A run saved under a name to compare later runs against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub name: String,
+    pub run: RunSummary,
+}
+
+/// Change in one metric between a baseline and a run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta {
+    pub metric: String,
+    pub unit: String,
+    pub baseline: f32,
+    pub current: f32,
+    /// FPS and clocks should go up; temperature and power should go down
+    pub higher_is_better: bool,
+}
+
+impl MetricDelta {
+    /// Relative change in percent; `None` when the baseline is zero
+    pub fn percent(&self) -> Option<f32> {
+        (self.baseline != 0.0).then(|| (self.current - self.baseline) / self.baseline * 100.0)
+    }
+
+    /// Whether the change is a gain, `None` when nothing changed
+    pub fn is_improvement(&self) -> Option<bool> {
+        if self.current == self.baseline {
+            return None;
+        }
+        Some((self.current > self.baseline) == self.higher_is_better)
+    }
+}
+
+/// A run compared against a saved baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineComparison {
+    pub baseline_name: String,
+    pub deltas: Vec<MetricDelta>,
+    /// GPU or driver differences that make the comparison unreliable
+    pub warnings: Vec<String>,
+}
+
+/// Compare a run against a baseline, metric by metric. FPS metrics are
+/// skipped when either side ran without a MangoHud log.
+pub fn compare(run: &RunSummary, baseline: &Baseline) -> BaselineComparison {
+    let base = &baseline.run;
+    let mut deltas = Vec::new();
+    let mut push = |metric: &str, unit: &str, baseline: f32, current: f32, higher: bool| {
+        deltas.push(MetricDelta {
+            metric: metric.to_string(),
+            unit: unit.to_string(),
+            baseline,
+            current,
+            higher_is_better: higher,
+        })
+    };
+    if let (Some(b), Some(c)) = (base.avg_fps, run.avg_fps) {
+        push("Avg FPS", "fps", b, c, true);
+    }
+    if let (Some(b), Some(c)) = (base.low_1pct_fps, run.low_1pct_fps) {
+        push("1% low", "fps", b, c, true);
+    }
+    push(
+        "Avg clock",
+        "MHz",
+        base.avg_gpu_clock_mhz,
+        run.avg_gpu_clock_mhz,
+        true,
+    );
+    push(
+        "Avg temp",
+        "°C",
+        base.avg_temperature_c,
+        run.avg_temperature_c,
+        false,
+    );
+    push("Avg power", "W", base.avg_power_w, run.avg_power_w, false);
+
+    let mut warnings = Vec::new();
+    if base.gpu_name != run.gpu_name {
+        warnings.push(format!(
+            "Different GPUs: baseline on {}, this run on {}",
+            base.gpu_name, run.gpu_name
+        ));
+    }
+    if base.driver_version != run.driver_version {
+        warnings.push(format!(
+            "Driver changed from {} to {}; gains may come from the driver, not tuning",
+            base.driver_version, run.driver_version
+        ));
+    }
+
+    BaselineComparison {
+        baseline_name: baseline.name.clone(),
+        deltas,
+        warnings,
+    }
+}
+
+/// `~/.local/share/nvcontrol/baselines`
+pub fn baselines_dir() -> NvResult<PathBuf> {
+    dirs::data_dir()
+        .map(|dir| dir.join("nvcontrol").join("baselines"))
+        .ok_or_else(|| NvControlError::ConfigError("Could not find data directory".into()))
+}
+
+/// Save a run as a named baseline, replacing any baseline of that name
+pub fn save_baseline(name: &str, run: &RunSummary) -> NvResult<Baseline> {
+    save_baseline_in(&baselines_dir()?, name, run)
+}
+
+pub fn save_baseline_in(dir: &Path, name: &str, run: &RunSummary) -> NvResult<Baseline> {
+    let name = name.trim();
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err(NvControlError::ConfigError(format!(
+            "Invalid baseline name '{}'",
+            name
+        )));
+    }
+    let baseline = Baseline {
+        name: name.to_string(),
+        run: run.clone(),
+    };
+    fs::create_dir_all(dir)?;
+    let json = serde_json::to_string_pretty(&baseline)
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to serialize: {}", e)))?;
+    fs::write(dir.join(format!("{}.json", name)), json)?;
+    Ok(baseline)
+}
+
+pub fn load_baseline(name: &str) -> NvResult<Baseline> {
+    load_baseline_in(&baselines_dir()?, name)
+}
+
+pub fn load_baseline_in(dir: &Path, name: &str) -> NvResult<Baseline> {
+    let path = dir.join(format!("{}.json", name));
+    let contents = fs::read_to_string(&path).map_err(|_| {
+        NvControlError::ConfigError(format!(
+            "No baseline named '{}' (save one with: nvctl benchmark run --save-baseline {})",
+            name, name
+        ))
+    })?;
+    serde_json::from_str(&contents)
+        .map_err(|e| NvControlError::ConfigError(format!("Corrupt baseline '{}': {}", name, e)))
+}
+
+/// Saved baselines, newest first
+pub fn list_baselines() -> Vec<Baseline> {
+    baselines_dir()
+        .map(|dir| list_baselines_in(&dir))
+        .unwrap_or_default()
+}
+
+pub fn list_baselines_in(dir: &Path) -> Vec<Baseline> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut baselines: Vec<Baseline> = entries
+        .flatten()
+        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("json"))
+        .filter_map(|e| serde_json::from_str(&fs::read_to_string(e.path()).ok()?).ok())
+        .collect();
+    baselines.sort_by_key(|b| std::cmp::Reverse(b.run.recorded_at));
+    baselines
+}
+
+impl CorrelatedRunReport {
+    /// Averages over the run, for saving as or comparing with a baseline
+    pub fn summary(&self) -> RunSummary {
+        let avg = |f: fn(&MetricSample) -> f32| {
+            let values: Vec<f32> = self.samples.iter().map(|s| f(&s.metrics)).collect();
+            min_avg_max(&values).map(|(_, avg, _)| avg).unwrap_or(0.0)
+        };
+        RunSummary {
+            recorded_at: self
+                .samples
+                .first()
+                .and_then(|s| chrono::DateTime::from_timestamp_millis(s.metrics.timestamp_ms))
+                .unwrap_or_else(chrono::Utc::now),
+            gpu_name: self.gpu_name.clone(),
+            driver_version: self.driver_version.clone(),
+            avg_fps: self.fps.map(|f| f.avg),
+            low_1pct_fps: self.fps.map(|f| f.low_1pct),
+            avg_gpu_clock_mhz: avg(|m| m.gpu_clock_mhz as f32),
+            avg_temperature_c: avg(|m| m.temperature_c as f32),
+            avg_power_w: avg(|m| m.power_w),
+        }
+    }
+
+    /// Write the timestamp-aligned samples as CSV
+    pub fn write_csv(&self, path: &Path) -> NvResult<()> {
+        let mut csv = String::from(
+            "timestamp_ms,fps,gpu_clock_mhz,memory_clock_mhz,power_w,temperature_c,gpu_utilization\n",
+        );
+        for sample in &self.samples {
+            let m = &sample.metrics;
+            csv.push_str(&format!(
+                "{},{},{},{},{:.1},{},{}\n",
+                m.timestamp_ms,
+                sample.fps.map(|f| format!("{:.1}", f)).unwrap_or_default(),
+                m.gpu_clock_mhz,
+                m.memory_clock_mhz,
+                m.power_w,
+                m.temperature_c,
+                m.gpu_utilization
+            ));
+        }
+        fs::write(path, csv)?;
+        Ok(())
+    }
+
+    pub fn print_summary(&self) {
+        println!("\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("📊 Benchmark Run Summary");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        match &self.fps {
+            Some(fps) => {
+                println!(
+                    "FPS min/avg/max: {:.1} / {:.1} / {:.1}",
+                    fps.min, fps.avg, fps.max
+                );
+                println!("1% low:          {:.1}", fps.low_1pct);
+            }
+            None => println!("FPS:             unavailable (no MangoHud log)"),
+        }
+
+        let stats = |f: fn(&MetricSample) -> f32| {
+            let values: Vec<f32> = self.samples.iter().map(|s| f(&s.metrics)).collect();
+            min_avg_max(&values)
+        };
+        if let Some((min, avg, max)) = stats(|m| m.gpu_clock_mhz as f32) {
+            println!("GPU clock:       {:.0} / {:.0} / {:.0} MHz", min, avg, max);
+        }
+        if let Some((min, avg, max)) = stats(|m| m.power_w) {
+            println!("Power:           {:.1} / {:.1} / {:.1} W", min, avg, max);
+        }
+        if let Some((min, avg, max)) = stats(|m| m.temperature_c as f32) {
+            println!("Temperature:     {:.0} / {:.0} / {:.0} °C", min, avg, max);
+        }
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+    }
+}
+
+fn min_avg_max(values: &[f32]) -> Option<(f32, f32, f32)> {
+    if values.is_empty() {
+        return None;
+    }
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let avg = values.iter().sum::<f32>() / values.len() as f32;
+    Some((min, avg, max))
+}
+
+/// Parse a MangoHud CSV log. `log_start_ms` is the wall-clock time the log
+/// began; each row's `elapsed` (nanoseconds) is added to it.
+pub fn parse_mangohud_log(contents: &str, log_start_ms: i64) -> Vec<FrameSample> {
+    let mut lines = contents.lines();
+    let Some(header) = lines.find(|line| line.starts_with("fps,")) else {
+        return Vec::new();
+    };
+
+    let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+    let col = |name: &str| columns.iter().position(|c| *c == name);
+    let (Some(fps_col), Some(frametime_col), Some(elapsed_col)) =
+        (col("fps"), col("frametime"), col("elapsed"))
+    else {
+        return Vec::new();
+    };
+
+    lines
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            let fps = fields.get(fps_col)?.trim().parse::<f32>().ok()?;
+            let frametime_ms = fields.get(frametime_col)?.trim().parse::<f32>().ok()?;
+            let elapsed_ns = fields.get(elapsed_col)?.trim().parse::<i64>().ok()?;
+            Some(FrameSample {
+                timestamp_ms: log_start_ms + elapsed_ns / 1_000_000,
+                fps,
+                frametime_ms,
+            })
+        })
+        .collect()
+}
+
+/// Wall-clock start of a MangoHud log from its `<app>_YYYY-MM-DD_HH-MM-SS.csv` name
+pub(crate) fn mangohud_log_start_ms(path: &Path) -> Option<i64> {
+    let stem = path.file_stem()?.to_str()?;
+    let stamp = stem.get(stem.len().checked_sub(19)?..)?;
+    let naive = chrono::NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d_%H-%M-%S").ok()?;
+    naive
+        .and_local_timezone(chrono::Local)
+        .single()
+        .map(|t| t.timestamp_millis())
+}
+
+/// Summarize FPS from frame times
+pub fn summarize_fps(frames: &[FrameSample]) -> Option<FpsSummary> {
+    let fps: Vec<f32> = frames.iter().map(|f| f.fps).collect();
+    let (min, avg, max) = min_avg_max(&fps)?;
+
+    let mut frametimes: Vec<f32> = frames.iter().map(|f| f.frametime_ms).collect();
+    frametimes.sort_by(|a, b| b.total_cmp(a));
+    let worst = &frametimes[..frametimes.len().div_ceil(100)];
+    let worst_avg = worst.iter().sum::<f32>() / worst.len() as f32;
+    let low_1pct = if worst_avg > 0.0 {
+        1000.0 / worst_avg
+    } else {
+        min
+    };
+
+    Some(FpsSummary {
+        min,
+        avg,
+        max,
+        low_1pct,
+    })
+}
+
+/// Attach to each metric sample the average FPS of frames that fall between
+/// it and the previous sample
+pub fn correlate(metrics: &[MetricSample], frames: &[FrameSample]) -> Vec<CorrelatedSample> {
+    let mut previous = i64::MIN;
+    metrics
+        .iter()
+        .map(|sample| {
+            let window: Vec<f32> = frames
+                .iter()
+                .filter(|f| f.timestamp_ms > previous && f.timestamp_ms <= sample.timestamp_ms)
+                .map(|f| f.fps)
+                .collect();
+            previous = sample.timestamp_ms;
+            CorrelatedSample {
+                metrics: *sample,
+                fps: (!window.is_empty()).then(|| window.iter().sum::<f32>() / window.len() as f32),
+            }
+        })
+        .collect()
+}
+
+const LAST_RUN_FILE: &str = "last_run.summary";
+
 pub struct BenchmarkSuite {
     results_dir: PathBuf,
 }
@@ -282,6 +684,141 @@ impl BenchmarkSuite {
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
     }
 
+    /// Run a game (under MangoHud) or the built-in workload while sampling
+    /// GPU clocks, power and temperature, then correlate with the FPS log.
+    /// Without MangoHud or a command, runs the GPU-only workload and reports
+    /// FPS as unavailable.
+    pub fn run_correlated(
+        &self,
+        command: &[String],
+        duration_secs: u64,
+    ) -> NvResult<CorrelatedRunReport> {
+        let duration = Duration::from_secs(duration_secs.max(1));
+        let log_dir = self.results_dir.join(format!(
+            "mangohud_{}",
+            chrono::Utc::now().format("%Y%m%d_%H%M%S")
+        ));
+
+        let use_mangohud =
+            !command.is_empty() && crate::osd::OsdManager::check_mangohud_installed();
+        let mut child = None;
+        let mut workload = None;
+
+        if use_mangohud {
+            fs::create_dir_all(&log_dir)?;
+            println!("🎮 Launching under MangoHud: {}", command.join(" "));
+            let spawned = std::process::Command::new("mangohud")
+                .args(command)
+                .env(
+                    "MANGOHUD_CONFIG",
+                    format!(
+                        "output_folder={},autostart_log=1,log_duration={}",
+                        log_dir.display(),
+                        duration.as_secs()
+                    ),
+                )
+                .spawn()
+                .map_err(|e| {
+                    NvControlError::CommandFailed(format!("Failed to launch game: {}", e))
+                })?;
+            child = Some(spawned);
+        } else {
+            if command.is_empty() {
+                println!("ℹ️  No command given; running the GPU-only workload");
+            } else {
+                println!("⚠️  MangoHud not installed; running the GPU-only workload instead");
+            }
+            println!("   FPS will be unavailable for this run");
+            workload = Some(std::thread::spawn(move || {
+                let start = Instant::now();
+                while start.elapsed() < duration {
+                    let _ = (0..10000).map(|x| x as f32 * 0.1).sum::<f32>();
+                }
+            }));
+        }
+
+        let backend = crate::nvml_backend::create_real_backend();
+        let mut metrics = Vec::new();
+        let start = Instant::now();
+        while start.elapsed() < duration {
+            if let Ok(m) = backend.get_metrics(0) {
+                metrics.push(MetricSample {
+                    timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                    gpu_clock_mhz: m.gpu_clock_mhz,
+                    memory_clock_mhz: m.memory_clock_mhz,
+                    power_w: m.power_draw_mw as f32 / 1000.0,
+                    temperature_c: m.temperature,
+                    gpu_utilization: m.gpu_utilization,
+                });
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+
+        if let Some(mut child) = child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+        if let Some(handle) = workload {
+            let _ = handle.join();
+        }
+
+        let frames = if use_mangohud {
+            Self::read_latest_mangohud_log(&log_dir)
+        } else {
+            Vec::new()
+        };
+
+        let report = CorrelatedRunReport {
+            fps: summarize_fps(&frames),
+            samples: correlate(&metrics, &frames),
+            gpu_name: backend
+                .get_name(0)
+                .unwrap_or_else(|_| "Unknown GPU".to_string()),
+            driver_version: backend
+                .get_driver_version()
+                .unwrap_or_else(|_| "Unknown".to_string()),
+        };
+        // Kept so `benchmark compare` can check the latest run against a baseline
+        let json = serde_json::to_string_pretty(&report.summary())
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to serialize: {}", e)))?;
+        fs::write(self.results_dir.join(LAST_RUN_FILE), json)?;
+
+        Ok(report)
+    }
+
+    /// Summary of the most recent `run_correlated`
+    pub fn last_run(&self) -> Option<RunSummary> {
+        let contents = fs::read_to_string(self.results_dir.join(LAST_RUN_FILE)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn read_latest_mangohud_log(dir: &Path) -> Vec<FrameSample> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let latest = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("csv"))
+            .filter(|p| !p.to_string_lossy().ends_with("_summary.csv"))
+            .max();
+
+        let Some(path) = latest else {
+            return Vec::new();
+        };
+        let start_ms = mangohud_log_start_ms(&path).unwrap_or_else(|| {
+            fs::metadata(&path)
+                .and_then(|m| m.created())
+                .ok()
+                .map(|t| chrono::DateTime::<chrono::Utc>::from(t).timestamp_millis())
+                .unwrap_or(0)
+        });
+
+        fs::read_to_string(&path)
+            .map(|contents| parse_mangohud_log(&contents, start_ms))
+            .unwrap_or_default()
+    }
+
     /// Get the most recent benchmark result
     pub fn get_latest_result(&self) -> NvResult<Option<BenchmarkResult>> {
         let results = self.load_all_results()?;
@@ -332,3 +869,643 @@ impl BenchmarkSuite {
         Ok(stable)
     }
 }
+
+/// A device-to-device copy measured below this share of the published
+/// bandwidth is flagged; healthy cards usually reach 80-90%
+pub const BANDWIDTH_WARN_PERCENT: f64 = 70.0;
+
+/// How a memory bandwidth figure was obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BandwidthMethod {
+    /// Timed `cudaMemcpy` device-to-device copies
+    Cuda,
+    /// Memory clock × bus width, nothing actually moved
+    ClockEstimate,
+}
+
+/// Result of [`memory_bandwidth`]
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryBandwidthResult {
+    pub gpu_index: u32,
+    pub gpu_name: String,
+    pub method: BandwidthMethod,
+    pub measured_gbps: f64,
+    /// Published bandwidth for the detected model
+    pub expected_gbps: Option<f64>,
+}
+
+impl MemoryBandwidthResult {
+    pub fn percent_of_expected(&self) -> Option<f64> {
+        self.expected_gbps
+            .filter(|expected| *expected > 0.0)
+            .map(|expected| self.measured_gbps / expected * 100.0)
+    }
+
+    /// Only a real measurement can be below expected; an estimate just
+    /// reflects the current memory clock
+    pub fn is_below_expected(&self) -> bool {
+        self.method == BandwidthMethod::Cuda
+            && self
+                .percent_of_expected()
+                .is_some_and(|percent| percent < BANDWIDTH_WARN_PERCENT)
+    }
+}
+
+/// Copies 256 MiB buffers on the device and prints `GBPS <value>`. Each copy
+/// reads and writes the buffer, so both directions count.
+const BANDWIDTH_CU: &str = r#"#include <cstdio>
+#include <cstdlib>
+#include <cuda_runtime.h>
+
+int main(int argc, char **argv) {
+    int device = argc > 1 ? atoi(argv[1]) : 0;
+    size_t bytes = (size_t)256 << 20;
+    int iterations = 20;
+    void *src, *dst;
+    if (cudaSetDevice(device) != cudaSuccess ||
+        cudaMalloc(&src, bytes) != cudaSuccess ||
+        cudaMalloc(&dst, bytes) != cudaSuccess) {
+        fprintf(stderr, "%s\n", cudaGetErrorString(cudaGetLastError()));
+        return 1;
+    }
+    cudaMemset(src, 1, bytes);
+    cudaMemcpy(dst, src, bytes, cudaMemcpyDeviceToDevice);
+
+    cudaEvent_t start, stop;
+    cudaEventCreate(&start);
+    cudaEventCreate(&stop);
+    cudaEventRecord(start);
+    for (int i = 0; i < iterations; i++) {
+        cudaMemcpy(dst, src, bytes, cudaMemcpyDeviceToDevice);
+    }
+    cudaEventRecord(stop);
+    cudaEventSynchronize(stop);
+    float ms = 0.0f;
+    cudaEventElapsedTime(&ms, start, stop);
+    if (cudaGetLastError() != cudaSuccess || ms <= 0.0f) {
+        fprintf(stderr, "copy failed\n");
+        return 1;
+    }
+    printf("GBPS %.2f\n", 2.0 * bytes * iterations / (ms / 1000.0) / 1e9);
+    cudaFree(src);
+    cudaFree(dst);
+    return 0;
+}
+"#;
+
+/// Read the `GBPS <value>` line printed by the CUDA copy benchmark
+pub fn parse_bandwidth_output(output: &str) -> Option<f64> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("GBPS "))
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|gbps: &f64| *gbps > 0.0)
+}
+
+/// Compile the copy benchmark once with nvcc and keep it in the cache dir
+fn cuda_bandwidth_binary() -> NvResult<PathBuf> {
+    let dir = dirs::cache_dir()
+        .ok_or_else(|| NvControlError::ConfigError("Could not find cache directory".into()))?
+        .join("nvcontrol")
+        .join("membw");
+    let binary = dir.join("membw");
+    if binary.exists() {
+        return Ok(binary);
+    }
+
+    fs::create_dir_all(&dir)?;
+    let source = dir.join("membw.cu");
+    fs::write(&source, BANDWIDTH_CU)?;
+    let output = std::process::Command::new("nvcc")
+        .args(["-O2", "-o"])
+        .arg(&binary)
+        .arg(&source)
+        .output()
+        .map_err(|e| NvControlError::CommandFailed(format!("Failed to run nvcc: {}", e)))?;
+    if !output.status.success() {
+        return Err(NvControlError::CommandFailed(format!(
+            "nvcc failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(binary)
+}
+
+fn measure_cuda_bandwidth(gpu_index: u32) -> NvResult<f64> {
+    let binary = cuda_bandwidth_binary()?;
+    let output = std::process::Command::new(&binary)
+        .arg(gpu_index.to_string())
+        .output()
+        .map_err(|e| NvControlError::CommandFailed(format!("Failed to run {:?}: {}", binary, e)))?;
+    if !output.status.success() {
+        return Err(NvControlError::CommandFailed(format!(
+            "Bandwidth benchmark failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    parse_bandwidth_output(&String::from_utf8_lossy(&output.stdout)).ok_or_else(|| {
+        NvControlError::CommandFailed("Bandwidth benchmark printed no result".to_string())
+    })
+}
+
+/// Measure VRAM throughput with a CUDA device-to-device copy and compare it
+/// with the published bandwidth of the detected model. Without the CUDA
+/// toolkit (or if the copy fails) this degrades to a rough estimate from the
+/// current memory clock and bus width.
+pub fn memory_bandwidth(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+    gpu_index: u32,
+) -> NvResult<MemoryBandwidthResult> {
+    let gpu_name = backend.get_name(gpu_index)?;
+    let spec = crate::gpu_profiles::get_gpu_spec(&gpu_name);
+    let expected_gbps = spec.as_ref().and_then(|s| s.memory_bandwidth_gbps());
+
+    let measured = if crate::cuda::is_cuda_available() {
+        measure_cuda_bandwidth(gpu_index)
+            .inspect_err(|e| log::warn!("CUDA bandwidth benchmark failed: {e}"))
+            .ok()
+    } else {
+        None
+    };
+
+    let (method, measured_gbps) = match measured {
+        Some(gbps) => (BandwidthMethod::Cuda, gbps),
+        None => {
+            let mut metrics = backend.get_metrics(gpu_index)?;
+            if metrics.memory_bus_width_bits.is_none() {
+                metrics.memory_bus_width_bits = spec.as_ref().map(|s| s.memory_bus_width);
+            }
+            let estimate = metrics.peak_bandwidth_gbps().ok_or_else(|| {
+                NvControlError::UnsupportedFeature(
+                    "Memory clock or bus width unavailable; install the CUDA toolkit to measure bandwidth"
+                        .to_string(),
+                )
+            })?;
+            (BandwidthMethod::ClockEstimate, estimate)
+        }
+    };
+
+    Ok(MemoryBandwidthResult {
+        gpu_index,
+        gpu_name,
+        method,
+        measured_gbps,
+        expected_gbps,
+    })
+}
+
+/// Degrees above the pre-load temperature that count as back at idle
+pub const SOAK_IDLE_MARGIN_C: u32 = 3;
+
+/// The load stops as soon as the core reaches this temperature
+pub const SOAK_CRITICAL_TEMP_C: u32 = 90;
+
+/// The cooldown phase ends after this long even if the card never gets back
+/// to its idle temperature
+pub const SOAK_MAX_COOLDOWN_SECS: u64 = 600;
+
+/// Tail of the load phase averaged into the steady-state temperature
+const SOAK_STEADY_WINDOW_SECS: f64 = 60.0;
+
+/// Which half of a thermal soak a sample belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SoakPhase {
+    Load,
+    Cooldown,
+}
+
+impl SoakPhase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Load => "load",
+            Self::Cooldown => "cooldown",
+        }
+    }
+}
+
+/// One reading taken during a thermal soak
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoakSample {
+    /// Seconds since the load started
+    pub elapsed_secs: f64,
+    pub phase: SoakPhase,
+    pub temperature_c: u32,
+    pub fan_percent: u32,
+    pub power_w: f32,
+    pub gpu_utilization: u32,
+}
+
+/// Result of [`thermal_soak`]: the temperature curve through a sustained
+/// load and the cooldown after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThermalSoakReport {
+    pub gpu_index: u32,
+    pub gpu_name: String,
+    /// Seconds of load actually run, shorter than requested when aborted
+    pub load_secs: f64,
+    /// Temperature before the load started
+    pub idle_temp_c: u32,
+    pub peak_temp_c: u32,
+    /// Average over the last minute of load
+    pub steady_state_temp_c: Option<f32>,
+    /// Seconds from the end of the load until the core was back within
+    /// [`SOAK_IDLE_MARGIN_C`] of idle; `None` if it never got there
+    pub time_to_idle_secs: Option<f64>,
+    /// Why the load stopped early
+    pub aborted: Option<String>,
+    pub samples: Vec<SoakSample>,
+}
+
+impl ThermalSoakReport {
+    /// Derive the steady-state temperature and time-to-idle from the samples
+    pub fn from_samples(
+        gpu_index: u32,
+        gpu_name: String,
+        idle_temp_c: u32,
+        samples: Vec<SoakSample>,
+        aborted: Option<String>,
+    ) -> Self {
+        let load: Vec<&SoakSample> = samples
+            .iter()
+            .filter(|s| s.phase == SoakPhase::Load)
+            .collect();
+        let load_secs = load.last().map(|s| s.elapsed_secs).unwrap_or(0.0);
+
+        let steady: Vec<f32> = load
+            .iter()
+            .filter(|s| s.elapsed_secs >= load_secs - SOAK_STEADY_WINDOW_SECS)
+            .map(|s| s.temperature_c as f32)
+            .collect();
+        let steady_state_temp_c = min_avg_max(&steady).map(|(_, avg, _)| avg);
+
+        let time_to_idle_secs = samples
+            .iter()
+            .filter(|s| s.phase == SoakPhase::Cooldown)
+            .find(|s| s.temperature_c <= idle_temp_c + SOAK_IDLE_MARGIN_C)
+            .map(|s| s.elapsed_secs - load_secs);
+
+        Self {
+            gpu_index,
+            gpu_name,
+            load_secs,
+            idle_temp_c,
+            peak_temp_c: samples.iter().map(|s| s.temperature_c).max().unwrap_or(0),
+            steady_state_temp_c,
+            time_to_idle_secs,
+            aborted,
+            samples,
+        }
+    }
+
+    /// Write the temperature curve across both phases as CSV
+    pub fn write_csv(&self, path: &Path) -> NvResult<()> {
+        let mut csv =
+            String::from("elapsed_s,phase,temperature_c,fan_percent,power_w,gpu_utilization\n");
+        for s in &self.samples {
+            csv.push_str(&format!(
+                "{:.1},{},{},{},{:.1},{}\n",
+                s.elapsed_secs,
+                s.phase.name(),
+                s.temperature_c,
+                s.fan_percent,
+                s.power_w,
+                s.gpu_utilization
+            ));
+        }
+        fs::write(path, csv)?;
+        Ok(())
+    }
+
+    pub fn print_summary(&self) {
+        println!("\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!(
+            "🌡️  Thermal Soak - GPU {} ({})",
+            self.gpu_index, self.gpu_name
+        );
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("Load:            {:.0}s", self.load_secs);
+        println!("Idle before:     {}°C", self.idle_temp_c);
+        println!("Peak:            {}°C", self.peak_temp_c);
+        match self.steady_state_temp_c {
+            Some(temp) => println!("Steady state:    {:.1}°C", temp),
+            None => println!("Steady state:    unavailable"),
+        }
+        match self.time_to_idle_secs {
+            Some(secs) => println!("Time to idle:    {:.0}s", secs),
+            None => println!(
+                "Time to idle:    not reached within {}s",
+                SOAK_MAX_COOLDOWN_SECS
+            ),
+        }
+        if let Some(reason) = &self.aborted {
+            println!("⚠️  Load stopped early: {}", reason);
+        }
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
+    }
+}
+
+/// Default CSV location for a soak run, in the benchmark results directory
+pub fn default_soak_csv_path() -> NvResult<PathBuf> {
+    let dir = dirs::data_dir()
+        .ok_or_else(|| NvControlError::ConfigError("Could not find data directory".into()))?
+        .join("nvcontrol")
+        .join("benchmarks");
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(format!(
+        "soak_{}.csv",
+        chrono::Local::now().format("%Y%m%d_%H%M%S")
+    )))
+}
+
+/// Run a full load on GPU 0 for `load_secs`, then record the cooldown until
+/// the core is back at its starting temperature. The load stops early at
+/// [`SOAK_CRITICAL_TEMP_C`].
+pub fn thermal_soak(load_secs: u64) -> NvResult<ThermalSoakReport> {
+    let backend = crate::nvml_backend::create_real_backend();
+    run_thermal_soak(
+        backend.as_ref(),
+        0,
+        Duration::from_secs(load_secs),
+        SOAK_CRITICAL_TEMP_C,
+        &AtomicBool::new(false),
+        |_| {},
+    )
+}
+
+/// Thermal soak with an explicit backend, GPU, abort temperature and cancel
+/// flag. `on_sample` sees each reading as it is taken. Cancelling ends the
+/// run without a cooldown phase.
+pub fn run_thermal_soak(
+    backend: &dyn crate::nvml_backend::NvmlBackend,
+    gpu_index: u32,
+    load: Duration,
+    critical_temp_c: u32,
+    cancel: &AtomicBool,
+    mut on_sample: impl FnMut(&SoakSample),
+) -> NvResult<ThermalSoakReport> {
+    let gpu_name = backend.get_name(gpu_index)?;
+    let idle_temp_c = backend.get_temperature(gpu_index)?;
+
+    let (tool, mut child) = crate::overclocking::spawn_stress_load(load)?;
+    log::info!(
+        "Thermal soak on GPU {gpu_index} using {tool} for {}s",
+        load.as_secs()
+    );
+
+    let started = Instant::now();
+    let mut samples = Vec::new();
+    let mut aborted = None;
+    let mut phase = SoakPhase::Load;
+    let mut load_ended = started;
+
+    loop {
+        std::thread::sleep(Duration::from_secs(1));
+        if cancel.load(Ordering::SeqCst) {
+            aborted.get_or_insert_with(|| "cancelled".to_string());
+            break;
+        }
+
+        let temperature_c = match backend.get_temperature(gpu_index) {
+            Ok(temp) => temp,
+            Err(e) => {
+                aborted = Some(format!("GPU stopped responding: {e}"));
+                break;
+            }
+        };
+        let sample = SoakSample {
+            elapsed_secs: started.elapsed().as_secs_f64(),
+            phase,
+            temperature_c,
+            fan_percent: backend.get_fan_speed(gpu_index, 0).unwrap_or(0),
+            power_w: backend
+                .get_power_usage(gpu_index)
+                .map(|mw| mw as f32 / 1000.0)
+                .unwrap_or(0.0),
+            gpu_utilization: backend
+                .get_utilization(gpu_index)
+                .map(|(gpu, _)| gpu)
+                .unwrap_or(0),
+        };
+        on_sample(&sample);
+        samples.push(sample);
+
+        match phase {
+            SoakPhase::Load => {
+                let reason = if temperature_c >= critical_temp_c {
+                    Some(format!(
+                        "core reached {temperature_c}°C (limit {critical_temp_c}°C)"
+                    ))
+                } else if let Ok(Some(status)) = child.try_wait() {
+                    Some(format!("{tool} exited early ({status})"))
+                } else {
+                    None
+                };
+                if let Some(reason) = &reason {
+                    log::warn!("Thermal soak load stopped: {reason}");
+                }
+                if reason.is_some() || started.elapsed() >= load {
+                    aborted = reason;
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    phase = SoakPhase::Cooldown;
+                    load_ended = Instant::now();
+                }
+            }
+            SoakPhase::Cooldown => {
+                if temperature_c <= idle_temp_c + SOAK_IDLE_MARGIN_C
+                    || load_ended.elapsed() >= Duration::from_secs(SOAK_MAX_COOLDOWN_SECS)
+                {
+                    break;
+                }
+            }
+        }
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    Ok(ThermalSoakReport::from_samples(
+        gpu_index,
+        gpu_name,
+        idle_temp_c,
+        samples,
+        aborted,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANGOHUD_LOG: &str = "os,cpu,gpu,ram,kernel,driver,cpuscheduler
+Arch,Ryzen,RTX 4090,64GB,6.17,580.95,EEVDF
+fps,frametime,cpu_load,gpu_load,cpu_temp,gpu_temp,gpu_core_clock,gpu_mem_clock,gpu_vram_used,gpu_power,ram_used,swap_used,process_rss,elapsed
+100,10,20,90,60,70,2500,10000,8,300,16,0,2,0
+50,20,20,90,60,70,2500,10000,8,300,16,0,2,500000000
+200,5,20,90,60,70,2500,10000,8,300,16,0,2,1500000000
+";
+
+    fn metric(timestamp_ms: i64) -> MetricSample {
+        MetricSample {
+            timestamp_ms,
+            gpu_clock_mhz: 2500,
+            memory_clock_mhz: 10000,
+            power_w: 300.0,
+            temperature_c: 70,
+            gpu_utilization: 90,
+        }
+    }
+
+    #[test]
+    fn test_parse_mangohud_log_aligns_timestamps() {
+        let frames = parse_mangohud_log(MANGOHUD_LOG, 1_000);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].timestamp_ms, 1_000);
+        assert_eq!(frames[1].timestamp_ms, 1_500);
+        assert_eq!(frames[2].timestamp_ms, 2_500);
+        assert!(parse_mangohud_log("not a log", 0).is_empty());
+    }
+
+    #[test]
+    fn test_summarize_fps_and_correlate() {
+        let frames = parse_mangohud_log(MANGOHUD_LOG, 0);
+        let summary = summarize_fps(&frames).unwrap();
+        assert_eq!(summary.min, 50.0);
+        assert_eq!(summary.max, 200.0);
+        // Slowest 1% of 3 frames is the single 20ms frame
+        assert_eq!(summary.low_1pct, 50.0);
+
+        let samples = correlate(&[metric(1_000), metric(2_000), metric(3_000)], &frames);
+        assert_eq!(samples[0].fps, Some(75.0));
+        assert_eq!(samples[1].fps, Some(200.0));
+        assert_eq!(samples[2].fps, None);
+        assert!(summarize_fps(&[]).is_none());
+    }
+
+    #[test]
+    fn test_baseline_compare() {
+        let frames = parse_mangohud_log(MANGOHUD_LOG, 0);
+        let report = CorrelatedRunReport {
+            fps: summarize_fps(&frames),
+            samples: correlate(&[metric(1_000), metric(2_000)], &frames),
+            gpu_name: "RTX 4090".to_string(),
+            driver_version: "580.95".to_string(),
+        };
+        let stock = report.summary();
+        assert_eq!(stock.avg_gpu_clock_mhz, 2500.0);
+
+        let dir = tempfile::tempdir().unwrap();
+        save_baseline_in(dir.path(), "stock", &stock).unwrap();
+        assert!(save_baseline_in(dir.path(), "../escape", &stock).is_err());
+        let baseline = load_baseline_in(dir.path(), "stock").unwrap();
+        assert_eq!(list_baselines_in(dir.path()), vec![baseline.clone()]);
+
+        let tuned = RunSummary {
+            avg_fps: stock.avg_fps.map(|fps| fps * 1.1),
+            avg_temperature_c: 75.0,
+            ..stock.clone()
+        };
+        let comparison = compare(&tuned, &baseline);
+        assert!(comparison.warnings.is_empty());
+        let fps = &comparison.deltas[0];
+        assert_eq!(fps.metric, "Avg FPS");
+        assert!((fps.percent().unwrap() - 10.0).abs() < 0.01);
+        assert_eq!(fps.is_improvement(), Some(true));
+        let temp = comparison
+            .deltas
+            .iter()
+            .find(|d| d.metric == "Avg temp")
+            .unwrap();
+        assert_eq!(temp.is_improvement(), Some(false));
+        let clock = comparison
+            .deltas
+            .iter()
+            .find(|d| d.metric == "Avg clock")
+            .unwrap();
+        assert_eq!(clock.is_improvement(), None);
+
+        let new_driver = RunSummary {
+            driver_version: "590.10".to_string(),
+            ..tuned
+        };
+        assert_eq!(compare(&new_driver, &baseline).warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_memory_bandwidth_assessment() {
+        assert_eq!(
+            parse_bandwidth_output("warming up\nGBPS 1523.40\n"),
+            Some(1523.4)
+        );
+        assert_eq!(parse_bandwidth_output("GBPS nan-ish"), None);
+
+        let expected = crate::gpu_profiles::get_gpu_spec("NVIDIA GeForce RTX 4090")
+            .and_then(|spec| spec.memory_bandwidth_gbps());
+        assert_eq!(expected, Some(1008.0));
+
+        let healthy = MemoryBandwidthResult {
+            gpu_index: 0,
+            gpu_name: "NVIDIA GeForce RTX 4090".to_string(),
+            method: BandwidthMethod::Cuda,
+            measured_gbps: 880.0,
+            expected_gbps: expected,
+        };
+        assert!(!healthy.is_below_expected());
+
+        let slow = MemoryBandwidthResult {
+            measured_gbps: 500.0,
+            ..healthy.clone()
+        };
+        assert!(slow.is_below_expected());
+
+        // An idle-clock estimate is never reported as a fault
+        let estimate = MemoryBandwidthResult {
+            method: BandwidthMethod::ClockEstimate,
+            ..slow
+        };
+        assert!(!estimate.is_below_expected());
+    }
+
+    #[test]
+    fn test_thermal_soak_summary() {
+        let sample = |secs: f64, phase, temp| SoakSample {
+            elapsed_secs: secs,
+            phase,
+            temperature_c: temp,
+            fan_percent: 60,
+            power_w: 300.0,
+            gpu_utilization: 99,
+        };
+        let mut samples: Vec<SoakSample> = (1..=120)
+            .map(|s| sample(s as f64, SoakPhase::Load, (40 + s / 4).min(70)))
+            .collect();
+        samples.extend([
+            sample(150.0, SoakPhase::Cooldown, 55),
+            sample(200.0, SoakPhase::Cooldown, 43),
+            sample(210.0, SoakPhase::Cooldown, 42),
+        ]);
+
+        let report = ThermalSoakReport::from_samples(0, "RTX 4080".into(), 40, samples, None);
+        assert_eq!(report.load_secs, 120.0);
+        assert_eq!(report.peak_temp_c, 70);
+        // The last minute of load sat at 55-70°C
+        let steady = report.steady_state_temp_c.unwrap();
+        assert!((60.0..=70.0).contains(&steady), "steady state {steady}");
+        // 43°C is within the idle margin of 40°C
+        assert_eq!(report.time_to_idle_secs, Some(80.0));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("soak.csv");
+        report.write_csv(&path).unwrap();
+        let csv = fs::read_to_string(&path).unwrap();
+        assert!(csv.starts_with("elapsed_s,phase,temperature_c"));
+        assert!(csv.contains("200.0,cooldown,43,60,300.0,99"));
+        assert_eq!(csv.lines().count(), 124);
+
+        let cut_short = ThermalSoakReport::from_samples(0, "RTX 4080".into(), 40, Vec::new(), None);
+        assert_eq!(cut_short.steady_state_temp_c, None);
+        assert_eq!(cut_short.time_to_idle_secs, None);
+    }
+}
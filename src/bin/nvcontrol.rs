@@ -5,7 +5,16 @@
 
 #[cfg(feature = "gui")]
 fn main() -> eframe::Result<()> {
-    nvcontrol::gui::run()
+    if std::env::args().skip(1).any(|arg| arg == "--safe-mode") {
+        nvcontrol::error_recovery::set_safe_mode(true);
+    }
+    let mini = std::env::args().skip(1).any(|arg| arg == "--mini");
+    let config = nvcontrol::config::Config::load();
+    nvcontrol::logging::init(
+        nvcontrol::logging::resolve_level(nvcontrol::logging::env_level(), &config.log_level),
+        nvcontrol::logging::Console::FileOnly,
+    );
+    nvcontrol::gui::launch(mini)
 }
 
 #[cfg(not(feature = "gui"))]
@@ -3,8 +3,8 @@ use clap_complete::{Generator, Shell, generate};
 use console::{Key, Term, style};
 use indicatif::{ProgressBar, ProgressStyle};
 use nvcontrol::{
-    arch_integration, asus_power_detector, companion, config, cuda, display, drivers, fan,
-    gamescope,
+    arch_integration, asus_power_detector, companion, config, cuda, display, display_info, drivers,
+    fan, gamescope,
     gpu::{self, OutputFormat},
     gsp_firmware, hdr, kde_optimizer, latency, monitoring, multimonitor,
     notifications::NotificationManager,
@@ -24,10 +24,14 @@ struct Cli {
     #[command(subcommand)]
     command: Command,
 
-    /// Enable verbose output
+    /// Enable verbose output (same as --log-level debug)
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Log verbosity: off, error, warn, info, debug or trace
+    #[arg(long, global = true, value_name = "LEVEL")]
+    log_level: Option<log::LevelFilter>,
+
     /// Output format
     #[arg(long, global = true, value_enum)]
     format: Option<OutputFormat>,
@@ -35,6 +39,14 @@ struct Cli {
     /// Disable colored output
     #[arg(long, global = true)]
     no_color: bool,
+
+    /// Print what would change without touching the GPU
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Skip overclock, fan, RGB and container probes at startup
+    #[arg(long, global = true)]
+    safe_mode: bool,
 }
 
 fn generate_shell_completion<G: Generator>(generator: G) {
@@ -65,10 +77,12 @@ enum Command {
         subcommand: DisplaySubcommand,
     },
     /// 🌈 Digital vibrance control (0-200%)
-    #[command(alias = "vibe")]
+    #[command(alias = "vibe", args_conflicts_with_subcommands = true)]
     Vibrance {
-        /// Vibrance percentage (0-200%, where 100% is default)
-        percentage: u32,
+        #[command(subcommand)]
+        action: Option<VibranceCommand>,
+        /// Vibrance percentage (0-200%, where 100% is default) for every display
+        percentage: Option<u32>,
     },
     /// 🌀 Fan control and curves
     Fan {
@@ -80,6 +94,11 @@ enum Command {
         #[command(subcommand)]
         subcommand: OverclockSubcommand,
     },
+    /// 🌿 Automatic tuning (undervolt for efficiency)
+    AutoOc {
+        #[command(subcommand)]
+        subcommand: AutoOcSubcommand,
+    },
     /// 🔄 Variable Refresh Rate (VRR/G-Sync)
     Vrr {
         #[command(subcommand)]
@@ -87,19 +106,38 @@ enum Command {
     },
     /// 📊 Real-time monitoring
     Monitor {
+        /// Append samples to this SQLite database instead of showing live output
+        #[arg(long)]
+        log_db: Option<std::path::PathBuf>,
+        /// Seconds between logged samples (with --log-db)
+        #[arg(long, default_value = "10")]
+        interval: u64,
         #[command(subcommand)]
         subcommand: Option<MonitorSubcommand>,
     },
     /// 📺 Terminal user interface
     Tui,
+    /// 🪟 Launch the graphical interface
+    Gui {
+        /// Start as a compact always-on-top stats panel
+        #[arg(long)]
+        mini: bool,
+    },
     /// 🖥️ GPU monitor (htop-style)
     Nvtop,
     /// 🎯 Gaming optimization and latency
+    #[command(alias = "game")]
     Gaming {
         #[command(subcommand)]
         subcommand: GamingSubcommand,
     },
+    /// 🚀 Gamescope launch presets
+    Gamescope {
+        #[command(subcommand)]
+        action: GamescopeAction,
+    },
     /// 📹 Recording and streaming
+    #[command(alias = "record")]
     Recording {
         #[command(subcommand)]
         subcommand: RecordingSubcommand,
@@ -130,6 +168,49 @@ enum Command {
         #[command(subcommand)]
         subcommand: ConfigSubcommand,
     },
+    /// 💾 Save and apply named profiles (OC, power, fan curve, vibrance)
+    Profile {
+        #[command(subcommand)]
+        subcommand: ProfileSubcommand,
+    },
+    /// 🏁 Benchmark runs correlating FPS with clocks, power and temperature
+    Benchmark {
+        #[command(subcommand)]
+        subcommand: BenchmarkSubcommand,
+    },
+    /// 🔔 Threshold alerts for temperature, power and VRAM
+    Alert {
+        #[command(subcommand)]
+        subcommand: AlertSubcommand,
+    },
+    /// ⚡ Low-latency mode (scheduler, preemption, Reflex)
+    Latency {
+        #[command(subcommand)]
+        subcommand: LatencySubcommand,
+    },
+    /// 🔁 Background daemon that applies game profiles and the power schedule
+    Daemon {
+        /// Process scan interval in seconds (default: auto-profile poll interval)
+        #[arg(long)]
+        interval: Option<u64>,
+        /// Seconds a game must stay closed before settings are restored
+        #[arg(long)]
+        debounce: Option<u64>,
+    },
+    /// 🔂 Apply the last saved OC, power, fan and vibrance settings once and exit
+    ApplySaved {
+        /// Login mode: wait `startup_apply.delay_secs`, require an NVML read,
+        /// and skip if the previous startup apply crashed
+        #[arg(long)]
+        startup: bool,
+    },
+    /// 🧯 Reset clocks, power, fans, vibrance and RGB to stock
+    Reset,
+    /// 🤫 Quiet mode: cap fans, power and optionally clocks until turned off
+    Quiet {
+        #[command(subcommand)]
+        subcommand: QuietSubcommand,
+    },
     /// 📈 AI upscaling and enhancement
     Upscaling {
         #[command(subcommand)]
@@ -215,10 +296,31 @@ enum Command {
         )]
         output: String,
     },
-    /// 🧭 Guided first-run setup and readiness checks
+    /// 📝 Markdown diagnostics report for bug reports (hostname and user redacted)
+    Report {
+        /// Copy the report to the clipboard
+        #[arg(long)]
+        copy: bool,
+        /// Write the report to a file
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+    /// 🌐 Serve GPU status as a JSON API for remote monitoring
+    Serve {
+        /// Address to listen on; use 0.0.0.0:8787 to accept other hosts
+        #[arg(long, default_value = nvcontrol::api_server::DEFAULT_BIND)]
+        bind: String,
+        /// Accept fan and power-limit requests (needs `api_token` in config)
+        #[arg(long)]
+        allow_control: bool,
+        /// Seconds between NVML samples
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
+    /// 🧭 Guided first-run setup and readiness checks (runs the wizard by default)
     Setup {
         #[command(subcommand)]
-        subcommand: SetupSubcommand,
+        subcommand: Option<SetupSubcommand>,
     },
     /// 🧩 Generate shell completions
     Completion {
@@ -242,6 +344,12 @@ enum Command {
 
 #[derive(Subcommand)]
 enum SetupSubcommand {
+    /// Walk through driver, modeset, permissions, compositor and ReBAR checks and offer fixes
+    Wizard {
+        /// Only report; don't offer to run fixes
+        #[arg(long)]
+        check_only: bool,
+    },
     /// Check driver, compositor, permissions, services, and helper tools
     Check {
         /// Output format: json, yaml, table
@@ -256,12 +364,31 @@ enum SetupSubcommand {
     Remove,
 }
 
+#[derive(Subcommand)]
+enum QuietSubcommand {
+    /// Cap fans and power (limits from `quiet_mode` in the config)
+    On,
+    /// Restore the fan curves, power limits and clock locks from before
+    Off,
+    /// Show whether quiet mode is on and its limits
+    Status,
+}
+
 #[derive(Subcommand)]
 enum CompanionSubcommand {
     /// Send a desktop notification test
     NotifyTest,
     /// Open the project documentation in the default desktop handler
     OpenDocs,
+    /// Show a tray icon with the GPU temperature and quick actions
+    Tray {
+        /// GPU whose temperature the icon shows
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        /// Seconds between temperature updates
+        #[arg(long, default_value = "2")]
+        interval: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -311,8 +438,16 @@ enum GpuSubcommand {
         #[arg(short, long, value_enum, default_value = "table")]
         format: OutputFormat,
     },
-    /// Launch live TUI dashboard for GPU monitoring
-    Stat,
+    /// Launch live TUI dashboard, or print one status line with --template
+    Stat {
+        /// One-line template, e.g. '{temp}°C {util}% {power}W' (see docs for
+        /// placeholders). `--format` is the global json/yaml/table switch.
+        #[arg(short, long)]
+        template: Option<String>,
+        /// GPU index for --template
+        #[arg(long, default_value = "0", requires = "template")]
+        gpu: u32,
+    },
     /// Show detailed GPU overclocking capabilities
     Capabilities,
     /// List all detected GPUs with details
@@ -368,12 +503,121 @@ enum GpuSubcommand {
         #[arg(short, long)]
         log: bool,
     },
+    /// Vulkan devices and GL renderer, and how to target NVIDIA under PRIME offload
+    Renderer,
+    /// PCIe link speed/width and Resizable BAR status
+    Pcie {
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+    },
+    /// Persistence mode: keep the driver loaded with no clients (requires root)
+    Persistence {
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        #[command(subcommand)]
+        action: PersistenceAction,
+    },
+    /// Lock GPU and memory clocks to fixed values for repeatable benchmarks (requires root)
+    LockClocks {
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        index: u32,
+        /// Core clock to lock in MHz
+        #[arg(long, conflicts_with = "reset")]
+        gpu: Option<u32>,
+        /// Memory clock to lock in MHz (Ampere or newer)
+        #[arg(long, conflicts_with = "reset")]
+        mem: Option<u32>,
+        /// Release the locks and return to normal boost behaviour
+        #[arg(long)]
+        reset: bool,
+    },
+    /// Reset a hung GPU with nvidia-smi (last resort, requires root)
+    Reset {
+        /// GPU index
+        index: u32,
+        /// Terminate processes still using the GPU without asking
+        #[arg(long)]
+        kill: bool,
+        /// Skip the confirmation prompt
+        #[arg(long, short = 'y')]
+        yes: bool,
+        /// Reset even when the driver can't tell whether the GPU drives a display
+        #[arg(long)]
+        force: bool,
+    },
+    /// ECC memory mode (workstation/data-center GPUs)
+    Ecc {
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        #[command(subcommand)]
+        action: EccAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum PersistenceAction {
+    /// Keep the driver loaded when no client is using the GPU
+    On,
+    /// Let the driver unload when idle
+    Off,
+    /// Show persistence mode and the nvidia-persistenced daemon state
+    Status,
+}
+
+#[derive(Subcommand)]
+enum EccAction {
+    /// Enable ECC after the next GPU reset or reboot
+    On,
+    /// Disable ECC after the next GPU reset or reboot
+    Off,
+    /// Show current and pending ECC mode
+    Status,
 }
 
 #[derive(Subcommand)]
 enum DisplaySubcommand {
     Info,
     Ls,
+    /// List connected displays with modes, HDR, color depth and VRR range
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Change a display's resolution and refresh rate, e.g. DP-2 2560x1440@165
+    SetMode {
+        /// Connector name
+        connector: String,
+        /// WIDTHxHEIGHT[@HZ]; without a rate the highest one is used
+        mode: String,
+        /// Also save the mode in the connector's profile
+        #[arg(long)]
+        save: bool,
+    },
+    /// Rotate a display, e.g. DP-1 left
+    Rotate {
+        /// Connector name
+        connector: String,
+        /// normal, left, right or inverted
+        orientation: String,
+        /// Also save the rotation in the connector's profile
+        #[arg(long)]
+        save: bool,
+    },
+    /// Scale a display, e.g. DP-1 1.5 (0.5-3.0 in steps of 0.05)
+    Scale {
+        /// Connector name
+        connector: String,
+        /// Scale factor
+        factor: f32,
+        /// Also save the scale in the connector's profile
+        #[arg(long)]
+        save: bool,
+    },
     Vibrance {
         #[command(subcommand)]
         subcommand: VibranceSubcommand,
@@ -405,6 +649,44 @@ enum DisplaySubcommand {
         #[command(subcommand)]
         subcommand: DitheringSubcommand,
     },
+    /// Per-monitor profiles keyed by connector name or EDID serial
+    Connector {
+        #[command(subcommand)]
+        subcommand: ConnectorSubcommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConnectorSubcommand {
+    /// List connected outputs and saved connector profiles
+    List,
+    /// Save a profile for a connector (e.g. DP-2)
+    Set {
+        /// Connector name
+        connector: String,
+        /// Vibrance percentage (0-200)
+        #[arg(long)]
+        vibrance: u32,
+        /// Resolution, e.g. 2560x1440 (requires --refresh)
+        #[arg(long, requires = "refresh")]
+        mode: Option<String>,
+        /// Refresh rate in Hz
+        #[arg(long, requires = "mode")]
+        refresh: Option<u32>,
+        /// Key the profile on the monitor's EDID serial instead of the port
+        #[arg(long)]
+        edid: bool,
+    },
+    /// Remove a saved profile (connector name or edid:<serial>)
+    Remove { key: String },
+    /// Apply saved profiles to connected outputs
+    Apply,
+    /// Re-apply profiles whenever a known monitor is reconnected
+    Watch {
+        /// Poll interval in seconds when no hotplug events are available
+        #[arg(long, default_value_t = 2)]
+        interval: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -589,7 +871,16 @@ enum FanSubcommand {
         /// Fan speed percentage (0-100)
         percent: u8,
     },
-    /// Reset a fan to automatic driver control
+    /// Enable manual fan control, then set a fixed speed
+    Manual {
+        /// Fan speed percentage (0-100)
+        #[arg(value_parser = clap::value_parser!(u8).range(0..=100))]
+        percent: u8,
+        /// Fan ID (0, 1, 2, etc.)
+        #[arg(long = "fan", default_value = "0")]
+        fan_id: usize,
+    },
+    /// Hand a fan back to the driver/VBIOS automatic curve
     Auto {
         /// Fan ID (0, 1, 2, etc.)
         #[arg(default_value = "0")]
@@ -600,6 +891,32 @@ enum FanSubcommand {
         #[command(subcommand)]
         action: FanCurveSubcommand,
     },
+    /// Ramp fans from 0 to 100% and report the RPM each step settles at
+    Test {
+        /// Duty-cycle increment per step in percent
+        #[arg(long, default_value = "10")]
+        step: u8,
+        /// Maximum seconds to wait for RPM to settle at each step
+        #[arg(long, default_value = "8")]
+        settle: u64,
+    },
+    /// Keep the saved fan curves applied (run by the systemd unit)
+    Daemon {
+        /// Curve file to follow (defaults to ~/.config/nvcontrol/active_fan_curves.json)
+        #[arg(long)]
+        config: Option<std::path::PathBuf>,
+    },
+    /// Install and start a systemd unit that runs `nvctl fan daemon`
+    InstallService {
+        /// Force a system unit (root) instead of auto-detecting
+        #[arg(long, conflicts_with = "user")]
+        system: bool,
+        /// Force a user unit (nvidia-settings in the graphical session)
+        #[arg(long)]
+        user: bool,
+    },
+    /// Stop and remove the fan daemon unit
+    UninstallService,
 }
 
 #[derive(Subcommand)]
@@ -613,6 +930,9 @@ enum FanCurveSubcommand {
         /// Fan ID (0, 1, 2, etc.)
         #[arg(long, default_value = "0")]
         fan_id: usize,
+        /// Apply to every fan on every GPU
+        #[arg(long, conflicts_with = "fan_id")]
+        all: bool,
     },
     /// Apply an inline curve, for example "30:20,60:50,75:80,85:100"
     Set {
@@ -621,7 +941,42 @@ enum FanCurveSubcommand {
         /// Fan ID (0, 1, 2, etc.)
         #[arg(long, default_value = "0")]
         fan_id: usize,
+        /// Apply to every fan on every GPU
+        #[arg(long, conflicts_with = "fan_id")]
+        all: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum AutoOcSubcommand {
+    /// Find the lowest stable voltage at stock clocks and save it as a profile
+    Efficiency {
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        /// Profile name to save the result as
+        #[arg(long, default_value = "efficiency")]
+        name: String,
+        /// Core offset step in MHz (each step lowers voltage at stock clocks)
+        #[arg(long, default_value = "15")]
+        step: i32,
+        /// Safety margin subtracted from the highest stable offset, in MHz
+        #[arg(long, default_value = "15")]
+        margin: i32,
+        /// Highest core offset to try, in MHz
+        #[arg(long, default_value = "250")]
+        max_offset: i32,
+        /// Stress test duration per step in seconds
+        #[arg(long, default_value = "60")]
+        duration: u64,
+    },
+    /// Apply a saved efficiency profile
+    Apply {
+        /// Profile name
+        name: String,
     },
+    /// List saved efficiency profiles
+    List,
 }
 
 #[derive(Subcommand)]
@@ -637,11 +992,34 @@ enum OverclockSubcommand {
         /// Power limit percentage (50-120)
         #[arg(long)]
         power_limit: Option<u8>,
+        /// Ramp the GPU offset up in steps, probing stability between each
+        #[arg(long)]
+        staged: bool,
+        /// Step size in MHz for --staged
+        #[arg(long, default_value_t = overclocking::DEFAULT_STAGED_STEP_MHZ, value_parser = clap::value_parser!(u32).range(1..), requires = "staged")]
+        step: u32,
+        /// Seconds of stability probing after each step
+        #[arg(long, default_value = "10", requires = "staged")]
+        probe_secs: u64,
+        /// Apply the offsets to every GPU
+        #[arg(long, conflicts_with_all = ["staged", "gpu"])]
+        all: bool,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
     },
     Profile {
         /// Profile name to apply
         name: String,
     },
+    /// Apply a clock offset preset scaled to this card (lists them without a name)
+    Preset {
+        /// stock, mild, performance or extreme
+        name: Option<String>,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+    },
     StressTest {
         /// Duration in minutes
         #[arg(default_value = "5")]
@@ -666,6 +1044,147 @@ enum OverclockSubcommand {
         stability_duration: u64,
     },
     Reset,
+    /// Revert to the previously applied overclock (stock if none)
+    Revert,
+}
+
+#[derive(Subcommand)]
+enum BenchmarkSubcommand {
+    /// Run a game under MangoHud (or the GPU-only workload) and record metrics
+    Run {
+        /// Run duration in seconds
+        #[arg(short, long, default_value_t = 60)]
+        duration: u64,
+        /// CSV file for the timestamp-aligned samples
+        #[arg(short, long, default_value = "benchmark.csv")]
+        output: std::path::PathBuf,
+        /// Save this run as a named baseline to compare later runs against
+        #[arg(long, value_name = "NAME")]
+        save_baseline: Option<String>,
+        /// Game command to launch (after --); omit for the built-in workload
+        #[arg(last = true)]
+        command: Vec<String>,
+    },
+    /// Compare the latest run against a saved baseline
+    Compare {
+        /// Baseline name
+        baseline: String,
+        /// Compare another baseline instead of the latest run
+        #[arg(long, value_name = "NAME")]
+        run: Option<String>,
+    },
+    /// List saved baselines
+    Baselines,
+    /// Measure VRAM bandwidth and compare it with the card's published figure
+    Memory {
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+    },
+    /// Hold a full load, then record the cooldown curve back to idle
+    Soak {
+        /// Seconds of sustained load
+        #[arg(long, default_value_t = 600)]
+        load: u64,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        /// Stop the load once the core reaches this temperature (°C)
+        #[arg(long, default_value_t = nvcontrol::benchmark::SOAK_CRITICAL_TEMP_C)]
+        critical_temp: u32,
+        /// CSV file for the temperature curve (default: benchmark results directory)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AlertSubcommand {
+    /// Add a rule, e.g. `nvctl alert add temperature --above 85`
+    Add {
+        /// Metric to watch
+        #[arg(value_enum)]
+        metric: nvcontrol::monitoring::AlertMetric,
+        /// Fire when the metric rises above this value
+        #[arg(long, conflicts_with = "below", required_unless_present = "below")]
+        above: Option<f64>,
+        /// Fire when the metric drops below this value
+        #[arg(long)]
+        below: Option<f64>,
+        /// Minimum seconds between notifications
+        #[arg(long, default_value_t = 300)]
+        cooldown: u64,
+        /// How far back past the threshold before the rule re-arms
+        #[arg(long, default_value_t = 3.0)]
+        hysteresis: f64,
+        /// Only watch this GPU (default: all)
+        #[arg(long)]
+        gpu: Option<u32>,
+        /// Shell command to run when the rule fires
+        #[arg(long)]
+        command: Option<String>,
+    },
+    /// List configured rules
+    List,
+    /// Remove a rule by its number in `nvctl alert list`
+    Remove {
+        /// Rule number
+        number: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum LatencySubcommand {
+    /// Apply a latency mode: competitive, balanced, default (or power-saver)
+    Set {
+        /// Latency mode
+        mode: String,
+        /// GPU index (DRM card) for the framebuffer compression toggle
+        #[arg(long, default_value_t = 0)]
+        gpu: u32,
+    },
+    /// Show scheduler, Reflex availability and estimated input lag
+    Status,
+}
+
+#[derive(Subcommand)]
+enum ProfileSubcommand {
+    /// List saved profiles
+    List,
+    /// Apply a saved profile by name
+    Apply {
+        /// Profile name
+        name: String,
+        /// List what would change, with risk levels, and ask before applying
+        #[arg(long)]
+        show_diff: bool,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+    },
+    /// Save the current OC offsets, power limit, fan curve, and vibrance
+    Save {
+        /// Profile name
+        name: String,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+    },
+    /// Write a saved profile to a file to share (TOML for .toml, JSON otherwise)
+    Export {
+        /// Profile name
+        name: String,
+        /// Output file
+        path: String,
+    },
+    /// Add a shared profile file, checking it against this GPU first
+    Import {
+        /// Profile file (.json or .toml)
+        path: String,
+        /// Replace a saved profile with the same name
+        #[arg(long)]
+        overwrite: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -814,6 +1333,13 @@ enum DlssSubcommand {
         /// Steam App ID or game name
         game: String,
     },
+    /// Force a DLSS render preset for a game launch profile
+    Preset {
+        /// Game launch profile name (see `nvctl gaming launch list`)
+        profile: String,
+        /// Preset: default, A-F, J, K or latest. Omit to show the current one
+        preset: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -826,6 +1352,15 @@ enum ShadersSubcommand {
         #[arg(long, default_value = "all")]
         cache_type: String,
     },
+    /// Show cache sizes and clear them after confirmation
+    Clean {
+        /// Only clear the Steam cache of games matching this name or app id
+        #[arg(long)]
+        game: Option<String>,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
     /// Optimize shader compilation settings
     Optimize,
     /// Precompile shaders for a game
@@ -877,14 +1412,16 @@ enum PassthroughSubcommand {
 
 #[derive(Subcommand)]
 enum WaylandSubcommand {
-    /// Show Wayland NVIDIA configuration status
+    /// Show compositor, modeset, GSP state and applicable optimizations
     Status,
-    /// Apply optimal Wayland configuration
+    /// Apply recommended environment and compositor tweaks for this session
     Optimize {
-        /// Create backup before applying
-        #[arg(long, default_value = "true")]
-        backup: bool,
+        /// Write the reboot-required kernel module options instead (requires root)
+        #[arg(long)]
+        kernel: bool,
     },
+    /// Undo every change made by `wayland optimize`
+    Revert,
     /// Generate environment variables script
     ExportEnv {
         /// Shell config file path
@@ -1264,11 +1801,26 @@ enum SystemSubcommand {
 enum PowerSubcommand {
     /// Show current power settings
     Status,
-    /// Set GPU power limit (percentage)
+    /// Show or set the GPU power limit
+    #[command(args_conflicts_with_subcommands = true)]
     Limit {
-        /// Power limit percentage (50-120)
+        #[command(subcommand)]
+        action: Option<PowerLimitAction>,
+        /// Power limit percentage (50-120) for every GPU
         #[arg(short, long)]
-        percentage: u32,
+        percentage: Option<u32>,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+    },
+    /// Show or set the driver PowerMizer mode (adaptive, max-performance, auto)
+    Mode {
+        /// Mode to set; omit to show the current mode
+        #[arg(value_enum)]
+        mode: Option<power::PowerMizerMode>,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
     },
     /// Configure power profile
     Profile {
@@ -1302,6 +1854,43 @@ enum PowerSubcommand {
     },
 }
 
+#[derive(Subcommand)]
+enum VibranceCommand {
+    /// Set vibrance on one display or all of them
+    Set {
+        /// Vibrance percentage (0-200, where 100 is default)
+        percentage: u32,
+        /// Display index, or "all"
+        #[arg(short, long, default_value = "all")]
+        display: nvcontrol::vibrance::DisplayTarget,
+    },
+    /// Show the current vibrance of each display
+    Get,
+    /// Apply a named preset (default, vibrant, competitive, max, muted, grayscale)
+    Preset {
+        /// Preset name
+        name: String,
+        /// Display index, or "all"
+        #[arg(short, long, default_value = "all")]
+        display: nvcontrol::vibrance::DisplayTarget,
+    },
+}
+
+#[derive(Subcommand)]
+enum PowerLimitAction {
+    /// Set the power limit in watts (320W) or percent of the default (90%)
+    Set {
+        /// Target limit, e.g. 320W or 90%
+        value: power::PowerLimitTarget,
+        /// GPU index
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        /// Set the limit on every GPU
+        #[arg(long, conflicts_with = "gpu")]
+        all: bool,
+    },
+}
+
 #[derive(Subcommand)]
 enum PowerCurveAction {
     /// Show current power curve
@@ -1334,15 +1923,21 @@ enum PowerScheduleAction {
     List,
     /// Add a scheduled power profile
     Add {
-        /// Hour (0-23)
+        /// Start hour (0-23)
         #[arg(long)]
-        hour: u8,
-        /// Weekdays (comma-separated): mon,tue,wed,thu,fri,sat,sun or "all"
+        start: u8,
+        /// End hour (0-23, exclusive); an end before the start wraps past midnight
+        #[arg(long)]
+        end: u8,
+        /// Weekdays (comma-separated): mon,tue,wed,thu,fri,sat,sun, "weekdays", "weekends" or "all"
         #[arg(long, default_value = "all")]
         days: String,
         /// Power limit percentage
         #[arg(long)]
-        power: u8,
+        power: u32,
+        /// Name shown in listings
+        #[arg(long)]
+        name: Option<String>,
     },
     /// Remove a schedule
     Remove {
@@ -1497,6 +2092,9 @@ enum ConfigSubcommand {
         /// Input file path, saved profile name, or `live`
         #[arg(short, long)]
         input: String,
+        /// List what would change, with risk levels, and ask before applying
+        #[arg(long)]
+        show_diff: bool,
     },
     /// List available profiles
     Profiles,
@@ -1527,6 +2125,15 @@ enum MonitorSubcommand {
         #[arg(short, long, default_value = "60")]
         duration: u64,
     },
+    /// Print daily min/avg/max from a sample database written with --log-db
+    Query {
+        /// How far back to look, e.g. 24h, 7d, 4w
+        #[arg(long, default_value = "7d")]
+        since: String,
+        /// Sample database (default: ~/.local/share/nvcontrol/samples.db)
+        #[arg(long)]
+        db: Option<std::path::PathBuf>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1557,6 +2164,11 @@ enum GamingSubcommand {
         #[command(subcommand)]
         action: GameAutoAction,
     },
+    /// Print Steam launch options that inject a profile's env vars
+    Wrapper {
+        /// Profile name
+        profile: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1593,6 +2205,9 @@ enum GameAutoAction {
         /// Restore default profile on game exit
         #[arg(long)]
         restore_on_exit: Option<bool>,
+        /// Seconds a game must stay closed before settings are restored
+        #[arg(long)]
+        relaunch_debounce: Option<u64>,
     },
 }
 
@@ -1651,6 +2266,20 @@ enum LaunchAction {
         /// Hook index from `hook-list`
         index: usize,
     },
+    /// Set an environment variable injected at launch
+    EnvSet {
+        /// Profile name
+        profile: String,
+        /// Variable as KEY=VALUE, e.g. MANGOHUD=1
+        assignment: String,
+    },
+    /// Remove an environment variable from a profile
+    EnvUnset {
+        /// Profile name
+        profile: String,
+        /// Variable name
+        key: String,
+    },
     /// Set a named gamescope preset on a profile
     SetGamescopePreset {
         /// Profile name
@@ -1679,10 +2308,12 @@ enum LatencyAction {
 #[derive(Subcommand)]
 enum GamescopeAction {
     /// Launch application with Gamescope
+    ///
+    /// As a Steam launch option: nvctl gamescope launch --preset handheld -- %command%
     Launch {
-        /// Command to run
+        /// Command to run (alternative to passing it after `--`)
         #[arg(short, long)]
-        command: String,
+        command: Option<String>,
         /// Preset to use
         #[arg(short, long)]
         preset: Option<String>,
@@ -1692,14 +2323,38 @@ enum GamescopeAction {
         /// Window height
         #[arg(long)]
         height: Option<u32>,
+        /// Command and arguments to run, e.g. `-- %command%`
+        #[arg(last = true)]
+        args: Vec<String>,
     },
     /// List available presets
     Presets,
-    /// Create new preset
+    /// Save a launch preset for `launch --preset`
     CreatePreset {
         /// Preset name
         #[arg(short, long)]
         name: String,
+        /// Output width
+        #[arg(long)]
+        width: u32,
+        /// Output height
+        #[arg(long)]
+        height: u32,
+        /// Refresh rate in Hz
+        #[arg(long)]
+        refresh: Option<u32>,
+        /// Upscaler: fsr, nis or native
+        #[arg(long, default_value = "fsr")]
+        upscaler: String,
+        /// Upscaler quality: ultra-performance, performance, balanced, quality, ultra-quality
+        #[arg(long)]
+        quality: Option<String>,
+        /// Enable HDR output
+        #[arg(long)]
+        hdr: bool,
+        /// Extra gamescope flags, e.g. --flags="-f --adaptive-sync"
+        #[arg(long, allow_hyphen_values = true)]
+        flags: Option<String>,
     },
 }
 
@@ -1731,6 +2386,18 @@ enum RecordingSubcommand {
     Save,
     /// List available presets
     Presets,
+    /// Capture per-frame frame times from a game running under MangoHud
+    Frametimes {
+        /// Game process ID (defaults to the first process with MangoHud loaded)
+        #[arg(long)]
+        pid: Option<u32>,
+        /// Capture length in seconds
+        #[arg(short, long, default_value = "30")]
+        duration: u64,
+        /// CSV output path
+        #[arg(short, long)]
+        output: Option<String>,
+    },
 }
 
 // NOTE: BoltSubcommand and NvbindSubcommand removed - experimental features
@@ -1872,6 +2539,12 @@ enum AsusSubcommand {
     },
     /// Show ASUS GPU Tweak-style status
     Status,
+    /// One-click OC scan; uses the higher ASUS power ceiling on ROG boards
+    OcScan {
+        /// GPU index
+        #[arg(short, long, default_value = "0")]
+        gpu: u32,
+    },
     /// ASUS Aura RGB control
     Aura {
         #[command(subcommand)]
@@ -1901,6 +2574,157 @@ enum AsusAuraAction {
     },
     /// Restore saved Aura configuration from config file
     Restore,
+    /// Drive every RGB zone (Aura motherboard/RAM, OpenRGB, asusctl) from GPU temperature
+    Sync {
+        /// GPU whose temperature drives the colors
+        #[arg(long, default_value = "0")]
+        gpu: u32,
+        /// Hex color at or below --cold-temp
+        #[arg(long, default_value = "0096FF")]
+        cold: String,
+        /// Hex color at or above --hot-temp
+        #[arg(long, default_value = "FF0000")]
+        hot: String,
+        /// Temperature (°C) where the gradient starts
+        #[arg(long, default_value = "40")]
+        cold_temp: u32,
+        /// Temperature (°C) where the gradient ends
+        #[arg(long, default_value = "85")]
+        hot_temp: u32,
+        /// Seconds between temperature polls
+        #[arg(long, default_value = "2")]
+        interval: u64,
+    },
+}
+
+/// Guarded login apply shared by `apply-saved --startup` and the daemon.
+/// `None` when nothing was applied.
+fn startup_apply_cli(
+    backend: &nvcontrol::nvml_backend::SharedNvmlBackend,
+    delay_secs: u64,
+) -> Option<Vec<nvcontrol::state::ApplyOutcome>> {
+    use nvcontrol::state::StartupApply;
+
+    println!(
+        "🔂 Applying saved settings in {}s (startup safety delay)",
+        delay_secs
+    );
+    match nvcontrol::state::apply_at_startup(backend, delay_secs) {
+        StartupApply::Applied(outcomes) => Some(outcomes),
+        StartupApply::SkippedAfterCrash => {
+            println!("⚠️  Skipped: the previous startup apply never finished");
+            println!("   Run `nvctl apply-saved` once the settings are known to be safe");
+            None
+        }
+        StartupApply::NvmlUnavailable(e) => {
+            eprintln!("⚠️  Skipped: NVML could not be read after the delay: {}", e);
+            None
+        }
+    }
+}
+
+fn print_renderer_report(report: &nvcontrol::renderer::RendererReport) {
+    use nvcontrol::renderer::{GlRenderer, RendererReport};
+
+    let gl_line = |gl: &Option<GlRenderer>| match gl {
+        Some(gl) => format!("{} ({})", gl.renderer, gl.vendor),
+        None => "unavailable (glxinfo missing or no display)".to_string(),
+    };
+
+    println!("🖥️  Renderers");
+    println!("   OpenGL default:   {}", gl_line(&report.gl_default));
+    println!("   OpenGL offload:   {}", gl_line(&report.gl_offload));
+    if report.vulkan_devices.is_empty() {
+        println!("   Vulkan devices:   unavailable (vulkaninfo missing)");
+    } else {
+        println!("   Vulkan devices:");
+        for (i, device) in report.vulkan_devices.iter().enumerate() {
+            println!(
+                "     GPU{}  {} ({}{})",
+                i,
+                device.name,
+                device.device_type,
+                device
+                    .driver_name
+                    .as_ref()
+                    .map(|driver| format!(", {}", driver))
+                    .unwrap_or_default()
+            );
+        }
+    }
+
+    println!();
+    match report.default_renderer() {
+        Some(name) => println!("   Default renderer: {}", name),
+        None => println!("   Default renderer: unknown"),
+    }
+    match report.nvidia_is_default() {
+        Some(true) => println!("   ✅ Apps render on the NVIDIA GPU by default"),
+        Some(false) => {
+            println!("   ℹ️  Apps render on another GPU by default; to use NVIDIA run:");
+            println!("      {} <command>", RendererReport::offload_prefix());
+            println!(
+                "   Steam launch options: {} %command%",
+                RendererReport::offload_prefix()
+            );
+        }
+        None => {}
+    }
+}
+
+/// One summary line per subsystem for `apply-saved` and `reset`
+fn print_apply_outcomes(outcomes: &[nvcontrol::state::ApplyOutcome], skipped: &str) {
+    use nvcontrol::state::ApplyStatus;
+
+    for outcome in outcomes {
+        match &outcome.status {
+            ApplyStatus::Applied(detail) => println!("   ✅ {}: {}", outcome.subsystem, detail),
+            ApplyStatus::Skipped => println!("   ⏭️  {}: {}", outcome.subsystem, skipped),
+            ApplyStatus::Failed(e) => {
+                let level = if outcome.critical { "❌" } else { "⚠️ " };
+                println!("   {} {}: {}", level, outcome.subsystem, e)
+            }
+        }
+    }
+}
+
+fn apply_vibrance_cli(target: nvcontrol::vibrance::DisplayTarget, percentage: u32) {
+    use nvcontrol::vibrance::DisplayTarget;
+
+    let percentage = percentage.min(200);
+    let scope = match target {
+        DisplayTarget::All => "all displays".to_string(),
+        DisplayTarget::Index(index) => format!("display {}", index),
+    };
+    match nvcontrol::vibrance::set_vibrance_percent(target, percentage) {
+        Ok(backend) => {
+            println!(
+                "✅ Set {} to {}% vibrance (via {})",
+                scope,
+                percentage,
+                backend.label()
+            );
+            if percentage == 100 {
+                println!("   🎨 Default vibrance restored");
+            } else if percentage > 100 {
+                println!("   🌈 Enhanced colors active (+{}%)", percentage - 100);
+            } else {
+                println!("   🎭 Reduced saturation (-{}%)", 100 - percentage);
+            }
+        }
+        Err(e) => {
+            eprintln!("❌ Vibrance failed: {}", e);
+            print_vibrance_permission_hints();
+            std::process::exit(1);
+        }
+    }
+}
+
+fn print_vibrance_permission_hints() {
+    eprintln!("💡 Vibrance needs access to /dev/nvidia-modeset:");
+    eprintln!("  → Add yourself to the video group: sudo usermod -aG video $USER");
+    eprintln!("  → Enable kernel modesetting: nvidia_drm.modeset=1");
+    eprintln!("  → Or install nvibrant as a fallback backend");
 }
 
 fn main() {
@@ -1908,36 +2732,94 @@ fn main() {
     let backend = nvcontrol::nvml_backend::create_real_backend();
 
     let cli = Cli::parse();
+    let config = nvcontrol::config::Config::load();
+    let log_flag = cli
+        .log_level
+        .or(cli.verbose.then_some(log::LevelFilter::Debug))
+        .or_else(nvcontrol::logging::env_level);
+    nvcontrol::logging::init(
+        nvcontrol::logging::resolve_level(log_flag, &config.log_level),
+        nvcontrol::logging::Console::Echo,
+    );
+    nvcontrol::gpu_safe::set_dry_run(cli.dry_run || config.dry_run);
+    if cli.safe_mode {
+        nvcontrol::error_recovery::set_safe_mode(true);
+    }
     match cli.command {
-        Command::Vibrance { percentage } => {
-            use nvcontrol::vibrance_native;
-
-            // Simple vibrance command - just works!
-            match vibrance_native::set_vibrance_all_native(percentage) {
-                Ok(()) => {
-                    println!("✅ Set all displays to {}% vibrance", percentage);
-                    if percentage == 100 {
-                        println!("   🎨 Default vibrance restored");
-                    } else if percentage > 100 {
-                        println!("   🌈 Enhanced colors active (+{}%)", percentage - 100);
-                    } else {
-                        println!("   🎭 Reduced saturation (-{}%)", 100 - percentage);
+        Command::Vibrance { action, percentage } => match (action, percentage) {
+            (
+                Some(VibranceCommand::Set {
+                    percentage,
+                    display,
+                }),
+                _,
+            ) => apply_vibrance_cli(display, percentage),
+            (Some(VibranceCommand::Preset { name, display }), _) => {
+                match nvcontrol::vibrance::preset_percentage(&name) {
+                    Some(percentage) => {
+                        println!("🎨 Preset '{}' ({}%)", name, percentage);
+                        apply_vibrance_cli(display, percentage);
+                    }
+                    None => {
+                        eprintln!("❌ Unknown vibrance preset '{}'", name);
+                        eprintln!("Available presets:");
+                        for (preset, percent, desc) in nvcontrol::vibrance::VIBRANCE_PRESETS {
+                            eprintln!("  {:<12} {:>3}%  {}", preset, percent, desc);
+                        }
+                        std::process::exit(1);
+                    }
+                }
+            }
+            (Some(VibranceCommand::Get), _) => match nvcontrol::vibrance::get_vibrance_percent() {
+                Ok((backend, displays)) => {
+                    println!("🌈 Digital vibrance (via {})", backend.label());
+                    if displays.is_empty() {
+                        println!("   No connected displays found");
+                    }
+                    for (index, name, percent) in displays {
+                        println!("   [{}] {}: {}%", index, name, percent);
                     }
                 }
                 Err(e) => {
-                    eprintln!("❌ Vibrance failed: {}", e);
-                    eprintln!("  → Try: nvidia-settings -q all | grep -i vibrance");
-                    eprintln!("💡 Ensure NVIDIA open drivers 610+ with nvidia_drm.modeset=1");
+                    eprintln!("❌ Could not read vibrance: {}", e);
+                    print_vibrance_permission_hints();
+                    std::process::exit(1);
                 }
+            },
+            (None, Some(percentage)) => {
+                apply_vibrance_cli(nvcontrol::vibrance::DisplayTarget::All, percentage)
             }
-        }
+            (None, None) => {
+                eprintln!("❌ Give a percentage or a subcommand, e.g. nvctl vibrance set 150");
+                std::process::exit(1);
+            }
+        },
         Command::Gpu { subcommand } => match subcommand {
             GpuSubcommand::Info { format } => {
                 if let Err(e) = gpu::get_gpu_info_with_format(format, &backend) {
                     eprintln!("❌ Failed to get GPU info: {}", e);
                 }
             }
-            GpuSubcommand::Stat => {
+            GpuSubcommand::Stat {
+                template: Some(template),
+                gpu,
+            } => {
+                let line = monitoring::validate_status_template(&template)
+                    .and_then(|()| backend.get_device_info(gpu))
+                    .and_then(|info| {
+                        let metrics = backend.get_metrics(gpu)?;
+                        let power_limit = backend.get_power_limit(gpu).ok();
+                        monitoring::format_status_line(&template, &info, &metrics, power_limit)
+                    });
+                match line {
+                    Ok(line) => println!("{}", line),
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            GpuSubcommand::Stat { template: None, .. } => {
                 if let Err(e) = nvcontrol::tui::launch_dashboard() {
                     eprintln!("TUI error: {}", e);
                 }
@@ -1985,67 +2867,291 @@ fn main() {
                     Err(e) => eprintln!("❌ Stress test failed: {}", e),
                 }
             }
-            GpuSubcommand::List { format } => match nvcontrol::multi_gpu::detect_gpus() {
-                Ok(gpus) => match format {
-                    OutputFormat::Json => {
-                        println!("{}", serde_json::to_string_pretty(&gpus).unwrap());
+            GpuSubcommand::List { format } => {
+                match nvcontrol::multi_gpu::detect_gpus(&config.gpu_labels) {
+                    Ok(gpus) => match format {
+                        OutputFormat::Json => {
+                            println!("{}", serde_json::to_string_pretty(&gpus).unwrap());
+                        }
+                        OutputFormat::Yaml => {
+                            println!("{}", serde_yaml::to_string(&gpus).unwrap());
+                        }
+                        OutputFormat::Table => {
+                            println!(
+                                "\n┌─────────────────────────────────────────────────────────────────┐"
+                            );
+                            println!(
+                                "│                       Detected GPUs                             │"
+                            );
+                            println!(
+                                "├──────┬─────────────────────┬──────────┬────────────┬───────────┤"
+                            );
+                            println!(
+                                "│ Idx  │ Name                │ Temp(°C) │ Util(%)    │ VRAM(GB)  │"
+                            );
+                            println!(
+                                "├──────┼─────────────────────┼──────────┼────────────┼───────────┤"
+                            );
+                            for gpu in &gpus {
+                                println!(
+                                    "│ {:4} │ {:19} │ {:8.1} │ {:10.1} │ {:9.1} │",
+                                    gpu.index,
+                                    &gpu.name[..gpu.name.len().min(19)],
+                                    gpu.temperature,
+                                    gpu.utilization,
+                                    gpu.vram_total as f64 / 1024.0 / 1024.0 / 1024.0
+                                );
+                            }
+                            println!(
+                                "└──────┴─────────────────────┴──────────┴────────────┴───────────┘"
+                            );
+                        }
+                        OutputFormat::Human => {
+                            println!("\n📊 Detected GPUs:\n");
+                            for gpu in &gpus {
+                                println!("GPU {}:", gpu.index);
+                                println!("  Name: {}", gpu.name);
+                                println!("  Temperature: {:.1}°C", gpu.temperature);
+                                println!("  Utilization: {:.1}%", gpu.utilization);
+                                println!(
+                                    "  VRAM: {:.2} GB",
+                                    gpu.vram_total as f64 / 1024.0 / 1024.0 / 1024.0
+                                );
+                                if let Some(cuda) = gpu.cuda_cores {
+                                    println!("  CUDA Cores: {}", cuda);
+                                }
+                                if let Some(cc) = &gpu.compute_capability {
+                                    println!("  Compute Capability: {}", cc);
+                                }
+                                println!();
+                            }
+                        }
+                    },
+                    Err(e) => eprintln!("❌ Failed to detect GPUs: {}", e),
+                }
+            }
+            GpuSubcommand::Ecc { gpu: index, action } => {
+                let result = match action {
+                    EccAction::On => gpu::set_ecc_enabled(&backend, index, true),
+                    EccAction::Off => gpu::set_ecc_enabled(&backend, index, false),
+                    EccAction::Status => gpu::get_ecc_status(&backend, index),
+                };
+
+                match result {
+                    Ok(status) if cli.format.is_some() => {
+                        print_formatted_output(&status, &cli.format, cli.no_color)
+                    }
+                    Ok(status) => {
+                        let mode = |enabled: bool| if enabled { "Enabled" } else { "Disabled" };
+                        println!("🧮 ECC memory - GPU {}", status.index);
+                        println!("   Current: {}", mode(status.current_enabled));
+                        println!("   Pending: {}", mode(status.pending_enabled));
+                        if let Some(errors) = &status.errors {
+                            let uncorrected = |n: u64| {
+                                if n > 0 {
+                                    style(n).red().bold().to_string()
+                                } else {
+                                    n.to_string()
+                                }
+                            };
+                            println!("   Errors        Corrected  Uncorrected");
+                            println!(
+                                "   Volatile      {:<10} {}",
+                                errors.volatile_corrected,
+                                uncorrected(errors.volatile_uncorrected)
+                            );
+                            println!(
+                                "   Aggregate     {:<10} {}",
+                                errors.aggregate_corrected,
+                                uncorrected(errors.aggregate_uncorrected)
+                            );
+                            if errors.has_uncorrected() {
+                                println!(
+                                    "{}",
+                                    style("⚠️  Uncorrected memory errors: VRAM data was lost; check cooling and consider an RMA").red()
+                                );
+                            }
+                        }
+                        if status.reboot_required() {
+                            println!(
+                                "⚠️  Change pending: reset the GPU (sudo nvidia-smi -r -i {}) or reboot to apply",
+                                status.index
+                            );
+                        } else if !matches!(action, EccAction::Status) {
+                            println!(
+                                "✅ ECC already {}",
+                                mode(status.current_enabled).to_lowercase()
+                            );
+                        }
                     }
-                    OutputFormat::Yaml => {
-                        println!("{}", serde_yaml::to_string(&gpus).unwrap());
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
                     }
-                    OutputFormat::Table => {
-                        println!(
-                            "\n┌─────────────────────────────────────────────────────────────────┐"
-                        );
-                        println!(
-                            "│                       Detected GPUs                             │"
-                        );
+                }
+            }
+            GpuSubcommand::Renderer => {
+                let report = nvcontrol::renderer::detect();
+                if cli.format.is_some() {
+                    print_formatted_output(&report, &cli.format, cli.no_color);
+                } else {
+                    print_renderer_report(&report);
+                }
+            }
+            GpuSubcommand::Pcie { gpu: index } => match gpu::get_pcie_status(&backend, index) {
+                Ok(status) if cli.format.is_some() => {
+                    print_formatted_output(&status, &cli.format, cli.no_color)
+                }
+                Ok(status) => {
+                    println!("🔌 PCIe - GPU {} ({})", status.index, status.pci_bus_id);
+                    match status.link {
+                        Some(link) => {
+                            println!(
+                                "   Link:          Gen{} x{}",
+                                link.current_gen, link.current_width
+                            );
+                            println!("   Capability:    Gen{} x{}", link.max_gen, link.max_width);
+                            if link.is_gen_degraded() && !status.under_load() {
+                                println!(
+                                    "   {}",
+                                    style("GPU is idle; the link generation rises under load")
+                                        .dim()
+                                );
+                            }
+                        }
+                        None => println!("   Link:          not reported by this GPU"),
+                    }
+                    match status.rebar {
+                        Some(rebar) => println!(
+                            "   Resizable BAR: {} (BAR1 {} MB, VRAM {} MB)",
+                            if rebar.enabled() {
+                                "enabled"
+                            } else {
+                                "disabled"
+                            },
+                            rebar.bar1_bytes / (1024 * 1024),
+                            rebar.vram_bytes / (1024 * 1024)
+                        ),
+                        None => println!("   Resizable BAR: unknown (BAR sizes not readable)"),
+                    }
+                    for warning in status.warnings() {
+                        println!("{}", style(format!("⚠️  {}", warning)).yellow());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ {}", e);
+                    std::process::exit(1);
+                }
+            },
+            GpuSubcommand::Persistence { gpu: index, action } => {
+                let requested = match action {
+                    PersistenceAction::On => Some(true),
+                    PersistenceAction::Off => Some(false),
+                    PersistenceAction::Status => None,
+                };
+                if let Some(enabled) = requested {
+                    if !enabled && gpu::persistence_daemon_running() {
                         println!(
-                            "├──────┬─────────────────────┬──────────┬────────────┬───────────┤"
+                            "{}",
+                            style("⚠️  nvidia-persistenced is running and will keep the driver loaded; stop the service instead").yellow()
                         );
+                    }
+                    if let Err(e) = gpu::set_persistence_mode(&backend, index, enabled) {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                }
+
+                match gpu::get_persistence_status(&backend, index) {
+                    Ok(status) if cli.format.is_some() => {
+                        print_formatted_output(&status, &cli.format, cli.no_color)
+                    }
+                    Ok(status) => {
+                        println!("📌 Persistence mode - GPU {}", status.index);
                         println!(
-                            "│ Idx  │ Name                │ Temp(°C) │ Util(%)    │ VRAM(GB)  │"
+                            "   Mode:   {}",
+                            if status.enabled {
+                                "Enabled"
+                            } else {
+                                "Disabled"
+                            }
                         );
                         println!(
-                            "├──────┼─────────────────────┼──────────┼────────────┼───────────┤"
+                            "   Daemon: {}",
+                            match (status.daemon_running, status.daemon_installed) {
+                                (true, _) => "nvidia-persistenced running",
+                                (false, true) => "nvidia-persistenced installed, not running",
+                                (false, false) => "nvidia-persistenced not installed",
+                            }
                         );
-                        for gpu in &gpus {
-                            println!(
-                                "│ {:4} │ {:19} │ {:8.1} │ {:10.1} │ {:9.1} │",
-                                gpu.index,
-                                &gpu.name[..gpu.name.len().min(19)],
-                                gpu.temperature,
-                                gpu.utilization,
-                                gpu.vram_total as f64 / 1024.0 / 1024.0 / 1024.0
-                            );
+                        if let Some(tip) = status.recommendation() {
+                            println!("{}", style(format!("💡 {}", tip)).dim());
                         }
-                        println!(
-                            "└──────┴─────────────────────┴──────────┴────────────┴───────────┘"
-                        );
                     }
-                    OutputFormat::Human => {
-                        println!("\n📊 Detected GPUs:\n");
-                        for gpu in &gpus {
-                            println!("GPU {}:", gpu.index);
-                            println!("  Name: {}", gpu.name);
-                            println!("  Temperature: {:.1}°C", gpu.temperature);
-                            println!("  Utilization: {:.1}%", gpu.utilization);
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            GpuSubcommand::LockClocks {
+                index,
+                gpu,
+                mem,
+                reset,
+            } => {
+                if reset {
+                    match overclocking::reset_locked_clocks(backend.as_ref(), index) {
+                        Ok(()) => println!("🔓 Clock locks released on GPU {}", index),
+                        Err(e) => {
+                            eprintln!("❌ {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                } else if gpu.is_some() || mem.is_some() {
+                    match overclocking::lock_clocks(backend.as_ref(), index, gpu, mem) {
+                        Ok(lock) => {
+                            println!("🔒 GPU {} clocks LOCKED at {}", index, lock.label());
                             println!(
-                                "  VRAM: {:.2} GB",
-                                gpu.vram_total as f64 / 1024.0 / 1024.0 / 1024.0
+                                "{}",
+                                style("💡 Release with: nvctl gpu lock-clocks --reset").dim()
                             );
-                            if let Some(cuda) = gpu.cuda_cores {
-                                println!("  CUDA Cores: {}", cuda);
+                        }
+                        Err(e) => {
+                            eprintln!("❌ {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                } else {
+                    match backend.get_supported_clocks(index) {
+                        Ok(supported) => {
+                            println!("🔒 Clock locks - GPU {}", index);
+                            match overclocking::LockedClocks::load(index) {
+                                Some(lock) => println!("   State:  LOCKED at {}", lock.label()),
+                                None => println!("   State:  not locked"),
                             }
-                            if let Some(cc) = &gpu.compute_capability {
-                                println!("  Compute Capability: {}", cc);
+                            if let Some((min, max)) = supported.graphics_range() {
+                                println!("   Core:   {}-{} MHz", min, max);
+                            }
+                            if let Some((min, max)) = supported.memory_range() {
+                                println!("   Memory: {}-{} MHz", min, max);
                             }
-                            println!();
+                        }
+                        Err(e) => {
+                            eprintln!("❌ {}", e);
+                            std::process::exit(1);
                         }
                     }
-                },
-                Err(e) => eprintln!("❌ Failed to detect GPUs: {}", e),
-            },
+                }
+            }
+            GpuSubcommand::Reset {
+                index,
+                kill,
+                yes,
+                force,
+            } => {
+                reset_gpu_command(&backend, index, kill, yes, force);
+            }
             GpuSubcommand::Select { index } => {
                 config::TuiSessionState::set_selected_gpu(index as usize);
                 println!("🎯 Selected GPU {} for subsequent commands", index);
@@ -2054,6 +3160,200 @@ fn main() {
         },
         Command::Display { subcommand } => match subcommand {
             DisplaySubcommand::Info => display::get_display_info(),
+            DisplaySubcommand::List { json } => {
+                let listing = display_info::list_connected_displays();
+                if json || matches!(cli.format, Some(OutputFormat::Json)) {
+                    match serde_json::to_string_pretty(&listing) {
+                        Ok(j) => println!("{}", j),
+                        Err(e) => {
+                            eprintln!("❌ Failed to serialize: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                    return;
+                }
+
+                println!(
+                    "🖥️  Connected displays ({} session, via {})",
+                    listing.session, listing.backend
+                );
+                if listing.displays.is_empty() {
+                    println!("   No connected displays detected");
+                }
+                for d in &listing.displays {
+                    let name = d.monitor_name.as_deref().unwrap_or("Unknown monitor");
+                    match &d.drm_connector {
+                        Some(drm) if drm != &d.connector => {
+                            println!("\n📺 {} — {} (DRM {})", d.connector, name, drm)
+                        }
+                        _ => println!("\n📺 {} — {}", d.connector, name),
+                    }
+                    if let Some(serial) = &d.edid_serial {
+                        println!("   Serial:      {}", serial);
+                    }
+                    match &d.current_mode {
+                        Some(m) => println!(
+                            "   Current:     {}x{} @ {:.2}Hz",
+                            m.width, m.height, m.refresh_hz
+                        ),
+                        None => println!("   Current:     unknown"),
+                    }
+                    for mode in &d.modes {
+                        let rates: Vec<String> = mode
+                            .refresh_rates
+                            .iter()
+                            .map(|r| format!("{:.2}", r))
+                            .collect();
+                        if rates.is_empty() {
+                            println!("     • {}x{}", mode.width, mode.height);
+                        } else {
+                            println!(
+                                "     • {}x{} @ {} Hz",
+                                mode.width,
+                                mode.height,
+                                rates.join(", ")
+                            );
+                        }
+                    }
+                    println!(
+                        "   HDR:         {}",
+                        if d.hdr_capable {
+                            "✅ Capable"
+                        } else {
+                            "❌ No"
+                        }
+                    );
+                    if let Some(bits) = d.color_depth_bits {
+                        println!("   Color depth: {} bpc", bits);
+                    }
+                    match (d.vrr_capable, d.vrr_range) {
+                        (true, Some(range)) => {
+                            println!("   VRR:         ✅ {}-{}Hz", range.min_hz, range.max_hz)
+                        }
+                        (true, None) => println!("   VRR:         ✅ Capable"),
+                        (false, _) => println!("   VRR:         ❌ No"),
+                    }
+                }
+            }
+            DisplaySubcommand::SetMode {
+                connector,
+                mode,
+                save,
+            } => {
+                let request: display::ModeRequest = match mode.parse() {
+                    Ok(request) => request,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                let applied = match display::set_mode(
+                    &connector,
+                    request.width,
+                    request.height,
+                    request.refresh_hz,
+                ) {
+                    Ok(applied) => applied,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                println!(
+                    "✅ {} set to {}x{} @ {:.2}Hz",
+                    connector, applied.width, applied.height, applied.refresh_hz
+                );
+
+                if save {
+                    use nvcontrol::monitor_profiles::ConnectorProfileMap;
+
+                    let result = ConnectorProfileMap::load().and_then(|mut profiles| {
+                        profiles.set_mode(&connector, &applied);
+                        profiles.save()
+                    });
+                    match result {
+                        Ok(()) => println!("💾 Saved to the {} connector profile", connector),
+                        Err(e) => {
+                            eprintln!("❌ Failed to save connector profile: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
+            DisplaySubcommand::Rotate {
+                connector,
+                orientation,
+                save,
+            } => {
+                let orientation: display::Orientation = match orientation.parse() {
+                    Ok(orientation) => orientation,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                if let Err(e) = display::set_rotation(&connector, orientation) {
+                    eprintln!("❌ {}", e);
+                    std::process::exit(1);
+                }
+                println!("✅ {} rotated {}", connector, orientation);
+
+                if save {
+                    use nvcontrol::monitor_profiles::ConnectorProfileMap;
+
+                    let result = ConnectorProfileMap::load().and_then(|mut profiles| {
+                        profiles.set_rotation(&connector, orientation);
+                        profiles.save()
+                    });
+                    match result {
+                        Ok(()) => println!("💾 Saved to the {} connector profile", connector),
+                        Err(e) => {
+                            eprintln!("❌ Failed to save connector profile: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
+            DisplaySubcommand::Scale {
+                connector,
+                factor,
+                save,
+            } => {
+                let factor = match display::validate_scale(factor) {
+                    Ok(factor) => factor,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                let warnings = match display::set_scale(&connector, factor) {
+                    Ok(warnings) => warnings,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                println!("✅ {} scaled to {}x", connector, factor);
+                for warning in warnings {
+                    eprintln!("⚠️  {}", warning);
+                }
+
+                if save {
+                    use nvcontrol::monitor_profiles::ConnectorProfileMap;
+
+                    let result = ConnectorProfileMap::load().and_then(|mut profiles| {
+                        profiles.set_scale(&connector, factor);
+                        profiles.save()
+                    });
+                    match result {
+                        Ok(()) => println!("💾 Saved to the {} connector profile", connector),
+                        Err(e) => {
+                            eprintln!("❌ Failed to save connector profile: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
             DisplaySubcommand::Ls => {
                 let count = display::get_display_count();
                 println!("Detected {count} display(s):");
@@ -2268,8 +3568,8 @@ fn main() {
                     Err(e) => eprintln!("❌ Failed to get gamma: {}", e),
                 },
                 GammaSubcommand::Set { display_id, gamma } => {
-                    match display::set_gamma(display_id, gamma) {
-                        Ok(()) => {
+                    match display::set_gamma(display_id, gamma, gamma, gamma) {
+                        Ok(_) => {
                             println!("✅ Gamma set to {:.2} for display {}", gamma, display_id);
                         }
                         Err(e) => eprintln!("❌ Failed to set gamma: {}", e),
@@ -2426,38 +3726,201 @@ fn main() {
                                 };
                                 println!("   Current: {}", space_str);
                             }
-                            Err(e) => eprintln!("❌ Failed to get color space: {}", e),
-                        },
-                        Err(e) => eprintln!("❌ Failed to open display: {}", e),
+                            Err(e) => eprintln!("❌ Failed to get color space: {}", e),
+                        },
+                        Err(e) => eprintln!("❌ Failed to open display: {}", e),
+                    }
+                }
+                ColorSpaceSubcommand::Set { display_id, space } => {
+                    use nvcontrol::display_controls::{ColorSpace, DisplayControls};
+
+                    let color_space = match space.to_lowercase().as_str() {
+                        "rgb" => ColorSpace::RGB,
+                        "ycbcr422" => ColorSpace::YCbCr422,
+                        "ycbcr444" => ColorSpace::YCbCr444,
+                        _ => {
+                            eprintln!(
+                                "❌ Invalid color space. Use 'rgb', 'ycbcr422', or 'ycbcr444'"
+                            );
+                            return;
+                        }
+                    };
+
+                    match DisplayControls::new(0, 0, display_id) {
+                        Ok(controls) => match controls.set_color_space(color_space) {
+                            Ok(()) => println!(
+                                "✅ Color space set to '{}' for display {}",
+                                space, display_id
+                            ),
+                            Err(e) => eprintln!("❌ Failed to set color space: {}", e),
+                        },
+                        Err(e) => eprintln!("❌ Failed to open display: {}", e),
+                    }
+                }
+            },
+            DisplaySubcommand::Connector { subcommand } => {
+                use nvcontrol::display_info::get_connected_connectors;
+                use nvcontrol::monitor_profiles::{
+                    ConnectorProfile, ConnectorProfileMap, Resolution, watch_connector_profiles,
+                };
+
+                let mut profiles = match ConnectorProfileMap::load() {
+                    Ok(profiles) => profiles,
+                    Err(e) => {
+                        eprintln!("❌ Failed to load connector profiles: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                match subcommand {
+                    ConnectorSubcommand::List => {
+                        println!("🖥️  Connected outputs:");
+                        for identity in get_connected_connectors() {
+                            let profile = profiles
+                                .resolve(&identity)
+                                .map(|p| format!("{}% vibrance", p.vibrance))
+                                .unwrap_or_else(|| "no profile".to_string());
+                            println!(
+                                "   {} (serial: {}) - {}",
+                                identity.connector,
+                                identity.edid_serial.as_deref().unwrap_or("unknown"),
+                                profile
+                            );
+                        }
+                        if !profiles.profiles.is_empty() {
+                            println!("\n💾 Saved profiles:");
+                            let mut keys: Vec<_> = profiles.profiles.keys().collect();
+                            keys.sort();
+                            for key in keys {
+                                let p = &profiles.profiles[key];
+                                let mut parts = vec![format!("{}% vibrance", p.vibrance)];
+                                if let (Some(res), Some(rate)) = (&p.resolution, p.refresh_rate) {
+                                    parts.push(format!("{}x{}@{}Hz", res.width, res.height, rate));
+                                }
+                                if let Some(rotation) = p.rotation {
+                                    parts.push(format!("rotated {}", rotation));
+                                }
+                                if let Some(scale) = p.scale {
+                                    parts.push(format!("{}x scale", scale));
+                                }
+                                println!("   {}: {}", key, parts.join(", "));
+                            }
+                        }
+                    }
+                    ConnectorSubcommand::Set {
+                        connector,
+                        vibrance,
+                        mode,
+                        refresh,
+                        edid,
+                    } => {
+                        let resolution = match mode.as_deref().map(|m| m.split_once('x')) {
+                            None => None,
+                            Some(Some((w, h))) => match (w.parse(), h.parse()) {
+                                (Ok(width), Ok(height)) => Some(Resolution { width, height }),
+                                _ => {
+                                    eprintln!("❌ Invalid mode. Use WIDTHxHEIGHT, e.g. 2560x1440");
+                                    std::process::exit(1);
+                                }
+                            },
+                            Some(None) => {
+                                eprintln!("❌ Invalid mode. Use WIDTHxHEIGHT, e.g. 2560x1440");
+                                std::process::exit(1);
+                            }
+                        };
+
+                        // Check the mode against the monitor when it's plugged in
+                        if let (Some(res), Some(rate)) = (&resolution, refresh)
+                            && let Some(display) =
+                                nvcontrol::display_info::list_connected_displays()
+                                    .displays
+                                    .into_iter()
+                                    .find(|d| {
+                                        d.connector == connector
+                                            || d.drm_connector.as_deref()
+                                                == Some(connector.as_str())
+                                    })
+                            && let Err(e) = display::validate_mode(
+                                &display,
+                                display::ModeRequest {
+                                    width: res.width,
+                                    height: res.height,
+                                    refresh_hz: Some(rate as f32),
+                                },
+                            )
+                        {
+                            eprintln!("❌ {}", e);
+                            std::process::exit(1);
+                        }
+
+                        let key = if edid {
+                            let serial = get_connected_connectors()
+                                .into_iter()
+                                .find(|c| c.connector == connector)
+                                .and_then(|c| c.edid_serial);
+                            match serial {
+                                Some(serial) => ConnectorProfileMap::edid_key(&serial),
+                                None => {
+                                    eprintln!(
+                                        "❌ No EDID serial found for connected output {}",
+                                        connector
+                                    );
+                                    std::process::exit(1);
+                                }
+                            }
+                        } else {
+                            connector
+                        };
+
+                        profiles.profiles.insert(
+                            key.clone(),
+                            ConnectorProfile {
+                                vibrance: vibrance.min(200),
+                                resolution,
+                                refresh_rate: refresh,
+                                ..Default::default()
+                            },
+                        );
+                        match profiles.save() {
+                            Ok(()) => println!("✅ Saved profile for {}", key),
+                            Err(e) => {
+                                eprintln!("❌ Failed to save connector profile: {}", e);
+                                std::process::exit(1);
+                            }
+                        }
                     }
-                }
-                ColorSpaceSubcommand::Set { display_id, space } => {
-                    use nvcontrol::display_controls::{ColorSpace, DisplayControls};
-
-                    let color_space = match space.to_lowercase().as_str() {
-                        "rgb" => ColorSpace::RGB,
-                        "ycbcr422" => ColorSpace::YCbCr422,
-                        "ycbcr444" => ColorSpace::YCbCr444,
-                        _ => {
-                            eprintln!(
-                                "❌ Invalid color space. Use 'rgb', 'ycbcr422', or 'ycbcr444'"
-                            );
-                            return;
+                    ConnectorSubcommand::Remove { key } => {
+                        if profiles.profiles.remove(&key).is_none() {
+                            eprintln!("❌ No profile saved for {}", key);
+                            std::process::exit(1);
+                        }
+                        if let Err(e) = profiles.save() {
+                            eprintln!("❌ Failed to save connector profiles: {}", e);
+                            std::process::exit(1);
+                        }
+                        println!("✅ Removed profile for {}", key);
+                    }
+                    ConnectorSubcommand::Apply => match profiles.apply() {
+                        Ok(applied) if applied.is_empty() => {
+                            println!("ℹ️  No connected output has a saved profile")
+                        }
+                        Ok(applied) => println!("✅ Applied profiles to {}", applied.join(", ")),
+                        Err(e) => {
+                            eprintln!("❌ Failed to apply connector profiles: {}", e);
+                            std::process::exit(1);
+                        }
+                    },
+                    ConnectorSubcommand::Watch { interval } => {
+                        println!("👀 Watching for monitor reconnects (Ctrl+C to stop)...");
+                        if let Err(e) = watch_connector_profiles(std::time::Duration::from_secs(
+                            interval.max(1),
+                        )) {
+                            eprintln!("❌ Connector watch failed: {}", e);
+                            std::process::exit(1);
                         }
-                    };
-
-                    match DisplayControls::new(0, 0, display_id) {
-                        Ok(controls) => match controls.set_color_space(color_space) {
-                            Ok(()) => println!(
-                                "✅ Color space set to '{}' for display {}",
-                                space, display_id
-                            ),
-                            Err(e) => eprintln!("❌ Failed to set color space: {}", e),
-                        },
-                        Err(e) => eprintln!("❌ Failed to open display: {}", e),
                     }
                 }
-            },
+            }
             DisplaySubcommand::Dithering { subcommand } => match subcommand {
                 DitheringSubcommand::Get { display_id } => {
                     use nvcontrol::display_controls::{
@@ -2560,22 +4023,133 @@ fn main() {
                 println!("Fan Information:");
                 for fan in fans {
                     println!(
-                        "  Fan {}: {} RPM, {}%, Controllable: {}",
+                        "  Fan {}: {}, {}%, Controllable: {}",
                         fan.id,
-                        fan.rpm.unwrap_or(0),
+                        fan::format_rpm(fan.rpm),
                         fan.percent.unwrap_or(0),
                         fan.controllable
                     );
                 }
             }
             FanSubcommand::Set { fan_id, percent } => match fan::set_fan_speed(fan_id, percent) {
-                Ok(()) => println!("Set fan {fan_id} to {percent}%"),
+                Ok(()) => {
+                    nvcontrol::state::LastApplied::record_fan_speed(fan_id, percent);
+                    println!("Set fan {fan_id} to {percent}%")
+                }
                 Err(e) => eprintln!("Failed to set fan speed: {e}"),
             },
+            FanSubcommand::Manual { percent, fan_id } => {
+                // A running fan daemon would otherwise take the fan back
+                let _ = nvcontrol::fan_daemon::clear_curve(&backend, fan_id);
+                match fan::set_manual_fan_speed(fan_id, percent) {
+                    Ok(()) => {
+                        nvcontrol::state::LastApplied::record_fan_speed(fan_id, percent);
+                        println!("Fan {fan_id} on manual control at {percent}%")
+                    }
+                    Err(e) => {
+                        nvcontrol::error_messages::print_error_with_solution(&e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             FanSubcommand::Auto { fan_id } => match fan::reset_fan_to_auto(fan_id) {
-                Ok(()) => println!("Fan {fan_id} reset to automatic control"),
+                Ok(()) => {
+                    let _ = nvcontrol::fan_daemon::clear_curve(&backend, fan_id);
+                    println!("Fan {fan_id} reset to automatic control")
+                }
                 Err(e) => eprintln!("Failed to reset fan to automatic control: {e}"),
             },
+            FanSubcommand::Daemon { config } => {
+                let path =
+                    config.unwrap_or_else(nvcontrol::fan_daemon::ActiveFanCurves::default_path);
+                let stop = fan::fan_interrupt_flag();
+                if let Err(e) = nvcontrol::fan_daemon::run_fan_daemon(&backend, &path, stop) {
+                    eprintln!("❌ Fan daemon failed: {}", e);
+                    std::process::exit(1);
+                }
+            }
+            FanSubcommand::InstallService { system, user } => {
+                use nvcontrol::fan_daemon::{ServiceScope, install_service};
+
+                let scope = if system {
+                    ServiceScope::System
+                } else if user {
+                    ServiceScope::User
+                } else {
+                    ServiceScope::detect()
+                };
+                match install_service(scope) {
+                    Ok(path) => {
+                        println!(
+                            "✅ Installed and started fan curve service at {}",
+                            path.display()
+                        );
+                        if scope == ServiceScope::System {
+                            println!(
+                                "   Running as a system unit (fan control needs root without a display)"
+                            );
+                        }
+                        println!("   Saved curves are applied by `nvctl fan curve apply/set`");
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Failed to install fan service: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            FanSubcommand::UninstallService => match nvcontrol::fan_daemon::uninstall_service() {
+                Ok(removed) if removed.is_empty() => println!("ℹ️  No fan curve service installed"),
+                Ok(removed) => {
+                    for path in removed {
+                        println!("✅ Removed {}", path.display());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to uninstall fan service: {}", e);
+                    std::process::exit(1);
+                }
+            },
+            FanSubcommand::Test { step, settle } => {
+                let interrupted = fan::fan_interrupt_flag();
+                println!(
+                    "🌀 Ramping fans in {}% steps (Ctrl+C restores and stops)...",
+                    step
+                );
+
+                match fan::ramp_test_fans(step, std::time::Duration::from_secs(settle), interrupted)
+                {
+                    Ok(results) => {
+                        for result in &results {
+                            println!("\nFan {}", result.fan_id);
+                            println!("  {:>7}  {:>8}", "Target", "RPM");
+                            for step in &result.steps {
+                                println!(
+                                    "  {:>6}%  {:>8}",
+                                    step.target_percent,
+                                    step.rpm
+                                        .map(|rpm| rpm.to_string())
+                                        .unwrap_or_else(|| "-".to_string())
+                                );
+                            }
+                            if result.stuck {
+                                println!(
+                                    "  ⚠️  RPM did not rise with duty cycle - fan may be stuck or disconnected"
+                                );
+                            } else {
+                                println!("  ✅ RPM follows duty cycle");
+                            }
+                        }
+                        if interrupted.load(std::sync::atomic::Ordering::SeqCst) {
+                            println!("\n⏹️  Interrupted - fan modes restored");
+                            std::process::exit(130);
+                        }
+                    }
+                    Err(e) => {
+                        nvcontrol::error_messages::print_error_with_solution(&e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             FanSubcommand::Curve { action } => match action {
                 FanCurveSubcommand::Show => {
                     println!("Fan Curve Profiles:");
@@ -2595,7 +4169,11 @@ fn main() {
                         }
                     }
                 }
-                FanCurveSubcommand::Apply { profile, fan_id } => {
+                FanCurveSubcommand::Apply {
+                    profile,
+                    fan_id,
+                    all,
+                } => {
                     let profiles =
                         fan::load_fan_profiles().unwrap_or_else(|_| fan::get_predefined_profiles());
                     let Some(selected) = profiles
@@ -2616,6 +4194,11 @@ fn main() {
                         return;
                     };
 
+                    if all {
+                        apply_fan_curve_to_all(&backend, curve);
+                        return;
+                    }
+
                     let result = nvcontrol::nvml_backend::create_real_backend()
                         .get_temperature((fan_id / 10) as u32)
                         .map(|temp| {
@@ -2625,17 +4208,35 @@ fn main() {
                         .unwrap_or_else(|_| fan::set_fan_curve(fan_id, &fan_curve_points(curve)));
 
                     match result {
-                        Ok(()) => println!(
-                            "Applied fan curve profile `{}` to fan {}",
-                            selected.name, fan_id
-                        ),
+                        Ok(()) => {
+                            if let Err(e) = nvcontrol::fan_daemon::persist_curve(fan_id, curve) {
+                                eprintln!("⚠️  Curve not saved for the fan daemon: {e}");
+                            }
+                            println!(
+                                "Applied fan curve profile `{}` to fan {}",
+                                selected.name, fan_id
+                            )
+                        }
                         Err(e) => eprintln!("Failed to apply fan curve `{}`: {e}", selected.name),
                     }
                 }
-                FanCurveSubcommand::Set { points, fan_id } => match parse_fan_curve_points(&points)
-                {
+                FanCurveSubcommand::Set {
+                    points,
+                    fan_id,
+                    all,
+                } => match parse_fan_curve_points(&points) {
+                    Ok(parsed) if all => apply_fan_curve_to_all(
+                        &backend,
+                        &nvcontrol::fan_daemon::curve_from_points("Custom", &parsed),
+                    ),
                     Ok(parsed) => match fan::set_fan_curve(fan_id, &parsed) {
-                        Ok(()) => println!("Applied inline fan curve to fan {fan_id}"),
+                        Ok(()) => {
+                            let curve = nvcontrol::fan_daemon::curve_from_points("Custom", &parsed);
+                            if let Err(e) = nvcontrol::fan_daemon::persist_curve(fan_id, &curve) {
+                                eprintln!("⚠️  Curve not saved for the fan daemon: {e}");
+                            }
+                            println!("Applied inline fan curve to fan {fan_id}")
+                        }
                         Err(e) => eprintln!("Failed to apply inline fan curve: {e}"),
                     },
                     Err(e) => eprintln!("{e}"),
@@ -2656,14 +4257,19 @@ fn main() {
                 gpu_offset,
                 memory_offset,
                 power_limit,
+                staged,
+                step,
+                probe_secs,
+                all,
+                gpu,
             } => {
                 let mut profile = overclocking::OverclockProfile {
                     name: "CLI Applied".to_string(),
                     ..overclocking::OverclockProfile::default()
                 };
 
-                if let Some(gpu) = gpu_offset {
-                    profile.gpu_clock_offset = gpu;
+                if let Some(offset) = gpu_offset {
+                    profile.gpu_clock_offset = offset;
                 }
                 if let Some(memory) = memory_offset {
                     profile.memory_clock_offset = memory;
@@ -2672,9 +4278,70 @@ fn main() {
                     profile.power_limit = power;
                 }
 
-                match overclocking::apply_overclock_profile(&profile) {
-                    Ok(()) => println!("Overclock applied successfully"),
-                    Err(e) => eprintln!("Failed to apply overclock: {e}"),
+                if staged {
+                    let cancel = fan::fan_interrupt_flag();
+                    println!("Press Ctrl+C to stop at the last stable step");
+                    let result = overclocking::apply_staged(
+                        &backend,
+                        gpu,
+                        &profile,
+                        step,
+                        Duration::from_secs(probe_secs),
+                        cancel,
+                        |step| match step {
+                            overclocking::StagedStep::Applying { offset_mhz } => {
+                                println!("  ⏳ {offset_mhz:+} MHz: probing stability...")
+                            }
+                            overclocking::StagedStep::Stable { offset_mhz } => {
+                                println!("  ✅ {offset_mhz:+} MHz stable")
+                            }
+                            overclocking::StagedStep::Unstable { offset_mhz, reason } => {
+                                println!("  ⚠️  {offset_mhz:+} MHz unstable: {reason}")
+                            }
+                        },
+                    );
+                    match result {
+                        Ok(result) if result.reached_target() => {
+                            println!("✅ Reached target offset {:+} MHz", result.target_offset);
+                        }
+                        Ok(result) => {
+                            println!(
+                                "⚠️  Stopped at {:+} MHz of {:+} MHz target ({})",
+                                result.achieved_offset,
+                                result.target_offset,
+                                result.stop_reason.as_deref().unwrap_or("unknown")
+                            );
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Staged apply failed: {e}");
+                            std::process::exit(1);
+                        }
+                    }
+                } else if all {
+                    match nvcontrol::multi_gpu::apply_overclock_all(&backend, &profile) {
+                        Ok(report) => print_batch_report(&report),
+                        Err(e) => {
+                            eprintln!("❌ Failed to enumerate GPUs: {e}");
+                            std::process::exit(1);
+                        }
+                    }
+                } else {
+                    match overclocking::apply_overclock_profile_for_gpu(&profile, gpu) {
+                        Ok(()) => println!("Overclock applied successfully"),
+                        Err(e) => eprintln!("Failed to apply overclock: {e}"),
+                    }
+                    if let Some(percent) = power_limit {
+                        match power::set_power_limit_percent(&backend, gpu, percent as f32) {
+                            Ok(limit) => {
+                                nvcontrol::state::LastApplied::record_power_limit(
+                                    gpu,
+                                    limit.applied_w,
+                                );
+                                println!("Power limit set to {:.0} W", limit.applied_w)
+                            }
+                            Err(e) => eprintln!("Failed to set power limit: {e}"),
+                        }
+                    }
                 }
             }
             OverclockSubcommand::Profile { name } => {
@@ -2682,25 +4349,95 @@ fn main() {
                 // This would load from saved profiles
                 println!("Profile system not yet implemented");
             }
+            OverclockSubcommand::Preset { name, gpu } => {
+                use nvcontrol::gpu_profiles::{OcPreset, preset_offsets};
+
+                let gpu_name = backend.get_name(gpu).unwrap_or_default();
+                let Some(name) = name else {
+                    let model = preset_offsets(&gpu_name, OcPreset::Stock)
+                        .model
+                        .unwrap_or_else(|| "unknown model, generic values".to_string());
+                    println!("Clock offset presets for GPU {} ({}):", gpu, model);
+                    for preset in OcPreset::ALL {
+                        let offsets = preset_offsets(&gpu_name, preset);
+                        println!(
+                            "  {:<12} {:+5} MHz core  {:+5} MHz memory",
+                            preset.label(),
+                            offsets.gpu_offset,
+                            offsets.memory_offset
+                        );
+                    }
+                    return;
+                };
+                let Some(preset) = OcPreset::from_name(&name) else {
+                    eprintln!(
+                        "❌ Unknown preset '{}' (use stock, mild, performance or extreme)",
+                        name
+                    );
+                    std::process::exit(1);
+                };
+                match overclocking::apply_oc_preset(preset, gpu, &gpu_name) {
+                    Ok(offsets) => println!(
+                        "✅ {} preset on GPU {}: {:+}MHz core, {:+}MHz memory{}",
+                        preset.label(),
+                        gpu,
+                        offsets.gpu_offset,
+                        offsets.memory_offset,
+                        if offsets.model.is_none() {
+                            " (generic values, model not recognised)"
+                        } else {
+                            ""
+                        }
+                    ),
+                    Err(e) => {
+                        eprintln!("❌ Failed to apply preset: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             OverclockSubcommand::StressTest { duration } => {
                 println!("🔥 Starting GPU stress test for {} minutes...", duration);
                 let test_duration = Duration::from_secs(duration as u64 * 60);
                 let _pb = show_progress_bar("GPU stress test", test_duration);
 
                 match overclocking::create_stress_test(duration) {
-                    Ok(()) => {
-                        println!("\n✅ Stress test completed successfully");
+                    Ok(result) => {
+                        if result.passed {
+                            println!("\n✅ PASS: GPU stable for {}s", result.duration_secs);
+                        } else {
+                            println!(
+                                "\n❌ FAIL: {}",
+                                result.failure_reason.as_deref().unwrap_or("unstable")
+                            );
+                        }
+                        println!("   Max temperature: {}°C", result.max_temp);
+                        println!(
+                            "   Throttled: {}",
+                            if result.throttled { "yes" } else { "no" }
+                        );
+                        println!("   Xid errors: {}", result.xid_errors);
+                        if !result.passed {
+                            std::process::exit(1);
+                        }
                     }
                     Err(e) => eprintln!("\n❌ Stress test failed: {e}"),
                 }
             }
-            OverclockSubcommand::Reset => {
-                let default_profile = overclocking::OverclockProfile::default();
-                match overclocking::apply_overclock_profile(&default_profile) {
-                    Ok(()) => println!("GPU settings reset to defaults"),
-                    Err(e) => eprintln!("Failed to reset settings: {e}"),
-                }
-            }
+            OverclockSubcommand::Reset => match overclocking::reset_to_stock() {
+                Ok(_) => println!("GPU settings reset to defaults"),
+                Err(e) => eprintln!("Failed to reset settings: {e}"),
+            },
+            OverclockSubcommand::Revert => match overclocking::revert_last() {
+                Ok((gpu, profile)) => println!(
+                    "GPU {}: reverted to '{}': Core {:+}MHz, Memory {:+}MHz, Power {}%",
+                    gpu,
+                    profile.name,
+                    profile.gpu_clock_offset,
+                    profile.memory_clock_offset,
+                    profile.power_limit
+                ),
+                Err(e) => eprintln!("Failed to revert overclock: {e}"),
+            },
             OverclockSubcommand::Auto {
                 target,
                 safety,
@@ -2774,6 +4511,115 @@ fn main() {
                 }
             }
         },
+        Command::AutoOc { subcommand } => {
+            use nvcontrol::auto_overclock::{
+                AutoOCConfig, AutoOverclocker, EfficiencyConfig, EfficiencyProfile,
+                EfficiencyProgress,
+            };
+
+            match subcommand {
+                AutoOcSubcommand::Efficiency {
+                    gpu,
+                    name,
+                    step,
+                    margin,
+                    max_offset,
+                    duration,
+                } => {
+                    let config = EfficiencyConfig {
+                        gpu_index: gpu,
+                        step_mhz: step,
+                        max_offset_mhz: max_offset,
+                        safety_margin_mhz: margin,
+                        test_duration_secs: duration,
+                    };
+
+                    let overclocker = match AutoOverclocker::new(AutoOCConfig::default()) {
+                        Ok(o) => o,
+                        Err(e) => {
+                            eprintln!("❌ Failed to initialize auto-overclocker: {}", e);
+                            std::process::exit(1);
+                        }
+                    };
+
+                    println!("🌿 Searching for the lowest stable voltage at stock clocks...");
+                    println!(
+                        "   Steps of {} MHz up to +{} MHz, {}s stress test each\n",
+                        step, max_offset, duration
+                    );
+
+                    let result =
+                        overclocker.find_efficient_voltage(&name, &config, |event| match event {
+                            EfficiencyProgress::Testing {
+                                iteration,
+                                offset_mhz,
+                            } => println!("   [{}] Testing +{} MHz...", iteration, offset_mhz),
+                            EfficiencyProgress::Stable { offset_mhz } => {
+                                println!("      ✅ Stable at +{} MHz", offset_mhz)
+                            }
+                            EfficiencyProgress::Unstable { offset_mhz } => {
+                                println!("      ❌ Unstable at +{} MHz, backing off", offset_mhz)
+                            }
+                            EfficiencyProgress::Converged { offset_mhz } => {
+                                println!("   🎯 Keeping +{} MHz with safety margin", offset_mhz)
+                            }
+                        });
+
+                    match result {
+                        Ok(result) => {
+                            let p = &result.profile;
+                            println!("\n✅ Efficiency profile '{}' applied", p.name);
+                            println!("   Clock ceiling:  {} MHz", p.max_gpu_clock_mhz);
+                            println!(
+                                "   Core offset:    {:+} MHz (stable up to {:+} MHz)",
+                                p.gpu_clock_offset, p.stable_offset_mhz
+                            );
+                            println!(
+                                "   Iterations:     {} in {:.1} minutes",
+                                result.iterations,
+                                result.time_taken.as_secs_f64() / 60.0
+                            );
+                            println!("   Reapply with:   nvctl auto-oc apply {}", p.name);
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Efficiency tuning failed: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                AutoOcSubcommand::Apply { name } => {
+                    match EfficiencyProfile::load(&name).and_then(|p| p.apply().map(|_| p)) {
+                        Ok(p) => println!(
+                            "✅ Applied '{}': ceiling {} MHz, offset {:+} MHz",
+                            p.name, p.max_gpu_clock_mhz, p.gpu_clock_offset
+                        ),
+                        Err(e) => {
+                            eprintln!("❌ {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                AutoOcSubcommand::List => {
+                    let names = EfficiencyProfile::list();
+                    if names.is_empty() {
+                        println!("📂 No efficiency profiles saved");
+                        println!("   Create one with: nvctl auto-oc efficiency");
+                    }
+                    for name in names {
+                        match EfficiencyProfile::load(&name) {
+                            Ok(p) => println!(
+                                "   • {}: ceiling {} MHz, offset {:+} MHz ({})",
+                                p.name,
+                                p.max_gpu_clock_mhz,
+                                p.gpu_clock_offset,
+                                p.created_at.format("%Y-%m-%d")
+                            ),
+                            Err(e) => println!("   • {}: {}", name, e),
+                        }
+                    }
+                }
+            }
+        }
         Command::Vrr { subcommand } => match subcommand {
             VrrSubcommand::Status => match vrr::detect_vrr_displays() {
                 Ok(displays) => {
@@ -2795,6 +4641,16 @@ fn main() {
                             "    Refresh Range: {}-{} Hz",
                             display.min_refresh, display.max_refresh
                         );
+                        if display.supports_vrr
+                            && let Ok(refresh) = vrr::get_current_refresh(&display.display_name)
+                        {
+                            let activity = vrr::VrrActivity::classify(
+                                &refresh,
+                                display.min_refresh,
+                                display.max_refresh,
+                            );
+                            println!("    Current: {:.0} Hz ({})", refresh.hz, activity.label());
+                        }
                     }
                 }
                 Err(e) => eprintln!("Failed to detect VRR displays: {e}"),
@@ -2942,7 +4798,7 @@ fn main() {
             },
         },
         Command::Cuda { subcommand } => match subcommand {
-            CudaSubcommand::Info { format } => match cuda::get_cuda_info() {
+            CudaSubcommand::Info { format } => match cuda::get_cuda_info_with(backend.as_ref()) {
                 Ok(info) => print_cuda_command_output(&info, &format, || {
                     cuda::print_cuda_info_summary(&info);
                 }),
@@ -3369,6 +5225,67 @@ fn main() {
                     }
                     Err(e) => eprintln!("❌ Failed to scan games: {}", e),
                 },
+                DlssSubcommand::Preset { profile, preset } => {
+                    use nvcontrol::game_launcher::GameLauncher;
+
+                    let preset = match preset.as_deref().map(dlss::DlssRenderPreset::parse) {
+                        Some(None) => {
+                            let names: Vec<&str> = dlss::DlssRenderPreset::all()
+                                .iter()
+                                .map(|p| p.as_str())
+                                .collect();
+                            eprintln!(
+                                "❌ Unknown DLSS preset '{}' (expected one of: {})",
+                                preset.unwrap_or_default(),
+                                names.join(", ")
+                            );
+                            std::process::exit(1);
+                        }
+                        Some(Some(p)) => Some(p),
+                        None => None,
+                    };
+
+                    let launcher = match GameLauncher::new() {
+                        Ok(launcher) => launcher,
+                        Err(e) => {
+                            eprintln!("❌ Failed to initialize game launcher: {}", e);
+                            std::process::exit(1);
+                        }
+                    };
+                    let mut game_profile = match launcher.load_profile(&profile) {
+                        Ok(p) => p,
+                        Err(e) => {
+                            eprintln!("❌ Failed to load profile '{}': {}", profile, e);
+                            eprintln!("   List profiles with: nvctl gaming launch list");
+                            std::process::exit(1);
+                        }
+                    };
+
+                    let Some(preset) = preset else {
+                        println!("🎯 {}: DLSS {}", profile, dlss::get_preset(&game_profile));
+                        return;
+                    };
+
+                    let caps = match drivers::DriverCapabilities::detect() {
+                        Ok(caps) => caps,
+                        Err(e) => {
+                            eprintln!("❌ Could not detect the driver version: {}", e);
+                            std::process::exit(1);
+                        }
+                    };
+                    if let Err(e) = dlss::set_preset(&mut game_profile, preset, &caps) {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+
+                    match launcher.save_profile(&game_profile) {
+                        Ok(()) => println!("✅ Forced DLSS {} for '{}'", preset, profile),
+                        Err(e) => {
+                            eprintln!("❌ Failed to save profile: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
             }
         }
         Command::Shaders { subcommand } => {
@@ -3406,6 +5323,7 @@ fn main() {
                         Err(e) => eprintln!("❌ Failed to clear shader cache: {}", e),
                     }
                 }
+                ShadersSubcommand::Clean { game, yes } => clean_shader_caches(game, yes),
                 ShadersSubcommand::Optimize => match shaders::optimize_shader_compilation() {
                     Ok(()) => println!("\n✅ Shader compilation optimized"),
                     Err(e) => eprintln!("❌ Failed to optimize shader compilation: {}", e),
@@ -3548,21 +5466,84 @@ fn main() {
             use wayland_nvidia::WaylandNvidiaManager;
 
             match subcommand {
-                WaylandSubcommand::Status => match WaylandNvidiaManager::new() {
-                    Ok(manager) => {
-                        if let Err(e) = manager.print_status() {
-                            eprintln!("❌ Failed to print status: {}", e);
+                WaylandSubcommand::Status => {
+                    let status = wayland_nvidia::WaylandStatus::detect();
+                    if cli.format.is_some() {
+                        print_formatted_output(&status, &cli.format, cli.no_color);
+                    } else {
+                        wayland_nvidia::print_wayland_status(&status);
+                    }
+                }
+                WaylandSubcommand::Optimize { kernel } => {
+                    let status = wayland_nvidia::WaylandStatus::detect();
+                    if status.compositor == wayland_nvidia::WaylandCompositor::X11 {
+                        eprintln!("❌ Not a Wayland session - nothing to optimize");
+                        std::process::exit(1);
+                    }
+                    let (session, reboot) = status.pending();
+                    // Session tweaks belong to the user; module options need root,
+                    // so the two are applied by separate invocations
+                    let to_apply = if kernel { reboot.clone() } else { session };
+                    if to_apply.is_empty() {
+                        println!(
+                            "✅ All recommended optimizations for {} are already applied",
+                            status.compositor.name()
+                        );
+                    } else {
+                        match wayland_nvidia::apply_tweaks(&to_apply) {
+                            Ok(applied) => {
+                                for tweak in to_apply
+                                    .iter()
+                                    .filter(|t| applied.iter().any(|a| a.id == t.id))
+                                {
+                                    println!(
+                                        "✅ {}: {} (takes effect after {})",
+                                        tweak.id,
+                                        tweak.target.describe(),
+                                        tweak.scope.label()
+                                    );
+                                }
+                                println!(
+                                    "{}",
+                                    style("💡 Logged for undo: nvctl wayland revert").dim()
+                                );
+                            }
+                            Err(e) => {
+                                eprintln!("❌ Failed to optimize: {}", e);
+                                std::process::exit(1);
+                            }
                         }
                     }
-                    Err(e) => eprintln!("❌ Failed to initialize: {}", e),
-                },
-                WaylandSubcommand::Optimize { backup } => match WaylandNvidiaManager::new() {
-                    Ok(manager) => {
-                        if let Err(e) = manager.apply_wayland_optimization(backup) {
-                            eprintln!("❌ Failed to optimize: {}", e);
+                    if !kernel && !reboot.is_empty() {
+                        println!("\n⚠️  Reboot-required changes not applied:");
+                        for tweak in &reboot {
+                            println!("   {} ({})", tweak.description, tweak.target.describe());
+                        }
+                        println!("   Apply with: sudo nvctl wayland optimize --kernel");
+                    }
+                }
+                WaylandSubcommand::Revert => match wayland_nvidia::revert_tweaks() {
+                    Ok(reverted) if reverted.is_empty() => {
+                        println!("ℹ️  No Wayland optimizations to revert")
+                    }
+                    Ok(reverted) => {
+                        for entry in &reverted {
+                            println!(
+                                "↩️  {}: {}",
+                                entry.id,
+                                entry
+                                    .previous
+                                    .as_deref()
+                                    .map(|v| format!("restored previous value {}", v))
+                                    .unwrap_or_else(|| "removed".to_string())
+                            );
                         }
+                        println!("✅ Reverted {} change(s)", reverted.len());
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Failed to revert: {}", e);
+                        std::process::exit(1);
                     }
-                    Err(e) => eprintln!("❌ Failed to initialize: {}", e),
                 },
                 WaylandSubcommand::ExportEnv { config } => match WaylandNvidiaManager::new() {
                     Ok(manager) => {
@@ -4361,6 +6342,14 @@ fn main() {
                     eprintln!("Failed to open docs: {e}");
                 }
             }
+            CompanionSubcommand::Tray { gpu, interval } => {
+                if let Err(e) =
+                    nvcontrol::tray::run(&backend, gpu, Duration::from_secs(interval.max(1)))
+                {
+                    eprintln!("❌ Tray failed: {e}");
+                    std::process::exit(1);
+                }
+            }
         },
         Command::Power { subcommand } => match subcommand {
             PowerSubcommand::Status => match power::get_power_info() {
@@ -4372,7 +6361,13 @@ fn main() {
                         println!("GPU {}:", gpu_id);
 
                         if let Some(power_draw) = info.power_draw {
-                            println!("  Current Power: {:.1}W", power_draw);
+                            println!("  Board Power: {:.1}W", power_draw);
+                        }
+                        match power::get_chip_power(&backend, gpu_id as u32) {
+                            Ok(chip) => println!("  Chip Power: {:.1}W", chip),
+                            Err(_) => {
+                                println!("  Chip Power: n/a ({})", power::BOARD_POWER_ONLY_NOTE)
+                            }
                         }
 
                         if let Some(power_limit) = info.power_limit {
@@ -4395,22 +6390,128 @@ fn main() {
                                 "Disabled"
                             }
                         );
+                        if let Ok(mode) = power::get_powermizer_mode(gpu_id as u32) {
+                            println!("  PowerMizer: {}", mode.label());
+                        }
                         println!();
                     }
                 }
                 Err(e) => eprintln!("❌ Failed to get power info: {}", e),
             },
+            PowerSubcommand::Mode { mode, gpu } => {
+                if let Some(mode) = mode {
+                    if let Err(e) = power::set_powermizer_mode(gpu, mode) {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                    println!("✅ GPU {} PowerMizer mode set to {}", gpu, mode.label());
+                } else {
+                    match power::get_powermizer_mode(gpu) {
+                        Ok(current) => {
+                            println!("⚡ GPU {} PowerMizer mode: {}", gpu, current.label())
+                        }
+                        Err(e) => {
+                            eprintln!("❌ {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
             PowerSubcommand::Profile { profile } => match power::set_power_profile(&profile) {
                 Ok(()) => println!("✅ Power profile applied successfully"),
                 Err(e) => eprintln!("❌ Failed to set power profile: {}", e),
             },
-            PowerSubcommand::Limit { percentage } => {
+            PowerSubcommand::Limit {
+                action:
+                    Some(PowerLimitAction::Set {
+                        value, all: true, ..
+                    }),
+                ..
+            } => match nvcontrol::multi_gpu::set_power_limit_all(&backend, value) {
+                Ok(report) => {
+                    if report.applied() > 0 {
+                        let _ = nvcontrol::power_curves::record_manual_override();
+                        nvcontrol::state::LastApplied::record_power_limits(&backend, &report);
+                    }
+                    print_batch_report(&report);
+                }
+                Err(e) => {
+                    nvcontrol::error_messages::print_error_with_solution(&e);
+                    std::process::exit(1);
+                }
+            },
+            PowerSubcommand::Limit {
+                action: Some(PowerLimitAction::Set { value, gpu, .. }),
+                ..
+            } => {
+                let result = match value {
+                    power::PowerLimitTarget::Watts(watts) => {
+                        power::set_power_limit_watts(&backend, gpu, watts)
+                    }
+                    power::PowerLimitTarget::Percent(percent) => {
+                        power::set_power_limit_percent(&backend, gpu, percent)
+                    }
+                };
+                match result {
+                    Ok(applied) => {
+                        let _ = nvcontrol::power_curves::record_manual_override();
+                        nvcontrol::state::LastApplied::record_power_limit(gpu, applied.applied_w);
+                        if applied.was_clamped() {
+                            println!(
+                                "⚠️  {:.0} W is outside the allowed range, clamped",
+                                applied.requested_w
+                            );
+                        }
+                        println!(
+                            "✅ GPU {} power limit set to {:.0} W ({:.0}% of default)",
+                            gpu,
+                            applied.applied_w,
+                            applied.range.percent_of_default(applied.applied_w)
+                        );
+                        println!(
+                            "   Allowed range: {:.0}–{:.0} W (default {:.0} W)",
+                            applied.range.min_w, applied.range.max_w, applied.range.default_w
+                        );
+                    }
+                    Err(e) => {
+                        nvcontrol::error_messages::print_error_with_solution(&e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            PowerSubcommand::Limit {
+                percentage: Some(percentage),
+                ..
+            } => {
                 let percentage = percentage.clamp(50, 120);
                 match power::set_power_limit_percentage(percentage) {
-                    Ok(()) => println!("✅ Power limit set to {}%", percentage),
+                    Ok(()) => {
+                        let _ = nvcontrol::power_curves::record_manual_override();
+                        println!("✅ Power limit set to {}%", percentage)
+                    }
                     Err(e) => eprintln!("❌ Failed to set power limit: {}", e),
                 }
             }
+            PowerSubcommand::Limit { gpu, .. } => {
+                match power::PowerLimitRange::query(&backend, gpu) {
+                    Ok(range) => {
+                        println!(
+                            "⚡ GPU {} power limit: {:.0} W ({:.0}% of default)",
+                            gpu,
+                            range.current_w,
+                            range.percent_of_default(range.current_w)
+                        );
+                        println!(
+                            "   Allowed range: {:.0}–{:.0} W (default {:.0} W)",
+                            range.min_w, range.max_w, range.default_w
+                        );
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Failed to read power limits: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             PowerSubcommand::Persistence { enabled } => {
                 match power::set_persistence_mode(enabled) {
                     Ok(()) => println!(
@@ -4524,12 +6625,148 @@ fn main() {
                     }
                 }
             }
-            PowerSubcommand::Schedule { action: _ } => {
-                println!("⚠️  Power scheduling not yet fully implemented");
-                println!("    Coming soon in next release");
+            PowerSubcommand::Schedule { action } => {
+                use nvcontrol::power_curves::{
+                    ScheduleEntry, Weekday, load_power_config, save_power_config,
+                };
+
+                if let PowerScheduleAction::Add {
+                    start, end, power, ..
+                } = &action
+                {
+                    if *start > 23 || *end > 23 {
+                        eprintln!("❌ Hours must be between 0 and 23");
+                        std::process::exit(1);
+                    }
+                    if !(50..=120).contains(power) {
+                        eprintln!("❌ Power limit must be between 50% and 120%");
+                        std::process::exit(1);
+                    }
+                }
+
+                let mut config = match load_power_config() {
+                    Ok(config) => config,
+                    Err(e) => {
+                        eprintln!("❌ Failed to load config: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                match action {
+                    PowerScheduleAction::List => {
+                        let schedule = &config.schedule;
+                        println!("🕒 Power Schedule:\n");
+                        println!(
+                            "Enabled: {}",
+                            if config.schedule_enabled && schedule.enabled {
+                                "Yes ✅"
+                            } else {
+                                "No ❌"
+                            }
+                        );
+                        if let Some(until) = schedule
+                            .override_expires_at()
+                            .filter(|until| chrono::Utc::now() < *until)
+                        {
+                            println!(
+                                "⏸️  Manual override active until {}",
+                                until.with_timezone(&chrono::Local).format("%H:%M")
+                            );
+                        }
+                        println!();
+
+                        let active = schedule.active_index();
+                        for (i, entry) in schedule.schedules.iter().enumerate() {
+                            let days: Vec<&str> =
+                                entry.days.iter().map(|d| d.short_name()).collect();
+                            println!(
+                                "  {}. {} {} → {}% [{}] {}",
+                                i,
+                                entry.time_range(),
+                                entry.name,
+                                entry.power_limit,
+                                days.join(","),
+                                if active == Some(i) { "◀ active" } else { "" }
+                            );
+                        }
+                        if schedule.schedules.is_empty() {
+                            println!("  (no entries)");
+                        }
+                        return;
+                    }
+                    PowerScheduleAction::Add {
+                        start,
+                        end,
+                        days,
+                        power,
+                        name,
+                    } => {
+                        let Some(days) = Weekday::parse_list(&days) else {
+                            eprintln!("❌ Invalid days '{}'", days);
+                            std::process::exit(1);
+                        };
+                        let entry = ScheduleEntry {
+                            start_hour: start,
+                            end_hour: end,
+                            power_limit: power,
+                            days,
+                            name: name.unwrap_or_else(|| format!("{}% slot", power)),
+                        };
+                        println!(
+                            "✅ Added {} → {}% ({})",
+                            entry.time_range(),
+                            entry.power_limit,
+                            entry.name
+                        );
+                        config.schedule.add_schedule(entry);
+                    }
+                    PowerScheduleAction::Remove { index } => {
+                        if index >= config.schedule.schedules.len() {
+                            eprintln!("❌ No schedule entry at index {}", index);
+                            std::process::exit(1);
+                        }
+                        config.schedule.remove_schedule(index);
+                        println!("✅ Removed schedule entry {}", index);
+                    }
+                    PowerScheduleAction::Enable => {
+                        config.schedule_enabled = true;
+                        config.schedule.enabled = true;
+                        println!("✅ Power schedule enabled");
+                        println!("   Run 'nvctl daemon' to apply it in the background");
+                    }
+                    PowerScheduleAction::Disable => {
+                        config.schedule_enabled = false;
+                        config.schedule.enabled = false;
+                        println!("✅ Power schedule disabled");
+                    }
+                }
+
+                if let Err(e) = save_power_config(&config) {
+                    eprintln!("❌ Failed to save: {}", e);
+                    std::process::exit(1);
+                }
             }
         },
-        Command::Monitor { subcommand } => match subcommand {
+        Command::Monitor {
+            log_db: Some(path),
+            interval,
+            subcommand: None,
+        } => {
+            #[cfg(feature = "logging-sqlite")]
+            if let Err(e) = monitoring::log_samples_to_db(&path, interval, 0, &backend) {
+                eprintln!("❌ Sample logging failed: {}", e);
+                std::process::exit(1);
+            }
+            #[cfg(not(feature = "logging-sqlite"))]
+            {
+                let _ = (path, interval);
+                eprintln!(
+                    "❌ This nvctl was built without SQLite logging; rebuild with --features logging-sqlite"
+                );
+                std::process::exit(1);
+            }
+        }
+        Command::Monitor { subcommand, .. } => match subcommand {
             Some(MonitorSubcommand::Start { interval, count }) => {
                 if let Err(e) =
                     monitoring::live_gpu_watch(interval, count.unwrap_or(0) as u32, &backend)
@@ -4549,6 +6786,68 @@ fn main() {
                     eprintln!("TUI error: {}", e);
                 }
             }
+            Some(MonitorSubcommand::Query { since, db }) => {
+                let Some(window) = monitoring::parse_since(&since) else {
+                    eprintln!("❌ Invalid --since '{}' (use e.g. 24h, 7d, 4w)", since);
+                    std::process::exit(1);
+                };
+                let path = db.unwrap_or_else(monitoring::default_sample_db_path);
+
+                #[cfg(feature = "logging-sqlite")]
+                match monitoring::query_daily_summary(&path, window) {
+                    Ok(days) if days.is_empty() => {
+                        println!("No samples in {} for the last {}", path.display(), since)
+                    }
+                    Ok(days) => {
+                        if matches!(
+                            cli.format,
+                            Some(OutputFormat::Json) | Some(OutputFormat::Yaml)
+                        ) {
+                            print_formatted_output(&days, &cli.format, cli.no_color);
+                        } else {
+                            println!("📈 Daily trends from {}\n", path.display());
+                            println!(
+                                "{:<10} {:>3} {:>6}  {:>17}  {:>20}  {:>20}  {:>14}",
+                                "Day",
+                                "GPU",
+                                "Samples",
+                                "Temp °C",
+                                "Power W",
+                                "Clock MHz",
+                                "Util %"
+                            );
+                            let fmt = |s: &monitoring::MetricStats| {
+                                format!("{:.0}/{:.0}/{:.0}", s.min, s.avg, s.max)
+                            };
+                            for day in &days {
+                                println!(
+                                    "{:<10} {:>3} {:>7}  {:>17}  {:>20}  {:>20}  {:>14}",
+                                    day.day,
+                                    day.gpu_index,
+                                    day.samples,
+                                    fmt(&day.temperature),
+                                    fmt(&day.power_draw),
+                                    fmt(&day.gpu_clock),
+                                    fmt(&day.gpu_utilization)
+                                );
+                            }
+                            println!("\n(min/avg/max per day)");
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Query failed: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+                #[cfg(not(feature = "logging-sqlite"))]
+                {
+                    let _ = (window, path);
+                    eprintln!(
+                        "❌ This nvctl was built without SQLite logging; rebuild with --features logging-sqlite"
+                    );
+                    std::process::exit(1);
+                }
+            }
             Some(MonitorSubcommand::Export { output, duration }) => {
                 println!("Exporting monitor data to {}...", output);
                 println!("Monitoring for {} seconds...", duration);
@@ -4564,11 +6863,25 @@ fn main() {
                 eprintln!("TUI error: {}", e);
             }
         }
+        Command::Gui { mini } => {
+            #[cfg(feature = "gui")]
+            if let Err(e) = nvcontrol::gui::launch(mini) {
+                eprintln!("❌ GUI error: {}", e);
+                std::process::exit(1);
+            }
+            #[cfg(not(feature = "gui"))]
+            {
+                let _ = mini;
+                eprintln!("❌ nvctl was built without the 'gui' feature");
+                std::process::exit(1);
+            }
+        }
         Command::Nvtop => {
             if let Err(e) = nvcontrol::tui::launch_nvtop() {
                 eprintln!("TUI error: {}", e);
             }
         }
+        Command::Gamescope { action } => run_gamescope_action(action),
         Command::Gaming { subcommand } => match subcommand {
             GamingSubcommand::Enable => {
                 println!("🎮 Enabling gaming mode...");
@@ -4603,87 +6916,7 @@ fn main() {
                     println!("Latency testing not implemented yet");
                 }
             },
-            GamingSubcommand::Gamescope { action } => match action {
-                GamescopeAction::Launch {
-                    command,
-                    preset,
-                    width,
-                    height,
-                } => {
-                    // Start with preset config or default
-                    let mut config = if let Some(preset_name) = preset {
-                        match preset_name.to_lowercase().as_str() {
-                            "performance" => gamescope::GamescopePreset::Performance.to_config(),
-                            "quality" => gamescope::GamescopePreset::Quality.to_config(),
-                            "balanced" => gamescope::GamescopePreset::Balanced.to_config(),
-                            "competitive" => gamescope::GamescopePreset::Competitive.to_config(),
-                            "cinematic" => gamescope::GamescopePreset::Cinematic.to_config(),
-                            "steamdeck" => gamescope::GamescopePreset::SteamDeck.to_config(),
-                            _ => {
-                                eprintln!("⚠️  Unknown preset '{}', using default", preset_name);
-                                gamescope::GamescopeConfig::default()
-                            }
-                        }
-                    } else {
-                        gamescope::GamescopeConfig::default()
-                    };
-
-                    // Override with custom width/height if provided
-                    if let Some(w) = width {
-                        config.width = w;
-                    }
-                    if let Some(h) = height {
-                        config.height = h;
-                    }
-
-                    let args = gamescope::generate_advanced_command(&config, &command);
-                    println!("🎯 Launching with Gamescope: {}", args.join(" "));
-
-                    let output = std::process::Command::new(&args[0])
-                        .args(&args[1..])
-                        .spawn();
-
-                    match output {
-                        Ok(_) => println!("✅ Gamescope launched"),
-                        Err(e) => eprintln!("❌ Launch failed: {}", e),
-                    }
-                }
-                GamescopeAction::Presets => {
-                    let presets = gamescope::create_steam_deck_presets();
-                    println!("🎮 Available Gamescope presets:");
-                    for preset in presets {
-                        let (name, description) = match preset {
-                            gamescope::GamescopePreset::SteamDeckHandheld => (
-                                "Steam Deck Handheld",
-                                "Optimized for Steam Deck handheld mode",
-                            ),
-                            gamescope::GamescopePreset::SteamDeckDocked => {
-                                ("Steam Deck Docked", "Optimized for Steam Deck docked mode")
-                            }
-                            gamescope::GamescopePreset::Performance => {
-                                ("Performance", "Maximum performance settings")
-                            }
-                            gamescope::GamescopePreset::Quality => {
-                                ("Quality", "High quality settings")
-                            }
-                            gamescope::GamescopePreset::Balanced => {
-                                ("Balanced", "Balanced performance and quality")
-                            }
-                            gamescope::GamescopePreset::Desktop => {
-                                ("Desktop", "Desktop gaming settings")
-                            }
-                            _ => ("Custom", "Custom configuration"),
-                        };
-                        println!("  📋 {}: {}", style(name).cyan(), description);
-                    }
-                }
-                GamescopeAction::CreatePreset { name } => {
-                    println!(
-                        "🔧 Creating preset '{}' (interactive setup not implemented)",
-                        name
-                    );
-                }
-            },
+            GamingSubcommand::Gamescope { action } => run_gamescope_action(action),
             GamingSubcommand::Launch { action } => match action {
                 LaunchAction::Run { profile, args } => {
                     use nvcontrol::game_launcher::GameLauncher;
@@ -4941,6 +7174,53 @@ fn main() {
                         Err(e) => eprintln!("❌ Failed to initialize launcher: {}", e),
                     }
                 }
+                LaunchAction::EnvSet {
+                    profile,
+                    assignment,
+                } => {
+                    use nvcontrol::game_launcher::GameLauncher;
+
+                    let Some((key, value)) = assignment.split_once('=') else {
+                        eprintln!("❌ Expected KEY=VALUE, got '{}'", assignment);
+                        std::process::exit(1);
+                    };
+                    match GameLauncher::new() {
+                        Ok(launcher) => match launcher.load_profile(&profile) {
+                            Ok(mut game_profile) => {
+                                if let Err(e) = game_profile.set_env(key, value) {
+                                    eprintln!("❌ {}", e);
+                                    std::process::exit(1);
+                                }
+                                match launcher.save_profile(&game_profile) {
+                                    Ok(()) => println!("✅ Set {}={} on '{}'", key, value, profile),
+                                    Err(e) => eprintln!("❌ Failed to save profile: {}", e),
+                                }
+                            }
+                            Err(e) => eprintln!("❌ Failed to load profile '{}': {}", profile, e),
+                        },
+                        Err(e) => eprintln!("❌ Failed to initialize launcher: {}", e),
+                    }
+                }
+                LaunchAction::EnvUnset { profile, key } => {
+                    use nvcontrol::game_launcher::GameLauncher;
+
+                    match GameLauncher::new() {
+                        Ok(launcher) => match launcher.load_profile(&profile) {
+                            Ok(mut game_profile) => {
+                                if game_profile.env_vars.remove(&key).is_none() {
+                                    println!("ℹ️  '{}' has no {} variable", profile, key);
+                                    return;
+                                }
+                                match launcher.save_profile(&game_profile) {
+                                    Ok(()) => println!("✅ Removed {} from '{}'", key, profile),
+                                    Err(e) => eprintln!("❌ Failed to save profile: {}", e),
+                                }
+                            }
+                            Err(e) => eprintln!("❌ Failed to load profile '{}': {}", profile, e),
+                        },
+                        Err(e) => eprintln!("❌ Failed to initialize launcher: {}", e),
+                    }
+                }
                 LaunchAction::SetGamescopePreset { profile, preset } => {
                     use nvcontrol::game_launcher::GameLauncher;
 
@@ -4984,6 +7264,25 @@ fn main() {
                     }
                 }
             },
+            GamingSubcommand::Wrapper { profile } => {
+                use nvcontrol::game_launcher::GameLauncher;
+
+                let loaded =
+                    GameLauncher::new().and_then(|launcher| launcher.load_profile(&profile));
+                match loaded.and_then(|game_profile| {
+                    game_profile.validate_env()?;
+                    Ok(game_profile)
+                }) {
+                    Ok(game_profile) => {
+                        // Bare output so it can be pasted or piped straight into Steam
+                        println!("{}", game_profile.steam_launch_options());
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Failed to load profile '{}': {}", profile, e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             GamingSubcommand::Auto { action } => {
                 use nvcontrol::game_profile_auto::{
                     AutoServiceState, auto_service_status_summary, disable_systemd_user_service,
@@ -5121,6 +7420,7 @@ fn main() {
                         poll_interval,
                         apply_delay,
                         restore_on_exit,
+                        relaunch_debounce,
                     } => match load_config() {
                         Ok(mut config) => {
                             if let Some(interval) = poll_interval {
@@ -5132,6 +7432,9 @@ fn main() {
                             if let Some(restore) = restore_on_exit {
                                 config.restore_on_exit = restore;
                             }
+                            if let Some(debounce) = relaunch_debounce {
+                                config.relaunch_debounce_secs = debounce;
+                            }
 
                             if let Err(e) = save_config(&config) {
                                 eprintln!("❌ Failed to save: {}", e);
@@ -5227,21 +7530,86 @@ fn main() {
                     Ok(()) => println!("💾 Clip saved: {}", output_path),
                     Err(e) => eprintln!("❌ Save failed: {}", e),
                 }
-            }
-            RecordingSubcommand::Presets => match recording::get_recording_presets() {
-                Ok(presets) => {
-                    println!("📋 Recording presets:");
-                    for (i, preset) in presets.iter().enumerate() {
+            }
+            RecordingSubcommand::Presets => match recording::get_recording_presets() {
+                Ok(presets) => {
+                    println!("📋 Recording presets:");
+                    for (i, preset) in presets.iter().enumerate() {
+                        println!(
+                            "  🎬 Preset {}: {:?} @ {} fps",
+                            i + 1,
+                            preset.quality_preset,
+                            preset.framerate
+                        );
+                    }
+                }
+                Err(e) => eprintln!("❌ Failed to get presets: {}", e),
+            },
+            RecordingSubcommand::Frametimes {
+                pid,
+                duration,
+                output,
+            } => {
+                let Some(pid) = pid.or_else(recording::find_mangohud_process) else {
+                    eprintln!(
+                        "❌ No process with MangoHud loaded; pass --pid or launch with `mangohud <game>`"
+                    );
+                    std::process::exit(1);
+                };
+                let capture = match recording::start_frametime_capture(pid) {
+                    Ok(capture) => capture,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                println!(
+                    "⏱️  Capturing frame times from PID {} for {}s...",
+                    pid, duration
+                );
+                std::thread::sleep(std::time::Duration::from_secs(duration.max(1)));
+                // MangoHud flushes its log shortly after the frames are drawn
+                std::thread::sleep(std::time::Duration::from_secs(2));
+
+                let report = match capture.finish() {
+                    Ok(report) => report,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                let output_path = output.unwrap_or_else(|| {
+                    format!(
+                        "frametimes_{}.csv",
+                        chrono::Local::now().format("%Y%m%d_%H%M%S")
+                    )
+                });
+                if let Err(e) = report.write_csv(std::path::Path::new(&output_path)) {
+                    eprintln!("❌ Failed to write {}: {}", output_path, e);
+                    std::process::exit(1);
+                }
+
+                match cli.format {
+                    Some(OutputFormat::Json) => {
+                        print_formatted_output(&report.stats, &cli.format, cli.no_color)
+                    }
+                    _ => {
+                        let s = &report.stats;
+                        println!("📊 {} frames, avg {:.2} ms", s.frames, s.avg_ms);
                         println!(
-                            "  🎬 Preset {}: {:?} @ {} fps",
-                            i + 1,
-                            preset.quality_preset,
-                            preset.framerate
+                            "   p50 {:.2} ms | p95 {:.2} ms | p99 {:.2} ms | max {:.2} ms",
+                            s.p50_ms, s.p95_ms, s.p99_ms, s.max_ms
+                        );
+                        println!(
+                            "   Stutters: {} (frames > 1.5x rolling median)",
+                            s.stutter_count
                         );
                     }
                 }
-                Err(e) => eprintln!("❌ Failed to get presets: {}", e),
-            },
+                println!("💾 Frame times written to {}", output_path);
+            }
         },
         Command::Color { subcommand } => match subcommand {
             ColorSubcommand::Vibrance { action } => match action {
@@ -5907,7 +8275,7 @@ fn main() {
                 use nvcontrol::profile_manager::ProfileManager;
 
                 match ProfileManager::new() {
-                    Ok(manager) => match ProfileManager::capture_live_bundle(&name) {
+                    Ok(manager) => match ProfileManager::capture_live_bundle(&name, 0) {
                         Ok(bundle) => match manager.export_profile(&bundle, Some(&name)) {
                             Ok(path) => {
                                 println!("✅ Captured current live state into '{}'", path.display())
@@ -5961,16 +8329,17 @@ fn main() {
                     Err(e) => eprintln!("❌ Failed to initialize profile manager: {}", e),
                 }
             }
-            ConfigSubcommand::Apply { input } => {
+            ConfigSubcommand::Apply { input, show_diff } => {
                 use nvcontrol::profile_manager::ProfileManager;
 
                 match ProfileManager::new() {
                     Ok(manager) => match manager.resolve_bundle_reference(&input) {
-                        Ok(bundle) => match ProfileManager::apply_bundle(&bundle) {
-                            Ok(applied) if applied.is_empty() => {
+                        Ok(bundle) => match apply_bundle_diff(&bundle, show_diff, 0) {
+                            Ok(None) => println!("Cancelled"),
+                            Ok(Some(applied)) if applied.is_empty() => {
                                 println!("No compatible bundle settings to apply")
                             }
-                            Ok(applied) => {
+                            Ok(Some(applied)) => {
                                 println!("✅ Applied profile bundle '{}':", bundle.name);
                                 for item in applied {
                                     println!("- {}", item);
@@ -5983,27 +8352,627 @@ fn main() {
                     Err(e) => eprintln!("❌ Failed to initialize profile manager: {}", e),
                 }
             }
-            ConfigSubcommand::Profiles => {
-                use nvcontrol::profile_manager::ProfileManager;
-
-                match ProfileManager::new() {
-                    Ok(manager) => match manager.list_profiles() {
-                        Ok(profiles) if profiles.is_empty() => {
-                            println!("📋 No saved profiles found")
+            ConfigSubcommand::Profiles => {
+                use nvcontrol::profile_manager::ProfileManager;
+
+                match ProfileManager::new() {
+                    Ok(manager) => match manager.list_profiles() {
+                        Ok(profiles) if profiles.is_empty() => {
+                            println!("📋 No saved profiles found")
+                        }
+                        Ok(profiles) => {
+                            println!("📋 Saved profile bundles:");
+                            for profile in profiles {
+                                println!("   • {}", profile.name);
+                                println!("     {}", profile.description);
+                            }
+                        }
+                        Err(e) => eprintln!("❌ Failed to list profiles: {}", e),
+                    },
+                    Err(e) => eprintln!("❌ Failed to initialize profile manager: {}", e),
+                }
+            }
+        },
+        Command::Alert { subcommand } => {
+            use nvcontrol::monitoring::{AlertRule, Comparator};
+
+            let mut config = config::Config::load();
+            match subcommand {
+                AlertSubcommand::Add {
+                    metric,
+                    above,
+                    below,
+                    cooldown,
+                    hysteresis,
+                    gpu,
+                    command,
+                } => {
+                    let (comparator, threshold) = match (above, below) {
+                        (Some(value), _) => (Comparator::Above, value),
+                        (None, Some(value)) => (Comparator::Below, value),
+                        (None, None) => unreachable!("clap requires --above or --below"),
+                    };
+                    let rule = AlertRule {
+                        cooldown_secs: cooldown,
+                        hysteresis: hysteresis.abs(),
+                        gpu,
+                        command,
+                        ..AlertRule::new(metric, comparator, threshold)
+                    };
+                    println!(
+                        "✅ Added alert #{}: {}",
+                        config.alert_rules.len() + 1,
+                        rule.describe()
+                    );
+                    config.alert_rules.push(rule);
+                    config.save();
+                    println!(
+                        "{}",
+                        style("Rules are checked while the GUI or TUI is polling").dim()
+                    );
+                }
+                AlertSubcommand::List => {
+                    if cli.format.is_some() {
+                        print_formatted_output(&config.alert_rules, &cli.format, cli.no_color);
+                    } else if config.alert_rules.is_empty() {
+                        println!(
+                            "No alert rules. Add one with `nvctl alert add temperature --above 85`"
+                        );
+                    } else {
+                        println!("🔔 Alert rules");
+                        for (i, rule) in config.alert_rules.iter().enumerate() {
+                            let gpu = rule
+                                .gpu
+                                .map(|g| format!("GPU {}", g))
+                                .unwrap_or_else(|| "all GPUs".to_string());
+                            println!(
+                                "   {:>2}. {:<24} {:<9} cooldown {}s, hysteresis {}{}",
+                                i + 1,
+                                rule.describe(),
+                                gpu,
+                                rule.cooldown_secs,
+                                rule.hysteresis,
+                                rule.metric.unit()
+                            );
+                            if let Some(command) = &rule.command {
+                                println!("       runs: {}", command);
+                            }
+                        }
+                    }
+                }
+                AlertSubcommand::Remove { number } => {
+                    if number == 0 || number > config.alert_rules.len() {
+                        eprintln!(
+                            "❌ No alert #{} ({} configured)",
+                            number,
+                            config.alert_rules.len()
+                        );
+                        std::process::exit(1);
+                    }
+                    let rule = config.alert_rules.remove(number - 1);
+                    config.save();
+                    println!("🗑️  Removed alert: {}", rule.describe());
+                }
+            }
+        }
+        Command::Benchmark { subcommand } => match subcommand {
+            BenchmarkSubcommand::Memory { gpu } => {
+                use nvcontrol::benchmark::{BANDWIDTH_WARN_PERCENT, BandwidthMethod};
+
+                let result = match nvcontrol::benchmark::memory_bandwidth(&backend, gpu) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        eprintln!("❌ Memory benchmark failed: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                if cli.format.is_some() {
+                    print_formatted_output(&result, &cli.format, cli.no_color);
+                    return;
+                }
+
+                println!(
+                    "🧠 Memory bandwidth - GPU {} ({})",
+                    result.gpu_index, result.gpu_name
+                );
+                let how = match result.method {
+                    BandwidthMethod::Cuda => "CUDA device-to-device copy",
+                    BandwidthMethod::ClockEstimate => "estimate from memory clock × bus width",
+                };
+                println!("   Measured: {:7.1} GB/s ({})", result.measured_gbps, how);
+                match (result.expected_gbps, result.percent_of_expected()) {
+                    (Some(expected), Some(percent)) => {
+                        println!("   Expected: {:7.1} GB/s ({:.0}%)", expected, percent)
+                    }
+                    _ => println!("   Expected: unknown for this model"),
+                }
+
+                if result.method == BandwidthMethod::ClockEstimate {
+                    println!(
+                        "ℹ️  Rough estimate only; idle memory clocks lower it. Install the CUDA toolkit (nvcc) for a measured result."
+                    );
+                } else if result.is_below_expected() {
+                    println!(
+                        "{}",
+                        style(format!(
+                            "⚠️  Below {:.0}% of expected. Check for thermal throttling, or back off a memory overclock: GDDR error correction turns instability into lost bandwidth.",
+                            BANDWIDTH_WARN_PERCENT
+                        ))
+                        .yellow()
+                    );
+                } else if result.expected_gbps.is_some() {
+                    println!("✅ Within the normal range for this card");
+                }
+            }
+            BenchmarkSubcommand::Soak {
+                load,
+                gpu,
+                critical_temp,
+                output,
+            } => {
+                if load == 0 {
+                    eprintln!("❌ --load must be at least 1 second");
+                    std::process::exit(1);
+                }
+                let output = match output
+                    .map(Ok)
+                    .unwrap_or_else(nvcontrol::benchmark::default_soak_csv_path)
+                {
+                    Ok(path) => path,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                // Progress goes to stderr so --format output stays parseable
+                eprintln!(
+                    "🌡️  Thermal soak: {}s of load on GPU {}, then cooldown (stops at {}°C)",
+                    load, gpu, critical_temp
+                );
+                let report = nvcontrol::benchmark::run_thermal_soak(
+                    backend.as_ref(),
+                    gpu,
+                    std::time::Duration::from_secs(load),
+                    critical_temp,
+                    &std::sync::atomic::AtomicBool::new(false),
+                    |sample| {
+                        eprint!(
+                            "\r   {:>5.0}s  {:<8} {:>3}°C  fan {:>3}%  {:>5.1}W   ",
+                            sample.elapsed_secs,
+                            sample.phase.name(),
+                            sample.temperature_c,
+                            sample.fan_percent,
+                            sample.power_w
+                        );
+                    },
+                );
+                eprintln!();
+                let report = match report {
+                    Ok(report) => report,
+                    Err(e) => {
+                        eprintln!("❌ Thermal soak failed: {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                if cli.format.is_some() {
+                    print_formatted_output(&report, &cli.format, cli.no_color);
+                } else {
+                    report.print_summary();
+                }
+                match report.write_csv(&output) {
+                    Ok(()) => eprintln!("💾 Temperature curve written to {}", output.display()),
+                    Err(e) => {
+                        eprintln!("❌ Failed to write {}: {}", output.display(), e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            BenchmarkSubcommand::Run {
+                duration,
+                output,
+                save_baseline,
+                command,
+            } => {
+                let report = nvcontrol::benchmark::BenchmarkSuite::new()
+                    .and_then(|suite| suite.run_correlated(&command, duration));
+                match report {
+                    Ok(report) => {
+                        report.print_summary();
+                        match report.write_csv(&output) {
+                            Ok(()) => println!("💾 Samples written to {}", output.display()),
+                            Err(e) => {
+                                eprintln!("❌ Failed to write {}: {}", output.display(), e);
+                                std::process::exit(1);
+                            }
+                        }
+                        if let Some(name) = save_baseline {
+                            match nvcontrol::benchmark::save_baseline(&name, &report.summary()) {
+                                Ok(_) => println!("📌 Saved as baseline '{}'", name),
+                                Err(e) => {
+                                    eprintln!("❌ Failed to save baseline: {}", e);
+                                    std::process::exit(1);
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Benchmark failed: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            BenchmarkSubcommand::Compare { baseline, run } => {
+                let baseline = match nvcontrol::benchmark::load_baseline(&baseline) {
+                    Ok(baseline) => baseline,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+                let current = match run {
+                    Some(name) => nvcontrol::benchmark::load_baseline(&name).map(|b| b.run),
+                    None => nvcontrol::benchmark::BenchmarkSuite::new().and_then(|suite| {
+                        suite.last_run().ok_or_else(|| {
+                            nvcontrol::NvControlError::ConfigError(
+                                "No benchmark run yet; start one with: nvctl benchmark run"
+                                    .to_string(),
+                            )
+                        })
+                    }),
+                };
+                let current = match current {
+                    Ok(current) => current,
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                };
+
+                let comparison = nvcontrol::benchmark::compare(&current, &baseline);
+                if cli.format.is_some() {
+                    print_formatted_output(&comparison, &cli.format, cli.no_color);
+                    return;
+                }
+                println!("📊 Compared with baseline '{}'", comparison.baseline_name);
+                println!(
+                    "   {:<12} {:>10} {:>10} {:>9}",
+                    "Metric", "Baseline", "Run", "Change"
+                );
+                for delta in &comparison.deltas {
+                    let change = delta
+                        .percent()
+                        .map(|p| format!("{:+.1}%", p))
+                        .unwrap_or_else(|| "-".to_string());
+                    let change = match delta.is_improvement() {
+                        Some(true) => style(change).green().to_string(),
+                        Some(false) => style(change).red().to_string(),
+                        None => change,
+                    };
+                    println!(
+                        "   {:<12} {:>10} {:>10} {:>9}",
+                        delta.metric,
+                        format!("{:.1} {}", delta.baseline, delta.unit),
+                        format!("{:.1} {}", delta.current, delta.unit),
+                        change
+                    );
+                }
+                for warning in &comparison.warnings {
+                    println!("{}", style(format!("⚠️  {}", warning)).yellow());
+                }
+            }
+            BenchmarkSubcommand::Baselines => {
+                let baselines = nvcontrol::benchmark::list_baselines();
+                if baselines.is_empty() {
+                    println!(
+                        "No baselines saved. Save one with: nvctl benchmark run --save-baseline stock"
+                    );
+                }
+                for baseline in baselines {
+                    let fps = baseline
+                        .run
+                        .avg_fps
+                        .map(|fps| format!("{:.1} fps", fps))
+                        .unwrap_or_else(|| "no FPS".to_string());
+                    println!(
+                        "📌 {:<16} {}  {}  driver {}  {}",
+                        baseline.name,
+                        baseline
+                            .run
+                            .recorded_at
+                            .with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M"),
+                        baseline.run.gpu_name,
+                        baseline.run.driver_version,
+                        fps
+                    );
+                }
+            }
+        },
+        Command::Latency { subcommand } => match subcommand {
+            LatencySubcommand::Set { mode, gpu } => {
+                let Some(latency_mode) = latency::LatencyMode::parse(&mode) else {
+                    eprintln!(
+                        "❌ Unknown latency mode '{}'. Use: competitive, balanced, default",
+                        mode
+                    );
+                    std::process::exit(1);
+                };
+
+                match latency::set_latency_mode_for_gpu(latency_mode, gpu) {
+                    Ok(report) => {
+                        if report.reboot_required.is_empty() {
+                            println!("✅ Latency mode '{}' active on GPU {}", report.mode, gpu);
+                        } else {
+                            println!(
+                                "⚠️  Latency mode '{}' partially applied on GPU {}; reboot needed for:",
+                                report.mode, gpu
+                            );
+                            for change in &report.reboot_required {
+                                println!("   {}", change);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Failed to set latency mode: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            LatencySubcommand::Status => match latency::get_latency_info() {
+                Ok(info) if cli.format.is_some() => {
+                    print_formatted_output(&info, &cli.format, cli.no_color)
+                }
+                Ok(info) => {
+                    println!("⚡ Latency Status");
+                    println!("   NVIDIA Reflex:       {}", info.reflex_status());
+                    println!(
+                        "   Framebuffer Comp.:   {}",
+                        info.framebuffer_compression_status()
+                    );
+                    println!("   CPU Scheduler:       {}", info.current_cpu_scheduler);
+                    println!(
+                        "   Estimated Input Lag: {:.1}ms",
+                        info.estimated_input_lag_ms
+                    );
+                    if let Some(timeout) = info.preemption_timeout {
+                        println!("   Preemption Timeout:  {}µs", timeout);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to get latency info: {}", e);
+                    std::process::exit(1);
+                }
+            },
+        },
+        Command::Profile { subcommand } => {
+            use nvcontrol::profile_manager::ProfileManager;
+
+            let manager = match ProfileManager::new() {
+                Ok(manager) => manager,
+                Err(e) => {
+                    eprintln!("❌ Failed to initialize profile manager: {}", e);
+                    std::process::exit(1);
+                }
+            };
+
+            match subcommand {
+                ProfileSubcommand::List => match manager.list_profiles() {
+                    Ok(profiles) if profiles.is_empty() => println!("📋 No saved profiles found"),
+                    Ok(profiles) => {
+                        println!("📋 Saved profiles:");
+                        for profile in profiles {
+                            println!("   • {}", profile.name);
+                            println!("     {}", profile.description);
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("❌ Failed to list profiles: {}", e);
+                        std::process::exit(1);
+                    }
+                },
+                ProfileSubcommand::Apply {
+                    name,
+                    show_diff,
+                    gpu,
+                } => {
+                    let target = match manager.load_profile_by_name(&name) {
+                        Ok(bundle) => bundle,
+                        Err(_) if !manager.has_profile(&name) => {
+                            eprintln!("❌ Profile '{}' not found", name);
+                            eprintln!("💡 List profiles with: nvctl profile list");
+                            std::process::exit(1);
+                        }
+                        // The file is there but unreadable or malformed
+                        Err(e) => {
+                            eprintln!("❌ Failed to load profile '{}': {}", name, e);
+                            std::process::exit(1);
+                        }
+                    };
+
+                    match apply_bundle_diff(&target, show_diff, gpu) {
+                        Ok(None) => println!("Cancelled"),
+                        Ok(Some(applied)) if applied.is_empty() => {
+                            println!(
+                                "✅ Profile '{}' already matches the current state",
+                                target.name
+                            )
+                        }
+                        Ok(Some(applied)) => {
+                            println!("✅ Applied profile '{}'", target.name);
+                            for item in applied {
+                                println!("   - {}", item);
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Failed to apply profile '{}': {}", name, e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                ProfileSubcommand::Save { name, gpu } => {
+                    match ProfileManager::capture_live_bundle(&name, gpu)
+                        .and_then(|bundle| manager.export_profile(&bundle, Some(&name)))
+                    {
+                        Ok(_) => println!("💾 Saved profile '{}'", name),
+                        Err(e) => {
+                            eprintln!("❌ Failed to save profile: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                ProfileSubcommand::Export { name, path } => {
+                    match nvcontrol::profile_manager::export(&name, std::path::Path::new(&path)) {
+                        Ok(bundle) => {
+                            println!("📤 Exported profile '{}' to {}", name, path);
+                            if let Some(tuned) = bundle.tuned_on {
+                                println!(
+                                    "   Tuned on {} (driver {})",
+                                    tuned.gpu_model, tuned.driver_version
+                                );
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Failed to export profile '{}': {}", name, e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                ProfileSubcommand::Import { path, overwrite } => {
+                    match nvcontrol::profile_manager::import(
+                        std::path::Path::new(&path),
+                        overwrite,
+                    ) {
+                        Ok(imported) => {
+                            println!(
+                                "📥 Imported profile '{}' to {}",
+                                imported.bundle.name,
+                                imported.path.display()
+                            );
+                            for warning in &imported.warnings {
+                                eprintln!("⚠️  {}", warning);
+                            }
+                            println!(
+                                "💡 Review and apply with: nvctl profile apply {} --show-diff",
+                                imported.bundle.name
+                            );
+                        }
+                        Err(e) => {
+                            eprintln!("❌ Failed to import profile: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
+                }
+            }
+        }
+        Command::Daemon { interval, debounce } => {
+            use nvcontrol::game_profile_auto::{load_config, run_process_scan_daemon};
+
+            let mut config = load_config().unwrap_or_default();
+            if let Some(interval) = interval {
+                config.poll_interval_secs = interval;
+            }
+            if let Some(debounce) = debounce {
+                config.relaunch_debounce_secs = debounce;
+            }
+
+            let startup = nvcontrol::config::Config::load().startup_apply;
+            if startup.enabled
+                && let Some(outcomes) = startup_apply_cli(&backend, startup.delay_secs)
+            {
+                print_apply_outcomes(&outcomes, "nothing saved");
+            }
+
+            if let Err(e) = run_process_scan_daemon(&config) {
+                eprintln!("❌ Daemon failed: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::ApplySaved { startup } => {
+            let outcomes = if startup {
+                let delay_secs = nvcontrol::config::Config::load().startup_apply.delay_secs;
+                startup_apply_cli(&backend, delay_secs).unwrap_or_else(|| std::process::exit(1))
+            } else {
+                println!("🔂 Applying saved settings");
+                nvcontrol::state::apply_saved(&backend)
+            };
+            print_apply_outcomes(&outcomes, "nothing saved");
+            if outcomes.iter().any(|o| o.is_critical_failure()) {
+                eprintln!("❌ One or more critical settings failed to apply");
+                std::process::exit(1);
+            }
+            if !startup && nvcontrol::state::startup_apply_crashed() {
+                // A clean manual run re-arms the startup apply
+                nvcontrol::state::clear_startup_apply_flag();
+                println!("   Startup apply re-enabled");
+            }
+        }
+        Command::Reset => {
+            println!("🧯 Resetting everything nvcontrol changed to stock");
+            let outcomes = nvcontrol::state::reset_all_to_defaults(&backend);
+            print_apply_outcomes(&outcomes, "nothing to reset");
+            if outcomes.iter().any(|o| o.is_critical_failure()) {
+                eprintln!("❌ One or more subsystems could not be reset");
+                std::process::exit(1);
+            }
+            println!("   Display modes and compositor settings were left unchanged");
+            nvcontrol::power::forget_quiet_mode();
+        }
+        Command::Quiet { subcommand } => {
+            let limits = nvcontrol::config::Config::load().quiet_mode;
+            let clocks = limits
+                .max_gpu_clock_mhz
+                .map(|mhz| format!(", core clock pinned at {mhz} MHz"))
+                .unwrap_or_default();
+            let enable = match subcommand {
+                QuietSubcommand::On => true,
+                QuietSubcommand::Off => false,
+                QuietSubcommand::Status => {
+                    match nvcontrol::power::QuietModeState::load() {
+                        Some(state) => {
+                            println!(
+                                "🤫 Quiet mode on since {}",
+                                state
+                                    .enabled_at
+                                    .with_timezone(&chrono::Local)
+                                    .format("%Y-%m-%d %H:%M")
+                            );
+                            println!(
+                                "   Fans ≤ {}%, power limit {}% of default{}",
+                                limits.fan_max_pct, limits.power_limit_pct, clocks
+                            );
                         }
-                        Ok(profiles) => {
-                            println!("📋 Saved profile bundles:");
-                            for profile in profiles {
-                                println!("   • {}", profile.name);
-                                println!("     {}", profile.description);
-                            }
+                        None => println!("🔊 Quiet mode is off"),
+                    }
+                    return;
+                }
+            };
+
+            let was_on = nvcontrol::power::is_quiet_mode();
+            match nvcontrol::power::set_quiet_mode(&backend, enable) {
+                Ok(warnings) => {
+                    match (enable, was_on) {
+                        (true, true) => println!("🤫 Quiet mode is already on"),
+                        (false, false) => println!("🔊 Quiet mode is already off"),
+                        (true, false) => println!(
+                            "🤫 Quiet mode on: fans ≤ {}%, power limit {}% of default{}",
+                            limits.fan_max_pct, limits.power_limit_pct, clocks
+                        ),
+                        (false, true) => {
+                            println!(
+                                "🔊 Quiet mode off: previous fan, power and clock settings restored"
+                            )
                         }
-                        Err(e) => eprintln!("❌ Failed to list profiles: {}", e),
-                    },
-                    Err(e) => eprintln!("❌ Failed to initialize profile manager: {}", e),
+                    }
+                    for warning in warnings {
+                        println!("   ⚠️  {}", warning);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to change quiet mode: {}", e);
+                    std::process::exit(1);
                 }
             }
-        },
+        }
         Command::Osd { subcommand } => {
             use nvcontrol::osd::{OsdManager, OsdMetric, OsdPosition};
 
@@ -6475,45 +9444,108 @@ fn main() {
                 }
             }
         }
-        Command::Setup { subcommand } => match subcommand {
-            SetupSubcommand::Check { format } => match format {
-                OutputFormat::Json => {
-                    let report = setup::collect_readiness_report();
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&report).unwrap_or_default()
-                    );
-                }
-                OutputFormat::Yaml => {
-                    let report = setup::collect_readiness_report();
-                    println!("{}", serde_yaml::to_string(&report).unwrap_or_default());
-                }
-                OutputFormat::Table | OutputFormat::Human => {
-                    if let Err(e) = setup::run_readiness_check() {
-                        eprintln!("Setup check failed: {e}");
-                        std::process::exit(1);
-                    }
-                }
-            },
-            SetupSubcommand::Permissions => {
-                if let Err(e) = setup::setup_permissions() {
-                    eprintln!("Failed to set up permissions: {e}");
+        Command::Report { copy, output } => {
+            let report = nvcontrol::report::build_report(backend.as_ref());
+            if let Some(path) = &output {
+                if let Err(e) = std::fs::write(path, &report) {
+                    eprintln!("❌ Failed to write report to {}: {}", path, e);
                     std::process::exit(1);
                 }
+                println!("✅ Report written to {}", path);
             }
-            SetupSubcommand::Status => {
-                if let Err(e) = setup::show_permissions_info() {
-                    eprintln!("Failed to inspect permissions: {e}");
+            if copy {
+                if let Err(e) = nvcontrol::report::copy_to_clipboard(&report) {
+                    eprintln!("❌ {}", e);
                     std::process::exit(1);
                 }
+                println!("✅ Report copied to clipboard");
+            }
+            if output.is_none() && !copy {
+                print!("{}", report);
             }
-            SetupSubcommand::Remove => {
-                if let Err(e) = setup::remove_setup() {
-                    eprintln!("Failed to remove setup: {e}");
+        }
+        Command::Serve {
+            bind,
+            allow_control,
+            interval,
+        } => {
+            use nvcontrol::api_server::{self, Control};
+
+            let control = match Control::from_options(allow_control, &config.api_token) {
+                Ok(control) => control,
+                Err(e) => {
+                    eprintln!("❌ {}", e);
                     std::process::exit(1);
                 }
+            };
+            if let Err(e) = api_server::serve(
+                backend,
+                &bind,
+                control,
+                config.gpu_labels.clone(),
+                Duration::from_secs(interval),
+            ) {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
             }
-        },
+        }
+        Command::Setup { subcommand } => {
+            match subcommand.unwrap_or(SetupSubcommand::Wizard { check_only: false }) {
+                SetupSubcommand::Wizard { check_only } => {
+                    use std::io::IsTerminal;
+                    let interactive = !check_only
+                        && std::io::stdin().is_terminal()
+                        && std::io::stdout().is_terminal();
+                    let mut confirm = |message: &str| confirm_action(message);
+                    let result = if interactive {
+                        setup::run_wizard(Some(&mut confirm))
+                    } else {
+                        setup::run_wizard(None)
+                    };
+                    if let Err(e) = result {
+                        eprintln!("Setup wizard failed: {e}");
+                        std::process::exit(1);
+                    }
+                }
+                SetupSubcommand::Check { format } => match format {
+                    OutputFormat::Json => {
+                        let report = setup::collect_readiness_report();
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&report).unwrap_or_default()
+                        );
+                    }
+                    OutputFormat::Yaml => {
+                        let report = setup::collect_readiness_report();
+                        println!("{}", serde_yaml::to_string(&report).unwrap_or_default());
+                    }
+                    OutputFormat::Table | OutputFormat::Human => {
+                        if let Err(e) = setup::run_readiness_check() {
+                            eprintln!("Setup check failed: {e}");
+                            std::process::exit(1);
+                        }
+                    }
+                },
+                SetupSubcommand::Permissions => {
+                    if let Err(e) = setup::setup_permissions() {
+                        eprintln!("Failed to set up permissions: {e}");
+                        std::process::exit(1);
+                    }
+                }
+                SetupSubcommand::Status => {
+                    if let Err(e) = setup::show_permissions_info() {
+                        eprintln!("Failed to inspect permissions: {e}");
+                        std::process::exit(1);
+                    }
+                }
+                SetupSubcommand::Remove => {
+                    if let Err(e) = setup::remove_setup() {
+                        eprintln!("Failed to remove setup: {e}");
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
         Command::Completion { shell } => match print_shell_completions(&shell) {
             Ok(()) => {}
             Err(e) => eprintln!("Failed to generate completions: {e}"),
@@ -6716,6 +9748,75 @@ fn main() {
                     println!();
                 }
             }
+            AsusSubcommand::OcScan { gpu } => {
+                use nvcontrol::asus_gpu_tweak::OcScanProgress;
+                println!("🔍 Running OC scanner on GPU {}...", gpu);
+                println!("Press Ctrl+C to stop at the last stable step");
+                let cancel = fan::fan_interrupt_flag();
+                let result = nvcontrol::asus_gpu_tweak::run_oc_scanner(
+                    &backend,
+                    gpu,
+                    cancel,
+                    |progress| match progress {
+                        OcScanProgress::PowerLimit {
+                            limit_w,
+                            default_w,
+                            unlocked: true,
+                        } => println!(
+                            "🔓 ASUS power ceiling: {} W (default {} W)",
+                            limit_w, default_w
+                        ),
+                        OcScanProgress::PowerLimit { default_w, .. } => {
+                            println!("ℹ️  Scanning at the default {} W limit", default_w)
+                        }
+                        OcScanProgress::ConnectorWarning => println!(
+                            "⚠️  Power Detector+ reports connector warnings; scanning at the default limit"
+                        ),
+                        OcScanProgress::Step(overclocking::StagedStep::Applying { offset_mhz }) => {
+                            println!("  ⏳ {offset_mhz:+} MHz: probing stability...")
+                        }
+                        OcScanProgress::Step(overclocking::StagedStep::Stable { offset_mhz }) => {
+                            println!("  ✅ {offset_mhz:+} MHz stable")
+                        }
+                        OcScanProgress::Step(overclocking::StagedStep::Unstable {
+                            offset_mhz,
+                            reason,
+                        }) => println!("  ⚠️  {offset_mhz:+} MHz unstable: {reason}"),
+                    },
+                );
+                match result {
+                    Ok(scan) => {
+                        match &scan.asus_model {
+                            Some(model) => println!(
+                                "🎮 {} - power limit {} W{}",
+                                model,
+                                scan.power_limit_w,
+                                if scan.power_unlocked {
+                                    " (unlocked)"
+                                } else {
+                                    ""
+                                }
+                            ),
+                            None => println!("ℹ️  No ASUS ROG board; scanned at the default limit"),
+                        }
+                        if scan.successful() {
+                            println!("✅ Stable at {:+} MHz core", scan.sweep.achieved_offset);
+                            if let Some(name) = &scan.profile_name {
+                                println!("💾 Saved as profile \"{}\"", name);
+                            }
+                        } else {
+                            println!(
+                                "⚠️  No stable overclock found - stock settings kept ({})",
+                                scan.sweep.stop_reason.as_deref().unwrap_or("unknown")
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
             AsusSubcommand::Aura { action } => {
                 use nvcontrol::asus_aura::{
                     AsusAuraController, AuraConfig, AuraEffect, AuraMode, AuraPresets, AuraSpeed,
@@ -6862,6 +9963,69 @@ fn main() {
                             Err(e) => eprintln!("❌ Failed to restore: {}", e),
                         }
                     }
+                    AsusAuraAction::Sync {
+                        gpu,
+                        cold,
+                        hot,
+                        cold_temp,
+                        hot_temp,
+                        interval,
+                    } => {
+                        use nvcontrol::rgb_control::{self, TempGradient};
+
+                        let parse_hex = |hex: &str| {
+                            let hex = hex.trim_start_matches('#');
+                            (hex.len() == 6)
+                                .then(|| u32::from_str_radix(hex, 16).ok())
+                                .flatten()
+                                .map(|v| rgb_control::RgbColor {
+                                    r: (v >> 16) as u8,
+                                    g: (v >> 8) as u8,
+                                    b: v as u8,
+                                })
+                        };
+                        let (Some(cold), Some(hot)) = (parse_hex(&cold), parse_hex(&hot)) else {
+                            eprintln!("❌ Invalid color format. Use 6-digit hex (e.g., FF0000)");
+                            std::process::exit(1);
+                        };
+                        if hot_temp <= cold_temp {
+                            eprintln!("❌ --hot-temp must be above --cold-temp");
+                            std::process::exit(1);
+                        }
+
+                        let gradient = TempGradient {
+                            cold,
+                            hot,
+                            cold_temp,
+                            hot_temp,
+                        };
+                        let stop = std::sync::atomic::AtomicBool::new(false);
+                        let result = rgb_control::set_system_temp_reactive(
+                            &backend,
+                            gpu,
+                            gradient,
+                            std::time::Duration::from_secs(interval.max(1)),
+                            &stop,
+                            |report| {
+                                println!(
+                                    "🌈 Syncing {} RGB zone(s) to GPU {} temperature",
+                                    report.synced.len(),
+                                    gpu
+                                );
+                                for zone in &report.synced {
+                                    println!("   ✓ {} ({:?})", zone.name, zone.device_type);
+                                }
+                                for (name, reason) in &report.skipped {
+                                    println!("   ⏭ {} - {}", name, reason);
+                                }
+                                println!("   {}°C → {}°C, Ctrl+C to stop", cold_temp, hot_temp);
+                            },
+                        );
+                        if let Err(e) = result {
+                            eprintln!("❌ RGB sync failed: {}", e);
+                            std::process::exit(1);
+                        }
+                    }
                 }
             }
         },
@@ -6922,6 +10086,302 @@ fn print_formatted_output<T: serde::Serialize>(
     }
 }
 
+fn run_gamescope_action(action: GamescopeAction) {
+    match action {
+        GamescopeAction::Launch {
+            command,
+            preset,
+            width,
+            height,
+            args,
+        } => {
+            let command: Vec<String> = if args.is_empty() {
+                command
+                    .map(|c| c.split_whitespace().map(str::to_string).collect())
+                    .unwrap_or_default()
+            } else {
+                args
+            };
+            if command.is_empty() {
+                eprintln!("❌ No command given. Use --command or pass it after `--`");
+                std::process::exit(1);
+            }
+
+            let launch_preset = match preset.as_deref().map(gamescope::find_launch_preset) {
+                Some(Ok(found)) => found,
+                Some(Err(e)) => {
+                    eprintln!("❌ Failed to load launch presets: {}", e);
+                    std::process::exit(1);
+                }
+                None => None,
+            };
+
+            if let Some(mut launch_preset) = launch_preset {
+                if let Some(w) = width {
+                    launch_preset.width = w;
+                }
+                if let Some(h) = height {
+                    launch_preset.height = h;
+                }
+
+                println!(
+                    "🎯 Launching with Gamescope: {}",
+                    launch_preset.to_argv(&command).join(" ")
+                );
+                match gamescope::launch_with_preset(&launch_preset, &command) {
+                    Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+                    Err(e) => {
+                        eprintln!("❌ Launch failed: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+
+            // Start with preset config or default
+            let mut config = if let Some(preset_name) = preset {
+                match preset_name.to_lowercase().as_str() {
+                    "performance" => gamescope::GamescopePreset::Performance.to_config(),
+                    "quality" => gamescope::GamescopePreset::Quality.to_config(),
+                    "balanced" => gamescope::GamescopePreset::Balanced.to_config(),
+                    "competitive" => gamescope::GamescopePreset::Competitive.to_config(),
+                    "cinematic" => gamescope::GamescopePreset::Cinematic.to_config(),
+                    "steamdeck" => gamescope::GamescopePreset::SteamDeck.to_config(),
+                    _ => {
+                        eprintln!("⚠️  Unknown preset '{}', using default", preset_name);
+                        gamescope::GamescopeConfig::default()
+                    }
+                }
+            } else {
+                gamescope::GamescopeConfig::default()
+            };
+
+            // Override with custom width/height if provided
+            if let Some(w) = width {
+                config.width = w;
+            }
+            if let Some(h) = height {
+                config.height = h;
+            }
+
+            let mut args = vec!["gamescope".to_string()];
+            args.extend(gamescope::generate_advanced_command(
+                &config,
+                &command.join(" "),
+            ));
+            println!("🎯 Launching with Gamescope: {}", args.join(" "));
+
+            let output = std::process::Command::new(&args[0])
+                .args(&args[1..])
+                .spawn();
+
+            match output {
+                Ok(_) => println!("✅ Gamescope launched"),
+                Err(e) => eprintln!("❌ Launch failed: {}", e),
+            }
+        }
+        GamescopeAction::Presets => {
+            match gamescope::load_launch_presets() {
+                Ok(presets) => {
+                    println!(
+                        "🚀 Launch presets (nvctl gamescope launch --preset <name> -- %command%):"
+                    );
+                    for preset in presets {
+                        println!(
+                            "  📋 {}: {}x{}{} {}{}",
+                            style(&preset.name).cyan(),
+                            preset.width,
+                            preset.height,
+                            preset
+                                .refresh
+                                .map(|r| format!("@{}Hz", r))
+                                .unwrap_or_default(),
+                            preset.upscaler.label(),
+                            if preset.hdr { ", HDR" } else { "" }
+                        );
+                    }
+                }
+                Err(e) => eprintln!("❌ Failed to load launch presets: {}", e),
+            }
+            println!();
+
+            let presets = gamescope::create_steam_deck_presets();
+            println!("🎮 Available Gamescope presets:");
+            for preset in presets {
+                let (name, description) = match preset {
+                    gamescope::GamescopePreset::SteamDeckHandheld => (
+                        "Steam Deck Handheld",
+                        "Optimized for Steam Deck handheld mode",
+                    ),
+                    gamescope::GamescopePreset::SteamDeckDocked => {
+                        ("Steam Deck Docked", "Optimized for Steam Deck docked mode")
+                    }
+                    gamescope::GamescopePreset::Performance => {
+                        ("Performance", "Maximum performance settings")
+                    }
+                    gamescope::GamescopePreset::Quality => ("Quality", "High quality settings"),
+                    gamescope::GamescopePreset::Balanced => {
+                        ("Balanced", "Balanced performance and quality")
+                    }
+                    gamescope::GamescopePreset::Desktop => ("Desktop", "Desktop gaming settings"),
+                    _ => ("Custom", "Custom configuration"),
+                };
+                println!("  📋 {}: {}", style(name).cyan(), description);
+            }
+        }
+        GamescopeAction::CreatePreset {
+            name,
+            width,
+            height,
+            refresh,
+            upscaler,
+            quality,
+            hdr,
+            flags,
+        } => {
+            let upscaler = match gamescope::PresetUpscaler::parse(&upscaler, quality.as_deref()) {
+                Ok(upscaler) => upscaler,
+                Err(e) => {
+                    eprintln!("❌ {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let preset = gamescope::LaunchPreset {
+                name,
+                width,
+                height,
+                refresh,
+                upscaler,
+                hdr,
+                flags: flags
+                    .map(|f| f.split_whitespace().map(str::to_string).collect())
+                    .unwrap_or_default(),
+            };
+
+            match gamescope::save_launch_preset(&preset) {
+                Ok(()) => {
+                    println!("✅ Saved launch preset '{}'", preset.name);
+                    println!(
+                        "   Steam launch options: nvctl gamescope launch --preset {} -- %command%",
+                        preset.name
+                    );
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to save preset: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// Print per-GPU results of an `--all` apply; exits non-zero if any GPU failed
+/// Print shader cache sizes, confirm, then clear the shared or per-game caches
+fn clean_shader_caches(game: Option<String>, yes: bool) {
+    use nvcontrol::shaders;
+
+    let summary = shaders::get_cache_summary();
+    let targets: Vec<&shaders::ShaderCacheInfo> = match &game {
+        Some(query) => {
+            let query = query.to_lowercase();
+            summary
+                .games
+                .iter()
+                .filter(|g| g.app_id == query || g.name.to_lowercase().contains(&query))
+                .map(|g| &g.cache)
+                .collect()
+        }
+        None => summary.all().collect(),
+    };
+
+    if targets.is_empty() {
+        println!("No matching shader caches found");
+        return;
+    }
+
+    println!("{}", style("Shader caches").bold());
+    for cache in &summary.locations {
+        if targets.iter().any(|t| t.path == cache.path) {
+            println!(
+                "  {:<8} {:>9.1} MB  {}",
+                cache.cache_type.as_str(),
+                cache.size_mb,
+                cache.path.display()
+            );
+        }
+    }
+    for entry in &summary.games {
+        if targets.iter().any(|t| t.path == entry.cache.path) {
+            println!(
+                "  {:<8} {:>9.1} MB  {} ({})",
+                "Game", entry.cache.size_mb, entry.name, entry.app_id
+            );
+        }
+    }
+    let total: f64 = targets.iter().map(|cache| cache.size_mb).sum();
+    println!("  Total    {:>9.1} MB", total);
+
+    if !yes && !confirm_action("Delete these caches? Games rebuild them on next launch") {
+        println!("Cancelled");
+        return;
+    }
+
+    let mut freed = 0.0;
+    let mut failed = false;
+    for cache in targets {
+        match shaders::clear_cache(&cache.path) {
+            Ok(report) => {
+                freed += report.freed_mb();
+                if !report.skipped.is_empty() {
+                    println!(
+                        "⚠️  {} entries in {} could not be removed (permission denied?)",
+                        report.skipped.len(),
+                        cache.path.display()
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("❌ {}: {}", cache.path.display(), e);
+                failed = true;
+            }
+        }
+    }
+    println!("✅ Freed {:.1} MB", freed);
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn print_batch_report(report: &nvcontrol::multi_gpu::BatchApplyReport) {
+    use nvcontrol::multi_gpu::GpuApplyOutcome;
+
+    for (index, outcome) in &report.results {
+        match outcome {
+            GpuApplyOutcome::Applied => println!("   GPU {}: ✅ applied", index),
+            GpuApplyOutcome::Skipped(reason) => {
+                println!("   GPU {}: ⏭️  skipped ({})", index, reason)
+            }
+            GpuApplyOutcome::Failed(reason) => println!("   GPU {}: ❌ {}", index, reason),
+        }
+    }
+    println!("{}", report.summary());
+    if report.failed() > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn apply_fan_curve_to_all(
+    backend: &nvcontrol::nvml_backend::SharedNvmlBackend,
+    curve: &fan::FanCurve,
+) {
+    match nvcontrol::multi_gpu::apply_fan_curve_all(backend, curve) {
+        Ok(report) => print_batch_report(&report),
+        Err(e) => {
+            eprintln!("❌ Failed to enumerate GPUs: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
 fn fan_curve_points(curve: &fan::FanCurve) -> Vec<(u8, u8)> {
     curve
         .points
@@ -7005,6 +10465,166 @@ fn show_progress_bar(message: &str, duration: Duration) -> ProgressBar {
     pb
 }
 
+/// Apply only the fields of `target` that differ from the live state of
+/// `gpu_index`.
+///
+/// With `show_diff` the changes are listed with their risk level first and
+/// `None` is returned if the user declines.
+fn apply_bundle_diff(
+    target: &nvcontrol::profile_manager::ProfileBundle,
+    show_diff: bool,
+    gpu_index: u32,
+) -> nvcontrol::NvResult<Option<Vec<String>>> {
+    use nvcontrol::profile_manager::{self, ProfileManager};
+
+    let Ok(current) = ProfileManager::capture_live_bundle("live", gpu_index) else {
+        if show_diff {
+            println!("⚠️  Could not read the current state; every section will be applied");
+        }
+        return ProfileManager::apply_bundle(target, gpu_index).map(Some);
+    };
+
+    let changes = profile_manager::diff(&current, target);
+    if show_diff {
+        print_change_items(&changes);
+        if !changes.is_empty() && !confirm_action("Apply these changes?") {
+            return Ok(None);
+        }
+    }
+    ProfileManager::apply_changes(target, &changes, gpu_index).map(Some)
+}
+
+fn print_change_items(changes: &[nvcontrol::profile_manager::ChangeItem]) {
+    use nvcontrol::profile_manager::ChangeRisk;
+
+    if changes.is_empty() {
+        println!("No changes: the profile matches the current state");
+        return;
+    }
+    let high = changes
+        .iter()
+        .filter(|c| c.risk == ChangeRisk::High)
+        .count();
+    println!("📊 {} change(s), {} high risk\n", changes.len(), high);
+    for change in changes {
+        let icon = match change.risk {
+            ChangeRisk::Low => "🟢",
+            ChangeRisk::Medium => "🟡",
+            ChangeRisk::High => "🔴",
+        };
+        println!(
+            "   {} {:<30} {} → {}",
+            icon, change.field, change.old, change.new
+        );
+    }
+    println!();
+}
+
+/// `nvctl gpu reset`: refuse on the display GPU, clear processes, reset and
+/// re-initialize NVML to confirm the device came back
+fn reset_gpu_command(
+    backend: &nvcontrol::nvml_backend::SharedNvmlBackend,
+    index: u32,
+    kill: bool,
+    yes: bool,
+    force: bool,
+) {
+    use nvcontrol::error_recovery;
+
+    let check = match error_recovery::check_gpu_reset(backend.as_ref(), index) {
+        Ok(check) => check,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!(
+        "🔄 GPU {} - {} ({})",
+        check.index, check.name, check.pci_bus_id
+    );
+    if check.drives_display() {
+        eprintln!(
+            "{}",
+            style("❌ REFUSING: this GPU is driving the active display.")
+                .red()
+                .bold()
+        );
+        eprintln!("   Resetting it would crash the desktop session and every app on it.");
+        eprintln!("   Stop the display server or use a different GPU for the display first.");
+        std::process::exit(1);
+    }
+    if check.display_active.is_none() {
+        if !force {
+            eprintln!(
+                "{}",
+                style("❌ REFUSING: the driver can't tell whether this GPU drives a display.")
+                    .red()
+                    .bold()
+            );
+            eprintln!("   Re-run with --force if you're sure no display is attached.");
+            std::process::exit(1);
+        }
+        println!(
+            "{}",
+            style("⚠️  The driver can't tell whether this GPU drives a display (--force given).")
+                .yellow()
+                .bold()
+        );
+    }
+    println!(
+        "{}",
+        style("⚠️  A GPU reset is a last resort: unsaved GPU work on this device is lost.")
+            .yellow()
+    );
+
+    if !check.processes.is_empty() {
+        println!("   Processes using the GPU:");
+        for (pid, name) in &check.processes {
+            println!("     {:>7}  {}", pid, name);
+        }
+    }
+
+    // Confirm the reset itself before anything is killed
+    if !yes && !confirm_action(&format!("Reset GPU {} now?", index)) {
+        println!("Cancelled");
+        return;
+    }
+
+    if !check.processes.is_empty() {
+        if !kill && !confirm_action("Terminate these processes?") {
+            eprintln!("❌ The GPU can't be reset while processes hold it");
+            std::process::exit(1);
+        }
+        let pids: Vec<u32> = check.processes.iter().map(|(pid, _)| *pid).collect();
+        if let Err(e) = error_recovery::kill_gpu_processes(&pids) {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+        // Give them a moment to release the device
+        std::thread::sleep(std::time::Duration::from_secs(2));
+    }
+
+    if let Err(e) = error_recovery::reset_gpu(index) {
+        eprintln!("❌ {}", e);
+        std::process::exit(1);
+    }
+    if nvcontrol::gpu_safe::is_dry_run() {
+        return;
+    }
+    match error_recovery::verify_gpu_after_reset(index) {
+        Ok(name) => println!(
+            "✅ GPU {} reset; NVML re-initialized and sees {}",
+            index, name
+        ),
+        Err(e) => {
+            eprintln!("❌ Reset ran but the GPU did not come back cleanly: {}", e);
+            eprintln!("   Check `dmesg` for Xid errors; a reboot may be required.");
+            std::process::exit(1);
+        }
+    }
+}
+
 /// Interactive confirmation prompt
 fn confirm_action(message: &str) -> bool {
     print!("{} (y/N): ", style(message).yellow());
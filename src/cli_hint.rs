@@ -0,0 +1,215 @@
+//! `nvctl` equivalents of GUI actions
+//!
+//! Each [`CliCommand`] describes a change the GUI can make and formats as the
+//! command line that does the same from a terminal. The GUI attaches it to the
+//! matching control's tooltip, so the scriptable interface is learned while
+//! clicking around.
+
+use crate::display::Orientation;
+use crate::power::PowerMizerMode;
+use clap::ValueEnum;
+use std::fmt;
+
+/// A GUI action expressed as its `nvctl` invocation
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    /// `None` targets every display
+    Vibrance {
+        display: Option<usize>,
+        percent: u32,
+    },
+    Overclock {
+        gpu_offset: i32,
+        memory_offset: i32,
+        /// Percent of the default limit; `None` leaves the limit alone
+        power_limit: Option<u32>,
+        all: bool,
+    },
+    PowerLimit {
+        gpu: u32,
+        percent: u32,
+        all: bool,
+    },
+    PowerMizer {
+        gpu: u32,
+        mode: PowerMizerMode,
+    },
+    FanSpeed {
+        fan: usize,
+        percent: u8,
+    },
+    Quiet {
+        enabled: bool,
+    },
+    Rotate {
+        connector: String,
+        orientation: Orientation,
+    },
+    Scale {
+        connector: String,
+        factor: f32,
+    },
+}
+
+impl CliCommand {
+    /// Arguments after `nvctl`
+    pub fn args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        match self {
+            Self::Vibrance { display, percent } => {
+                push(&mut args, &["vibrance", "set", &percent.to_string()]);
+                if let Some(display) = display {
+                    push(&mut args, &["--display", &display.to_string()]);
+                }
+            }
+            Self::Overclock {
+                gpu_offset,
+                memory_offset,
+                power_limit,
+                all,
+            } => {
+                push(&mut args, &["overclock", "apply"]);
+                args.extend(offset_arg("--gpu-offset", *gpu_offset));
+                args.extend(offset_arg("--memory-offset", *memory_offset));
+                if let Some(limit) = power_limit {
+                    push(&mut args, &["--power-limit", &limit.to_string()]);
+                }
+                if *all {
+                    push(&mut args, &["--all"]);
+                }
+            }
+            Self::PowerLimit { gpu, percent, all } => {
+                push(
+                    &mut args,
+                    &["power", "limit", "set", &format!("{percent}%")],
+                );
+                if *all {
+                    push(&mut args, &["--all"]);
+                } else if *gpu != 0 {
+                    push(&mut args, &["--gpu", &gpu.to_string()]);
+                }
+            }
+            Self::PowerMizer { gpu, mode } => {
+                let name = mode
+                    .to_possible_value()
+                    .map(|value| value.get_name().to_string())
+                    .unwrap_or_default();
+                push(&mut args, &["power", "mode", &name]);
+                if *gpu != 0 {
+                    push(&mut args, &["--gpu", &gpu.to_string()]);
+                }
+            }
+            Self::FanSpeed { fan, percent } => {
+                push(
+                    &mut args,
+                    &["fan", "set", &fan.to_string(), &percent.to_string()],
+                );
+            }
+            Self::Quiet { enabled } => {
+                push(&mut args, &["quiet", if *enabled { "on" } else { "off" }]);
+            }
+            Self::Rotate {
+                connector,
+                orientation,
+            } => push(
+                &mut args,
+                &["display", "rotate", connector, orientation.name()],
+            ),
+            Self::Scale { connector, factor } => push(
+                &mut args,
+                &["display", "scale", connector, &format!("{factor}")],
+            ),
+        }
+        args
+    }
+
+    /// Tooltip text for the control that performs this action
+    pub fn hover_text(&self) -> String {
+        format!("CLI: {self}\nRight-click to copy")
+    }
+}
+
+fn push(args: &mut Vec<String>, parts: &[&str]) {
+    args.extend(parts.iter().map(|part| part.to_string()));
+}
+
+/// `--flag value`, joined with `=` when negative so the value isn't taken for
+/// another flag
+fn offset_arg(flag: &str, value: i32) -> Vec<String> {
+    if value < 0 {
+        vec![format!("{flag}={value}")]
+    } else {
+        vec![flag.to_string(), value.to_string()]
+    }
+}
+
+/// Quote an argument for a POSIX shell when it needs it
+fn shell_quote(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.,:/%+=@".contains(c));
+    if plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+impl fmt::Display for CliCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nvctl")?;
+        for arg in self.args() {
+            write!(f, " {}", shell_quote(&arg))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_format_as_nvctl_invocations() {
+        let vibrance = CliCommand::Vibrance {
+            display: Some(0),
+            percent: 150,
+        };
+        assert_eq!(vibrance.to_string(), "nvctl vibrance set 150 --display 0");
+
+        let overclock = CliCommand::Overclock {
+            gpu_offset: -50,
+            memory_offset: 500,
+            power_limit: Some(90),
+            all: true,
+        };
+        assert_eq!(
+            overclock.to_string(),
+            "nvctl overclock apply --gpu-offset=-50 --memory-offset 500 --power-limit 90 --all"
+        );
+
+        let power = CliCommand::PowerLimit {
+            gpu: 1,
+            percent: 85,
+            all: false,
+        };
+        assert_eq!(power.to_string(), "nvctl power limit set 85% --gpu 1");
+
+        let mode = CliCommand::PowerMizer {
+            gpu: 0,
+            mode: PowerMizerMode::PreferMaxPerformance,
+        };
+        assert_eq!(mode.to_string(), "nvctl power mode max-performance");
+
+        let quiet = CliCommand::Quiet { enabled: true };
+        assert_eq!(quiet.to_string(), "nvctl quiet on");
+
+        let scale = CliCommand::Scale {
+            connector: "DP 1".to_string(),
+            factor: 1.25,
+        };
+        assert_eq!(scale.to_string(), "nvctl display scale 'DP 1' 1.25");
+        assert!(scale.hover_text().starts_with("CLI: nvctl display scale"));
+    }
+}
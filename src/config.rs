@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Current state file version for migration tracking
 const STATE_VERSION: u32 = 1;
 
+/// Current `config.toml` layout version
+pub const CONFIG_VERSION: u32 = 1;
+
 /// TUI session state that persists across restarts
 #[derive(Serialize, Deserialize, Clone)]
 pub struct TuiSessionState {
@@ -199,8 +202,11 @@ impl TuiSessionState {
     }
 }
 
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize)]
 pub struct Config {
+    /// Layout version; files written before versioning read as 0
+    #[serde(default)]
+    pub config_version: u32,
     pub vibrance_levels: Vec<i16>,
     pub hdr_enabled: bool,
     pub selected_icc_profile: String,
@@ -212,6 +218,140 @@ pub struct Config {
     pub osd_position: String,
     #[serde(default)]
     pub driver: DriverConfig,
+    /// Reset to stock when an overclock misbehaves (opt-out)
+    #[serde(default = "default_true")]
+    pub oc_watchdog_enabled: bool,
+    /// Temperature (°C) at which the overclock watchdog resets to stock
+    #[serde(default = "default_oc_watchdog_max_temp")]
+    pub oc_watchdog_max_temp: u32,
+    /// Print GPU changes instead of applying them (same as `nvctl --dry-run`)
+    #[serde(default)]
+    pub dry_run: bool,
+    /// TUI dashboard tabs to show (e.g. "overview", "fan-control"); empty shows all
+    #[serde(default)]
+    pub visible_tabs: Vec<String>,
+    /// Color temperature and gamma per display index, re-applied at startup
+    #[serde(default)]
+    pub display_color: Vec<crate::display::DisplayColorSettings>,
+    /// Re-apply connector profiles on display hotplug while the GUI runs
+    /// (`nvctl daemon` always does)
+    #[serde(default)]
+    pub hotplug_reapply: bool,
+    /// Samples kept for TUI history sparklines (one per second)
+    #[serde(default = "default_sparkline_samples")]
+    pub sparkline_samples: usize,
+    /// TUI key overrides: action name to key specs, e.g. `top = ["gg", "Home"]`
+    #[serde(default)]
+    pub keybindings: std::collections::BTreeMap<String, Vec<String>>,
+    /// Threshold alerts checked on every poll (`nvctl alert add`)
+    #[serde(default)]
+    pub alert_rules: Vec<crate::monitoring::AlertRule>,
+    /// Fade between vibrance presets in the GUI instead of jumping
+    #[serde(default)]
+    pub animate_vibrance: bool,
+    /// Shift vibrance with GPU load while the GUI runs (opt-in)
+    #[serde(default)]
+    pub load_reactive_vibrance: crate::vibrance::LoadReactiveSettings,
+    /// Nickname and accent color per GPU, keyed by PCI bus id
+    #[serde(default)]
+    pub gpu_labels: std::collections::BTreeMap<String, crate::multi_gpu::GpuLabel>,
+    /// Log file verbosity (`error`, `warn`, `info`, `debug`, `trace`); empty means `info`
+    #[serde(default)]
+    pub log_level: String,
+    /// Bearer token for `nvctl serve --allow-control`; control stays off while empty
+    #[serde(default)]
+    pub api_token: String,
+    /// Guarded replay of saved OC/fan settings at login
+    #[serde(default)]
+    pub startup_apply: StartupApplyConfig,
+    /// Caps applied by `nvctl quiet on` and the GUI quiet toggle
+    #[serde(default)]
+    pub quiet_mode: QuietModeConfig,
+}
+
+/// When and how saved settings are re-applied at login (`nvctl daemon`,
+/// `nvctl apply-saved --startup`)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct StartupApplyConfig {
+    /// Apply saved settings when `nvctl daemon` starts
+    pub enabled: bool,
+    /// Seconds to let the desktop settle before touching the GPU
+    pub delay_secs: u64,
+}
+
+impl Default for StartupApplyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            delay_secs: 30,
+        }
+    }
+}
+
+/// Limits quiet mode holds the GPUs to
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct QuietModeConfig {
+    /// Highest fan duty cycle in percent
+    pub fan_max_pct: u8,
+    /// Power limit as a percentage of each GPU's default
+    pub power_limit_pct: u32,
+    /// Pin the core clock at this many MHz; unset leaves clocks alone
+    pub max_gpu_clock_mhz: Option<u32>,
+}
+
+impl Default for QuietModeConfig {
+    fn default() -> Self {
+        Self {
+            fan_max_pct: 45,
+            power_limit_pct: 70,
+            max_gpu_clock_mhz: None,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_oc_watchdog_max_temp() -> u32 {
+    90
+}
+
+fn default_sparkline_samples() -> usize {
+    120
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            config_version: CONFIG_VERSION,
+            vibrance_levels: Vec::new(),
+            hdr_enabled: false,
+            selected_icc_profile: String::new(),
+            theme: String::new(),
+            osd_enabled: false,
+            osd_position: String::new(),
+            driver: DriverConfig::default(),
+            oc_watchdog_enabled: default_true(),
+            oc_watchdog_max_temp: default_oc_watchdog_max_temp(),
+            dry_run: false,
+            visible_tabs: Vec::new(),
+            display_color: Vec::new(),
+            hotplug_reapply: false,
+            sparkline_samples: default_sparkline_samples(),
+            keybindings: Default::default(),
+            alert_rules: Vec::new(),
+            animate_vibrance: false,
+            load_reactive_vibrance: Default::default(),
+            gpu_labels: Default::default(),
+            log_level: String::new(),
+            api_token: String::new(),
+            startup_apply: StartupApplyConfig::default(),
+            quiet_mode: QuietModeConfig::default(),
+        }
+    }
 }
 
 /// Driver-related configuration for DKMS setup and builds
@@ -223,6 +363,9 @@ pub struct DriverConfig {
     /// DKMS configuration
     #[serde(default)]
     pub dkms: DriverDkmsConfig,
+    /// Ask download.nvidia.com for the latest driver on startup (opt-in)
+    #[serde(default)]
+    pub check_latest_online: bool,
 }
 
 /// DKMS-specific driver configuration
@@ -235,32 +378,110 @@ pub struct DriverDkmsConfig {
 
 impl Config {
     pub fn load() -> Self {
-        let config_path = Self::config_path();
-        if config_path.exists() {
-            match fs::read_to_string(&config_path) {
-                Ok(content) => match toml::from_str(&content) {
-                    Ok(config) => return config,
-                    Err(e) => eprintln!("Failed to parse config: {e}"),
-                },
-                Err(e) => eprintln!("Failed to read config: {e}"),
+        Self::load_from(&Self::config_path())
+    }
+
+    /// Load a config file, migrating older layouts in place.
+    ///
+    /// Migration only runs when the file's `config_version` is older than
+    /// [`CONFIG_VERSION`]; the result is written back so it happens once,
+    /// and an existing `config.v<N>.bak` is never overwritten. A file that
+    /// can't be parsed is kept as `config.bak` and defaults are used, so the
+    /// next save never destroys the user's only copy.
+    fn load_from(config_path: &Path) -> Self {
+        if !config_path.exists() {
+            return Self::default();
+        }
+        let content = match fs::read_to_string(config_path) {
+            Ok(content) => content,
+            Err(e) => {
+                eprintln!("Failed to read config: {e}");
+                return Self::default();
+            }
+        };
+
+        let parsed = content.parse::<toml::Table>().and_then(|mut table| {
+            let from_version = table
+                .get("config_version")
+                .and_then(|v| v.as_integer())
+                .unwrap_or(0);
+            let changes = if from_version < i64::from(CONFIG_VERSION) {
+                migrate(&mut table)
+            } else {
+                Vec::new()
+            };
+            toml::Value::Table(table)
+                .try_into::<Config>()
+                .map(|config| (config, changes, from_version))
+        });
+
+        match parsed {
+            Ok((config, changes, _)) if changes.is_empty() => config,
+            Ok((config, changes, from_version)) => {
+                let backup = config_path.with_extension(format!("v{from_version}.bak"));
+                // Keep the first backup for this version; a later copy would
+                // only hold a file that was already partly migrated
+                let backed_up = backup.exists()
+                    || match copy_private(config_path, &backup) {
+                        Ok(()) => true,
+                        Err(e) => {
+                            eprintln!("Failed to back up config before migration: {e}");
+                            false
+                        }
+                    };
+                // Without a backup, migrate in memory only
+                if backed_up && config.save_to(config_path) {
+                    eprintln!(
+                        "Migrated config from v{from_version} to v{CONFIG_VERSION} ({}); previous file kept at {}",
+                        changes.join(", "),
+                        backup.display()
+                    );
+                }
+                config
+            }
+            Err(e) => {
+                eprintln!("Failed to parse config: {e}");
+                let backup = config_path.with_file_name("config.bak");
+                // Don't rewrite the backup on every load of the same broken file
+                let kept = if fs::read_to_string(&backup).is_ok_and(|kept| kept == content) {
+                    Ok(())
+                } else {
+                    copy_private(config_path, &backup)
+                };
+                match kept {
+                    Ok(()) => eprintln!(
+                        "Using default settings; broken config kept at {}",
+                        backup.display()
+                    ),
+                    Err(e) => eprintln!("Failed to back up broken config: {e}"),
+                }
+                Self::default()
             }
         }
-        Self::default()
     }
 
     pub fn save(&self) {
-        let config_path = Self::config_path();
+        self.save_to(&Self::config_path());
+    }
+
+    /// Write the config to `config_path`, returning whether it was saved
+    fn save_to(&self, config_path: &Path) -> bool {
         if let Some(parent) = config_path.parent() {
             let _ = fs::create_dir_all(parent);
         }
 
         match toml::to_string_pretty(self) {
-            Ok(content) => {
-                if let Err(e) = fs::write(&config_path, content) {
+            Ok(content) => match write_private(config_path, &content) {
+                Ok(()) => true,
+                Err(e) => {
                     eprintln!("Failed to save config: {e}");
+                    false
                 }
+            },
+            Err(e) => {
+                eprintln!("Failed to serialize config: {e}");
+                false
             }
-            Err(e) => eprintln!("Failed to serialize config: {e}"),
         }
     }
 
@@ -273,6 +494,65 @@ impl Config {
     }
 }
 
+/// Write `content` readable by the owner only, since `config.toml` can hold
+/// the API bearer token. Tightens the mode of an existing file as well.
+fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    file.write_all(content.as_bytes())
+}
+
+/// Copy `from` to `to` with owner-only permissions
+fn copy_private(from: &Path, to: &Path) -> std::io::Result<()> {
+    write_private(to, &fs::read_to_string(from)?)
+}
+
+/// Upgrade a raw `config.toml` table to [`CONFIG_VERSION`].
+///
+/// Returns a description of each change; empty when the file was current.
+/// Each step works on the raw table so keys can be renamed or reshaped
+/// before serde sees them.
+pub fn migrate(table: &mut toml::Table) -> Vec<String> {
+    let version = table
+        .get("config_version")
+        .and_then(|v| v.as_integer())
+        .unwrap_or(0);
+    let mut changes = Vec::new();
+
+    if version < 1 {
+        // v0 files could predate keys that have no serde default
+        let defaults: [(&str, toml::Value); 3] = [
+            ("vibrance_levels", toml::Value::Array(Vec::new())),
+            ("hdr_enabled", toml::Value::Boolean(false)),
+            ("selected_icc_profile", toml::Value::String(String::new())),
+        ];
+        for (key, value) in defaults {
+            if !table.contains_key(key) {
+                table.insert(key.to_string(), value);
+                changes.push(format!("added missing `{key}`"));
+            }
+        }
+        changes.push("stamped config_version".to_string());
+    }
+    // Later layouts add steps here: if version < 2 { ... }
+
+    if version < i64::from(CONFIG_VERSION) {
+        table.insert(
+            "config_version".to_string(),
+            toml::Value::Integer(CONFIG_VERSION.into()),
+        );
+    }
+    changes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +566,93 @@ mod tests {
         assert_eq!(state.memory_offset, 0);
     }
 
+    #[test]
+    fn test_config_watchdog_defaults_for_old_files() {
+        let old_config_toml = r#"
+vibrance_levels = [0]
+hdr_enabled = false
+selected_icc_profile = ""
+"#;
+
+        let config: Config = toml::from_str(old_config_toml).unwrap();
+        assert!(config.oc_watchdog_enabled);
+        assert_eq!(config.oc_watchdog_max_temp, 90);
+    }
+
+    #[test]
+    fn test_config_migration_from_unversioned() {
+        let mut table: toml::Table = "theme = \"dracula\"\nhdr_enabled = true".parse().unwrap();
+        let changes = migrate(&mut table);
+        assert!(changes.iter().any(|c| c.contains("vibrance_levels")));
+        assert_eq!(
+            table["config_version"].as_integer(),
+            Some(CONFIG_VERSION.into())
+        );
+        assert_eq!(table["hdr_enabled"].as_bool(), Some(true));
+
+        // Already current: nothing to do
+        assert!(migrate(&mut table).is_empty());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "theme = \"dracula\"\n").unwrap();
+        let config = Config::load_from(&path);
+        assert_eq!(config.theme, "dracula");
+        assert_eq!(config.config_version, CONFIG_VERSION);
+        assert!(dir.path().join("config.v0.bak").exists());
+        assert!(
+            fs::read_to_string(&path)
+                .unwrap()
+                .contains("config_version = 1")
+        );
+
+        // Loading again is a no-op: nothing is migrated or backed up twice
+        let saved = fs::read_to_string(&path).unwrap();
+        fs::write(dir.path().join("config.v0.bak"), "original").unwrap();
+        Config::load_from(&path);
+        assert_eq!(fs::read_to_string(&path).unwrap(), saved);
+
+        // An existing backup for a version is kept, not overwritten
+        fs::write(&path, "theme = \"nord\"\n").unwrap();
+        assert_eq!(Config::load_from(&path).theme, "nord");
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.v0.bak")).unwrap(),
+            "original"
+        );
+    }
+
+    #[test]
+    fn test_config_saved_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let config = Config {
+            api_token: "secret".to_string(),
+            ..Config::default()
+        };
+        config.save_to(&path);
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_broken_config_is_preserved() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "theme = [unterminated").unwrap();
+
+        let config = Config::load_from(&path);
+        assert!(config.theme.is_empty());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.bak")).unwrap(),
+            "theme = [unterminated"
+        );
+    }
+
     #[test]
     fn test_tui_state_migration_from_v0() {
         // Simulate v0.7.5 state (no version field)
@@ -86,6 +86,20 @@ pub struct RuntimeDoctorReport {
     pub smoke_test_command: Option<String>,
 }
 
+/// A running container as reported by `docker ps` / `podman ps`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RunningContainer {
+    pub runtime: String,
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub status: String,
+    /// Started with `--gpus` (device requests) or an NVIDIA CDI device
+    pub gpu_enabled: bool,
+    /// GPU memory used by the container's processes, if any are on the GPU
+    pub gpu_memory_mb: Option<u64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VolumeMount {
     pub source: String,
@@ -605,6 +619,113 @@ impl NvContainerRuntime {
         Ok(Vec::new()) // Placeholder
     }
 
+    /// List running Docker/Podman containers with their GPU access.
+    /// Errors if neither Docker nor Podman is installed.
+    pub fn list_containers(&self) -> NvResult<Vec<RunningContainer>> {
+        let runtimes: Vec<&str> = self
+            .supported_runtimes
+            .iter()
+            .filter_map(|runtime| match runtime {
+                ContainerRuntime::Docker => Some("docker"),
+                ContainerRuntime::Podman => Some("podman"),
+                _ => None,
+            })
+            .collect();
+
+        if runtimes.is_empty() {
+            return Err(NvControlError::ContainerOperationFailed(
+                "Neither Docker nor Podman is installed".to_string(),
+            ));
+        }
+
+        let gpu_memory = Self::gpu_memory_by_cgroup();
+        let mut containers = Vec::new();
+        let mut last_error = None;
+
+        for cli in &runtimes {
+            match Self::list_runtime_containers(cli) {
+                Ok(mut listed) => {
+                    for container in &mut listed {
+                        container.gpu_memory_mb = gpu_memory
+                            .iter()
+                            .filter(|(cgroup, _)| cgroup.contains(&container.id))
+                            .map(|(_, bytes)| bytes / (1024 * 1024))
+                            .reduce(|a, b| a + b);
+                    }
+                    containers.append(&mut listed);
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        match last_error {
+            Some(e) if containers.is_empty() => Err(e),
+            _ => Ok(containers),
+        }
+    }
+
+    fn list_runtime_containers(cli: &str) -> NvResult<Vec<RunningContainer>> {
+        let output = Command::new(cli)
+            .args([
+                "ps",
+                "--no-trunc",
+                "--format",
+                "{{.ID}}\t{{.Names}}\t{{.Image}}\t{{.Status}}",
+            ])
+            .output()
+            .map_err(|e| NvControlError::CommandFailed(format!("{} ps failed: {}", cli, e)))?;
+
+        if !output.status.success() {
+            return Err(NvControlError::ContainerOperationFailed(format!(
+                "{} ps failed: {}",
+                cli,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let mut containers = parse_ps_output(cli, &String::from_utf8_lossy(&output.stdout));
+        if containers.is_empty() {
+            return Ok(containers);
+        }
+
+        let ids: Vec<&str> = containers.iter().map(|c| c.id.as_str()).collect();
+        let inspect = Command::new(cli).arg("inspect").args(&ids).output();
+        if let Ok(inspect) = inspect {
+            let gpu_ids = parse_gpu_container_ids(&String::from_utf8_lossy(&inspect.stdout));
+            for container in &mut containers {
+                container.gpu_enabled = gpu_ids.iter().any(|id| id.starts_with(&container.id));
+            }
+        }
+
+        Ok(containers)
+    }
+
+    /// (cgroup path, GPU memory bytes) for every process using an NVIDIA GPU
+    fn gpu_memory_by_cgroup() -> Vec<(String, u64)> {
+        let backend = crate::nvml_backend::create_real_backend();
+        let count = backend.device_count().unwrap_or(0);
+        let mut usage = Vec::new();
+
+        for index in 0..count {
+            let processes = backend
+                .get_running_compute_processes(index)
+                .unwrap_or_default()
+                .into_iter()
+                .chain(
+                    backend
+                        .get_running_graphics_processes(index)
+                        .unwrap_or_default(),
+                );
+            for process in processes {
+                if let Ok(cgroup) = fs::read_to_string(format!("/proc/{}/cgroup", process.pid)) {
+                    usage.push((cgroup, process.used_gpu_memory_bytes.unwrap_or(0)));
+                }
+            }
+        }
+
+        usage
+    }
+
     /// Get container status by name or ID
     pub fn get_container_status(
         &self,
@@ -1099,7 +1220,7 @@ impl Default for NvContainerRuntime {
     fn default() -> Self {
         Self::new().unwrap_or(NvContainerRuntime {
             version: "1.0.0-nvcontrol".to_string(),
-            supported_runtimes: Vec::new(),
+            supported_runtimes: Self::detect_container_runtimes().unwrap_or_default(),
             gpu_devices: Vec::new(),
             config_path: PathBuf::from("/tmp/nvcontrol"),
         })
@@ -1121,10 +1242,78 @@ impl Default for ContainerGpuConfig {
     }
 }
 
+/// Parse tab-separated `ps --format "{{.ID}}\t{{.Names}}\t{{.Image}}\t{{.Status}}"` output
+fn parse_ps_output(runtime: &str, output: &str) -> Vec<RunningContainer> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let id = fields.next()?.trim();
+            if id.is_empty() {
+                return None;
+            }
+            Some(RunningContainer {
+                runtime: runtime.to_string(),
+                id: id.to_string(),
+                name: fields.next().unwrap_or_default().trim().to_string(),
+                image: fields.next().unwrap_or_default().trim().to_string(),
+                status: fields.next().unwrap_or_default().trim().to_string(),
+                gpu_enabled: false,
+                gpu_memory_mb: None,
+            })
+        })
+        .collect()
+}
+
+/// IDs of containers in `inspect` JSON that requested GPUs, either with
+/// `--gpus` (DeviceRequests) or an NVIDIA device/CDI mapping
+fn parse_gpu_container_ids(inspect_json: &str) -> Vec<String> {
+    let Ok(serde_json::Value::Array(entries)) = serde_json::from_str(inspect_json) else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .filter(|entry| {
+            let host = &entry["HostConfig"];
+            let has_requests = host["DeviceRequests"]
+                .as_array()
+                .is_some_and(|r| !r.is_empty());
+            let has_nvidia_device = host["Devices"]
+                .as_array()
+                .is_some_and(|devices| devices.iter().any(|d| d.to_string().contains("nvidia")));
+            has_requests || has_nvidia_device
+        })
+        .filter_map(|entry| entry["Id"].as_str().map(str::to_string))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_ps_output() {
+        let output = "abc123\tollama\tollama/ollama:latest\tUp 2 hours\n\ndef456\tweb\tnginx\tUp 5 minutes\n";
+        let containers = parse_ps_output("docker", output);
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].name, "ollama");
+        assert_eq!(containers[0].image, "ollama/ollama:latest");
+        assert_eq!(containers[1].status, "Up 5 minutes");
+        assert!(!containers[0].gpu_enabled);
+    }
+
+    #[test]
+    fn test_parse_gpu_container_ids() {
+        let json = r#"[
+            {"Id": "abc123", "HostConfig": {"DeviceRequests": [{"Driver": "nvidia", "Count": -1}]}},
+            {"Id": "def456", "HostConfig": {"DeviceRequests": null, "Devices": []}},
+            {"Id": "ghi789", "HostConfig": {"Devices": [{"PathOnHost": "/dev/nvidia0"}]}}
+        ]"#;
+        assert_eq!(parse_gpu_container_ids(json), vec!["abc123", "ghi789"]);
+        assert!(parse_gpu_container_ids("not json").is_empty());
+    }
+
     #[test]
     fn test_runtime_detection() {
         let runtime = NvContainerRuntime::new();
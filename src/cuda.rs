@@ -1,4 +1,5 @@
 use crate::NvResult;
+use crate::nvml_backend::NvmlBackend;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::process::Command;
@@ -8,6 +9,10 @@ use std::time::Duration;
 pub struct CudaInfo {
     pub version: String,
     pub driver_version: String,
+    /// Highest CUDA version the driver supports, from NVML
+    #[serde(default)]
+    pub cuda_driver_version: Option<String>,
+    /// Installed CUDA runtime (libcudart) version, if any
     pub runtime_version: Option<String>,
     pub devices: Vec<CudaDevice>,
     pub toolkit_path: Option<PathBuf>,
@@ -21,6 +26,10 @@ pub struct CudaDevice {
     pub memory_total: u64,
     pub memory_free: u64,
     pub cuda_cores: Option<u32>,
+    #[serde(default)]
+    pub sm_count: Option<u32>,
+    #[serde(default)]
+    pub max_threads_per_block: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,25 +96,43 @@ pub fn is_cuda_available() -> bool {
         .unwrap_or(false)
 }
 
+/// Every GPU from compute capability 2.0 on allows 1024 threads per block
+const MAX_THREADS_PER_BLOCK: u32 = 1024;
+
 /// Get comprehensive CUDA information
 pub fn get_cuda_info() -> NvResult<CudaInfo> {
+    get_cuda_info_with(crate::nvml_backend::create_real_backend().as_ref())
+}
+
+/// Get CUDA information, taking driver and device details from NVML where
+/// available so they're reported even without the CUDA runtime installed
+pub fn get_cuda_info_with(backend: &dyn NvmlBackend) -> NvResult<CudaInfo> {
     let mut info = CudaInfo {
         version: "Unknown".to_string(),
         driver_version: "Unknown".to_string(),
+        cuda_driver_version: None,
         runtime_version: None,
         devices: Vec::new(),
         toolkit_path: None,
     };
 
-    // Get CUDA driver version via nvidia-smi
-    if let Ok(output) = Command::new("nvidia-smi")
-        .args(["--query-gpu=driver_version"])
-        .arg("--format=csv,noheader,nounits")
-        .output()
+    if let Ok(version) = backend.get_driver_version() {
+        info.driver_version = version;
+    }
+    info.cuda_driver_version = backend
+        .get_cuda_driver_version()
+        .ok()
+        .map(|(major, minor)| format!("{}.{}", major, minor));
+
+    // Fall back to nvidia-smi for the driver version
+    if info.driver_version == "Unknown"
+        && let Ok(output) = Command::new("nvidia-smi")
+            .args(["--query-gpu=driver_version"])
+            .arg("--format=csv,noheader,nounits")
+            .output()
+        && output.status.success()
     {
-        if output.status.success() {
-            info.driver_version = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        }
+        info.driver_version = String::from_utf8_lossy(&output.stdout).trim().to_string();
     }
 
     // Get CUDA toolkit version
@@ -121,14 +148,101 @@ pub fn get_cuda_info() -> NvResult<CudaInfo> {
     }
 
     // Get CUDA devices information
-    info.devices = get_cuda_devices()?;
+    info.devices = get_nvml_cuda_devices(backend);
+    if info.devices.is_empty() {
+        info.devices = get_cuda_devices()?;
+    }
 
     // Find CUDA toolkit path
     info.toolkit_path = find_cuda_toolkit_path();
+    info.runtime_version = find_cuda_runtime_version(info.toolkit_path.as_deref());
 
     Ok(info)
 }
 
+fn get_nvml_cuda_devices(backend: &dyn NvmlBackend) -> Vec<CudaDevice> {
+    let count = backend.device_count().unwrap_or(0);
+    (0..count)
+        .filter_map(|id| {
+            let name = backend.get_name(id).ok()?;
+            let (used, total) = backend.get_memory_info(id).unwrap_or((0, 0));
+            let capability = backend.get_compute_capability(id).ok();
+            let cuda_cores = backend.get_cuda_cores(id).ok();
+            Some(CudaDevice {
+                id,
+                compute_capability: capability
+                    .map(|(major, minor)| format!("{}.{}", major, minor))
+                    .unwrap_or_else(|| estimate_compute_capability(&name)),
+                memory_total: total,
+                memory_free: total.saturating_sub(used),
+                cuda_cores,
+                sm_count: cuda_cores
+                    .zip(capability)
+                    .and_then(|(cores, (major, minor))| {
+                        cores_per_sm(major, minor).map(|per_sm| cores / per_sm)
+                    }),
+                max_threads_per_block: capability.map(|_| MAX_THREADS_PER_BLOCK),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// CUDA cores per streaming multiprocessor for a compute capability
+fn cores_per_sm(major: u32, minor: u32) -> Option<u32> {
+    match (major, minor) {
+        (3, _) => Some(192),
+        (5, _) => Some(128),
+        (6, 0) => Some(64),
+        (6, _) => Some(128),
+        (7, _) => Some(64),
+        (8, 0) => Some(64),
+        (8, _) | (9, _) | (10, _) | (12, _) => Some(128),
+        _ => None,
+    }
+}
+
+/// Version of the newest libcudart found in the toolkit or system library paths
+fn find_cuda_runtime_version(toolkit_path: Option<&std::path::Path>) -> Option<String> {
+    let mut dirs: Vec<PathBuf> = toolkit_path
+        .map(|path| vec![path.join("lib64"), path.join("lib")])
+        .unwrap_or_default();
+    dirs.extend(
+        [
+            "/usr/lib",
+            "/usr/lib64",
+            "/usr/lib/x86_64-linux-gnu",
+            "/opt/cuda/lib64",
+            "/usr/local/cuda/lib64",
+        ]
+        .iter()
+        .map(PathBuf::from),
+    );
+
+    dirs.iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            cudart_version_from_filename(&name)
+        })
+        .max_by_key(|version| {
+            version
+                .split('.')
+                .map(|part| part.parse::<u32>().unwrap_or(0))
+                .collect::<Vec<_>>()
+        })
+}
+
+/// "libcudart.so.12.4.127" -> "12.4"
+fn cudart_version_from_filename(name: &str) -> Option<String> {
+    let version = name.strip_prefix("libcudart.so.")?;
+    let mut parts = version.split('.');
+    let major: u32 = parts.next()?.parse().ok()?;
+    let minor: u32 = parts.next()?.parse().ok()?;
+    Some(format!("{}.{}", major, minor))
+}
+
 fn extract_cuda_version(version_line: &str) -> Option<String> {
     // Extract version from line like "Cuda compilation tools, release 12.0, V12.0.76"
     if let Some(start) = version_line.find("release ") {
@@ -181,6 +295,8 @@ fn parse_device_line(line: &str) -> Option<CudaDevice> {
             memory_total,
             memory_free,
             cuda_cores: estimate_cuda_cores(&name),
+            sm_count: None,
+            max_threads_per_block: None,
         })
     } else {
         None
@@ -389,6 +505,14 @@ pub fn print_cuda_doctor() -> NvResult<()> {
 pub fn print_cuda_info_summary(info: &CudaInfo) {
     println!("CUDA:");
     println!("  Driver version: {}", info.driver_version);
+    println!(
+        "  CUDA driver API: {}",
+        info.cuda_driver_version.as_deref().unwrap_or("unknown")
+    );
+    match &info.runtime_version {
+        Some(version) => println!("  Runtime version: {}", version),
+        None => println!("  Runtime version: not installed (driver-level info from NVML only)"),
+    }
     println!("  Toolkit version: {}", info.version);
     println!(
         "  Toolkit path: {}",
@@ -407,6 +531,22 @@ pub fn print_cuda_info_summary(info: &CudaInfo) {
             bytes_to_gib(device.memory_total),
             bytes_to_gib(device.memory_free)
         );
+        let unknown = || "?".to_string();
+        println!(
+            "      SMs: {} | CUDA cores: {} | max threads/block: {}",
+            device
+                .sm_count
+                .map(|n| n.to_string())
+                .unwrap_or_else(unknown),
+            device
+                .cuda_cores
+                .map(|n| n.to_string())
+                .unwrap_or_else(unknown),
+            device
+                .max_threads_per_block
+                .map(|n| n.to_string())
+                .unwrap_or_else(unknown)
+        );
     }
 }
 
@@ -791,11 +931,30 @@ mod tests {
         assert_eq!(estimate_compute_capability("Unknown GPU"), "Unknown");
     }
 
+    #[test]
+    fn test_cuda_info_from_nvml() {
+        use crate::nvml_backend::MockNvmlBackend;
+
+        let info = get_cuda_info_with(&MockNvmlBackend::single_gpu()).unwrap();
+        assert_eq!(info.cuda_driver_version.as_deref(), Some("12.7"));
+        let device = &info.devices[0];
+        assert_eq!(device.compute_capability, "8.9");
+        assert_eq!(device.sm_count, Some(76)); // 9728 cores / 128 per SM
+        assert_eq!(device.max_threads_per_block, Some(1024));
+
+        assert_eq!(
+            cudart_version_from_filename("libcudart.so.12.4.127").as_deref(),
+            Some("12.4")
+        );
+        assert_eq!(cudart_version_from_filename("libcudart.so"), None);
+    }
+
     #[test]
     fn test_ai_recommendations_do_not_require_gpu() {
         let info = CudaInfo {
             version: "Unknown".to_string(),
             driver_version: "Unknown".to_string(),
+            cuda_driver_version: None,
             runtime_version: None,
             devices: Vec::new(),
             toolkit_path: None,
@@ -814,6 +973,7 @@ mod tests {
         let info = CudaInfo {
             version: "Unknown".to_string(),
             driver_version: "Unknown".to_string(),
+            cuda_driver_version: None,
             runtime_version: None,
             devices: Vec::new(),
             toolkit_path: None,
@@ -170,71 +170,193 @@ pub fn list_displays() -> Vec<DisplayInfo> {
     ]
 }
 
-/// Set gamma for a display
-/// Gamma values typically range from 0.5 to 3.0, with 1.0 being neutral
-pub fn set_gamma(display_id: usize, gamma: f32) -> crate::NvResult<()> {
-    use std::process::Command;
+/// Neutral white point; color temperature adjustments are relative to it
+pub const NEUTRAL_KELVIN: u32 = 6500;
+/// Warmest and coolest color temperatures accepted
+pub const KELVIN_RANGE: std::ops::RangeInclusive<u32> = 1900..=10000;
+/// Per-channel gamma limits
+pub const GAMMA_RANGE: std::ops::RangeInclusive<f32> = 0.5..=3.0;
+
+/// Color temperature and per-channel gamma for one display.
+///
+/// These feed the output gamma ramp, a separate stage from digital vibrance
+/// (a saturation attribute), so the two never overwrite each other.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DisplayColorSettings {
+    pub kelvin: u32,
+    /// Red, green, blue gamma with 1.0 neutral
+    pub gamma: [f32; 3],
+}
 
-    // Clamp gamma to reasonable range
-    let gamma = gamma.clamp(0.5, 3.0);
+impl Default for DisplayColorSettings {
+    fn default() -> Self {
+        Self {
+            kelvin: NEUTRAL_KELVIN,
+            gamma: [1.0; 3],
+        }
+    }
+}
 
-    // Try nvidia-settings first (works on X11)
-    let result = Command::new("nvidia-settings")
-        .args(&["-a", &format!("[gpu:0]/Gamma={}", gamma)])
-        .output();
+impl DisplayColorSettings {
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
 
-    if let Ok(output) = result {
-        if output.status.success() {
-            println!("✅ Gamma set to {:.2} via nvidia-settings", gamma);
-            return Ok(());
-        }
+    /// Per-channel gain for the white point, 1.0 for all channels at 6500K
+    pub fn white_point(&self) -> [f32; 3] {
+        let raw = kelvin_to_rgb(self.kelvin);
+        let neutral = kelvin_to_rgb(NEUTRAL_KELVIN);
+        [0, 1, 2].map(|c| (raw[c] / neutral[c]).clamp(0.0, 1.0))
     }
 
-    // Fallback: Try xrandr (works on X11)
-    let displays = list_displays();
-    if display_id < displays.len() {
-        let display_name = &displays[display_id].name;
+    /// Gamma per channel with the white point folded in.
+    ///
+    /// xrandr and nvidia-settings only take a gamma exponent per channel, so a
+    /// dimmer channel is approximated by a proportionally lower gamma.
+    pub fn effective_gamma(&self) -> [f32; 3] {
+        let white = self.white_point();
+        [0, 1, 2].map(|c| (self.gamma[c] * white[c]).clamp(0.1, *GAMMA_RANGE.end()))
+    }
+}
 
-        let result = Command::new("xrandr")
-            .args(&[
-                "--output",
-                display_name,
-                "--gamma",
-                &format!("{}:{}:{}", gamma, gamma, gamma),
-            ])
-            .output();
-
-        if let Ok(output) = result {
-            if output.status.success() {
-                println!(
-                    "✅ Gamma set to {:.2} via xrandr for {}",
-                    gamma, display_name
-                );
-                return Ok(());
-            }
-        }
+/// Approximate sRGB color of a black body at `kelvin` (Tanner Helland's fit)
+fn kelvin_to_rgb(kelvin: u32) -> [f32; 3] {
+    let t = kelvin as f32 / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.698_73 * (t - 60.0).powf(-0.133_204_76)
+    };
+    let green = if t <= 66.0 {
+        99.470_8 * t.ln() - 161.119_57
+    } else {
+        288.122_16 * (t - 60.0).powf(-0.075_514_85)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_73 * (t - 10.0).ln() - 305.044_8
+    };
+    [red, green, blue].map(|c| c.clamp(1.0, 255.0) / 255.0)
+}
+
+/// Set a display's color temperature, keeping its gamma
+pub fn set_color_temperature(display_index: usize, kelvin: u32) -> NvResult<DisplayColorSettings> {
+    let settings = DisplayColorSettings {
+        kelvin: kelvin.clamp(*KELVIN_RANGE.start(), *KELVIN_RANGE.end()),
+        ..saved_color(display_index)
+    };
+    set_display_color(display_index, settings)?;
+    Ok(settings)
+}
+
+/// Set a display's red, green and blue gamma, keeping its color temperature
+pub fn set_gamma(display_index: usize, r: f32, g: f32, b: f32) -> NvResult<DisplayColorSettings> {
+    let settings = DisplayColorSettings {
+        gamma: [r, g, b].map(|v| v.clamp(*GAMMA_RANGE.start(), *GAMMA_RANGE.end())),
+        ..saved_color(display_index)
+    };
+    set_display_color(display_index, settings)?;
+    Ok(settings)
+}
+
+/// Restore neutral color temperature and gamma
+pub fn reset_color(display_index: usize) -> NvResult<()> {
+    set_display_color(display_index, DisplayColorSettings::default())
+}
+
+/// Apply color temperature and gamma together and save them for startup
+pub fn set_display_color(display_index: usize, settings: DisplayColorSettings) -> NvResult<()> {
+    apply_color(display_index, &settings)?;
+    save_color(display_index, settings);
+    Ok(())
+}
+
+/// Re-apply saved color settings, e.g. at startup. Neutral displays are
+/// left alone so nvcontrol doesn't override another tool's gamma.
+pub fn reapply_saved_color() -> Vec<(usize, NvControlError)> {
+    crate::config::Config::load()
+        .display_color
+        .iter()
+        .enumerate()
+        .filter(|(_, settings)| !settings.is_neutral())
+        .filter_map(|(index, settings)| apply_color(index, settings).err().map(|e| (index, e)))
+        .collect()
+}
+
+/// Settings saved for a display, neutral when none were saved
+pub fn saved_color(display_index: usize) -> DisplayColorSettings {
+    crate::config::Config::load()
+        .display_color
+        .get(display_index)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn save_color(display_index: usize, settings: DisplayColorSettings) {
+    let mut config = crate::config::Config::load();
+    if config.display_color.len() <= display_index {
+        config
+            .display_color
+            .resize(display_index + 1, DisplayColorSettings::default());
     }
+    config.display_color[display_index] = settings;
+    config.save();
+}
 
-    // Wayland: Try wlr-randr for wlroots compositors
-    let result = Command::new("wlr-randr")
-        .args(&[
-            "--output",
-            &format!("HDMI-A-{}", display_id),
-            "--gamma",
-            &gamma.to_string(),
-        ])
-        .output();
+/// Program the display's gamma ramp through xrandr, falling back to
+/// nvidia-settings. Wayland compositors own the ramp, so there it fails
+/// with a pointer to the compositor's night light instead.
+fn apply_color(display_index: usize, settings: &DisplayColorSettings) -> NvResult<()> {
+    use std::process::Command;
 
-    if let Ok(output) = result {
-        if output.status.success() {
-            println!("✅ Gamma set to {:.2} via wlr-randr", gamma);
-            return Ok(());
-        }
+    if std::env::var("WAYLAND_DISPLAY").is_ok() {
+        return Err(NvControlError::UnsupportedFeature(
+            "Wayland compositors own the gamma ramp; use the compositor's night light \
+             (GNOME Night Light, KDE Night Color, wlsunset or gammastep)"
+                .to_string(),
+        ));
+    }
+
+    let [r, g, b] = settings.effective_gamma();
+    let displays = list_displays();
+    let display = displays.get(display_index).ok_or_else(|| {
+        NvControlError::DisplayDetectionFailed(format!("Display {display_index} not found"))
+    })?;
+
+    let applied = crate::gpu_safe::execute(Command::new("xrandr").args([
+        "--output",
+        &display.name,
+        "--gamma",
+        &format!("{:.3}:{:.3}:{:.3}", r, g, b),
+    ]))
+    .map(|o| o.status.success())
+    .unwrap_or(false);
+    if applied {
+        return Ok(());
     }
 
-    Err(crate::NvControlError::DisplayDetectionFailed(
-        "Failed to set gamma - no supported method available".to_string(),
-    ))
+    let target = format!("[DPY:{}]", display.name);
+    let applied = crate::gpu_safe::execute(Command::new("nvidia-settings").args([
+        "-a",
+        &format!("{target}/RedGamma={r:.3}"),
+        "-a",
+        &format!("{target}/GreenGamma={g:.3}"),
+        "-a",
+        &format!("{target}/BlueGamma={b:.3}"),
+    ]))
+    .map(|o| o.status.success())
+    .unwrap_or(false);
+    if applied {
+        return Ok(());
+    }
+
+    Err(NvControlError::DisplayDetectionFailed(format!(
+        "Failed to set color for {} - xrandr and nvidia-settings both failed",
+        display.name
+    )))
 }
 
 /// Get current gamma for a display
@@ -259,9 +381,9 @@ pub fn get_gamma(_display_id: usize) -> crate::NvResult<f32> {
     Ok(1.0)
 }
 
-/// Reset gamma to default (1.0)
+/// Reset gamma to default (1.0), keeping the color temperature
 pub fn reset_gamma(display_id: usize) -> crate::NvResult<()> {
-    set_gamma(display_id, 1.0)
+    set_gamma(display_id, 1.0, 1.0, 1.0).map(|_| ())
 }
 
 /// Check if display supports HDR
@@ -758,10 +880,1000 @@ pub fn open_icc_folder() -> NvResult<()> {
     )))
 }
 
+/// Transfer functions advertised in an EDID HDR static metadata block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TransferFunction {
+    SdrGamma,
+    HdrGamma,
+    Pq,
+    Hlg,
+}
+
+impl std::fmt::Display for TransferFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransferFunction::SdrGamma => write!(f, "Gamma (SDR)"),
+            TransferFunction::HdrGamma => write!(f, "Gamma (HDR)"),
+            TransferFunction::Pq => write!(f, "PQ (SMPTE ST 2084)"),
+            TransferFunction::Hlg => write!(f, "HLG"),
+        }
+    }
+}
+
+/// HDR metadata for one connector, decoded from its EDID. Fields are `None`
+/// when the display doesn't advertise them or the EDID can't be read.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HdrMetadata {
+    pub connector: Option<String>,
+    pub max_luminance_nits: Option<f32>,
+    pub max_frame_avg_nits: Option<f32>,
+    pub min_luminance_nits: Option<f32>,
+    pub transfer_functions: Vec<TransferFunction>,
+    pub bt2020: bool,
+}
+
+impl HdrMetadata {
+    /// Whether any HDR metadata was found
+    pub fn is_available(&self) -> bool {
+        !self.transfer_functions.is_empty()
+    }
+}
+
+/// Get EDID-derived HDR metadata for a connected display.
+///
+/// Returns empty (unavailable) metadata when no connector EDIDs are readable
+/// from this session, e.g. inside a sandbox or on a remote compositor.
+pub fn get_hdr_metadata(display_index: usize) -> NvResult<HdrMetadata> {
+    let connectors = connected_edids(std::path::Path::new("/sys/class/drm"));
+    if connectors.is_empty() {
+        return Ok(HdrMetadata::default());
+    }
+
+    let (connector, edid) = connectors.get(display_index).ok_or_else(|| {
+        NvControlError::DisplayDetectionFailed(format!(
+            "Display {} not found ({} connected)",
+            display_index,
+            connectors.len()
+        ))
+    })?;
+
+    let mut metadata = parse_edid_hdr_metadata(edid).unwrap_or_default();
+    metadata.connector = Some(connector.clone());
+    Ok(metadata)
+}
+
+/// Connected DRM connectors with a non-empty EDID, sorted by name
+pub(crate) fn connected_edids(drm_root: &std::path::Path) -> Vec<(String, Vec<u8>)> {
+    let Ok(entries) = std::fs::read_dir(drm_root) else {
+        return Vec::new();
+    };
+
+    let mut connectors: Vec<(String, Vec<u8>)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let connector = name.strip_prefix("card")?.split_once('-')?.1.to_string();
+            let status = std::fs::read_to_string(entry.path().join("status")).ok()?;
+            if status.trim() != "connected" {
+                return None;
+            }
+            let edid = std::fs::read(entry.path().join("edid")).ok()?;
+            (!edid.is_empty()).then_some((connector, edid))
+        })
+        .collect();
+
+    connectors.sort_by(|a, b| a.0.cmp(&b.0));
+    connectors
+}
+
+/// Resolutions advertised by connected DRM connectors, deduplicated
+pub(crate) fn connected_modes(drm_root: &std::path::Path) -> Vec<(u32, u32)> {
+    let mut modes: Vec<(u32, u32)> = connected_connector_modes(drm_root)
+        .into_iter()
+        .flat_map(|(_, modes)| modes)
+        .collect();
+
+    modes.sort_unstable();
+    modes.dedup();
+    modes
+}
+
+/// Resolutions advertised by each connected DRM connector, sorted by name
+pub(crate) fn connected_connector_modes(
+    drm_root: &std::path::Path,
+) -> Vec<(String, Vec<(u32, u32)>)> {
+    let Ok(entries) = std::fs::read_dir(drm_root) else {
+        return Vec::new();
+    };
+
+    let mut connectors: Vec<(String, Vec<(u32, u32)>)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let connector = name.strip_prefix("card")?.split_once('-')?.1.to_string();
+            let status = std::fs::read_to_string(entry.path().join("status")).ok()?;
+            if status.trim() != "connected" {
+                return None;
+            }
+            let contents = std::fs::read_to_string(entry.path().join("modes")).ok()?;
+            let mut modes: Vec<(u32, u32)> = Vec::new();
+            for line in contents.lines() {
+                let Some((w, h)) = line.trim().split_once('x') else {
+                    continue;
+                };
+                let h: String = h.chars().take_while(|c| c.is_ascii_digit()).collect();
+                if let (Ok(w), Ok(h)) = (w.parse(), h.parse()) {
+                    if !modes.contains(&(w, h)) {
+                        modes.push((w, h));
+                    }
+                }
+            }
+            Some((connector, modes))
+        })
+        .collect();
+
+    connectors.sort_by(|a, b| a.0.cmp(&b.0));
+    connectors
+}
+
+/// Decode HDR static metadata (CTA-861.3) and colorimetry blocks from an
+/// EDID. Returns `None` if the EDID itself is invalid.
+pub fn parse_edid_hdr_metadata(edid: &[u8]) -> Option<HdrMetadata> {
+    if edid.len() < 128 || edid[0..8] != [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00] {
+        return None;
+    }
+
+    let mut metadata = HdrMetadata::default();
+    let num_extensions = edid[126] as usize;
+
+    for ext_block in edid[128..].chunks_exact(128).take(num_extensions) {
+        // CTA-861 extension
+        if ext_block[0] != 0x02 {
+            continue;
+        }
+
+        let dtd_start = (ext_block[2] as usize).min(127);
+        let mut offset = 4;
+
+        while offset < dtd_start {
+            let header = ext_block[offset];
+            let tag = header >> 5;
+            let length = (header & 0x1F) as usize;
+            let payload = ext_block.get(offset + 1..=offset + length).unwrap_or(&[]);
+
+            // Extended tag blocks: 0x05 colorimetry, 0x06 HDR static metadata
+            if tag == 0x07 {
+                match payload {
+                    [0x05, flags, ..] => metadata.bt2020 = flags & 0xE0 != 0,
+                    [0x06, eotf, _descriptors, rest @ ..] => {
+                        let flags = [
+                            (0x01, TransferFunction::SdrGamma),
+                            (0x02, TransferFunction::HdrGamma),
+                            (0x04, TransferFunction::Pq),
+                            (0x08, TransferFunction::Hlg),
+                        ];
+                        metadata.transfer_functions = flags
+                            .iter()
+                            .filter(|(bit, _)| eotf & bit != 0)
+                            .map(|(_, tf)| *tf)
+                            .collect();
+
+                        // Luminance code values; 0 means "not indicated"
+                        let decode = |cv: u8| 50.0 * 2f32.powf(cv as f32 / 32.0);
+                        let max = rest.first().filter(|cv| **cv != 0).map(|cv| decode(*cv));
+                        metadata.max_luminance_nits = max;
+                        metadata.max_frame_avg_nits =
+                            rest.get(1).filter(|cv| **cv != 0).map(|cv| decode(*cv));
+                        metadata.min_luminance_nits = match (max, rest.get(2)) {
+                            (Some(max), Some(cv)) => {
+                                Some(max * (*cv as f32 / 255.0).powi(2) / 100.0)
+                            }
+                            _ => None,
+                        };
+                    }
+                    _ => {}
+                }
+            }
+
+            offset += 1 + length;
+        }
+    }
+
+    Some(metadata)
+}
+
+/// A requested display mode, parsed from "2560x1440@165" or "2560x1440"
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModeRequest {
+    pub width: u32,
+    pub height: u32,
+    /// `None` picks the highest refresh rate the resolution supports
+    pub refresh_hz: Option<f32>,
+}
+
+impl std::str::FromStr for ModeRequest {
+    type Err = NvControlError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            NvControlError::DisplayDetectionFailed(format!(
+                "Invalid mode '{}'. Use WIDTHxHEIGHT[@HZ], e.g. 2560x1440@165",
+                spec
+            ))
+        };
+        let (res, rate) = match spec.split_once('@') {
+            Some((res, rate)) => (res, Some(rate.trim_end_matches("Hz"))),
+            None => (spec, None),
+        };
+        let (w, h) = res.split_once('x').ok_or_else(invalid)?;
+        let refresh_hz = match rate {
+            Some(rate) => match rate.parse::<f32>() {
+                Ok(hz) if hz > 0.0 => Some(hz),
+                _ => return Err(invalid()),
+            },
+            None => None,
+        };
+        Ok(Self {
+            width: w.parse().map_err(|_| invalid())?,
+            height: h.parse().map_err(|_| invalid())?,
+            refresh_hz,
+        })
+    }
+}
+
+impl std::fmt::Display for ModeRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)?;
+        match self.refresh_hz {
+            Some(hz) => write!(f, "@{}", hz),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Check a requested mode against a display's advertised modes and resolve
+/// the refresh rate to the exact value the backend listed (165 -> 164.96)
+pub fn validate_mode(
+    display: &crate::display_info::ListedDisplay,
+    request: ModeRequest,
+) -> NvResult<crate::display_info::CurrentMode> {
+    let mode = display
+        .modes
+        .iter()
+        .find(|m| m.width == request.width && m.height == request.height)
+        .ok_or_else(|| {
+            NvControlError::DisplayDetectionFailed(format!(
+                "{}x{} is not supported by {} (see `nvctl display list`)",
+                request.width, request.height, display.connector
+            ))
+        })?;
+
+    let refresh_hz = match (request.refresh_hz, mode.refresh_rates.is_empty()) {
+        // DRM sysfs lists resolutions only, so the rate can't be checked
+        (Some(hz), true) => hz,
+        (None, true) => {
+            return Err(NvControlError::DisplayDetectionFailed(format!(
+                "No refresh rates known for {}; specify one, e.g. {}x{}@60",
+                display.connector, request.width, request.height
+            )));
+        }
+        (Some(hz), false) => mode
+            .refresh_rates
+            .iter()
+            .copied()
+            .filter(|rate| (rate - hz).abs() < 0.5)
+            .min_by(|a, b| (a - hz).abs().total_cmp(&(b - hz).abs()))
+            .ok_or_else(|| {
+                let rates: Vec<String> = mode
+                    .refresh_rates
+                    .iter()
+                    .map(|r| format!("{:.2}", r))
+                    .collect();
+                NvControlError::DisplayDetectionFailed(format!(
+                    "{} Hz is not supported at {}x{} on {} (supported: {})",
+                    hz,
+                    request.width,
+                    request.height,
+                    display.connector,
+                    rates.join(", ")
+                ))
+            })?,
+        (None, false) => mode.refresh_rates.iter().copied().fold(0.0, f32::max),
+    };
+
+    Ok(crate::display_info::CurrentMode {
+        width: request.width,
+        height: request.height,
+        refresh_hz,
+    })
+}
+
+/// Switch a connector's resolution and refresh rate with the session's
+/// tool: xrandr on X11, kscreen-doctor on KDE Wayland and wlr-randr on
+/// wlroots compositors. The mode must be one the display advertises.
+pub fn set_mode_with(
+    runner: &crate::display_backend::SharedDisplayRunner,
+    listing: &crate::display_info::DisplayListing,
+    connector: &str,
+    request: ModeRequest,
+) -> NvResult<crate::display_info::CurrentMode> {
+    let display = find_listed_display(listing, connector)?;
+    let mode = validate_mode(display, request)?;
+    let resolution = format!("{}x{}", mode.width, mode.height);
+    let rate = format!("{:.2}", mode.refresh_hz);
+
+    if !crate::gpu_safe::should_apply(&format!(
+        "set {} to {}@{} Hz",
+        display.connector, resolution, rate
+    )) {
+        return Ok(mode);
+    }
+
+    match output_tool(runner, "mode")? {
+        OutputTool::Xrandr => {
+            runner.run_xrandr(&[
+                "--output",
+                &display.connector,
+                "--mode",
+                &resolution,
+                "--rate",
+                &rate,
+            ])?;
+        }
+        OutputTool::KscreenDoctor => {
+            runner.run_command(
+                "kscreen-doctor",
+                &[&format!(
+                    "output.{}.mode.{}@{}",
+                    kscreen_output(display),
+                    resolution,
+                    mode.refresh_hz.round()
+                )],
+            )?;
+        }
+        OutputTool::WlrRandr => {
+            runner.run_wlr_randr(&[
+                "--output",
+                &display.connector,
+                "--mode",
+                &format!("{}@{}Hz", resolution, rate),
+            ])?;
+        }
+    }
+
+    Ok(mode)
+}
+
+/// Tool used to change output settings in the current session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputTool {
+    Xrandr,
+    KscreenDoctor,
+    WlrRandr,
+}
+
+impl OutputTool {
+    fn display_server(self) -> crate::display_backend::DisplayServer {
+        match self {
+            Self::Xrandr => crate::display_backend::DisplayServer::X11,
+            Self::KscreenDoctor | Self::WlrRandr => crate::display_backend::DisplayServer::Wayland,
+        }
+    }
+}
+
+/// Pick xrandr on X11, kscreen-doctor on KDE Wayland or wlr-randr on
+/// wlroots compositors. `setting` names what is being changed for the error.
+fn output_tool(
+    runner: &crate::display_backend::SharedDisplayRunner,
+    setting: &str,
+) -> NvResult<OutputTool> {
+    use crate::display_backend::DisplayServer;
+
+    match runner.get_display_server()? {
+        DisplayServer::X11 => Ok(OutputTool::Xrandr),
+        DisplayServer::Wayland if runner.command_available("kscreen-doctor") => {
+            Ok(OutputTool::KscreenDoctor)
+        }
+        DisplayServer::Wayland if runner.command_available("wlr-randr") => Ok(OutputTool::WlrRandr),
+        DisplayServer::Wayland => Err(NvControlError::UnsupportedFeature(format!(
+            "This compositor has no supported tool to change the {} (kscreen-doctor or \
+             wlr-randr); change it in its display settings",
+            setting
+        ))),
+        _ => Err(NvControlError::DisplayDetectionFailed(
+            "No X11 or Wayland session found".to_string(),
+        )),
+    }
+}
+
+fn find_listed_display<'a>(
+    listing: &'a crate::display_info::DisplayListing,
+    connector: &str,
+) -> NvResult<&'a crate::display_info::ListedDisplay> {
+    listing
+        .displays
+        .iter()
+        .find(|d| d.connector == connector || d.drm_connector.as_deref() == Some(connector))
+        .ok_or_else(|| {
+            NvControlError::DisplayDetectionFailed(format!("No connected display on {}", connector))
+        })
+}
+
+/// kscreen-doctor names outputs after their DRM connector
+fn kscreen_output(display: &crate::display_info::ListedDisplay) -> &str {
+    display
+        .drm_connector
+        .as_deref()
+        .unwrap_or(&display.connector)
+}
+
+/// Switch a connected display's resolution and refresh rate. Without a
+/// refresh rate the highest one available at that resolution is used.
+pub fn set_mode(
+    connector: &str,
+    width: u32,
+    height: u32,
+    refresh_hz: Option<f32>,
+) -> NvResult<crate::display_info::CurrentMode> {
+    let runner = crate::display_backend::create_real_runner();
+    let listing = crate::display_info::list_connected_displays();
+    let request = ModeRequest {
+        width,
+        height,
+        refresh_hz,
+    };
+    set_mode_with(&runner, &listing, connector, request)
+}
+
+/// Display rotation, named the way xrandr names it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Orientation {
+    #[default]
+    Normal,
+    /// Rotated 90° counter-clockwise (portrait)
+    Left,
+    /// Rotated 90° clockwise (portrait)
+    Right,
+    Inverted,
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 4] = [Self::Normal, Self::Left, Self::Right, Self::Inverted];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Inverted => "inverted",
+        }
+    }
+
+    /// wlr-randr `--transform` value; wlroots transforms turn clockwise
+    fn wlr_transform(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Left => "270",
+            Self::Right => "90",
+            Self::Inverted => "180",
+        }
+    }
+}
+
+impl std::str::FromStr for Orientation {
+    type Err = NvControlError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "normal" | "none" | "0" => Ok(Self::Normal),
+            "left" | "90" => Ok(Self::Left),
+            "right" | "270" => Ok(Self::Right),
+            "inverted" | "180" => Ok(Self::Inverted),
+            _ => Err(NvControlError::DisplayDetectionFailed(format!(
+                "Invalid orientation '{}'. Use normal, left, right or inverted",
+                name
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Rotate a connected display with the session's tool
+pub fn set_rotation_with(
+    runner: &crate::display_backend::SharedDisplayRunner,
+    listing: &crate::display_info::DisplayListing,
+    connector: &str,
+    orientation: Orientation,
+) -> NvResult<()> {
+    let display = find_listed_display(listing, connector)?;
+
+    if !crate::gpu_safe::should_apply(&format!("rotate {} to {}", display.connector, orientation)) {
+        return Ok(());
+    }
+
+    match output_tool(runner, "rotation")? {
+        OutputTool::Xrandr => {
+            runner.run_xrandr(&[
+                "--output",
+                &display.connector,
+                "--rotate",
+                orientation.name(),
+            ])?;
+        }
+        OutputTool::KscreenDoctor => {
+            runner.run_command(
+                "kscreen-doctor",
+                &[&format!(
+                    "output.{}.rotation.{}",
+                    kscreen_output(display),
+                    orientation.name()
+                )],
+            )?;
+        }
+        OutputTool::WlrRandr => {
+            runner.run_wlr_randr(&[
+                "--output",
+                &display.connector,
+                "--transform",
+                orientation.wlr_transform(),
+            ])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Rotate a connected display
+pub fn set_rotation(connector: &str, orientation: Orientation) -> NvResult<()> {
+    let runner = crate::display_backend::create_real_runner();
+    let listing = crate::display_info::list_connected_displays();
+    set_rotation_with(&runner, &listing, connector, orientation)
+}
+
+/// Smallest and largest scale factor accepted
+pub const MIN_SCALE: f32 = 0.5;
+pub const MAX_SCALE: f32 = 3.0;
+
+/// Check a scale factor is in range and on a 5% step, which is what
+/// kscreen-doctor and wlroots compositors accept without rounding
+pub fn validate_scale(factor: f32) -> NvResult<f32> {
+    let steps = factor * 20.0;
+    if !(MIN_SCALE..=MAX_SCALE).contains(&factor) || (steps - steps.round()).abs() > 1e-3 {
+        return Err(NvControlError::DisplayDetectionFailed(format!(
+            "Invalid scale {}. Use a value from {} to {} in steps of 0.05, e.g. 1.25",
+            factor, MIN_SCALE, MAX_SCALE
+        )));
+    }
+    Ok(steps.round() / 20.0)
+}
+
+/// Caveats for a scale factor on NVIDIA with the given display server
+pub fn scale_warnings(factor: f32, server: crate::display_backend::DisplayServer) -> Vec<String> {
+    use crate::display_backend::DisplayServer;
+
+    let fractional = factor.fract().abs() > f32::EPSILON;
+    let mut warnings = Vec::new();
+    match server {
+        DisplayServer::X11 if (factor - 1.0).abs() > f32::EPSILON => warnings.push(
+            "xrandr scaling renders a resized framebuffer and stretches it to the panel; \
+             on NVIDIA text looks blurry and page flipping (G-SYNC) may be disabled. \
+             Prefer the desktop's own UI scaling where it has one."
+                .to_string(),
+        ),
+        DisplayServer::Wayland if fractional => warnings.push(
+            "Fractional scaling on NVIDIA: XWayland apps render at 1x and are upscaled, so \
+             they look blurry unless the compositor lets them scale themselves. Integer \
+             scales (1 or 2) stay sharp."
+                .to_string(),
+        ),
+        _ => {}
+    }
+    warnings
+}
+
+/// Scale a connected display with the session's tool, returning caveats
+/// worth showing the user (see [`scale_warnings`])
+pub fn set_scale_with(
+    runner: &crate::display_backend::SharedDisplayRunner,
+    listing: &crate::display_info::DisplayListing,
+    connector: &str,
+    factor: f32,
+) -> NvResult<Vec<String>> {
+    let factor = validate_scale(factor)?;
+    let display = find_listed_display(listing, connector)?;
+    let tool = output_tool(runner, "scale")?;
+    let warnings = scale_warnings(factor, tool.display_server());
+
+    if !crate::gpu_safe::should_apply(&format!("scale {} to {}", display.connector, factor)) {
+        return Ok(warnings);
+    }
+
+    match tool {
+        OutputTool::Xrandr => {
+            // xrandr scales the framebuffer, so a 1.25x UI needs 0.8x0.8
+            let inverse = 1.0 / factor;
+            runner.run_xrandr(&[
+                "--output",
+                &display.connector,
+                "--scale",
+                &format!("{:.4}x{:.4}", inverse, inverse),
+            ])?;
+        }
+        OutputTool::KscreenDoctor => {
+            runner.run_command(
+                "kscreen-doctor",
+                &[&format!(
+                    "output.{}.scale.{}",
+                    kscreen_output(display),
+                    factor
+                )],
+            )?;
+        }
+        OutputTool::WlrRandr => {
+            runner.run_wlr_randr(&[
+                "--output",
+                &display.connector,
+                "--scale",
+                &factor.to_string(),
+            ])?;
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// Scale a connected display, returning any caveats for the scale chosen
+pub fn set_scale(connector: &str, factor: f32) -> NvResult<Vec<String>> {
+    let runner = crate::display_backend::create_real_runner();
+    let listing = crate::display_info::list_connected_displays();
+    set_scale_with(&runner, &listing, connector, factor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HotplugSource {
+    /// Kernel DRM uevents (needs `nvidia_drm.modeset=1` on NVIDIA)
+    DrmUevent,
+    /// X11 RandR output/screen change events
+    RandR,
+    /// No event source; every wait is treated as a possible change
+    Poll,
+}
+
+impl HotplugSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::DrmUevent => "DRM uevent",
+            Self::RandR => "RandR",
+            Self::Poll => "polling",
+        }
+    }
+}
+
+enum HotplugBackend {
+    Uevent(std::os::fd::OwnedFd),
+    RandR(x11rb::rust_connection::RustConnection),
+    Poll,
+}
+
+/// Blocks until a display is connected or disconnected.
+///
+/// X11 sessions listen for RandR events, since the NVIDIA driver without
+/// kernel modesetting sends no DRM uevents. Everything else listens on the
+/// kernel uevent netlink socket, falling back to plain polling.
+pub struct HotplugListener {
+    backend: HotplugBackend,
+}
+
+impl HotplugListener {
+    pub fn open() -> Self {
+        let x11 =
+            std::env::var_os("DISPLAY").is_some() && std::env::var_os("WAYLAND_DISPLAY").is_none();
+        let randr = x11.then(open_randr_listener).flatten();
+        let backend = match randr {
+            Some(conn) => HotplugBackend::RandR(conn),
+            None => open_uevent_socket()
+                .map(HotplugBackend::Uevent)
+                .unwrap_or(HotplugBackend::Poll),
+        };
+        Self { backend }
+    }
+
+    pub fn source(&self) -> HotplugSource {
+        match self.backend {
+            HotplugBackend::Uevent(_) => HotplugSource::DrmUevent,
+            HotplugBackend::RandR(_) => HotplugSource::RandR,
+            HotplugBackend::Poll => HotplugSource::Poll,
+        }
+    }
+
+    /// Wait up to `timeout`; true if a hotplug event arrived. In polling mode
+    /// this sleeps and always returns true.
+    pub fn wait(&mut self, timeout: std::time::Duration) -> bool {
+        use std::os::fd::AsRawFd;
+        use x11rb::connection::Connection;
+
+        match &self.backend {
+            HotplugBackend::Uevent(fd) => {
+                if !poll_readable(fd.as_raw_fd(), timeout) {
+                    return false;
+                }
+                // Drain everything queued; any DRM hotplug among it counts
+                let mut buf = [0u8; 8192];
+                let mut hotplug = false;
+                loop {
+                    // SAFETY: buf is valid for writes of buf.len() bytes
+                    let n = unsafe {
+                        libc::recv(
+                            fd.as_raw_fd(),
+                            buf.as_mut_ptr().cast(),
+                            buf.len(),
+                            libc::MSG_DONTWAIT,
+                        )
+                    };
+                    if n <= 0 {
+                        break;
+                    }
+                    hotplug |= is_drm_hotplug_uevent(&buf[..n as usize]);
+                }
+                hotplug
+            }
+            HotplugBackend::RandR(conn) => {
+                let mut hotplug = false;
+                if poll_readable(conn.stream().as_raw_fd(), timeout) {
+                    while let Ok(Some(event)) = conn.poll_for_event() {
+                        hotplug |= matches!(
+                            event,
+                            x11rb::protocol::Event::RandrScreenChangeNotify(_)
+                                | x11rb::protocol::Event::RandrNotify(_)
+                        );
+                    }
+                }
+                hotplug
+            }
+            HotplugBackend::Poll => {
+                std::thread::sleep(timeout);
+                true
+            }
+        }
+    }
+}
+
+/// Whether a raw kernel uevent ("ACTION@DEVPATH\0KEY=VALUE\0...") is a DRM
+/// connector hotplug
+pub fn is_drm_hotplug_uevent(message: &[u8]) -> bool {
+    let mut fields = message.split(|b| *b == 0);
+    let is_change = fields
+        .next()
+        .is_some_and(|header| header.starts_with(b"change@"));
+    let (mut drm, mut hotplug) = (false, false);
+    for field in fields {
+        drm |= field == b"SUBSYSTEM=drm";
+        hotplug |= field == b"HOTPLUG=1";
+    }
+    is_change && drm && hotplug
+}
+
+fn open_uevent_socket() -> Option<std::os::fd::OwnedFd> {
+    use std::os::fd::FromRawFd;
+
+    // SAFETY: plain socket(2) call; the fd is owned immediately below
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+            libc::NETLINK_KOBJECT_UEVENT,
+        )
+    };
+    if fd < 0 {
+        return None;
+    }
+    // SAFETY: fd is a freshly created socket nobody else owns
+    let fd = unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) };
+
+    // SAFETY: sockaddr_nl is plain data; all-zero is a valid starting value
+    let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+    addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+    // Group 1 is the kernel's own broadcast, so this works without udevd
+    addr.nl_groups = 1;
+    // SAFETY: addr is a valid sockaddr_nl and the length matches it
+    let bound = unsafe {
+        libc::bind(
+            std::os::fd::AsRawFd::as_raw_fd(&fd),
+            (&addr as *const libc::sockaddr_nl).cast(),
+            std::mem::size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+        )
+    };
+    (bound == 0).then_some(fd)
+}
+
+fn open_randr_listener() -> Option<x11rb::rust_connection::RustConnection> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::randr::{ConnectionExt, NotifyMask};
+
+    let (conn, screen) = x11rb::connect(None).ok()?;
+    let root = conn.setup().roots.get(screen)?.root;
+    conn.randr_select_input(root, NotifyMask::SCREEN_CHANGE | NotifyMask::OUTPUT_CHANGE)
+        .ok()?;
+    conn.flush().ok()?;
+    Some(conn)
+}
+
+fn poll_readable(fd: std::os::fd::RawFd, timeout: std::time::Duration) -> bool {
+    let mut pollfd = libc::pollfd {
+        fd,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+    // SAFETY: pollfd is a single valid entry
+    let ready = unsafe { libc::poll(&mut pollfd, 1, timeout_ms) };
+    ready > 0 && pollfd.revents & libc::POLLIN != 0
+}
+
+/// Collapses a burst of hotplug events (a monitor waking up often sends
+/// several) into one, once things have been quiet for a while
+#[derive(Debug, Clone)]
+pub struct HotplugDebouncer {
+    quiet: std::time::Duration,
+    last_event: Option<std::time::Instant>,
+}
+
+impl HotplugDebouncer {
+    pub fn new(quiet: std::time::Duration) -> Self {
+        Self {
+            quiet,
+            last_event: None,
+        }
+    }
+
+    pub fn event(&mut self, now: std::time::Instant) {
+        self.last_event = Some(now);
+    }
+
+    /// Time left before a pending burst settles
+    pub fn remaining(&self, now: std::time::Instant) -> Option<std::time::Duration> {
+        self.last_event
+            .map(|at| self.quiet.saturating_sub(now.duration_since(at)))
+    }
+
+    /// True once per burst, after `quiet` has passed since its last event
+    pub fn take_ready(&mut self, now: std::time::Instant) -> bool {
+        if self.remaining(now).is_some_and(|left| left.is_zero()) {
+            self.last_event = None;
+            true
+        } else {
+            false
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_drm_hotplug_uevent_and_debounce() {
+        let hotplug = b"change@/devices/pci0000:00/0000:00:01.0/0000:01:00.0/drm/card1\0ACTION=change\0SUBSYSTEM=drm\0HOTPLUG=1\0";
+        assert!(is_drm_hotplug_uevent(hotplug));
+        let usb = b"add@/devices/usb1/1-1\0ACTION=add\0SUBSYSTEM=usb\0";
+        assert!(!is_drm_hotplug_uevent(usb));
+
+        let t0 = std::time::Instant::now();
+        let ms = std::time::Duration::from_millis;
+        let mut debouncer = HotplugDebouncer::new(ms(500));
+        assert!(!debouncer.take_ready(t0));
+
+        // Three events in a burst settle into one re-apply
+        debouncer.event(t0);
+        debouncer.event(t0 + ms(100));
+        debouncer.event(t0 + ms(200));
+        assert!(!debouncer.take_ready(t0 + ms(600)));
+        assert!(debouncer.take_ready(t0 + ms(700)));
+        assert!(!debouncer.take_ready(t0 + ms(800)));
+    }
+
+    #[test]
+    fn test_color_temperature_white_point() {
+        let neutral = DisplayColorSettings::default();
+        assert!(neutral.is_neutral());
+        for gain in neutral.white_point() {
+            assert!((gain - 1.0).abs() < 1e-4);
+        }
+
+        // Warm keeps red, cuts blue hardest
+        let warm = DisplayColorSettings {
+            kelvin: 3400,
+            ..neutral
+        };
+        let [r, g, b] = warm.white_point();
+        assert!((r - 1.0).abs() < 0.01);
+        assert!(b < g && g < r);
+
+        // Gamma composes with the white point rather than replacing it
+        let warm_bright = DisplayColorSettings {
+            gamma: [1.2; 3],
+            ..warm
+        };
+        let effective = warm_bright.effective_gamma();
+        assert!((effective[0] - 1.2).abs() < 0.02);
+        assert!(effective[2] < warm.effective_gamma()[2] * 1.3);
+    }
+
+    fn hdr_test_edid() -> Vec<u8> {
+        let mut edid = vec![0u8; 256];
+        edid[0..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        edid[126] = 1;
+
+        let ext = &mut edid[128..];
+        ext[0] = 0x02;
+        ext[1] = 0x03;
+        ext[2] = 15;
+        // HDR static metadata: SDR + PQ + HLG, 400/200 nits max/FALL
+        ext[4..11].copy_from_slice(&[0xE6, 0x06, 0x0D, 0x01, 96, 64, 32]);
+        // Colorimetry: BT.2020 RGB
+        ext[11..15].copy_from_slice(&[0xE3, 0x05, 0x80, 0x00]);
+        edid
+    }
+
+    #[test]
+    fn test_connected_modes_skips_disconnected() {
+        let root = tempfile::tempdir().unwrap();
+        for (name, status, modes) in [
+            (
+                "card1-DP-1",
+                "connected",
+                "2560x1440\n1920x1080\n1920x1080i\n",
+            ),
+            ("card1-HDMI-A-1", "disconnected", "3840x2160\n"),
+        ] {
+            let dir = root.path().join(name);
+            std::fs::create_dir(&dir).unwrap();
+            std::fs::write(dir.join("status"), status).unwrap();
+            std::fs::write(dir.join("modes"), modes).unwrap();
+        }
+
+        assert_eq!(
+            connected_modes(root.path()),
+            vec![(1920, 1080), (2560, 1440)]
+        );
+    }
+
+    #[test]
+    fn test_parse_edid_hdr_metadata() {
+        let metadata = parse_edid_hdr_metadata(&hdr_test_edid()).unwrap();
+        assert!(metadata.is_available());
+        assert!(metadata.bt2020);
+        assert_eq!(
+            metadata.transfer_functions,
+            vec![
+                TransferFunction::SdrGamma,
+                TransferFunction::Pq,
+                TransferFunction::Hlg
+            ]
+        );
+        assert_eq!(metadata.max_luminance_nits, Some(400.0));
+        assert_eq!(metadata.max_frame_avg_nits, Some(200.0));
+        let min = metadata.min_luminance_nits.unwrap();
+        assert!((min - 0.063).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_parse_edid_without_hdr_block() {
+        let mut edid = hdr_test_edid();
+        edid[126] = 0;
+        let metadata = parse_edid_hdr_metadata(&edid).unwrap();
+        assert!(!metadata.is_available());
+        assert_eq!(metadata.max_luminance_nits, None);
+
+        assert!(parse_edid_hdr_metadata(&edid[..64]).is_none());
+    }
+
     #[test]
     fn test_get_display_count() {
         let count = get_display_count();
@@ -806,4 +1918,96 @@ mod tests {
         assert!(!profiles.is_empty());
         assert!(profiles.iter().any(|p| p.contains(".icc")));
     }
+
+    #[test]
+    fn test_set_mode_validates_against_listed_modes() {
+        use crate::display_backend::{MockDisplayRunner, SharedDisplayRunner};
+
+        assert_eq!(
+            "2560x1440@144".parse::<ModeRequest>().unwrap(),
+            ModeRequest {
+                width: 2560,
+                height: 1440,
+                refresh_hz: Some(144.0)
+            }
+        );
+        assert_eq!("1920x1080".parse::<ModeRequest>().unwrap().refresh_hz, None);
+        assert!("2560x1440@".parse::<ModeRequest>().is_err());
+        assert!("fast".parse::<ModeRequest>().is_err());
+
+        let runner: SharedDisplayRunner = std::sync::Arc::new(MockDisplayRunner::x11());
+        let listing = crate::display_info::collect_display_listing(
+            &runner,
+            std::path::Path::new("/nonexistent"),
+            &[],
+        );
+        let set = |mode: &str| set_mode_with(&runner, &listing, "DP-0", mode.parse().unwrap());
+
+        // 144 resolves to the listed 143.91; no rate picks the highest
+        assert_eq!(set("2560x1440@144").unwrap().refresh_hz, 143.91);
+        assert_eq!(set("1920x1080").unwrap().refresh_hz, 119.88);
+        assert!(set("2560x1440@165").is_err());
+        assert!(set("3840x2160@60").is_err());
+        assert!(set_mode_with(&runner, &listing, "HDMI-0", "1920x1080".parse().unwrap()).is_err());
+
+        // GNOME has neither kscreen-doctor nor wlr-randr
+        let mut gnome = MockDisplayRunner::gnome();
+        gnome.available_commands.retain(|c| c != "wlr-randr");
+        let gnome: SharedDisplayRunner = std::sync::Arc::new(gnome);
+        assert!(matches!(
+            set_mode_with(&gnome, &listing, "DP-0", "1920x1080@60".parse().unwrap()),
+            Err(NvControlError::UnsupportedFeature(_))
+        ));
+    }
+
+    #[test]
+    fn test_rotation_and_scale_validation() {
+        use crate::display_backend::{DisplayServer, MockDisplayRunner, SharedDisplayRunner};
+
+        assert_eq!("LEFT".parse::<Orientation>().unwrap(), Orientation::Left);
+        assert_eq!("180".parse::<Orientation>().unwrap(), Orientation::Inverted);
+        assert!("sideways".parse::<Orientation>().is_err());
+        assert_eq!(Orientation::Right.wlr_transform(), "90");
+
+        assert_eq!(validate_scale(1.25).unwrap(), 1.25);
+        assert!(validate_scale(1.33).is_err());
+        assert!(validate_scale(0.25).is_err());
+        assert!(validate_scale(4.0).is_err());
+
+        assert!(scale_warnings(2.0, DisplayServer::Wayland).is_empty());
+        assert_eq!(scale_warnings(1.5, DisplayServer::Wayland).len(), 1);
+        assert_eq!(scale_warnings(2.0, DisplayServer::X11).len(), 1);
+        assert!(scale_warnings(1.0, DisplayServer::X11).is_empty());
+
+        let runner: SharedDisplayRunner = std::sync::Arc::new(MockDisplayRunner::x11());
+        let listing = crate::display_info::collect_display_listing(
+            &runner,
+            std::path::Path::new("/nonexistent"),
+            &[],
+        );
+        assert!(set_rotation_with(&runner, &listing, "DP-0", Orientation::Left).is_ok());
+        assert!(set_rotation_with(&runner, &listing, "HDMI-0", Orientation::Left).is_err());
+        assert_eq!(
+            set_scale_with(&runner, &listing, "DP-0", 1.5)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(set_scale_with(&runner, &listing, "DP-0", 1.33).is_err());
+
+        let kde: SharedDisplayRunner = std::sync::Arc::new(MockDisplayRunner::kde());
+        assert!(
+            set_scale_with(&kde, &listing, "DP-0", 2.0)
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut gnome = MockDisplayRunner::gnome();
+        gnome.available_commands.retain(|c| c != "wlr-randr");
+        let gnome: SharedDisplayRunner = std::sync::Arc::new(gnome);
+        assert!(matches!(
+            set_rotation_with(&gnome, &listing, "DP-0", Orientation::Right),
+            Err(NvControlError::UnsupportedFeature(_))
+        ));
+    }
 }
@@ -1,4 +1,5 @@
 // Enhanced Display Information - Comprehensive display details
+use crate::display_backend::{DisplayServer, SharedDisplayRunner};
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,85 @@ pub struct EnhancedDisplayInfo {
     pub color_range: String,
 }
 
+/// A connected output identified by stable names rather than enumeration order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectorIdentity {
+    /// DRM connector name, e.g. "DP-2" or "HDMI-A-1"
+    pub connector: String,
+    /// Monitor serial from the EDID, if it reports one
+    pub edid_serial: Option<String>,
+    /// Index into the NVKMS connector list used for vibrance control
+    pub vibrance_index: Option<usize>,
+}
+
+/// Resolve the currently connected outputs from DRM sysfs
+pub fn get_connected_connectors() -> Vec<ConnectorIdentity> {
+    let nvkms_types: Vec<String> = crate::vibrance_native::get_vibrance_controller()
+        .ok()
+        .and_then(|guard| {
+            guard.as_ref().map(|c| {
+                c.connectors
+                    .iter()
+                    .map(|c| c.connector_type.clone())
+                    .collect()
+            })
+        })
+        .unwrap_or_default();
+
+    crate::display::connected_edids(std::path::Path::new("/sys/class/drm"))
+        .into_iter()
+        .map(|(connector, edid)| ConnectorIdentity {
+            vibrance_index: drm_connector_to_nvkms_index(&connector, &nvkms_types),
+            edid_serial: parse_edid_serial(&edid),
+            connector,
+        })
+        .collect()
+}
+
+/// Map a DRM connector name ("DP-2") to the NVKMS connector index by
+/// matching the n-th connector of the same type
+pub fn drm_connector_to_nvkms_index(connector: &str, nvkms_types: &[String]) -> Option<usize> {
+    let (drm_type, ordinal) = connector.rsplit_once('-')?;
+    let ordinal: usize = ordinal.parse().ok()?;
+    let nvkms_type = match drm_type {
+        "HDMI-A" | "HDMI-B" => "HDMI",
+        other => other,
+    };
+
+    nvkms_types
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.as_str() == nvkms_type)
+        .map(|(idx, _)| idx)
+        .nth(ordinal.checked_sub(1)?)
+}
+
+/// Extract the monitor serial from an EDID: the ASCII serial descriptor
+/// when present, otherwise the numeric serial in the header
+pub fn parse_edid_serial(edid: &[u8]) -> Option<String> {
+    if edid.len() < 128 {
+        return None;
+    }
+
+    for start in [54, 72, 90, 108] {
+        let descriptor = &edid[start..start + 18];
+        if descriptor[0..3] == [0, 0, 0] && descriptor[3] == 0xFF {
+            let text: String = descriptor[5..]
+                .iter()
+                .take_while(|b| **b != 0x0A)
+                .map(|b| *b as char)
+                .collect();
+            let text = text.trim();
+            if !text.is_empty() {
+                return Some(text.to_string());
+            }
+        }
+    }
+
+    let numeric = u32::from_le_bytes([edid[12], edid[13], edid[14], edid[15]]);
+    (numeric != 0).then(|| numeric.to_string())
+}
+
 pub fn get_all_displays_info() -> NvResult<Vec<EnhancedDisplayInfo>> {
     use std::process::Command;
 
@@ -151,3 +231,471 @@ fn vibrance_raw_to_percentage(raw: i32) -> u32 {
         (100.0 + (raw as f32 / 1023.0 * 100.0)) as u32
     }
 }
+
+/// A resolution and the refresh rates it can be driven at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayModeInfo {
+    pub width: u32,
+    pub height: u32,
+    /// Empty when the backend only reports resolutions (DRM sysfs)
+    pub refresh_rates: Vec<f32>,
+}
+
+/// The mode a display is currently driven at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurrentMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_hz: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VrrRange {
+    pub min_hz: u32,
+    pub max_hz: u32,
+}
+
+/// One connected display as reported by `nvctl display list`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListedDisplay {
+    /// Output name as the detection backend reports it
+    pub connector: String,
+    /// Matching DRM connector, used for EDID data
+    pub drm_connector: Option<String>,
+    pub monitor_name: Option<String>,
+    pub edid_serial: Option<String>,
+    pub current_mode: Option<CurrentMode>,
+    pub modes: Vec<DisplayModeInfo>,
+    pub hdr_capable: bool,
+    pub color_depth_bits: Option<u8>,
+    pub vrr_capable: bool,
+    pub vrr_range: Option<VrrRange>,
+}
+
+/// Connected displays plus the session type and backend they were read from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayListing {
+    /// "x11", "wayland" or "unknown"
+    pub session: String,
+    /// "xrandr", "wlr-randr" or "drm-sysfs"
+    pub backend: String,
+    pub displays: Vec<ListedDisplay>,
+}
+
+/// Modes for one output parsed from a backend tool
+type ParsedOutput = (String, Option<CurrentMode>, Vec<DisplayModeInfo>);
+
+/// Parse connected outputs and their modes from `xrandr --query`
+fn parse_xrandr_outputs(output: &str) -> Vec<ParsedOutput> {
+    let mut outputs: Vec<ParsedOutput> = Vec::new();
+    let mut in_connected = false;
+
+    for line in output.lines() {
+        if !line.starts_with(' ') {
+            let mut parts = line.split_whitespace();
+            let name = parts.next().unwrap_or_default();
+            in_connected = parts.next() == Some("connected");
+            if in_connected {
+                outputs.push((name.to_string(), None, Vec::new()));
+            }
+            continue;
+        }
+
+        let Some((_, current, modes)) = outputs.last_mut().filter(|_| in_connected) else {
+            continue;
+        };
+        let mut parts = line.split_whitespace();
+        let Some((width, height)) = parts.next().and_then(parse_resolution) else {
+            continue;
+        };
+
+        let mut mode = DisplayModeInfo {
+            width,
+            height,
+            refresh_rates: Vec::new(),
+        };
+        for rate in parts {
+            let Ok(hz) = rate.trim_end_matches(['*', '+']).parse::<f32>() else {
+                continue;
+            };
+            if rate.contains('*') {
+                *current = Some(CurrentMode {
+                    width,
+                    height,
+                    refresh_hz: hz,
+                });
+            }
+            mode.refresh_rates.push(hz);
+        }
+        modes.push(mode);
+    }
+
+    outputs
+}
+
+/// Parse outputs and modes from `wlr-randr`
+fn parse_wlr_randr_outputs(output: &str) -> Vec<ParsedOutput> {
+    let mut outputs: Vec<ParsedOutput> = Vec::new();
+
+    for line in output.lines() {
+        if !line.starts_with(' ') {
+            if let Some(name) = line.split_whitespace().next() {
+                outputs.push((name.to_string(), None, Vec::new()));
+            }
+            continue;
+        }
+
+        // "    2560x1440 px, 143.912000 Hz (preferred, current)"
+        let Some((_, current, modes)) = outputs.last_mut() else {
+            continue;
+        };
+        let trimmed = line.trim();
+        let Some((res, rest)) = trimmed.split_once(" px, ") else {
+            continue;
+        };
+        let (Some((width, height)), Some(hz)) = (
+            parse_resolution(res),
+            rest.split_whitespace()
+                .next()
+                .and_then(|hz| hz.parse::<f32>().ok()),
+        ) else {
+            continue;
+        };
+
+        if rest.contains("current") {
+            *current = Some(CurrentMode {
+                width,
+                height,
+                refresh_hz: hz,
+            });
+        }
+        match modes
+            .iter_mut()
+            .find(|m| m.width == width && m.height == height)
+        {
+            Some(mode) => mode.refresh_rates.push(hz),
+            None => modes.push(DisplayModeInfo {
+                width,
+                height,
+                refresh_rates: vec![hz],
+            }),
+        }
+    }
+
+    outputs
+}
+
+fn parse_resolution(text: &str) -> Option<(u32, u32)> {
+    let (w, h) = text.split_once('x')?;
+    let h: String = h.chars().take_while(|c| c.is_ascii_digit()).collect();
+    Some((w.parse().ok()?, h.parse().ok()?))
+}
+
+/// Connector type and ordinal, normalising NVIDIA's X11 names ("HDMI-0")
+/// and DRM names ("HDMI-A-1") to the same type
+fn connector_kind(connector: &str) -> &str {
+    let kind = connector
+        .rsplit_once('-')
+        .map(|(kind, _)| kind)
+        .unwrap_or(connector);
+    match kind {
+        "HDMI-A" | "HDMI-B" => "HDMI",
+        other => other,
+    }
+}
+
+/// Match a backend output name to a DRM connector: by name when they agree,
+/// otherwise the n-th connected output of the same type
+fn match_drm_connector(name: &str, backend_names: &[String], drm: &[String]) -> Option<String> {
+    if drm.iter().any(|c| c == name) {
+        return Some(name.to_string());
+    }
+
+    let kind = connector_kind(name);
+    let ordinal = backend_names
+        .iter()
+        .filter(|n| connector_kind(n) == kind)
+        .position(|n| n == name)?;
+    drm.iter()
+        .filter(|c| connector_kind(c) == kind)
+        .nth(ordinal)
+        .cloned()
+}
+
+/// Monitor name from the EDID display-name descriptor
+pub fn parse_edid_monitor_name(edid: &[u8]) -> Option<String> {
+    edid_descriptor(edid, 0xFC).and_then(|descriptor| {
+        let text: String = descriptor[5..]
+            .iter()
+            .take_while(|b| **b != 0x0A)
+            .map(|b| *b as char)
+            .collect();
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    })
+}
+
+/// Bits per colour channel from an EDID 1.4 digital input definition
+pub fn parse_edid_color_depth(edid: &[u8]) -> Option<u8> {
+    if edid.len() < 128 || edid[19] < 4 || edid[20] & 0x80 == 0 {
+        return None;
+    }
+    match (edid[20] >> 4) & 0x07 {
+        1 => Some(6),
+        2 => Some(8),
+        3 => Some(10),
+        4 => Some(12),
+        5 => Some(14),
+        6 => Some(16),
+        _ => None,
+    }
+}
+
+/// Vertical refresh range from the EDID range-limits descriptor
+pub fn parse_edid_refresh_range(edid: &[u8]) -> Option<VrrRange> {
+    edid_descriptor(edid, 0xFD).map(|descriptor| {
+        // Byte 4 flags add 255 Hz offsets for rates above 255
+        let min_offset = if descriptor[4] & 0x01 != 0 { 255 } else { 0 };
+        let max_offset = if descriptor[4] & 0x02 != 0 { 255 } else { 0 };
+        VrrRange {
+            min_hz: descriptor[5] as u32 + min_offset,
+            max_hz: descriptor[6] as u32 + max_offset,
+        }
+    })
+}
+
+fn edid_descriptor(edid: &[u8], tag: u8) -> Option<&[u8]> {
+    if edid.len() < 128 {
+        return None;
+    }
+    [54, 72, 90, 108]
+        .into_iter()
+        .map(|start| &edid[start..start + 18])
+        .find(|d| d[0..3] == [0, 0, 0] && d[3] == tag)
+}
+
+/// Build the display listing from a display backend, DRM sysfs and VRR
+/// detection results
+pub fn collect_display_listing(
+    runner: &SharedDisplayRunner,
+    drm_root: &std::path::Path,
+    vrr: &[crate::vrr::DisplayVrrCapability],
+) -> DisplayListing {
+    let session = match runner.get_display_server() {
+        Ok(DisplayServer::X11) => "x11",
+        Ok(DisplayServer::Wayland) => "wayland",
+        _ => "unknown",
+    };
+
+    let edids = crate::display::connected_edids(drm_root);
+    let drm_names: Vec<String> = edids.iter().map(|(c, _)| c.clone()).collect();
+
+    let from_tool = match session {
+        "x11" => runner
+            .run_xrandr(&["--query"])
+            .ok()
+            .map(|out| ("xrandr", parse_xrandr_outputs(&out))),
+        "wayland" => runner
+            .run_wlr_randr(&[])
+            .ok()
+            .map(|out| ("wlr-randr", parse_wlr_randr_outputs(&out))),
+        _ => None,
+    }
+    .filter(|(_, outputs)| !outputs.is_empty());
+
+    let (backend, outputs) = from_tool.unwrap_or_else(|| {
+        let outputs = crate::display::connected_connector_modes(drm_root)
+            .into_iter()
+            .map(|(name, modes)| {
+                let modes = modes
+                    .into_iter()
+                    .map(|(width, height)| DisplayModeInfo {
+                        width,
+                        height,
+                        refresh_rates: Vec::new(),
+                    })
+                    .collect();
+                (name, None, modes)
+            })
+            .collect();
+        ("drm-sysfs", outputs)
+    });
+
+    let backend_names: Vec<String> = outputs.iter().map(|(n, _, _)| n.clone()).collect();
+    let displays = outputs
+        .into_iter()
+        .map(|(connector, current_mode, modes)| {
+            let drm_connector = if backend == "drm-sysfs" {
+                Some(connector.clone())
+            } else {
+                match_drm_connector(&connector, &backend_names, &drm_names)
+            };
+            let edid = drm_connector
+                .as_ref()
+                .and_then(|c| edids.iter().find(|(name, _)| name == c))
+                .map(|(_, edid)| edid.as_slice());
+
+            let vrr_entry = vrr.iter().find(|v| {
+                v.display_name == connector || Some(&v.display_name) == drm_connector.as_ref()
+            });
+            let vrr_capable = vrr_entry.map(|v| v.supports_vrr).unwrap_or(false);
+            let vrr_range = vrr_capable
+                .then(|| {
+                    edid.and_then(parse_edid_refresh_range).or_else(|| {
+                        vrr_entry.map(|v| VrrRange {
+                            min_hz: v.min_refresh,
+                            max_hz: v.max_refresh,
+                        })
+                    })
+                })
+                .flatten();
+
+            ListedDisplay {
+                monitor_name: edid.and_then(parse_edid_monitor_name),
+                edid_serial: edid.and_then(parse_edid_serial),
+                hdr_capable: edid
+                    .and_then(crate::display::parse_edid_hdr_metadata)
+                    .map(|m| m.is_available())
+                    .unwrap_or(false),
+                color_depth_bits: edid.and_then(parse_edid_color_depth),
+                connector,
+                drm_connector,
+                current_mode,
+                modes,
+                vrr_capable,
+                vrr_range,
+            }
+        })
+        .collect();
+
+    DisplayListing {
+        session: session.to_string(),
+        backend: backend.to_string(),
+        displays,
+    }
+}
+
+/// List connected displays with modes, EDID details and VRR ranges
+pub fn list_connected_displays() -> DisplayListing {
+    let runner = crate::display_backend::create_real_runner();
+    let vrr = crate::vrr::detect_vrr_displays().unwrap_or_default();
+    collect_display_listing(&runner, std::path::Path::new("/sys/class/drm"), &vrr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drm_connector_to_nvkms_index() {
+        let types: Vec<String> = ["DP", "HDMI", "DP", "DP"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(drm_connector_to_nvkms_index("DP-1", &types), Some(0));
+        assert_eq!(drm_connector_to_nvkms_index("DP-3", &types), Some(3));
+        assert_eq!(drm_connector_to_nvkms_index("HDMI-A-1", &types), Some(1));
+        assert_eq!(drm_connector_to_nvkms_index("HDMI-A-2", &types), None);
+        assert_eq!(drm_connector_to_nvkms_index("DP-0", &types), None);
+    }
+
+    fn listing_test_edid() -> Vec<u8> {
+        let mut edid = vec![0u8; 128];
+        edid[0..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        edid[19] = 4;
+        edid[20] = 0x80 | (3 << 4);
+        edid[54..61].copy_from_slice(&[0, 0, 0, 0xFD, 0, 48, 144]);
+        edid[72..77].copy_from_slice(&[0, 0, 0, 0xFC, 0]);
+        edid[77..88].copy_from_slice(b"LG 27GP850\n");
+        edid
+    }
+
+    #[test]
+    fn test_parse_edid_display_details() {
+        let edid = listing_test_edid();
+        assert_eq!(
+            parse_edid_monitor_name(&edid).as_deref(),
+            Some("LG 27GP850")
+        );
+        assert_eq!(parse_edid_color_depth(&edid), Some(10));
+        assert_eq!(
+            parse_edid_refresh_range(&edid),
+            Some(VrrRange {
+                min_hz: 48,
+                max_hz: 144
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_wlr_randr_groups_refresh_rates() {
+        let output = "DP-1 \"LG\"\n  Modes:\n    2560x1440 px, 143.912000 Hz (preferred, current)\n    2560x1440 px, 60.000000 Hz\n    1920x1080 px, 60.000000 Hz\n";
+        let outputs = parse_wlr_randr_outputs(output);
+
+        assert_eq!(outputs.len(), 1);
+        let (name, current, modes) = &outputs[0];
+        assert_eq!(name, "DP-1");
+        assert_eq!(current.as_ref().map(|c| c.width), Some(2560));
+        assert_eq!(modes.len(), 2);
+        assert_eq!(modes[0].refresh_rates, vec![143.912, 60.0]);
+    }
+
+    #[test]
+    fn test_collect_display_listing_matches_x11_names_to_drm() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("card1-DP-1");
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("status"), "connected\n").unwrap();
+        std::fs::write(dir.join("edid"), listing_test_edid()).unwrap();
+
+        let runner: SharedDisplayRunner =
+            std::sync::Arc::new(crate::display_backend::MockDisplayRunner::x11());
+        let vrr = vec![crate::vrr::DisplayVrrCapability {
+            display_name: "DP-0".to_string(),
+            supports_vrr: true,
+            supports_gsync: true,
+            supports_freesync: false,
+            min_refresh: 1,
+            max_refresh: 165,
+            current_settings: crate::vrr::VrrSettings::default(),
+        }];
+
+        let listing = collect_display_listing(&runner, root.path(), &vrr);
+        assert_eq!(listing.session, "x11");
+        assert_eq!(listing.backend, "xrandr");
+        assert_eq!(listing.displays.len(), 1);
+
+        let display = &listing.displays[0];
+        assert_eq!(display.connector, "DP-0");
+        assert_eq!(display.drm_connector.as_deref(), Some("DP-1"));
+        assert_eq!(display.monitor_name.as_deref(), Some("LG 27GP850"));
+        assert_eq!(
+            display.current_mode,
+            Some(CurrentMode {
+                width: 2560,
+                height: 1440,
+                refresh_hz: 59.95
+            })
+        );
+        assert_eq!(display.modes[0].refresh_rates, vec![59.95, 143.91, 119.88]);
+        assert_eq!(
+            display.vrr_range,
+            Some(VrrRange {
+                min_hz: 48,
+                max_hz: 144
+            })
+        );
+        assert!(!display.hdr_capable);
+    }
+
+    #[test]
+    fn test_parse_edid_serial() {
+        let mut edid = vec![0u8; 128];
+        edid[12..16].copy_from_slice(&1234u32.to_le_bytes());
+        assert_eq!(parse_edid_serial(&edid).as_deref(), Some("1234"));
+
+        edid[72..77].copy_from_slice(&[0, 0, 0, 0xFF, 0]);
+        edid[77..85].copy_from_slice(b"ABC123\n ");
+        assert_eq!(parse_edid_serial(&edid).as_deref(), Some("ABC123"));
+    }
+}
@@ -1733,6 +1733,142 @@ pub fn enable_frame_generation(quality: DlssQuality) -> NvResult<()> {
     Ok(())
 }
 
+/// DLSS Super Resolution render preset forced through NGX driver settings.
+/// Proton games pick these up through DXVK-NVAPI's DRS overrides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DlssRenderPreset {
+    /// Let the game choose
+    Default,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    /// Transformer model (DLSS 4)
+    J,
+    /// Transformer model (DLSS 4), successor to J
+    K,
+    /// Newest preset the installed NGX runtime ships
+    Latest,
+}
+
+/// DRS setting that enables the Super Resolution override
+const DLSS_SR_OVERRIDE_ENV: &str = "DXVK_NVAPI_DRS_NGX_DLSS_SR_OVERRIDE";
+/// DRS setting that selects the forced render preset
+const DLSS_SR_PRESET_ENV: &str = "DXVK_NVAPI_DRS_NGX_DLSS_SR_OVERRIDE_RENDER_PRESET_SELECTION";
+
+impl DlssRenderPreset {
+    pub fn all() -> &'static [DlssRenderPreset] {
+        &[
+            Self::Default,
+            Self::A,
+            Self::B,
+            Self::C,
+            Self::D,
+            Self::E,
+            Self::F,
+            Self::J,
+            Self::K,
+            Self::Latest,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::E => "E",
+            Self::F => "F",
+            Self::J => "J",
+            Self::K => "K",
+            Self::Latest => "latest",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|p| p.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Value for the render preset DRS setting, `None` for `Default`
+    fn drs_value(&self) -> Option<String> {
+        match self {
+            Self::Default => None,
+            Self::Latest => Some("render_preset_latest".to_string()),
+            other => Some(format!("render_preset_{}", other.as_str().to_lowercase())),
+        }
+    }
+}
+
+impl fmt::Display for DlssRenderPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default | Self::Latest => write!(f, "{}", self.as_str()),
+            other => write!(f, "Preset {}", other.as_str()),
+        }
+    }
+}
+
+/// Fail if the driver is too old for NGX to honor forced presets
+pub fn check_preset_support(caps: &crate::drivers::DriverCapabilities) -> NvResult<()> {
+    if caps.has_dlss_preset_override {
+        return Ok(());
+    }
+
+    Err(NvControlError::UnsupportedFeature(format!(
+        "Driver {} does not honor DLSS preset overrides (570 or newer required)",
+        caps.version
+    )))
+}
+
+/// Preset currently forced by a game profile
+pub fn get_preset(profile: &crate::game_launcher::GameProfile) -> DlssRenderPreset {
+    profile
+        .env_vars
+        .get(DLSS_SR_PRESET_ENV)
+        .and_then(|value| value.strip_prefix("render_preset_"))
+        .and_then(DlssRenderPreset::parse)
+        .unwrap_or(DlssRenderPreset::Default)
+}
+
+/// Force a DLSS render preset for a game profile. The caller persists the
+/// profile; `caps` gates the override on the installed driver.
+pub fn set_preset(
+    profile: &mut crate::game_launcher::GameProfile,
+    preset: DlssRenderPreset,
+    caps: &crate::drivers::DriverCapabilities,
+) -> NvResult<()> {
+    if preset != DlssRenderPreset::Default {
+        check_preset_support(caps)?;
+    }
+
+    match preset.drs_value() {
+        Some(value) => {
+            profile
+                .env_vars
+                .insert(DLSS_SR_OVERRIDE_ENV.to_string(), "on".to_string());
+            profile
+                .env_vars
+                .insert(DLSS_SR_PRESET_ENV.to_string(), value);
+            profile
+                .env_vars
+                .insert("PROTON_ENABLE_NVAPI".to_string(), "1".to_string());
+        }
+        None => {
+            profile.env_vars.remove(DLSS_SR_OVERRIDE_ENV);
+            profile.env_vars.remove(DLSS_SR_PRESET_ENV);
+        }
+    }
+
+    Ok(())
+}
+
 /// Get DLSS status and capabilities
 pub fn get_dlss_status() -> NvResult<String> {
     let controller = DlssController::new()?;
@@ -1833,6 +1969,39 @@ mod tests {
         assert!(profiles.contains_key("cs2"));
     }
 
+    #[test]
+    fn test_set_preset_writes_drs_overrides() {
+        let mut profile = crate::game_launcher::GameProfile::new(
+            "cyberpunk".to_string(),
+            "Cyberpunk2077.exe".to_string(),
+        );
+        let caps = crate::drivers::DriverCapabilities::from_version("580.95.05").unwrap();
+
+        set_preset(&mut profile, DlssRenderPreset::K, &caps).unwrap();
+        assert_eq!(
+            profile.env_vars.get(DLSS_SR_PRESET_ENV).map(String::as_str),
+            Some("render_preset_k")
+        );
+        assert_eq!(get_preset(&profile), DlssRenderPreset::K);
+
+        set_preset(&mut profile, DlssRenderPreset::Default, &caps).unwrap();
+        assert!(!profile.env_vars.contains_key(DLSS_SR_OVERRIDE_ENV));
+        assert_eq!(get_preset(&profile), DlssRenderPreset::Default);
+    }
+
+    #[test]
+    fn test_set_preset_rejects_old_driver() {
+        let mut profile = crate::game_launcher::GameProfile::default();
+        let caps = crate::drivers::DriverCapabilities::from_version("550.120").unwrap();
+
+        assert!(set_preset(&mut profile, DlssRenderPreset::E, &caps).is_err());
+        assert!(set_preset(&mut profile, DlssRenderPreset::Default, &caps).is_ok());
+        assert_eq!(
+            DlssRenderPreset::parse("latest"),
+            Some(DlssRenderPreset::Latest)
+        );
+    }
+
     #[test]
     fn test_default_settings() {
         let settings = DlssSettings::default();
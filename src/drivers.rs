@@ -397,7 +397,7 @@ fn collect_arch_package_diagnostics() -> Vec<PackageDiagnostic> {
     diagnostics
 }
 
-fn detect_initramfs_tool() -> Option<String> {
+pub(crate) fn detect_initramfs_tool() -> Option<String> {
     if std::path::Path::new("/usr/bin/mkinitcpio").exists() {
         Some("mkinitcpio".to_string())
     } else if std::path::Path::new("/usr/bin/dracut").exists() {
@@ -3026,6 +3026,8 @@ pub struct DriverCapabilities {
     pub has_usb4_dp_support: bool,
     pub supports_preempt_rt: bool,
     pub has_powermizer_wayland_fix: bool,
+    /// NGX honors per-app DLSS preset overrides (570+)
+    pub has_dlss_preset_override: bool,
     // 610+ capabilities
     pub has_vulkan_device_group: bool,
     pub has_fp16_egl_wayland: bool,
@@ -3063,6 +3065,7 @@ impl DriverCapabilities {
             has_usb4_dp_support: major >= 590,
             supports_preempt_rt: major >= 590,
             has_powermizer_wayland_fix: major >= 590,
+            has_dlss_preset_override: major >= 570,
             // 610+ capabilities
             has_vulkan_device_group: major >= 610,
             has_fp16_egl_wayland: major >= 610,
@@ -3071,6 +3074,69 @@ impl DriverCapabilities {
         })
     }
 
+    /// Capabilities gained or lost relative to `previous`
+    pub fn changes_since(&self, previous: &Self) -> Vec<String> {
+        let features = [
+            (
+                "Vulkan swapchain performance improvements",
+                previous.has_vulkan_swapchain_perf,
+                self.has_vulkan_swapchain_perf,
+            ),
+            (
+                "USB4 DisplayPort",
+                previous.has_usb4_dp_support,
+                self.has_usb4_dp_support,
+            ),
+            (
+                "PREEMPT_RT kernels",
+                previous.supports_preempt_rt,
+                self.supports_preempt_rt,
+            ),
+            (
+                "the PowerMizer Wayland fix",
+                previous.has_powermizer_wayland_fix,
+                self.has_powermizer_wayland_fix,
+            ),
+            (
+                "DLSS preset overrides",
+                previous.has_dlss_preset_override,
+                self.has_dlss_preset_override,
+            ),
+            (
+                "Vulkan device groups",
+                previous.has_vulkan_device_group,
+                self.has_vulkan_device_group,
+            ),
+            (
+                "FP16 EGL surfaces on Wayland",
+                previous.has_fp16_egl_wayland,
+                self.has_fp16_egl_wayland,
+            ),
+            (
+                "dma-buf mmap",
+                previous.has_dmabuf_mmap,
+                self.has_dmabuf_mmap,
+            ),
+            (
+                "the DRM color pipeline",
+                previous.has_drm_color_pipeline,
+                self.has_drm_color_pipeline,
+            ),
+        ];
+
+        features
+            .into_iter()
+            .filter(|(_, before, after)| before != after)
+            .map(|(name, _, after)| {
+                if after {
+                    format!("now supports {name}")
+                } else {
+                    format!("no longer supports {name}")
+                }
+            })
+            .collect()
+    }
+
     fn parse_major_version(version: &str) -> u32 {
         // Version format: "590.44.01" -> 590
         version
@@ -3081,6 +3147,91 @@ impl DriverCapabilities {
     }
 }
 
+/// A driver version different from the one recorded on the previous start
+#[derive(Debug, Clone)]
+pub struct DriverVersionChange {
+    pub previous_version: String,
+    pub current_version: String,
+    /// Capability differences, e.g. "now supports USB4 DisplayPort"
+    pub changes: Vec<String>,
+}
+
+/// Compare the recorded driver version against the installed one
+pub fn detect_version_change(previous: &str, current: &str) -> Option<DriverVersionChange> {
+    if previous == current {
+        return None;
+    }
+    let before = DriverCapabilities::from_version(previous).ok()?;
+    let after = DriverCapabilities::from_version(current).ok()?;
+    Some(DriverVersionChange {
+        previous_version: previous.to_string(),
+        current_version: current.to_string(),
+        changes: after.changes_since(&before),
+    })
+}
+
+/// Check the installed driver against the last recorded version and record
+/// the current one. The first run only records and reports nothing.
+pub fn check_driver_version_change() -> NvResult<Option<DriverVersionChange>> {
+    let current = get_driver_status()?.current_version;
+    if current.is_empty() || current == "Unknown" {
+        return Ok(None);
+    }
+
+    let change = crate::state::DriverSeenState::load()
+        .and_then(|seen| detect_version_change(&seen.last_seen_version, &current));
+    crate::state::DriverSeenState::record(&current)?;
+    Ok(change)
+}
+
+/// NVIDIA's published pointer to the latest Linux x86_64 driver
+pub const NVIDIA_LATEST_DRIVER_URL: &str =
+    "https://download.nvidia.com/XFree86/Linux-x86_64/latest.txt";
+
+/// Fetch the latest driver version NVIDIA publishes. Only called when
+/// `[driver] check_latest_online` is enabled in the config.
+pub fn fetch_latest_published_version() -> NvResult<String> {
+    let output = Command::new("curl")
+        .args(["-fsSL", "--max-time", "10", NVIDIA_LATEST_DRIVER_URL])
+        .output()
+        .map_err(|e| NvControlError::CommandFailed(format!("curl failed: {e}")))?;
+    if !output.status.success() {
+        return Err(NvControlError::CommandFailed(format!(
+            "Could not fetch {}: {}",
+            NVIDIA_LATEST_DRIVER_URL,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    parse_latest_driver_txt(&String::from_utf8_lossy(&output.stdout)).ok_or_else(|| {
+        NvControlError::CommandFailed("Unexpected format in NVIDIA latest.txt".to_string())
+    })
+}
+
+/// `latest.txt` is "<version> <version>/NVIDIA-Linux-x86_64-<version>.run"
+fn parse_latest_driver_txt(contents: &str) -> Option<String> {
+    let version = contents.split_whitespace().next()?;
+    version
+        .split('.')
+        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        .then(|| version.to_string())
+}
+
+/// Whether dotted version `candidate` is newer than `current`
+pub fn is_newer_driver_version(candidate: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u32> {
+        v.split('.')
+            .map(|part| {
+                part.chars()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect::<String>()
+                    .parse()
+                    .unwrap_or(0)
+            })
+            .collect()
+    };
+    parse(candidate) > parse(current)
+}
+
 /// System requirements check result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemRequirementCheck {
@@ -3195,7 +3346,7 @@ pub fn detect_vulkan_extensions() -> Vec<String> {
         .collect()
 }
 
-fn overlay_safe_vulkaninfo_command() -> Command {
+pub(crate) fn overlay_safe_vulkaninfo_command() -> Command {
     let mut command = Command::new("vulkaninfo");
     command
         .arg("--summary")
@@ -3486,8 +3637,7 @@ pub fn print_driver_info_full() -> NvResult<()> {
 
     // Driver version and type
     let mut driver_version = "Unknown".to_string();
-    let mut module_type = "Unknown".to_string();
-    let mut license = "Unknown".to_string();
+    let module = crate::kernel_driver::module_type();
     let mut built_by = None;
 
     if let Ok(output) = Command::new("nvidia-smi")
@@ -3499,14 +3649,7 @@ pub fn print_driver_info_full() -> NvResult<()> {
         }
     }
 
-    // Detect open vs proprietary from /proc/driver/nvidia/version
     if let Ok(version_info) = fs::read_to_string("/proc/driver/nvidia/version") {
-        if version_info.contains("Open Kernel Module") {
-            module_type = "Open Kernel".to_string();
-        } else {
-            module_type = "Proprietary".to_string();
-        }
-
         // Extract build info
         for line in version_info.lines() {
             if line.contains("NVRM version:") {
@@ -3528,26 +3671,21 @@ pub fn print_driver_info_full() -> NvResult<()> {
         }
     }
 
-    // Get license from modinfo
-    if let Ok(output) = Command::new("modinfo")
-        .args(["nvidia", "-F", "license"])
-        .output()
-    {
-        if output.status.success() {
-            license = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        }
-    }
-
     println!(
         "Driver:         {} ({})",
         driver_version,
-        if module_type == "Open Kernel" {
+        if module.is_open() {
             "nvidia-open"
         } else {
             "nvidia"
         }
     );
-    println!("Module Type:    {} ({})", module_type, license);
+    println!(
+        "Module Type:    {} ({})",
+        module.summary(),
+        module.license.as_deref().unwrap_or("Unknown")
+    );
+    println!("Vibrance:       {}", module.native_vibrance_note());
 
     if let Some(ref build) = built_by {
         println!("Built By:       {}", build);
@@ -5063,6 +5201,45 @@ mod tests {
         assert!(DriverType::from_str("invalid").is_none());
     }
 
+    #[test]
+    fn test_driver_version_change_summary() {
+        assert!(detect_version_change("590.44.01", "590.44.01").is_none());
+
+        let change = detect_version_change("580.95.05", "610.12").unwrap();
+        assert_eq!(change.previous_version, "580.95.05");
+        assert!(
+            change
+                .changes
+                .contains(&"now supports USB4 DisplayPort".to_string())
+        );
+        assert!(
+            change
+                .changes
+                .contains(&"now supports the DRM color pipeline".to_string())
+        );
+
+        let downgrade = detect_version_change("590.44.01", "580.95.05").unwrap();
+        assert!(
+            downgrade
+                .changes
+                .contains(&"no longer supports USB4 DisplayPort".to_string())
+        );
+    }
+
+    #[test]
+    fn test_latest_driver_version_parsing() {
+        assert_eq!(
+            parse_latest_driver_txt("580.95.05 580.95.05/NVIDIA-Linux-x86_64-580.95.05.run\n")
+                .as_deref(),
+            Some("580.95.05")
+        );
+        assert!(parse_latest_driver_txt("<html>").is_none());
+        assert!(is_newer_driver_version("580.95.05", "580.82.09"));
+        assert!(is_newer_driver_version("590.44.01", "580.95.05"));
+        assert!(!is_newer_driver_version("580.95.05", "580.95.05"));
+        assert!(!is_newer_driver_version("575.64", "580.95.05"));
+    }
+
     #[test]
     fn test_detect_distribution() {
         let distro = detect_distribution();
@@ -5097,6 +5274,7 @@ mod tests {
         let caps = DriverCapabilities::from_version("570.86.10").unwrap();
         assert_eq!(caps.major_version, 570);
         assert!(!caps.has_vulkan_swapchain_perf);
+        assert!(caps.has_dlss_preset_override);
         assert!(!caps.supports_preempt_rt);
         assert!(!caps.has_vulkan_device_group);
         assert_eq!(caps.wayland_min_version, "1.17");
@@ -5164,6 +5342,7 @@ mod tests {
         let caps = DriverCapabilities::from_version("470.82.00").unwrap();
         assert_eq!(caps.major_version, 470);
         assert!(!caps.has_vulkan_swapchain_perf);
+        assert!(!caps.has_dlss_preset_override);
     }
 
     #[test]
@@ -259,6 +259,20 @@ impl ErrorWithSolution for NvControlError {
                     )
                 )
             }
+            NvControlError::InvalidArgument(msg) => {
+                format!(
+                    "{} {}\n\n{}\n{}\n\n{}\n  {}",
+                    style("❌ Error:").red().bold(),
+                    style("Invalid argument").bold(),
+                    style("Details:").yellow(),
+                    msg,
+                    style("💡 Solutions:").green().bold(),
+                    format!(
+                        "{} Check the accepted values: nvctl <command> --help",
+                        style("1.").cyan().bold()
+                    )
+                )
+            }
             NvControlError::RuntimeError(msg) => {
                 format!(
                     "{} {}\n\n{}\n{}\n\n{}\n  {}\n  {}",
@@ -1,8 +1,90 @@
 /// Error recovery and graceful degradation system
 ///
 /// Provides automatic fallback mechanisms and user-friendly error handling
+use crate::nvml_backend::NvmlBackend;
 use crate::{NvControlError, NvResult};
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable that starts nvcontrol in safe mode (any value but "0")
+pub const SAFE_MODE_ENV: &str = "NVCONTROL_SAFE_MODE";
+
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Turn safe mode on for this process (the `--safe-mode` flag)
+pub fn set_safe_mode(enabled: bool) {
+    SAFE_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether risky hardware probes should be skipped at startup
+pub fn is_safe_mode() -> bool {
+    SAFE_MODE.load(Ordering::Relaxed)
+        || std::env::var(SAFE_MODE_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Which optional subsystems were skipped or failed during startup
+#[derive(Debug, Clone, Default)]
+pub struct SubsystemReport {
+    pub safe_mode: bool,
+    pub skipped: Vec<&'static str>,
+    /// (subsystem, panic message)
+    pub failed: Vec<(&'static str, String)>,
+}
+
+impl SubsystemReport {
+    pub fn new() -> Self {
+        Self {
+            safe_mode: is_safe_mode(),
+            ..Default::default()
+        }
+    }
+
+    /// Run one subsystem's initialization, continuing with it disabled if it
+    /// panics. Risky subsystems (overclock, fan, RGB, containers) are skipped
+    /// entirely in safe mode.
+    pub fn init<T>(&mut self, name: &'static str, risky: bool, f: impl FnOnce() -> T) -> Option<T> {
+        if risky && self.safe_mode {
+            self.skipped.push(name);
+            return None;
+        }
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+            Ok(value) => Some(value),
+            Err(payload) => {
+                let reason = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "panicked".to_string());
+                self.failed.push((name, reason));
+                None
+            }
+        }
+    }
+
+    /// One-line summary for a startup banner, if anything is disabled
+    pub fn banner(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if self.safe_mode {
+            parts.push(format!(
+                "Safe mode: {} disabled",
+                if self.skipped.is_empty() {
+                    "hardware control".to_string()
+                } else {
+                    self.skipped.join(", ")
+                }
+            ));
+        }
+        if !self.failed.is_empty() {
+            let failed: Vec<String> = self
+                .failed
+                .iter()
+                .map(|(name, reason)| format!("{} ({})", name, reason))
+                .collect();
+            parts.push(format!("Failed to start: {}", failed.join(", ")));
+        }
+        (!parts.is_empty()).then(|| parts.join(" · "))
+    }
+}
 
 /// Error recovery strategy
 #[derive(Debug, Clone, Copy)]
@@ -272,6 +354,119 @@ pub fn handle_nvml_error<T>(
     }
 }
 
+// =============================================================================
+// GPU reset (last resort for a hung device)
+// =============================================================================
+
+/// What stands between a GPU and `nvidia-smi --gpu-reset`
+#[derive(Debug, Clone)]
+pub struct GpuResetCheck {
+    pub index: u32,
+    pub name: String,
+    pub pci_bus_id: String,
+    /// `None` when the driver can't tell whether a display is attached
+    pub display_active: Option<bool>,
+    /// (pid, process name) of everything holding the device
+    pub processes: Vec<(u32, String)>,
+}
+
+impl GpuResetCheck {
+    /// Resetting the GPU that drives the desktop takes the session down with it
+    pub fn drives_display(&self) -> bool {
+        self.display_active == Some(true)
+    }
+}
+
+/// Inspect a GPU before resetting it: display state and processes using it
+pub fn check_gpu_reset(backend: &dyn NvmlBackend, index: u32) -> NvResult<GpuResetCheck> {
+    let info = backend.get_device_info(index)?;
+    let display_active = match backend.is_display_active(index) {
+        Ok(active) => Some(active),
+        Err(NvControlError::UnsupportedFeature(_)) => None,
+        Err(e) => return Err(e),
+    };
+
+    let mut processes: Vec<(u32, String)> = Vec::new();
+    let graphics = backend.get_running_graphics_processes(index)?;
+    let compute = backend.get_running_compute_processes(index)?;
+    for process in graphics.into_iter().chain(compute) {
+        if !processes.iter().any(|(pid, _)| *pid == process.pid) {
+            let name = std::fs::read_to_string(format!("/proc/{}/comm", process.pid))
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| format!("pid:{}", process.pid));
+            processes.push((process.pid, name));
+        }
+    }
+
+    Ok(GpuResetCheck {
+        index,
+        name: info.name,
+        pci_bus_id: info.pci_bus_id,
+        display_active,
+        processes,
+    })
+}
+
+/// Send SIGTERM to the processes holding a GPU
+pub fn kill_gpu_processes(pids: &[u32]) -> NvResult<()> {
+    if pids.is_empty() {
+        return Ok(());
+    }
+    let output = crate::gpu_safe::execute(
+        Command::new("kill")
+            .arg("-TERM")
+            .args(pids.iter().map(|pid| pid.to_string())),
+    )
+    .map_err(|e| NvControlError::CommandFailed(format!("kill: {}", e)))?;
+    if !output.status.success() {
+        return Err(NvControlError::CommandFailed(format!(
+            "kill: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// Run `nvidia-smi --gpu-reset` on one GPU (requires root)
+pub fn reset_gpu(index: u32) -> NvResult<()> {
+    let output = crate::gpu_safe::execute(
+        Command::new("nvidia-smi")
+            .arg("--gpu-reset")
+            .arg("-i")
+            .arg(index.to_string()),
+    )
+    .map_err(|e| NvControlError::CommandFailed(format!("nvidia-smi: {}", e)))?;
+    if !output.status.success() {
+        // nvidia-smi reports reset refusals on stdout
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = [stdout.trim(), stderr.trim()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        return Err(NvControlError::CommandFailed(format!(
+            "nvidia-smi --gpu-reset failed: {}",
+            reason
+        )));
+    }
+    Ok(())
+}
+
+/// Re-initialize NVML from scratch and query the GPU, confirming it came
+/// back after a reset. Returns the device name.
+pub fn verify_gpu_after_reset(index: u32) -> NvResult<String> {
+    let backend = crate::nvml_backend::RealNvmlBackend::new();
+    if !backend.is_available() {
+        return Err(NvControlError::NvmlNotAvailable(
+            "NVML failed to re-initialize after the reset".to_string(),
+        ));
+    }
+    let name = backend.get_name(index)?;
+    backend.get_temperature(index)?;
+    Ok(name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +491,26 @@ mod tests {
         assert!(msg.contains("Check NVIDIA driver installation"));
     }
 
+    #[test]
+    fn test_subsystem_report_isolates_panics() {
+        let mut report = SubsystemReport {
+            safe_mode: true,
+            ..Default::default()
+        };
+        assert_eq!(report.init("monitoring", false, || 7), Some(7));
+        assert_eq!(report.init("fan", true, || 1), None);
+        let crashed: Option<u32> = report.init("RGB", false, || panic!("no i2c bus"));
+        assert_eq!(crashed, None);
+
+        assert_eq!(report.skipped, vec!["fan"]);
+        assert_eq!(report.failed, vec![("RGB", "no i2c bus".to_string())]);
+        let banner = report.banner().unwrap();
+        assert!(banner.contains("Safe mode: fan disabled"));
+        assert!(banner.contains("RGB (no i2c bus)"));
+
+        assert!(SubsystemReport::default().banner().is_none());
+    }
+
     #[test]
     fn test_retry_handler() {
         let handler = RetryHandler::new(3, 10);
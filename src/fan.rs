@@ -1,10 +1,11 @@
 use crate::nvml_backend::SharedNvmlBackend;
-use crate::{NvControlError, NvResult};
+use crate::{NvControlError, NvResult, gpu_safe};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 /// Represents a GPU fan
@@ -36,9 +37,15 @@ pub struct FanCurve {
     pub hysteresis: u8,     // Temperature difference for up/down changes
     pub min_duty_cycle: u8, // Minimum fan speed percentage
     pub max_duty_cycle: u8, // Maximum fan speed percentage
-    pub zero_rpm_threshold: Option<u8>, // Temperature below which fan stops
+    /// Temperature (°C) below which the fan is stopped (0% duty)
+    #[serde(default, alias = "zero_rpm_threshold")]
+    pub zero_rpm_below: Option<u32>,
 }
 
+/// Degrees above `zero_rpm_below` a stopped fan must reach before it spins up
+/// again, so a GPU idling at the threshold doesn't start and stop repeatedly
+pub const ZERO_RPM_HYSTERESIS_C: u32 = 3;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FanCurvePoint {
     pub temperature: u8, // Temperature in Celsius
@@ -93,7 +100,7 @@ impl Default for FanCurve {
             hysteresis: 2,
             min_duty_cycle: 0,
             max_duty_cycle: 100,
-            zero_rpm_threshold: Some(35),
+            zero_rpm_below: Some(35),
         }
     }
 }
@@ -163,7 +170,7 @@ fn create_silent_profile() -> FanProfile {
             hysteresis: 3,
             min_duty_cycle: 0,
             max_duty_cycle: 80, // Cap at 80% for noise reduction
-            zero_rpm_threshold: Some(40),
+            zero_rpm_below: Some(40),
         },
     );
 
@@ -216,7 +223,7 @@ fn create_performance_profile() -> FanProfile {
             hysteresis: 1,
             min_duty_cycle: 30,
             max_duty_cycle: 100,
-            zero_rpm_threshold: None, // Always spinning
+            zero_rpm_below: None, // Always spinning
         },
     );
 
@@ -265,7 +272,7 @@ fn create_aggressive_profile() -> FanProfile {
             hysteresis: 0,
             min_duty_cycle: 50,
             max_duty_cycle: 100,
-            zero_rpm_threshold: None,
+            zero_rpm_below: None,
         },
     );
 
@@ -291,8 +298,11 @@ pub fn list_fans_with_backend(backend: &SharedNvmlBackend) -> Vec<FanInfo> {
             let max_rpm = estimate_max_rpm_from_name(&gpu_name);
 
             for fan_id in 0..fan_count {
-                let rpm = backend.get_fan_speed(gpu_id, fan_id).ok();
-                let percent = rpm.map(|r| r.min(100) as u8);
+                let percent = backend
+                    .get_fan_speed(gpu_id, fan_id)
+                    .ok()
+                    .map(|p| p.min(100) as u8);
+                let rpm = backend.get_fan_rpm(gpu_id, fan_id).ok();
                 let controllable = backend.is_fan_control_supported(gpu_id);
                 let health_status = assess_fan_health_from_metrics(temp, rpm);
 
@@ -336,6 +346,14 @@ pub fn list_fans() -> Vec<FanInfo> {
     list_fans_with_backend(&backend)
 }
 
+/// RPM for display, "—RPM" when the board only reports duty cycle
+pub fn format_rpm(rpm: Option<u32>) -> String {
+    match rpm {
+        Some(rpm) => format!("{} RPM", rpm),
+        None => "—RPM".to_string(),
+    }
+}
+
 /// Assess fan health from temperature and RPM metrics
 fn assess_fan_health_from_metrics(temp: u32, rpm: Option<u32>) -> FanHealthStatus {
     match rpm {
@@ -370,12 +388,10 @@ fn get_fans_via_nvidia_smi() -> Vec<FanInfo> {
             let output_str = String::from_utf8_lossy(&output.stdout);
             for (id, line) in output_str.lines().enumerate() {
                 if let Ok(percent) = line.trim().parse::<u8>() {
-                    // Estimate RPM from percentage (very rough)
-                    let estimated_rpm = (percent as f32 / 100.0 * 3000.0) as u32;
-
+                    // nvidia-smi only reports duty cycle
                     fans.push(FanInfo {
                         id,
-                        rpm: Some(estimated_rpm),
+                        rpm: None,
                         percent: Some(percent),
                         controllable: false, // nvidia-smi typically can't control fans
                         health_status: FanHealthStatus::Unknown,
@@ -413,29 +429,40 @@ pub fn set_fan_speed(fan_id: usize, speed_percent: u8) -> NvResult<()> {
     set_fan_speed_sysfs(fan_id, speed_percent)
 }
 
+/// nvidia-settings numbers fans across all GPUs, so `[fan:N]` for GPU 1 starts
+/// after the last fan of GPU 0
+fn nvidia_settings_fan_index(backend: &SharedNvmlBackend, fan_id: usize) -> usize {
+    let gpu_id = (fan_id / 10) as u32;
+    let preceding: u32 = (0..gpu_id)
+        .map(|gpu| backend.get_fan_count(gpu).unwrap_or(1))
+        .sum();
+    preceding as usize + fan_id % 10
+}
+
 fn set_fan_speed_nvidia_settings(fan_id: usize, speed_percent: u8) -> NvResult<()> {
+    let gpu_id = fan_id / 10;
+
     // Enable manual fan control first
-    let enable_cmd = "nvidia-settings -a '[gpu:0]/GPUFanControlState=1'".to_string();
-    let _ = Command::new("sh").arg("-c").arg(&enable_cmd).output();
+    let enable_cmd = format!("nvidia-settings -a '[gpu:{gpu_id}]/GPUFanControlState=1'");
+    let _ = gpu_safe::execute(Command::new("sh").arg("-c").arg(&enable_cmd));
 
     // Set fan speed
+    let backend = crate::nvml_backend::create_real_backend();
     let fan_cmd = format!(
         "nvidia-settings -a '[fan:{}]/GPUTargetFanSpeed={}'",
-        fan_id, speed_percent
+        nvidia_settings_fan_index(&backend, fan_id),
+        speed_percent
     );
 
-    let output = Command::new("sh")
-        .arg("-c")
-        .arg(&fan_cmd)
-        .output()
-        .map_err(|e| {
-            NvControlError::DisplayDetectionFailed(format!("nvidia-settings failed: {e}"))
-        })?;
+    let output = gpu_safe::execute(Command::new("sh").arg("-c").arg(&fan_cmd)).map_err(|e| {
+        NvControlError::DisplayDetectionFailed(format!("nvidia-settings failed: {e}"))
+    })?;
 
     if output.status.success() {
-        println!(
+        log::info!(
             "Fan {} set to {}% via nvidia-settings",
-            fan_id, speed_percent
+            fan_id,
+            speed_percent
         );
         Ok(())
     } else {
@@ -447,21 +474,20 @@ fn set_fan_speed_nvidia_settings(fan_id: usize, speed_percent: u8) -> NvResult<(
 }
 
 fn set_fan_speed_sysfs(fan_id: usize, speed_percent: u8) -> NvResult<()> {
-    // Try to find the correct hwmon path for the fan
-    let hwmon_paths = find_nvidia_hwmon_paths();
+    let local_fan_id = fan_id % 10;
 
-    for hwmon_path in hwmon_paths {
-        let pwm_path = hwmon_path.join(format!("pwm{}", fan_id + 1));
-        let pwm_enable_path = hwmon_path.join(format!("pwm{}_enable", fan_id + 1));
+    if let Some(hwmon_path) = nvidia_hwmon_path_for_gpu((fan_id / 10) as u32) {
+        let pwm_path = hwmon_path.join(format!("pwm{}", local_fan_id + 1));
+        let pwm_enable_path = hwmon_path.join(format!("pwm{}_enable", local_fan_id + 1));
 
         // Convert percentage to PWM value (0-255)
         let pwm_value = (speed_percent as f32 / 100.0 * 255.0) as u8;
 
         // Enable manual control
-        if std::fs::write(&pwm_enable_path, "1").is_ok() {
+        if gpu_safe::write_sysfs(&pwm_enable_path, "1").is_ok() {
             // Set PWM value
-            if std::fs::write(&pwm_path, pwm_value.to_string()).is_ok() {
-                println!("Fan {} set to {}% via sysfs", fan_id, speed_percent);
+            if gpu_safe::write_sysfs(&pwm_path, pwm_value.to_string()).is_ok() {
+                log::info!("Fan {} set to {}% via sysfs", fan_id, speed_percent);
                 return Ok(());
             }
         }
@@ -489,6 +515,62 @@ fn find_nvidia_hwmon_paths() -> Vec<std::path::PathBuf> {
     paths
 }
 
+/// The nvidia hwmon directory of `gpu_index`. NVML numbers GPUs in PCI bus
+/// order, so the hwmon directories are sorted by the PCI device they sit on.
+fn nvidia_hwmon_path_for_gpu(gpu_index: u32) -> Option<std::path::PathBuf> {
+    let mut paths: Vec<_> = find_nvidia_hwmon_paths()
+        .into_iter()
+        .map(|path| (std::fs::canonicalize(path.join("device")).ok(), path))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .nth(gpu_index as usize)
+        .map(|(_, path)| path)
+}
+
+/// Take a fan off automatic control so duty-cycle writes stick.
+///
+/// Uses nvidia-settings' `GPUFanControlState` on the fan's GPU under X11,
+/// otherwise the `pwmN_enable` attribute of that GPU's hwmon. Fails with [`NvControlError::FanControlNotSupported`]
+/// when neither accepts the switch.
+pub fn enable_manual_control(fan_id: usize) -> NvResult<()> {
+    let gpu_id = (fan_id / 10) as u32;
+    let local_fan_id = fan_id % 10;
+
+    if std::env::var("DISPLAY").is_ok() {
+        let cmd = format!("nvidia-settings -a '[gpu:{gpu_id}]/GPUFanControlState=1'");
+        if gpu_safe::execute(Command::new("sh").arg("-c").arg(&cmd))
+            .is_ok_and(|output| output.status.success())
+        {
+            log::info!("Fan {} switched to manual control", fan_id);
+            return Ok(());
+        }
+    }
+
+    if let Some(hwmon_path) = nvidia_hwmon_path_for_gpu(gpu_id) {
+        let pwm_enable_path = hwmon_path.join(format!("pwm{}_enable", local_fan_id + 1));
+        if gpu_safe::write_sysfs(&pwm_enable_path, "1").is_ok() {
+            // 1 = manual
+            log::info!("Fan {} switched to manual control via sysfs", fan_id);
+            return Ok(());
+        }
+    }
+
+    Err(NvControlError::FanControlNotSupported)
+}
+
+/// Switch a fan to manual control, then set its duty cycle
+pub fn set_manual_fan_speed(fan_id: usize, speed_percent: u8) -> NvResult<()> {
+    if speed_percent > 100 {
+        return Err(NvControlError::InvalidArgument(format!(
+            "fan speed {speed_percent}% is out of range (0-100)"
+        )));
+    }
+    enable_manual_control(fan_id)?;
+    set_fan_speed(fan_id, speed_percent)
+}
+
 /// Create custom fan curve
 pub fn set_fan_curve(fan_id: usize, curve_points: &[(u8, u8)]) -> NvResult<()> {
     if curve_points.is_empty() {
@@ -506,7 +588,7 @@ pub fn set_fan_curve(fan_id: usize, curve_points: &[(u8, u8)]) -> NvResult<()> {
         }
     }
 
-    println!("Setting fan curve for fan {}: {:?}", fan_id, curve_points);
+    log::info!("Setting fan curve for fan {}: {:?}", fan_id, curve_points);
 
     // This would require more complex implementation to set automatic curves
     // For now, just apply the maximum speed point as a simple implementation
@@ -519,30 +601,38 @@ pub fn set_fan_curve(fan_id: usize, curve_points: &[(u8, u8)]) -> NvResult<()> {
 
 /// Reset fan to automatic control
 pub fn reset_fan_to_auto(fan_id: usize) -> NvResult<()> {
+    let result = reset_fan_to_auto_inner(fan_id);
+    if result.is_ok() {
+        crate::state::LastApplied::update(|state| {
+            state.fan_speeds.remove(&fan_id);
+        });
+    }
+    result
+}
+
+fn reset_fan_to_auto_inner(fan_id: usize) -> NvResult<()> {
+    let gpu_id = (fan_id / 10) as u32;
+    let local_fan_id = fan_id % 10;
+
     // Try nvidia-settings first
     if std::env::var("DISPLAY").is_ok() {
-        let cmd = "nvidia-settings -a '[gpu:0]/GPUFanControlState=0'";
-        let output = Command::new("sh")
-            .arg("-c")
-            .arg(cmd)
-            .output()
-            .map_err(|e| {
-                NvControlError::DisplayDetectionFailed(format!("nvidia-settings failed: {e}"))
-            })?;
+        let cmd = format!("nvidia-settings -a '[gpu:{gpu_id}]/GPUFanControlState=0'");
+        let output = gpu_safe::execute(Command::new("sh").arg("-c").arg(&cmd)).map_err(|e| {
+            NvControlError::DisplayDetectionFailed(format!("nvidia-settings failed: {e}"))
+        })?;
 
         if output.status.success() {
-            println!("Fan {} reset to automatic control", fan_id);
+            log::info!("Fan {} reset to automatic control", fan_id);
             return Ok(());
         }
     }
 
     // Try sysfs
-    let hwmon_paths = find_nvidia_hwmon_paths();
-    for hwmon_path in hwmon_paths {
-        let pwm_enable_path = hwmon_path.join(format!("pwm{}_enable", fan_id + 1));
-        if std::fs::write(&pwm_enable_path, "2").is_ok() {
+    if let Some(hwmon_path) = nvidia_hwmon_path_for_gpu(gpu_id) {
+        let pwm_enable_path = hwmon_path.join(format!("pwm{}_enable", local_fan_id + 1));
+        if gpu_safe::write_sysfs(&pwm_enable_path, "2").is_ok() {
             // 2 = automatic
-            println!("Fan {} reset to automatic control via sysfs", fan_id);
+            log::info!("Fan {} reset to automatic control via sysfs", fan_id);
             return Ok(());
         }
     }
@@ -557,20 +647,38 @@ pub fn apply_fan_curve(
     current_temp: u8,
     previous_speed: Option<u8>,
 ) -> NvResult<u8> {
+    let speed = curve_speed(curve, current_temp, previous_speed);
+
+    // Apply the fan speed
+    set_fan_speed(fan_id, speed)?;
+
+    Ok(speed)
+}
+
+/// Duty cycle a curve asks for at `current_temp`.
+///
+/// Below `zero_rpm_below` this is 0%; a fan already stopped (`previous_speed`
+/// of 0) stays stopped until the temperature clears the threshold by
+/// [`ZERO_RPM_HYSTERESIS_C`].
+pub fn curve_speed(curve: &FanCurve, current_temp: u8, previous_speed: Option<u8>) -> u8 {
     // Sort curve points by temperature
     let mut sorted_points = curve.points.clone();
     sorted_points.sort_by_key(|p| p.temperature);
 
-    // Check zero RPM threshold
-    if let Some(zero_threshold) = curve.zero_rpm_threshold {
-        if current_temp < zero_threshold {
-            return Ok(0);
+    if let Some(zero_below) = curve.zero_rpm_below {
+        let restart_at = if previous_speed == Some(0) {
+            zero_below + ZERO_RPM_HYSTERESIS_C
+        } else {
+            zero_below
+        };
+        if (current_temp as u32) < restart_at {
+            return 0;
         }
     }
 
     // Handle empty curves
     if sorted_points.is_empty() {
-        return Ok(curve.min_duty_cycle);
+        return curve.min_duty_cycle;
     }
 
     // Find the appropriate speed using linear interpolation
@@ -600,22 +708,18 @@ pub fn apply_fan_curve(
         result
     };
 
-    // Apply hysteresis to prevent oscillation
-    let final_speed = if let Some(prev_speed) = previous_speed {
+    // Apply hysteresis to prevent oscillation; a fan restarting from zero RPM
+    // goes straight to the curve speed
+    let final_speed = if let Some(prev_speed) = previous_speed.filter(|s| *s > 0) {
         apply_hysteresis(target_speed, prev_speed, curve.hysteresis, current_temp)
     } else {
         target_speed
     };
 
     // Clamp to min/max duty cycle
-    let clamped_speed = final_speed
+    final_speed
         .max(curve.min_duty_cycle)
-        .min(curve.max_duty_cycle);
-
-    // Apply the fan speed
-    set_fan_speed(fan_id, clamped_speed)?;
-
-    Ok(clamped_speed)
+        .min(curve.max_duty_cycle)
 }
 
 /// Apply hysteresis to prevent fan speed oscillation
@@ -695,15 +799,186 @@ pub fn test_fan(fan_id: usize) -> NvResult<FanTestResult> {
     Ok(test_result)
 }
 
+/// Measured RPM at one duty-cycle step of a ramp test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FanRampStep {
+    pub target_percent: u8,
+    pub rpm: Option<u32>,
+}
+
+/// Ramp test outcome for a single fan
+#[derive(Debug, Clone)]
+pub struct FanRampResult {
+    pub fan_id: usize,
+    pub steps: Vec<FanRampStep>,
+    pub stuck: bool,
+}
+
+/// Duty cycles visited by a ramp test, always ending at 100%
+pub fn ramp_steps(increment: u8) -> Vec<u8> {
+    let increment = increment.clamp(1, 100);
+    let mut steps: Vec<u8> = (0..100u8).step_by(increment as usize).collect();
+    steps.push(100);
+    steps
+}
+
+/// Two readings are settled once they're within 3% (or 50 RPM) of each other
+fn rpm_settled(previous: u32, current: u32) -> bool {
+    previous.abs_diff(current) <= (previous.max(current) * 3 / 100).max(50)
+}
+
+/// A fan that spins no faster at full duty than at its lowest non-zero step is
+/// probably stuck or disconnected. Fans with fewer than two readings can't be judged.
+pub fn fan_looks_stuck(steps: &[FanRampStep]) -> bool {
+    let readings: Vec<(u8, u32)> = steps
+        .iter()
+        .filter(|step| step.target_percent > 0)
+        .filter_map(|step| step.rpm.map(|rpm| (step.target_percent, rpm)))
+        .collect();
+
+    match (readings.first(), readings.last()) {
+        (Some(&(low_pct, low_rpm)), Some(&(high_pct, high_rpm))) if high_pct > low_pct => {
+            high_rpm <= low_rpm || rpm_settled(low_rpm, high_rpm)
+        }
+        _ => false,
+    }
+}
+
+/// Whether a fan is currently under manual duty-cycle control, if that can be read
+fn fan_is_manual(fan_id: usize) -> Option<bool> {
+    let gpu_id = (fan_id / 10) as u32;
+    let local_fan_id = fan_id % 10;
+
+    if let Some(hwmon_path) = nvidia_hwmon_path_for_gpu(gpu_id) {
+        let enable_path = hwmon_path.join(format!("pwm{}_enable", local_fan_id + 1));
+        if let Ok(value) = std::fs::read_to_string(enable_path) {
+            return Some(value.trim() == "1");
+        }
+    }
+
+    if std::env::var("DISPLAY").is_ok() {
+        let target = format!("[gpu:{gpu_id}]/GPUFanControlState");
+        let output = Command::new("nvidia-settings")
+            .args(["-q", target.as_str(), "-t"])
+            .output()
+            .ok()?;
+        if output.status.success() {
+            return Some(String::from_utf8_lossy(&output.stdout).trim() == "1");
+        }
+    }
+
+    None
+}
+
+/// Poll a fan until its RPM stops moving, giving up after `timeout`
+fn wait_for_stable_rpm(fan_id: usize, timeout: Duration, cancel: &AtomicBool) -> Option<u32> {
+    let start = Instant::now();
+    let mut previous: Option<u32> = None;
+
+    while start.elapsed() < timeout && !cancel.load(Ordering::SeqCst) {
+        std::thread::sleep(Duration::from_millis(500));
+        let current = get_fan_info(fan_id).and_then(|info| info.rpm);
+        if let (Some(prev), Some(cur)) = (previous, current) {
+            if rpm_settled(prev, cur) {
+                return Some(cur);
+            }
+        }
+        previous = current.or(previous);
+    }
+
+    previous
+}
+
+static FAN_INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn on_fan_interrupt(_signal: libc::c_int) {
+    FAN_INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Route Ctrl+C and SIGTERM into a flag so a ramp test or the fan daemon can
+/// hand fans back to the driver before exiting
+pub fn fan_interrupt_flag() -> &'static AtomicBool {
+    // SAFETY: the handler only stores to an atomic, which is async-signal-safe
+    unsafe {
+        for signal in [libc::SIGINT, libc::SIGTERM] {
+            libc::signal(signal, on_fan_interrupt as *const () as libc::sighandler_t);
+        }
+    }
+    &FAN_INTERRUPTED
+}
+
+/// Step every controllable fan from 0 to 100% and record the RPM it settles at.
+/// Each fan's prior mode (automatic or its manual duty cycle) is restored
+/// afterwards, including when `cancel` is raised part way through.
+pub fn ramp_test_fans(
+    increment: u8,
+    settle_timeout: Duration,
+    cancel: &AtomicBool,
+) -> NvResult<Vec<FanRampResult>> {
+    let fans: Vec<FanInfo> = list_fans()
+        .into_iter()
+        .filter(|fan| fan.controllable)
+        .collect();
+    if fans.is_empty() {
+        return Err(NvControlError::FanControlNotSupported);
+    }
+
+    let steps = ramp_steps(increment);
+    let mut results = Vec::new();
+
+    for fan in &fans {
+        let was_manual = fan_is_manual(fan.id).unwrap_or(false);
+        let mut result = FanRampResult {
+            fan_id: fan.id,
+            steps: Vec::new(),
+            stuck: false,
+        };
+
+        let mut outcome = Ok(());
+        for &target in &steps {
+            if cancel.load(Ordering::SeqCst) {
+                break;
+            }
+            if let Err(e) = set_fan_speed(fan.id, target) {
+                outcome = Err(e);
+                break;
+            }
+            let rpm = wait_for_stable_rpm(fan.id, settle_timeout, cancel);
+            result.steps.push(FanRampStep {
+                target_percent: target,
+                rpm,
+            });
+        }
+
+        let restored = match fan.percent {
+            Some(percent) if was_manual => set_fan_speed(fan.id, percent),
+            _ => reset_fan_to_auto(fan.id),
+        };
+
+        outcome?;
+        restored?;
+
+        result.stuck = fan_looks_stuck(&result.steps);
+        results.push(result);
+
+        if cancel.load(Ordering::SeqCst) {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
 /// Enable zero RPM mode using backend (fan stops at low temperatures)
 pub fn enable_zero_rpm_mode_with_backend(
     fan_id: usize,
     threshold_temp: u8,
     backend: &SharedNvmlBackend,
 ) -> NvResult<()> {
-    println!(
+    log::info!(
         "Enabling zero RPM mode for fan {} at {}°C threshold",
-        fan_id, threshold_temp
+        fan_id,
+        threshold_temp
     );
 
     // This would typically involve setting fan curves with zero RPM points
@@ -713,7 +988,7 @@ pub fn enable_zero_rpm_mode_with_backend(
     if let Ok(temp) = backend.get_temperature(gpu_id) {
         if temp < threshold_temp as u32 {
             set_fan_speed(fan_id, 0)?;
-            println!("Fan {} stopped (zero RPM mode active)", fan_id);
+            log::info!("Fan {} stopped (zero RPM mode active)", fan_id);
         }
     }
 
@@ -938,4 +1213,75 @@ mod tests {
             assert_eq!(fan.id, 0);
         }
     }
+
+    #[test]
+    fn test_manual_fan_speed_rejects_out_of_range() {
+        assert!(matches!(
+            set_manual_fan_speed(0, 101),
+            Err(NvControlError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_nvidia_settings_fan_index_counts_preceding_gpus() {
+        let backend: SharedNvmlBackend =
+            std::sync::Arc::new(crate::nvml_backend::MockNvmlBackend::multi_gpu(3));
+        assert_eq!(nvidia_settings_fan_index(&backend, 0), 0);
+        assert_eq!(nvidia_settings_fan_index(&backend, 1), 1);
+        assert_eq!(nvidia_settings_fan_index(&backend, 10), 2);
+        assert_eq!(nvidia_settings_fan_index(&backend, 21), 5);
+    }
+
+    #[test]
+    fn test_ramp_steps_end_at_full_speed() {
+        assert_eq!(ramp_steps(25), vec![0, 25, 50, 75, 100]);
+        assert_eq!(ramp_steps(30), vec![0, 30, 60, 90, 100]);
+        assert_eq!(ramp_steps(0).len(), 101);
+    }
+
+    #[test]
+    fn test_fan_looks_stuck() {
+        let step = |target_percent, rpm| FanRampStep {
+            target_percent,
+            rpm,
+        };
+
+        let healthy = [
+            step(0, Some(0)),
+            step(50, Some(1400)),
+            step(100, Some(2900)),
+        ];
+        assert!(!fan_looks_stuck(&healthy));
+
+        let flat = [
+            step(0, Some(0)),
+            step(50, Some(1200)),
+            step(100, Some(1210)),
+        ];
+        assert!(fan_looks_stuck(&flat));
+
+        let dead = [step(0, Some(0)), step(50, Some(0)), step(100, Some(0))];
+        assert!(fan_looks_stuck(&dead));
+
+        // Not enough readings to judge
+        let unread = [step(0, None), step(50, None), step(100, Some(2000))];
+        assert!(!fan_looks_stuck(&unread));
+    }
+
+    #[test]
+    fn test_zero_rpm_hysteresis() {
+        let curve = FanCurve {
+            zero_rpm_below: Some(50),
+            min_duty_cycle: 0,
+            ..FanCurve::default()
+        };
+
+        assert_eq!(curve_speed(&curve, 45, None), 0);
+        // Stopped fans wait for threshold + hysteresis before restarting
+        assert_eq!(curve_speed(&curve, 51, Some(0)), 0);
+        assert!(curve_speed(&curve, 50 + ZERO_RPM_HYSTERESIS_C as u8, Some(0)) > 0);
+        // Spinning fans keep spinning down to the threshold itself
+        assert!(curve_speed(&curve, 51, Some(40)) > 0);
+        assert_eq!(curve_speed(&curve, 49, Some(40)), 0);
+    }
 }
@@ -0,0 +1,508 @@
+//! Persistent fan curve daemon
+//!
+//! Manual fan control reverts to the VBIOS curve as soon as nvcontrol exits.
+//! The curve chosen in the CLI or GUI is saved to `active_fan_curves.json`
+//! and `nvctl fan daemon` keeps applying it, installed as a systemd unit.
+//!
+//! Each fan can have its own curve; fans without one follow the shared
+//! curve, if any. Every fan follows the temperature of the GPU it is on.
+
+use crate::fan::{self, FanCurve, FanCurvePoint};
+use crate::nvml_backend::SharedNvmlBackend;
+use crate::{NvControlError, NvResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+const SERVICE_NAME: &str = "nvcontrol-fan-curve.service";
+const SYSTEM_UNIT_DIR: &str = "/etc/systemd/system";
+
+fn default_poll_interval() -> u64 {
+    2
+}
+
+/// Curves the daemon keeps applied, keyed by fan id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActiveFanCurves {
+    /// GPU whose fans follow `shared`
+    #[serde(default)]
+    pub gpu_index: u32,
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval_secs: u64,
+    /// Curve for every fan of `gpu_index` that has no entry in `curves`
+    #[serde(default)]
+    pub shared: Option<FanCurve>,
+    /// Per-fan curves, overriding `shared`
+    #[serde(default)]
+    pub curves: BTreeMap<usize, FanCurve>,
+}
+
+impl ActiveFanCurves {
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol")
+            .join("active_fan_curves.json")
+    }
+
+    /// Load from `path`; a missing file means no curves are active
+    pub fn load(path: &Path) -> NvResult<Self> {
+        if !path.exists() {
+            return Ok(Self {
+                poll_interval_secs: default_poll_interval(),
+                ..Self::default()
+            });
+        }
+        let content = fs::read_to_string(path).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Curve driving `fan_id`: its own if set, else the shared one
+    pub fn curve_for(&self, fan_id: usize) -> Option<&FanCurve> {
+        self.curves.get(&fan_id).or(self.shared.as_ref())
+    }
+
+    /// Fans to drive and their curves: every fan with its own curve, plus
+    /// each of `gpu_fans` when a shared curve is set
+    pub fn driven_fans(&self, gpu_fans: &[usize]) -> BTreeMap<usize, &FanCurve> {
+        let mut driven: BTreeMap<usize, &FanCurve> =
+            self.curves.iter().map(|(id, c)| (*id, c)).collect();
+        if let Some(shared) = &self.shared {
+            for fan_id in gpu_fans {
+                driven.entry(*fan_id).or_insert(shared);
+            }
+        }
+        driven
+    }
+
+    /// Stop driving `fan_id`. When it followed the shared curve, the rest of
+    /// `gpu_fans` keep that curve as their own. Returns whether anything
+    /// changed.
+    pub fn release_fan(&mut self, fan_id: usize, gpu_fans: &[usize]) -> bool {
+        let had_curve = self.curves.remove(&fan_id).is_some();
+        if !gpu_fans.contains(&fan_id) {
+            return had_curve;
+        }
+        let Some(shared) = self.shared.take() else {
+            return had_curve;
+        };
+        for other in gpu_fans.iter().filter(|id| **id != fan_id) {
+            self.curves.entry(*other).or_insert_with(|| shared.clone());
+        }
+        true
+    }
+
+    pub fn save(&self, path: &Path) -> NvResult<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                NvControlError::ConfigError(format!("Failed to create config dir: {}", e))
+            })?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to serialize fan curves: {}", e))
+        })?;
+        fs::write(path, content).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+}
+
+/// Build a plain curve from temp:percent pairs (no zero-RPM, no clamping)
+pub fn curve_from_points(name: &str, points: &[(u8, u8)]) -> FanCurve {
+    FanCurve {
+        name: name.to_string(),
+        points: points
+            .iter()
+            .map(|&(temperature, duty_cycle)| FanCurvePoint {
+                temperature,
+                duty_cycle,
+            })
+            .collect(),
+        zero_rpm_below: None,
+        ..FanCurve::default()
+    }
+}
+
+/// Remember `curve` as the one the daemon applies to `fan_id`
+pub fn persist_curve(fan_id: usize, curve: &FanCurve) -> NvResult<()> {
+    if crate::gpu_safe::is_dry_run() {
+        return Ok(());
+    }
+    let path = ActiveFanCurves::default_path();
+    let mut active = ActiveFanCurves::load(&path)?;
+    active.curves.insert(fan_id, curve.clone());
+    active.save(&path)
+}
+
+/// Remember `curve` as the shared curve for fans without their own
+pub fn persist_shared_curve(curve: &FanCurve) -> NvResult<()> {
+    if crate::gpu_safe::is_dry_run() {
+        return Ok(());
+    }
+    let path = ActiveFanCurves::default_path();
+    let mut active = ActiveFanCurves::load(&path)?;
+    active.shared = Some(curve.clone());
+    active.save(&path)
+}
+
+/// Fan ids of `gpu_index`, numbered like `fan::list_fans` (GPU * 10 + fan)
+pub fn gpu_fan_ids(backend: &SharedNvmlBackend, gpu_index: u32) -> Vec<usize> {
+    let count = backend.get_fan_count(gpu_index).unwrap_or(1);
+    (0..count)
+        .map(|fan| (gpu_index * 10 + fan) as usize)
+        .collect()
+}
+
+/// Stop the daemon driving `fan_id` (e.g. after `nvctl fan auto`). Other
+/// fans on the shared curve keep following it.
+pub fn clear_curve(backend: &SharedNvmlBackend, fan_id: usize) -> NvResult<()> {
+    if crate::gpu_safe::is_dry_run() {
+        return Ok(());
+    }
+    let path = ActiveFanCurves::default_path();
+    let mut active = ActiveFanCurves::load(&path)?;
+    let gpu_fans = gpu_fan_ids(backend, active.gpu_index);
+    if active.release_fan(fan_id, &gpu_fans) {
+        active.save(&path)?;
+    }
+    Ok(())
+}
+
+/// GPU a fan id belongs to (GPU * 10 + fan, like `fan::list_fans`)
+fn fan_gpu_index(fan_id: usize) -> u32 {
+    (fan_id / 10) as u32
+}
+
+/// Duty each of `driven` should run at, following the temperature of the
+/// GPU the fan is on. Fans whose GPU temperature can't be read are left out.
+fn fan_targets(
+    backend: &SharedNvmlBackend,
+    driven: &BTreeMap<usize, &FanCurve>,
+    applied: &HashMap<usize, u8>,
+) -> BTreeMap<usize, u8> {
+    let mut temps: BTreeMap<u32, Option<u8>> = BTreeMap::new();
+    let mut targets = BTreeMap::new();
+    for (&fan_id, curve) in driven {
+        let gpu = fan_gpu_index(fan_id);
+        let temp = *temps
+            .entry(gpu)
+            .or_insert_with(|| match backend.get_temperature(gpu) {
+                Ok(temp) => Some(temp.min(u8::MAX as u32) as u8),
+                Err(e) => {
+                    eprintln!("⚠️  GPU {} temperature read failed: {}", gpu, e);
+                    None
+                }
+            });
+        if let Some(temp) = temp {
+            let previous = applied.get(&fan_id).copied();
+            targets.insert(fan_id, fan::curve_speed(curve, temp, previous));
+        }
+    }
+    targets
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Apply the curves in `path` until `stop` is raised, re-reading the file
+/// whenever it changes. Fans are handed back to the driver on exit.
+pub fn run_fan_daemon(backend: &SharedNvmlBackend, path: &Path, stop: &AtomicBool) -> NvResult<()> {
+    let mut active = ActiveFanCurves::load(path)?;
+    let mut loaded_at = modified_at(path);
+    let mut gpu_fans = gpu_fan_ids(backend, active.gpu_index);
+    // Last duty applied per fan; 0 while parked in the zero-RPM region
+    let mut applied: HashMap<usize, u8> = HashMap::new();
+
+    println!(
+        "🌀 Fan daemon driving {} fan(s) from {}",
+        active.driven_fans(&gpu_fans).len(),
+        path.display()
+    );
+
+    while !stop.load(Ordering::SeqCst) {
+        if modified_at(path) != loaded_at {
+            match ActiveFanCurves::load(path) {
+                Ok(reloaded) => {
+                    let reloaded_fans = gpu_fan_ids(backend, reloaded.gpu_index);
+                    let still_driven = reloaded.driven_fans(&reloaded_fans);
+                    for fan_id in active.driven_fans(&gpu_fans).keys() {
+                        if !still_driven.contains_key(fan_id) {
+                            let _ = fan::reset_fan_to_auto(*fan_id);
+                            applied.remove(fan_id);
+                        }
+                    }
+                    println!("🔄 Reloaded fan curves ({} fan(s))", still_driven.len());
+                    active = reloaded;
+                    gpu_fans = reloaded_fans;
+                }
+                Err(e) => eprintln!("⚠️  Keeping previous fan curves: {}", e),
+            }
+            loaded_at = modified_at(path);
+        }
+
+        for (fan_id, target) in fan_targets(backend, &active.driven_fans(&gpu_fans), &applied) {
+            if applied.get(&fan_id) == Some(&target) {
+                continue;
+            }
+            match fan::set_fan_speed(fan_id, target) {
+                Ok(()) => {
+                    applied.insert(fan_id, target);
+                }
+                Err(e) => eprintln!("⚠️  Fan {}: {}", fan_id, e),
+            }
+        }
+
+        std::thread::sleep(Duration::from_secs(active.poll_interval_secs.max(1)));
+    }
+
+    for fan_id in active.driven_fans(&gpu_fans).keys() {
+        let _ = fan::reset_fan_to_auto(*fan_id);
+    }
+    Ok(())
+}
+
+/// Where the daemon's unit is installed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceScope {
+    /// `systemctl --user`, fans driven through nvidia-settings on the session's X display
+    User,
+    /// System unit running as root, for sysfs/NVML fan control without a display
+    System,
+}
+
+impl ServiceScope {
+    /// nvidia-settings only works from inside the graphical session; without
+    /// it fan writes go through sysfs, which needs root
+    pub fn detect() -> Self {
+        let has_nvidia_settings = Command::new("nvidia-settings")
+            .arg("--version")
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if std::env::var("DISPLAY").is_ok() && has_nvidia_settings {
+            ServiceScope::User
+        } else {
+            ServiceScope::System
+        }
+    }
+
+    pub fn unit_path(self) -> NvResult<PathBuf> {
+        match self {
+            ServiceScope::User => Ok(dirs::config_dir()
+                .ok_or_else(|| NvControlError::ConfigError("No config directory".into()))?
+                .join("systemd/user")
+                .join(SERVICE_NAME)),
+            ServiceScope::System => Ok(Path::new(SYSTEM_UNIT_DIR).join(SERVICE_NAME)),
+        }
+    }
+
+    fn systemctl(self, args: &[&str]) -> NvResult<()> {
+        let mut cmd = Command::new("systemctl");
+        if self == ServiceScope::User {
+            cmd.arg("--user");
+        }
+        let output = cmd
+            .args(args)
+            .output()
+            .map_err(|e| NvControlError::RuntimeError(format!("Failed to run systemctl: {}", e)))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(NvControlError::RuntimeError(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ))
+        }
+    }
+}
+
+/// Unit file for the daemon. The curve path is passed explicitly so a system
+/// unit running as root still reads the invoking user's curves.
+pub fn service_unit(scope: ServiceScope, exe: &Path, curves_path: &Path) -> String {
+    let exec = format!(
+        "{} fan daemon --config {}",
+        exe.display(),
+        curves_path.display()
+    );
+    match scope {
+        ServiceScope::User => format!(
+            "[Unit]\nDescription=nvcontrol fan curve daemon\nAfter=graphical-session.target\nPartOf=graphical-session.target\n\n[Service]\nType=simple\nExecStart={}\nRestart=on-failure\nRestartSec=2\n\n[Install]\nWantedBy=graphical-session.target\n",
+            exec
+        ),
+        ServiceScope::System => format!(
+            "[Unit]\nDescription=nvcontrol fan curve daemon\nAfter=nvidia-persistenced.service\n\n[Service]\nType=simple\nExecStart={}\nRestart=on-failure\nRestartSec=2\n# sysfs pwm writes and NVML fan control need root; nothing else does\nCapabilityBoundingSet=CAP_SYS_ADMIN CAP_DAC_OVERRIDE\nNoNewPrivileges=true\nProtectSystem=full\nProtectHome=read-only\nPrivateTmp=true\n\n[Install]\nWantedBy=multi-user.target\n",
+            exec
+        ),
+    }
+}
+
+/// Write, enable and start the daemon unit
+pub fn install_service(scope: ServiceScope) -> NvResult<PathBuf> {
+    if scope == ServiceScope::System && unsafe { libc::geteuid() } != 0 {
+        return Err(NvControlError::RuntimeError(
+            "A system unit is needed for fan control without a display; re-run with sudo".into(),
+        ));
+    }
+
+    let exe = std::env::current_exe()
+        .map_err(|e| NvControlError::RuntimeError(format!("Failed to locate nvctl: {}", e)))?;
+    // Under sudo, keep pointing at the invoking user's curves
+    let curves_path = std::env::var("SUDO_USER")
+        .ok()
+        .filter(|_| scope == ServiceScope::System)
+        .map(|user| {
+            PathBuf::from("/home")
+                .join(user)
+                .join(".config/nvcontrol/active_fan_curves.json")
+        })
+        .unwrap_or_else(ActiveFanCurves::default_path);
+
+    let unit_path = scope.unit_path()?;
+    if let Some(parent) = unit_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to create systemd dir: {}", e))
+        })?;
+    }
+    fs::write(&unit_path, service_unit(scope, &exe, &curves_path)).map_err(|e| {
+        NvControlError::ConfigError(format!("Failed to write systemd service: {}", e))
+    })?;
+
+    scope.systemctl(&["daemon-reload"])?;
+    scope.systemctl(&["enable", "--now", SERVICE_NAME])?;
+    Ok(unit_path)
+}
+
+/// Stop, disable and remove the daemon unit from both scopes
+pub fn uninstall_service() -> NvResult<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    for scope in [ServiceScope::User, ServiceScope::System] {
+        let unit_path = scope.unit_path()?;
+        if !unit_path.exists() {
+            continue;
+        }
+        let _ = scope.systemctl(&["disable", "--now", SERVICE_NAME]);
+        fs::remove_file(&unit_path).map_err(|e| {
+            NvControlError::ConfigError(format!(
+                "Failed to remove {}: {} (system units need sudo)",
+                unit_path.display(),
+                e
+            ))
+        })?;
+        let _ = scope.systemctl(&["daemon-reload"]);
+        removed.push(unit_path);
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_active_curves_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("active_fan_curves.json");
+
+        let loaded = ActiveFanCurves::load(&path).unwrap();
+        assert!(loaded.curves.is_empty());
+        assert_eq!(loaded.poll_interval_secs, 2);
+
+        let mut active = loaded;
+        active
+            .curves
+            .insert(1, curve_from_points("Custom", &[(40, 30), (80, 100)]));
+        active.save(&path).unwrap();
+
+        let reloaded = ActiveFanCurves::load(&path).unwrap();
+        assert_eq!(reloaded.curves[&1].points.len(), 2);
+        assert_eq!(reloaded.curves[&1].zero_rpm_below, None);
+    }
+
+    #[test]
+    fn test_per_fan_curve_overrides_shared() {
+        let mut active = ActiveFanCurves::default();
+        assert!(active.driven_fans(&[0, 1]).is_empty());
+
+        active.shared = Some(curve_from_points("Shared", &[(40, 30), (80, 100)]));
+        active
+            .curves
+            .insert(1, curve_from_points("Front", &[(30, 50), (70, 100)]));
+
+        let driven = active.driven_fans(&[0, 1]);
+        assert_eq!(driven.len(), 2);
+        assert_eq!(driven[&0].name, "Shared");
+        assert_eq!(driven[&1].name, "Front");
+        assert_eq!(active.curve_for(5).map(|c| c.name.as_str()), Some("Shared"));
+
+        active.shared = None;
+        assert_eq!(active.driven_fans(&[0, 1]).keys().collect::<Vec<_>>(), [&1]);
+    }
+
+    #[test]
+    fn test_releasing_one_fan_keeps_the_others_on_the_shared_curve() {
+        let mut active = ActiveFanCurves {
+            shared: Some(curve_from_points("Shared", &[(40, 30), (80, 100)])),
+            ..ActiveFanCurves::default()
+        };
+        active
+            .curves
+            .insert(2, curve_from_points("Rear", &[(30, 50), (70, 100)]));
+
+        assert!(active.release_fan(1, &[0, 1, 2]));
+        let driven = active.driven_fans(&[0, 1, 2]);
+        assert_eq!(driven.keys().collect::<Vec<_>>(), [&0, &2]);
+        assert_eq!(driven[&0].name, "Shared");
+        assert_eq!(driven[&2].name, "Rear");
+
+        // A fan on another GPU never followed the shared curve
+        let mut active = ActiveFanCurves {
+            shared: Some(curve_from_points("Shared", &[(40, 30), (80, 100)])),
+            ..ActiveFanCurves::default()
+        };
+        assert!(!active.release_fan(10, &[0, 1]));
+        assert!(active.shared.is_some());
+    }
+
+    #[test]
+    fn test_each_fan_follows_its_own_gpu() {
+        let backend: SharedNvmlBackend =
+            std::sync::Arc::new(crate::nvml_backend::MockNvmlBackend::multi_gpu(2));
+        let curve = curve_from_points("Shared", &[(40, 30), (80, 100)]);
+        // Fan 0 is on GPU 0 (55°C), fan 10 on GPU 1 (58°C)
+        let driven = BTreeMap::from([(0, &curve), (10, &curve)]);
+
+        let targets = fan_targets(&backend, &driven, &HashMap::new());
+        assert_eq!(targets[&0], fan::curve_speed(&curve, 55, None));
+        assert_eq!(targets[&10], fan::curve_speed(&curve, 58, None));
+        assert!(targets[&10] > targets[&0]);
+    }
+
+    #[test]
+    fn test_system_unit_restricts_capabilities() {
+        let unit = service_unit(
+            ServiceScope::System,
+            Path::new("/usr/bin/nvctl"),
+            Path::new("/home/me/.config/nvcontrol/active_fan_curves.json"),
+        );
+        assert!(unit.contains(
+            "ExecStart=/usr/bin/nvctl fan daemon --config /home/me/.config/nvcontrol/active_fan_curves.json"
+        ));
+        assert!(unit.contains("CapabilityBoundingSet="));
+        assert!(unit.contains("WantedBy=multi-user.target"));
+
+        let user = service_unit(
+            ServiceScope::User,
+            Path::new("/usr/bin/nvctl"),
+            Path::new("/tmp/curves.json"),
+        );
+        assert!(!user.contains("CapabilityBoundingSet"));
+        assert!(user.contains("WantedBy=graphical-session.target"));
+    }
+}
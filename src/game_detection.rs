@@ -65,6 +65,24 @@ impl GameDetector {
         Ok(())
     }
 
+    /// Re-read saved profiles from disk, dropping ones that were deleted or renamed
+    pub fn reload_profiles(&mut self) -> NvResult<()> {
+        self.profiles.clear();
+        self.load_profiles()
+    }
+
+    /// Newest modification time of the profiles directory or any profile in
+    /// it. Changes when a profile is added, renamed, removed or edited.
+    pub fn profiles_modified(&self) -> Option<std::time::SystemTime> {
+        let dir_modified = fs::metadata(&self.profiles_dir).ok()?.modified().ok();
+        fs::read_dir(&self.profiles_dir)
+            .ok()?
+            .flatten()
+            .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+            .chain(dir_modified)
+            .max()
+    }
+
     pub fn save_profile(&self, profile: &GameProfile) -> NvResult<()> {
         let filename = format!("{}.toml", profile.executable.replace("/", "_"));
         let path = self.profiles_dir.join(filename);
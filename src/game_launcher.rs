@@ -1,6 +1,7 @@
 // Per-Game Launch Optimizer
 // Launches games with optimal settings, environment variables, and CPU affinity
 
+use crate::rgb_control::RgbSettings;
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -64,6 +65,10 @@ pub struct GameProfile {
     pub fps_limit: Option<u32>,
     pub gamescope_preset: Option<String>,
     pub priority: ProcessPriority,
+    /// GPU lighting while the game runs; the auto-apply service restores the
+    /// previous lighting when it exits
+    #[serde(default)]
+    pub rgb: Option<RgbSettings>,
 }
 
 impl Default for GameProfile {
@@ -105,6 +110,7 @@ impl Default for GameProfile {
             fps_limit: None,
             gamescope_preset: None,
             priority: ProcessPriority::Normal,
+            rgb: None,
         }
     }
 }
@@ -118,6 +124,32 @@ impl GameProfile {
         }
     }
 
+    /// Set an environment variable injected at launch
+    pub fn set_env(&mut self, key: &str, value: impl Into<String>) -> NvResult<()> {
+        validate_env_key(key)?;
+        self.env_vars.insert(key.to_string(), value.into());
+        Ok(())
+    }
+
+    /// Check that every env var key is a valid variable name
+    pub fn validate_env(&self) -> NvResult<()> {
+        self.env_vars
+            .keys()
+            .try_for_each(|key| validate_env_key(key))
+    }
+
+    /// Steam launch options that inject this profile's env vars,
+    /// e.g. `MANGOHUD=1 __GL_SYNC_TO_VBLANK=0 %command%`
+    pub fn steam_launch_options(&self) -> String {
+        let mut vars: Vec<_> = self.env_vars.iter().collect();
+        vars.sort();
+        vars.into_iter()
+            .map(|(key, value)| format!("{}={}", key, shell_quote(value)))
+            .chain(std::iter::once("%command%".to_string()))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Add DXVK environment variables
     pub fn with_dxvk(mut self, enable_async: bool) -> Self {
         self.env_vars
@@ -188,6 +220,37 @@ impl GameProfile {
     }
 }
 
+/// Check that `key` looks like an environment variable name
+/// (letters, digits and underscores, not starting with a digit)
+pub fn validate_env_key(key: &str) -> NvResult<()> {
+    let mut chars = key.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(NvControlError::ConfigError(format!(
+            "'{}' is not a valid environment variable name",
+            key
+        )))
+    }
+}
+
+/// Quote a value for a POSIX shell command line if it needs it
+fn shell_quote(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-./:,+=@%".contains(c));
+    if plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
 /// Game launcher that applies profiles and optimizations
 pub struct GameLauncher {
     profiles_dir: PathBuf,
@@ -214,6 +277,7 @@ impl GameLauncher {
 
     /// Save a game profile
     pub fn save_profile(&self, profile: &GameProfile) -> NvResult<()> {
+        profile.validate_env()?;
         let profile_path = self.profiles_dir.join(format!("{}.toml", profile.name));
         let content = toml::to_string_pretty(profile).map_err(|e| {
             NvControlError::ConfigError(format!("Failed to serialize profile: {}", e))
@@ -273,6 +337,7 @@ impl GameLauncher {
     /// Launch a game with the given profile
     pub fn launch_game(&self, profile: &GameProfile, args: Vec<String>) -> NvResult<()> {
         println!("🚀 Launching game: {}", profile.name);
+        profile.validate_env()?;
 
         self.run_hooks("pre-launch", &profile.pre_launch_hooks, profile)?;
 
@@ -629,4 +694,24 @@ mod tests {
         assert_eq!(profile.pre_launch_hooks[0].command, "echo");
         assert!(profile.post_exit_hooks[0].ignore_failure);
     }
+
+    #[test]
+    fn env_vars_validate_and_render_for_steam() {
+        let mut profile = GameProfile::new("test".to_string(), "game".to_string());
+        profile.env_vars.clear();
+        profile.set_env("MANGOHUD", "1").unwrap();
+        profile.set_env("PROTON_LOG_DIR", "/tmp/my logs").unwrap();
+        assert!(profile.set_env("1BAD", "x").is_err());
+        assert!(profile.set_env("DXVK-HUD", "x").is_err());
+
+        assert_eq!(
+            profile.steam_launch_options(),
+            "MANGOHUD=1 PROTON_LOG_DIR='/tmp/my logs' %command%"
+        );
+
+        profile
+            .env_vars
+            .insert("BAD KEY".to_string(), "1".to_string());
+        assert!(profile.validate_env().is_err());
+    }
 }
@@ -2,20 +2,24 @@
 // Automatically detect game launches and apply GPU profiles
 
 use crate::game_detection::GameDetector;
-use crate::game_launcher::GameLauncher;
+use crate::game_launcher::{GameLauncher, GameProfile};
 use crate::overclocking::OverclockProfile;
+use crate::rgb_control::RgbOverride;
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 const SERVICE_STATE_FILE: &str = "game_profile_auto.state";
 const SERVICE_PID_FILE: &str = "game_profile_auto.pid";
 const SYSTEMD_SERVICE_NAME: &str = "nvcontrol-game-profile-auto.service";
+/// GPU that game profiles are applied to (see `GameDetector::apply_profile`)
+const GAME_PROFILE_GPU: u32 = 0;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutoProfileConfig {
@@ -23,6 +27,13 @@ pub struct AutoProfileConfig {
     pub poll_interval_secs: u64,
     pub restore_on_exit: bool,
     pub apply_delay_secs: u64, // Wait before applying (in case game crashes immediately)
+    /// How long a game must stay closed before settings are restored
+    #[serde(default = "default_relaunch_debounce_secs")]
+    pub relaunch_debounce_secs: u64,
+}
+
+fn default_relaunch_debounce_secs() -> u64 {
+    10
 }
 
 impl Default for AutoProfileConfig {
@@ -32,6 +43,7 @@ impl Default for AutoProfileConfig {
             poll_interval_secs: 2,
             restore_on_exit: true,
             apply_delay_secs: 3,
+            relaunch_debounce_secs: default_relaunch_debounce_secs(),
         }
     }
 }
@@ -105,6 +117,7 @@ impl GameProfileAutoApplier {
             println!("🎮 Game profile auto-applier started");
 
             let mut last_detected_game: Option<String> = None;
+            let mut rgb_override: Option<RgbOverride> = None;
 
             loop {
                 // Check if we should stop
@@ -176,6 +189,10 @@ impl GameProfileAutoApplier {
                                 }
                             }
 
+                            // Switch lighting, keeping the pre-game state for the exit
+                            rgb_override =
+                                RgbOverride::switch(rgb_override.take(), profile.rgb.as_ref());
+
                             let state = ProfileState {
                                 game_executable: game_exe.clone(),
                                 profile_name: profile.name.clone(),
@@ -201,6 +218,10 @@ impl GameProfileAutoApplier {
                         println!("🎮 Game exited");
 
                         if config.restore_on_exit {
+                            if let Some(rgb) = rgb_override.take() {
+                                println!("   🔄 Restoring RGB lighting");
+                                rgb.restore();
+                            }
                             if let Some(ref default) = default_profile {
                                 println!("   🔄 Restoring default profile");
                                 if let Err(e) =
@@ -212,6 +233,7 @@ impl GameProfileAutoApplier {
                         }
 
                         last_detected_game = None;
+                        rgb_override = None;
                         *active_profile.lock().unwrap_or_else(|e| e.into_inner()) = None;
                         let _ = save_service_state(&AutoServiceState {
                             enabled: config.enabled,
@@ -265,6 +287,241 @@ impl GameProfileAutoApplier {
     }
 }
 
+/// What the process-scan daemon should do after a scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwitchAction {
+    /// A (different) game is running; apply its profile
+    Apply(String),
+    /// The game has stayed closed past the debounce window; restore settings
+    Restore,
+    None,
+}
+
+/// Debounces profile switches so a quick relaunch doesn't thrash settings
+#[derive(Debug, Clone)]
+pub struct ProfileSwitcher {
+    active: Option<String>,
+    missing_since: Option<Instant>,
+    debounce: Duration,
+}
+
+impl ProfileSwitcher {
+    pub fn new(debounce: Duration) -> Self {
+        Self {
+            active: None,
+            missing_since: None,
+            debounce,
+        }
+    }
+
+    /// Executable whose profile is currently applied
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Feed the result of one process scan
+    pub fn observe(&mut self, running: Option<&str>, now: Instant) -> SwitchAction {
+        match running {
+            Some(exe) => {
+                self.missing_since = None;
+                if self.active.as_deref() == Some(exe) {
+                    SwitchAction::None
+                } else {
+                    self.active = Some(exe.to_string());
+                    SwitchAction::Apply(exe.to_string())
+                }
+            }
+            None if self.active.is_none() => SwitchAction::None,
+            None => {
+                let since = *self.missing_since.get_or_insert(now);
+                if now.duration_since(since) >= self.debounce {
+                    self.active = None;
+                    self.missing_since = None;
+                    SwitchAction::Restore
+                } else {
+                    SwitchAction::None
+                }
+            }
+        }
+    }
+}
+
+/// Profiles for every known game executable: installed library games get
+/// a recommended profile, and saved per-game profiles take precedence.
+pub fn known_game_profiles(detector: &GameDetector) -> HashMap<String, GameProfile> {
+    let mut profiles = HashMap::new();
+
+    if let Ok(scanner) = crate::game_scanner::GameLibraryScanner::new() {
+        if let Ok(games) = scanner.scan_all() {
+            for game in &games {
+                profiles.insert(
+                    game.executable.clone(),
+                    scanner.generate_recommended_profile(game),
+                );
+            }
+        }
+    }
+
+    for profile in detector.get_all_profiles() {
+        profiles.insert(profile.executable.clone(), profile.clone());
+    }
+
+    profiles
+}
+
+fn find_running_game<'a>(
+    system: &sysinfo::System,
+    profiles: &'a HashMap<String, GameProfile>,
+) -> Option<&'a GameProfile> {
+    system.processes().values().find_map(|process| {
+        let name = process.name().to_string_lossy();
+        profiles.get(name.as_ref()).or_else(|| {
+            let exe = process.exe()?;
+            let file_name = exe.file_name()?.to_string_lossy();
+            profiles.get(file_name.as_ref())
+        })
+    })
+}
+
+fn log_switch(message: &str) {
+    println!(
+        "[{}] {}",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        message
+    );
+}
+
+/// Run the process-scan daemon (blocking).
+///
+/// Scans running processes against known game executables, applies the
+/// matching profile, and restores the pre-game settings once the game has
+/// been gone for `relaunch_debounce_secs`. If the overclock watchdog resets
+/// the GPU during a session, the daemon leaves it at stock on exit.
+pub fn run_process_scan_daemon(config: &AutoProfileConfig) -> NvResult<()> {
+    let mut detector = GameDetector::new()?;
+    let mut profiles = known_game_profiles(&detector);
+    let mut profiles_modified = detector.profiles_modified();
+    let mut nvml = nvml_wrapper::Nvml::init().ok();
+    let interval = Duration::from_secs(config.poll_interval_secs.max(1));
+    let mut switcher = ProfileSwitcher::new(Duration::from_secs(config.relaunch_debounce_secs));
+    let mut watchdog =
+        crate::hardware_safety::OverclockWatchdog::from_config(&crate::config::Config::load());
+    let mut system = sysinfo::System::new();
+    let mut baseline: Option<OverclockProfile> = None;
+    let mut rgb_override: Option<RgbOverride> = None;
+    let mut watchdog_tripped = false;
+    let mut power_scheduler = crate::power_curves::PowerScheduler::new();
+    let power_backend = crate::nvml_backend::create_real_backend();
+    let mut hotplug =
+        crate::monitor_profiles::HotplugReapplier::new(crate::monitor_profiles::HOTPLUG_DEBOUNCE);
+
+    log_switch(&format!(
+        "Process-scan daemon started: {} known games, scan every {}s, display hotplug via {}",
+        profiles.len(),
+        interval.as_secs(),
+        hotplug.source().label()
+    ));
+
+    loop {
+        // Pick up profiles saved, renamed or removed since the last scan
+        let modified = detector.profiles_modified();
+        if modified != profiles_modified {
+            profiles_modified = modified;
+            match detector.reload_profiles() {
+                Ok(()) => {
+                    profiles = known_game_profiles(&detector);
+                    log_switch(&format!(
+                        "Game profiles changed, {} known games",
+                        profiles.len()
+                    ));
+                }
+                Err(e) => log_switch(&format!("Failed to reload game profiles: {}", e)),
+            }
+        }
+
+        system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        let running = find_running_game(&system, &profiles);
+
+        match switcher.observe(running.map(|p| p.executable.as_str()), Instant::now()) {
+            SwitchAction::Apply(exe) => {
+                if baseline.is_none() {
+                    baseline = Some(
+                        crate::overclocking::OverclockHistory::load()
+                            .last_for_gpu(GAME_PROFILE_GPU)
+                            .map(|entry| entry.profile.clone())
+                            .unwrap_or_default(),
+                    );
+                }
+                if let Some(profile) = profiles.get(&exe) {
+                    log_switch(&format!(
+                        "Detected {} ({}), applying profile",
+                        profile.name, exe
+                    ));
+                    if let Err(e) = detector.apply_profile(profile) {
+                        log_switch(&format!("Failed to apply profile for {}: {}", exe, e));
+                    }
+                    rgb_override = RgbOverride::switch(rgb_override.take(), profile.rgb.as_ref());
+                }
+            }
+            SwitchAction::Restore => {
+                if let Some(rgb) = rgb_override.take().filter(|_| config.restore_on_exit) {
+                    log_switch(&format!(
+                        "Restoring RGB lighting to {:?}",
+                        rgb.previous().mode
+                    ));
+                    rgb.restore();
+                }
+                if watchdog_tripped {
+                    log_switch("Game exited; watchdog reset active, leaving GPU at stock");
+                } else if let Some(previous) = baseline.as_ref().filter(|_| config.restore_on_exit)
+                {
+                    log_switch(&format!("Game exited, restoring {}", previous.name));
+                    if let Err(e) =
+                        crate::overclocking::reapply_profile(previous, GAME_PROFILE_GPU)
+                    {
+                        log_switch(&format!("Failed to restore settings: {}", e));
+                    }
+                }
+                baseline = None;
+                watchdog_tripped = false;
+            }
+            SwitchAction::None => {}
+        }
+
+        // A running game's profile owns the power limit; the schedule resumes once it exits
+        if switcher.active().is_some() {
+            power_scheduler.reset();
+        } else {
+            match power_scheduler.tick(&power_backend) {
+                Ok(Some(limit)) => log_switch(&format!("Power schedule: limit set to {}%", limit)),
+                Ok(None) => {}
+                Err(e) => log_switch(&format!("Power schedule failed: {}", e)),
+            }
+        }
+
+        if let Some(watchdog) = watchdog.as_mut() {
+            // Retry only until the driver is up; NVML stays initialised after that
+            if nvml.is_none() {
+                nvml = nvml_wrapper::Nvml::init().ok();
+            }
+            let read_temp = |gpu| {
+                nvml.as_ref()?
+                    .device_by_index(gpu)
+                    .ok()?
+                    .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
+                    .ok()
+            };
+            if let Some(reason) = watchdog.poll(read_temp) {
+                log_switch(&format!("Watchdog reset GPU to stock: {}", reason));
+                watchdog_tripped = true;
+            }
+        }
+
+        // Sleeps out the scan interval, re-applying monitor profiles on hotplug
+        hotplug.run_for(interval);
+    }
+}
+
 fn service_state_path() -> NvResult<PathBuf> {
     let config_dir = dirs::config_dir()
         .ok_or_else(|| NvControlError::ConfigError("No config directory".into()))?
@@ -593,5 +850,40 @@ mod tests {
         let config = AutoProfileConfig::default();
         assert!(config.enabled);
         assert_eq!(config.poll_interval_secs, 2);
+        assert_eq!(config.relaunch_debounce_secs, 10);
+    }
+
+    #[test]
+    fn test_switcher_debounces_quick_relaunch() {
+        let start = Instant::now();
+        let mut switcher = ProfileSwitcher::new(Duration::from_secs(10));
+
+        assert_eq!(
+            switcher.observe(Some("game.exe"), start),
+            SwitchAction::Apply("game.exe".to_string())
+        );
+        assert_eq!(
+            switcher.observe(Some("game.exe"), start),
+            SwitchAction::None
+        );
+
+        // Closed briefly, then relaunched inside the window
+        assert_eq!(switcher.observe(None, start), SwitchAction::None);
+        assert_eq!(
+            switcher.observe(None, start + Duration::from_secs(5)),
+            SwitchAction::None
+        );
+        assert_eq!(
+            switcher.observe(Some("game.exe"), start + Duration::from_secs(6)),
+            SwitchAction::None
+        );
+
+        // Closed for longer than the window
+        switcher.observe(None, start + Duration::from_secs(7));
+        assert_eq!(
+            switcher.observe(None, start + Duration::from_secs(17)),
+            SwitchAction::Restore
+        );
+        assert!(switcher.active().is_none());
     }
 }
@@ -1,4 +1,5 @@
-use crate::NvResult;
+use crate::upscaling_tech::FsrQuality;
+use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -621,7 +622,6 @@ pub fn get_recording_presets() -> NvResult<Vec<crate::recording::RecordingSettin
 
 /// Apply a Gamescope configuration (saves to config file for persistence)
 pub fn apply_gamescope_config(config: &GamescopeConfig) -> NvResult<()> {
-    use crate::NvControlError;
     use std::fs;
 
     // Get config directory
@@ -684,7 +684,6 @@ pub fn apply_gamescope_config(config: &GamescopeConfig) -> NvResult<()> {
 
 /// Load the saved Gamescope configuration
 pub fn load_gamescope_config() -> NvResult<GamescopeConfig> {
-    use crate::NvControlError;
     use std::fs;
 
     let config_path = dirs::config_dir()
@@ -707,7 +706,6 @@ pub fn load_gamescope_config() -> NvResult<GamescopeConfig> {
 
 /// Launch an application with Gamescope using the current config
 pub fn launch_with_gamescope(command: &str, config: Option<&GamescopeConfig>) -> NvResult<()> {
-    use crate::NvControlError;
     use std::process::Command;
 
     let config = match config {
@@ -795,3 +793,373 @@ pub fn launch_with_gamescope(command: &str, config: Option<&GamescopeConfig>) ->
 
     Ok(())
 }
+
+/// Upscaling filter for a launch preset. Gamescope only ships spatial
+/// upscalers, so the choice is limited to FSR 1 and NIS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "filter", content = "quality", rename_all = "lowercase")]
+pub enum PresetUpscaler {
+    /// Render at the output resolution
+    Native,
+    Fsr(FsrQuality),
+    Nis(FsrQuality),
+}
+
+impl PresetUpscaler {
+    /// Parse a filter name plus optional quality level, e.g. ("fsr", "balanced")
+    pub fn parse(filter: &str, quality: Option<&str>) -> NvResult<Self> {
+        let quality = match quality.map(|q| q.to_lowercase().replace(['-', '_', ' '], "")) {
+            None => FsrQuality::Quality,
+            Some(q) => match q.as_str() {
+                "ultraperformance" => FsrQuality::UltraPerformance,
+                "performance" => FsrQuality::Performance,
+                "balanced" => FsrQuality::Balanced,
+                "quality" => FsrQuality::Quality,
+                "ultraquality" => FsrQuality::UltraQuality,
+                _ => {
+                    return Err(NvControlError::ConfigError(format!(
+                        "Unknown upscaler quality '{}'",
+                        q
+                    )));
+                }
+            },
+        };
+
+        match filter.to_lowercase().as_str() {
+            "native" | "none" | "off" => Ok(Self::Native),
+            "fsr" => Ok(Self::Fsr(quality)),
+            "nis" => Ok(Self::Nis(quality)),
+            other => Err(NvControlError::ConfigError(format!(
+                "Gamescope cannot upscale with '{}' (use fsr, nis or native)",
+                other
+            ))),
+        }
+    }
+
+    /// Resolution the game renders at for a given output resolution
+    pub fn render_resolution(&self, width: u32, height: u32) -> (u32, u32) {
+        match self {
+            Self::Native => (width, height),
+            Self::Fsr(quality) | Self::Nis(quality) => quality.render_resolution(width, height),
+        }
+    }
+
+    /// Value for gamescope's `-F` flag
+    fn filter_arg(&self) -> Option<&'static str> {
+        match self {
+            Self::Native => None,
+            Self::Fsr(_) => Some("fsr"),
+            Self::Nis(_) => Some("nis"),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Native => "Native".to_string(),
+            Self::Fsr(q) => format!("FSR {}", q.to_str()),
+            Self::Nis(q) => format!("NIS {}", q.to_str()),
+        }
+    }
+}
+
+/// A named gamescope launch configuration, usable as a Steam launch option
+/// via `nvctl gamescope launch --preset <name> -- %command%`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LaunchPreset {
+    pub name: String,
+    /// Output resolution
+    pub width: u32,
+    pub height: u32,
+    pub refresh: Option<u32>,
+    pub upscaler: PresetUpscaler,
+    #[serde(default)]
+    pub hdr: bool,
+    /// Extra gamescope arguments passed through verbatim
+    #[serde(default)]
+    pub flags: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LaunchPresetFile {
+    #[serde(default)]
+    presets: Vec<LaunchPreset>,
+}
+
+impl LaunchPreset {
+    /// Presets available without any saved configuration
+    pub fn builtin() -> Vec<LaunchPreset> {
+        vec![
+            LaunchPreset {
+                name: "handheld".to_string(),
+                width: 1280,
+                height: 800,
+                refresh: Some(60),
+                upscaler: PresetUpscaler::Fsr(FsrQuality::Quality),
+                hdr: false,
+                flags: vec!["-f".to_string()],
+            },
+            LaunchPreset {
+                name: "1080p-fsr".to_string(),
+                width: 1920,
+                height: 1080,
+                refresh: None,
+                upscaler: PresetUpscaler::Fsr(FsrQuality::Balanced),
+                hdr: false,
+                flags: vec!["-f".to_string()],
+            },
+            LaunchPreset {
+                name: "4k-hdr".to_string(),
+                width: 3840,
+                height: 2160,
+                refresh: Some(60),
+                upscaler: PresetUpscaler::Fsr(FsrQuality::Performance),
+                hdr: true,
+                flags: vec!["-f".to_string()],
+            },
+        ]
+    }
+
+    /// Capture the resolution, upscaler and HDR settings of a config
+    pub fn from_config(name: &str, config: &GamescopeConfig) -> Self {
+        let upscaler = match config.upscaling {
+            GamescopeUpscaling::Fsr | GamescopeUpscaling::FsrSharpness(_) => {
+                PresetUpscaler::Fsr(FsrQuality::Quality)
+            }
+            GamescopeUpscaling::Nis => PresetUpscaler::Nis(FsrQuality::Quality),
+            _ => PresetUpscaler::Native,
+        };
+
+        let mut flags = Vec::new();
+        if config.fullscreen {
+            flags.push("-f".to_string());
+        }
+        if config.borderless {
+            flags.push("-b".to_string());
+        }
+        if config.adaptive_sync {
+            flags.push("--adaptive-sync".to_string());
+        }
+
+        LaunchPreset {
+            name: name.to_string(),
+            width: config.width,
+            height: config.height,
+            refresh: config.refresh_rate,
+            upscaler,
+            hdr: config.hdr_enabled,
+            flags,
+        }
+    }
+
+    /// Translate the preset into a full `gamescope` argv wrapping `command`
+    pub fn to_argv(&self, command: &[String]) -> Vec<String> {
+        let (render_w, render_h) = self.upscaler.render_resolution(self.width, self.height);
+
+        let mut args = vec![
+            "gamescope".to_string(),
+            "-W".to_string(),
+            self.width.to_string(),
+            "-H".to_string(),
+            self.height.to_string(),
+            "-w".to_string(),
+            render_w.to_string(),
+            "-h".to_string(),
+            render_h.to_string(),
+        ];
+
+        if let Some(refresh) = self.refresh {
+            args.push("-r".to_string());
+            args.push(refresh.to_string());
+        }
+
+        if let Some(filter) = self.upscaler.filter_arg() {
+            args.push("-F".to_string());
+            args.push(filter.to_string());
+        }
+
+        if self.hdr {
+            args.push("--hdr-enabled".to_string());
+        }
+
+        args.extend(self.flags.iter().cloned());
+        args.push("--".to_string());
+        args.extend(command.iter().cloned());
+        args
+    }
+
+    /// Check the output resolution against the modes the connected displays
+    /// advertise. An empty mode list means they could not be read, which is
+    /// not treated as an error.
+    pub fn validate_resolution(&self, modes: &[(u32, u32)]) -> NvResult<()> {
+        if modes.is_empty() || modes.contains(&(self.width, self.height)) {
+            return Ok(());
+        }
+
+        Err(NvControlError::UnsupportedFeature(format!(
+            "No connected display supports {}x{} (preset '{}')",
+            self.width, self.height, self.name
+        )))
+    }
+}
+
+fn launch_presets_path() -> NvResult<std::path::PathBuf> {
+    Ok(dirs::config_dir()
+        .ok_or_else(|| NvControlError::ConfigError("Failed to get config directory".to_string()))?
+        .join("nvcontrol")
+        .join("gamescope_presets.toml"))
+}
+
+fn load_saved_launch_presets() -> NvResult<Vec<LaunchPreset>> {
+    let path = launch_presets_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let toml_string = std::fs::read_to_string(&path)
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to read presets: {}", e)))?;
+    let file: LaunchPresetFile = toml::from_str(&toml_string)
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to parse presets: {}", e)))?;
+    Ok(file.presets)
+}
+
+/// Built-in presets followed by saved ones; a saved preset replaces a
+/// built-in of the same name
+pub fn load_launch_presets() -> NvResult<Vec<LaunchPreset>> {
+    let saved = load_saved_launch_presets()?;
+    let mut presets: Vec<LaunchPreset> = LaunchPreset::builtin()
+        .into_iter()
+        .filter(|b| !saved.iter().any(|s| s.name == b.name))
+        .collect();
+    presets.extend(saved);
+    Ok(presets)
+}
+
+/// Look up a launch preset by name (case-insensitive)
+pub fn find_launch_preset(name: &str) -> NvResult<Option<LaunchPreset>> {
+    Ok(load_launch_presets()?
+        .into_iter()
+        .find(|p| p.name.eq_ignore_ascii_case(name)))
+}
+
+/// Save a launch preset, replacing any saved preset with the same name
+pub fn save_launch_preset(preset: &LaunchPreset) -> NvResult<()> {
+    let mut presets = load_saved_launch_presets()?;
+    presets.retain(|p| !p.name.eq_ignore_ascii_case(&preset.name));
+    presets.push(preset.clone());
+
+    let path = launch_presets_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to create config directory: {}", e))
+        })?;
+    }
+
+    let toml_string = toml::to_string_pretty(&LaunchPresetFile { presets })
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to serialize presets: {}", e)))?;
+    std::fs::write(&path, toml_string)
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to write presets: {}", e)))
+}
+
+/// Launch `command` inside gamescope using `preset`, waiting for it to exit.
+/// The resolution is validated against the connected displays first.
+pub fn launch_with_preset(
+    preset: &LaunchPreset,
+    command: &[String],
+) -> NvResult<std::process::ExitStatus> {
+    if command.is_empty() {
+        return Err(NvControlError::ConfigError(
+            "No command given to launch".to_string(),
+        ));
+    }
+
+    preset.validate_resolution(&crate::display::connected_modes(std::path::Path::new(
+        "/sys/class/drm",
+    )))?;
+
+    let argv = preset.to_argv(command);
+    std::process::Command::new(&argv[0])
+        .args(&argv[1..])
+        .status()
+        .map_err(|e| NvControlError::CommandFailed(format!("Failed to launch gamescope: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_argv_uses_fsr_render_resolution() {
+        let preset = LaunchPreset::builtin()
+            .into_iter()
+            .find(|p| p.name == "handheld")
+            .unwrap();
+        let argv = preset.to_argv(&["%command%".to_string()]);
+
+        assert_eq!(
+            argv,
+            [
+                "gamescope",
+                "-W",
+                "1280",
+                "-H",
+                "800",
+                "-w",
+                "853",
+                "-h",
+                "533",
+                "-r",
+                "60",
+                "-F",
+                "fsr",
+                "-f",
+                "--",
+                "%command%"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_native_preset_skips_filter() {
+        let preset = LaunchPreset {
+            name: "native".to_string(),
+            width: 2560,
+            height: 1440,
+            refresh: None,
+            upscaler: PresetUpscaler::parse("native", None).unwrap(),
+            hdr: true,
+            flags: Vec::new(),
+        };
+        let argv = preset.to_argv(&["game".to_string()]);
+
+        assert!(!argv.contains(&"-F".to_string()));
+        assert!(argv.contains(&"--hdr-enabled".to_string()));
+        assert_eq!(argv[6], "2560");
+    }
+
+    #[test]
+    fn test_upscaler_parse_rejects_dlss() {
+        assert_eq!(
+            PresetUpscaler::parse("nis", Some("ultra-quality")).unwrap(),
+            PresetUpscaler::Nis(FsrQuality::UltraQuality)
+        );
+        assert!(PresetUpscaler::parse("dlss", None).is_err());
+    }
+
+    #[test]
+    fn test_validate_resolution_against_modes() {
+        let preset = LaunchPreset::builtin().remove(0);
+        assert!(preset.validate_resolution(&[]).is_ok());
+        assert!(preset.validate_resolution(&[(1280, 800)]).is_ok());
+        assert!(preset.validate_resolution(&[(1920, 1080)]).is_err());
+    }
+
+    #[test]
+    fn test_launch_presets_roundtrip_toml() {
+        let file = LaunchPresetFile {
+            presets: LaunchPreset::builtin(),
+        };
+        let toml_string = toml::to_string_pretty(&file).unwrap();
+        let parsed: LaunchPresetFile = toml::from_str(&toml_string).unwrap();
+        assert_eq!(parsed.presets, file.presets);
+    }
+}
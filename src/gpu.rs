@@ -1,4 +1,7 @@
-use crate::{NvControlError, NvResult, nvml_backend::SharedNvmlBackend};
+use crate::{
+    NvControlError, NvResult,
+    nvml_backend::{PcieLink, SharedNvmlBackend},
+};
 use clap::ValueEnum;
 use crossterm::{
     event::{self, Event, KeyCode},
@@ -13,6 +16,7 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Row, Table},
 };
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +41,10 @@ pub struct GpuInfo {
     pub memory_clock: Option<u32>,
     pub architecture: Option<String>,
     pub throttle_reason: Option<String>,
+    pub vbios_version: Option<String>,
+    pub board_part_number: Option<String>,
+    /// `None` on cards that don't report a serial, which includes most GeForce boards
+    pub serial: Option<String>,
 }
 
 /// Check if NVIDIA GPU is available on the system
@@ -62,8 +70,9 @@ pub fn get_gpu_info(backend: &SharedNvmlBackend) -> NvResult<GpuInfo> {
         .get_driver_version()
         .unwrap_or_else(|_| "Unknown".to_string());
     let cuda_compute = None; // Not available via backend yet
-    let pcie_gen = None; // Not available via backend yet
-    let pcie_width = None; // Not available via backend yet
+    let pcie_link = backend.get_pcie_link(0).ok();
+    let pcie_gen = pcie_link.map(|link| link.current_gen);
+    let pcie_width = pcie_link.map(|link| link.current_width);
     let power_limit = None; // Not available via backend yet
     let power_limit_min = None;
     let power_limit_max = None;
@@ -71,6 +80,7 @@ pub fn get_gpu_info(backend: &SharedNvmlBackend) -> NvResult<GpuInfo> {
     let memory_clock = backend.get_memory_clock(0).ok();
     let architecture = detect_architecture(&info.name);
     let throttle_reason = None; // Not available via backend yet
+    let identity = BoardIdentity::query(backend, 0);
 
     Ok(GpuInfo {
         name: info.name,
@@ -81,7 +91,7 @@ pub fn get_gpu_info(backend: &SharedNvmlBackend) -> NvResult<GpuInfo> {
         power_draw: metrics.power_draw_mw as f32 / 1000.0,
         fan_speed: metrics.fan_speed,
         gpu_utilization: metrics.gpu_utilization,
-        memory_utilization: metrics.memory_utilization,
+        memory_utilization: metrics.memory_controller_utilization,
         cuda_compute,
         pcie_gen,
         pcie_width,
@@ -92,9 +102,50 @@ pub fn get_gpu_info(backend: &SharedNvmlBackend) -> NvResult<GpuInfo> {
         memory_clock,
         architecture,
         throttle_reason,
+        vbios_version: identity.vbios_version,
+        board_part_number: identity.part_number,
+        serial: identity.serial,
     })
 }
 
+/// Firmware and board identity for inventory and warranty records. Each
+/// field is `None` when the card doesn't report it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BoardIdentity {
+    pub vbios_version: Option<String>,
+    pub part_number: Option<String>,
+    pub serial: Option<String>,
+}
+
+impl BoardIdentity {
+    pub fn query(backend: &SharedNvmlBackend, index: u32) -> Self {
+        Self {
+            vbios_version: get_vbios_version(backend, index).ok(),
+            part_number: get_board_part_number(backend, index).ok(),
+            serial: get_board_serial(backend, index).ok(),
+        }
+    }
+}
+
+/// VBIOS version of one GPU, e.g. "95.02.18.80.5F"
+pub fn get_vbios_version(backend: &SharedNvmlBackend, index: u32) -> NvResult<String> {
+    backend.get_vbios_version(index)
+}
+
+/// Board part number, for matching a card against vendor documentation
+pub fn get_board_part_number(backend: &SharedNvmlBackend, index: u32) -> NvResult<String> {
+    backend.get_board_part_number(index)
+}
+
+/// Board serial number for inventory and warranty claims. Most consumer
+/// cards don't expose it and report `UnsupportedFeature`.
+pub fn get_board_serial(backend: &SharedNvmlBackend, index: u32) -> NvResult<String> {
+    backend.get_board_serial(index)
+}
+
+/// Shown in place of a serial the card doesn't report
+pub const SERIAL_UNAVAILABLE_NOTE: &str = "not reported (usual for consumer cards)";
+
 /// Detect GPU architecture from name
 fn detect_architecture(name: &str) -> Option<String> {
     let name_lower = name.to_lowercase();
@@ -315,7 +366,27 @@ pub fn get_gpu_info_with_format(format: OutputFormat, backend: &SharedNvmlBacken
             if let Some(ref arch) = gpu_info.architecture {
                 println!("   Architecture: {}", arch);
             }
+            if let Some(mobile) = mobile_gpu_status(backend, 0) {
+                println!(
+                    "   Form Factor: Laptop (Dynamic Boost {})",
+                    if mobile.dynamic_boost { "on" } else { "off" }
+                );
+                println!("   Display: {}", mobile.display_mode());
+            }
             println!("   Driver: {}", gpu_info.driver_version);
+            if let Some(ref vbios) = gpu_info.vbios_version {
+                println!("   VBIOS: {}", vbios);
+            }
+            if let Some(ref part) = gpu_info.board_part_number {
+                println!("   Part Number: {}", part);
+            }
+            println!(
+                "   Serial: {}",
+                gpu_info
+                    .serial
+                    .as_deref()
+                    .unwrap_or(SERIAL_UNAVAILABLE_NOTE)
+            );
             if let Some(ref cuda) = gpu_info.cuda_compute {
                 println!("   CUDA Compute: {}", cuda);
             }
@@ -333,7 +404,7 @@ pub fn get_gpu_info_with_format(format: OutputFormat, backend: &SharedNvmlBacken
             }
             println!("   Fan Speed: {}%", gpu_info.fan_speed);
             println!("   GPU Utilization: {}%", gpu_info.gpu_utilization);
-            println!("   Memory Utilization: {}%", gpu_info.memory_utilization);
+            println!("   Memory Controller: {}%", gpu_info.memory_utilization);
             if let (Some(gpu_clk), Some(mem_clk)) = (gpu_info.gpu_clock, gpu_info.memory_clock) {
                 println!("   Clocks: {} MHz (GPU) / {} MHz (Mem)", gpu_clk, mem_clk);
             }
@@ -356,6 +427,16 @@ pub fn get_gpu_info_with_format(format: OutputFormat, backend: &SharedNvmlBacken
                 println!("│ Architecture         │ {:<28} │", arch);
             }
             println!("│ Driver               │ {:<28} │", gpu_info.driver_version);
+            if let Some(ref vbios) = gpu_info.vbios_version {
+                println!("│ VBIOS                │ {:<28} │", vbios);
+            }
+            if let Some(ref part) = gpu_info.board_part_number {
+                println!("│ Part Number          │ {:<28} │", part);
+            }
+            println!(
+                "│ Serial               │ {:<28} │",
+                gpu_info.serial.as_deref().unwrap_or("not reported")
+            );
             if let Some(ref cuda) = gpu_info.cuda_compute {
                 println!("│ CUDA Compute         │ {:<28} │", cuda);
             }
@@ -392,7 +473,7 @@ pub fn get_gpu_info_with_format(format: OutputFormat, backend: &SharedNvmlBacken
                 format!("{}%", gpu_info.gpu_utilization)
             );
             println!(
-                "│ Memory Utilization   │ {:<28} │",
+                "│ Memory Controller    │ {:<28} │",
                 format!("{}%", gpu_info.memory_utilization)
             );
             if let (Some(gpu_clk), Some(mem_clk)) = (gpu_info.gpu_clock, gpu_info.memory_clock) {
@@ -411,6 +492,379 @@ pub fn get_gpu_info_with_format(format: OutputFormat, backend: &SharedNvmlBacken
     Ok(())
 }
 
+/// ECC memory mode for one GPU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EccStatus {
+    pub index: u32,
+    pub current_enabled: bool,
+    pub pending_enabled: bool,
+    /// Error counters; `None` when the driver doesn't report them
+    pub errors: Option<EccErrors>,
+}
+
+impl EccStatus {
+    /// A pending mode only takes effect after a GPU reset or reboot
+    pub fn reboot_required(&self) -> bool {
+        self.current_enabled != self.pending_enabled
+    }
+}
+
+/// Query ECC mode. Consumer GPUs report `UnsupportedFeature`.
+pub fn get_ecc_status(backend: &SharedNvmlBackend, index: u32) -> NvResult<EccStatus> {
+    let state = backend.get_ecc_mode(index)?;
+    Ok(EccStatus {
+        index,
+        current_enabled: state.current_enabled,
+        pending_enabled: state.pending_enabled,
+        errors: get_ecc_errors(backend, index).ok(),
+    })
+}
+
+/// Persistence mode of one GPU and whether `nvidia-persistenced` manages it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistenceStatus {
+    pub index: u32,
+    pub enabled: bool,
+    pub daemon_running: bool,
+    pub daemon_installed: bool,
+}
+
+impl PersistenceStatus {
+    /// The NVML toggle is deprecated and lost on reboot; point at the daemon
+    pub fn recommendation(&self) -> Option<&'static str> {
+        if self.daemon_running {
+            None
+        } else if self.daemon_installed {
+            Some(
+                "nvidia-persistenced is installed but not running; `sudo systemctl enable --now nvidia-persistenced` keeps the driver loaded across reboots",
+            )
+        } else {
+            Some(
+                "The NVML persistence toggle is deprecated and resets on reboot; install nvidia-persistenced from your driver packages instead",
+            )
+        }
+    }
+}
+
+/// Whether an `nvidia-persistenced` process is running
+pub fn persistence_daemon_running() -> bool {
+    process_running("nvidia-persistenced")
+}
+
+/// Whether any process named `name` shows up in `/proc`
+fn process_running(name: &str) -> bool {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        std::fs::read_to_string(entry.path().join("comm")).is_ok_and(|comm| comm.trim() == name)
+    })
+}
+
+fn persistence_daemon_installed() -> bool {
+    std::env::var_os("PATH").is_some_and(|path| {
+        std::env::split_paths(&path).any(|dir| dir.join("nvidia-persistenced").is_file())
+    })
+}
+
+pub fn get_persistence_mode(backend: &SharedNvmlBackend, index: u32) -> NvResult<bool> {
+    backend.get_persistence_mode(index)
+}
+
+/// Toggle legacy persistence mode through NVML (requires root)
+pub fn set_persistence_mode(
+    backend: &SharedNvmlBackend,
+    index: u32,
+    enabled: bool,
+) -> NvResult<()> {
+    if !crate::gpu_safe::should_apply(&format!(
+        "turn persistence mode {} on GPU {}",
+        if enabled { "on" } else { "off" },
+        index
+    )) {
+        return Ok(());
+    }
+    backend.set_persistence_mode(index, enabled)
+}
+
+/// Persistence mode together with the state of the persistence daemon
+pub fn get_persistence_status(
+    backend: &SharedNvmlBackend,
+    index: u32,
+) -> NvResult<PersistenceStatus> {
+    Ok(PersistenceStatus {
+        index,
+        enabled: get_persistence_mode(backend, index)?,
+        daemon_running: persistence_daemon_running(),
+        daemon_installed: persistence_daemon_installed(),
+    })
+}
+
+/// PCIe link and Resizable BAR state of one GPU
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PcieStatus {
+    pub index: u32,
+    pub pci_bus_id: String,
+    /// `None` when NVML doesn't report the link
+    pub link: Option<PcieLink>,
+    /// `None` when the BAR sizes can't be read from sysfs
+    pub rebar: Option<RebarStatus>,
+    /// GPU utilization at the time of the reading; a lower link generation
+    /// only matters under load
+    pub gpu_utilization: u32,
+}
+
+impl PcieStatus {
+    /// Utilization above which a downclocked link counts as a bottleneck
+    const LOAD_THRESHOLD: u32 = 30;
+
+    pub fn under_load(&self) -> bool {
+        self.gpu_utilization >= Self::LOAD_THRESHOLD
+    }
+
+    /// Bottleneck and configuration warnings
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(warning) = self
+            .link
+            .and_then(|link| link.bottleneck_warning(self.under_load()))
+        {
+            warnings.push(warning);
+        }
+        if let Some(rebar) = &self.rebar
+            && !rebar.enabled()
+        {
+            warnings.push(
+                "Resizable BAR is off - enable Above 4G Decoding and Re-Size BAR in the BIOS"
+                    .to_string(),
+            );
+        }
+        warnings
+    }
+}
+
+/// BAR1, the CPU-visible window into VRAM, compared with the VRAM size
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RebarStatus {
+    pub bar1_bytes: u64,
+    pub vram_bytes: u64,
+}
+
+impl RebarStatus {
+    /// With ReBAR the BAR covers all of VRAM; without it, it's 256 MB.
+    /// NVML reports slightly less VRAM than is installed, hence the margin.
+    pub fn enabled(&self) -> bool {
+        self.bar1_bytes >= self.vram_bytes / 10 * 9
+    }
+}
+
+/// Read a PCI device's BAR1 size from `/sys/bus/pci/devices/<id>/resource`
+pub fn read_bar1_size(pci_bus_id: &str) -> Option<u64> {
+    read_bar1_size_in(Path::new("/sys/bus/pci/devices"), pci_bus_id)
+}
+
+/// As [`read_bar1_size`], under another sysfs root
+pub fn read_bar1_size_in(devices_root: &Path, pci_bus_id: &str) -> Option<u64> {
+    let resource = std::fs::read_to_string(
+        devices_root
+            .join(pci_bus_id.to_lowercase())
+            .join("resource"),
+    )
+    .ok()?;
+    // One "start end flags" line per BAR, in hex
+    let line = resource.lines().nth(1)?;
+    let mut fields = line
+        .split_whitespace()
+        .map(|field| u64::from_str_radix(field.trim_start_matches("0x"), 16).ok());
+    let start = fields.next()??;
+    let end = fields.next()??;
+    (end > start).then(|| end - start + 1)
+}
+
+/// Query the PCIe link and ReBAR state of a GPU
+pub fn get_pcie_status(backend: &SharedNvmlBackend, index: u32) -> NvResult<PcieStatus> {
+    let pci_bus_id = backend.get_pci_bus_id(index)?;
+    let (_, vram_bytes) = backend.get_memory_info(index)?;
+    let rebar = read_bar1_size(&pci_bus_id).map(|bar1_bytes| RebarStatus {
+        bar1_bytes,
+        vram_bytes,
+    });
+    Ok(PcieStatus {
+        index,
+        link: backend.get_pcie_link(index).ok(),
+        rebar,
+        gpu_utilization: backend
+            .get_utilization(index)
+            .map(|(gpu, _)| gpu)
+            .unwrap_or(0),
+        pci_bus_id,
+    })
+}
+
+/// ECC error counters for one GPU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EccErrors {
+    pub index: u32,
+    /// Single-bit errors since the driver loaded
+    pub volatile_corrected: u64,
+    /// Double-bit errors since the driver loaded
+    pub volatile_uncorrected: u64,
+    /// Single-bit errors over the board's lifetime
+    pub aggregate_corrected: u64,
+    /// Double-bit errors over the board's lifetime
+    pub aggregate_uncorrected: u64,
+}
+
+impl EccErrors {
+    pub fn has_uncorrected(&self) -> bool {
+        self.volatile_uncorrected > 0 || self.aggregate_uncorrected > 0
+    }
+}
+
+/// Query ECC error counters. Consumer GPUs report `UnsupportedFeature`.
+pub fn get_ecc_errors(backend: &SharedNvmlBackend, index: u32) -> NvResult<EccErrors> {
+    let counts = backend.get_ecc_errors(index)?;
+    Ok(EccErrors {
+        index,
+        volatile_corrected: counts.volatile_corrected,
+        volatile_uncorrected: counts.volatile_uncorrected,
+        aggregate_corrected: counts.aggregate_corrected,
+        aggregate_uncorrected: counts.aggregate_uncorrected,
+    })
+}
+
+/// Request an ECC mode change and return the resulting current/pending state
+pub fn set_ecc_enabled(
+    backend: &SharedNvmlBackend,
+    index: u32,
+    enabled: bool,
+) -> NvResult<EccStatus> {
+    backend.set_ecc_mode(index, enabled)?;
+    let mut status = get_ecc_status(backend, index)?;
+    // Backends that can't re-read the pending state still reflect the request
+    status.pending_enabled = enabled;
+    Ok(status)
+}
+
+/// SMBIOS chassis types of portable machines: portable, laptop, notebook,
+/// sub notebook, tablet, convertible and detachable
+const PORTABLE_CHASSIS_TYPES: [u32; 7] = [8, 9, 10, 14, 30, 31, 32];
+
+/// Whether the GPU name marks a notebook part ("RTX 4070 Laptop GPU",
+/// "Quadro T1000 with Max-Q Design", "GTX 1050 Mobile")
+pub fn is_mobile_gpu_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+    ["laptop gpu", "mobile", "max-q", "max-p"]
+        .iter()
+        .any(|marker| name.contains(marker))
+}
+
+/// Read the SMBIOS chassis type from `<dmi_root>/chassis_type`
+pub fn read_chassis_type_in(dmi_root: &Path) -> Option<u32> {
+    std::fs::read_to_string(dmi_root.join("chassis_type"))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Whether `/sys/class/dmi/id/chassis_type` reports a laptop or other
+/// portable chassis
+pub fn is_portable_chassis() -> bool {
+    read_chassis_type_in(Path::new("/sys/class/dmi/id"))
+        .is_some_and(|t| PORTABLE_CHASSIS_TYPES.contains(&t))
+}
+
+/// Whether the GPU sits in a laptop: either its name marks a mobile part or
+/// `/sys/class/dmi/id/chassis_type` reports a portable chassis
+pub fn is_mobile(backend: &SharedNvmlBackend, index: u32) -> bool {
+    is_mobile_in(backend, index, Path::new("/sys/class/dmi/id"))
+}
+
+/// As [`is_mobile`], reading the chassis type under another DMI root
+pub fn is_mobile_in(backend: &SharedNvmlBackend, index: u32, dmi_root: &Path) -> bool {
+    backend
+        .get_name(index)
+        .is_ok_and(|name| is_mobile_gpu_name(&name))
+        || read_chassis_type_in(dmi_root).is_some_and(|t| PORTABLE_CHASSIS_TYPES.contains(&t))
+}
+
+/// Laptop-specific power state of a mobile GPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MobileGpuStatus {
+    /// `nvidia-powerd` is running, so Dynamic Boost shifts power between
+    /// CPU and GPU and the board power limit is managed by the driver
+    pub dynamic_boost: bool,
+    /// Whether the dGPU drives a display. `Some(false)` means hybrid
+    /// graphics or a MUX switched to the iGPU.
+    pub drives_display: Option<bool>,
+}
+
+impl MobileGpuStatus {
+    /// Short description of the display routing
+    pub fn display_mode(&self) -> &'static str {
+        match self.drives_display {
+            Some(true) => "dGPU drives the display (MUX set to discrete)",
+            Some(false) => "Hybrid graphics - the iGPU drives the display",
+            None => "Display routing unknown",
+        }
+    }
+}
+
+/// Dynamic Boost and MUX state for a laptop GPU, `None` on desktops
+pub fn mobile_gpu_status(backend: &SharedNvmlBackend, index: u32) -> Option<MobileGpuStatus> {
+    mobile_gpu_status_in(backend, index, Path::new("/sys/class/dmi/id"))
+}
+
+/// As [`mobile_gpu_status`], reading the chassis type under another DMI root
+pub fn mobile_gpu_status_in(
+    backend: &SharedNvmlBackend,
+    index: u32,
+    dmi_root: &Path,
+) -> Option<MobileGpuStatus> {
+    is_mobile_in(backend, index, dmi_root).then(|| MobileGpuStatus {
+        dynamic_boost: process_running("nvidia-powerd"),
+        drives_display: backend.is_display_active(index).ok(),
+    })
+}
+
+/// NVENC/NVDEC activity for one GPU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncoderSessions {
+    pub index: u32,
+    pub encoder_utilization: u32,
+    pub decoder_utilization: u32,
+    /// Active NVENC sessions, when NVML exposes the count
+    pub session_count: Option<u32>,
+    pub average_fps: Option<u32>,
+    pub average_latency_us: Option<u32>,
+}
+
+/// Query encoder/decoder load and NVENC sessions. GPUs without NVENC
+/// report `UnsupportedFeature`.
+pub fn get_encoder_sessions(backend: &SharedNvmlBackend, index: u32) -> NvResult<EncoderSessions> {
+    let stats = backend.get_video_engine_stats(index)?;
+    Ok(EncoderSessions {
+        index,
+        encoder_utilization: stats.encoder_utilization,
+        decoder_utilization: stats.decoder_utilization,
+        session_count: stats.session_count,
+        average_fps: stats.average_fps,
+        average_latency_us: stats.average_latency_us,
+    })
+}
+
+/// Graphics/compute/copy engine load for one GPU. Telling them apart shows
+/// whether a workload is render-bound or waiting on transfers; drivers
+/// without per-engine counters report the overall figure with
+/// `per_engine` unset.
+pub fn get_engine_utilization(
+    backend: &SharedNvmlBackend,
+    index: u32,
+) -> NvResult<crate::nvml_backend::EngineUtilization> {
+    backend.get_engine_utilization(index)
+}
+
 #[derive(Debug, Clone, ValueEnum)]
 pub enum OutputFormat {
     Human,
@@ -17,6 +17,40 @@ pub struct GpuModelSpec {
     pub boost_clock: u32,        // MHz
 }
 
+impl GpuModelSpec {
+    /// Published peak memory bandwidth in GB/s
+    pub fn memory_bandwidth_gbps(&self) -> Option<f64> {
+        let base = self
+            .model_name
+            .split(" (")
+            .next()
+            .unwrap_or(&self.model_name);
+        let gbps = match base {
+            "RTX 5090" => 1792.0,
+            "RTX 5080" => 960.0,
+            "RTX 5070 Ti" => 896.0,
+            "RTX 5070" => 672.0,
+            "RTX 5060 Ti" | "RTX 5060" => 448.0,
+            "RTX 4090" => 1008.0,
+            "RTX 4080 Super" => 736.0,
+            "RTX 4080" => 716.8,
+            "RTX 4070 Ti Super" => 672.0,
+            "RTX 4070 Ti" | "RTX 4070 Super" | "RTX 4070" => 504.0,
+            "RTX 4060 Ti" => 288.0,
+            "RTX 4060" => 272.0,
+            "RTX 3090 Ti" => 1008.0,
+            "RTX 3090" => 936.0,
+            "RTX 3080 Ti" => 912.0,
+            "RTX 3080" => 760.0,
+            "RTX 3070 Ti" => 608.0,
+            "RTX 3070" | "RTX 3060 Ti" => 448.0,
+            "RTX 3060" => 360.0,
+            _ => return None,
+        };
+        Some(gbps)
+    }
+}
+
 /// Get GPU specifications based on model name
 pub fn get_gpu_spec(model_name: &str) -> Option<GpuModelSpec> {
     let model_lower = model_name.to_lowercase();
@@ -477,6 +511,100 @@ pub fn generate_gaming_profiles(model_name: &str) -> Vec<OverclockProfile> {
     ]
 }
 
+/// Clock offset preset levels
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OcPreset {
+    Stock,
+    MildOc,
+    Performance,
+    Extreme,
+}
+
+impl OcPreset {
+    pub const ALL: [OcPreset; 4] = [
+        OcPreset::Stock,
+        OcPreset::MildOc,
+        OcPreset::Performance,
+        OcPreset::Extreme,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OcPreset::Stock => "Stock",
+            OcPreset::MildOc => "Mild OC",
+            OcPreset::Performance => "Performance",
+            OcPreset::Extreme => "Extreme",
+        }
+    }
+
+    /// Parse `stock`, `mild`, `performance` or `extreme` (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' '], "")
+            .as_str()
+        {
+            "stock" => Some(OcPreset::Stock),
+            "mild" | "mildoc" => Some(OcPreset::MildOc),
+            "performance" | "perf" => Some(OcPreset::Performance),
+            "extreme" => Some(OcPreset::Extreme),
+            _ => None,
+        }
+    }
+
+    /// Fraction of the model's safe (core, memory) offsets this preset uses
+    fn scale(self) -> (f32, f32) {
+        match self {
+            OcPreset::Stock => (0.0, 0.0),
+            OcPreset::MildOc => (0.5, 0.5),
+            OcPreset::Performance => (1.0, 1.0),
+            // Same headroom as the "Max Performance" gaming profile
+            OcPreset::Extreme => (1.2, 1.1),
+        }
+    }
+}
+
+/// Safe offsets assumed for cards missing from [`get_gpu_spec`]
+pub const GENERIC_SAFE_GPU_OFFSET: i32 = 75;
+pub const GENERIC_SAFE_MEMORY_OFFSET: i32 = 400;
+
+/// Presets never go past the offset editors' ranges
+const MAX_PRESET_GPU_OFFSET: i32 = 300;
+const MAX_PRESET_MEMORY_OFFSET: i32 = 1500;
+
+/// Offsets a preset resolves to on a particular card
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresetOffsets {
+    pub preset: OcPreset,
+    pub gpu_offset: i32,
+    pub memory_offset: i32,
+    /// Model the values were scaled to; `None` means the generic fallback
+    pub model: Option<String>,
+}
+
+/// Scale `preset` to the known-safe offsets of `gpu_name`, or to
+/// conservative generic values when the model isn't in the database
+pub fn preset_offsets(gpu_name: &str, preset: OcPreset) -> PresetOffsets {
+    let spec = get_gpu_spec(gpu_name);
+    let (safe_gpu, safe_memory) = spec
+        .as_ref()
+        .map(|s| (s.safe_gpu_offset, s.safe_memory_offset))
+        .unwrap_or((GENERIC_SAFE_GPU_OFFSET, GENERIC_SAFE_MEMORY_OFFSET));
+    let (gpu_scale, memory_scale) = preset.scale();
+    // Round to 5 MHz steps like the offset editors
+    let scaled = |safe: i32, scale: f32, max: i32| {
+        (((safe as f32 * scale) / 5.0).round() as i32 * 5).min(max)
+    };
+
+    PresetOffsets {
+        preset,
+        gpu_offset: scaled(safe_gpu, gpu_scale, MAX_PRESET_GPU_OFFSET),
+        memory_offset: scaled(safe_memory, memory_scale, MAX_PRESET_MEMORY_OFFSET),
+        model: spec.map(|s| s.model_name),
+    }
+}
+
 /// Print GPU specifications
 pub fn print_gpu_info(model_name: &str) {
     if let Some(spec) = get_gpu_spec(model_name) {
@@ -514,6 +642,31 @@ mod tests {
         assert_eq!(spec.default_tdp, 575);
     }
 
+    #[test]
+    fn test_preset_offsets_scale_to_model() {
+        let small = preset_offsets("NVIDIA GeForce RTX 3060", OcPreset::Extreme);
+        let big = preset_offsets("NVIDIA GeForce RTX 5090", OcPreset::Extreme);
+        assert_eq!(big.model.as_deref(), Some("RTX 5090"));
+        assert_eq!((big.gpu_offset, big.memory_offset), (180, 1500));
+        assert_eq!(small.model.as_deref(), Some("RTX 3060"));
+        assert!(small.memory_offset < big.memory_offset);
+
+        let mild = preset_offsets("NVIDIA GeForce RTX 5090", OcPreset::MildOc);
+        assert_eq!((mild.gpu_offset, mild.memory_offset), (75, 750));
+
+        let unknown = preset_offsets("Quadro Mystery", OcPreset::Performance);
+        assert_eq!(unknown.model, None);
+        assert_eq!(
+            (unknown.gpu_offset, unknown.memory_offset),
+            (GENERIC_SAFE_GPU_OFFSET, GENERIC_SAFE_MEMORY_OFFSET)
+        );
+        let stock = preset_offsets("Quadro Mystery", OcPreset::Stock);
+        assert_eq!((stock.gpu_offset, stock.memory_offset), (0, 0));
+
+        assert_eq!(OcPreset::from_name("Mild-OC"), Some(OcPreset::MildOc));
+        assert_eq!(OcPreset::from_name("turbo"), None);
+    }
+
     #[test]
     fn test_rtx_4090_spec() {
         let spec = get_gpu_spec("RTX 4090").unwrap();
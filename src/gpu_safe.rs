@@ -9,6 +9,9 @@ use crate::{
 };
 use nvml_wrapper::Nvml;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Enhanced GPU information with architecture detection
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -276,23 +279,19 @@ impl SafeGpuController {
     fn apply_overclock_impl(&self, gpu_offset: i32, memory_offset: i32) -> NvResult<()> {
         // Try nvidia-settings (X11)
         if std::env::var("DISPLAY").is_ok() {
-            let output = std::process::Command::new("nvidia-settings")
-                .args(&[
-                    "-a",
-                    &format!(
-                        "[gpu:{}]/GPUGraphicsClockOffsetAllPerformanceLevels={}",
-                        self.gpu_id, gpu_offset
-                    ),
-                    "-a",
-                    &format!(
-                        "[gpu:{}]/GPUMemoryTransferRateOffsetAllPerformanceLevels={}",
-                        self.gpu_id, memory_offset
-                    ),
-                ])
-                .output()
-                .map_err(|e| {
-                    NvControlError::CommandFailed(format!("nvidia-settings failed: {}", e))
-                })?;
+            let output = execute(Command::new("nvidia-settings").args([
+                "-a",
+                &format!(
+                    "[gpu:{}]/GPUGraphicsClockOffsetAllPerformanceLevels={}",
+                    self.gpu_id, gpu_offset
+                ),
+                "-a",
+                &format!(
+                    "[gpu:{}]/GPUMemoryTransferRateOffsetAllPerformanceLevels={}",
+                    self.gpu_id, memory_offset
+                ),
+            ]))
+            .map_err(|e| NvControlError::CommandFailed(format!("nvidia-settings failed: {}", e)))?;
 
             if output.status.success() {
                 println!(
@@ -338,6 +337,14 @@ impl SafeGpuController {
             .with_gpu(self.gpu_id)
             .with_suggestion("Try running with sudo for power management");
 
+        if !should_apply(&format!(
+            "call NVML set_power_management_limit({} mW) on GPU {}",
+            watts * 1000,
+            self.gpu_id
+        )) {
+            return Ok(());
+        }
+
         let nvml = handle_nvml_error(Nvml::init(), ErrorContext::new("initialize NVML"))?;
         let mut device = handle_nvml_error(nvml.device_by_index(self.gpu_id), ctx.clone())?;
 
@@ -370,10 +377,113 @@ impl SafeGpuController {
     }
 }
 
+// =============================================================================
+// Dry-run executor
+// =============================================================================
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Turn dry-run on or off for every mutating operation in this process
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::SeqCst)
+}
+
+/// Shell-style rendering of a command, quoting arguments that need it
+pub fn describe_command(cmd: &Command) -> String {
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|part| {
+            let part = part.to_string_lossy();
+            if part.is_empty() || part.contains(|c: char| c.is_whitespace() || "'\"$".contains(c)) {
+                format!("'{}'", part.replace('\'', r"'\''"))
+            } else {
+                part.into_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Gate for changes that aren't a command or file write (NVML calls, ioctls).
+/// In dry-run mode prints the intended change and returns false.
+pub fn should_apply(action: &str) -> bool {
+    apply_unless_dry_run(is_dry_run(), action)
+}
+
+fn apply_unless_dry_run(dry_run: bool, action: &str) -> bool {
+    if dry_run {
+        println!("🧪 [dry-run] would {}", action);
+        return false;
+    }
+    true
+}
+
+/// Run a command that changes GPU state. In dry-run mode the command is
+/// printed and an empty successful output is returned instead.
+pub fn execute(cmd: &mut Command) -> std::io::Result<Output> {
+    execute_with(is_dry_run(), cmd)
+}
+
+fn execute_with(dry_run: bool, cmd: &mut Command) -> std::io::Result<Output> {
+    if !apply_unless_dry_run(dry_run, &format!("run: {}", describe_command(cmd))) {
+        use std::os::unix::process::ExitStatusExt;
+        return Ok(Output {
+            status: std::process::ExitStatus::from_raw(0),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        });
+    }
+    cmd.output()
+}
+
+/// Write a sysfs attribute, or print the intended write in dry-run mode
+pub fn write_sysfs(path: impl AsRef<Path>, value: impl AsRef<str>) -> std::io::Result<()> {
+    write_sysfs_with(is_dry_run(), path.as_ref(), value.as_ref())
+}
+
+fn write_sysfs_with(dry_run: bool, path: &Path, value: &str) -> std::io::Result<()> {
+    if !apply_unless_dry_run(dry_run, &format!("write '{}' to {}", value, path.display())) {
+        return Ok(());
+    }
+    std::fs::write(path, value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_describe_command_quotes_args() {
+        let mut cmd = Command::new("nvidia-smi");
+        cmd.args(["-pl", "80%"]);
+        assert_eq!(describe_command(&cmd), "nvidia-smi -pl 80%");
+
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "nvidia-settings -a '[gpu:0]/GPUPowerMizerMode=1'"]);
+        assert_eq!(
+            describe_command(&cmd),
+            r"sh -c 'nvidia-settings -a '\''[gpu:0]/GPUPowerMizerMode=1'\'''"
+        );
+    }
+
+    #[test]
+    fn test_dry_run_skips_execution() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("pwm1");
+
+        // The process-wide flag is left alone; other tests run in parallel
+        let output = execute_with(true, Command::new("false").arg("--nonexistent")).unwrap();
+        let write = write_sysfs_with(true, &target, "128");
+
+        assert!(output.status.success());
+        assert!(write.is_ok());
+        assert!(!target.exists());
+    }
+
     #[test]
     fn test_architecture_detection() {
         assert_eq!(
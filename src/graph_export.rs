@@ -0,0 +1,255 @@
+//! Export monitoring graphs as images for reports
+//!
+//! [`Graph::render_svg`] draws one time series as a standalone chart with a
+//! title, axis labels and grid. PNG export takes pixels captured by the GUI
+//! (see [`write_png`]).
+
+use crate::{NvControlError, NvResult};
+use chrono::{DateTime, Local};
+use std::fmt::Write as _;
+use std::path::Path;
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 400.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 24.0;
+const MARGIN_TOP: f64 = 50.0;
+const MARGIN_BOTTOM: f64 = 56.0;
+const Y_TICKS: usize = 5;
+const X_TICKS: usize = 6;
+
+/// One monitoring series ready to export
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pub title: String,
+    /// Y axis label including the unit, e.g. "Temperature (°C)"
+    pub y_label: String,
+    pub color: [u8; 3],
+    /// `[seconds, value]` samples in time order
+    pub points: Vec<[f64; 2]>,
+}
+
+/// "RTX 4090 — Temperature (2026-10-16 14:02:10 – 14:04:10)"
+pub fn graph_title(
+    gpu_name: &str,
+    metric: &str,
+    from: DateTime<Local>,
+    to: DateTime<Local>,
+) -> String {
+    let end = if from.date_naive() == to.date_naive() {
+        to.format("%H:%M:%S").to_string()
+    } else {
+        to.format("%Y-%m-%d %H:%M:%S").to_string()
+    };
+    format!(
+        "{gpu_name} — {metric} ({} – {end})",
+        from.format("%Y-%m-%d %H:%M:%S")
+    )
+}
+
+/// Smallest 1/2/5 × 10ⁿ value at or above `value`, used as the axis maximum
+fn nice_ceiling(value: f64) -> f64 {
+    if value <= 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(value.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|step| step * magnitude)
+        .find(|candidate| *candidate >= value)
+        .unwrap_or(10.0 * magnitude)
+}
+
+fn format_tick(value: f64) -> String {
+    if value.fract().abs() < 1e-9 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Graph {
+    /// Whether there is enough data to draw a line
+    pub fn is_exportable(&self) -> bool {
+        self.points.len() >= 2
+    }
+
+    /// Render the series as an 800×400 SVG chart
+    pub fn render_svg(&self) -> String {
+        let plot_w = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+        let plot_h = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+
+        let t0 = self.points.first().map(|p| p[0]).unwrap_or(0.0);
+        let span = self
+            .points
+            .last()
+            .map(|p| p[0] - t0)
+            .filter(|span| *span > 0.0)
+            .unwrap_or(1.0);
+        let y_max = nice_ceiling(self.points.iter().map(|p| p[1]).fold(0.0, f64::max));
+
+        let x_of = |t: f64| MARGIN_LEFT + (t - t0) / span * plot_w;
+        let y_of = |v: f64| MARGIN_TOP + plot_h - (v / y_max).clamp(0.0, 1.0) * plot_h;
+        let [r, g, b] = self.color;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" font-family="sans-serif">"#
+        );
+        let _ = writeln!(
+            svg,
+            r##"<rect width="{WIDTH}" height="{HEIGHT}" fill="#ffffff"/>"##
+        );
+        let _ = writeln!(
+            svg,
+            r##"<text x="{}" y="28" text-anchor="middle" font-size="16" font-weight="bold" fill="#222222">{}</text>"##,
+            WIDTH / 2.0,
+            escape_xml(&self.title)
+        );
+
+        // Grid and Y ticks
+        for i in 0..=Y_TICKS {
+            let value = y_max * i as f64 / Y_TICKS as f64;
+            let y = y_of(value);
+            let _ = writeln!(
+                svg,
+                r##"<line x1="{MARGIN_LEFT}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="#e0e0e0"/>"##,
+                MARGIN_LEFT + plot_w
+            );
+            let _ = writeln!(
+                svg,
+                r##"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="12" fill="#444444">{}</text>"##,
+                MARGIN_LEFT - 8.0,
+                y + 4.0,
+                format_tick(value)
+            );
+        }
+
+        // X ticks, seconds from the first sample
+        for i in 0..=X_TICKS {
+            let offset = span * i as f64 / X_TICKS as f64;
+            let x = x_of(t0 + offset);
+            let _ = writeln!(
+                svg,
+                r##"<text x="{x:.1}" y="{:.1}" text-anchor="middle" font-size="12" fill="#444444">{}</text>"##,
+                MARGIN_TOP + plot_h + 18.0,
+                format_tick(offset.round())
+            );
+        }
+
+        // Axes
+        let _ = writeln!(
+            svg,
+            r##"<path d="M{MARGIN_LEFT},{MARGIN_TOP} V{:.1} H{:.1}" fill="none" stroke="#444444"/>"##,
+            MARGIN_TOP + plot_h,
+            MARGIN_LEFT + plot_w
+        );
+        let _ = writeln!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="13" fill="#222222">Time (s)</text>"##,
+            MARGIN_LEFT + plot_w / 2.0,
+            HEIGHT - 12.0
+        );
+        let _ = writeln!(
+            svg,
+            r##"<text x="18" y="{0:.1}" text-anchor="middle" font-size="13" fill="#222222" transform="rotate(-90 18 {0:.1})">{1}</text>"##,
+            MARGIN_TOP + plot_h / 2.0,
+            escape_xml(&self.y_label)
+        );
+
+        // Series
+        let line: Vec<String> = self
+            .points
+            .iter()
+            .map(|p| format!("{:.1},{:.1}", x_of(p[0]), y_of(p[1])))
+            .collect();
+        let _ = writeln!(
+            svg,
+            r#"<polyline points="{}" fill="none" stroke="rgb({r},{g},{b})" stroke-width="2"/>"#,
+            line.join(" ")
+        );
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Write `graph` to `path` as SVG
+pub fn write_svg(graph: &Graph, path: &Path) -> NvResult<()> {
+    if !graph.is_exportable() {
+        return Err(NvControlError::RuntimeError(
+            "Not enough samples to export a graph".to_string(),
+        ));
+    }
+    std::fs::write(path, graph.render_svg()).map_err(|e| {
+        NvControlError::RuntimeError(format!("Failed to write {}: {}", path.display(), e))
+    })
+}
+
+/// Encode RGBA pixels (row-major, 4 bytes per pixel) as a PNG file
+pub fn write_png(path: &Path, width: u32, height: u32, rgba: &[u8]) -> NvResult<()> {
+    let fail = |e: &dyn std::fmt::Display| {
+        NvControlError::RuntimeError(format!("Failed to write {}: {}", path.display(), e))
+    };
+
+    let file = std::fs::File::create(path).map_err(|e| fail(&e))?;
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|e| fail(&e))?;
+    writer.write_image_data(rgba).map_err(|e| fail(&e))?;
+    writer.finish().map_err(|e| fail(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_svg_has_title_axes_and_series() {
+        let graph = Graph {
+            title: "RTX 4090 — Temperature <test>".to_string(),
+            y_label: "Temperature (°C)".to_string(),
+            color: [255, 0, 0],
+            points: vec![[10.0, 40.0], [20.0, 55.0], [30.0, 72.0]],
+        };
+        assert!(graph.is_exportable());
+
+        let svg = graph.render_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("RTX 4090 — Temperature &lt;test&gt;"));
+        assert!(svg.contains("Time (s)"));
+        assert!(svg.contains("Temperature (°C)"));
+        assert!(svg.contains(r#"stroke="rgb(255,0,0)""#));
+        // 72 rounds up to a 100 axis
+        assert!(svg.contains(">100</text>"));
+        assert_eq!(nice_ceiling(72.0), 100.0);
+        assert_eq!(nice_ceiling(310.0), 500.0);
+        assert_eq!(nice_ceiling(0.0), 1.0);
+
+        let empty = Graph {
+            points: vec![[0.0, 1.0]],
+            ..graph
+        };
+        assert!(!empty.is_exportable());
+        assert!(write_svg(&empty, Path::new("/nonexistent/graph.svg")).is_err());
+    }
+
+    #[test]
+    fn test_write_png_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("graph.png");
+        write_png(&path, 2, 1, &[255, 0, 0, 255, 0, 255, 0, 255]).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
@@ -7,14 +7,49 @@ use eframe::egui;
 use super::state::GuiState;
 use super::tabs::Tab;
 use super::theme;
-use super::widgets::{HeaderBar, StatusState};
+use super::widgets::{CommandPalette, HeaderBar, PaletteAction, StatusState};
+
+/// Full window size, and the smallest it can be resized to
+const FULL_SIZE: [f32; 2] = [900.0, 600.0];
+const FULL_MIN_SIZE: [f32; 2] = [800.0, 500.0];
+
+/// Size of the compact stats panel in mini mode
+const MINI_SIZE: [f32; 2] = [240.0, 96.0];
+
+/// Window size, minimum size and stacking level for the full window or for
+/// mini mode
+fn window_layout(mini: bool) -> ([f32; 2], [f32; 2], egui::WindowLevel) {
+    if mini {
+        (MINI_SIZE, MINI_SIZE, egui::WindowLevel::AlwaysOnTop)
+    } else {
+        (FULL_SIZE, FULL_MIN_SIZE, egui::WindowLevel::Normal)
+    }
+}
 
 /// Run the modern modular GUI application
 pub fn run() -> eframe::Result<()> {
+    launch(false)
+}
+
+/// Run the GUI, starting in mini mode (a compact always-on-top stats panel)
+/// when `mini` is set
+pub fn launch(mini: bool) -> eframe::Result<()> {
+    // Driver update checks may hit the package manager or network
+    if !crate::error_recovery::is_safe_mode() {
+        std::thread::spawn(|| {
+            if let Err(e) = crate::notifications::check_driver_on_startup() {
+                eprintln!("Driver update check failed: {e}");
+            }
+        });
+    }
+
+    let (size, min_size, level) = window_layout(mini);
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_inner_size([900.0, 600.0])
-            .with_min_inner_size([800.0, 500.0])
+            .with_inner_size(size)
+            .with_min_inner_size(min_size)
+            .with_window_level(level)
+            .with_decorations(!mini)
             .with_title("nvcontrol - NVIDIA Settings Manager"),
         ..Default::default()
     };
@@ -22,8 +57,10 @@ pub fn run() -> eframe::Result<()> {
     eframe::run_native(
         "nvcontrol - NVIDIA Settings Manager",
         options,
-        Box::new(|cc| {
-            let app = NvControlApp::new();
+        Box::new(move |cc| {
+            let mut app = NvControlApp::new();
+            app.state.mini_mode = mini;
+            app.mini_window = mini;
             app.init(&cc.egui_ctx);
             Ok(Box::new(app))
         }),
@@ -38,6 +75,10 @@ pub struct NvControlApp {
     last_update: std::time::Instant,
     /// Update interval
     update_interval: std::time::Duration,
+    /// Ctrl+P command palette
+    palette: CommandPalette,
+    /// Whether the window is currently sized and layered for mini mode
+    mini_window: bool,
 }
 
 impl NvControlApp {
@@ -47,6 +88,8 @@ impl NvControlApp {
             state: GuiState::new(),
             last_update: std::time::Instant::now(),
             update_interval: std::time::Duration::from_millis(500),
+            palette: CommandPalette::default(),
+            mini_window: false,
         }
     }
 
@@ -56,6 +99,8 @@ impl NvControlApp {
             state: GuiState::with_theme(theme),
             last_update: std::time::Instant::now(),
             update_interval: std::time::Duration::from_millis(500),
+            palette: CommandPalette::default(),
+            mini_window: false,
         }
     }
 
@@ -75,6 +120,14 @@ impl NvControlApp {
 
     /// Handle keyboard shortcuts
     fn handle_keyboard(&mut self, ctx: &egui::Context) {
+        // Ctrl+P toggles the command palette, which then owns the keyboard
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::CTRL, egui::Key::P)) {
+            self.palette.toggle();
+        }
+        if self.palette.is_open() {
+            return;
+        }
+
         // Don't process number key shortcuts if a text field has focus
         let has_focus = ctx.memory(|m| m.focused().is_some());
 
@@ -129,6 +182,71 @@ impl NvControlApp {
         });
     }
 
+    /// Render the command palette with every tab plus common actions
+    fn render_palette(&mut self, ctx: &egui::Context) {
+        if !self.palette.is_open() {
+            return;
+        }
+
+        let mut actions: Vec<PaletteAction<'_, GuiState>> = Tab::sidebar_tabs()
+            .into_iter()
+            .map(|(tab, _, label)| -> PaletteAction<'_, GuiState> {
+                (
+                    format!("Open {} tab", label),
+                    Box::new(move |state: &mut GuiState| state.tab = tab),
+                )
+            })
+            .collect();
+
+        let theme_ctx = ctx.clone();
+        actions.push((
+            "Cycle theme".to_string(),
+            Box::new(move |state: &mut GuiState| {
+                state.cycle_theme();
+                theme::apply_theme(&theme_ctx, state.current_theme);
+            }),
+        ));
+        actions.push((
+            "Apply stock OC (reset offsets and power limit)".to_string(),
+            Box::new(|state: &mut GuiState| {
+                state.oc_profile = crate::overclocking::OverclockProfile::default();
+                state.oc_staged = false;
+                state.apply_overclock();
+            }),
+        ));
+        actions.push((
+            "Revert last overclock".to_string(),
+            Box::new(|state: &mut GuiState| state.revert_overclock()),
+        ));
+        for percentage in [100, 150, 175, 200] {
+            actions.push((
+                format!("Set vibrance {}%", percentage),
+                Box::new(move |state: &mut GuiState| {
+                    super::tabs::vibrance::apply_vibrance_to_all(state, percentage)
+                }),
+            ));
+        }
+        actions.push((
+            "Toggle HDR".to_string(),
+            Box::new(|state: &mut GuiState| state.toggle_hdr()),
+        ));
+        actions.push((
+            "Mini mode (compact always-on-top stats)".to_string(),
+            Box::new(|state: &mut GuiState| state.mini_mode = true),
+        ));
+        actions.push((
+            "Save config".to_string(),
+            Box::new(|state: &mut GuiState| state.save_config()),
+        ));
+        actions.push((
+            "Refresh GPU stats".to_string(),
+            Box::new(|state: &mut GuiState| state.refresh_gpu_stats()),
+        ));
+
+        let colors = self.state.theme_colors();
+        self.palette.show(ctx, &colors, actions, &mut self.state);
+    }
+
     /// Render the sidebar navigation
     fn render_sidebar(&mut self, ui: &mut egui::Ui) {
         let colors = self.state.theme_colors();
@@ -166,7 +284,7 @@ impl NvControlApp {
                     }
                 }
 
-                // Version at bottom
+                // Version and the Reset All panic button at the bottom
                 ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
                     ui.add_space(8.0);
                     ui.label(
@@ -174,10 +292,109 @@ impl NvControlApp {
                             .small()
                             .weak(),
                     );
+                    ui.add_space(4.0);
+                    let resetting = self.state.reset_all_rx.is_some();
+                    let label = if resetting {
+                        "Resetting…"
+                    } else {
+                        "Reset All"
+                    };
+                    if ui
+                        .add_enabled(
+                            !resetting,
+                            egui::Button::new(
+                                egui::RichText::new(format!("{} {}", super::icons::REFRESH, label))
+                                    .color(colors.red.to_egui())
+                                    .strong(),
+                            )
+                            .stroke(egui::Stroke::new(1.0, colors.red.to_egui()))
+                            .min_size(egui::vec2(170.0, 28.0)),
+                        )
+                        .on_hover_text("Revert clocks, power, fans, vibrance and RGB to stock")
+                        .clicked()
+                    {
+                        self.state.confirm_reset_all = true;
+                    }
                 });
             });
     }
 
+    /// Resize and re-layer the window when mini mode is switched
+    fn sync_mini_window(&mut self, ctx: &egui::Context) {
+        if self.mini_window == self.state.mini_mode {
+            return;
+        }
+        self.mini_window = self.state.mini_mode;
+
+        let (size, min_size, level) = window_layout(self.mini_window);
+        ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(min_size.into()));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size.into()));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(level));
+        ctx.send_viewport_cmd(egui::ViewportCommand::Decorations(!self.mini_window));
+    }
+
+    /// Compact panel with temperature, utilization, power and fan speed.
+    /// Drag to move it; click to restore the full window.
+    fn render_mini(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        let colors = self.state.theme_colors();
+
+        egui::Frame::new()
+            .fill(colors.bg_dark.to_egui())
+            .inner_margin(8.0)
+            .show(ui, |ui| {
+                ui.set_min_size(ui.available_size());
+                let Some(stats) = &self.state.gpu_stats else {
+                    ui.label(egui::RichText::new("Waiting for GPU stats…").weak());
+                    return;
+                };
+
+                ui.label(egui::RichText::new(&stats.name).small().weak());
+                let power_percent = if stats.power_limit > 0.0 {
+                    stats.power_draw / stats.power_limit * 100.0
+                } else {
+                    0.0
+                };
+                egui::Grid::new("mini_mode_stats")
+                    .num_columns(2)
+                    .spacing([16.0, 2.0])
+                    .show(ui, |ui| {
+                        ui.colored_label(
+                            colors.temp_color(stats.temperature).to_egui(),
+                            format!("{} {:.0}°C", super::icons::TEMP, stats.temperature),
+                        );
+                        ui.colored_label(
+                            colors.usage_color(stats.utilization).to_egui(),
+                            format!("{} {:.0}%", super::icons::GPU, stats.utilization),
+                        );
+                        ui.end_row();
+                        ui.colored_label(
+                            colors.power_color(power_percent).to_egui(),
+                            format!("{} {:.0} W", super::icons::POWER, stats.power_draw),
+                        );
+                        ui.colored_label(
+                            colors.fg.to_egui(),
+                            format!("{} {}%", super::icons::FAN_ICON, stats.fan_speed),
+                        );
+                        ui.end_row();
+                    });
+            });
+
+        let response = ui
+            .interact(
+                ui.max_rect(),
+                egui::Id::new("mini_mode_panel"),
+                egui::Sense::click_and_drag(),
+            )
+            .on_hover_text("Click to restore the full window, drag to move");
+        if response.drag_started() {
+            ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+        } else if response.clicked() {
+            self.state.mini_mode = false;
+        }
+
+        ctx.request_repaint_after(self.update_interval);
+    }
+
     /// Render the header bar
     fn render_header(&self, ui: &mut egui::Ui) {
         let colors = self.state.theme_colors();
@@ -210,10 +427,212 @@ impl NvControlApp {
             header = header.add_status("Power Monitor+", power_state);
         }
 
+        // Capped fans and power are easy to forget, so keep them visible
+        if self.state.quiet_mode {
+            header = header.add_status("Quiet Mode", StatusState::Warning);
+        }
+
         header.show(ui);
     }
 }
 
+/// Confirmation for the sidebar's Reset All button
+fn render_reset_all_dialog(ctx: &egui::Context, state: &mut GuiState) {
+    if !state.confirm_reset_all {
+        return;
+    }
+    let colors = state.theme_colors();
+    let mut confirmed = false;
+    let mut cancelled = false;
+
+    let response = egui::Modal::new(egui::Id::new("reset_all_dialog")).show(ctx, |ui| {
+        ui.heading("Reset everything to stock?");
+        ui.label(
+            egui::RichText::new(
+                "Clock offsets go to 0, power limits to default, clocks are unlocked, \
+                 fans return to automatic, vibrance to 100% and GPU RGB is turned off.",
+            )
+            .color(colors.fg_dark.to_egui()),
+        );
+        ui.label(
+            egui::RichText::new("Display modes and compositor settings are not touched.").small(),
+        );
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("{} Reset All", super::icons::REFRESH))
+                .clicked()
+            {
+                confirmed = true;
+            }
+            if ui.button("Cancel").clicked() {
+                cancelled = true;
+            }
+        });
+    });
+
+    if confirmed {
+        state.start_reset_all();
+    } else if cancelled || response.should_close() {
+        state.confirm_reset_all = false;
+    }
+}
+
+/// Where a requested graph screenshot should be cropped and saved
+struct GraphScreenshot {
+    path: std::path::PathBuf,
+    rect: egui::Rect,
+}
+
+fn render_graph_export_dialog(ctx: &egui::Context, state: &mut GuiState) {
+    let Some(metric) = state.graph_export.as_ref().map(|dialog| dialog.metric) else {
+        return;
+    };
+    let graph = state.export_graph(metric);
+    let colors = state.theme_colors();
+    let mut save_svg = false;
+    let mut save_png = None;
+    let mut close = false;
+
+    let response = egui::Modal::new(egui::Id::new("graph_export_dialog")).show(ctx, |ui| {
+        // Everything in this group ends up in the PNG
+        let chart = ui
+            .vertical(|ui| {
+                ui.label(egui::RichText::new(&graph.title).strong());
+                let t0 = graph.points.first().map(|p| p[0]).unwrap_or(0.0);
+                let points: Vec<[f64; 2]> =
+                    graph.points.iter().map(|p| [p[0] - t0, p[1]]).collect();
+                let [r, g, b] = graph.color;
+                egui_plot::Plot::new("graph_export_plot")
+                    .width(560.0)
+                    .height(260.0)
+                    .include_y(0.0)
+                    .x_axis_label("Time (s)")
+                    .y_axis_label(graph.y_label.as_str())
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(
+                            egui_plot::Line::new(
+                                metric.name(),
+                                egui_plot::PlotPoints::from(points),
+                            )
+                            .color(egui::Color32::from_rgb(r, g, b))
+                            .width(2.0),
+                        );
+                    });
+            })
+            .response
+            .rect;
+
+        ui.add_space(8.0);
+        if let Some(dialog) = state.graph_export.as_mut() {
+            ui.horizontal(|ui| {
+                ui.label("Save to:");
+                ui.add(egui::TextEdit::singleline(&mut dialog.path).desired_width(420.0));
+            });
+        }
+        if !graph.is_exportable() {
+            ui.label(
+                egui::RichText::new("Not enough samples yet")
+                    .small()
+                    .color(colors.yellow.to_egui()),
+            );
+        }
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(graph.is_exportable(), egui::Button::new("Save PNG"))
+                .clicked()
+            {
+                save_png = Some(chart);
+            }
+            if ui
+                .add_enabled(graph.is_exportable(), egui::Button::new("Save SVG"))
+                .clicked()
+            {
+                save_svg = true;
+            }
+            if ui.button("Close").clicked() {
+                close = true;
+            }
+        });
+    });
+
+    let path = |extension: &str| {
+        state
+            .graph_export
+            .as_ref()
+            .map(|dialog| std::path::Path::new(dialog.path.trim()).with_extension(extension))
+    };
+    if save_svg && let Some(path) = path("svg") {
+        match crate::graph_export::write_svg(&graph, &path) {
+            Ok(()) => state
+                .toasts
+                .success(format!("Graph saved to {}", path.display())),
+            Err(e) => state.toasts.error(e.to_string()),
+        }
+    }
+    if let Some(rect) = save_png
+        && let Some(path) = path("png")
+    {
+        ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(
+            GraphScreenshot { path, rect },
+        )));
+    }
+    if close || response.should_close() {
+        state.graph_export = None;
+    }
+}
+
+/// Crop a finished graph screenshot to the chart and write it as PNG
+fn save_graph_screenshots(ctx: &egui::Context, state: &mut GuiState) {
+    let shots: Vec<_> = ctx.input(|i| {
+        i.events
+            .iter()
+            .filter_map(|event| match event {
+                egui::Event::Screenshot {
+                    user_data, image, ..
+                } => user_data
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.downcast_ref::<GraphScreenshot>())
+                    .map(|shot| (shot.path.clone(), shot.rect, image.clone())),
+                _ => None,
+            })
+            .collect()
+    });
+
+    for (path, rect, image) in shots {
+        let chart = image.region(&rect, Some(ctx.pixels_per_point()));
+        let [width, height] = chart.size;
+        match crate::graph_export::write_png(&path, width as u32, height as u32, chart.as_raw()) {
+            Ok(()) => state
+                .toasts
+                .success(format!("Graph saved to {}", path.display())),
+            Err(e) => state.toasts.error(e.to_string()),
+        }
+    }
+}
+
+/// Stand-in for tabs whose hardware probes safe mode skips
+fn render_safe_mode_notice(ui: &mut egui::Ui, state: &GuiState) {
+    let colors = state.theme_colors();
+    ui.add_space(24.0);
+    ui.vertical_centered(|ui| {
+        ui.heading(format!("{} Disabled in safe mode", super::icons::WARN));
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(format!(
+                "Restart nvcontrol without --safe-mode (and with {} unset) to use this tab.",
+                crate::error_recovery::SAFE_MODE_ENV
+            ))
+            .color(colors.fg_dark.to_egui()),
+        );
+    });
+}
+
 impl Default for NvControlApp {
     fn default() -> Self {
         Self::new()
@@ -229,9 +648,20 @@ impl eframe::App for NvControlApp {
             self.state.refresh_gpu_stats();
             self.state.refresh_asus_power();
             self.state.poll_support_jobs();
+            self.state.poll_vibrance_fade();
+            self.state.poll_reset_all();
+            if let Some(report) = self.state.diagnostics_report.take() {
+                ctx.copy_text(report);
+            }
             self.last_update = std::time::Instant::now();
         }
 
+        self.sync_mini_window(&ctx);
+        if self.state.mini_mode {
+            self.render_mini(ui, &ctx);
+            return;
+        }
+
         // Handle keyboard shortcuts
         self.handle_keyboard(&ctx);
 
@@ -239,6 +669,21 @@ impl eframe::App for NvControlApp {
             self.render_header(ui);
         });
 
+        if let Some(banner) = self.state.subsystems.banner() {
+            let colors = self.state.theme_colors();
+            egui::Panel::top("subsystem_banner").show_inside(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.colored_label(
+                        colors.yellow.to_egui(),
+                        format!("{} {}", super::icons::WARN, banner),
+                    );
+                    if ui.small_button("Settings").clicked() {
+                        self.state.tab = Tab::Settings;
+                    }
+                });
+            });
+        }
+
         egui::Panel::left("sidebar")
             .resizable(false)
             .default_size(180.0)
@@ -247,7 +692,11 @@ impl eframe::App for NvControlApp {
             });
 
         egui::CentralPanel::default().show_inside(ui, |ui| match self.state.tab {
+            Tab::Overclock | Tab::Fan | Tab::Containers if self.state.subsystems.safe_mode => {
+                render_safe_mode_notice(ui, &self.state)
+            }
             Tab::Gpu => super::tabs::gpu::render(ui, &mut self.state, &ctx),
+            Tab::Overclock => super::tabs::overclock::render(ui, &mut self.state, &ctx),
             Tab::Fan => super::tabs::fan::render(ui, &mut self.state, &ctx),
             Tab::Display => super::tabs::display::render(ui, &mut self.state, &ctx),
             Tab::Power => super::tabs::power::render(ui, &mut self.state, &ctx),
@@ -260,11 +709,19 @@ impl eframe::App for NvControlApp {
             Tab::Latency => super::tabs::latency::render(ui, &mut self.state, &ctx),
             Tab::Gamescope => super::tabs::gamescope::render(ui, &mut self.state, &ctx),
             Tab::Recording => super::tabs::recording::render(ui, &mut self.state, &ctx),
+            Tab::Shaders => super::tabs::shaders::render(ui, &mut self.state, &ctx),
             Tab::System => super::tabs::system::render(ui, &mut self.state, &ctx),
+            Tab::Containers => super::tabs::containers::render(ui, &mut self.state, &ctx),
             Tab::Support => super::tabs::support::render(ui, &mut self.state, &ctx),
+            Tab::Events => super::tabs::events::render(ui, &mut self.state, &ctx),
             Tab::Settings => super::tabs::settings::render(ui, &mut self.state, &ctx),
         });
 
+        self.render_palette(&ctx);
+        render_reset_all_dialog(&ctx, &mut self.state);
+        render_graph_export_dialog(&ctx, &mut self.state);
+        save_graph_screenshots(&ctx, &mut self.state);
+
         let colors = self.state.theme_colors();
         self.state.toasts.show(&ctx, &colors);
 
@@ -288,3 +745,20 @@ impl eframe::App for NvControlApp {
         egui::CentralPanel::default().show_inside(&mut root_ui, |ui| self.ui(ui, _frame));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mini_layout_is_compact_and_on_top() {
+        let (mini_size, mini_min, mini_level) = window_layout(true);
+        let (full_size, full_min, full_level) = window_layout(false);
+
+        assert_eq!(mini_level, egui::WindowLevel::AlwaysOnTop);
+        assert_eq!(full_level, egui::WindowLevel::Normal);
+        assert!(mini_size[0] < full_min[0] && mini_size[1] < full_min[1]);
+        assert!(mini_min[0] <= mini_size[0] && mini_min[1] <= mini_size[1]);
+        assert!(full_min[0] <= full_size[0] && full_min[1] <= full_size[1]);
+    }
+}
@@ -0,0 +1,109 @@
+//! Activity Timeline
+//!
+//! A bounded, timestamped record of what happened during the session:
+//! profiles applied, overclock changes, watchdog resets, throttling and
+//! alerts. Toasts are mirrored here so feedback that fades from the screen
+//! can still be reviewed. The handle is cheap to clone, letting the
+//! monitoring thread append from the background.
+
+use super::toast::ToastKind;
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Entries kept before the oldest are dropped
+pub const EVENT_LOG_CAPACITY: usize = 500;
+
+/// One timeline entry
+#[derive(Debug, Clone)]
+pub struct LogEvent {
+    pub time: DateTime<Local>,
+    pub kind: ToastKind,
+    pub message: String,
+}
+
+/// Shared ring buffer of session events
+#[derive(Debug, Clone, Default)]
+pub struct EventLog {
+    entries: Arc<Mutex<VecDeque<LogEvent>>>,
+}
+
+impl EventLog {
+    /// Append an event, dropping the oldest once full
+    pub fn push(&self, kind: ToastKind, message: impl Into<String>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= EVENT_LOG_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(LogEvent {
+            time: Local::now(),
+            kind,
+            message: message.into(),
+        });
+    }
+
+    pub fn info(&self, message: impl Into<String>) {
+        self.push(ToastKind::Info, message);
+    }
+
+    pub fn warning(&self, message: impl Into<String>) {
+        self.push(ToastKind::Warning, message);
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.push(ToastKind::Error, message);
+    }
+
+    /// Copy of the entries, oldest first
+    pub fn snapshot(&self) -> Vec<LogEvent> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_log_is_shared_and_bounded() {
+        let log = EventLog::default();
+        let background = log.clone();
+        background.warning("Watchdog reset GPU 0 to stock");
+        assert_eq!(log.len(), 1);
+        assert_eq!(log.snapshot()[0].kind, ToastKind::Warning);
+
+        for i in 0..EVENT_LOG_CAPACITY {
+            log.info(format!("event {i}"));
+        }
+        let entries = log.snapshot();
+        assert_eq!(entries.len(), EVENT_LOG_CAPACITY);
+        assert_eq!(entries[0].message, "event 0");
+        assert_eq!(
+            entries.last().unwrap().message,
+            format!("event {}", EVENT_LOG_CAPACITY - 1)
+        );
+
+        log.clear();
+        assert!(background.is_empty());
+    }
+}
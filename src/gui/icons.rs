@@ -40,9 +40,13 @@ pub const WARN: &str = WARNING;
 pub const ERR: &str = X_CIRCLE;
 pub const INFO: &str = egui_phosphor::regular::INFO;
 pub const BULB: &str = LIGHTBULB;
+pub const EVENTS: &str = LIST_BULLETS;
 
 // Actions
 pub const REFRESH: &str = ARROW_COUNTER_CLOCKWISE;
+pub const EXPORT: &str = egui_phosphor::regular::EXPORT;
+pub const MINI: &str = PICTURE_IN_PICTURE;
+pub const ROTATE: &str = ARROW_CLOCKWISE;
 
 // Misc
 pub const CHART: &str = CHART_LINE;
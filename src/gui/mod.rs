@@ -4,6 +4,7 @@
 //! Split from the monolithic nvcontrol.rs for maintainability.
 
 pub mod app;
+pub mod event_log;
 pub mod icons;
 pub mod state;
 pub mod tabs;
@@ -12,6 +13,6 @@ pub mod toast;
 pub mod widgets;
 
 // Re-export main types
-pub use app::{NvControlApp, run};
+pub use app::{NvControlApp, launch, run};
 pub use state::GuiState;
 pub use toast::{Toast, ToastKind, ToastManager};
@@ -18,6 +18,9 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::mpsc::{Receiver, Sender};
 
+/// One display's live refresh reading, or why it couldn't be taken
+pub type LiveRefresh = Result<crate::vrr::CurrentRefresh, String>;
+
 #[derive(Debug, Clone)]
 pub enum SupportJobResult {
     SystemRefresh {
@@ -28,9 +31,38 @@ pub enum SupportJobResult {
     BundleCreated {
         path: String,
     },
+    DiagnosticsReport(String),
     Error(String),
 }
 
+/// Updates from the background efficiency (undervolt) search
+#[derive(Debug, Clone)]
+pub enum EfficiencyJobUpdate {
+    Progress(crate::auto_overclock::EfficiencyProgress),
+    Finished(Result<crate::auto_overclock::EfficiencyProfile, String>),
+}
+
+/// Updates from a background staged overclock apply
+#[derive(Debug, Clone)]
+pub enum StagedApplyUpdate {
+    Step(crate::overclocking::StagedStep),
+    Finished(Result<crate::overclocking::StagedApplyResult, String>),
+}
+
+/// Updates from a background OC scan
+#[derive(Debug, Clone)]
+pub enum OcScanUpdate {
+    Progress(crate::asus_gpu_tweak::OcScanProgress),
+    Finished(Result<crate::asus_gpu_tweak::OcScanResult, String>),
+}
+
+/// Readings and the final report from a background thermal soak
+#[derive(Debug, Clone)]
+pub enum SoakUpdate {
+    Sample(crate::benchmark::SoakSample),
+    Finished(Result<crate::benchmark::ThermalSoakReport, String>),
+}
+
 /// GPU statistics snapshot
 #[derive(Debug, Clone, Default)]
 pub struct GpuStats {
@@ -43,22 +75,36 @@ pub struct GpuStats {
     pub temperature: f32,
     pub utilization: f32,
     pub fan_speed: u32,
+    /// Whole-board power in watts
     pub power_draw: f32,
+    /// GPU chip power in watts; `None` when only board power is reported
+    pub chip_power: Option<f32>,
     pub power_limit: f32,
     pub core_clock: u32,
     pub memory_clock: u32,
     pub pci_bus: String,
     pub compute_capability: String,
+    /// NVENC/NVDEC activity; `None` on GPUs without NVENC
+    pub video_engines: Option<crate::nvml_backend::VideoEngineStats>,
+    /// ECC error totals; `None` on GPUs without ECC memory
+    pub ecc_errors: Option<crate::nvml_backend::EccErrorCounts>,
+    pub pcie: crate::gpu::PcieStatus,
+    /// `None` when the driver doesn't report persistence mode
+    pub persistence_mode: Option<bool>,
+    /// Clocks pinned with `nvctl gpu lock-clocks` this boot
+    pub locked_clocks: Option<crate::overclocking::LockedClocks>,
 }
 
 /// Container information for the containers tab
 #[derive(Debug, Clone)]
 pub struct ContainerInfo {
+    pub runtime: String,
     pub id: String,
     pub name: String,
     pub image: String,
     pub status: String,
-    pub gpu_usage: String,
+    pub gpu_enabled: bool,
+    pub gpu_memory_mb: Option<u64>,
 }
 
 /// Fan control mode
@@ -79,6 +125,55 @@ impl FanMode {
     }
 }
 
+/// Monitoring series the GPU tab can export as an image
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphMetric {
+    Temperature,
+    Utilization,
+    Power,
+}
+
+impl GraphMetric {
+    pub fn name(self) -> &'static str {
+        match self {
+            GraphMetric::Temperature => "Temperature",
+            GraphMetric::Utilization => "GPU Utilization",
+            GraphMetric::Power => "Power",
+        }
+    }
+
+    pub fn axis_label(self) -> &'static str {
+        match self {
+            GraphMetric::Temperature => "Temperature (°C)",
+            GraphMetric::Utilization => "Utilization (%)",
+            GraphMetric::Power => "Power (W)",
+        }
+    }
+
+    fn file_stem(self) -> &'static str {
+        match self {
+            GraphMetric::Temperature => "temperature",
+            GraphMetric::Utilization => "utilization",
+            GraphMetric::Power => "power",
+        }
+    }
+
+    pub fn series(self, dashboard: &MonitoringDashboard) -> &crate::gui_widgets::TimeSeriesData {
+        match self {
+            GraphMetric::Temperature => &dashboard.temperature,
+            GraphMetric::Utilization => &dashboard.gpu_utilization,
+            GraphMetric::Power => &dashboard.power,
+        }
+    }
+}
+
+/// Open "Export graph" dialog
+pub struct GraphExportDialog {
+    pub metric: GraphMetric,
+    /// Destination; the extension is replaced by the chosen format
+    pub path: String,
+}
+
 /// Unified GUI state
 pub struct GuiState {
     // === Navigation ===
@@ -91,6 +186,8 @@ pub struct GuiState {
     pub gpu_stats: Option<GpuStats>,
     pub available_gpus: Vec<GpuInfo>,
     pub selected_gpu_index: u32,
+    /// Broadcast overclock, power limit and fan curve changes to every GPU
+    pub apply_to_all_gpus: bool,
 
     // Shared atomic for background thread to read current GPU selection
     selected_gpu_atomic: Arc<AtomicU32>,
@@ -110,20 +207,90 @@ pub struct GuiState {
     pub config: Config,
     pub app_state: Option<AppState>,
 
+    // === Startup ===
+    /// Safe-mode skips and subsystems whose initialization panicked
+    pub subsystems: crate::error_recovery::SubsystemReport,
+
     // === Toast Notifications ===
     pub toasts: ToastManager,
+    /// Session timeline; toasts are mirrored into it
+    pub event_log: crate::gui::event_log::EventLog,
+    /// Event Log tab hides info and success entries
+    pub event_log_problems_only: bool,
 
     // === Fan Control ===
     pub fan_curve: FanCurve,
+    /// Fan the curve editor targets; `None` edits the shared curve
+    pub fan_curve_target: Option<usize>,
     pub fan_mode: FanMode,
     pub fan_speeds: HashMap<usize, u8>,
     pub manual_fan_speed: u32,
 
+    // === Overclocking ===
+    pub oc_profile: crate::overclocking::OverclockProfile,
+    pub oc_history: crate::overclocking::OverclockHistory,
+    /// Read-back of the last single-GPU apply, one entry per field
+    pub oc_verification: Vec<crate::overclocking::FieldCheck>,
+    /// Saved benchmark baselines, newest first; `None` until first shown
+    pub benchmark_baselines: Option<Vec<crate::benchmark::Baseline>>,
+    /// Latest `nvctl benchmark run`, compared against the selected baseline
+    pub benchmark_last_run: Option<crate::benchmark::RunSummary>,
+    pub selected_baseline: usize,
+    pub new_baseline_name: String,
+    /// Receiver for a running efficiency search; `None` when idle
+    pub efficiency_rx: Option<Receiver<EfficiencyJobUpdate>>,
+    pub efficiency_log: Vec<String>,
+    pub efficiency_profile_name: String,
+    /// The sidebar's Reset All is waiting for confirmation
+    pub confirm_reset_all: bool,
+    /// Show only the compact always-on-top stats panel
+    pub mini_mode: bool,
+    /// Graph being exported from the GPU tab; `None` when the dialog is closed
+    pub graph_export: Option<GraphExportDialog>,
+    /// Receiver for a running Reset All; `None` when idle
+    pub reset_all_rx: Option<Receiver<Vec<crate::state::ApplyOutcome>>>,
+    /// Receiver for a running OC scan; `None` when idle
+    pub oc_scan_rx: Option<Receiver<OcScanUpdate>>,
+    pub oc_scan_cancel: Arc<AtomicBool>,
+    pub oc_scan_log: Vec<String>,
+    pub last_oc_scan: Option<crate::asus_gpu_tweak::OcScanResult>,
+    /// ASUS boards found on the PCI bus as (PCI bus id, model), for the OC
+    /// scanner badge
+    pub asus_boards: Vec<(String, String)>,
+    /// Ramp the core offset up in steps with a stability probe between each
+    pub oc_staged: bool,
+    /// Receiver for a running staged apply; `None` when idle
+    pub staged_rx: Option<Receiver<StagedApplyUpdate>>,
+    pub staged_cancel: Arc<AtomicBool>,
+    pub staged_log: Vec<String>,
+    /// Receiver for a running stress test; `None` when idle
+    pub stress_rx: Option<Receiver<Result<crate::overclocking::StressResult, String>>>,
+    pub stress_cancel: Arc<AtomicBool>,
+    pub stress_minutes: u32,
+    pub last_stress_result: Option<crate::overclocking::StressResult>,
+    /// Updates from a running thermal soak; `None` when idle
+    pub soak_rx: Option<Receiver<SoakUpdate>>,
+    pub soak_cancel: Arc<AtomicBool>,
+    pub soak_load_minutes: u32,
+    /// Readings of the running (or last) soak, for the live plot
+    pub soak_samples: Vec<crate::benchmark::SoakSample>,
+    pub last_soak: Option<crate::benchmark::ThermalSoakReport>,
+
     // === Power Curves ===
     pub power_config: crate::power_curves::PowerManagementConfig,
+    /// Power limit slider on the Power tab, percent of the default limit
+    pub power_limit_percent: f32,
+    /// PowerMizer mode read from nvidia-settings; `None` until the Power tab
+    /// opens, `Err` with the reason when it can't be read
+    pub powermizer_mode: Option<Result<crate::power::PowerMizerMode, String>>,
+    /// Dynamic Boost and MUX state of the selected GPU; `None` on desktops,
+    /// where the raw power limit controls apply
+    pub mobile_gpu: Option<crate::gpu::MobileGpuStatus>,
 
     // === Display Settings ===
     pub vibrance_levels: Vec<i16>,
+    /// Loaded NVIDIA kernel module; native vibrance needs the open one
+    pub kernel_module: Option<crate::kernel_driver::KernelModuleInfo>,
     pub selected_icc_profile_idx: usize,
     pub available_icc_profiles: Vec<String>,
 
@@ -134,6 +301,12 @@ pub struct GuiState {
     // === VRR ===
     pub vrr_enabled: bool,
     pub vrr_displays: Vec<crate::vrr::DisplayVrrCapability>,
+    /// Live refresh per display, sampled while the VRR tab is open
+    pub vrr_live: HashMap<String, LiveRefresh>,
+    /// Vibrance preset transition in progress
+    pub vibrance_fade: Option<crate::vibrance::VibranceFade>,
+    vrr_live_rx: Option<Receiver<Vec<(String, LiveRefresh)>>>,
+    vrr_live_at: std::time::Instant,
 
     // === Recording ===
     pub recording_active: bool,
@@ -142,10 +315,8 @@ pub struct GuiState {
 
     // === OSD (On-Screen Display) ===
     pub osd_enabled: bool,
-    pub osd_position: String,
-    pub osd_metrics: Vec<String>,
-    pub osd_font_size: u32,
-    pub osd_opacity: f32,
+    /// MangoHud.conf being edited in the OSD tab
+    pub osd_config: crate::osd::MangoHudConfig,
     pub mangohud_installed: bool,
 
     // === RGB Control ===
@@ -155,9 +326,33 @@ pub struct GuiState {
     // === Container Management ===
     pub container_runtime: Option<crate::container_runtime::NvContainerRuntime>,
     pub containers: Vec<ContainerInfo>,
+    /// Why the container list is empty (no runtime installed, ps failed)
+    pub containers_error: Option<String>,
 
     // === Game Profiles ===
     pub game_auto_config: crate::game_profile_auto::AutoProfileConfig,
+    /// Saved game launch profiles, listed when the Game Profiles tab opens
+    pub launch_profiles: Vec<String>,
+    pub dlss_preset_profile: String,
+    pub dlss_preset: crate::dlss::DlssRenderPreset,
+
+    // === Profile Bundles ===
+    /// Saved profile bundles, listed when the Settings tab opens
+    pub profile_bundles: Vec<String>,
+    pub selected_profile_bundle: String,
+    /// File a profile is exported to or imported from
+    pub profile_share_path: String,
+    /// Profile awaiting confirmation, with what applying it would change
+    pub pending_profile_apply: Option<(
+        crate::profile_manager::ProfileBundle,
+        Vec<crate::profile_manager::ChangeItem>,
+    )>,
+
+    // === Shader Cache ===
+    /// Sizes from the last scan; `None` until the Shaders tab opens
+    pub shader_summary: Option<crate::shaders::ShaderCacheSummary>,
+    /// Caches awaiting confirmation before they are cleared, with a label
+    pub pending_shader_clear: Option<(String, Vec<std::path::PathBuf>)>,
 
     // === Performance History (Ring Buffers) ===
     pub temp_history: VecDeque<f32>,
@@ -179,12 +374,33 @@ pub struct GuiState {
     pub asus_power_history: crate::asus_power_detector::PowerHistory,
     pub asus_power_last_update: std::time::Instant,
 
+    // === NVLink ===
+    /// Latest topology; `None` until probed and on systems without NVLink
+    pub nvlink: Option<crate::advanced_multi_gpu::NvLinkTopology>,
+    /// Set once a probe found no NVLink, so it isn't polled again
+    nvlink_absent: bool,
+    nvlink_last_update: Option<std::time::Instant>,
+    /// Combined active-link throughput in MB/s, one sample per refresh
+    pub nvlink_throughput: VecDeque<f32>,
+
+    // === Board Identity ===
+    /// VBIOS, part number and serial of the GPU they were read from; these
+    /// don't change, so they are only re-read when the selected GPU does
+    pub board_identity: Option<(u32, crate::gpu::BoardIdentity)>,
+
+    // === Quiet Mode ===
+    /// Whether quiet mode is on; re-read by the Power tab so CLI changes show up
+    pub quiet_mode: bool,
+
     // === Latency Settings ===
     pub latency_mode: String,
     pub reflex_enabled: bool,
 
     // === Gamescope ===
     pub gamescope_config: Option<crate::gamescope::GamescopeConfig>,
+    pub gamescope_launch_presets: Vec<crate::gamescope::LaunchPreset>,
+    pub gamescope_selected_preset: String,
+    pub gamescope_new_preset_name: String,
 
     // === Cached Fan Data (to avoid per-frame queries) ===
     pub cached_fans: Vec<FanInfo>,
@@ -195,6 +411,13 @@ pub struct GuiState {
     pub displays_last_update: std::time::Instant,
     pub cached_icc_profiles: Vec<String>,
     pub icc_profiles_last_update: std::time::Instant,
+    /// Connected displays with their modes, loaded when the Display tab opens
+    pub display_listing: Option<crate::display_info::DisplayListing>,
+    /// Save mode, rotation and scale changes in the connector profile as well
+    pub save_display_mode: bool,
+    /// Rotation and scale per connector, from connector profiles and changes made here
+    pub display_rotation: HashMap<String, crate::display::Orientation>,
+    pub display_scale: HashMap<String, f32>,
 
     // === Cached Recording Data (to avoid NVML init and file I/O per frame) ===
     pub cached_nvenc_caps: Option<crate::recording::NvencCapabilities>,
@@ -213,8 +436,14 @@ pub struct GuiState {
     // === Async Support Jobs ===
     pub support_job_running: bool,
     pub support_job_status: Option<String>,
+    /// Finished diagnostics report waiting to be put on the clipboard
+    pub diagnostics_report: Option<String>,
     support_job_rx: Receiver<SupportJobResult>,
     support_job_tx: Sender<SupportJobResult>,
+
+    // === Threshold alerts (config.alert_rules) ===
+    alert_evaluator: crate::monitoring::AlertEvaluator,
+    alert_notifier: Option<crate::notifications::NotificationManager>,
 }
 
 impl Default for GuiState {
@@ -227,6 +456,10 @@ impl Drop for GuiState {
     fn drop(&mut self) {
         // Signal background thread to stop
         self.shutdown_signal.store(true, Ordering::Relaxed);
+        // Hand the screen back at its base vibrance
+        if let Err(e) = crate::vibrance::set_load_reactive(false, 0, 0) {
+            log::warn!("failed to restore vibrance: {e}");
+        }
     }
 }
 
@@ -234,14 +467,44 @@ impl GuiState {
     /// Create new GUI state with defaults
     pub fn new() -> Self {
         let config = Config::load();
+        crate::gpu_safe::set_dry_run(config.dry_run);
         let app_state = AppState::load().ok();
 
         // Detect theme from config
         let theme =
             ThemeVariant::from_config_key(&config.theme).unwrap_or(ThemeVariant::TokyoNightMoon);
 
+        // Optional subsystems start isolated so one crashing probe doesn't take
+        // the whole app down; safe mode skips the risky ones entirely
+        let mut subsystems = crate::error_recovery::SubsystemReport::new();
+
         // Detect GPUs
-        let available_gpus = crate::multi_gpu::detect_gpus().unwrap_or_default();
+        let available_gpus = subsystems
+            .init("GPU detection", false, || {
+                crate::multi_gpu::detect_gpus(&config.gpu_labels).unwrap_or_default()
+            })
+            .unwrap_or_default();
+
+        let mobile_gpu = subsystems
+            .init("Laptop GPU detection", false, || {
+                crate::gpu::mobile_gpu_status(&crate::nvml_backend::create_real_backend(), 0)
+            })
+            .flatten();
+
+        let kernel_module = subsystems.init(
+            "Kernel module detection",
+            false,
+            crate::kernel_driver::module_type,
+        );
+
+        // Gamma ramps don't survive an X restart; put saved color settings back
+        if !subsystems.safe_mode && config.display_color.iter().any(|c| !c.is_neutral()) {
+            std::thread::spawn(|| {
+                for (index, e) in crate::display::reapply_saved_color() {
+                    eprintln!("Failed to restore color for display {index}: {e}");
+                }
+            });
+        }
 
         // Load vibrance from config
         let display_count = crate::display::get_display_count();
@@ -252,15 +515,17 @@ impl GuiState {
         };
 
         // Initialize ASUS Power Monitor+ if applicable
-        let asus_power_detector = {
-            let gpus = crate::asus_power_detector::detect_asus_gpus();
-            gpus.into_iter()
-                .find(|(_, model)| model.supports_power_detector())
-                .and_then(|(pci_id, _)| {
-                    crate::asus_power_detector::AsusPowerDetector::new(&pci_id).ok()
-                })
-                .filter(|d| d.is_supported())
-        };
+        let asus_power_detector = subsystems
+            .init("ASUS/RGB", true, || {
+                let gpus = crate::asus_power_detector::detect_asus_gpus();
+                gpus.into_iter()
+                    .find(|(_, model)| model.supports_power_detector())
+                    .and_then(|(pci_id, _)| {
+                        crate::asus_power_detector::AsusPowerDetector::new(&pci_id).ok()
+                    })
+                    .filter(|d| d.is_supported())
+            })
+            .flatten();
 
         // Create channel for async GPU stats
         let (tx, rx) = std::sync::mpsc::channel();
@@ -274,21 +539,61 @@ impl GuiState {
         let shutdown_signal = Arc::new(AtomicBool::new(false));
         let shutdown_for_thread = Arc::clone(&shutdown_signal);
 
+        // Monitors waking up or switching KVM inputs lose vibrance and modes
+        if config.hotplug_reapply && !subsystems.safe_mode {
+            let shutdown = Arc::clone(&shutdown_signal);
+            std::thread::spawn(move || {
+                let mut reapplier = crate::monitor_profiles::HotplugReapplier::new(
+                    crate::monitor_profiles::HOTPLUG_DEBOUNCE,
+                );
+                while !shutdown.load(Ordering::Relaxed) {
+                    reapplier.run_for(std::time::Duration::from_secs(1));
+                }
+            });
+        }
+
+        let event_log = crate::gui::event_log::EventLog::default();
+        let thread_events = event_log.clone();
+
+        let reactive = config.load_reactive_vibrance;
+        if reactive.enabled
+            && let Err(e) =
+                crate::vibrance::set_load_reactive(true, reactive.min_pct, reactive.max_pct)
+        {
+            event_log.warning(format!("Load-reactive vibrance unavailable: {}", e));
+        }
+
+        // Overclock watchdog runs on the monitoring thread unless opted out
+        let mut oc_watchdog = subsystems
+            .init("overclock", true, || {
+                crate::hardware_safety::OverclockWatchdog::from_config(&config)
+            })
+            .flatten();
+
         // Spawn background thread for GPU monitoring
         let tx_clone = tx.clone();
         std::thread::spawn(move || {
+            let mut throttle_log = crate::throttle_log::ThrottleLog::default();
+            let mut throttle_gpu = None;
             while !shutdown_for_thread.load(Ordering::Relaxed) {
                 // Read the currently selected GPU index atomically
                 let gpu_index = gpu_index_for_thread.load(Ordering::Relaxed);
+                let mut polled_temp = None;
+                if throttle_gpu != Some(gpu_index) {
+                    throttle_log = Default::default();
+                    throttle_gpu = Some(gpu_index);
+                }
 
-                if let Ok(nvml) = nvml_wrapper::Nvml::init() {
+                let nvml = nvml_wrapper::Nvml::init().ok();
+                if let Some(nvml) = &nvml {
                     if let Ok(device) = nvml.device_by_index(gpu_index) {
                         let name = device.name().unwrap_or_else(|_| "Unknown GPU".to_string());
-                        let temperature = device
+                        polled_temp = device
                             .temperature(
                                 nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu,
                             )
-                            .unwrap_or(0) as f32;
+                            .ok();
+                        let temperature = polled_temp.unwrap_or(0) as f32;
                         let power_draw = device
                             .power_usage()
                             .map(|p| p as f32 / 1000.0)
@@ -339,6 +644,20 @@ impl GuiState {
                             .unwrap_or_else(|| ("Unknown".to_string(), "N/A".to_string()));
 
                         let cuda_cores = device.num_cores().unwrap_or(0);
+                        let video_engines = crate::nvml_backend::video_engine_stats(&device).ok();
+                        let ecc_errors = crate::nvml_backend::ecc_error_counts(&device).ok();
+                        let pcie = crate::gpu::PcieStatus {
+                            index: gpu_index,
+                            link: crate::nvml_backend::pcie_link(&device).ok(),
+                            rebar: crate::gpu::read_bar1_size(&pci_bus).map(|bar1_bytes| {
+                                crate::gpu::RebarStatus {
+                                    bar1_bytes,
+                                    vram_bytes: memory_total,
+                                }
+                            }),
+                            gpu_utilization: utilization as u32,
+                            pci_bus_id: pci_bus.clone(),
+                        };
 
                         let stats = GpuStats {
                             name,
@@ -351,15 +670,59 @@ impl GuiState {
                             utilization,
                             fan_speed,
                             power_draw,
+                            chip_power: crate::nvml_backend::chip_power_usage(&device)
+                                .ok()
+                                .map(|mw| mw as f32 / 1000.0),
                             power_limit,
                             core_clock,
                             memory_clock,
                             pci_bus,
                             compute_capability,
+                            video_engines,
+                            ecc_errors,
+                            pcie,
+                            persistence_mode: device.is_in_persistent_mode().ok(),
+                            locked_clocks: crate::overclocking::LockedClocks::load(gpu_index),
                         };
 
                         let _ = tx_clone.send(stats);
+                        crate::vibrance::update_load_reactive(utilization as u32);
+
+                        if let Ok(reasons) = device.current_throttle_reasons() {
+                            let reasons = crate::nvml_backend::ThrottleReasons(reasons.bits());
+                            match throttle_log.record(Ok(reasons)) {
+                                Some(crate::throttle_log::ThrottleTransition::Started(_)) => {
+                                    thread_events.warning(format!(
+                                        "GPU {} throttling: {}",
+                                        gpu_index,
+                                        reasons.summary()
+                                    ))
+                                }
+                                Some(crate::throttle_log::ThrottleTransition::Ended) => {
+                                    thread_events
+                                        .info(format!("GPU {} stopped throttling", gpu_index))
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+
+                // Every overclocked GPU is watched, not just the one on screen
+                let read_temp = |gpu| {
+                    if gpu == gpu_index {
+                        return polled_temp;
                     }
+                    nvml.as_ref()?
+                        .device_by_index(gpu)
+                        .ok()?
+                        .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
+                        .ok()
+                };
+                if let Some(watchdog) = oc_watchdog.as_mut()
+                    && let Some(reason) = watchdog.poll(read_temp)
+                {
+                    thread_events.error(format!("Watchdog reset GPU to stock: {}", reason));
                 }
 
                 std::thread::sleep(std::time::Duration::from_millis(500));
@@ -367,7 +730,11 @@ impl GuiState {
         });
 
         // Initialize container runtime
-        let container_runtime = crate::container_runtime::NvContainerRuntime::new().ok();
+        let container_runtime = subsystems
+            .init("containers", true, || {
+                crate::container_runtime::NvContainerRuntime::new().ok()
+            })
+            .flatten();
 
         // Check for MangoHud
         let mangohud_installed = std::process::Command::new("which")
@@ -383,12 +750,36 @@ impl GuiState {
         // Load power config
         let power_config = crate::power_curves::load_power_config().unwrap_or_default();
 
+        let oc_history = crate::overclocking::OverclockHistory::load();
+
         // Load game auto config
         let game_auto_config = crate::game_profile_auto::AutoProfileConfig::default();
 
+        let vrr_displays = subsystems
+            .init("VRR", false, || {
+                crate::vrr::detect_vrr_displays().unwrap_or_default()
+            })
+            .unwrap_or_default();
+        let driver_capabilities = subsystems
+            .init("driver capabilities", true, || {
+                crate::drivers::DriverCapabilities::detect().ok()
+            })
+            .flatten();
+        // Fan control probes on demand from its tab, which stays closed in safe mode
+        if subsystems.safe_mode {
+            subsystems.skipped.push("fan control");
+        }
+
         // Save OSD settings before moving config
         let osd_enabled = config.osd_enabled;
-        let osd_position = config.osd_position.clone();
+        // An existing MangoHud.conf wins over the position saved in our config
+        let osd_config = crate::osd::MangoHudConfig::load().unwrap_or_else(|| {
+            let mut osd_config = crate::osd::MangoHudConfig::default();
+            if crate::osd::MANGOHUD_POSITIONS.contains(&config.osd_position.as_str()) {
+                osd_config.position = config.osd_position.clone();
+            }
+            osd_config
+        });
 
         Self {
             tab: Tab::Gpu,
@@ -396,6 +787,7 @@ impl GuiState {
             gpu_stats: None,
             available_gpus,
             selected_gpu_index: 0,
+            apply_to_all_gpus: false,
             selected_gpu_atomic,
             shutdown_signal,
             gpu_stats_rx: rx,
@@ -404,38 +796,90 @@ impl GuiState {
             ui_scale: 1.0,
             config,
             app_state,
-            toasts: ToastManager::new(),
+            subsystems,
+            toasts: ToastManager::new().with_event_log(event_log.clone()),
+            event_log,
+            event_log_problems_only: false,
             fan_curve: FanCurve::default(),
+            fan_curve_target: None,
             fan_mode: FanMode::Auto,
             fan_speeds: HashMap::new(),
             manual_fan_speed: 50,
+            oc_profile: oc_history
+                .last()
+                .map(|entry| entry.profile.clone())
+                .unwrap_or_default(),
+            oc_history,
+            oc_verification: Vec::new(),
+            benchmark_baselines: None,
+            benchmark_last_run: None,
+            selected_baseline: 0,
+            new_baseline_name: String::new(),
+            efficiency_rx: None,
+            efficiency_log: Vec::new(),
+            efficiency_profile_name: "efficiency".to_string(),
+            confirm_reset_all: false,
+            mini_mode: false,
+            graph_export: None,
+            reset_all_rx: None,
+            oc_scan_rx: None,
+            oc_scan_cancel: Arc::new(AtomicBool::new(false)),
+            oc_scan_log: Vec::new(),
+            last_oc_scan: None,
+            asus_boards: crate::asus_power_detector::detect_asus_gpus()
+                .into_iter()
+                .map(|(pci_id, model)| (pci_id, model.name().to_string()))
+                .collect(),
+            oc_staged: false,
+            staged_rx: None,
+            staged_cancel: Arc::new(AtomicBool::new(false)),
+            staged_log: Vec::new(),
+            stress_rx: None,
+            stress_cancel: Arc::new(AtomicBool::new(false)),
+            stress_minutes: 5,
+            last_stress_result: crate::overclocking::StressResult::load_last(),
+            soak_rx: None,
+            soak_cancel: Arc::new(AtomicBool::new(false)),
+            soak_load_minutes: 10,
+            soak_samples: Vec::new(),
+            last_soak: None,
             power_config,
+            power_limit_percent: 100.0,
+            powermizer_mode: None,
+            mobile_gpu,
             vibrance_levels,
+            kernel_module,
             selected_icc_profile_idx: 0,
             available_icc_profiles: Vec::new(),
             hdr_enabled,
             hdr_config,
             vrr_enabled: false,
-            vrr_displays: crate::vrr::detect_vrr_displays().unwrap_or_default(),
+            vrr_displays,
+            vrr_live: HashMap::new(),
+            vibrance_fade: None,
+            vrr_live_rx: None,
+            vrr_live_at: std::time::Instant::now(),
             recording_active: false,
             recording_path: String::new(),
             recording_settings: crate::recording::create_shadowplay_preset(),
             osd_enabled,
-            osd_position,
-            osd_metrics: vec![
-                "fps".to_string(),
-                "frametime".to_string(),
-                "cpu".to_string(),
-                "gpu".to_string(),
-            ],
-            osd_font_size: 24,
-            osd_opacity: 0.8,
+            osd_config,
             mangohud_installed,
             rgb_mode: "static".to_string(),
             rgb_color: [0.0, 1.0, 0.5], // Default green
             container_runtime,
             containers: Vec::new(),
+            containers_error: None,
             game_auto_config,
+            launch_profiles: Vec::new(),
+            dlss_preset_profile: String::new(),
+            dlss_preset: crate::dlss::DlssRenderPreset::Default,
+            profile_bundles: Vec::new(),
+            selected_profile_bundle: String::new(),
+            profile_share_path: String::new(),
+            pending_profile_apply: None,
+            shader_summary: None,
+            pending_shader_clear: None,
             temp_history: VecDeque::with_capacity(120),
             util_history: VecDeque::with_capacity(120),
             power_history: VecDeque::with_capacity(120),
@@ -443,14 +887,23 @@ impl GuiState {
             history_max_len: 120,
             monitoring_dashboard: MonitoringDashboard::new(120),
             driver_validation: crate::state::DriverValidationState::load(),
-            driver_capabilities: crate::drivers::DriverCapabilities::detect().ok(),
+            driver_capabilities,
             asus_power_detector,
             asus_power_status: None,
             asus_power_history: crate::asus_power_detector::PowerHistory::new(),
             asus_power_last_update: std::time::Instant::now(),
+            nvlink: None,
+            nvlink_absent: false,
+            nvlink_last_update: None,
+            nvlink_throughput: VecDeque::new(),
+            board_identity: None,
+            quiet_mode: crate::power::is_quiet_mode(),
             latency_mode: "normal".to_string(),
             reflex_enabled: false,
             gamescope_config: None,
+            gamescope_launch_presets: crate::gamescope::load_launch_presets().unwrap_or_default(),
+            gamescope_selected_preset: "handheld".to_string(),
+            gamescope_new_preset_name: String::new(),
             cached_fans: Vec::new(),
             fans_last_update: std::time::Instant::now()
                 .checked_sub(std::time::Duration::from_secs(10))
@@ -459,6 +912,10 @@ impl GuiState {
             displays_last_update: std::time::Instant::now()
                 .checked_sub(std::time::Duration::from_secs(60))
                 .unwrap_or_else(std::time::Instant::now), // Force initial refresh
+            display_listing: None,
+            save_display_mode: false,
+            display_rotation: HashMap::new(),
+            display_scale: HashMap::new(),
             cached_icc_profiles: Vec::new(),
             icc_profiles_last_update: std::time::Instant::now()
                 .checked_sub(std::time::Duration::from_secs(60))
@@ -483,8 +940,11 @@ impl GuiState {
                 .unwrap_or_else(std::time::Instant::now),
             support_job_running: false,
             support_job_status: None,
+            diagnostics_report: None,
             support_job_rx: support_rx,
             support_job_tx: support_tx,
+            alert_evaluator: Default::default(),
+            alert_notifier: None,
         }
     }
 
@@ -504,6 +964,8 @@ impl GuiState {
     pub fn set_selected_gpu(&mut self, index: u32) {
         self.selected_gpu_index = index;
         self.selected_gpu_atomic.store(index, Ordering::Relaxed);
+        self.mobile_gpu =
+            crate::gpu::mobile_gpu_status(&crate::nvml_backend::create_real_backend(), index);
         // Clear history when switching GPUs
         self.temp_history.clear();
         self.util_history.clear();
@@ -517,7 +979,7 @@ impl GuiState {
         self.config.vibrance_levels = self.vibrance_levels.clone();
         self.config.hdr_enabled = self.hdr_enabled;
         self.config.osd_enabled = self.osd_enabled;
-        self.config.osd_position = self.osd_position.clone();
+        self.config.osd_position = self.osd_config.position.clone();
         self.config.save();
         self.toasts.success("Configuration saved");
     }
@@ -559,11 +1021,48 @@ impl GuiState {
                 self.memory_history.push_back(mem_percent);
             }
 
+            self.check_alert_rules(&stats);
             self.gpu_stats = Some(stats);
             self.last_stats_update = std::time::Instant::now();
         }
     }
 
+    /// Evaluate the user's alert rules against the latest sample
+    fn check_alert_rules(&mut self, stats: &GpuStats) {
+        use crate::monitoring::AlertMetric;
+
+        if self.config.alert_rules.is_empty() {
+            return;
+        }
+        let firings = self.alert_evaluator.evaluate(
+            &self.config.alert_rules,
+            self.selected_gpu_index,
+            |metric| match metric {
+                AlertMetric::Temperature => Some(stats.temperature as f64),
+                AlertMetric::Power => Some(stats.power_draw as f64),
+                AlertMetric::Vram => (stats.memory_total > 0)
+                    .then(|| stats.memory_used as f64 / stats.memory_total as f64 * 100.0),
+            },
+        );
+        for firing in firings {
+            let rule = &self.config.alert_rules[firing.rule];
+            self.toasts.warning(format!(
+                "Alert: {} (now {:.1}{})",
+                rule.describe(),
+                firing.value,
+                rule.metric.unit()
+            ));
+            if self.alert_notifier.is_none() {
+                self.alert_notifier = crate::notifications::NotificationManager::new().ok();
+            }
+            if let Some(notifier) = &self.alert_notifier
+                && let Err(e) = notifier.notify_alert_rule(rule, &firing)
+            {
+                eprintln!("Alert notification failed: {e}");
+            }
+        }
+    }
+
     pub fn poll_support_jobs(&mut self) {
         while let Ok(result) = self.support_job_rx.try_recv() {
             self.support_job_running = false;
@@ -585,6 +1084,12 @@ impl GuiState {
                     self.toasts
                         .success(format!("Support bundle saved to {}", path));
                 }
+                SupportJobResult::DiagnosticsReport(report) => {
+                    self.support_job_status = Some("Diagnostics report copied".to_string());
+                    self.diagnostics_report = Some(report);
+                    self.toasts
+                        .success("Diagnostics report copied to clipboard");
+                }
                 SupportJobResult::Error(error) => {
                     self.support_job_status = Some(error.clone());
                     self.toasts.error(error);
@@ -606,6 +1111,21 @@ impl GuiState {
         });
     }
 
+    /// Build the markdown diagnostics report off the UI thread
+    pub fn spawn_diagnostics_report(&mut self) {
+        if self.support_job_running {
+            return;
+        }
+        self.support_job_running = true;
+        self.support_job_status = Some("Gathering diagnostics...".to_string());
+        let tx = self.support_job_tx.clone();
+        std::thread::spawn(move || {
+            let backend = crate::nvml_backend::create_real_backend();
+            let report = crate::report::build_report(backend.as_ref());
+            let _ = tx.send(SupportJobResult::DiagnosticsReport(report));
+        });
+    }
+
     pub fn spawn_support_bundle(&mut self, path: String) {
         if self.support_job_running {
             return;
@@ -649,6 +1169,54 @@ impl GuiState {
         }
     }
 
+    /// Read the selected GPU's board identity if it isn't cached yet
+    pub fn refresh_board_identity(&mut self) {
+        let gpu = self.selected_gpu_index;
+        if self
+            .board_identity
+            .as_ref()
+            .is_some_and(|(index, _)| *index == gpu)
+        {
+            return;
+        }
+        let backend = crate::nvml_backend::create_real_backend();
+        self.board_identity = Some((gpu, crate::gpu::BoardIdentity::query(&backend, gpu)));
+    }
+
+    /// Poll NVLink state and counters once a second while the GPU tab is
+    /// shown. Stops for good after a probe finds no NVLink.
+    pub fn refresh_nvlink(&mut self) {
+        const THROUGHPUT_SAMPLES: usize = 120;
+
+        if self.nvlink_absent
+            || self
+                .nvlink_last_update
+                .is_some_and(|t| t.elapsed() < std::time::Duration::from_secs(1))
+        {
+            return;
+        }
+        let now = std::time::Instant::now();
+        let topology = match crate::advanced_multi_gpu::get_nvlink_topology() {
+            Ok(topology) if topology.has_nvlink() => topology,
+            _ => {
+                self.nvlink_absent = true;
+                self.nvlink = None;
+                return;
+            }
+        };
+        if let (Some(previous), Some(last)) = (&self.nvlink, self.nvlink_last_update) {
+            let (rx, tx) =
+                topology.throughput_since(previous, now.duration_since(last).as_secs_f64());
+            if self.nvlink_throughput.len() >= THROUGHPUT_SAMPLES {
+                self.nvlink_throughput.pop_front();
+            }
+            self.nvlink_throughput
+                .push_back(((rx + tx) / 1_000_000.0) as f32);
+        }
+        self.nvlink = Some(topology);
+        self.nvlink_last_update = Some(now);
+    }
+
     /// Refresh cached fan data (rate-limited to avoid blocking main thread)
     pub fn refresh_fans(&mut self) {
         // Only refresh every 1 second to avoid hammering NVML
@@ -663,6 +1231,82 @@ impl GuiState {
         &self.cached_fans
     }
 
+    /// Open the export dialog for one monitoring graph
+    pub fn open_graph_export(&mut self, metric: GraphMetric) {
+        let dir = dirs::picture_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| std::path::PathBuf::from("."));
+        let file = format!(
+            "nvcontrol-{}-{}.png",
+            metric.file_stem(),
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        );
+        self.graph_export = Some(GraphExportDialog {
+            metric,
+            path: dir.join(file).display().to_string(),
+        });
+    }
+
+    /// Current dashboard series for `metric`, titled with the GPU name and
+    /// the wall-clock range it covers
+    pub fn export_graph(&self, metric: GraphMetric) -> crate::graph_export::Graph {
+        let dashboard = &self.monitoring_dashboard;
+        let points = metric.series(dashboard).get_points();
+
+        let now = chrono::Local::now();
+        let elapsed = dashboard.start_time.elapsed().as_secs_f64();
+        let at =
+            |t: f64| now - chrono::Duration::milliseconds(((elapsed - t).max(0.0) * 1000.0) as i64);
+        let first = points.first().map(|p| at(p[0])).unwrap_or(now);
+        let last = points.last().map(|p| at(p[0])).unwrap_or(now);
+        let gpu_name = self
+            .gpu_stats
+            .as_ref()
+            .map(|stats| stats.name.as_str())
+            .unwrap_or("GPU");
+
+        let colors = self.theme_colors();
+        let color = match metric {
+            GraphMetric::Temperature => colors.red,
+            GraphMetric::Utilization => colors.green,
+            GraphMetric::Power => colors.cyan,
+        }
+        .to_egui();
+
+        crate::graph_export::Graph {
+            title: crate::graph_export::graph_title(gpu_name, metric.name(), first, last),
+            y_label: metric.axis_label().to_string(),
+            color: [color.r(), color.g(), color.b()],
+            points,
+        }
+    }
+
+    /// Ids of the selected GPU's fans in the cached list (GPU * 10 + fan)
+    pub fn selected_gpu_fan_ids(&self) -> Vec<usize> {
+        self.cached_fans
+            .iter()
+            .map(|fan| fan.id)
+            .filter(|id| id / 10 == self.selected_gpu_index as usize)
+            .collect()
+    }
+
+    /// Point the curve editor at one fan, or the shared curve with `None`,
+    /// and load the curve saved for it
+    pub fn set_fan_curve_target(&mut self, target: Option<usize>) {
+        self.fan_curve_target = target;
+        let active = crate::fan_daemon::ActiveFanCurves::load(
+            &crate::fan_daemon::ActiveFanCurves::default_path(),
+        )
+        .unwrap_or_default();
+        let saved = match target {
+            Some(fan_id) => active.curve_for(fan_id),
+            None => active.shared.as_ref(),
+        };
+        if let Some(curve) = saved {
+            self.fan_curve = FanCurve::from_fan_curve(curve);
+        }
+    }
+
     /// Refresh cached display data (rate-limited to avoid subprocess spawns)
     pub fn refresh_displays(&mut self) {
         // Only refresh every 5 seconds - displays rarely change
@@ -677,6 +1321,122 @@ impl GuiState {
         &self.cached_displays
     }
 
+    /// Re-read connected displays and their modes
+    pub fn refresh_display_listing(&mut self) {
+        self.display_listing = Some(crate::display_info::list_connected_displays());
+        if let Ok(profiles) = crate::monitor_profiles::ConnectorProfileMap::load() {
+            for (key, profile) in profiles.profiles {
+                if let Some(rotation) = profile.rotation {
+                    self.display_rotation.entry(key.clone()).or_insert(rotation);
+                }
+                if let Some(scale) = profile.scale {
+                    self.display_scale.entry(key).or_insert(scale);
+                }
+            }
+        }
+    }
+
+    /// Switch a display's mode, optionally saving it in its connector profile
+    pub fn apply_display_mode(
+        &mut self,
+        connector: &str,
+        mode: crate::display_info::CurrentMode,
+        save: bool,
+    ) {
+        let applied = match crate::display::set_mode(
+            connector,
+            mode.width,
+            mode.height,
+            Some(mode.refresh_hz),
+        ) {
+            Ok(applied) => applied,
+            Err(e) => {
+                self.toasts
+                    .error(format!("Failed to set mode on {}: {}", connector, e));
+                return;
+            }
+        };
+        self.toasts.success(format!(
+            "{} set to {}x{} @ {:.0}Hz",
+            connector, applied.width, applied.height, applied.refresh_hz
+        ));
+
+        if save {
+            let result =
+                crate::monitor_profiles::ConnectorProfileMap::load().and_then(|mut profiles| {
+                    profiles.set_mode(connector, &applied);
+                    profiles.save()
+                });
+            if let Err(e) = result {
+                self.toasts
+                    .error(format!("Failed to save connector profile: {}", e));
+            }
+        }
+        self.refresh_display_listing();
+    }
+
+    /// Rotate a display, optionally saving it in its connector profile
+    pub fn apply_display_rotation(
+        &mut self,
+        connector: &str,
+        orientation: crate::display::Orientation,
+        save: bool,
+    ) {
+        if let Err(e) = crate::display::set_rotation(connector, orientation) {
+            self.toasts
+                .error(format!("Failed to rotate {}: {}", connector, e));
+            return;
+        }
+        self.toasts
+            .success(format!("{} rotated {}", connector, orientation));
+        self.display_rotation
+            .insert(connector.to_string(), orientation);
+
+        if save {
+            let result =
+                crate::monitor_profiles::ConnectorProfileMap::load().and_then(|mut profiles| {
+                    profiles.set_rotation(connector, orientation);
+                    profiles.save()
+                });
+            if let Err(e) = result {
+                self.toasts
+                    .error(format!("Failed to save connector profile: {}", e));
+            }
+        }
+        self.refresh_display_listing();
+    }
+
+    /// Scale a display, optionally saving it in its connector profile.
+    /// Caveats for the chosen scale (blurry fractional scaling) show as warnings.
+    pub fn apply_display_scale(&mut self, connector: &str, factor: f32, save: bool) {
+        let warnings = match crate::display::set_scale(connector, factor) {
+            Ok(warnings) => warnings,
+            Err(e) => {
+                self.toasts
+                    .error(format!("Failed to scale {}: {}", connector, e));
+                return;
+            }
+        };
+        self.toasts
+            .success(format!("{} scaled to {}x", connector, factor));
+        for warning in warnings {
+            self.toasts.warning(warning);
+        }
+        self.display_scale.insert(connector.to_string(), factor);
+
+        if save {
+            let result =
+                crate::monitor_profiles::ConnectorProfileMap::load().and_then(|mut profiles| {
+                    profiles.set_scale(connector, factor);
+                    profiles.save()
+                });
+            if let Err(e) = result {
+                self.toasts
+                    .error(format!("Failed to save connector profile: {}", e));
+            }
+        }
+    }
+
     /// Refresh cached ICC profiles (rate-limited to avoid filesystem scans)
     pub fn refresh_icc_profiles(&mut self) {
         // Only refresh every 30 seconds - ICC profiles rarely change
@@ -767,6 +1527,10 @@ impl GuiState {
                 if let Err(e) = crate::fan::reset_fan_to_auto(0) {
                     self.toasts.error(format!("Failed to set auto fan: {}", e));
                 } else {
+                    let _ = crate::fan_daemon::clear_curve(
+                        &crate::nvml_backend::create_real_backend(),
+                        0,
+                    );
                     self.toasts.success("Fan set to automatic control");
                 }
             }
@@ -781,22 +1545,172 @@ impl GuiState {
                     .iter()
                     .map(|(t, s)| (*t as u8, *s as u8))
                     .collect();
-                if let Err(e) = crate::fan::set_fan_curve(0, &points) {
-                    self.toasts
-                        .error(format!("Failed to apply fan curve: {}", e));
+                if self.apply_to_all_gpus {
+                    let mut curve = crate::fan_daemon::curve_from_points("GUI", &points);
+                    curve.zero_rpm_below = self.fan_curve.zero_rpm_below;
+                    let backend = crate::nvml_backend::create_real_backend();
+                    let report = crate::multi_gpu::apply_fan_curve_all(&backend, &curve);
+                    self.report_batch_apply("Fan curve", report);
                 } else {
-                    self.toasts.success("Custom fan curve applied");
+                    self.apply_fan_curve_to_target(&points);
                 }
             }
         }
     }
 
+    /// Apply the editor curve to the targeted fan, or as the shared curve
+    /// to every fan of the selected GPU without its own curve
+    fn apply_fan_curve_to_target(&mut self, points: &[(u8, u8)]) {
+        let mut curve = crate::fan_daemon::curve_from_points("GUI", points);
+        curve.zero_rpm_below = self.fan_curve.zero_rpm_below;
+
+        let fans = match self.fan_curve_target {
+            Some(fan_id) => vec![fan_id],
+            None => {
+                let active = crate::fan_daemon::ActiveFanCurves::load(
+                    &crate::fan_daemon::ActiveFanCurves::default_path(),
+                )
+                .unwrap_or_default();
+                let mut fans = self.selected_gpu_fan_ids();
+                if fans.is_empty() {
+                    fans.push(self.selected_gpu_index as usize * 10);
+                }
+                fans.retain(|fan_id| !active.curves.contains_key(fan_id));
+                fans
+            }
+        };
+
+        for fan_id in &fans {
+            if let Err(e) = crate::fan::set_fan_curve(*fan_id, points) {
+                self.toasts.error(format!(
+                    "Failed to apply fan curve to fan {}: {}",
+                    fan_id, e
+                ));
+                return;
+            }
+        }
+
+        // Saved so `nvctl fan daemon` keeps it applied after the GUI closes
+        let saved = match self.fan_curve_target {
+            Some(fan_id) => crate::fan_daemon::persist_curve(fan_id, &curve),
+            None => crate::fan_daemon::persist_shared_curve(&curve),
+        };
+        if let Err(e) = saved {
+            self.toasts.warning(format!("Fan curve not saved: {}", e));
+        }
+        match self.fan_curve_target {
+            Some(fan_id) => self
+                .toasts
+                .success(format!("Fan curve applied to fan {}", fan_id)),
+            None => self.toasts.success("Custom fan curve applied"),
+        }
+    }
+
+    /// Set the Power tab's limit on the selected GPU, or every GPU
+    pub fn apply_power_limit(&mut self) {
+        let backend = crate::nvml_backend::create_real_backend();
+        let target = crate::power::PowerLimitTarget::Percent(self.power_limit_percent);
+        if self.apply_to_all_gpus {
+            let report = crate::multi_gpu::set_power_limit_all(&backend, target);
+            if let Ok(report) = &report
+                && report.applied() > 0
+            {
+                let _ = crate::power_curves::record_manual_override();
+                crate::state::LastApplied::record_power_limits(&backend, report);
+            }
+            self.report_batch_apply("Power limit", report);
+            return;
+        }
+        match crate::power::set_power_limit_percent(
+            &backend,
+            self.selected_gpu_index,
+            self.power_limit_percent,
+        ) {
+            Ok(applied) => {
+                let _ = crate::power_curves::record_manual_override();
+                crate::state::LastApplied::record_power_limit(
+                    self.selected_gpu_index,
+                    applied.applied_w,
+                );
+                self.toasts
+                    .success(format!("Power limit set to {:.0} W", applied.applied_w));
+            }
+            Err(e) => self
+                .toasts
+                .error(format!("Failed to set power limit: {}", e)),
+        }
+    }
+
+    /// Turn quiet mode on or off for every GPU
+    pub fn set_quiet_mode(&mut self, enabled: bool) {
+        let backend = crate::nvml_backend::create_real_backend();
+        match crate::power::set_quiet_mode(&backend, enabled) {
+            Ok(warnings) => {
+                self.quiet_mode = crate::power::is_quiet_mode();
+                let label = if enabled {
+                    "Quiet mode on"
+                } else {
+                    "Quiet mode off, previous settings restored"
+                };
+                if warnings.is_empty() {
+                    self.toasts.success(label);
+                } else {
+                    self.toasts
+                        .warning(format!("{}: {}", label, warnings.join("; ")));
+                }
+            }
+            Err(e) => self
+                .toasts
+                .error(format!("Failed to change quiet mode: {}", e)),
+        }
+    }
+
+    pub fn refresh_powermizer_mode(&mut self) {
+        self.powermizer_mode = Some(
+            crate::power::get_powermizer_mode(self.selected_gpu_index).map_err(|e| e.to_string()),
+        );
+    }
+
+    pub fn apply_powermizer_mode(&mut self, mode: crate::power::PowerMizerMode) {
+        match crate::power::set_powermizer_mode(self.selected_gpu_index, mode) {
+            Ok(()) => {
+                self.toasts
+                    .success(format!("PowerMizer set to {}", mode.label()));
+                self.powermizer_mode = Some(Ok(mode));
+            }
+            Err(e) => self
+                .toasts
+                .error(format!("Failed to set PowerMizer mode: {}", e)),
+        }
+    }
+
+    /// Toast the outcome of an "Apply to all GPUs" action
+    fn report_batch_apply(
+        &mut self,
+        what: &str,
+        report: crate::NvResult<crate::multi_gpu::BatchApplyReport>,
+    ) {
+        match report {
+            Ok(report) if report.failed() > 0 || report.applied() == 0 => {
+                self.toasts
+                    .warning(format!("{}: {}", what, report.summary()));
+            }
+            Ok(report) => self
+                .toasts
+                .success(format!("{}: {}", what, report.summary())),
+            Err(e) => self.toasts.error(format!("{} failed: {}", what, e)),
+        }
+    }
+
     /// Set manual fan speed
     pub fn set_manual_fan_speed(&mut self, speed: u32) {
         self.manual_fan_speed = speed.clamp(0, 100);
         if self.fan_mode == FanMode::Manual {
-            if let Err(e) = crate::fan::set_fan_speed(0, self.manual_fan_speed as u8) {
-                self.toasts.error(format!("Failed to set fan speed: {}", e));
+            match crate::fan::set_fan_speed(0, self.manual_fan_speed as u8) {
+                Ok(()) => {
+                    crate::state::LastApplied::record_fan_speed(0, self.manual_fan_speed as u8)
+                }
+                Err(e) => self.toasts.error(format!("Failed to set fan speed: {}", e)),
             }
         }
     }
@@ -818,6 +1732,58 @@ impl GuiState {
         self.vrr_enabled = self.vrr_displays.iter().any(|d| d.current_settings.enabled);
     }
 
+    /// Sample each VRR display's refresh rate about once a second. Call
+    /// every frame while the VRR tab is visible.
+    pub fn poll_vrr_live(&mut self) {
+        if let Some(rx) = &self.vrr_live_rx {
+            match rx.try_recv() {
+                Ok(readings) => {
+                    self.vrr_live = readings.into_iter().collect();
+                    self.vrr_live_rx = None;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => return,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => self.vrr_live_rx = None,
+            }
+        }
+
+        if self.vrr_live_at.elapsed() < std::time::Duration::from_secs(1) {
+            return;
+        }
+        self.vrr_live_at = std::time::Instant::now();
+        let names: Vec<String> = self
+            .vrr_displays
+            .iter()
+            .filter(|d| d.supports_vrr)
+            .map(|d| d.display_name.clone())
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.vrr_live_rx = Some(rx);
+        std::thread::spawn(move || {
+            let readings = names
+                .into_iter()
+                .map(|name| {
+                    let reading = crate::vrr::get_current_refresh(&name).map_err(|e| e.to_string());
+                    (name, reading)
+                })
+                .collect();
+            let _ = tx.send(readings);
+        });
+    }
+
+    /// Report a finished vibrance fade that failed
+    pub fn poll_vibrance_fade(&mut self) {
+        if !self.vibrance_fade.as_ref().is_some_and(|f| f.is_finished()) {
+            return;
+        }
+        if let Some(Err(e)) = self.vibrance_fade.take().map(|f| f.join()) {
+            self.toasts.error(format!("Failed to set vibrance: {}", e));
+        }
+    }
+
     /// Apply VRR settings to a display
     pub fn apply_vrr_to_display(&mut self, display_name: &str, enabled: bool) {
         let settings = crate::vrr::VrrSettings {
@@ -866,6 +1832,717 @@ impl GuiState {
         }
     }
 
+    /// Apply the overclock profile currently being edited
+    /// Load `preset`, scaled to the selected card, into the offset editors
+    /// and apply it. The power limit slider is left as is.
+    pub fn apply_oc_preset(&mut self, preset: crate::gpu_profiles::OcPreset) {
+        let gpu_name = self
+            .gpu_stats
+            .as_ref()
+            .map(|stats| stats.name.as_str())
+            .unwrap_or_default();
+        let offsets = crate::gpu_profiles::preset_offsets(gpu_name, preset);
+        self.oc_profile.gpu_clock_offset = offsets.gpu_offset;
+        self.oc_profile.memory_clock_offset = offsets.memory_offset;
+        self.apply_overclock();
+    }
+
+    pub fn apply_overclock(&mut self) {
+        self.oc_profile.name = "GUI Applied".to_string();
+        self.oc_verification.clear();
+        if self.apply_to_all_gpus {
+            let backend = crate::nvml_backend::create_real_backend();
+            let offsets = crate::multi_gpu::apply_overclock_all(&backend, &self.oc_profile);
+            let power = crate::multi_gpu::set_power_limit_all(
+                &backend,
+                crate::power::PowerLimitTarget::Percent(self.oc_profile.power_limit as f32),
+            );
+            if let Ok(report) = &power {
+                crate::state::LastApplied::record_power_limits(&backend, report);
+            }
+            self.report_batch_apply("Overclock", offsets);
+            self.report_batch_apply("Power limit", power);
+            self.oc_history = crate::overclocking::OverclockHistory::load();
+            return;
+        }
+        if self.oc_staged {
+            self.start_staged_apply();
+            return;
+        }
+        let gpu_index = self.selected_gpu_index;
+        match crate::overclocking::apply_overclock_profile_for_gpu(&self.oc_profile, gpu_index) {
+            Ok(()) => {
+                // Laptop firmware owns the power budget; only the offsets apply
+                let power = if self.mobile_gpu.is_some() {
+                    Ok(())
+                } else {
+                    crate::power::set_power_limit_percent(
+                        &crate::nvml_backend::create_real_backend(),
+                        gpu_index,
+                        self.oc_profile.power_limit as f32,
+                    )
+                    .map(|applied| {
+                        crate::state::LastApplied::record_power_limit(gpu_index, applied.applied_w)
+                    })
+                };
+                if let Err(e) = power {
+                    self.toasts
+                        .error(format!("Offsets applied, power limit failed: {}", e));
+                } else {
+                    let _ = crate::power_curves::record_manual_override();
+                    self.toasts.success(format!(
+                        "Overclock applied: {:+}MHz core, {:+}MHz memory",
+                        self.oc_profile.gpu_clock_offset, self.oc_profile.memory_clock_offset
+                    ));
+                }
+                self.oc_verification =
+                    crate::overclocking::read_applied_state(gpu_index).verify(&self.oc_profile);
+                if self
+                    .oc_verification
+                    .iter()
+                    .any(|check| check.applied() == Some(false))
+                {
+                    self.toasts.warning(
+                        "Some settings did not stick - the driver may need root or Coolbits",
+                    );
+                }
+            }
+            Err(e) => {
+                self.toasts
+                    .error(format!("Failed to apply overclock: {}", e));
+            }
+        }
+        self.oc_history = crate::overclocking::OverclockHistory::load();
+    }
+
+    /// Diff the selected profile bundle against the live state for confirmation
+    pub fn review_profile_apply(&mut self) {
+        use crate::profile_manager::{self, ProfileManager};
+
+        let result = ProfileManager::new()
+            .and_then(|manager| manager.load_profile_by_name(&self.selected_profile_bundle))
+            .and_then(|target| {
+                let current = ProfileManager::capture_live_bundle("live", self.selected_gpu_index)?;
+                let changes = profile_manager::diff(&current, &target);
+                Ok((target, changes))
+            });
+        match result {
+            Ok((target, changes)) if changes.is_empty() => {
+                self.toasts.info(format!(
+                    "Profile '{}' already matches the current state",
+                    target.name
+                ));
+            }
+            Ok(pending) => self.pending_profile_apply = Some(pending),
+            Err(e) => self.toasts.error(format!("Failed to load profile: {}", e)),
+        }
+    }
+
+    /// Write the selected profile to `profile_share_path` for sharing
+    pub fn export_profile_bundle(&mut self) {
+        let path = expand_home(self.profile_share_path.trim());
+        match crate::profile_manager::export(&self.selected_profile_bundle, &path) {
+            Ok(_) => self.toasts.success(format!(
+                "Exported '{}' to {}",
+                self.selected_profile_bundle,
+                path.display()
+            )),
+            Err(e) => self
+                .toasts
+                .error(format!("Failed to export profile: {}", e)),
+        }
+    }
+
+    /// Import the profile file at `profile_share_path` and select it
+    pub fn import_profile_bundle(&mut self) {
+        let path = expand_home(self.profile_share_path.trim());
+        let imported = match crate::profile_manager::import(&path, false) {
+            Ok(imported) => imported,
+            Err(e) => {
+                self.toasts
+                    .error(format!("Failed to import profile: {}", e));
+                return;
+            }
+        };
+        self.toasts
+            .success(format!("Imported profile '{}'", imported.bundle.name));
+        for warning in imported.warnings {
+            self.toasts.warning(warning);
+        }
+        if !self.profile_bundles.contains(&imported.bundle.name) {
+            self.profile_bundles.push(imported.bundle.name.clone());
+        }
+        self.selected_profile_bundle = imported.bundle.name;
+    }
+
+    /// Apply the reviewed profile, touching only the fields that differ
+    pub fn confirm_profile_apply(&mut self) {
+        let Some((target, changes)) = self.pending_profile_apply.take() else {
+            return;
+        };
+        match crate::profile_manager::ProfileManager::apply_changes(
+            &target,
+            &changes,
+            self.selected_gpu_index,
+        ) {
+            Ok(applied) => {
+                self.toasts.success(format!(
+                    "Applied profile '{}' ({} changes)",
+                    target.name,
+                    applied.len()
+                ));
+            }
+            Err(e) => {
+                self.toasts
+                    .error(format!("Failed to apply profile '{}': {}", target.name, e));
+            }
+        }
+        self.oc_history = crate::overclocking::OverclockHistory::load();
+    }
+
+    /// Rescan shader cache sizes
+    pub fn refresh_shader_summary(&mut self) {
+        self.shader_summary = Some(crate::shaders::get_cache_summary());
+    }
+
+    /// Clear the caches confirmed in the Shaders tab dialog
+    pub fn confirm_shader_clear(&mut self) {
+        let Some((label, paths)) = self.pending_shader_clear.take() else {
+            return;
+        };
+        let mut freed = 0.0;
+        let mut skipped = 0;
+        let mut errors = Vec::new();
+        for path in &paths {
+            match crate::shaders::clear_cache(path) {
+                Ok(report) => {
+                    freed += report.freed_mb();
+                    skipped += report.skipped.len();
+                }
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+
+        if let Some(error) = errors.first() {
+            self.toasts
+                .error(format!("Failed to clear {}: {}", label, error));
+        } else if skipped > 0 {
+            self.toasts.warning(format!(
+                "Cleared {} ({:.1} MB), {} entries skipped for lack of permission",
+                label, freed, skipped
+            ));
+        } else {
+            self.toasts
+                .success(format!("Cleared {} ({:.1} MB)", label, freed));
+        }
+        self.refresh_shader_summary();
+    }
+
+    /// Reload saved baselines and the latest benchmark run
+    pub fn refresh_baselines(&mut self) {
+        self.benchmark_baselines = Some(crate::benchmark::list_baselines());
+        self.benchmark_last_run = crate::benchmark::BenchmarkSuite::new()
+            .ok()
+            .and_then(|suite| suite.last_run());
+    }
+
+    /// Save the latest benchmark run under `new_baseline_name`
+    pub fn save_last_run_as_baseline(&mut self) {
+        let Some(run) = &self.benchmark_last_run else {
+            return;
+        };
+        match crate::benchmark::save_baseline(&self.new_baseline_name, run) {
+            Ok(baseline) => {
+                self.toasts
+                    .success(format!("Saved baseline '{}'", baseline.name));
+                self.new_baseline_name.clear();
+                self.refresh_baselines();
+                self.selected_baseline = 0;
+            }
+            Err(e) => self.toasts.error(format!("Failed to save baseline: {}", e)),
+        }
+    }
+
+    /// Revert to the previously applied overclock (stock if none)
+    pub fn revert_overclock(&mut self) {
+        match crate::overclocking::revert_last() {
+            Ok((gpu, profile)) => {
+                self.toasts
+                    .success(format!("GPU {}: reverted to {}", gpu, profile.name));
+                self.oc_profile = profile;
+            }
+            Err(e) => {
+                self.toasts
+                    .error(format!("Failed to revert overclock: {}", e));
+            }
+        }
+        self.oc_history = crate::overclocking::OverclockHistory::load();
+    }
+
+    /// Apply the overclock in steps on a background thread
+    fn start_staged_apply(&mut self) {
+        if self.staged_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let profile = self.oc_profile.clone();
+        let gpu_index = self.selected_gpu_index;
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.staged_cancel = cancel.clone();
+        self.staged_rx = Some(rx);
+        self.staged_log.clear();
+
+        std::thread::spawn(move || {
+            let backend = crate::nvml_backend::create_real_backend();
+            let result = crate::overclocking::apply_staged(
+                &backend,
+                gpu_index,
+                &profile,
+                crate::overclocking::DEFAULT_STAGED_STEP_MHZ,
+                crate::overclocking::DEFAULT_PROBE_DURATION,
+                &cancel,
+                |step| {
+                    let _ = tx.send(StagedApplyUpdate::Step(step.clone()));
+                },
+            )
+            .map_err(|e| e.to_string());
+            let _ = tx.send(StagedApplyUpdate::Finished(result));
+        });
+    }
+
+    /// Ask a running staged apply to stop at its last stable step
+    pub fn cancel_staged_apply(&mut self) {
+        self.staged_cancel.store(true, Ordering::SeqCst);
+    }
+
+    /// Drain progress from a running staged apply
+    pub fn poll_staged_apply(&mut self) {
+        let Some(rx) = &self.staged_rx else {
+            return;
+        };
+
+        let mut finished = None;
+        while let Ok(update) = rx.try_recv() {
+            use crate::overclocking::StagedStep;
+            match update {
+                StagedApplyUpdate::Step(step) => {
+                    self.staged_log.push(match step {
+                        StagedStep::Applying { offset_mhz } => {
+                            format!("Probing {:+} MHz", offset_mhz)
+                        }
+                        StagedStep::Stable { offset_mhz } => {
+                            format!("Stable at {:+} MHz", offset_mhz)
+                        }
+                        StagedStep::Unstable { offset_mhz, reason } => {
+                            format!("Unstable at {:+} MHz: {}", offset_mhz, reason)
+                        }
+                    });
+                }
+                StagedApplyUpdate::Finished(result) => finished = Some(result),
+            }
+        }
+
+        match finished {
+            Some(Ok(result)) => {
+                self.oc_profile.gpu_clock_offset = result.achieved_offset;
+                if result.reached_target() {
+                    let _ = crate::power_curves::record_manual_override();
+                    self.toasts.success(format!(
+                        "Overclock applied: {:+}MHz core, {:+}MHz memory",
+                        result.achieved_offset, self.oc_profile.memory_clock_offset
+                    ));
+                } else {
+                    let _ = crate::power_curves::record_manual_override();
+                    self.toasts.info(format!(
+                        "Stopped at {:+}MHz of {:+}MHz target ({})",
+                        result.achieved_offset,
+                        result.target_offset,
+                        result.stop_reason.as_deref().unwrap_or("unknown")
+                    ));
+                }
+                self.staged_rx = None;
+                self.oc_history = crate::overclocking::OverclockHistory::load();
+            }
+            Some(Err(e)) => {
+                self.toasts.error(format!("Staged apply failed: {}", e));
+                self.staged_log.push(format!("Failed: {}", e));
+                self.staged_rx = None;
+            }
+            None => {}
+        }
+    }
+
+    /// ASUS board model of the selected GPU, if it is one
+    pub fn selected_asus_board(&self) -> Option<&str> {
+        let pci_bus = &self.gpu_stats.as_ref()?.pci_bus;
+        self.asus_boards
+            .iter()
+            .find(|(pci_id, _)| crate::asus_gpu_tweak::same_pci_device(pci_id, pci_bus))
+            .map(|(_, model)| model.as_str())
+    }
+
+    /// Run the OC scanner on the selected GPU on a background thread
+    pub fn start_oc_scan(&mut self) {
+        if self.oc_scan_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let gpu_index = self.selected_gpu_index;
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.oc_scan_cancel = cancel.clone();
+        self.oc_scan_rx = Some(rx);
+        self.oc_scan_log.clear();
+
+        std::thread::spawn(move || {
+            let backend = crate::nvml_backend::create_real_backend();
+            let result =
+                crate::asus_gpu_tweak::run_oc_scanner(&backend, gpu_index, &cancel, |progress| {
+                    let _ = tx.send(OcScanUpdate::Progress(progress));
+                })
+                .map_err(|e| e.to_string());
+            let _ = tx.send(OcScanUpdate::Finished(result));
+        });
+    }
+
+    /// Ask a running OC scan to stop at its last stable step
+    pub fn cancel_oc_scan(&mut self) {
+        self.oc_scan_cancel.store(true, Ordering::SeqCst);
+    }
+
+    /// Drain progress from a running OC scan and pick up its result
+    pub fn poll_oc_scan(&mut self) {
+        let Some(rx) = &self.oc_scan_rx else {
+            return;
+        };
+
+        let mut finished = None;
+        while let Ok(update) = rx.try_recv() {
+            use crate::asus_gpu_tweak::OcScanProgress;
+            use crate::overclocking::StagedStep;
+            match update {
+                OcScanUpdate::Progress(progress) => self.oc_scan_log.push(match progress {
+                    OcScanProgress::PowerLimit {
+                        limit_w,
+                        default_w,
+                        unlocked,
+                    } => {
+                        if unlocked {
+                            format!(
+                                "Power limit raised to {} W (default {} W)",
+                                limit_w, default_w
+                            )
+                        } else {
+                            format!("Scanning at the default {} W limit", default_w)
+                        }
+                    }
+                    OcScanProgress::ConnectorWarning => {
+                        "Power Detector+ reports connector warnings".to_string()
+                    }
+                    OcScanProgress::Step(StagedStep::Applying { offset_mhz }) => {
+                        format!("Probing {:+} MHz", offset_mhz)
+                    }
+                    OcScanProgress::Step(StagedStep::Stable { offset_mhz }) => {
+                        format!("Stable at {:+} MHz", offset_mhz)
+                    }
+                    OcScanProgress::Step(StagedStep::Unstable { offset_mhz, reason }) => {
+                        format!("Unstable at {:+} MHz: {}", offset_mhz, reason)
+                    }
+                }),
+                OcScanUpdate::Finished(result) => finished = Some(result),
+            }
+        }
+
+        match finished {
+            Some(Ok(scan)) => {
+                if let Some(profile) = &scan.profile_name {
+                    self.toasts.success(format!(
+                        "OC scan found {:+} MHz core, saved as '{}'",
+                        scan.sweep.achieved_offset, profile
+                    ));
+                } else {
+                    self.toasts.warning(format!(
+                        "OC scan found no stable overclock ({})",
+                        scan.sweep.stop_reason.as_deref().unwrap_or("unknown")
+                    ));
+                }
+                self.oc_history = crate::overclocking::OverclockHistory::load();
+                self.last_oc_scan = Some(scan);
+            }
+            Some(Err(e)) => {
+                self.toasts.error(format!("OC scan failed: {}", e));
+                self.oc_scan_log.push(format!("Failed: {}", e));
+            }
+            None => return,
+        }
+        self.oc_scan_rx = None;
+    }
+
+    /// Reset everything nvcontrol applied to stock on a background thread
+    pub fn start_reset_all(&mut self) {
+        self.confirm_reset_all = false;
+        if self.reset_all_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.reset_all_rx = Some(rx);
+        std::thread::spawn(move || {
+            let backend = crate::nvml_backend::create_real_backend();
+            let _ = tx.send(crate::state::reset_all_to_defaults(&backend));
+        });
+    }
+
+    /// Summarize a finished Reset All
+    pub fn poll_reset_all(&mut self) {
+        let Some(rx) = &self.reset_all_rx else {
+            return;
+        };
+        let Ok(outcomes) = rx.try_recv() else {
+            return;
+        };
+        self.reset_all_rx = None;
+
+        let failed: Vec<String> = outcomes
+            .iter()
+            .filter_map(|outcome| match &outcome.status {
+                crate::state::ApplyStatus::Failed(e) => {
+                    Some(format!("{}: {}", outcome.subsystem, e))
+                }
+                _ => None,
+            })
+            .collect();
+        if outcomes.iter().any(|o| o.is_critical_failure()) {
+            self.toasts
+                .error(format!("Reset incomplete: {}", failed.join("; ")));
+        } else if !failed.is_empty() {
+            self.toasts
+                .warning(format!("Reset to stock, except {}", failed.join("; ")));
+        } else {
+            self.toasts.success("Everything reset to stock".to_string());
+        }
+        self.oc_history = crate::overclocking::OverclockHistory::load();
+        self.vibrance_levels.iter_mut().for_each(|level| *level = 0);
+    }
+
+    /// Run a pass/fail stress test on a background thread
+    pub fn start_stress_test(&mut self) {
+        if self.stress_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let gpu_index = self.selected_gpu_index;
+        let duration = std::time::Duration::from_secs(self.stress_minutes as u64 * 60);
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.stress_cancel = cancel.clone();
+        self.stress_rx = Some(rx);
+
+        std::thread::spawn(move || {
+            let backend = crate::nvml_backend::create_real_backend();
+            let result = crate::overclocking::run_stress_test(
+                backend.as_ref(),
+                gpu_index,
+                duration,
+                &cancel,
+            )
+            .map_err(|e| e.to_string());
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Stop a running stress test early; it is judged on what ran so far
+    pub fn cancel_stress_test(&mut self) {
+        self.stress_cancel.store(true, Ordering::SeqCst);
+    }
+
+    /// Pick up the result of a finished stress test
+    pub fn poll_stress_test(&mut self) {
+        let Some(rx) = &self.stress_rx else {
+            return;
+        };
+        let Ok(result) = rx.try_recv() else {
+            return;
+        };
+
+        match result {
+            Ok(result) => {
+                if result.passed {
+                    self.toasts.success(format!(
+                        "Stress test passed ({}s, max {}°C)",
+                        result.duration_secs, result.max_temp
+                    ));
+                } else {
+                    self.toasts.error(format!(
+                        "Stress test failed: {}",
+                        result.failure_reason.as_deref().unwrap_or("unstable")
+                    ));
+                }
+                self.last_stress_result = Some(result);
+            }
+            Err(e) => self.toasts.error(format!("Stress test failed: {}", e)),
+        }
+        self.stress_rx = None;
+    }
+
+    /// Run a thermal soak on a background thread, streaming readings back
+    pub fn start_thermal_soak(&mut self) {
+        if self.soak_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let gpu_index = self.selected_gpu_index;
+        let load = std::time::Duration::from_secs(self.soak_load_minutes as u64 * 60);
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.soak_cancel = cancel.clone();
+        self.soak_rx = Some(rx);
+        self.soak_samples.clear();
+
+        std::thread::spawn(move || {
+            let backend = crate::nvml_backend::create_real_backend();
+            let samples_tx = tx.clone();
+            let result = crate::benchmark::run_thermal_soak(
+                backend.as_ref(),
+                gpu_index,
+                load,
+                crate::benchmark::SOAK_CRITICAL_TEMP_C,
+                &cancel,
+                |sample| {
+                    let _ = samples_tx.send(SoakUpdate::Sample(sample.clone()));
+                },
+            )
+            .map_err(|e| e.to_string());
+            let _ = tx.send(SoakUpdate::Finished(result));
+        });
+    }
+
+    /// Stop a running thermal soak without waiting for the cooldown
+    pub fn cancel_thermal_soak(&mut self) {
+        self.soak_cancel.store(true, Ordering::SeqCst);
+    }
+
+    /// Collect soak readings and, once it finishes, save the CSV
+    pub fn poll_thermal_soak(&mut self) {
+        let Some(rx) = &self.soak_rx else {
+            return;
+        };
+        let mut finished = None;
+        for update in rx.try_iter() {
+            match update {
+                SoakUpdate::Sample(sample) => self.soak_samples.push(sample),
+                SoakUpdate::Finished(result) => finished = Some(result),
+            }
+        }
+        let Some(result) = finished else {
+            return;
+        };
+        self.soak_rx = None;
+
+        match result {
+            Ok(report) => {
+                let saved = crate::benchmark::default_soak_csv_path()
+                    .and_then(|path| report.write_csv(&path).map(|()| path));
+                match (&report.aborted, saved) {
+                    (Some(reason), _) => self
+                        .toasts
+                        .warning(format!("Thermal soak stopped early: {}", reason)),
+                    (None, Ok(path)) => self.toasts.success(format!(
+                        "Thermal soak done (peak {}°C), saved {}",
+                        report.peak_temp_c,
+                        path.display()
+                    )),
+                    (None, Err(e)) => self
+                        .toasts
+                        .error(format!("Thermal soak done, saving CSV failed: {}", e)),
+                }
+                self.last_soak = Some(report);
+            }
+            Err(e) => self.toasts.error(format!("Thermal soak failed: {}", e)),
+        }
+    }
+
+    /// Start the efficiency (undervolt) search on a background thread
+    pub fn start_efficiency_tuning(&mut self) {
+        if self.efficiency_rx.is_some() {
+            return;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let name = self.efficiency_profile_name.trim().to_string();
+        let config = crate::auto_overclock::EfficiencyConfig {
+            gpu_index: self.selected_gpu_index,
+            ..Default::default()
+        };
+        self.efficiency_rx = Some(rx);
+        self.efficiency_log.clear();
+
+        std::thread::spawn(move || {
+            let result = crate::auto_overclock::AutoOverclocker::new(
+                crate::auto_overclock::AutoOCConfig::default(),
+            )
+            .and_then(|overclocker| {
+                overclocker.find_efficient_voltage(&name, &config, |event| {
+                    let _ = tx.send(EfficiencyJobUpdate::Progress(event));
+                })
+            })
+            .map(|result| result.profile)
+            .map_err(|e| e.to_string());
+            let _ = tx.send(EfficiencyJobUpdate::Finished(result));
+        });
+    }
+
+    /// Drain progress from a running efficiency search
+    pub fn poll_efficiency_tuning(&mut self) {
+        let Some(rx) = &self.efficiency_rx else {
+            return;
+        };
+
+        let mut finished = None;
+        while let Ok(update) = rx.try_recv() {
+            use crate::auto_overclock::EfficiencyProgress;
+            match update {
+                EfficiencyJobUpdate::Progress(event) => {
+                    self.efficiency_log.push(match event {
+                        EfficiencyProgress::Testing {
+                            iteration,
+                            offset_mhz,
+                        } => format!("[{}] Testing +{} MHz", iteration, offset_mhz),
+                        EfficiencyProgress::Stable { offset_mhz } => {
+                            format!("Stable at +{} MHz", offset_mhz)
+                        }
+                        EfficiencyProgress::Unstable { offset_mhz } => {
+                            format!("Unstable at +{} MHz, backing off", offset_mhz)
+                        }
+                        EfficiencyProgress::Converged { offset_mhz } => {
+                            format!("Keeping +{} MHz with safety margin", offset_mhz)
+                        }
+                    });
+                }
+                EfficiencyJobUpdate::Finished(result) => finished = Some(result),
+            }
+        }
+
+        match finished {
+            Some(Ok(profile)) => {
+                self.toasts.success(format!(
+                    "Efficiency profile '{}' applied ({:+} MHz under {} MHz)",
+                    profile.name, profile.gpu_clock_offset, profile.max_gpu_clock_mhz
+                ));
+                self.efficiency_rx = None;
+                self.oc_history = crate::overclocking::OverclockHistory::load();
+            }
+            Some(Err(e)) => {
+                self.toasts
+                    .error(format!("Efficiency tuning failed: {}", e));
+                self.efficiency_log.push(format!("Failed: {}", e));
+                self.efficiency_rx = None;
+            }
+            None => {}
+        }
+    }
+
     /// Set vibrance for a display
     pub fn set_vibrance(&mut self, display_idx: usize, level: i16) {
         if display_idx < self.vibrance_levels.len() {
@@ -880,25 +2557,38 @@ impl GuiState {
 
     /// Refresh container list
     pub fn refresh_containers(&mut self) {
-        if let Some(ref runtime) = self.container_runtime {
-            match runtime.monitor_gpu_containers() {
-                Ok(containers) => {
-                    self.containers = containers
-                        .into_iter()
-                        .map(|c: crate::container::ContainerGpuInfo| ContainerInfo {
-                            id: c.container_id,
-                            name: c.container_name,
-                            image: c.image,
-                            status: format!("{:?}", c.status),
-                            gpu_usage: format!("{:.1}%", c.gpu_utilization),
-                        })
-                        .collect();
-                }
-                Err(e) => {
-                    self.toasts
-                        .error(format!("Failed to list containers: {}", e));
-                }
+        let runtime = self
+            .container_runtime
+            .get_or_insert_with(crate::container_runtime::NvContainerRuntime::default);
+
+        match runtime.list_containers() {
+            Ok(containers) => {
+                self.containers = containers
+                    .into_iter()
+                    .map(|c| ContainerInfo {
+                        runtime: c.runtime,
+                        id: c.id.chars().take(12).collect(),
+                        name: c.name,
+                        image: c.image,
+                        status: c.status,
+                        gpu_enabled: c.gpu_enabled,
+                        gpu_memory_mb: c.gpu_memory_mb,
+                    })
+                    .collect();
+                self.containers_error = None;
+            }
+            Err(e) => {
+                self.containers.clear();
+                self.containers_error = Some(e.to_string());
             }
         }
     }
 }
+
+/// Expand a leading `~/` in a user-typed path
+fn expand_home(path: &str) -> std::path::PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => std::path::PathBuf::from(path),
+    }
+}
@@ -0,0 +1,81 @@
+//! Containers Tab
+//!
+//! Running Docker/Podman containers and which of them have GPU access.
+
+use eframe::egui;
+
+use crate::gui::icons;
+use crate::gui::state::GuiState;
+use crate::gui::widgets::Card;
+
+/// Render the Containers tab
+pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
+    let colors = state.theme_colors();
+
+    // List once when the tab is first opened; afterwards only on demand
+    let loaded_id = egui::Id::new("containers_loaded");
+    if !ctx.data(|d| d.get_temp::<bool>(loaded_id).unwrap_or(false)) {
+        state.refresh_containers();
+        ctx.data_mut(|d| d.insert_temp(loaded_id, true));
+    }
+
+    ui.heading(format!("{} Containers", icons::CONTAINER));
+    ui.add_space(4.0);
+
+    Card::new(&colors)
+        .title("Running Containers")
+        .icon(icons::CONTAINER)
+        .show(ui, |ui| {
+            if ui
+                .button(format!("{} Refresh", icons::REFRESH))
+                .clicked()
+            {
+                state.refresh_containers();
+            }
+            ui.add_space(8.0);
+
+            if let Some(error) = &state.containers_error {
+                ui.colored_label(colors.yellow.to_egui(), format!("{} {}", icons::WARN, error));
+                ui.label(
+                    egui::RichText::new(
+                        "Install Docker or Podman with nvidia-container-toolkit to run GPU containers",
+                    )
+                    .small()
+                    .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            }
+
+            if state.containers.is_empty() {
+                ui.label(egui::RichText::new("No running containers").weak().italics());
+                return;
+            }
+
+            egui::Grid::new("containers_grid")
+                .num_columns(6)
+                .striped(true)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    for header in ["GPU", "Name", "Image", "Status", "Runtime", "ID"] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    for container in &state.containers {
+                        let (gpu_text, gpu_color) =
+                            match (container.gpu_enabled, container.gpu_memory_mb) {
+                                (_, Some(mb)) => (format!("● {} MB", mb), colors.green),
+                                (true, None) => ("● idle".to_string(), colors.cyan),
+                                (false, None) => ("○ none".to_string(), colors.fg_dark),
+                            };
+                        ui.colored_label(gpu_color.to_egui(), gpu_text);
+                        ui.label(&container.name);
+                        ui.label(&container.image);
+                        ui.label(&container.status);
+                        ui.label(&container.runtime);
+                        ui.label(egui::RichText::new(&container.id).monospace().small());
+                        ui.end_row();
+                    }
+                });
+        });
+}
@@ -4,12 +4,16 @@
 
 use eframe::egui;
 
+use crate::cli_hint::CliCommand;
 use crate::display;
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
 use crate::vibrance;
 
+/// Scale factors offered in the Display tab
+const SCALE_OPTIONS: [f32; 7] = [1.0, 1.25, 1.5, 1.75, 2.0, 2.5, 3.0];
+
 /// Render the Display tab
 pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
     let colors = state.theme_colors();
@@ -34,10 +38,15 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     // Convert from -1024..1023 range to 0..100 percentage
                     let mut percentage = ((*level + 1024) as f32 / 2047.0 * 100.0) as u32;
 
-                    if ui
-                        .add(egui::Slider::new(&mut percentage, 0..=100).suffix("%"))
-                        .changed()
-                    {
+                    let slider = ui.add(egui::Slider::new(&mut percentage, 0..=100).suffix("%"));
+                    let slider = super::cli_hint(
+                        slider,
+                        &CliCommand::Vibrance {
+                            display: Some(i),
+                            percent: vibrance::vibrance_to_percentage(*level as i32),
+                        },
+                    );
+                    if slider.changed() {
                         // Convert back to -1024..1023 range
                         *level = ((percentage as f32 / 100.0 * 2047.0) - 1024.0) as i16;
                         changed = true;
@@ -77,6 +86,100 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
     ui.add_space(8.0);
 
+    render_display_modes(ui, state);
+
+    ui.add_space(8.0);
+
+    // Color temperature and gamma, applied when a slider is released
+    Card::new(&colors)
+        .title("Color Temperature & Gamma")
+        .icon(icons::DISPLAY)
+        .show(ui, |ui| {
+            if std::env::var("WAYLAND_DISPLAY").is_ok() {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} On Wayland the compositor owns the gamma ramp - use its night light setting",
+                        icons::WARN
+                    ))
+                    .color(colors.yellow.to_egui()),
+                );
+                return;
+            }
+
+            let display_count = state.get_displays().len();
+            if display_count == 0 {
+                ui.label(egui::RichText::new("No displays detected").weak().italics());
+                return;
+            }
+
+            for index in 0..display_count {
+                let mut settings = state
+                    .config
+                    .display_color
+                    .get(index)
+                    .copied()
+                    .unwrap_or_default();
+                let mut commit = false;
+                let mut reset = false;
+
+                ui.label(egui::RichText::new(format!("Display {}", index)).strong());
+                egui::Grid::new(("display_color_grid", index))
+                    .num_columns(2)
+                    .spacing([16.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new("Temperature").color(colors.fg_dark.to_egui()));
+                        let response = ui.add(
+                            egui::Slider::new(&mut settings.kelvin, display::KELVIN_RANGE)
+                                .step_by(100.0)
+                                .suffix(" K"),
+                        );
+                        commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+                        ui.end_row();
+
+                        for (channel, label) in ["Red gamma", "Green gamma", "Blue gamma"].iter().enumerate() {
+                            ui.label(egui::RichText::new(*label).color(colors.fg_dark.to_egui()));
+                            let response = ui.add(
+                                egui::Slider::new(&mut settings.gamma[channel], display::GAMMA_RANGE)
+                                    .fixed_decimals(2),
+                            );
+                            commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+                            ui.end_row();
+                        }
+                    });
+                if ui
+                    .add_enabled(!settings.is_neutral(), egui::Button::new(format!("{} Reset", icons::REFRESH)))
+                    .on_hover_text("Back to 6500 K and gamma 1.0")
+                    .clicked()
+                {
+                    reset = true;
+                }
+
+                // Keep the slider position while dragging, before it is applied
+                if state.config.display_color.len() <= index {
+                    state
+                        .config
+                        .display_color
+                        .resize(index + 1, display::DisplayColorSettings::default());
+                }
+                state.config.display_color[index] = settings;
+
+                let result = if reset {
+                    state.config.display_color[index] = display::DisplayColorSettings::default();
+                    Some(display::reset_color(index))
+                } else if commit {
+                    Some(display::set_display_color(index, settings))
+                } else {
+                    None
+                };
+                if let Some(Err(e)) = result {
+                    state.toasts.error(format!("Failed to set display color: {}", e));
+                }
+                ui.add_space(6.0);
+            }
+        });
+
+    ui.add_space(8.0);
+
     // ICC Profile Management
     Card::new(&colors)
         .title("ICC Profile Management")
@@ -192,3 +295,163 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
             }
         });
 }
+
+/// Resolution and refresh rate per connected display
+fn render_display_modes(ui: &mut egui::Ui, state: &mut GuiState) {
+    let colors = state.theme_colors();
+    if state.display_listing.is_none() {
+        state.refresh_display_listing();
+    }
+    let Some(listing) = state.display_listing.clone() else {
+        return;
+    };
+
+    Card::new(&colors)
+        .title("Resolution & Refresh Rate")
+        .icon(icons::DISPLAY)
+        .show(ui, |ui| {
+            if listing.displays.is_empty() {
+                ui.label(egui::RichText::new("No displays detected").weak().italics());
+            }
+
+            let mut requested = None;
+            let mut rotate = None;
+            let mut scale = None;
+            egui::Grid::new("display_modes_grid")
+                .num_columns(4)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for disp in &listing.displays {
+                        ui.label(egui::RichText::new(&disp.connector).strong())
+                            .on_hover_text(
+                                disp.monitor_name.as_deref().unwrap_or("Unknown monitor"),
+                            );
+
+                        let current = disp
+                            .current_mode
+                            .as_ref()
+                            .map(|m| format!("{}x{} @ {:.2}Hz", m.width, m.height, m.refresh_hz))
+                            .unwrap_or_else(|| "Unknown".to_string());
+                        egui::ComboBox::from_id_salt(("display_mode", &disp.connector))
+                            .selected_text(current)
+                            .height(320.0)
+                            .show_ui(ui, |ui| {
+                                for mode in &disp.modes {
+                                    for &hz in &mode.refresh_rates {
+                                        let selected =
+                                            disp.current_mode.as_ref().is_some_and(|m| {
+                                                m.width == mode.width
+                                                    && m.height == mode.height
+                                                    && (m.refresh_hz - hz).abs() < 0.01
+                                            });
+                                        let label =
+                                            format!("{}x{} @ {:.2}Hz", mode.width, mode.height, hz);
+                                        if ui.selectable_label(selected, label).clicked()
+                                            && !selected
+                                        {
+                                            requested = Some((
+                                                disp.connector.clone(),
+                                                crate::display_info::CurrentMode {
+                                                    width: mode.width,
+                                                    height: mode.height,
+                                                    refresh_hz: hz,
+                                                },
+                                            ));
+                                        }
+                                    }
+                                }
+                            });
+
+                        let rotation = state
+                            .display_rotation
+                            .get(&disp.connector)
+                            .copied()
+                            .unwrap_or_default();
+                        let rotation_combo =
+                            egui::ComboBox::from_id_salt(("display_rotation", &disp.connector))
+                                .selected_text(format!("{} {}", icons::ROTATE, rotation))
+                                .show_ui(ui, |ui| {
+                                    for orientation in display::Orientation::ALL {
+                                        if ui
+                                            .selectable_label(
+                                                orientation == rotation,
+                                                orientation.name(),
+                                            )
+                                            .clicked()
+                                            && orientation != rotation
+                                        {
+                                            rotate = Some((disp.connector.clone(), orientation));
+                                        }
+                                    }
+                                })
+                                .response;
+                        super::cli_hint(
+                            rotation_combo,
+                            &CliCommand::Rotate {
+                                connector: disp.connector.clone(),
+                                orientation: rotation,
+                            },
+                        );
+
+                        let factor = state
+                            .display_scale
+                            .get(&disp.connector)
+                            .copied()
+                            .unwrap_or(1.0);
+                        let scale_combo =
+                            egui::ComboBox::from_id_salt(("display_scale", &disp.connector))
+                                .selected_text(format!("{}x", factor))
+                                .show_ui(ui, |ui| {
+                                    for option in SCALE_OPTIONS {
+                                        let selected = (option - factor).abs() < 0.01;
+                                        let label = if option.fract() == 0.0 {
+                                            format!("{}x", option)
+                                        } else {
+                                            format!("{}x (fractional)", option)
+                                        };
+                                        if ui.selectable_label(selected, label).clicked()
+                                            && !selected
+                                        {
+                                            scale = Some((disp.connector.clone(), option));
+                                        }
+                                    }
+                                })
+                                .response
+                                .on_hover_text(
+                                    "Fractional scales can look blurry on NVIDIA, \
+                                 especially in XWayland apps",
+                                );
+                        super::cli_hint(
+                            scale_combo,
+                            &CliCommand::Scale {
+                                connector: disp.connector.clone(),
+                                factor,
+                            },
+                        );
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut state.save_display_mode, "Save to connector profile")
+                    .on_hover_text("Re-applied by `nvctl display connector apply` and on hotplug");
+                if ui.button(format!("{} Rescan", icons::REFRESH)).clicked() {
+                    state.refresh_display_listing();
+                }
+            });
+
+            if let Some((connector, mode)) = requested {
+                let save = state.save_display_mode;
+                state.apply_display_mode(&connector, mode, save);
+            }
+            if let Some((connector, orientation)) = rotate {
+                let save = state.save_display_mode;
+                state.apply_display_rotation(&connector, orientation, save);
+            }
+            if let Some((connector, factor)) = scale {
+                let save = state.save_display_mode;
+                state.apply_display_scale(&connector, factor, save);
+            }
+        });
+}
@@ -0,0 +1,79 @@
+//! Event Log Tab
+//!
+//! Scrollable timeline of the session's events, newest first.
+
+use eframe::egui;
+
+use crate::gui::icons;
+use crate::gui::state::GuiState;
+use crate::gui::toast::ToastKind;
+use crate::gui::widgets::Card;
+
+/// Render the Event Log tab
+pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
+    let colors = state.theme_colors();
+
+    ui.heading(format!("{} Event Log", icons::EVENTS));
+    ui.add_space(4.0);
+
+    let events = state.event_log.snapshot();
+
+    Card::new(&colors)
+        .title("Activity")
+        .icon(icons::LIST)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(format!("{} event(s) this session", events.len()));
+                ui.checkbox(
+                    &mut state.event_log_problems_only,
+                    "Warnings and errors only",
+                );
+                if ui
+                    .add_enabled(!events.is_empty(), egui::Button::new("Clear"))
+                    .clicked()
+                {
+                    state.event_log.clear();
+                }
+            });
+            ui.add_space(4.0);
+
+            let shown: Vec<_> = events
+                .iter()
+                .rev()
+                .filter(|event| {
+                    !state.event_log_problems_only
+                        || matches!(event.kind, ToastKind::Warning | ToastKind::Error)
+                })
+                .collect();
+            if shown.is_empty() {
+                ui.label(
+                    egui::RichText::new("Nothing logged yet")
+                        .italics()
+                        .color(colors.comment.to_egui()),
+                );
+                return;
+            }
+
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    egui::Grid::new("event_log_grid")
+                        .num_columns(3)
+                        .spacing([12.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for event in shown {
+                                let color = event.kind.color(&colors);
+                                ui.label(
+                                    egui::RichText::new(event.time.format("%H:%M:%S").to_string())
+                                        .monospace()
+                                        .color(colors.comment.to_egui()),
+                                );
+                                ui.colored_label(color, event.kind.icon());
+                                ui.label(egui::RichText::new(&event.message).color(color));
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+}
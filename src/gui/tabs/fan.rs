@@ -4,10 +4,12 @@
 
 use eframe::egui;
 
+use crate::cli_hint::CliCommand;
 use crate::fan;
 use crate::gui::icons;
 use crate::gui::state::{FanMode, GuiState};
 use crate::gui::widgets::Card;
+use crate::gui_widgets::{FAN_CURVE_SNAP_STEP, snap_to_step};
 
 /// Render the Fan Control tab
 pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
@@ -59,12 +61,15 @@ fn render_fan_status(
             for fan_info in fans {
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new(format!("Fan {}:", fan_info.id)).strong());
-                    if let Some(rpm) = fan_info.rpm {
-                        ui.label(
-                            egui::RichText::new(format!("{} RPM", rpm))
-                                .color(colors.green.to_egui()),
-                        );
-                    }
+                    ui.label(
+                        egui::RichText::new(crate::fan::format_rpm(fan_info.rpm)).color(
+                            if fan_info.rpm.is_some() {
+                                colors.green.to_egui()
+                            } else {
+                                colors.fg_dark.to_egui()
+                            },
+                        ),
+                    );
                     if let Some(percent) = fan_info.percent {
                         let fan_color = if percent > 80 {
                             colors.red.to_egui()
@@ -86,15 +91,24 @@ fn render_fan_status(
                     let mut new_speed = current_speed;
                     ui.horizontal(|ui| {
                         ui.label("Speed:");
-                        if ui
-                            .add(egui::Slider::new(&mut new_speed, 0..=100).suffix("%"))
-                            .changed()
-                        {
+                        let slider = ui.add(egui::Slider::new(&mut new_speed, 0..=100).suffix("%"));
+                        let slider = super::cli_hint(
+                            slider,
+                            &CliCommand::FanSpeed {
+                                fan: fan_info.id,
+                                percent: current_speed,
+                            },
+                        );
+                        if slider.changed() {
                             state.fan_speeds.insert(fan_info.id, new_speed);
-                            if let Err(e) = fan::set_fan_speed(fan_info.id, new_speed) {
-                                state
+                            match fan::set_fan_speed(fan_info.id, new_speed) {
+                                Ok(()) => crate::state::LastApplied::record_fan_speed(
+                                    fan_info.id,
+                                    new_speed,
+                                ),
+                                Err(e) => state
                                     .toasts
-                                    .error(format!("Failed to set fan speed: {}", e));
+                                    .error(format!("Failed to set fan speed: {}", e)),
                             }
                         }
                     });
@@ -148,6 +162,7 @@ fn render_fan_status(
                     if let Err(e) = fan::set_fan_speed(0, 30) {
                         state.toasts.error(format!("Failed: {}", e));
                     } else {
+                        crate::state::LastApplied::record_fan_speed(0, 30);
                         state.toasts.info("Fan set to silent mode (30%)");
                     }
                 }
@@ -162,6 +177,7 @@ fn render_fan_status(
                     if let Err(e) = fan::set_fan_speed(0, 50) {
                         state.toasts.error(format!("Failed: {}", e));
                     } else {
+                        crate::state::LastApplied::record_fan_speed(0, 50);
                         state.toasts.info("Fan set to balanced mode (50%)");
                     }
                 }
@@ -176,6 +192,7 @@ fn render_fan_status(
                     if let Err(e) = fan::set_fan_speed(0, 70) {
                         state.toasts.error(format!("Failed: {}", e));
                     } else {
+                        crate::state::LastApplied::record_fan_speed(0, 70);
                         state.toasts.info("Fan set to cool mode (70%)");
                     }
                 }
@@ -190,6 +207,7 @@ fn render_fan_status(
                     if let Err(e) = fan::set_fan_speed(0, 100) {
                         state.toasts.error(format!("Failed: {}", e));
                     } else {
+                        crate::state::LastApplied::record_fan_speed(0, 100);
                         state.toasts.info("Fan set to maximum (100%)");
                     }
                 }
@@ -208,13 +226,7 @@ fn render_thermal_status(
         .icon(icons::TEMP)
         .show(ui, |ui| {
             if let Some(ref stats) = state.gpu_stats {
-                let temp_color = if stats.temperature > 80.0 {
-                    colors.red.to_egui()
-                } else if stats.temperature > 65.0 {
-                    colors.yellow.to_egui()
-                } else {
-                    colors.green.to_egui()
-                };
+                let temp_color = colors.temp_color(stats.temperature).to_egui();
 
                 ui.horizontal(|ui| {
                     ui.label("GPU Temperature:");
@@ -287,6 +299,40 @@ fn render_thermal_status(
         });
 }
 
+/// How close (in screen pixels) the pointer must be to grab a curve point
+const FAN_POINT_GRAB_RADIUS: f32 = 10.0;
+
+/// Pick which fan the editor targets. Hidden when the GPU exposes a single
+/// (synchronized) fan controller, since there is nothing to choose.
+fn render_fan_curve_target(ui: &mut egui::Ui, state: &mut GuiState) {
+    let fans = state.selected_gpu_fan_ids();
+    if fans.len() < 2 {
+        return;
+    }
+
+    let label = |target: Option<usize>| match target {
+        Some(fan_id) => format!("Fan {}", fan_id % 10 + 1),
+        None => "All fans (shared)".to_string(),
+    };
+    let mut target = state.fan_curve_target;
+    ui.horizontal(|ui| {
+        ui.label("Curve for:");
+        egui::ComboBox::from_id_salt("fan_curve_target")
+            .selected_text(label(target))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut target, None, label(None))
+                    .on_hover_text("Used by every fan without its own curve");
+                for fan_id in &fans {
+                    ui.selectable_value(&mut target, Some(*fan_id), label(Some(*fan_id)));
+                }
+            });
+    });
+    if target != state.fan_curve_target {
+        state.set_fan_curve_target(target);
+    }
+    ui.add_space(4.0);
+}
+
 /// Render fan curve editor
 fn render_fan_curve_editor(
     ui: &mut egui::Ui,
@@ -297,8 +343,10 @@ fn render_fan_curve_editor(
         .title("Fan Curve Editor")
         .icon(icons::CHART)
         .show(ui, |ui| {
+            render_fan_curve_target(ui, state);
+
             // Fan curve plot
-            use egui_plot::{Line, Plot, PlotPoints, Points};
+            use egui_plot::{Line, Plot, PlotPoints, Points, Polygon};
 
             let curve_points_vec: Vec<[f64; 2]> = state
                 .fan_curve
@@ -307,21 +355,48 @@ fn render_fan_curve_editor(
                 .map(|p| [p.x, p.y])
                 .collect();
 
-            Plot::new("fan_curve_plot")
+            let current = state.gpu_stats.as_ref().map(|stats| {
+                let temp = stats.temperature as f64;
+                [temp, state.fan_curve.get_speed_at_temp(temp)]
+            });
+            let dragging = state.fan_curve.selected_point;
+            let zero_rpm_below = state.fan_curve.zero_rpm_below;
+
+            // The plot view is fixed so drags move points rather than pan
+            let interaction = Plot::new("fan_curve_plot")
                 .height(220.0)
                 .width(ui.available_width())
                 .x_axis_label("Temperature (°C)")
                 .y_axis_label("Fan Speed (%)")
-                .allow_drag(true)
-                .allow_zoom(true)
+                .allow_drag(false)
+                .allow_zoom(false)
+                .allow_scroll(false)
+                .allow_boxed_zoom(false)
+                .allow_double_click_reset(false)
+                .include_x(0.0)
+                .include_x(100.0)
+                .include_y(0.0)
+                .include_y(100.0)
                 .show_axes([true, true])
                 .show(ui, |plot_ui| {
+                    // Shade the zero-RPM region where the fan is stopped
+                    if let Some(below) = zero_rpm_below {
+                        let below = below as f64;
+                        let region: PlotPoints =
+                            vec![[0.0, 0.0], [below, 0.0], [below, 100.0], [0.0, 100.0]].into();
+                        plot_ui.polygon(
+                            Polygon::new("Zero RPM", region)
+                                .fill_color(colors.blue.to_egui().gamma_multiply(0.15))
+                                .stroke(egui::Stroke::NONE),
+                        );
+                    }
+
                     // Draw the curve line
                     let curve_line: PlotPoints = curve_points_vec.clone().into();
                     plot_ui.line(Line::new("Curve", curve_line).color(colors.cyan.to_egui()));
 
                     // Draw the control points
-                    let curve_pts: PlotPoints = curve_points_vec.into();
+                    let curve_pts: PlotPoints = curve_points_vec.clone().into();
                     plot_ui.points(
                         Points::new("Control Points", curve_pts)
                             .radius(6.0)
@@ -329,17 +404,70 @@ fn render_fan_curve_editor(
                     );
 
                     // Draw current temperature indicator
-                    if let Some(ref stats) = state.gpu_stats {
-                        let temp = stats.temperature as f64;
-                        let speed = state.fan_curve.get_speed_at_temp(temp);
-                        let current_point: PlotPoints = vec![[temp, speed]].into();
+                    if let Some(point) = current {
+                        let current_point: PlotPoints = vec![point].into();
                         plot_ui.points(
                             Points::new("Current", current_point)
                                 .radius(8.0)
                                 .color(colors.red.to_egui()),
                         );
                     }
-                });
+
+                    let response = plot_ui.response().clone();
+                    let pointer = plot_ui.pointer_coordinate();
+                    let hovered_point = response.hover_pos().and_then(|pos| {
+                        curve_points_vec
+                            .iter()
+                            .enumerate()
+                            .map(|(i, p)| {
+                                let screen = plot_ui.screen_from_plot([p[0], p[1]].into());
+                                (i, screen.distance(pos))
+                            })
+                            .filter(|(_, distance)| *distance <= FAN_POINT_GRAB_RADIUS)
+                            .min_by(|a, b| a.1.total_cmp(&b.1))
+                            .map(|(i, _)| i)
+                    });
+                    (response, pointer, hovered_point)
+                })
+                .inner;
+
+            let (response, pointer, hovered_point) = interaction;
+            let snap = ui.input(|i| i.modifiers.shift);
+            if response.drag_started() {
+                state.fan_curve.selected_point = hovered_point;
+            }
+            if let (Some(index), Some(pointer)) = (dragging, pointer) {
+                if response.dragged() {
+                    state.fan_curve.drag_point(index, pointer.x, pointer.y, snap);
+                }
+            }
+            if response.drag_stopped() {
+                state.fan_curve.selected_point = None;
+            }
+            if response.double_clicked() {
+                if let Some(pointer) = pointer {
+                    let (temp, speed) = if snap {
+                        (
+                            snap_to_step(pointer.x, FAN_CURVE_SNAP_STEP),
+                            snap_to_step(pointer.y, FAN_CURVE_SNAP_STEP),
+                        )
+                    } else {
+                        (pointer.x.round(), pointer.y.round())
+                    };
+                    if !state.fan_curve.add_point(temp, speed) {
+                        state.toasts.info("A point already exists at that temperature");
+                    }
+                }
+            }
+            if response.secondary_clicked() {
+                if let Some(index) = hovered_point {
+                    if state.fan_curve.points.len() > 2 {
+                        state.fan_curve.remove_point(index);
+                    } else {
+                        state.toasts.info("A fan curve needs at least two points");
+                    }
+                }
+            }
 
             ui.add_space(8.0);
 
@@ -421,6 +549,27 @@ fn render_fan_curve_editor(
                         state.set_fan_mode(FanMode::Curve);
                     }
                 });
+                super::apply_to_all_checkbox(&mut columns[1], state);
+
+                // Zero RPM: fans stop below a temperature and restart a few
+                // degrees higher (see fan::ZERO_RPM_HYSTERESIS_C)
+                columns[1].add_space(8.0);
+                columns[1].horizontal(|ui| {
+                    let mut enabled = state.fan_curve.zero_rpm_below.is_some();
+                    if ui
+                        .checkbox(&mut enabled, "Zero RPM below")
+                        .on_hover_text(format!(
+                            "Stop the fan below this temperature; it spins up again {}°C higher",
+                            crate::fan::ZERO_RPM_HYSTERESIS_C
+                        ))
+                        .changed()
+                    {
+                        state.fan_curve.zero_rpm_below = enabled.then_some(45);
+                    }
+                    if let Some(below) = state.fan_curve.zero_rpm_below.as_mut() {
+                        ui.add(egui::Slider::new(below, 20..=70).suffix("°C"));
+                    }
+                });
 
                 // Preset curves
                 columns[1].add_space(8.0);
@@ -472,7 +621,7 @@ fn render_fan_curve_editor(
             ui.add_space(8.0);
             ui.label(
                 egui::RichText::new(
-                    "Tip: Drag points in the graph to adjust the curve, or use preset curves above.",
+                    "Tip: Drag points to adjust (hold Shift to snap to 5°/5%), double-click to add a point, right-click a point to remove it.",
                 )
                 .small()
                 .weak(),
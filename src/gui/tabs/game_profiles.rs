@@ -5,13 +5,15 @@
 
 use eframe::egui;
 
+use crate::dlss::{self, DlssRenderPreset};
+use crate::game_launcher::GameLauncher;
 use crate::game_profile_auto;
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
 
 /// Render the Game Profiles tab
-pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
+pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
     let colors = state.theme_colors();
 
     ui.heading(format!("{} Game Profile Auto-Application", icons::GAME));
@@ -134,6 +136,125 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
     ui.add_space(8.0);
 
+    // DLSS Preset Override Card
+    let loaded_id = egui::Id::new("launch_profiles_loaded");
+    if !ctx.data(|d| d.get_temp::<bool>(loaded_id).unwrap_or(false)) {
+        state.launch_profiles = GameLauncher::new()
+            .map(|launcher| launcher.list_profiles())
+            .unwrap_or_default();
+        ctx.data_mut(|d| d.insert_temp(loaded_id, true));
+    }
+
+    Card::new(&colors)
+        .title("DLSS Preset Override")
+        .icon(icons::GAME)
+        .show(ui, |ui| {
+            let supported = state
+                .driver_capabilities
+                .as_ref()
+                .map(|caps| caps.has_dlss_preset_override);
+            match supported {
+                Some(false) => {
+                    ui.colored_label(
+                        colors.yellow.to_egui(),
+                        format!(
+                            "{} Driver {} is too old to honor DLSS overrides (570+ required)",
+                            icons::WARN,
+                            state
+                                .driver_capabilities
+                                .as_ref()
+                                .map(|caps| caps.version.as_str())
+                                .unwrap_or("unknown")
+                        ),
+                    );
+                    return;
+                }
+                None => {
+                    ui.colored_label(
+                        colors.yellow.to_egui(),
+                        format!("{} Driver version unknown", icons::WARN),
+                    );
+                    return;
+                }
+                Some(true) => {}
+            }
+
+            if state.launch_profiles.is_empty() {
+                ui.label(
+                    egui::RichText::new(
+                        "No game launch profiles - create one with: nvctl gaming launch create",
+                    )
+                    .weak()
+                    .italics(),
+                );
+                return;
+            }
+
+            egui::Grid::new("dlss_preset_grid")
+                .num_columns(2)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("Game:");
+                    let previous = state.dlss_preset_profile.clone();
+                    egui::ComboBox::from_id_salt("dlss_preset_profile")
+                        .selected_text(&state.dlss_preset_profile)
+                        .show_ui(ui, |ui| {
+                            for name in &state.launch_profiles {
+                                ui.selectable_value(
+                                    &mut state.dlss_preset_profile,
+                                    name.clone(),
+                                    name,
+                                );
+                            }
+                        });
+                    if state.dlss_preset_profile != previous {
+                        state.dlss_preset = GameLauncher::new()
+                            .and_then(|l| l.load_profile(&state.dlss_preset_profile))
+                            .map(|p| dlss::get_preset(&p))
+                            .unwrap_or(DlssRenderPreset::Default);
+                    }
+                    ui.end_row();
+
+                    ui.label("Preset:");
+                    egui::ComboBox::from_id_salt("dlss_preset_value")
+                        .selected_text(state.dlss_preset.to_string())
+                        .show_ui(ui, |ui| {
+                            for preset in DlssRenderPreset::all() {
+                                ui.selectable_value(
+                                    &mut state.dlss_preset,
+                                    *preset,
+                                    preset.to_string(),
+                                );
+                            }
+                        });
+                    ui.end_row();
+                });
+
+            ui.add_space(8.0);
+            let can_apply = !state.dlss_preset_profile.is_empty();
+            if ui
+                .add_enabled(can_apply, egui::Button::new("💾 Save Override"))
+                .clicked()
+            {
+                let result = GameLauncher::new().and_then(|launcher| {
+                    let mut profile = launcher.load_profile(&state.dlss_preset_profile)?;
+                    if let Some(caps) = &state.driver_capabilities {
+                        dlss::set_preset(&mut profile, state.dlss_preset, caps)?;
+                    }
+                    launcher.save_profile(&profile)
+                });
+                match result {
+                    Ok(()) => state.toasts.success(format!(
+                        "DLSS {} saved for {}",
+                        state.dlss_preset, state.dlss_preset_profile
+                    )),
+                    Err(e) => state.toasts.error(format!("Failed: {}", e)),
+                }
+            }
+        });
+
+    ui.add_space(8.0);
+
     // Profile Directory Info Card
     Card::new(&colors)
         .title("Profile Directory")
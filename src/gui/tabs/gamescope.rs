@@ -107,6 +107,78 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
     ui.add_space(8.0);
 
+    // Launch Presets Card
+    Card::new(&colors)
+        .title("Steam Launch Presets")
+        .icon(icons::ROCKET)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Preset:");
+                egui::ComboBox::from_id_salt("gamescope_launch_preset")
+                    .selected_text(&state.gamescope_selected_preset)
+                    .show_ui(ui, |ui| {
+                        for preset in &state.gamescope_launch_presets {
+                            ui.selectable_value(
+                                &mut state.gamescope_selected_preset,
+                                preset.name.clone(),
+                                format!(
+                                    "{} ({}x{}, {})",
+                                    preset.name,
+                                    preset.width,
+                                    preset.height,
+                                    preset.upscaler.label()
+                                ),
+                            );
+                        }
+                    });
+            });
+
+            let launch_option = format!(
+                "nvctl gamescope launch --preset {} -- %command%",
+                state.gamescope_selected_preset
+            );
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(&launch_option)
+                        .monospace()
+                        .color(colors.cyan.to_egui()),
+                );
+                if ui.button("📋 Copy").clicked() {
+                    ui.ctx().copy_text(launch_option.clone());
+                    state.toasts.success("Launch option copied to clipboard!");
+                }
+            });
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                ui.label("Save current config as:");
+                ui.text_edit_singleline(&mut state.gamescope_new_preset_name);
+                let name = state.gamescope_new_preset_name.trim().to_string();
+                if ui
+                    .add_enabled(!name.is_empty(), egui::Button::new("💾 Save Preset"))
+                    .clicked()
+                {
+                    let config = state.gamescope_config.clone().unwrap_or_default();
+                    let preset = gamescope::LaunchPreset::from_config(&name, &config);
+                    match gamescope::save_launch_preset(&preset) {
+                        Ok(()) => {
+                            state.gamescope_launch_presets =
+                                gamescope::load_launch_presets().unwrap_or_default();
+                            state.gamescope_selected_preset = name.clone();
+                            state.gamescope_new_preset_name.clear();
+                            state
+                                .toasts
+                                .success(format!("Saved launch preset '{}'", name));
+                        }
+                        Err(e) => state.toasts.error(format!("Failed: {}", e)),
+                    }
+                }
+            });
+        });
+
+    ui.add_space(8.0);
+
     // Custom Configuration Card
     Card::new(&colors)
         .title("Custom Gamescope Configuration")
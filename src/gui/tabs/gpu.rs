@@ -6,9 +6,22 @@ use eframe::egui;
 use std::collections::VecDeque;
 
 use crate::gui::icons;
-use crate::gui::state::GuiState;
+use crate::gui::state::{GraphMetric, GuiState};
 use crate::gui::widgets::Card;
 
+/// Small "Export graph" button, disabled until the series has data
+fn export_graph_button(ui: &mut egui::Ui, state: &GuiState, metric: GraphMetric) -> bool {
+    let has_data = metric
+        .series(&state.monitoring_dashboard)
+        .get_points()
+        .len()
+        >= 2;
+    ui.add_enabled(has_data, egui::Button::new(icons::EXPORT).small())
+        .on_hover_text(format!("Export {} graph", metric.name().to_lowercase()))
+        .on_disabled_hover_text("No samples yet")
+        .clicked()
+}
+
 /// Draw a mini sparkline graph
 fn draw_sparkline(
     ui: &mut egui::Ui,
@@ -47,6 +60,20 @@ fn draw_sparkline(
 }
 
 /// Render the GPU status tab
+/// Marker next to a clock pinned with `nvctl gpu lock-clocks`
+fn locked_badge(ui: &mut egui::Ui, colors: &crate::themes::ColorPalette, mhz: u32) {
+    ui.label(
+        egui::RichText::new("🔒 LOCKED")
+            .small()
+            .strong()
+            .color(colors.orange.to_egui()),
+    )
+    .on_hover_text(format!(
+        "Locked at {} MHz for benchmarking. Release with `nvctl gpu lock-clocks --reset`",
+        mhz
+    ));
+}
+
 pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
     let colors = state.theme_colors();
 
@@ -84,7 +111,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                         });
 
                     if ui.button(format!("{} Refresh", icons::REFRESH)).clicked() {
-                        state.available_gpus = crate::multi_gpu::detect_gpus().unwrap_or_default();
+                        state.available_gpus =
+                            crate::multi_gpu::detect_gpus(&state.config.gpu_labels)
+                                .unwrap_or_default();
                         state.toasts.info("GPU list refreshed");
                     }
                 });
@@ -93,7 +122,10 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
         ui.add_space(8.0);
     }
 
+    state.refresh_board_identity();
+
     // Main GPU info in two columns
+    let mut export = None;
     ui.columns(2, |columns| {
         // Left column: GPU Identity
         Card::new(&colors)
@@ -184,6 +216,55 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                             );
                             ui.end_row();
 
+                            if let Some((_, identity)) = &state.board_identity {
+                                if let Some(vbios) = &identity.vbios_version {
+                                    ui.label(
+                                        egui::RichText::new("VBIOS:").color(colors.cyan.to_egui()),
+                                    );
+                                    ui.label(
+                                        egui::RichText::new(vbios).color(colors.green.to_egui()),
+                                    );
+                                    ui.end_row();
+                                }
+                                if let Some(part) = &identity.part_number {
+                                    ui.label(
+                                        egui::RichText::new("Part Number:")
+                                            .color(colors.cyan.to_egui()),
+                                    );
+                                    ui.label(
+                                        egui::RichText::new(part)
+                                            .small()
+                                            .color(colors.green.to_egui()),
+                                    );
+                                    ui.end_row();
+                                }
+                                ui.label(egui::RichText::new("Serial:").color(colors.cyan.to_egui()));
+                                match &identity.serial {
+                                    Some(serial) => {
+                                        ui.label(
+                                            egui::RichText::new(serial)
+                                                .small()
+                                                .color(colors.green.to_egui()),
+                                        )
+                                        .on_hover_text("Right-click to copy")
+                                        .context_menu(|ui| {
+                                            if ui.button("Copy serial").clicked() {
+                                                ui.ctx().copy_text(serial.clone());
+                                                ui.close();
+                                            }
+                                        });
+                                    }
+                                    None => {
+                                        ui.label(
+                                            egui::RichText::new(crate::gpu::SERIAL_UNAVAILABLE_NOTE)
+                                                .italics()
+                                                .color(colors.fg_dark.to_egui()),
+                                        );
+                                    }
+                                }
+                                ui.end_row();
+                            }
+
                             ui.label(egui::RichText::new("PCI Bus:").color(colors.cyan.to_egui()));
                             ui.label(
                                 egui::RichText::new(&stats.pci_bus)
@@ -191,7 +272,131 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                                     .color(colors.green.to_egui()),
                             );
                             ui.end_row();
+
+                            if let Some(link) = stats.pcie.link {
+                                ui.label(egui::RichText::new("PCIe:").color(colors.cyan.to_egui()));
+                                let color = if link.is_width_degraded() {
+                                    colors.yellow
+                                } else {
+                                    colors.green
+                                };
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "Gen{} x{}",
+                                        link.current_gen, link.current_width
+                                    ))
+                                    .strong()
+                                    .color(color.to_egui()),
+                                )
+                                .on_hover_text(format!(
+                                    "Card supports Gen{} x{}; the generation drops when idle to save power",
+                                    link.max_gen, link.max_width
+                                ));
+                                ui.end_row();
+                            }
+
+                            if let Some(persistent) = stats.persistence_mode {
+                                ui.label(
+                                    egui::RichText::new("Persistence:")
+                                        .color(colors.cyan.to_egui()),
+                                );
+                                ui.label(
+                                    egui::RichText::new(if persistent {
+                                        "Enabled"
+                                    } else {
+                                        "Disabled"
+                                    })
+                                    .color(colors.green.to_egui()),
+                                )
+                                .on_hover_text(
+                                    "Keeps the driver loaded with no clients. Prefer the nvidia-persistenced service; change with `nvctl gpu persistence`",
+                                );
+                                ui.end_row();
+                            }
+
+                            if let Some(rebar) = stats.pcie.rebar {
+                                ui.label(
+                                    egui::RichText::new("Resizable BAR:")
+                                        .color(colors.cyan.to_egui()),
+                                );
+                                let (text, color) = if rebar.enabled() {
+                                    ("Enabled", colors.green)
+                                } else {
+                                    ("Disabled", colors.yellow)
+                                };
+                                ui.label(egui::RichText::new(text).color(color.to_egui()))
+                                    .on_hover_text(format!(
+                                        "BAR1 {} MB",
+                                        rebar.bar1_bytes / (1024 * 1024)
+                                    ));
+                                ui.end_row();
+                            }
                         });
+
+                    for warning in stats.pcie.warnings() {
+                        ui.colored_label(
+                            colors.yellow.to_egui(),
+                            format!("{} {}", icons::WARN, warning),
+                        );
+                    }
+
+                    if let Some(ecc) = stats.ecc_errors {
+                        ui.add_space(8.0);
+                        ui.label(
+                            egui::RichText::new("ECC Errors")
+                                .strong()
+                                .color(colors.cyan.to_egui()),
+                        );
+                        let uncorrected_color = |count: u64| {
+                            if count > 0 {
+                                colors.red.to_egui()
+                            } else {
+                                colors.green.to_egui()
+                            }
+                        };
+                        egui::Grid::new("gpu_ecc_grid")
+                            .num_columns(3)
+                            .spacing([20.0, 4.0])
+                            .show(ui, |ui| {
+                                ui.label("");
+                                ui.label(egui::RichText::new("Corrected").small());
+                                ui.label(egui::RichText::new("Uncorrected").small());
+                                ui.end_row();
+
+                                for (label, corrected, uncorrected) in [
+                                    (
+                                        "Volatile:",
+                                        ecc.volatile_corrected,
+                                        ecc.volatile_uncorrected,
+                                    ),
+                                    (
+                                        "Aggregate:",
+                                        ecc.aggregate_corrected,
+                                        ecc.aggregate_uncorrected,
+                                    ),
+                                ] {
+                                    ui.label(
+                                        egui::RichText::new(label).color(colors.cyan.to_egui()),
+                                    );
+                                    ui.label(corrected.to_string());
+                                    ui.label(
+                                        egui::RichText::new(uncorrected.to_string())
+                                            .strong()
+                                            .color(uncorrected_color(uncorrected)),
+                                    );
+                                    ui.end_row();
+                                }
+                            });
+                        if ecc.has_uncorrected() {
+                            ui.colored_label(
+                                colors.red.to_egui(),
+                                format!(
+                                    "{} Uncorrected memory errors - VRAM data was lost",
+                                    icons::WARN
+                                ),
+                            );
+                        }
+                    }
                 } else {
                     ui.label(
                         egui::RichText::new("No GPU data available").color(colors.yellow.to_egui()),
@@ -206,13 +411,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
             .show(&mut columns[1], |ui| {
                 if let Some(ref stats) = state.gpu_stats {
                     // Temperature
-                    let temp_color = if stats.temperature > 80.0 {
-                        colors.red.to_egui()
-                    } else if stats.temperature > 65.0 {
-                        colors.yellow.to_egui()
-                    } else {
-                        colors.green.to_egui()
-                    };
+                    let temp_color = colors.temp_color(stats.temperature).to_egui();
 
                     ui.horizontal(|ui| {
                         ui.label(
@@ -237,6 +436,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                             .color(colors.fg.to_egui()),
                     );
                     let util_bar = egui::ProgressBar::new(stats.utilization / 100.0)
+                        .fill(colors.usage_color(stats.utilization).to_egui())
                         .text(format!("{:.0}%", stats.utilization));
                     ui.add(util_bar);
 
@@ -259,6 +459,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                     ui.add_space(8.0);
 
                     // Power and clocks
+                    let lock = stats.locked_clocks.as_ref();
                     egui::Grid::new("live_stats_grid")
                         .num_columns(2)
                         .spacing([20.0, 4.0])
@@ -270,24 +471,40 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                                     stats.power_draw, stats.power_limit
                                 ))
                                 .strong()
-                                .color(colors.cyan.to_egui()),
+                                .color(
+                                    colors
+                                        .power_color(
+                                            stats.power_draw / stats.power_limit.max(1.0) * 100.0,
+                                        )
+                                        .to_egui(),
+                                ),
                             );
                             ui.end_row();
 
                             ui.label(egui::RichText::new("Core:").color(colors.fg.to_egui()));
-                            ui.label(
-                                egui::RichText::new(format!("{} MHz", stats.core_clock))
-                                    .strong()
-                                    .color(colors.green.to_egui()),
-                            );
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(format!("{} MHz", stats.core_clock))
+                                        .strong()
+                                        .color(colors.green.to_egui()),
+                                );
+                                if let Some(mhz) = lock.and_then(|l| l.gpu_mhz) {
+                                    locked_badge(ui, &colors, mhz);
+                                }
+                            });
                             ui.end_row();
 
                             ui.label(egui::RichText::new("Memory:").color(colors.fg.to_egui()));
-                            ui.label(
-                                egui::RichText::new(format!("{} MHz", stats.memory_clock))
-                                    .strong()
-                                    .color(colors.purple.to_egui()),
-                            );
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(format!("{} MHz", stats.memory_clock))
+                                        .strong()
+                                        .color(colors.purple.to_egui()),
+                                );
+                                if let Some(mhz) = lock.and_then(|l| l.mem_mhz) {
+                                    locked_badge(ui, &colors, mhz);
+                                }
+                            });
                             ui.end_row();
 
                             ui.label(egui::RichText::new("Fan:").color(colors.fg.to_egui()));
@@ -310,7 +527,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                         ui.add_space(4.0);
 
                         egui::Grid::new("sparklines_grid")
-                            .num_columns(2)
+                            .num_columns(3)
                             .spacing([8.0, 4.0])
                             .show(ui, |ui| {
                                 // Temperature sparkline
@@ -320,6 +537,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                                         .color(colors.fg_dark.to_egui()),
                                 );
                                 draw_sparkline(ui, &state.temp_history, temp_color, 20.0, 100.0);
+                                if export_graph_button(ui, state, GraphMetric::Temperature) {
+                                    export = Some(GraphMetric::Temperature);
+                                }
                                 ui.end_row();
 
                                 // Utilization sparkline
@@ -335,6 +555,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                                     20.0,
                                     100.0,
                                 );
+                                if export_graph_button(ui, state, GraphMetric::Utilization) {
+                                    export = Some(GraphMetric::Utilization);
+                                }
                                 ui.end_row();
 
                                 // Power sparkline
@@ -350,6 +573,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                                     20.0,
                                     stats.power_limit.max(450.0),
                                 );
+                                if export_graph_button(ui, state, GraphMetric::Power) {
+                                    export = Some(GraphMetric::Power);
+                                }
                                 ui.end_row();
                             });
                     }
@@ -361,9 +587,169 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                 }
             });
     });
+    if let Some(metric) = export {
+        state.open_graph_export(metric);
+    }
 
     ui.add_space(8.0);
 
+    // NVLink is hidden entirely on systems without it
+    state.refresh_nvlink();
+    if let Some(topology) = &state.nvlink {
+        Card::new(&colors)
+            .title("NVLink Topology")
+            .icon(icons::GPU)
+            .show(ui, |ui| {
+                let matrix = topology.link_matrix();
+                egui::Grid::new("nvlink_matrix")
+                    .num_columns(topology.gpus.len() + 1)
+                    .spacing([16.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for (index, _) in &topology.gpus {
+                            ui.label(egui::RichText::new(format!("GPU{}", index)).strong());
+                        }
+                        ui.end_row();
+                        for (row, (index, name)) in topology.gpus.iter().enumerate() {
+                            ui.label(egui::RichText::new(format!("GPU{}", index)).strong())
+                                .on_hover_text(name);
+                            for (col, &count) in matrix[row].iter().enumerate() {
+                                let (text, color) = if row == col {
+                                    ("X".to_string(), colors.comment)
+                                } else if count > 0 {
+                                    (format!("NV{}", count), colors.green)
+                                } else {
+                                    ("PCIe".to_string(), colors.fg_dark)
+                                };
+                                ui.label(
+                                    egui::RichText::new(text).monospace().color(color.to_egui()),
+                                );
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.label(
+                    egui::RichText::new(
+                        "NV# = active NVLinks between the pair; PCIe = no direct link",
+                    )
+                    .small()
+                    .color(colors.comment.to_egui()),
+                );
+
+                ui.add_space(4.0);
+                let active = topology.links.iter().filter(|l| l.active).count();
+                egui::CollapsingHeader::new(format!(
+                    "{} of {} link(s) active",
+                    active,
+                    topology.links.len()
+                ))
+                .id_salt("nvlink_links")
+                .show(ui, |ui| {
+                    for link in &topology.links {
+                        let peer = match (link.remote_gpu, &link.remote_pci_bus_id) {
+                            (Some(gpu), _) => format!("GPU{}", gpu),
+                            (None, Some(bus)) => bus.clone(),
+                            (None, None) => "unknown".to_string(),
+                        };
+                        let version = link
+                            .version
+                            .map(|v| format!(" · NVLink {}", v))
+                            .unwrap_or_default();
+                        let (state_text, color) = if link.active {
+                            ("active", colors.green)
+                        } else {
+                            ("down", colors.red)
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label(format!(
+                                "GPU{} link {} → {}{}",
+                                link.gpu, link.link, peer, version
+                            ));
+                            ui.colored_label(color.to_egui(), state_text);
+                        });
+                    }
+                });
+
+                ui.add_space(4.0);
+                if topology
+                    .links
+                    .iter()
+                    .any(|l| l.active && l.rx_bytes.is_some())
+                {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new("Throughput").color(colors.fg_dark.to_egui()));
+                        let peak = state
+                            .nvlink_throughput
+                            .iter()
+                            .copied()
+                            .fold(1.0f32, f32::max);
+                        draw_sparkline(
+                            ui,
+                            &state.nvlink_throughput,
+                            colors.cyan.to_egui(),
+                            24.0,
+                            peak,
+                        );
+                        ui.label(format!(
+                            "{:.1} MB/s",
+                            state.nvlink_throughput.back().copied().unwrap_or(0.0)
+                        ));
+                    });
+                } else {
+                    ui.label(
+                        egui::RichText::new(
+                            "Link byte counters aren't enabled, so throughput isn't available",
+                        )
+                        .small()
+                        .color(colors.comment.to_egui()),
+                    );
+                }
+            });
+
+        ui.add_space(8.0);
+    }
+
+    // Video engines are hidden entirely on GPUs without NVENC
+    if let Some(video) = state.gpu_stats.as_ref().and_then(|s| s.video_engines) {
+        Card::new(&colors)
+            .title("Video Engines (NVENC / NVDEC)")
+            .icon(icons::CHART)
+            .show(ui, |ui| {
+                for (label, value) in [
+                    ("Encoder", video.encoder_utilization),
+                    ("Decoder", video.decoder_utilization),
+                ] {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(label).color(colors.fg_dark.to_egui()));
+                        ui.add(
+                            egui::ProgressBar::new(value.min(100) as f32 / 100.0)
+                                .text(format!("{}%", value))
+                                .fill(colors.cyan.to_egui()),
+                        );
+                    });
+                }
+
+                if let Some(sessions) = video.session_count {
+                    let mut details = format!("{} active NVENC session(s)", sessions);
+                    if sessions > 0 {
+                        if let (Some(fps), Some(latency)) =
+                            (video.average_fps, video.average_latency_us)
+                        {
+                            details
+                                .push_str(&format!(" · {} fps avg · {} µs latency", fps, latency));
+                        }
+                    }
+                    ui.label(
+                        egui::RichText::new(details)
+                            .small()
+                            .color(colors.fg_dark.to_egui()),
+                    );
+                }
+            });
+
+        ui.add_space(8.0);
+    }
+
     // ASUS Power Monitor+ Card (if available)
     if state.asus_power_detector.is_some() {
         Card::new(&colors)
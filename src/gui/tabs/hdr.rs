@@ -7,7 +7,7 @@ use eframe::egui;
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
-use crate::hdr::{self, ColorSpace, Eotf, ToneMappingMode};
+use crate::hdr::{self, ColorSpace, Eotf, OutputMapping, ToneMappingMode};
 
 /// Render the HDR tab
 pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
@@ -80,6 +80,79 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
     ui.add_space(8.0);
 
+    // EDID-reported metadata per connector
+    Card::new(&colors)
+        .title("Display Metadata (EDID)")
+        .icon(icons::DISPLAY)
+        .show(ui, |ui| {
+            let mut shown = 0;
+            for index in 0.. {
+                let Ok(metadata) = crate::display::get_hdr_metadata(index) else {
+                    break;
+                };
+                let Some(connector) = metadata.connector.clone() else {
+                    break;
+                };
+                shown += 1;
+
+                ui.label(
+                    egui::RichText::new(&connector)
+                        .strong()
+                        .color(colors.cyan.to_egui()),
+                );
+                if !metadata.is_available() {
+                    ui.colored_label(colors.fg_dark.to_egui(), "No HDR metadata advertised");
+                    ui.add_space(4.0);
+                    continue;
+                }
+
+                let nits = |value: Option<f32>, precision: usize| {
+                    value.map_or("unavailable".to_string(), |v| {
+                        format!("{:.*} nits", precision, v)
+                    })
+                };
+                egui::Grid::new(("hdr_metadata_grid", index))
+                    .num_columns(2)
+                    .spacing([20.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Max Luminance:");
+                        ui.label(nits(metadata.max_luminance_nits, 0));
+                        ui.end_row();
+
+                        ui.label("Max Frame Average:");
+                        ui.label(nits(metadata.max_frame_avg_nits, 0));
+                        ui.end_row();
+
+                        ui.label("Min Luminance:");
+                        ui.label(nits(metadata.min_luminance_nits, 4));
+                        ui.end_row();
+
+                        ui.label("Transfer Functions:");
+                        let names: Vec<String> = metadata
+                            .transfer_functions
+                            .iter()
+                            .map(|tf| tf.to_string())
+                            .collect();
+                        ui.label(names.join(", "));
+                        ui.end_row();
+
+                        ui.label("Color Primaries:");
+                        ui.label(if metadata.bt2020 { "BT.2020" } else { "BT.709" });
+                        ui.end_row();
+                    });
+                ui.add_space(4.0);
+            }
+
+            if shown == 0 {
+                ui.colored_label(
+                    colors.yellow.to_egui(),
+                    "HDR metadata unavailable - connector EDIDs are not readable from this session",
+                );
+            }
+        });
+
+    ui.add_space(8.0);
+
     // HDR Metadata
     Card::new(&colors)
         .title("HDR Metadata")
@@ -216,6 +289,34 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     });
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Output Mapping:");
+                let current = state.hdr_config.output_mapping;
+                let label = |mapping: OutputMapping| match mapping {
+                    OutputMapping::Passthrough => "Passthrough",
+                    OutputMapping::ForceBt2020 => "Force BT.2020",
+                };
+                let mut selected = current;
+                egui::ComboBox::from_id_salt("output_mapping")
+                    .selected_text(label(current))
+                    .show_ui(ui, |ui| {
+                        for mapping in [OutputMapping::Passthrough, OutputMapping::ForceBt2020] {
+                            ui.selectable_value(&mut selected, mapping, label(mapping));
+                        }
+                    });
+                if selected != current {
+                    match hdr::set_tone_mapping(selected) {
+                        Ok(()) => {
+                            state.hdr_config.output_mapping = selected;
+                            state
+                                .toasts
+                                .success(format!("Output mapping: {}", label(selected)));
+                        }
+                        Err(e) => state.toasts.error(format!("Output mapping: {}", e)),
+                    }
+                }
+            });
+
             ui.add_space(8.0);
 
             // Action buttons
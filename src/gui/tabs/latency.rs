@@ -30,25 +30,25 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     .spacing([20.0, 4.0])
                     .show(ui, |ui| {
                         ui.label("NVIDIA Reflex:");
-                        if info.nvidia_reflex_available {
-                            let (text, color) = if info.nvidia_reflex_enabled {
-                                ("✅ Enabled", colors.green.to_egui())
-                            } else {
-                                ("⚠️ Available", colors.yellow.to_egui())
+                        let (mark, color) =
+                            match (info.nvidia_reflex_available, info.nvidia_reflex_enabled) {
+                                (true, true) => ("✅", colors.green.to_egui()),
+                                (true, false) => ("⚠️", colors.yellow.to_egui()),
+                                (false, _) => ("❌", colors.red.to_egui()),
                             };
-                            ui.colored_label(color, text);
-                        } else {
-                            ui.colored_label(colors.red.to_egui(), "❌ Not Available");
-                        }
+                        ui.colored_label(color, format!("{} {}", mark, info.reflex_status()));
                         ui.end_row();
 
-                        ui.label("GPU Scheduling:");
-                        let (text, color) = if info.gpu_scheduling_enabled {
-                            ("✅ Enabled", colors.green.to_egui())
+                        ui.label("Framebuffer Compression:");
+                        let (mark, color) = if info.framebuffer_compression_enabled {
+                            ("✅", colors.green.to_egui())
                         } else {
-                            ("❌ Disabled", colors.yellow.to_egui())
+                            ("❌", colors.yellow.to_egui())
                         };
-                        ui.colored_label(color, text);
+                        ui.colored_label(
+                            color,
+                            format!("{} {}", mark, info.framebuffer_compression_status()),
+                        );
                         ui.end_row();
 
                         ui.label("CPU Scheduler:");
@@ -88,6 +88,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
             ui.horizontal(|ui| {
                 if ui.button("🏆 Competitive Mode").clicked() {
                     match latency::set_latency_mode(LatencyMode::Competitive) {
+                        Ok(report) if !report.reboot_required.is_empty() => state
+                            .toasts
+                            .warning("Competitive latency mode enabled; preemption timeout needs a reboot (nvctl latency set prints the fix)"),
                         Ok(_) => state.toasts.success("Competitive latency mode enabled"),
                         Err(e) => state.toasts.error(format!("Failed: {}", e)),
                     }
@@ -98,6 +101,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
             ui.horizontal(|ui| {
                 if ui.button("⚖️ Balanced Mode").clicked() {
                     match latency::set_latency_mode(LatencyMode::Balanced) {
+                        Ok(report) if !report.reboot_required.is_empty() => state
+                            .toasts
+                            .warning("Balanced latency mode enabled; preemption timeout needs a reboot (nvctl latency set prints the fix)"),
                         Ok(_) => state.toasts.success("Balanced latency mode enabled"),
                         Err(e) => state.toasts.error(format!("Failed: {}", e)),
                     }
@@ -108,6 +114,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
             ui.horizontal(|ui| {
                 if ui.button("🔋 Power Saver").clicked() {
                     match latency::set_latency_mode(LatencyMode::PowerSaver) {
+                        Ok(report) if !report.reboot_required.is_empty() => state
+                            .toasts
+                            .warning("Power saver mode enabled; preemption timeout needs a reboot (nvctl latency set prints the fix)"),
                         Ok(_) => state.toasts.success("Power saver mode enabled"),
                         Err(e) => state.toasts.error(format!("Failed: {}", e)),
                     }
@@ -118,6 +127,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
             ui.horizontal(|ui| {
                 if ui.button("🔄 Reset to Default").clicked() {
                     match latency::set_latency_mode(LatencyMode::Default) {
+                        Ok(report) if !report.reboot_required.is_empty() => state
+                            .toasts
+                            .warning("Latency settings reset; preemption timeout needs a reboot (nvctl latency set prints the fix)"),
                         Ok(_) => state.toasts.success("Latency settings reset"),
                         Err(e) => state.toasts.error(format!("Failed: {}", e)),
                     }
@@ -2,8 +2,10 @@
 //!
 //! Each tab is a separate module for maintainability.
 
+pub mod containers;
 pub mod display;
 pub mod dlss;
+pub mod events;
 pub mod fan;
 pub mod game_profiles;
 pub mod gamescope;
@@ -11,21 +13,50 @@ pub mod gpu;
 pub mod hdr;
 pub mod latency;
 pub mod osd;
+pub mod overclock;
 pub mod power;
-// Overclocking remains CLI-only on Wayland, so the GUI overclock tab stays disabled.
-// pub mod overclock;
 pub mod recording;
 pub mod settings;
+pub mod shaders;
 pub mod support;
 pub mod system;
 pub mod vibrance;
 pub mod vrr;
 
+use eframe::egui;
+
+use crate::cli_hint::CliCommand;
+use crate::gui::state::GuiState;
+
+/// "Apply to all GPUs" toggle shared by the Overclock, Power and Fan tabs
+pub fn apply_to_all_checkbox(ui: &mut egui::Ui, state: &mut GuiState) {
+    ui.add_enabled(
+        state.available_gpus.len() > 1,
+        egui::Checkbox::new(&mut state.apply_to_all_gpus, "Apply to all GPUs"),
+    )
+    .on_hover_text("GPUs that don't support a control are skipped and listed in the result")
+    .on_disabled_hover_text("Only one GPU detected");
+}
+
+/// Show the `nvctl` equivalent of a control in its tooltip; right-click
+/// copies it
+pub fn cli_hint(response: egui::Response, command: &CliCommand) -> egui::Response {
+    let response = response.on_hover_text(command.hover_text());
+    response.context_menu(|ui| {
+        if ui.button("Copy command").clicked() {
+            ui.ctx().copy_text(command.to_string());
+            ui.close();
+        }
+    });
+    response
+}
+
 /// All available tabs in the GUI
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Tab {
     // Core GPU tabs
     Gpu,
+    Overclock,
     Fan,
     // Display tabs
     Display,
@@ -40,9 +71,12 @@ pub enum Tab {
     Latency,
     Gamescope,
     Recording,
+    Shaders,
     // System
     System,
+    Containers,
     Support,
+    Events,
     Settings,
 }
 
@@ -68,6 +102,7 @@ impl Tab {
         vec![
             // Core GPU
             (Tab::Gpu, super::icons::GPU, "GPU Status"),
+            (Tab::Overclock, super::icons::OVERCLOCK, "Overclock"),
             (Tab::Fan, super::icons::FAN_ICON, "Fan Control"),
             // Display
             (Tab::Display, super::icons::DISPLAY, "Display"),
@@ -82,9 +117,12 @@ impl Tab {
             (Tab::Latency, super::icons::LATENCY, "Latency"),
             (Tab::Gamescope, super::icons::ROCKET, "Gamescope"),
             (Tab::Recording, super::icons::RECORD, "Recording"),
+            (Tab::Shaders, super::icons::SHADER, "Shader Cache"),
             // System
             (Tab::System, super::icons::SYSTEM, "System"),
+            (Tab::Containers, super::icons::CONTAINER, "Containers"),
             (Tab::Support, super::icons::BENCHMARK, "Support"),
+            (Tab::Events, super::icons::EVENTS, "Event Log"),
             (Tab::Settings, super::icons::SETTINGS, "Settings"),
         ]
     }
@@ -1,29 +1,15 @@
 //! OSD (On-Screen Display) Tab
 //!
 //! MangoHud configuration for in-game performance overlay.
-//! Provides real-time FPS, temperatures, and GPU metrics during gameplay.
+//! Edits MangoHud.conf in place: metrics, graphs, colors, layout and logging,
+//! keeping any options the editor doesn't cover.
 
 use eframe::egui;
 
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
-
-/// All available OSD metrics with their display names
-const OSD_METRICS: &[(&str, &str)] = &[
-    ("fps", "FPS Counter"),
-    ("frametime", "Frame Time Graph"),
-    ("gpu_temp", "GPU Temperature"),
-    ("gpu_load", "GPU Utilization"),
-    ("gpu_core_clock", "GPU Clock Speed"),
-    ("gpu_power", "GPU Power Draw"),
-    ("vram", "VRAM Usage"),
-    ("fan", "Fan Speed"),
-    ("cpu_temp", "CPU Temperature"),
-    ("cpu_load", "CPU Utilization"),
-    ("ram", "RAM Usage"),
-    ("gpu_name", "GPU Name"),
-];
+use crate::osd::{MANGOHUD_GRAPHS, MANGOHUD_METRICS, MANGOHUD_POSITIONS, MangoHudConfig};
 
 /// Render the OSD tab
 pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
@@ -108,37 +94,67 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
     ui.add_space(8.0);
 
-    // Position Card
+    // Layout Card
     Card::new(&colors)
-        .title("Position")
+        .title("Layout")
         .icon(icons::DISPLAY)
         .show(ui, |ui| {
-            let positions = [
-                ("top-left", "Top Left"),
-                ("top-right", "Top Right"),
-                ("bottom-left", "Bottom Left"),
-                ("bottom-right", "Bottom Right"),
-            ];
-
-            egui::ComboBox::from_label("OSD Position")
-                .selected_text(
-                    positions
-                        .iter()
-                        .find(|(id, _)| *id == state.osd_position)
-                        .map(|(_, name)| *name)
-                        .unwrap_or("Top Left"),
-                )
-                .show_ui(ui, |ui| {
-                    for (id, name) in positions {
-                        if ui
-                            .selectable_label(state.osd_position == id, name)
-                            .clicked()
-                        {
-                            state.osd_position = id.to_string();
-                            save_mangohud_config(state);
-                        }
-                    }
+            let mut changed = false;
+            egui::Grid::new("osd_layout_grid")
+                .num_columns(2)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("Position:");
+                    egui::ComboBox::from_id_salt("osd_position")
+                        .selected_text(&state.osd_config.position)
+                        .show_ui(ui, |ui| {
+                            for position in MANGOHUD_POSITIONS {
+                                changed |= ui
+                                    .selectable_value(
+                                        &mut state.osd_config.position,
+                                        position.to_string(),
+                                        *position,
+                                    )
+                                    .changed();
+                            }
+                        });
+                    ui.end_row();
+
+                    ui.label("Offset:");
+                    ui.horizontal(|ui| {
+                        changed |= ui
+                            .add(egui::DragValue::new(&mut state.osd_config.offset_x).prefix("x "))
+                            .changed();
+                        changed |= ui
+                            .add(egui::DragValue::new(&mut state.osd_config.offset_y).prefix("y "))
+                            .changed();
+                    });
+                    ui.end_row();
+
+                    ui.label("Font Size:");
+                    changed |= ui
+                        .add(
+                            egui::Slider::new(&mut state.osd_config.font_size, 12..=48)
+                                .suffix("px"),
+                        )
+                        .changed();
+                    ui.end_row();
+
+                    ui.label("Background Opacity:");
+                    changed |= ui
+                        .add(egui::Slider::new(
+                            &mut state.osd_config.background_alpha,
+                            0.0..=1.0,
+                        ))
+                        .changed();
+                    ui.end_row();
                 });
+            changed |= ui
+                .checkbox(&mut state.osd_config.horizontal, "Horizontal layout")
+                .changed();
+            if changed {
+                save_mangohud_config(state);
+            }
         });
 
     ui.add_space(8.0);
@@ -148,52 +164,167 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
         .title("Metrics to Display")
         .icon(icons::CHART)
         .show(ui, |ui| {
+            let mut changed = false;
             ui.columns(2, |columns| {
-                for (i, (metric_id, metric_name)) in OSD_METRICS.iter().enumerate() {
-                    let col = i % 2;
-                    let mut enabled = state.osd_metrics.contains(&metric_id.to_string());
-                    if columns[col].checkbox(&mut enabled, *metric_name).changed() {
-                        if enabled {
-                            if !state.osd_metrics.contains(&metric_id.to_string()) {
-                                state.osd_metrics.push(metric_id.to_string());
-                            }
-                        } else {
-                            state.osd_metrics.retain(|m| m != *metric_id);
-                        }
-                        save_mangohud_config(state);
-                    }
+                for (i, (key, name)) in MANGOHUD_METRICS.iter().enumerate() {
+                    changed |= toggle_key(
+                        &mut columns[i % 2],
+                        &mut state.osd_config.metrics,
+                        key,
+                        name,
+                    );
                 }
             });
+            if changed {
+                save_mangohud_config(state);
+            }
         });
 
     ui.add_space(8.0);
 
-    // Appearance Card
+    // Graphs Card
     Card::new(&colors)
-        .title("Appearance")
-        .icon(icons::VIBRANCE)
+        .title("Graphs")
+        .icon(icons::CHART)
         .show(ui, |ui| {
+            let mut changed = false;
             ui.horizontal(|ui| {
-                ui.label("Font Size:");
-                let mut font_size = state.osd_font_size as i32;
-                if ui
-                    .add(egui::Slider::new(&mut font_size, 12..=48).suffix("px"))
-                    .changed()
-                {
-                    state.osd_font_size = font_size as u32;
-                    save_mangohud_config(state);
-                }
+                changed |= ui
+                    .checkbox(&mut state.osd_config.frame_timing, "Frame timing graph")
+                    .changed();
+                changed |= ui
+                    .add_enabled(
+                        state.osd_config.frame_timing,
+                        egui::Checkbox::new(&mut state.osd_config.histogram, "As histogram"),
+                    )
+                    .changed();
             });
-
-            ui.horizontal(|ui| {
-                ui.label("Background Opacity:");
-                if ui
-                    .add(egui::Slider::new(&mut state.osd_opacity, 0.0..=1.0))
-                    .changed()
-                {
-                    save_mangohud_config(state);
+            ui.add_space(4.0);
+            ui.columns(2, |columns| {
+                for (i, (key, name)) in MANGOHUD_GRAPHS.iter().enumerate() {
+                    changed |=
+                        toggle_key(&mut columns[i % 2], &mut state.osd_config.graphs, key, name);
                 }
             });
+            if changed {
+                save_mangohud_config(state);
+            }
+        });
+
+    ui.add_space(8.0);
+
+    // Colors Card
+    Card::new(&colors)
+        .title("Colors")
+        .icon(icons::VIBRANCE)
+        .show(ui, |ui| {
+            let mut changed = false;
+            let config = &mut state.osd_config;
+            egui::Grid::new("osd_colors_grid")
+                .num_columns(2)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, color) in [
+                        ("Text", &mut config.text_color),
+                        ("GPU", &mut config.gpu_color),
+                        ("CPU", &mut config.cpu_color),
+                        ("Frame time", &mut config.frametime_color),
+                        ("Background", &mut config.background_color),
+                    ] {
+                        ui.label(label);
+                        changed |= ui.color_edit_button_srgb(color).changed();
+                        ui.end_row();
+                    }
+                });
+            if ui.button("Reset colors").clicked() {
+                let defaults = MangoHudConfig::default();
+                config.text_color = defaults.text_color;
+                config.gpu_color = defaults.gpu_color;
+                config.cpu_color = defaults.cpu_color;
+                config.frametime_color = defaults.frametime_color;
+                config.background_color = defaults.background_color;
+                changed = true;
+            }
+            if changed {
+                save_mangohud_config(state);
+            }
+        });
+
+    ui.add_space(8.0);
+
+    // Logging & Hotkeys Card
+    Card::new(&colors)
+        .title("Logging & Hotkeys")
+        .icon(icons::LIST)
+        .show(ui, |ui| {
+            let mut changed = false;
+            let config = &mut state.osd_config;
+            egui::Grid::new("osd_logging_grid")
+                .num_columns(2)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label("FPS limit:");
+                    let mut limit = config.fps_limit.unwrap_or(0);
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut limit)
+                                .range(0..=1000)
+                                .suffix(" fps"),
+                        )
+                        .on_hover_text("0 disables the limiter")
+                        .changed()
+                    {
+                        config.fps_limit = (limit > 0).then_some(limit);
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label("Toggle HUD:");
+                    changed |= ui
+                        .add(
+                            egui::TextEdit::singleline(&mut config.toggle_hud).desired_width(140.0),
+                        )
+                        .lost_focus();
+                    ui.end_row();
+
+                    let mut logging = config.output_folder.is_some();
+                    if ui.checkbox(&mut logging, "Benchmark logging").changed() {
+                        config.output_folder = logging.then(|| {
+                            dirs::home_dir()
+                                .map(|home| home.join("mangohud_logs").display().to_string())
+                                .unwrap_or_else(|| "/tmp/mangohud_logs".to_string())
+                        });
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    if let Some(folder) = &mut config.output_folder {
+                        ui.label("Output folder:");
+                        changed |= ui
+                            .add(egui::TextEdit::singleline(folder).desired_width(240.0))
+                            .lost_focus();
+                        ui.end_row();
+
+                        ui.label("Log duration:");
+                        changed |= ui
+                            .add(egui::DragValue::new(&mut config.log_duration).suffix(" s"))
+                            .on_hover_text("0 logs until stopped")
+                            .changed();
+                        ui.end_row();
+
+                        ui.label("Toggle logging:");
+                        changed |= ui
+                            .add(
+                                egui::TextEdit::singleline(&mut config.toggle_logging)
+                                    .desired_width(140.0),
+                            )
+                            .lost_focus();
+                        ui.end_row();
+                    }
+                });
+            if changed {
+                save_mangohud_config(state);
+            }
         });
 
     ui.add_space(8.0);
@@ -203,75 +334,60 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
         .title("Quick Presets")
         .icon(icons::ROCKET)
         .show(ui, |ui| {
-            ui.horizontal(|ui| {
-                if ui.button("Minimal").on_hover_text("FPS only").clicked() {
-                    state.osd_metrics = vec!["fps".to_string()];
-                    state.osd_font_size = 24;
-                    state.osd_opacity = 0.5;
-                    save_mangohud_config(state);
-                    state.toasts.info("Minimal preset applied");
-                }
-
-                if ui
-                    .button("Standard")
-                    .on_hover_text("FPS, frametime, GPU temp/load, VRAM")
-                    .clicked()
-                {
-                    state.osd_metrics = vec![
-                        "fps".to_string(),
-                        "frametime".to_string(),
-                        "gpu_temp".to_string(),
-                        "gpu_load".to_string(),
-                        "vram".to_string(),
-                    ];
-                    state.osd_font_size = 24;
-                    state.osd_opacity = 0.8;
-                    save_mangohud_config(state);
-                    state.toasts.info("Standard preset applied");
-                }
-
-                if ui
-                    .button("Full")
-                    .on_hover_text("All GPU and CPU metrics")
-                    .clicked()
-                {
-                    state.osd_metrics = vec![
-                        "fps".to_string(),
-                        "frametime".to_string(),
-                        "gpu_temp".to_string(),
-                        "gpu_load".to_string(),
-                        "gpu_core_clock".to_string(),
-                        "gpu_power".to_string(),
-                        "vram".to_string(),
-                        "fan".to_string(),
-                        "cpu_temp".to_string(),
-                        "cpu_load".to_string(),
-                        "ram".to_string(),
-                    ];
-                    state.osd_font_size = 20;
-                    state.osd_opacity = 0.9;
-                    save_mangohud_config(state);
-                    state.toasts.info("Full preset applied");
-                }
+            let presets: [(&str, &str, &[&str], u32, f32); 4] = [
+                ("Minimal", "FPS only", &["fps"], 24, 0.5),
+                (
+                    "Standard",
+                    "FPS, frametime, GPU temp/load, VRAM",
+                    &["fps", "frametime", "gpu_stats", "gpu_temp", "vram"],
+                    24,
+                    0.8,
+                ),
+                (
+                    "Full",
+                    "All GPU and CPU metrics",
+                    &[
+                        "fps",
+                        "frametime",
+                        "gpu_stats",
+                        "gpu_temp",
+                        "gpu_core_clock",
+                        "gpu_power",
+                        "vram",
+                        "fan",
+                        "cpu_stats",
+                        "cpu_temp",
+                        "ram",
+                    ],
+                    20,
+                    0.9,
+                ),
+                (
+                    "Benchmark",
+                    "Optimized for benchmarking/reviews",
+                    &[
+                        "fps",
+                        "frametime",
+                        "gpu_stats",
+                        "gpu_temp",
+                        "gpu_core_clock",
+                        "gpu_power",
+                        "cpu_stats",
+                    ],
+                    28,
+                    0.9,
+                ),
+            ];
 
-                if ui
-                    .button("Benchmark")
-                    .on_hover_text("Optimized for benchmarking/reviews")
-                    .clicked()
-                {
-                    state.osd_metrics = vec![
-                        "fps".to_string(),
-                        "frametime".to_string(),
-                        "gpu_temp".to_string(),
-                        "gpu_load".to_string(),
-                        "gpu_core_clock".to_string(),
-                        "gpu_power".to_string(),
-                        "cpu_load".to_string(),
-                    ];
-                    state.osd_font_size = 28;
-                    state.osd_opacity = 0.9;
-                    save_mangohud_config(state);
-                    state.toasts.info("Benchmark preset applied");
+            ui.horizontal(|ui| {
+                for (name, hint, metrics, font_size, alpha) in presets {
+                    if ui.button(name).on_hover_text(hint).clicked() {
+                        state.osd_config.metrics = metrics.iter().map(|m| m.to_string()).collect();
+                        state.osd_config.font_size = font_size;
+                        state.osd_config.background_alpha = alpha;
+                        save_mangohud_config(state);
+                        state.toasts.info(format!("{} preset applied", name));
+                    }
                 }
             });
         });
@@ -283,7 +399,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
         .title("Config Preview (MangoHud.conf)")
         .icon(icons::INFO)
         .show(ui, |ui| {
-            let config_preview = generate_mangohud_config(state);
+            let config_preview = state.osd_config.to_conf_string();
 
             egui::ScrollArea::vertical()
                 .max_height(150.0)
@@ -298,51 +414,45 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
             ui.add_space(4.0);
 
-            let config_path = dirs::config_dir()
-                .map(|p| p.join("MangoHud").join("MangoHud.conf"))
-                .map(|p| p.display().to_string())
-                .unwrap_or_else(|| "~/.config/MangoHud/MangoHud.conf".to_string());
-
-            ui.label(
-                egui::RichText::new(format!("Saved to: {}", config_path))
-                    .small()
-                    .weak(),
-            );
+            ui.horizontal(|ui| {
+                let config_path = MangoHudConfig::default_path()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "~/.config/MangoHud/MangoHud.conf".to_string());
+                ui.label(
+                    egui::RichText::new(format!("Saved to: {}", config_path))
+                        .small()
+                        .weak(),
+                );
+                if ui
+                    .small_button(format!("{} Reload", icons::REFRESH))
+                    .on_hover_text("Discard edits and re-read MangoHud.conf from disk")
+                    .clicked()
+                {
+                    state.osd_config = MangoHudConfig::load().unwrap_or_default();
+                }
+            });
         });
 }
 
-/// Generate MangoHud configuration content
-fn generate_mangohud_config(state: &GuiState) -> String {
-    let mut lines = vec![
-        "# nvcontrol MangoHud configuration".to_string(),
-        "# Auto-generated by nvcontrol GUI".to_string(),
-        "".to_string(),
-        format!("position={}", state.osd_position),
-        "".to_string(),
-    ];
-
-    // Add enabled metrics
-    for metric in &state.osd_metrics {
-        lines.push(metric.clone());
+/// Checkbox that adds or removes `key` from an ordered key list
+fn toggle_key(ui: &mut egui::Ui, keys: &mut Vec<String>, key: &str, label: &str) -> bool {
+    let mut enabled = keys.iter().any(|k| k == key);
+    if !ui.checkbox(&mut enabled, label).changed() {
+        return false;
     }
-
-    lines.push("".to_string());
-    lines.push(format!("font_size={}", state.osd_font_size));
-    lines.push(format!("background_alpha={:.2}", state.osd_opacity));
-
-    lines.join("\n")
+    if enabled {
+        keys.push(key.to_string());
+    } else {
+        keys.retain(|k| k != key);
+    }
+    true
 }
 
 /// Save MangoHud configuration to disk
-fn save_mangohud_config(state: &GuiState) {
-    if let Some(config_dir) = dirs::config_dir() {
-        let mangohud_dir = config_dir.join("MangoHud");
-        if std::fs::create_dir_all(&mangohud_dir).is_ok() {
-            let config_path = mangohud_dir.join("MangoHud.conf");
-            let config_content = generate_mangohud_config(state);
-            if let Err(e) = std::fs::write(&config_path, &config_content) {
-                eprintln!("Failed to save MangoHud config: {}", e);
-            }
-        }
+fn save_mangohud_config(state: &mut GuiState) {
+    if let Err(e) = state.osd_config.save() {
+        state
+            .toasts
+            .error(format!("Failed to save MangoHud config: {}", e));
     }
 }
@@ -0,0 +1,715 @@
+//! Overclock Tab
+//!
+//! Clock offsets and power limit with apply/revert. Applying offsets needs
+//! nvidia-settings on X11; on Wayland the CLI remains the supported path.
+
+use eframe::egui;
+
+use crate::cli_hint::CliCommand;
+use crate::gpu_profiles::{OcPreset, preset_offsets};
+use crate::gui::icons;
+use crate::gui::state::GuiState;
+use crate::gui::widgets::{Card, StatusBadge};
+
+/// Render the Overclock tab
+/// Sustained load followed by the cooldown back to idle, plotted live
+fn render_thermal_soak(
+    ui: &mut egui::Ui,
+    state: &mut GuiState,
+    ctx: &egui::Context,
+    colors: &crate::themes::ColorPalette,
+) {
+    state.poll_thermal_soak();
+    let soaking = state.soak_rx.is_some();
+    if soaking {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    Card::new(colors)
+        .title("Thermal Soak")
+        .icon(icons::TEMP)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Load:");
+                ui.add_enabled(
+                    !soaking,
+                    egui::Slider::new(&mut state.soak_load_minutes, 1..=30).suffix(" min"),
+                );
+                if ui
+                    .add_enabled(!soaking, egui::Button::new("🌡 Run"))
+                    .on_hover_text(format!(
+                        "Full load, then records the cooldown back to idle. \
+                         Stops early at {}°C",
+                        crate::benchmark::SOAK_CRITICAL_TEMP_C
+                    ))
+                    .clicked()
+                {
+                    state.start_thermal_soak();
+                }
+                if soaking {
+                    ui.spinner();
+                    if ui.button("Cancel").clicked() {
+                        state.cancel_thermal_soak();
+                    }
+                }
+            });
+
+            if state.soak_samples.is_empty() {
+                ui.label(
+                    egui::RichText::new(
+                        "Compare runs before and after a repaste or fan curve change",
+                    )
+                    .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            }
+
+            let points = |phase: crate::benchmark::SoakPhase| -> Vec<[f64; 2]> {
+                state
+                    .soak_samples
+                    .iter()
+                    .filter(|s| s.phase == phase)
+                    .map(|s| [s.elapsed_secs, s.temperature_c as f64])
+                    .collect()
+            };
+            let load = points(crate::benchmark::SoakPhase::Load);
+            let cooldown = points(crate::benchmark::SoakPhase::Cooldown);
+            egui_plot::Plot::new("thermal_soak_plot")
+                .height(180.0)
+                .include_y(0.0)
+                .x_axis_label("Time (s)")
+                .y_axis_label("Temperature (°C)")
+                .allow_drag(false)
+                .allow_scroll(false)
+                .legend(egui_plot::Legend::default())
+                .show(ui, |plot_ui| {
+                    plot_ui.line(
+                        egui_plot::Line::new("Load", egui_plot::PlotPoints::from(load))
+                            .color(colors.red.to_egui())
+                            .width(2.0),
+                    );
+                    plot_ui.line(
+                        egui_plot::Line::new("Cooldown", egui_plot::PlotPoints::from(cooldown))
+                            .color(colors.cyan.to_egui())
+                            .width(2.0),
+                    );
+                });
+
+            if soaking {
+                return;
+            }
+            let Some(report) = &state.last_soak else {
+                return;
+            };
+            let steady = report
+                .steady_state_temp_c
+                .map(|t| format!("{:.1}°C", t))
+                .unwrap_or_else(|| "n/a".to_string());
+            let to_idle = report
+                .time_to_idle_secs
+                .map(|s| format!("{:.0}s", s))
+                .unwrap_or_else(|| "not reached".to_string());
+            ui.label(
+                egui::RichText::new(format!(
+                    "Idle {}°C · peak {}°C · steady state {} · time to idle {}",
+                    report.idle_temp_c, report.peak_temp_c, steady, to_idle
+                ))
+                .small(),
+            );
+            if let Some(reason) = &report.aborted {
+                ui.colored_label(
+                    colors.orange.to_egui(),
+                    format!("Stopped early: {}", reason),
+                );
+            }
+        });
+}
+
+pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
+    let colors = state.theme_colors();
+
+    ui.heading(format!("{} Overclock", icons::OVERCLOCK));
+    ui.add_space(4.0);
+
+    let is_wayland = std::env::var("WAYLAND_DISPLAY").is_ok()
+        || std::env::var("XDG_SESSION_TYPE")
+            .map(|s| s == "wayland")
+            .unwrap_or(false);
+
+    if is_wayland {
+        ui.label(
+            egui::RichText::new(format!(
+                "{} Clock offsets require X11. Use CLI: nvctl overclock apply --gpu-offset 100 --memory-offset 500",
+                icons::WARN
+            ))
+            .color(colors.yellow.to_egui()),
+        );
+        ui.add_space(8.0);
+    }
+
+    state.poll_staged_apply();
+    let staging = state.staged_rx.is_some();
+    if staging {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    Card::new(&colors)
+        .title("Clock Offsets")
+        .icon(icons::OVERCLOCK)
+        .show(ui, |ui| {
+            render_presets(ui, state, staging);
+            ui.add_space(8.0);
+
+            egui::Grid::new("oc_offsets_grid")
+                .num_columns(2)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new("GPU Offset").color(colors.fg_dark.to_egui()));
+                    ui.add(
+                        egui::Slider::new(&mut state.oc_profile.gpu_clock_offset, -200..=300)
+                            .suffix(" MHz"),
+                    );
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Memory Offset").color(colors.fg_dark.to_egui()));
+                    ui.add(
+                        egui::Slider::new(&mut state.oc_profile.memory_clock_offset, -500..=1500)
+                            .suffix(" MHz"),
+                    );
+                    ui.end_row();
+
+                    ui.label(egui::RichText::new("Power Limit").color(colors.fg_dark.to_egui()));
+                    let mobile = state.mobile_gpu.is_some();
+                    ui.add_enabled(
+                        !mobile,
+                        egui::Slider::new(&mut state.oc_profile.power_limit, 50..=100).suffix("%"),
+                    )
+                    .on_disabled_hover_text(
+                        "Laptop GPUs take their power budget from the firmware and Dynamic Boost",
+                    );
+                    ui.end_row();
+                });
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                // Offsets only reach the driver through nvidia-settings on X11
+                let apply = ui
+                    .add_enabled(
+                        !staging && !is_wayland,
+                        egui::Button::new(format!("{} Apply OC", icons::OVERCLOCK)),
+                    )
+                    .on_disabled_hover_text("Clock offsets are CLI-only on Wayland");
+                let command = CliCommand::Overclock {
+                    gpu_offset: state.oc_profile.gpu_clock_offset,
+                    memory_offset: state.oc_profile.memory_clock_offset,
+                    power_limit: state
+                        .mobile_gpu
+                        .is_none()
+                        .then_some(u32::from(state.oc_profile.power_limit)),
+                    all: state.apply_to_all_gpus,
+                };
+                if super::cli_hint(apply, &command).clicked() {
+                    state.apply_overclock();
+                }
+                ui.add_enabled(
+                    !staging && !state.apply_to_all_gpus,
+                    egui::Checkbox::new(&mut state.oc_staged, "Staged apply"),
+                )
+                .on_hover_text(
+                    "Ramp the core offset up in 25 MHz steps with a short stability probe \
+                     between each, stopping at the last stable step",
+                );
+                super::apply_to_all_checkbox(ui, state);
+                if staging {
+                    ui.spinner();
+                    if ui.button("Cancel").clicked() {
+                        state.cancel_staged_apply();
+                    }
+                }
+                if ui
+                    .add_enabled(
+                        !is_wayland,
+                        egui::Button::new(format!("{} Revert", icons::REFRESH)),
+                    )
+                    .on_hover_text("Restore the previously applied offsets and power limit")
+                    .on_disabled_hover_text("Use `nvctl overclock revert` on Wayland")
+                    .clicked()
+                {
+                    state.revert_overclock();
+                }
+            });
+
+            if !state.oc_verification.is_empty() {
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new("Read back from the driver")
+                        .small()
+                        .color(colors.fg_dark.to_egui()),
+                );
+                egui::Grid::new("oc_verification_grid")
+                    .num_columns(3)
+                    .spacing([12.0, 2.0])
+                    .show(ui, |ui| {
+                        for check in &state.oc_verification {
+                            let (icon, color) = match check.applied() {
+                                Some(true) => (icons::OK, colors.green),
+                                Some(false) => (icons::ERR, colors.red),
+                                None => (icons::INFO, colors.fg_dark),
+                            };
+                            ui.label(egui::RichText::new(icon).color(color.to_egui()));
+                            ui.label(check.field);
+                            let actual = check
+                                .actual
+                                .map(|v| format!("{}{}", v, check.unit))
+                                .unwrap_or_else(|| "unreadable".to_string());
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "requested {}{}, driver reports {}",
+                                    check.requested, check.unit, actual
+                                ))
+                                .small(),
+                            );
+                            ui.end_row();
+                        }
+                    });
+            }
+
+            if !state.staged_log.is_empty() {
+                ui.add_space(4.0);
+                egui::ScrollArea::vertical()
+                    .id_salt("staged_apply_log")
+                    .max_height(100.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        for line in &state.staged_log {
+                            ui.label(egui::RichText::new(line).small().monospace());
+                        }
+                    });
+            }
+        });
+
+    ui.add_space(8.0);
+
+    render_oc_scanner(ui, state, ctx);
+    ui.add_space(8.0);
+
+    state.poll_efficiency_tuning();
+    let tuning = state.efficiency_rx.is_some();
+    if tuning {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    Card::new(&colors)
+        .title("Efficiency Tuning (Undervolt)")
+        .icon(icons::POWER)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(
+                    "Locks the stock boost clock and raises the core offset step by step, \
+                     running a stress test after each, to find the lowest stable voltage.",
+                )
+                .small()
+                .color(colors.fg_dark.to_egui()),
+            );
+            ui.add_space(4.0);
+
+            ui.horizontal(|ui| {
+                ui.label("Profile name:");
+                ui.add_enabled(
+                    !tuning,
+                    egui::TextEdit::singleline(&mut state.efficiency_profile_name)
+                        .desired_width(140.0),
+                );
+                let can_start = !tuning && !state.efficiency_profile_name.trim().is_empty();
+                if ui
+                    .add_enabled(can_start, egui::Button::new("🌿 Find Efficient Voltage"))
+                    .on_hover_text("Takes several minutes; the GPU is stressed between steps")
+                    .clicked()
+                {
+                    state.start_efficiency_tuning();
+                }
+                if tuning {
+                    ui.spinner();
+                }
+            });
+
+            if !state.efficiency_log.is_empty() {
+                ui.add_space(4.0);
+                egui::ScrollArea::vertical()
+                    .max_height(120.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        for line in &state.efficiency_log {
+                            ui.label(egui::RichText::new(line).small().monospace());
+                        }
+                    });
+            }
+        });
+
+    ui.add_space(8.0);
+
+    state.poll_stress_test();
+    let stressing = state.stress_rx.is_some();
+    if stressing {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    Card::new(&colors)
+        .title("Stress Test")
+        .icon(icons::TEMP)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Duration:");
+                ui.add_enabled(
+                    !stressing,
+                    egui::Slider::new(&mut state.stress_minutes, 1..=30).suffix(" min"),
+                );
+                if ui
+                    .add_enabled(!stressing, egui::Button::new("🔥 Run"))
+                    .on_hover_text(
+                        "Loads the GPU and watches for crashes, Xid errors and throttling",
+                    )
+                    .clicked()
+                {
+                    state.start_stress_test();
+                }
+                if stressing {
+                    ui.spinner();
+                    if ui.button("Cancel").clicked() {
+                        state.cancel_stress_test();
+                    }
+                }
+            });
+
+            let Some(result) = &state.last_stress_result else {
+                ui.label(
+                    egui::RichText::new("No stress test run yet").color(colors.fg_dark.to_egui()),
+                );
+                return;
+            };
+
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                let (badge, color) = if result.passed {
+                    ("PASS", colors.green)
+                } else {
+                    ("FAIL", colors.red)
+                };
+                ui.label(
+                    egui::RichText::new(format!(" {} ", badge))
+                        .strong()
+                        .color(colors.bg.to_egui())
+                        .background_color(color.to_egui()),
+                );
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{}s · max {}°C · throttled: {} · Xid errors: {}",
+                        result.duration_secs,
+                        result.max_temp,
+                        if result.throttled { "yes" } else { "no" },
+                        result.xid_errors
+                    ))
+                    .small(),
+                );
+            });
+            if let Some(reason) = &result.failure_reason {
+                ui.colored_label(colors.red.to_egui(), reason);
+            }
+        });
+
+    ui.add_space(8.0);
+
+    render_thermal_soak(ui, state, ctx, &colors);
+
+    ui.add_space(8.0);
+
+    if state.benchmark_baselines.is_none() {
+        state.refresh_baselines();
+    }
+
+    Card::new(&colors)
+        .title("Benchmark vs Baseline")
+        .icon(icons::BENCHMARK)
+        .show(ui, |ui| {
+            let baselines = state.benchmark_baselines.clone().unwrap_or_default();
+            ui.horizontal(|ui| {
+                ui.label("Baseline:");
+                egui::ComboBox::from_id_salt("benchmark_baseline")
+                    .selected_text(
+                        baselines
+                            .get(state.selected_baseline)
+                            .map(|b| b.name.as_str())
+                            .unwrap_or("none saved"),
+                    )
+                    .show_ui(ui, |ui| {
+                        for (i, baseline) in baselines.iter().enumerate() {
+                            ui.selectable_value(&mut state.selected_baseline, i, &baseline.name);
+                        }
+                    });
+                if ui.button(format!("{} Reload", icons::REFRESH)).clicked() {
+                    state.refresh_baselines();
+                }
+            });
+
+            let Some(run) = state.benchmark_last_run.clone() else {
+                ui.label(
+                    egui::RichText::new("No benchmark run yet - run: nvctl benchmark run")
+                        .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            };
+
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Latest run {}",
+                        run.recorded_at
+                            .with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M")
+                    ))
+                    .small()
+                    .color(colors.fg_dark.to_egui()),
+                );
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.new_baseline_name)
+                        .hint_text("baseline name")
+                        .desired_width(120.0),
+                );
+                if ui
+                    .add_enabled(
+                        !state.new_baseline_name.trim().is_empty(),
+                        egui::Button::new("Save as baseline"),
+                    )
+                    .clicked()
+                {
+                    state.save_last_run_as_baseline();
+                }
+            });
+
+            let Some(baseline) = baselines.get(state.selected_baseline) else {
+                return;
+            };
+            let comparison = crate::benchmark::compare(&run, baseline);
+
+            ui.add_space(4.0);
+            egui::Grid::new("benchmark_compare_grid")
+                .num_columns(4)
+                .spacing([16.0, 2.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for header in ["Metric", "Baseline", "Latest", "Change"] {
+                        ui.label(egui::RichText::new(header).small().strong());
+                    }
+                    ui.end_row();
+                    for delta in &comparison.deltas {
+                        ui.label(&delta.metric);
+                        ui.label(format!("{:.1} {}", delta.baseline, delta.unit));
+                        ui.label(format!("{:.1} {}", delta.current, delta.unit));
+                        let color = match delta.is_improvement() {
+                            Some(true) => colors.green,
+                            Some(false) => colors.red,
+                            None => colors.fg_dark,
+                        };
+                        ui.label(
+                            egui::RichText::new(
+                                delta
+                                    .percent()
+                                    .map(|p| format!("{:+.1}%", p))
+                                    .unwrap_or_else(|| "-".to_string()),
+                            )
+                            .strong()
+                            .color(color.to_egui()),
+                        );
+                        ui.end_row();
+                    }
+                });
+            for warning in &comparison.warnings {
+                ui.colored_label(
+                    colors.yellow.to_egui(),
+                    format!("{} {}", icons::WARN, warning),
+                );
+            }
+        });
+
+    ui.add_space(8.0);
+
+    Card::new(&colors)
+        .title("Recent Changes")
+        .icon(icons::LIST)
+        .show(ui, |ui| {
+            if state.oc_history.is_empty() {
+                ui.label(
+                    egui::RichText::new("No overclock applied yet - revert returns to stock")
+                        .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            }
+
+            for entry in state.oc_history.entries().rev() {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{}  {}: {:+}MHz core, {:+}MHz mem, {}%",
+                        entry
+                            .applied_at
+                            .with_timezone(&chrono::Local)
+                            .format("%H:%M:%S"),
+                        entry.profile.name,
+                        entry.profile.gpu_clock_offset,
+                        entry.profile.memory_clock_offset,
+                        entry.profile.power_limit
+                    ))
+                    .small()
+                    .color(colors.fg.to_egui()),
+                );
+            }
+        });
+}
+
+/// One-click OC scanner; uses the ASUS power ceiling on ROG boards
+/// Preset cards showing the offsets each preset resolves to on this card
+fn render_presets(ui: &mut egui::Ui, state: &mut GuiState, staging: bool) {
+    let colors = state.theme_colors();
+    let gpu_name = state
+        .gpu_stats
+        .as_ref()
+        .map(|stats| stats.name.clone())
+        .unwrap_or_default();
+    let model = preset_offsets(&gpu_name, OcPreset::Stock).model;
+
+    ui.label(
+        egui::RichText::new(match &model {
+            Some(model) => format!("Presets for {}", model),
+            None => "Presets (model not recognised, using conservative values)".to_string(),
+        })
+        .small()
+        .color(colors.fg_dark.to_egui()),
+    );
+    ui.add_space(4.0);
+
+    let mut chosen = None;
+    ui.horizontal_wrapped(|ui| {
+        for preset in OcPreset::ALL {
+            let offsets = preset_offsets(&gpu_name, preset);
+            egui::Frame::new()
+                .fill(colors.bg_highlight.to_egui())
+                .inner_margin(8.0)
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.set_min_width(110.0);
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(preset.label()).strong());
+                        ui.label(
+                            egui::RichText::new(format!("{:+} MHz core", offsets.gpu_offset))
+                                .small(),
+                        );
+                        ui.label(
+                            egui::RichText::new(format!("{:+} MHz mem", offsets.memory_offset))
+                                .small(),
+                        );
+                        if ui
+                            .add_enabled(!staging, egui::Button::new("Apply").small())
+                            .clicked()
+                        {
+                            chosen = Some(preset);
+                        }
+                    });
+                });
+        }
+    });
+
+    if let Some(preset) = chosen {
+        state.apply_oc_preset(preset);
+    }
+}
+
+fn render_oc_scanner(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
+    let colors = state.theme_colors();
+    state.poll_oc_scan();
+    let scanning = state.oc_scan_rx.is_some();
+    if scanning {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    Card::new(&colors)
+        .title("OC Scanner")
+        .icon(icons::GPU)
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                if let Some(board) = state.selected_asus_board() {
+                    StatusBadge::info("ASUS", &colors)
+                        .show(ui)
+                        .on_hover_text(format!(
+                            "{} - the scan raises the power limit to the board's VBIOS ceiling first",
+                            board
+                        ));
+                }
+                ui.label(
+                    egui::RichText::new(
+                        "Ramps the core offset in steps with a GPU load held after each one, \
+                         and saves the last stable step as the \"OC Scanner\" profile.",
+                    )
+                    .small()
+                    .color(colors.fg_dark.to_egui()),
+                );
+            });
+            ui.add_space(4.0);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!scanning, egui::Button::new("🔍 Run OC Scanner"))
+                    .on_hover_text(
+                        "Takes up to 10 minutes; the GPU is held under load between steps",
+                    )
+                    .clicked()
+                {
+                    state.start_oc_scan();
+                }
+                if scanning {
+                    ui.spinner();
+                    ui.label("Scanning...");
+                    if ui.button("Stop").clicked() {
+                        state.cancel_oc_scan();
+                    }
+                }
+            });
+
+            if !state.oc_scan_log.is_empty() {
+                ui.add_space(4.0);
+                egui::ScrollArea::vertical()
+                    .id_salt("oc_scan_log")
+                    .max_height(100.0)
+                    .stick_to_bottom(true)
+                    .show(ui, |ui| {
+                        for line in &state.oc_scan_log {
+                            ui.label(egui::RichText::new(line).small().monospace());
+                        }
+                    });
+            }
+
+            let Some(scan) = &state.last_oc_scan else {
+                return;
+            };
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                if let Some(model) = &scan.asus_model {
+                    StatusBadge::info("ASUS", &colors).show(ui).on_hover_text(model);
+                }
+                let successful = scan.successful();
+                let text = if successful {
+                    format!(
+                        "{:+} MHz core, stable at {} W{}",
+                        scan.sweep.achieved_offset,
+                        scan.power_limit_w,
+                        if scan.power_unlocked { " unlocked" } else { "" }
+                    )
+                } else {
+                    "No stable overclock found - stock settings kept".to_string()
+                };
+                ui.label(egui::RichText::new(text).strong().color(if successful {
+                    colors.green.to_egui()
+                } else {
+                    colors.yellow.to_egui()
+                }));
+            });
+        });
+}
@@ -1,9 +1,37 @@
 use eframe::egui;
 
 use crate::asus_power_detector::{PowerHealth, PowerTrend};
+use crate::cli_hint::CliCommand;
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
+use crate::power::PowerMizerMode;
+
+/// Laptop GPUs take their power budget from the firmware and Dynamic Boost,
+/// so show that state in place of the power limit slider
+fn render_mobile_power(
+    ui: &mut egui::Ui,
+    colors: &crate::themes::ColorPalette,
+    mobile: crate::gpu::MobileGpuStatus,
+) {
+    ui.label(
+        egui::RichText::new(format!("{} Laptop GPU", icons::INFO)).color(colors.cyan.to_egui()),
+    );
+    let boost = if mobile.dynamic_boost {
+        "Dynamic Boost active (nvidia-powerd running) - the driver shifts power between CPU and GPU"
+    } else {
+        "Dynamic Boost inactive - enable nvidia-powerd.service to let the GPU borrow CPU power"
+    };
+    ui.label(egui::RichText::new(boost).color(colors.fg.to_egui()));
+    ui.label(egui::RichText::new(mobile.display_mode()).color(colors.fg_dark.to_egui()));
+    ui.label(
+        egui::RichText::new(
+            "The power limit is set by the laptop firmware; use the vendor's performance mode to raise it",
+        )
+        .color(colors.fg_dark.to_egui())
+        .italics(),
+    );
+}
 
 pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
     let colors = state.theme_colors();
@@ -23,8 +51,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     .spacing([16.0, 6.0])
                     .show(ui, |ui| {
                         ui.label(
-                            egui::RichText::new("Current Draw").color(colors.fg_dark.to_egui()),
-                        );
+                            egui::RichText::new("Board Power").color(colors.fg_dark.to_egui()),
+                        )
+                        .on_hover_text("Whole card: chip, memory, VRMs and fans. Size your PSU against this");
                         ui.label(
                             egui::RichText::new(format!("{:.1}W", stats.power_draw))
                                 .color(colors.cyan.to_egui()),
@@ -32,7 +61,30 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                         ui.end_row();
 
                         ui.label(
-                            egui::RichText::new("Power Limit").color(colors.fg_dark.to_egui()),
+                            egui::RichText::new("Chip Power").color(colors.fg_dark.to_egui()),
+                        )
+                        .on_hover_text("GPU die only, excluding memory and board components");
+                        match stats.chip_power {
+                            Some(chip) => ui.label(
+                                egui::RichText::new(format!("{:.1}W", chip))
+                                    .color(colors.fg.to_egui()),
+                            ),
+                            None => ui.label(
+                                egui::RichText::new(crate::power::BOARD_POWER_ONLY_NOTE)
+                                    .color(colors.fg_dark.to_egui())
+                                    .italics(),
+                            ),
+                        };
+                        ui.end_row();
+
+                        // Laptops report the current TGP, which Dynamic Boost moves around
+                        let limit_label = if state.mobile_gpu.is_some() {
+                            "Current TGP"
+                        } else {
+                            "Power Limit"
+                        };
+                        ui.label(
+                            egui::RichText::new(limit_label).color(colors.fg_dark.to_egui()),
                         );
                         ui.label(
                             egui::RichText::new(format!("{:.1}W", stats.power_limit))
@@ -55,6 +107,77 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                         );
                         ui.end_row();
                     });
+
+                ui.add_space(6.0);
+                if let Some(mobile) = state.mobile_gpu {
+                    render_mobile_power(ui, &colors, mobile);
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.label("Set limit:");
+                        ui.add(
+                            egui::Slider::new(&mut state.power_limit_percent, 50.0..=120.0)
+                                .suffix("%"),
+                        )
+                        .on_hover_text(
+                            "Percent of the default limit, clamped to each board's range",
+                        );
+                        let apply = ui.button(format!("{} Apply", icons::POWER));
+                        let command = CliCommand::PowerLimit {
+                            gpu: state.selected_gpu_index,
+                            percent: state.power_limit_percent.round() as u32,
+                            all: state.apply_to_all_gpus,
+                        };
+                        if super::cli_hint(apply, &command).clicked() {
+                            state.apply_power_limit();
+                        }
+                        super::apply_to_all_checkbox(ui, state);
+                    });
+                }
+
+                ui.add_space(6.0);
+                if state.powermizer_mode.is_none() {
+                    state.refresh_powermizer_mode();
+                }
+                ui.horizontal(|ui| {
+                    ui.label("PowerMizer:");
+                    match state.powermizer_mode.clone() {
+                        Some(Ok(current)) => {
+                            let mut selected = current;
+                            let combo = egui::ComboBox::from_id_salt("powermizer_mode")
+                                .selected_text(selected.label())
+                                .show_ui(ui, |ui| {
+                                    for mode in PowerMizerMode::ALL {
+                                        ui.selectable_value(&mut selected, mode, mode.label());
+                                    }
+                                })
+                                .response
+                                .on_hover_text(
+                                    "Prefer Maximum Performance holds high clocks while any app runs",
+                                );
+                            super::cli_hint(
+                                combo,
+                                &CliCommand::PowerMizer {
+                                    gpu: state.selected_gpu_index,
+                                    mode: current,
+                                },
+                            );
+                            if selected != current {
+                                state.apply_powermizer_mode(selected);
+                            }
+                        }
+                        Some(Err(reason)) => {
+                            ui.label(
+                                egui::RichText::new("unavailable")
+                                    .color(colors.fg_dark.to_egui()),
+                            )
+                            .on_hover_text(reason);
+                        }
+                        None => {}
+                    }
+                    if ui.small_button(icons::REFRESH).clicked() {
+                        state.refresh_powermizer_mode();
+                    }
+                });
             } else {
                 ui.label(
                     egui::RichText::new("Waiting for GPU power data...")
@@ -65,6 +188,121 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
 
     ui.add_space(8.0);
 
+    state.quiet_mode = crate::power::is_quiet_mode();
+    Card::new(&colors)
+        .title("Quiet Mode")
+        .icon(icons::FAN_ICON)
+        .show(ui, |ui| {
+            let limits = state.config.quiet_mode.clone();
+            let mut quiet = state.quiet_mode;
+            let toggle = ui.checkbox(&mut quiet, "Quiet mode");
+            let command = CliCommand::Quiet { enabled: !state.quiet_mode };
+            if super::cli_hint(toggle, &command).changed() {
+                state.set_quiet_mode(quiet);
+            }
+
+            let mut caps = format!(
+                "Fans capped at {}%, power limit {}% of default",
+                limits.fan_max_pct, limits.power_limit_pct
+            );
+            if let Some(mhz) = limits.max_gpu_clock_mhz {
+                caps.push_str(&format!(", core clock pinned at {} MHz", mhz));
+            }
+            ui.label(egui::RichText::new(caps).color(colors.fg_dark.to_egui()));
+            if let Some(active) = crate::power::QuietModeState::load() {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} On since {} - turning it off restores the previous fan curves, power limits and clock locks",
+                        icons::INFO,
+                        active.enabled_at.with_timezone(&chrono::Local).format("%H:%M")
+                    ))
+                    .color(colors.cyan.to_egui()),
+                );
+            }
+        });
+
+    ui.add_space(8.0);
+
+    Card::new(&colors)
+        .title("Power Schedule")
+        .icon(icons::POWER)
+        .show(ui, |ui| {
+            let schedule = &state.power_config.schedule;
+            if !(state.power_config.schedule_enabled && schedule.enabled) {
+                ui.label(
+                    egui::RichText::new(
+                        "Schedule disabled - enable with: nvctl power schedule enable",
+                    )
+                    .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            }
+
+            let active = schedule.active_index();
+            match active.map(|index| &schedule.schedules[index]) {
+                Some(entry) => {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Active: {} ({}) → {}%",
+                            entry.name,
+                            entry.time_range(),
+                            entry.power_limit
+                        ))
+                        .strong()
+                        .color(colors.green.to_egui()),
+                    );
+                }
+                None => {
+                    ui.label(
+                        egui::RichText::new("No slot active right now - power limit left as is")
+                            .color(colors.fg_dark.to_egui()),
+                    );
+                }
+            }
+
+            if let Some(until) = schedule
+                .override_expires_at()
+                .filter(|until| chrono::Utc::now() < *until)
+            {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} Manual override - schedule resumes at {}",
+                        icons::WARN,
+                        until.with_timezone(&chrono::Local).format("%H:%M")
+                    ))
+                    .color(colors.yellow.to_egui()),
+                );
+            }
+
+            ui.add_space(6.0);
+            egui::Grid::new("power_schedule_grid")
+                .num_columns(3)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for (i, entry) in schedule.schedules.iter().enumerate() {
+                        let color = if active == Some(i) {
+                            colors.cyan.to_egui()
+                        } else {
+                            colors.fg_dark.to_egui()
+                        };
+                        let days: Vec<&str> = entry.days.iter().map(|d| d.short_name()).collect();
+                        ui.label(egui::RichText::new(entry.time_range().to_string()).color(color));
+                        ui.label(egui::RichText::new(&entry.name).color(color));
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{}%  {}",
+                                entry.power_limit,
+                                days.join(",")
+                            ))
+                            .color(color),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+
+    ui.add_space(8.0);
+
     Card::new(&colors)
         .title("ASUS Power Monitor+")
         .icon(icons::POWER)
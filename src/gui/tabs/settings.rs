@@ -32,6 +32,8 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                         (ThemeVariant::RogRed, "ROG Red"),
                         (ThemeVariant::MatrixGreen, "Matrix Green"),
                         (ThemeVariant::Cyberpunk, "Cyberpunk"),
+                        (ThemeVariant::Deuteranopia, "Deuteranopia"),
+                        (ThemeVariant::HighContrast, "High Contrast"),
                     ];
 
                     egui::ComboBox::from_id_salt("theme_selector")
@@ -55,10 +57,22 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
 
                 ui.add_space(8.0);
 
-                if ui.button(format!("{} Cycle Theme (Ctrl+T)", icons::REFRESH)).clicked() {
-                    state.cycle_theme();
-                    crate::gui::theme::apply_theme(ctx, state.current_theme);
-                }
+                ui.horizontal(|ui| {
+                    if ui.button(format!("{} Cycle Theme (Ctrl+T)", icons::REFRESH)).clicked() {
+                        state.cycle_theme();
+                        crate::gui::theme::apply_theme(ctx, state.current_theme);
+                    }
+                    if ui
+                        .button(format!("{} Mini Mode", icons::MINI))
+                        .on_hover_text(
+                            "Shrink to a small always-on-top panel with temperature, \
+                             utilization, power and fan speed. Click the panel to restore.",
+                        )
+                        .clicked()
+                    {
+                        state.mini_mode = true;
+                    }
+                });
 
                 ui.add_space(12.0);
                 ui.separator();
@@ -104,10 +118,36 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                 if ui.input(|i| i.pointer.any_released()) {
                     ctx.set_pixels_per_point(state.ui_scale);
                 }
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.add_space(8.0);
+
+                if ui
+                    .checkbox(
+                        &mut state.config.hotplug_reapply,
+                        "Re-apply monitor profiles on hotplug",
+                    )
+                    .on_hover_text(
+                        "Restore connector profiles when a monitor wakes or a KVM switches back \
+                         (takes effect on next launch)",
+                    )
+                    .changed()
+                {
+                    state.save_config();
+                }
             });
 
         columns[0].add_space(8.0);
 
+        render_profile_bundles(&mut columns[0], state, ctx);
+
+        columns[0].add_space(8.0);
+
+        render_alert_rules(&mut columns[0], state);
+
+        columns[0].add_space(8.0);
+
         // System info
         Card::new(&colors)
             .title("System Information")
@@ -324,4 +364,280 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
                     });
             });
     });
+
+    render_profile_apply_dialog(ctx, state);
+}
+
+/// Saved profile bundles with a review step before applying
+fn render_profile_bundles(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
+    let colors = state.theme_colors();
+
+    let loaded_id = egui::Id::new("profile_bundles_loaded");
+    if !ctx.data(|d| d.get_temp::<bool>(loaded_id).unwrap_or(false)) {
+        state.profile_bundles = crate::profile_manager::ProfileManager::new()
+            .and_then(|manager| manager.list_profiles())
+            .map(|bundles| bundles.into_iter().map(|b| b.name).collect())
+            .unwrap_or_default();
+        ctx.data_mut(|d| d.insert_temp(loaded_id, true));
+    }
+
+    Card::new(&colors)
+        .title("Profiles")
+        .icon(icons::LIST)
+        .show(ui, |ui| {
+            if state.profile_bundles.is_empty() {
+                ui.label(
+                    egui::RichText::new("No saved profiles - create one with: nvctl profile save")
+                        .weak()
+                        .italics(),
+                );
+                render_profile_sharing(ui, state);
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("profile_bundle_select")
+                    .selected_text(&state.selected_profile_bundle)
+                    .show_ui(ui, |ui| {
+                        for name in &state.profile_bundles {
+                            ui.selectable_value(
+                                &mut state.selected_profile_bundle,
+                                name.clone(),
+                                name,
+                            );
+                        }
+                    });
+                if ui
+                    .add_enabled(
+                        !state.selected_profile_bundle.is_empty(),
+                        egui::Button::new("Review & Apply…"),
+                    )
+                    .on_hover_text("Show what would change before applying anything")
+                    .clicked()
+                {
+                    state.review_profile_apply();
+                }
+            });
+
+            render_profile_sharing(ui, state);
+        });
+}
+
+/// Export the selected profile to a shareable file, or import one
+fn render_profile_sharing(ui: &mut egui::Ui, state: &mut GuiState) {
+    ui.add_space(4.0);
+    ui.horizontal(|ui| {
+        ui.label("File:");
+        ui.add(
+            egui::TextEdit::singleline(&mut state.profile_share_path)
+                .hint_text("~/profile.json or .toml")
+                .desired_width(220.0),
+        );
+    });
+    ui.horizontal(|ui| {
+        let has_path = !state.profile_share_path.trim().is_empty();
+        if ui
+            .add_enabled(
+                has_path && !state.selected_profile_bundle.is_empty(),
+                egui::Button::new(format!("{} Export", icons::EXPORT)),
+            )
+            .on_hover_text("Write the selected profile, with the GPU it was tuned on, to the file")
+            .clicked()
+        {
+            state.export_profile_bundle();
+        }
+        if ui
+            .add_enabled(has_path, egui::Button::new("Import"))
+            .on_hover_text("Check the file against this GPU and add it to your profiles")
+            .clicked()
+        {
+            state.import_profile_bundle();
+        }
+    });
+}
+
+/// Threshold alert rules from config.toml, checked on every stats poll
+fn render_alert_rules(ui: &mut egui::Ui, state: &mut GuiState) {
+    use crate::monitoring::{AlertMetric, AlertRule, Comparator};
+
+    let colors = state.theme_colors();
+    let mut changed = false;
+    let mut remove = None;
+
+    Card::new(&colors)
+        .title("Alerts")
+        .icon(icons::WARN)
+        .show(ui, |ui| {
+            if state.config.alert_rules.is_empty() {
+                ui.label(
+                    egui::RichText::new("No alert rules - you'll only see built-in warnings")
+                        .weak()
+                        .italics(),
+                );
+            }
+
+            for (i, rule) in state.config.alert_rules.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt(("alert_metric", i))
+                        .width(100.0)
+                        .selected_text(rule.metric.label())
+                        .show_ui(ui, |ui| {
+                            for metric in AlertMetric::ALL {
+                                changed |= ui
+                                    .selectable_value(&mut rule.metric, metric, metric.label())
+                                    .changed();
+                            }
+                        });
+                    egui::ComboBox::from_id_salt(("alert_comparator", i))
+                        .width(40.0)
+                        .selected_text(rule.comparator.symbol())
+                        .show_ui(ui, |ui| {
+                            for comparator in [Comparator::Above, Comparator::Below] {
+                                changed |= ui
+                                    .selectable_value(
+                                        &mut rule.comparator,
+                                        comparator,
+                                        comparator.symbol(),
+                                    )
+                                    .changed();
+                            }
+                        });
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut rule.threshold)
+                                .speed(1.0)
+                                .suffix(rule.metric.unit()),
+                        )
+                        .changed();
+                    ui.label("every");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut rule.cooldown_secs)
+                                .range(0..=86_400)
+                                .suffix(" s"),
+                        )
+                        .on_hover_text("Cooldown between notifications")
+                        .changed();
+                    if ui
+                        .small_button(icons::ERR)
+                        .on_hover_text("Remove rule")
+                        .clicked()
+                    {
+                        remove = Some(i);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Hysteresis").small());
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut rule.hysteresis)
+                                .range(0.0..=50.0)
+                                .speed(0.5),
+                        )
+                        .on_hover_text("How far back past the threshold before it can fire again")
+                        .changed();
+                    ui.label(egui::RichText::new("Command").small());
+                    let mut command = rule.command.clone().unwrap_or_default();
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut command)
+                                .hint_text("optional hook")
+                                .desired_width(140.0),
+                        )
+                        .changed()
+                    {
+                        rule.command = (!command.trim().is_empty()).then_some(command);
+                        changed = true;
+                    }
+                });
+                ui.add_space(4.0);
+            }
+
+            if ui.button("➕ Add rule").clicked() {
+                state.config.alert_rules.push(AlertRule::new(
+                    AlertMetric::Temperature,
+                    Comparator::Above,
+                    85.0,
+                ));
+                changed = true;
+            }
+        });
+
+    if let Some(i) = remove {
+        state.config.alert_rules.remove(i);
+        changed = true;
+    }
+    // DragValues change on every frame while dragging; save once released
+    let dirty_id = egui::Id::new("alert_rules_dirty");
+    let dirty = changed || ui.data(|d| d.get_temp::<bool>(dirty_id).unwrap_or(false));
+    if dirty && !ui.input(|i| i.pointer.any_down()) {
+        state.config.save();
+        ui.data_mut(|d| d.remove::<bool>(dirty_id));
+    } else if dirty {
+        ui.data_mut(|d| d.insert_temp(dirty_id, true));
+    }
+}
+
+/// Confirmation dialog listing each field a profile would change
+fn render_profile_apply_dialog(ctx: &egui::Context, state: &mut GuiState) {
+    use crate::profile_manager::ChangeRisk;
+
+    let Some((target, changes)) = &state.pending_profile_apply else {
+        return;
+    };
+    let colors = state.theme_colors();
+    let mut confirmed = false;
+    let mut cancelled = false;
+
+    let response = egui::Modal::new(egui::Id::new("profile_apply_dialog")).show(ctx, |ui| {
+        ui.heading(format!("Apply profile '{}'?", target.name));
+        ui.label(
+            egui::RichText::new("Only these fields differ from the current state:")
+                .color(colors.fg_dark.to_egui()),
+        );
+        ui.add_space(6.0);
+
+        egui::Grid::new("profile_apply_changes")
+            .num_columns(4)
+            .spacing([16.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for header in ["Field", "Current", "New", "Risk"] {
+                    ui.label(egui::RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for change in changes {
+                    let risk_color = match change.risk {
+                        ChangeRisk::Low => colors.green,
+                        ChangeRisk::Medium => colors.yellow,
+                        ChangeRisk::High => colors.red,
+                    };
+                    ui.label(egui::RichText::new(change.field).monospace());
+                    ui.label(&change.old);
+                    ui.label(egui::RichText::new(&change.new).strong());
+                    ui.label(egui::RichText::new(change.risk.label()).color(risk_color.to_egui()));
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui
+                .button(format!("{} Apply {} changes", icons::OK, changes.len()))
+                .clicked()
+            {
+                confirmed = true;
+            }
+            if ui.button("Cancel").clicked() {
+                cancelled = true;
+            }
+        });
+    });
+
+    if confirmed {
+        state.confirm_profile_apply();
+    } else if cancelled || response.should_close() {
+        state.pending_profile_apply = None;
+    }
 }
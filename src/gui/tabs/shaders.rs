@@ -0,0 +1,177 @@
+//! Shader Cache Tab
+//!
+//! Sizes of the driver, Mesa, DXVK/VKD3D and per-game Steam shader caches,
+//! with confirm-before-clear for each one or all at once.
+
+use eframe::egui;
+
+use crate::gui::icons;
+use crate::gui::state::GuiState;
+use crate::gui::widgets::Card;
+use crate::shaders::ShaderCacheInfo;
+
+/// Render the Shader Cache tab
+pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
+    let colors = state.theme_colors();
+
+    ui.heading(format!("{} Shader Cache", icons::SHADER));
+    ui.add_space(4.0);
+
+    if state.shader_summary.is_none() {
+        state.refresh_shader_summary();
+    }
+    let Some(summary) = state.shader_summary.clone() else {
+        return;
+    };
+
+    ui.horizontal(|ui| {
+        ui.label(
+            egui::RichText::new(format!("Total: {:.1} MB", summary.total_mb()))
+                .strong()
+                .color(colors.cyan.to_egui()),
+        );
+        if ui.button(format!("{} Rescan", icons::REFRESH)).clicked() {
+            state.refresh_shader_summary();
+        }
+        if ui
+            .add_enabled(
+                summary.total_mb() > 0.0,
+                egui::Button::new(format!("{} Clear all", icons::ERR)),
+            )
+            .clicked()
+        {
+            state.pending_shader_clear = Some((
+                "all shader caches".to_string(),
+                summary.all().map(|cache| cache.path.clone()).collect(),
+            ));
+        }
+    });
+    ui.add_space(8.0);
+
+    Card::new(&colors)
+        .title("Shared Caches")
+        .icon(icons::GPU)
+        .show(ui, |ui| {
+            if summary.locations.is_empty() {
+                ui.label(
+                    egui::RichText::new("No driver or translation-layer caches found")
+                        .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            }
+            egui::Grid::new("shader_locations_grid")
+                .num_columns(4)
+                .spacing([16.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for cache in &summary.locations {
+                        ui.label(egui::RichText::new(cache.cache_type.as_str()).strong());
+                        ui.label(
+                            egui::RichText::new(cache.path.display().to_string())
+                                .small()
+                                .monospace(),
+                        );
+                        size_label(ui, cache);
+                        clear_button(ui, state, cache, cache.cache_type.as_str());
+                        ui.end_row();
+                    }
+                });
+        });
+
+    ui.add_space(8.0);
+
+    Card::new(&colors)
+        .title("Per-Game Caches (Steam)")
+        .icon(icons::GAME)
+        .show(ui, |ui| {
+            if summary.games.is_empty() {
+                ui.label(
+                    egui::RichText::new("No Steam shader caches found")
+                        .color(colors.fg_dark.to_egui()),
+                );
+                return;
+            }
+            egui::ScrollArea::vertical()
+                .id_salt("shader_games")
+                .max_height(320.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("shader_games_grid")
+                        .num_columns(3)
+                        .spacing([16.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for game in &summary.games {
+                                ui.label(&game.name)
+                                    .on_hover_text(format!("App {}", game.app_id));
+                                size_label(ui, &game.cache);
+                                clear_button(ui, state, &game.cache, &game.name);
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+
+    render_clear_dialog(ctx, state);
+}
+
+fn size_label(ui: &mut egui::Ui, cache: &ShaderCacheInfo) {
+    ui.label(format!(
+        "{:.1} MB · {} files",
+        cache.size_mb, cache.file_count
+    ));
+}
+
+fn clear_button(ui: &mut egui::Ui, state: &mut GuiState, cache: &ShaderCacheInfo, label: &str) {
+    if ui
+        .add_enabled(cache.file_count > 0, egui::Button::new("Clear"))
+        .clicked()
+    {
+        state.pending_shader_clear = Some((format!("{} cache", label), vec![cache.path.clone()]));
+    }
+}
+
+fn render_clear_dialog(ctx: &egui::Context, state: &mut GuiState) {
+    let Some((label, paths)) = &state.pending_shader_clear else {
+        return;
+    };
+    let colors = state.theme_colors();
+    let mut confirmed = false;
+    let mut cancelled = false;
+
+    let response = egui::Modal::new(egui::Id::new("shader_clear_dialog")).show(ctx, |ui| {
+        ui.heading(format!("Clear {}?", label));
+        ui.label(
+            egui::RichText::new(
+                "Games rebuild these caches on next launch, which can cause stutter at first.",
+            )
+            .color(colors.fg_dark.to_egui()),
+        );
+        ui.add_space(6.0);
+        for path in paths.iter().take(8) {
+            ui.label(
+                egui::RichText::new(path.display().to_string())
+                    .small()
+                    .monospace(),
+            );
+        }
+        if paths.len() > 8 {
+            ui.label(egui::RichText::new(format!("…and {} more", paths.len() - 8)).small());
+        }
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui.button(format!("{} Clear", icons::ERR)).clicked() {
+                confirmed = true;
+            }
+            if ui.button("Cancel").clicked() {
+                cancelled = true;
+            }
+        });
+    });
+
+    if confirmed {
+        state.confirm_shader_clear();
+    } else if cancelled || response.should_close() {
+        state.pending_shader_clear = None;
+    }
+}
@@ -157,6 +157,20 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     state.toasts.success("Support workflow copied");
                 }
 
+                if ui
+                    .add_enabled(
+                        !state.support_job_running,
+                        egui::Button::new("Copy Diagnostics"),
+                    )
+                    .on_hover_text(
+                        "Markdown report of GPU, driver, kernel, compositor and system \
+                         validation, with hostname and user redacted",
+                    )
+                    .clicked()
+                {
+                    state.spawn_diagnostics_report();
+                }
+
                 if ui.button("Copy Summary").clicked() {
                     let summary = format!(
                         "{}: {}",
@@ -53,6 +53,7 @@ impl SystemInfo {
 pub struct DriverInfo {
     pub driver_version: String,
     pub driver_type: String,
+    pub kernel_module: crate::kernel_driver::KernelModuleInfo,
     pub gsp_enabled: bool,
     pub gsp_state: String,
     pub gsp_arch: Option<String>,
@@ -82,7 +83,8 @@ impl DriverInfo {
         }
 
         // Determine driver type
-        info.driver_type = if GspManager::is_nvidia_open() {
+        info.kernel_module = crate::kernel_driver::module_type();
+        info.driver_type = if info.kernel_module.is_open() {
             "nvidia-open".to_string()
         } else {
             "proprietary".to_string()
@@ -594,6 +596,22 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     );
                     ui.end_row();
 
+                    // Kernel module
+                    ui.label(
+                        egui::RichText::new("Module:")
+                            .strong()
+                            .color(colors.comment.to_egui()),
+                    );
+                    let module = &driver_info.kernel_module;
+                    let module_color = if !module.loaded {
+                        colors.yellow.to_egui()
+                    } else {
+                        colors.green.to_egui()
+                    };
+                    ui.colored_label(module_color, module.summary())
+                        .on_hover_text(module.license.as_deref().unwrap_or("License unknown"));
+                    ui.end_row();
+
                     // GSP Status (only for nvidia-open)
                     if driver_info.driver_type == "nvidia-open" {
                         ui.label(
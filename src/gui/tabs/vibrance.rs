@@ -4,6 +4,7 @@
 
 use eframe::egui;
 
+use crate::cli_hint::CliCommand;
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
@@ -17,6 +18,10 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
     ui.add_space(4.0);
 
     // Backend Status
+    let module_note = state
+        .kernel_module
+        .as_ref()
+        .map(|module| module.native_vibrance_note());
     Card::new(&colors)
         .title("Vibrance Backend Status")
         .icon(icons::INFO)
@@ -66,6 +71,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                             colors.yellow.to_egui(),
                             format!("{} Using nvibrant fallback", icons::WARN),
                         );
+                        if let Some(note) = module_note {
+                            ui.label(egui::RichText::new(note).small().weak());
+                        }
                         match vibrance::get_driver_info() {
                             Ok(info) => {
                                 ui.label(egui::RichText::new(format!("Driver: {}", info)).small())
@@ -80,6 +88,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                             format!("{} Vibrance Not Available", icons::ERR),
                         );
                         ui.label(egui::RichText::new(format!("Error: {}", e)).small().weak());
+                        if let Some(note) = module_note {
+                            ui.label(egui::RichText::new(note).small());
+                        }
                         ui.add_space(4.0);
                         ui.label("Requirements:");
                         ui.label(egui::RichText::new("• NVIDIA open drivers 610+").small());
@@ -129,14 +140,19 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                                         .unwrap_or(100);
 
                                     let mut percentage = current_pct;
-                                    if ui
-                                        .add(
-                                            egui::Slider::new(&mut percentage, 0..=200)
-                                                .suffix("%")
-                                                .text("Vibrance"),
-                                        )
-                                        .changed()
-                                    {
+                                    let slider = ui.add(
+                                        egui::Slider::new(&mut percentage, 0..=200)
+                                            .suffix("%")
+                                            .text("Vibrance"),
+                                    );
+                                    let slider = super::cli_hint(
+                                        slider,
+                                        &CliCommand::Vibrance {
+                                            display: Some(*connector_idx as usize),
+                                            percent: current_pct as u32,
+                                        },
+                                    );
+                                    if slider.changed() {
                                         if let Err(e) =
                                             crate::vibrance_native::set_display_vibrance_native(
                                                 0,
@@ -173,14 +189,19 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                                     let mut percentage =
                                         vibrance::vibrance_to_percentage(current_vibrance) as i32;
 
-                                    if ui
-                                        .add(
-                                            egui::Slider::new(&mut percentage, 0..=200)
-                                                .suffix("%")
-                                                .text("Vibrance"),
-                                        )
-                                        .changed()
-                                    {
+                                    let slider = ui.add(
+                                        egui::Slider::new(&mut percentage, 0..=200)
+                                            .suffix("%")
+                                            .text("Vibrance"),
+                                    );
+                                    let slider = super::cli_hint(
+                                        slider,
+                                        &CliCommand::Vibrance {
+                                            display: Some(i),
+                                            percent: percentage as u32,
+                                        },
+                                    );
+                                    if slider.changed() {
                                         let vibrance_val =
                                             vibrance::percentage_to_vibrance(percentage as u32);
                                         let display_values = vec![(i, vibrance_val)];
@@ -249,6 +270,58 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                     apply_vibrance_to_all(state, 50);
                 }
             });
+            ui.add_space(4.0);
+            if ui
+                .checkbox(&mut state.config.animate_vibrance, "Animate transitions")
+                .on_hover_text("Fade smoothly between presets (native NVKMS only)")
+                .changed()
+            {
+                state.config.save();
+            }
+        });
+
+    ui.add_space(8.0);
+
+    // Load-reactive vibrance
+    Card::new(&colors)
+        .title("React to GPU Load")
+        .icon(icons::CHART_UP)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(
+                    "Colors grow more vibrant as the GPU works harder and calm down when idle. \
+                     Off by default; turning it off restores your current vibrance.",
+                )
+                .small()
+                .color(colors.comment.to_egui()),
+            );
+            ui.add_space(4.0);
+            let settings = &mut state.config.load_reactive_vibrance;
+            let mut changed = ui.checkbox(&mut settings.enabled, "Enabled").changed();
+            ui.add_enabled_ui(settings.enabled, |ui| {
+                changed |= ui
+                    .add(egui::Slider::new(&mut settings.min_pct, 50..=200).text("% at idle"))
+                    .drag_stopped();
+                changed |= ui
+                    .add(egui::Slider::new(&mut settings.max_pct, 50..=200).text("% at full load"))
+                    .drag_stopped();
+            });
+            if changed {
+                let settings = state.config.load_reactive_vibrance;
+                match vibrance::set_load_reactive(
+                    settings.enabled,
+                    settings.min_pct,
+                    settings.max_pct,
+                ) {
+                    Ok(()) => state.config.save(),
+                    Err(e) => {
+                        state.config.load_reactive_vibrance.enabled = vibrance::is_load_reactive();
+                        state
+                            .toasts
+                            .error(format!("Load-reactive vibrance failed: {}", e));
+                    }
+                }
+            }
         });
 
     ui.add_space(8.0);
@@ -267,36 +340,30 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
         });
 }
 
-/// Apply vibrance to all displays
-fn apply_vibrance_to_all(state: &mut GuiState, percentage: u32) {
-    // Try native first
-    if let Ok(guard) = crate::vibrance_native::get_vibrance_controller() {
-        if let Some(controller) = guard.as_ref() {
-            for (_device_id, connector_idx, _name, _connected) in controller.list_displays() {
-                let _ = crate::vibrance_native::set_display_vibrance_native(
-                    0,
-                    connector_idx,
-                    percentage,
-                );
-            }
-            state
-                .toasts
-                .success(format!("Vibrance set to {}%", percentage));
-            return;
-        }
+/// How long a preset transition takes when animation is on
+const PRESET_FADE_MS: u64 = 400;
+
+/// Apply vibrance to all displays, fading when "Animate transitions" is on
+pub(crate) fn apply_vibrance_to_all(state: &mut GuiState, percentage: u32) {
+    if state.config.animate_vibrance
+        && let Ok(displays) = crate::vibrance_native::get_display_vibrance_native()
+    {
+        let targets: Vec<(usize, u32)> = displays
+            .iter()
+            .map(|(index, _, _)| (*index as usize, percentage))
+            .collect();
+        // A fade still running is cancelled by the new one
+        state.vibrance_fade = Some(vibrance::set_vibrance_smooth(&targets, PRESET_FADE_MS));
+        state
+            .toasts
+            .success(format!("Vibrance fading to {}%", percentage));
+        return;
     }
 
-    // Fall back to nvibrant
-    if let Ok(displays) = vibrance::get_displays() {
-        let vibrance_val = vibrance::percentage_to_vibrance(percentage);
-        let display_values: Vec<(usize, i32)> =
-            (0..displays.len()).map(|i| (i, vibrance_val)).collect();
-        if let Err(e) = vibrance::set_vibrance(&display_values) {
-            state.toasts.error(format!("Failed to set vibrance: {}", e));
-        } else {
-            state
-                .toasts
-                .success(format!("Vibrance set to {}%", percentage));
-        }
+    match vibrance::set_vibrance_percent(vibrance::DisplayTarget::All, percentage) {
+        Ok(_) => state
+            .toasts
+            .success(format!("Vibrance set to {}%", percentage)),
+        Err(e) => state.toasts.error(format!("Failed to set vibrance: {}", e)),
     }
 }
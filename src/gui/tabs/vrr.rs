@@ -7,11 +7,18 @@ use eframe::egui;
 use crate::gui::icons;
 use crate::gui::state::GuiState;
 use crate::gui::widgets::Card;
+use crate::vrr::{RefreshSource, VrrActivity};
 
 /// Render the VRR tab
-pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
+pub fn render(ui: &mut egui::Ui, state: &mut GuiState, ctx: &egui::Context) {
     let colors = state.theme_colors();
 
+    // Live refresh is sampled in the background while this tab is open
+    state.poll_vrr_live();
+    if state.vrr_displays.iter().any(|d| d.supports_vrr) {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
     ui.heading(format!(
         "{} VRR (Variable Refresh Rate) & G-Sync Control",
         icons::VRR
@@ -109,6 +116,46 @@ pub fn render(ui: &mut egui::Ui, state: &mut GuiState, _ctx: &egui::Context) {
                                     ui.colored_label(colors.green.to_egui(), "✅ FreeSync");
                                 }
                             });
+
+                            if *supports_vrr {
+                                ui.horizontal(|ui| {
+                                    ui.label("Current Refresh:");
+                                    match state.vrr_live.get(display_name) {
+                                        Some(Ok(refresh)) => {
+                                            let activity = VrrActivity::classify(
+                                                refresh,
+                                                *min_refresh,
+                                                *max_refresh,
+                                            );
+                                            ui.label(
+                                                egui::RichText::new(format!("{:.0}Hz", refresh.hz))
+                                                    .strong()
+                                                    .monospace(),
+                                            );
+                                            let color = match activity {
+                                                VrrActivity::Active => colors.green,
+                                                VrrActivity::Idle => colors.fg_dark,
+                                                VrrActivity::Unknown => colors.yellow,
+                                            };
+                                            let badge =
+                                                ui.colored_label(color.to_egui(), activity.label());
+                                            if refresh.source == RefreshSource::Mode {
+                                                badge.on_hover_text(
+                                                    "Only the mode's nominal rate is readable; \
+                                                     enable nvidia_drm.modeset=1 to see VRR",
+                                                );
+                                            }
+                                        }
+                                        Some(Err(e)) => {
+                                            ui.label(egui::RichText::new("unavailable").weak())
+                                                .on_hover_text(e);
+                                        }
+                                        None => {
+                                            ui.spinner();
+                                        }
+                                    }
+                                });
+                            }
                         });
 
                     ui.add_space(4.0);
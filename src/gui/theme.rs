@@ -52,6 +52,8 @@ pub fn theme_icon(variant: ThemeVariant) -> &'static str {
         ThemeVariant::RogRed => "🔴",
         ThemeVariant::MatrixGreen => "💚",
         ThemeVariant::Cyberpunk => "🌆",
+        ThemeVariant::Deuteranopia => "👁",
+        ThemeVariant::HighContrast => "◐",
     }
 }
 
@@ -64,7 +66,9 @@ pub fn next_theme(current: ThemeVariant) -> ThemeVariant {
         ThemeVariant::Dracula => ThemeVariant::RogRed,
         ThemeVariant::RogRed => ThemeVariant::MatrixGreen,
         ThemeVariant::MatrixGreen => ThemeVariant::Cyberpunk,
-        ThemeVariant::Cyberpunk => ThemeVariant::TokyoNightNight,
+        ThemeVariant::Cyberpunk => ThemeVariant::Deuteranopia,
+        ThemeVariant::Deuteranopia => ThemeVariant::HighContrast,
+        ThemeVariant::HighContrast => ThemeVariant::TokyoNightNight,
     }
 }
 
@@ -121,6 +121,8 @@ pub struct ToastManager {
     toasts: VecDeque<Toast>,
     /// Maximum number of visible toasts
     max_visible: usize,
+    /// Where every toast is also recorded, once attached
+    event_log: Option<super::event_log::EventLog>,
 }
 
 impl ToastManager {
@@ -129,11 +131,21 @@ impl ToastManager {
         Self {
             toasts: VecDeque::new(),
             max_visible: 5,
+            event_log: None,
         }
     }
 
+    /// Also record every toast in `log`
+    pub fn with_event_log(mut self, log: super::event_log::EventLog) -> Self {
+        self.event_log = Some(log);
+        self
+    }
+
     /// Add a toast to the queue
     pub fn push(&mut self, toast: Toast) {
+        if let Some(log) = &self.event_log {
+            log.push(toast.kind, toast.message.clone());
+        }
         self.toasts.push_back(toast);
         // Limit queue size
         while self.toasts.len() > 20 {
@@ -0,0 +1,199 @@
+//! Command Palette Widget
+//!
+//! Ctrl+P overlay that fuzzy-searches a list of labelled actions and runs
+//! the chosen one. Arrow keys move the selection, Enter runs, Esc dismisses.
+
+use crate::themes::ColorPalette;
+use eframe::egui;
+
+/// Maximum number of matches listed at once
+const MAX_VISIBLE: usize = 12;
+
+/// A labelled action the palette can run against some state `S`
+pub type PaletteAction<'a, S> = (String, Box<dyn FnOnce(&mut S) + 'a>);
+
+/// Command palette overlay state
+#[derive(Default)]
+pub struct CommandPalette {
+    open: bool,
+    query: String,
+    selected: usize,
+    focus_requested: bool,
+}
+
+impl CommandPalette {
+    /// Whether the palette is showing
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show or hide the palette, starting from an empty query
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.query.clear();
+        self.selected = 0;
+        self.focus_requested = false;
+    }
+
+    fn close(&mut self) {
+        self.open = false;
+        self.query.clear();
+    }
+
+    /// Draw the palette and run the chosen action against `target`
+    pub fn show<S>(
+        &mut self,
+        ctx: &egui::Context,
+        colors: &ColorPalette,
+        actions: Vec<PaletteAction<'_, S>>,
+        target: &mut S,
+    ) {
+        if !self.open {
+            return;
+        }
+
+        // Consume navigation keys before the text field can see them
+        let (escape, enter, up, down) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            )
+        });
+        if escape {
+            self.close();
+            return;
+        }
+
+        let mut matches: Vec<(i32, usize)> = actions
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, (label, _))| fuzzy_score(&self.query, label).map(|s| (s, idx)))
+            .collect();
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        matches.truncate(MAX_VISIBLE);
+
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if down {
+            self.selected += 1;
+        }
+        self.selected = self.selected.min(matches.len().saturating_sub(1));
+
+        let mut chosen = enter
+            .then(|| matches.get(self.selected).map(|m| m.1))
+            .flatten();
+
+        egui::Area::new(egui::Id::new("command_palette"))
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 80.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Frame::new()
+                    .fill(colors.bg_popup.to_egui())
+                    .stroke(egui::Stroke::new(1.0, colors.cyan.to_egui()))
+                    .corner_radius(8.0)
+                    .inner_margin(10.0)
+                    .show(ui, |ui| {
+                        ui.set_width(420.0);
+
+                        let edit = ui.add(
+                            egui::TextEdit::singleline(&mut self.query)
+                                .hint_text("Type a command…")
+                                .desired_width(f32::INFINITY),
+                        );
+                        if !self.focus_requested {
+                            edit.request_focus();
+                            self.focus_requested = true;
+                        }
+                        if edit.changed() {
+                            self.selected = 0;
+                        }
+                        ui.add_space(6.0);
+
+                        if matches.is_empty() {
+                            ui.label(
+                                egui::RichText::new("No matching commands")
+                                    .italics()
+                                    .color(colors.fg_dark.to_egui()),
+                            );
+                        }
+
+                        for (row, (_, idx)) in matches.iter().enumerate() {
+                            let selected = row == self.selected;
+                            let label = egui::RichText::new(&actions[*idx].0).color(if selected {
+                                colors.cyan.to_egui()
+                            } else {
+                                colors.fg.to_egui()
+                            });
+                            let response = ui.add(
+                                egui::Button::new(label)
+                                    .fill(if selected {
+                                        colors.selection.to_egui()
+                                    } else {
+                                        egui::Color32::TRANSPARENT
+                                    })
+                                    .stroke(egui::Stroke::NONE)
+                                    .min_size(egui::vec2(400.0, 24.0)),
+                            );
+                            if response.clicked() {
+                                chosen = Some(*idx);
+                            }
+                        }
+                    });
+            });
+
+        if let Some(idx) = chosen {
+            self.close();
+            if let Some((_, action)) = actions.into_iter().nth(idx) {
+                action(target);
+            }
+        }
+    }
+}
+
+/// Score `label` against `query` as an in-order subsequence match.
+///
+/// Returns `None` when some query character is missing. Consecutive runs and
+/// matches at word starts score higher, so "fan" ranks "Open Fan tab" above
+/// "Refresh all GPU stats now".
+pub fn fuzzy_score(query: &str, label: &str) -> Option<i32> {
+    let label: Vec<char> = label.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for qc in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = (pos..label.len()).find(|&i| label[i] == qc)?;
+        score += 1;
+        if prev_match.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !label[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        prev_match = Some(found);
+        pos = found + 1;
+    }
+
+    // Prefer shorter labels among equally good matches
+    Some(score * 100 - label.len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_ranking() {
+        assert!(fuzzy_score("xyz", "Open Fan tab").is_none());
+        assert!(fuzzy_score("", "anything").is_some());
+
+        let fan = fuzzy_score("fan", "Open Fan tab").unwrap();
+        let scattered = fuzzy_score("fan", "Refresh all GPU stats now").unwrap();
+        assert!(fan > scattered);
+
+        assert!(fuzzy_score("vib 150", "Set vibrance 150%").is_some());
+    }
+}
@@ -126,13 +126,7 @@ impl<'a> HeaderBar<'a> {
 
                 // Center: Quick stats
                 if let Some(temp) = self.gpu_temp {
-                    let temp_color = if temp > 80.0 {
-                        self.colors.red.to_egui()
-                    } else if temp > 65.0 {
-                        self.colors.yellow.to_egui()
-                    } else {
-                        self.colors.green.to_egui()
-                    };
+                    let temp_color = self.colors.temp_color(temp).to_egui();
 
                     ui.label(
                         egui::RichText::new(icons::TEMP)
@@ -148,11 +142,7 @@ impl<'a> HeaderBar<'a> {
                 }
 
                 if let Some(util) = self.gpu_util {
-                    let util_color = if util > 90.0 {
-                        self.colors.yellow.to_egui()
-                    } else {
-                        self.colors.cyan.to_egui()
-                    };
+                    let util_color = self.colors.usage_color(util).to_egui();
 
                     ui.label(
                         egui::RichText::new(icons::SPEED)
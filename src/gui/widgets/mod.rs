@@ -3,9 +3,11 @@
 //! Custom widgets for nvcontrol GUI.
 
 pub mod card;
+pub mod command_palette;
 pub mod header;
 pub mod status_badge;
 
 pub use card::Card;
+pub use command_palette::{CommandPalette, PaletteAction};
 pub use header::{HeaderBar, StatusState};
 pub use status_badge::StatusBadge;
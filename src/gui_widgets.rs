@@ -19,6 +19,9 @@ impl CurvePoint {
 pub struct FanCurve {
     pub points: Vec<CurvePoint>,
     pub selected_point: Option<usize>,
+    /// Stop the fan (0% duty) below this temperature
+    #[serde(default)]
+    pub zero_rpm_below: Option<u32>,
 }
 
 impl Default for FanCurve {
@@ -31,45 +34,130 @@ impl Default for FanCurve {
                 CurvePoint::new(85.0, 100.0),
             ],
             selected_point: None,
+            zero_rpm_below: None,
         }
     }
 }
 
+/// Smallest temperature gap (°C) kept between neighbouring curve points
+pub const FAN_CURVE_MIN_GAP: f64 = 1.0;
+
+/// Step used when snapping is requested (Shift held while dragging)
+pub const FAN_CURVE_SNAP_STEP: f64 = 5.0;
+
+/// Round `value` to the nearest multiple of `step`
+pub fn snap_to_step(value: f64, step: f64) -> f64 {
+    (value / step).round() * step
+}
+
 impl FanCurve {
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn add_point(&mut self, temp: f64, speed: f64) {
-        let point = CurvePoint::new(temp, speed);
+    /// Editor copy of a saved curve
+    pub fn from_fan_curve(curve: &crate::fan::FanCurve) -> Self {
+        Self {
+            points: curve
+                .points
+                .iter()
+                .map(|p| CurvePoint::new(p.temperature as f64, p.duty_cycle as f64))
+                .collect(),
+            selected_point: None,
+            zero_rpm_below: curve.zero_rpm_below,
+        }
+    }
+
+    /// Insert a point in temperature order.
+    ///
+    /// The speed is clamped between its neighbours so the curve never slows
+    /// the fan as temperature rises. Points too close to an existing one are
+    /// ignored; returns whether the point was added.
+    pub fn add_point(&mut self, temp: f64, speed: f64) -> bool {
+        let temp = temp.clamp(0.0, 100.0);
+        if self
+            .points
+            .iter()
+            .any(|p| (p.x - temp).abs() < FAN_CURVE_MIN_GAP)
+        {
+            return false;
+        }
 
-        // Insert in sorted order by temperature
         let insert_pos = self
             .points
             .iter()
             .position(|p| p.x > temp)
             .unwrap_or(self.points.len());
+        let (low, high) = self.speed_bounds(insert_pos, insert_pos);
 
-        self.points.insert(insert_pos, point);
+        self.points
+            .insert(insert_pos, CurvePoint::new(temp, speed.clamp(low, high)));
+        true
     }
 
     pub fn remove_point(&mut self, index: usize) {
-        if self.points.len() > 2 {
+        if self.points.len() > 2 && index < self.points.len() {
             self.points.remove(index);
+            if self.selected_point == Some(index) {
+                self.selected_point = None;
+            }
         }
     }
 
+    /// Move a point without letting it cross its neighbours.
+    ///
+    /// Temperature stays strictly between the adjacent points and speed stays
+    /// between their speeds, so interpolation remains monotonic.
     pub fn update_point(&mut self, index: usize, temp: f64, speed: f64) {
-        if let Some(point) = self.points.get_mut(index) {
-            point.x = temp.clamp(0.0, 100.0);
-            point.y = speed.clamp(0.0, 100.0);
+        self.drag_point(index, temp, speed, false);
+    }
+
+    /// Move a point as in [`update_point`](Self::update_point), optionally
+    /// snapping to 5 °C / 5 % increments first
+    pub fn drag_point(&mut self, index: usize, temp: f64, speed: f64, snap: bool) {
+        if index >= self.points.len() {
+            return;
         }
 
-        // Re-sort by temperature
-        self.points.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+        let (temp, speed) = if snap {
+            (
+                snap_to_step(temp, FAN_CURVE_SNAP_STEP),
+                snap_to_step(speed, FAN_CURVE_SNAP_STEP),
+            )
+        } else {
+            (temp, speed)
+        };
+
+        let min_temp = match index {
+            0 => 0.0,
+            i => self.points[i - 1].x + FAN_CURVE_MIN_GAP,
+        };
+        let max_temp = self
+            .points
+            .get(index + 1)
+            .map_or(100.0, |p| p.x - FAN_CURVE_MIN_GAP);
+        let (low, high) = self.speed_bounds(index, index + 1);
+
+        let point = &mut self.points[index];
+        point.x = temp.clamp(min_temp.min(max_temp), max_temp.max(min_temp));
+        point.y = speed.clamp(low, high);
+    }
+
+    /// Speed range allowed between the point before `prev_end` and the one at `next_start`
+    fn speed_bounds(&self, prev_end: usize, next_start: usize) -> (f64, f64) {
+        let low = prev_end
+            .checked_sub(1)
+            .and_then(|i| self.points.get(i))
+            .map_or(0.0, |p| p.y);
+        let high = self.points.get(next_start).map_or(100.0, |p| p.y);
+        (low, high.max(low))
     }
 
+    /// Speed applied at `temp`, 0% inside the zero-RPM region
     pub fn get_speed_at_temp(&self, temp: f64) -> f64 {
+        if self.zero_rpm_below.is_some_and(|below| temp < below as f64) {
+            return 0.0;
+        }
         if self.points.is_empty() {
             return 50.0;
         }
@@ -314,3 +402,37 @@ pub struct GpuStats {
     pub utilization: f32,
     pub fan_speed: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fan_curve_drag_constraints() {
+        let mut curve = FanCurve::default(); // 30/30, 50/50, 70/70, 85/100
+
+        // Can't cross neighbours in temperature or speed
+        curve.drag_point(1, 90.0, 95.0, false);
+        assert_eq!((curve.points[1].x, curve.points[1].y), (69.0, 70.0));
+        curve.drag_point(1, -10.0, -10.0, false);
+        assert_eq!((curve.points[1].x, curve.points[1].y), (31.0, 30.0));
+
+        // Edge points clamp to the plot range
+        curve.drag_point(3, 150.0, 150.0, false);
+        assert_eq!((curve.points[3].x, curve.points[3].y), (100.0, 100.0));
+
+        // Snapping rounds to 5 degree / 5 percent steps
+        curve.drag_point(2, 62.4, 58.0, true);
+        assert_eq!((curve.points[2].x, curve.points[2].y), (60.0, 60.0));
+
+        // New points are clamped between neighbours' speeds
+        assert!(curve.add_point(45.0, 90.0));
+        assert_eq!(curve.points[2].y, 60.0);
+        assert!(!curve.add_point(45.5, 50.0));
+
+        let speeds: Vec<f64> = (0..=100)
+            .map(|t| curve.get_speed_at_temp(t as f64))
+            .collect();
+        assert!(speeds.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
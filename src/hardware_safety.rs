@@ -4,6 +4,7 @@
 /// and automatic emergency shutdown
 use crate::{NvControlError, NvResult};
 use nvml_wrapper::Nvml;
+use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -323,6 +324,120 @@ impl BackgroundSafetyMonitor {
     }
 }
 
+/// Resets an active overclock to stock when an overclocked GPU runs too hot
+/// or NVML stops responding for it. Poll it once per monitoring interval.
+#[derive(Debug, Clone)]
+pub struct OverclockWatchdog {
+    max_temp: u32,
+    /// Consecutive failed polls per GPU index
+    missed_polls: BTreeMap<u32, u32>,
+    /// Set when a reset to stock failed; the watchdog stays quiet until the
+    /// overclock is gone or the latch is cleared
+    reset_failed: bool,
+}
+
+/// Consecutive failed NVML polls before the watchdog fires
+pub const WATCHDOG_MAX_MISSED_POLLS: u32 = 3;
+
+impl OverclockWatchdog {
+    pub fn new(max_temp: u32) -> Self {
+        Self {
+            max_temp,
+            missed_polls: BTreeMap::new(),
+            reset_failed: false,
+        }
+    }
+
+    /// Build from config, or `None` if the user opted out
+    pub fn from_config(config: &crate::config::Config) -> Option<Self> {
+        config
+            .oc_watchdog_enabled
+            .then(|| Self::new(config.oc_watchdog_max_temp))
+    }
+
+    /// Record one poll of `gpu_index`. `None` means NVML did not answer.
+    ///
+    /// Returns the reason when the watchdog should fire.
+    pub fn evaluate(&mut self, gpu_index: u32, temperature: Option<u32>) -> Option<String> {
+        match temperature {
+            Some(temp) => {
+                self.missed_polls.remove(&gpu_index);
+                (temp >= self.max_temp).then(|| {
+                    format!(
+                        "GPU {} reached {}°C (limit {}°C)",
+                        gpu_index, temp, self.max_temp
+                    )
+                })
+            }
+            None => {
+                let missed = self.missed_polls.entry(gpu_index).or_insert(0);
+                *missed += 1;
+                (*missed >= WATCHDOG_MAX_MISSED_POLLS).then(|| {
+                    format!(
+                        "NVML stopped responding for GPU {} for {} consecutive polls",
+                        gpu_index, missed
+                    )
+                })
+            }
+        }
+    }
+
+    /// Read the temperature of every overclocked GPU with `read_temp` and,
+    /// if one trips, reset to stock through the same path as a manual revert.
+    pub fn poll(&mut self, read_temp: impl FnMut(u32) -> Option<u32>) -> Option<String> {
+        let overclocked = crate::overclocking::OverclockHistory::load().overclocked_gpus();
+        let reason = self.check(&overclocked, read_temp)?;
+
+        log::warn!("Overclock watchdog: {reason}, resetting to stock");
+        if let Err(e) = crate::overclocking::reset_to_stock() {
+            log::error!("Overclock watchdog reset failed: {e}");
+            self.reset_failed = true;
+        }
+        if let Ok(mut notifier) = crate::notifications::NotificationManager::new() {
+            let _ = notifier.notify_watchdog_reset(&reason);
+        }
+
+        Some(reason)
+    }
+
+    /// Whether an earlier reset failed and the watchdog is holding off
+    pub fn is_latched(&self) -> bool {
+        self.reset_failed
+    }
+
+    /// Re-arm the watchdog after a failed reset was dealt with by hand
+    pub fn clear_latch(&mut self) {
+        self.reset_failed = false;
+    }
+
+    /// Decide whether a poll of the `overclocked` GPUs should fire. After a
+    /// failed reset this stays quiet until no GPU is overclocked.
+    fn check(
+        &mut self,
+        overclocked: &[u32],
+        mut read_temp: impl FnMut(u32) -> Option<u32>,
+    ) -> Option<String> {
+        if self.reset_failed {
+            if !overclocked.is_empty() {
+                return None;
+            }
+            self.reset_failed = false;
+        }
+
+        // GPUs back at stock start from a clean slate if overclocked again
+        self.missed_polls.retain(|gpu, _| overclocked.contains(gpu));
+        let reasons: Vec<String> = overclocked
+            .iter()
+            .filter_map(|&gpu| self.evaluate(gpu, read_temp(gpu)))
+            .collect();
+        if reasons.is_empty() {
+            return None;
+        }
+        self.missed_polls.clear();
+        Some(reasons.join("; "))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,6 +469,61 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_watchdog_temperature_trip() {
+        let mut watchdog = OverclockWatchdog::new(90);
+        assert!(watchdog.evaluate(0, Some(75)).is_none());
+        assert!(watchdog.evaluate(0, Some(90)).is_some());
+    }
+
+    #[test]
+    fn test_watchdog_missed_polls() {
+        let mut watchdog = OverclockWatchdog::new(90);
+        assert!(watchdog.evaluate(0, None).is_none());
+        assert!(watchdog.evaluate(0, None).is_none());
+        // A good poll resets the counter
+        assert!(watchdog.evaluate(0, Some(60)).is_none());
+        assert!(watchdog.evaluate(0, None).is_none());
+        assert!(watchdog.evaluate(0, None).is_none());
+        // Misses are counted per GPU
+        assert!(watchdog.evaluate(1, None).is_none());
+        assert!(watchdog.evaluate(0, None).is_some());
+    }
+
+    #[test]
+    fn test_watchdog_polls_every_overclocked_gpu() {
+        let mut watchdog = OverclockWatchdog::new(90);
+        let temps = |gpu: u32| Some(if gpu == 1 { 95 } else { 60 });
+
+        // GPU 1 overheats while GPU 0 is the one on screen
+        let reason = watchdog.check(&[0, 1], temps).unwrap();
+        assert!(reason.contains("GPU 1"), "{reason}");
+
+        // A hot GPU at stock is not the watchdog's business
+        assert!(watchdog.check(&[0], temps).is_none());
+    }
+
+    #[test]
+    fn test_watchdog_latches_after_failed_reset() {
+        let mut watchdog = OverclockWatchdog::new(90);
+        let hot = |_| Some(95);
+        assert!(watchdog.check(&[0], hot).is_some());
+
+        // The reset failed, so the overclock is still recorded as active
+        watchdog.reset_failed = true;
+        assert!(watchdog.check(&[0], hot).is_none());
+        assert!(watchdog.is_latched());
+
+        // Once the overclock is gone the watchdog re-arms itself
+        assert!(watchdog.check(&[], hot).is_none());
+        assert!(!watchdog.is_latched());
+        assert!(watchdog.check(&[0], hot).is_some());
+
+        watchdog.reset_failed = true;
+        watchdog.clear_latch();
+        assert!(watchdog.check(&[0], hot).is_some());
+    }
+
     #[test]
     fn test_power_limit_validation() {
         let monitor = SafetyMonitor::new(0, SafetyThresholds::default());
@@ -624,6 +624,18 @@ pub struct HdrConfig {
     pub tone_mapping: ToneMappingMode,
     pub color_space: ColorSpace,
     pub eotf: Eotf,
+    #[serde(default)]
+    pub output_mapping: OutputMapping,
+}
+
+/// How the compositor maps output color for HDR displays
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum OutputMapping {
+    /// Let the compositor pick based on content
+    #[default]
+    Passthrough,
+    /// Always output in the BT.2020 wide color gamut
+    ForceBt2020,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -660,6 +672,7 @@ impl Default for HdrConfig {
             tone_mapping: ToneMappingMode::Hable,
             color_space: ColorSpace::BT2020,
             eotf: Eotf::PQ,
+            output_mapping: OutputMapping::default(),
         }
     }
 }
@@ -735,6 +748,68 @@ impl HdrConfig {
     }
 }
 
+/// Override the output color mapping on all connected displays and persist it
+/// in the HDR config. Only KDE and Hyprland expose this control.
+pub fn set_tone_mapping(mapping: OutputMapping) -> NvResult<()> {
+    let compositor = detect_compositor()?;
+
+    match compositor.as_str() {
+        "kde" | "kwin" | "plasma" => {
+            let action = match mapping {
+                OutputMapping::Passthrough => "disable",
+                OutputMapping::ForceBt2020 => "enable",
+            };
+            for display in get_kde_displays()? {
+                let output = crate::gpu_safe::execute(
+                    std::process::Command::new("kscreen-doctor")
+                        .arg(format!("output.{}.wcg.{}", display.name, action)),
+                );
+                if !output.map(|o| o.status.success()).unwrap_or(false) {
+                    return Err(crate::NvControlError::DisplayDetectionFailed(format!(
+                        "kscreen-doctor could not set wide color gamut on {}",
+                        display.name
+                    )));
+                }
+            }
+        }
+        "hyprland" => {
+            let cm = match mapping {
+                OutputMapping::Passthrough => "auto",
+                OutputMapping::ForceBt2020 => "wide",
+            };
+            for monitor in get_hyprland_monitors()? {
+                let rule = format!(
+                    "{},{}x{}@{},auto,1,cm,{}",
+                    monitor.name, monitor.width, monitor.height, monitor.refresh_rate, cm
+                );
+                let output = crate::gpu_safe::execute(
+                    std::process::Command::new("hyprctl").args(["keyword", "monitor", &rule]),
+                );
+                if !output.map(|o| o.status.success()).unwrap_or(false) {
+                    return Err(crate::NvControlError::DisplayDetectionFailed(format!(
+                        "hyprctl could not set color mapping on {}",
+                        monitor.name
+                    )));
+                }
+            }
+        }
+        other => {
+            return Err(crate::NvControlError::UnsupportedFeature(format!(
+                "Output color mapping is not exposed by {}",
+                other
+            )));
+        }
+    }
+
+    // Nothing changed on the displays, so keep the saved mapping as is
+    if crate::gpu_safe::is_dry_run() {
+        return Ok(());
+    }
+    let mut config = HdrConfig::load().unwrap_or_default();
+    config.output_mapping = mapping;
+    config.save()
+}
+
 /// Get display HDR capabilities
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HdrCapabilities {
@@ -825,13 +900,17 @@ fn parse_edid_hdr_capabilities(edid: &[u8]) -> Option<HdrCapabilities> {
                         let eotf_byte = ext_block.get(offset + 2).copied().unwrap_or(0);
                         let supports_hdr10 = (eotf_byte & 0x04) != 0; // SMPTE ST 2084
                         let supports_hlg = (eotf_byte & 0x08) != 0; // HLG
+                        let metadata =
+                            crate::display::parse_edid_hdr_metadata(edid).unwrap_or_default();
 
                         return Some(HdrCapabilities {
                             supports_hdr10,
                             supports_hdr10_plus: false, // Requires separate detection
-                            max_luminance: 1000,        // Default, would need metadata byte parsing
-                            min_luminance: 0.1,
-                            max_fall: 400, // Typical FALL value
+                            max_luminance: metadata
+                                .max_luminance_nits
+                                .map_or(1000, |nits| nits as u32),
+                            min_luminance: metadata.min_luminance_nits.unwrap_or(0.1),
+                            max_fall: metadata.max_frame_avg_nits.map_or(400, |nits| nits as u32), // Typical FALL value
                             supports_dolby_vision: false,
                             supports_hlg,
                         });
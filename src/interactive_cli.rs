@@ -276,6 +276,7 @@ Select option (1-8): "#,
                             if let Err(e) = crate::fan::set_fan_speed(0, speed) {
                                 self.term.write_line(&format!("❌ Error: {}", e)).ok();
                             } else {
+                                crate::state::LastApplied::record_fan_speed(0, speed);
                                 self.term
                                     .write_line(&format!("✅ Fan speed set to {}%", speed))
                                     .ok();
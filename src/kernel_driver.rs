@@ -17,14 +17,156 @@ pub struct KernelDriverInfo {
     pub features: DriverFeatures,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub enum DriverType {
     Proprietary, // nvidia.ko
     OpenKernel,  // nvidia-open.ko
     Nouveau,     // nouveau.ko
+    #[default]
     Unknown,
 }
 
+/// The NVIDIA kernel module in use, as reported by [`module_type`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct KernelModuleInfo {
+    pub driver_type: DriverType,
+    /// Whether the module is loaded; when it isn't, `driver_type` describes
+    /// the installed module
+    pub loaded: bool,
+    /// Module version, e.g. "580.105.08"
+    pub version: Option<String>,
+    /// Module license from modinfo ("Dual MIT/GPL" for the open module)
+    pub license: Option<String>,
+    /// GSP firmware enabled; `None` when it can't be determined
+    pub gsp_enabled: Option<bool>,
+}
+
+impl KernelModuleInfo {
+    pub fn is_open(&self) -> bool {
+        self.driver_type == DriverType::OpenKernel
+    }
+
+    /// Short module name, e.g. "nvidia-open"
+    pub fn name(&self) -> &'static str {
+        match self.driver_type {
+            DriverType::OpenKernel => "nvidia-open",
+            DriverType::Proprietary => "nvidia (proprietary)",
+            DriverType::Nouveau => "nouveau",
+            DriverType::Unknown => "unknown",
+        }
+    }
+
+    /// "nvidia-open 590.48.01", "nvidia (proprietary) 580.82.09, not loaded"
+    pub fn summary(&self) -> String {
+        let mut text = self.name().to_string();
+        if let Some(version) = &self.version {
+            text.push(' ');
+            text.push_str(version);
+        }
+        if !self.loaded {
+            text.push_str(", not loaded");
+        }
+        text
+    }
+
+    /// Why native (NVKMS) vibrance is or isn't expected to work with this module
+    pub fn native_vibrance_note(&self) -> &'static str {
+        match (&self.driver_type, self.loaded) {
+            (DriverType::OpenKernel, true) => {
+                "Open kernel module loaded: native NVKMS vibrance is supported"
+            }
+            (DriverType::Proprietary, true) => {
+                "Proprietary kernel module loaded: native NVKMS vibrance needs the open module (nvidia-open)"
+            }
+            (DriverType::Nouveau, _) => "nouveau is loaded: NVIDIA vibrance control is unavailable",
+            _ => "No NVIDIA kernel module loaded: vibrance control is unavailable",
+        }
+    }
+}
+
+/// Which NVIDIA kernel module is loaded (open or proprietary), its version
+/// and GSP firmware status. Reads `/proc/driver/nvidia/version`, falling back
+/// to modinfo for the installed module when none is loaded.
+pub fn module_type() -> KernelModuleInfo {
+    let proc_version = fs::read_to_string("/proc/driver/nvidia/version").ok();
+    let modinfo = |field: &str| {
+        Command::new("modinfo")
+            .args(["-F", field, "nvidia"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let license = modinfo("license");
+
+    let mut info = parse_module_type(
+        proc_version.as_deref(),
+        license.as_deref(),
+        modinfo("version").as_deref(),
+    );
+    if info.driver_type == DriverType::Unknown
+        && std::path::Path::new("/sys/module/nouveau").exists()
+    {
+        info.driver_type = DriverType::Nouveau;
+        info.loaded = true;
+    }
+    if info.driver_type != DriverType::Nouveau {
+        info.gsp_enabled = crate::gsp_firmware::GspManager::is_gsp_enabled().ok();
+    }
+    info.license = license;
+    info
+}
+
+/// Classify the module from `/proc/driver/nvidia/version` (loaded) or the
+/// modinfo license and version (installed only)
+fn parse_module_type(
+    proc_version: Option<&str>,
+    modinfo_license: Option<&str>,
+    modinfo_version: Option<&str>,
+) -> KernelModuleInfo {
+    if let Some(nvrm) = proc_version
+        .into_iter()
+        .flat_map(str::lines)
+        .find(|line| line.starts_with("NVRM version:"))
+    {
+        // NVRM version: NVIDIA UNIX Open Kernel Module for x86_64  590.48.01  Release Build ...
+        // NVRM version: NVIDIA UNIX x86_64 Kernel Module  580.82.09  Fri Aug ...
+        let version = nvrm
+            .split_whitespace()
+            .find(|word| {
+                word.starts_with(|c: char| c.is_ascii_digit())
+                    && word.contains('.')
+                    && word.chars().all(|c| c.is_ascii_digit() || c == '.')
+            })
+            .map(str::to_string);
+        return KernelModuleInfo {
+            driver_type: if nvrm.contains("Open Kernel Module") {
+                DriverType::OpenKernel
+            } else {
+                DriverType::Proprietary
+            },
+            loaded: true,
+            version,
+            ..Default::default()
+        };
+    }
+
+    let driver_type = match modinfo_license {
+        Some(license) if license.contains("MIT") || license.contains("GPL") => {
+            DriverType::OpenKernel
+        }
+        Some(_) => DriverType::Proprietary,
+        None => DriverType::Unknown,
+    };
+    KernelModuleInfo {
+        driver_type,
+        loaded: false,
+        version: modinfo_version.map(str::to_string),
+        ..Default::default()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DriverFeatures {
     pub gsp_firmware: bool, // GSP (GPU System Processor) firmware
@@ -79,25 +221,9 @@ impl KernelDriverInfo {
 
     /// Detect which NVIDIA driver type is loaded
     fn detect_driver_type() -> DriverType {
-        if let Ok(version_info) = fs::read_to_string("/proc/driver/nvidia/version") {
-            if version_info.contains("Open Kernel Module") {
-                return DriverType::OpenKernel;
-            }
-            if version_info.contains("NVIDIA UNIX") {
-                return DriverType::Proprietary;
-            }
-        }
-
-        if let Ok(output) = Command::new("modinfo")
-            .args(["-F", "license", "nvidia"])
-            .output()
-        {
-            if output.status.success() {
-                let license = String::from_utf8_lossy(&output.stdout);
-                if license.contains("MIT") || license.contains("GPL") {
-                    return DriverType::OpenKernel;
-                }
-            }
+        let module = module_type();
+        if module.driver_type != DriverType::Unknown {
+            return module.driver_type;
         }
 
         if let Ok(output) = Command::new("lsmod").output() {
@@ -420,6 +546,43 @@ mod tests {
         assert!(info.is_ok());
     }
 
+    #[test]
+    fn test_parse_module_type() {
+        let open = parse_module_type(
+            Some(
+                "NVRM version: NVIDIA UNIX Open Kernel Module for x86_64  590.48.01  Release Build  (builder@host)  Thu Dec 18 2025\nGCC version:  gcc version 15.2.1\n",
+            ),
+            Some("Dual MIT/GPL"),
+            Some("590.48.01"),
+        );
+        assert_eq!(open.driver_type, DriverType::OpenKernel);
+        assert!(open.loaded && open.is_open());
+        assert_eq!(open.version.as_deref(), Some("590.48.01"));
+        assert_eq!(open.summary(), "nvidia-open 590.48.01");
+
+        let proprietary = parse_module_type(
+            Some("NVRM version: NVIDIA UNIX x86_64 Kernel Module  580.82.09  Fri Aug 29 2025\n"),
+            None,
+            None,
+        );
+        assert_eq!(proprietary.driver_type, DriverType::Proprietary);
+        assert_eq!(proprietary.version.as_deref(), Some("580.82.09"));
+
+        // Nothing loaded: the installed module is described from modinfo
+        let installed = parse_module_type(None, Some("NVIDIA"), Some("575.64"));
+        assert_eq!(installed.driver_type, DriverType::Proprietary);
+        assert!(!installed.loaded);
+        assert_eq!(
+            installed.summary(),
+            "nvidia (proprietary) 575.64, not loaded"
+        );
+
+        assert_eq!(
+            parse_module_type(None, None, None).driver_type,
+            DriverType::Unknown
+        );
+    }
+
     #[test]
     fn test_modprobe_generation() {
         let config = generate_modprobe_config();
@@ -9,13 +9,43 @@ pub struct LatencyInfo {
     pub nvidia_reflex_available: bool,
     pub nvidia_reflex_enabled: bool,
     pub current_cpu_scheduler: String,
-    pub gpu_scheduling_enabled: bool,
+    pub framebuffer_compression_enabled: bool,
     pub preemption_timeout: Option<u32>,
     pub frame_time_consistency: f32,
     pub estimated_input_lag_ms: f32,
     pub optimizations_applied: Vec<String>,
 }
 
+impl LatencyInfo {
+    /// Reflex state as shown by `nvctl latency status` and the GUI Latency tab
+    pub fn reflex_status(&self) -> &'static str {
+        match (self.nvidia_reflex_available, self.nvidia_reflex_enabled) {
+            (true, true) => "Enabled",
+            (true, false) => "Available",
+            (false, _) => "Not Available",
+        }
+    }
+
+    /// Framebuffer compression (`enable_fbc`) state as shown by the CLI and GUI
+    pub fn framebuffer_compression_status(&self) -> &'static str {
+        if self.framebuffer_compression_enabled {
+            "Enabled"
+        } else {
+            "Disabled"
+        }
+    }
+}
+
+/// Result of applying a latency mode
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyModeReport {
+    pub mode: String,
+    pub gpu: u32,
+    /// Changes that could not be made at runtime, with the exact
+    /// modprobe/kernel-parameter edit needed before the next reboot
+    pub reboot_required: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum LatencyMode {
     Default,
@@ -33,6 +63,41 @@ impl LatencyMode {
             LatencyMode::PowerSaver => "power_saver",
         }
     }
+
+    /// Parse a mode name as accepted by `nvctl latency set`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace('-', "_").as_str() {
+            "default" => Some(LatencyMode::Default),
+            "competitive" => Some(LatencyMode::Competitive),
+            "balanced" => Some(LatencyMode::Balanced),
+            "power_saver" | "powersaver" => Some(LatencyMode::PowerSaver),
+            _ => None,
+        }
+    }
+
+    /// NVreg_PreemptionTimeout (microseconds) this mode targets
+    pub fn preemption_timeout_us(&self) -> u32 {
+        match self {
+            LatencyMode::Competitive => 500,
+            LatencyMode::Balanced => 2000,
+            LatencyMode::PowerSaver => 10000,
+            LatencyMode::Default => DEFAULT_PREEMPTION_TIMEOUT_US,
+        }
+    }
+}
+
+const DEFAULT_PREEMPTION_TIMEOUT_US: u32 = 5000;
+const PREEMPTION_TIMEOUT_PARAM: &str = "/sys/module/nvidia/parameters/NVreg_PreemptionTimeout";
+const LATENCY_MODPROBE_CONF: &str = "/etc/modprobe.d/nvcontrol-latency.conf";
+
+/// Instructions for setting the preemption timeout persistently when the
+/// loaded nvidia module doesn't allow changing it at runtime
+pub fn preemption_timeout_reboot_hint(timeout_us: u32) -> String {
+    format!(
+        "NVreg_PreemptionTimeout={timeout_us} needs a module reload or reboot:\n  \
+         echo 'options nvidia NVreg_PreemptionTimeout={timeout_us}' | sudo tee {LATENCY_MODPROBE_CONF}\n  \
+         (or add kernel parameter: nvidia.NVreg_PreemptionTimeout={timeout_us})"
+    )
 }
 
 /// Get comprehensive latency information
@@ -41,7 +106,7 @@ pub fn get_latency_info() -> NvResult<LatencyInfo> {
         nvidia_reflex_available: false,
         nvidia_reflex_enabled: false,
         current_cpu_scheduler: get_cpu_scheduler()?,
-        gpu_scheduling_enabled: is_gpu_scheduling_enabled()?,
+        framebuffer_compression_enabled: is_framebuffer_compression_enabled()?,
         preemption_timeout: get_preemption_timeout()?,
         frame_time_consistency: measure_frame_time_consistency()?,
         estimated_input_lag_ms: estimate_input_lag()?,
@@ -64,14 +129,16 @@ pub fn optimize_latency() -> NvResult<()> {
 
     let mut applied_optimizations = Vec::new();
 
-    // 1. GPU Scheduling Optimization
-    if enable_gpu_scheduling()? {
-        applied_optimizations.push("GPU hardware scheduling enabled".to_string());
+    // 1. Framebuffer compression
+    if set_framebuffer_compression(0, true)? {
+        applied_optimizations.push("Framebuffer compression enabled".to_string());
     }
 
     // 2. Set low preemption timeout
     if set_preemption_timeout(1000)? {
         applied_optimizations.push("Preemption timeout set to 1ms".to_string());
+    } else {
+        println!("{}", preemption_timeout_reboot_hint(1000));
     }
 
     // 3. CPU scheduler optimization
@@ -109,129 +176,92 @@ pub fn optimize_latency() -> NvResult<()> {
 }
 
 /// Apply latency optimizations by mode
-pub fn set_latency_mode(mode: LatencyMode) -> NvResult<()> {
+pub fn set_latency_mode(mode: LatencyMode) -> NvResult<LatencyModeReport> {
+    set_latency_mode_for_gpu(mode, 0)
+}
+
+/// Apply latency optimizations by mode, targeting DRM card `gpu` for the
+/// framebuffer compression toggle
+pub fn set_latency_mode_for_gpu(mode: LatencyMode, gpu: u32) -> NvResult<LatencyModeReport> {
     println!("Setting latency mode: {}", mode.as_str());
 
     match mode {
         LatencyMode::Competitive => {
             // Maximum performance, minimum latency
-            enable_gpu_scheduling()?;
-            set_preemption_timeout(500)?; // Ultra-low preemption
+            set_framebuffer_compression(gpu, true)?;
             set_cpu_performance_mode()?;
             apply_nvidia_latency_optimizations()?;
             disable_cpu_mitigations()?; // For absolute minimum latency
         }
         LatencyMode::Balanced => {
             // Good latency with system stability
-            enable_gpu_scheduling()?;
-            set_preemption_timeout(2000)?; // Reasonable preemption
+            set_framebuffer_compression(gpu, true)?;
             optimize_cpu_scheduler()?;
             apply_nvidia_latency_optimizations()?;
         }
         LatencyMode::PowerSaver => {
             // Higher latency but lower power consumption
-            disable_gpu_scheduling()?;
-            set_preemption_timeout(10000)?; // Higher preemption for power saving
+            set_framebuffer_compression(gpu, false)?;
             set_cpu_powersave_mode()?;
         }
         LatencyMode::Default => {
             // Reset to system defaults
-            reset_gpu_scheduling()?;
-            reset_preemption_timeout()?;
+            set_framebuffer_compression(gpu, true)?;
             reset_cpu_scheduler()?;
         }
     }
 
-    println!("Latency mode '{}' applied successfully", mode.as_str());
-    Ok(())
-}
-
-// GPU Scheduling Functions
-
-fn is_gpu_scheduling_enabled() -> NvResult<bool> {
-    // Check Windows-style GPU scheduling on Linux (via wine/dxvk)
-    if Path::new("/sys/class/drm/card0/device/enable_fbc").exists() {
-        if let Ok(content) = fs::read_to_string("/sys/class/drm/card0/device/enable_fbc") {
-            return Ok(content.trim() == "1");
-        }
-    }
-
-    // Check NVIDIA-specific scheduling
-    let output = Command::new("nvidia-smi")
-        .args(&["-q", "-d", "COMPUTE"])
-        .output();
+    let mut report = LatencyModeReport {
+        mode: mode.as_str().to_string(),
+        gpu,
+        reboot_required: Vec::new(),
+    };
 
-    if let Ok(output) = output {
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        return Ok(output_str.contains("Compute Mode") && output_str.contains("Default"));
+    let timeout = mode.preemption_timeout_us();
+    if !set_preemption_timeout(timeout)? {
+        report
+            .reboot_required
+            .push(preemption_timeout_reboot_hint(timeout));
     }
 
-    Ok(false)
+    println!("Latency mode '{}' applied", mode.as_str());
+    Ok(report)
 }
 
-fn enable_gpu_scheduling() -> NvResult<bool> {
-    // Try to enable hardware-accelerated GPU scheduling
-
-    // Method 1: Direct sysfs approach
-    if Path::new("/sys/class/drm/card0/device/enable_fbc").exists() {
-        let result = Command::new("sudo")
-            .args(&["tee", "/sys/class/drm/card0/device/enable_fbc"])
-            .arg("1")
-            .output();
-
-        if result.is_ok() {
-            return Ok(true);
-        }
-    }
-
-    // Method 2: NVIDIA-specific settings
-    let result = Command::new("nvidia-settings")
-        .args(&["-a", "GPUGraphicsClockOffset[4]=0"])
-        .output();
-
-    if result.is_ok() {
-        return Ok(true);
-    }
-
-    // Method 3: Set environment variables for applications
-    crate::safe_env::set_vars([
-        ("__GL_THREADED_OPTIMIZATIONS", "1"),
-        ("__GL_SYNC_TO_VBLANK", "0"),
-    ]);
-
-    Ok(false) // Partial success - env vars set but no hardware changes
+fn fbc_path(gpu: u32) -> String {
+    format!("/sys/class/drm/card{}/device/enable_fbc", gpu)
 }
 
-fn disable_gpu_scheduling() -> NvResult<bool> {
-    if Path::new("/sys/class/drm/card0/device/enable_fbc").exists() {
-        let result = Command::new("sudo")
-            .args(&["tee", "/sys/class/drm/card0/device/enable_fbc"])
-            .arg("0")
-            .output();
+// Framebuffer Compression Functions
 
-        return Ok(result.is_ok());
+fn is_framebuffer_compression_enabled() -> NvResult<bool> {
+    match fs::read_to_string(fbc_path(0)) {
+        Ok(content) => Ok(content.trim() == "1"),
+        Err(_) => Ok(false),
     }
-
-    Ok(false)
 }
 
-fn reset_gpu_scheduling() -> NvResult<bool> {
-    // Reset to system default
-    if Path::new("/sys/class/drm/card0/device/enable_fbc").exists() {
-        let result = Command::new("sudo")
-            .args(&["tee", "/sys/class/drm/card0/device/enable_fbc"])
-            .arg("1")
-            .output();
-
-        return Ok(result.is_ok());
+/// Toggle framebuffer compression on DRM card `gpu`. Returns false when the
+/// attribute is missing or the write is refused.
+fn set_framebuffer_compression(gpu: u32, enabled: bool) -> NvResult<bool> {
+    let path = fbc_path(gpu);
+    if !Path::new(&path).exists() {
+        return Ok(false);
     }
 
-    Ok(false)
+    Ok(crate::gpu_safe::write_sysfs(&path, if enabled { "1" } else { "0" }).is_ok())
 }
 
 // Preemption Timeout Functions
 
 fn get_preemption_timeout() -> NvResult<Option<u32>> {
+    // The loaded module reports the effective value
+    if let Ok(value) = fs::read_to_string(PREEMPTION_TIMEOUT_PARAM) {
+        if let Ok(timeout) = value.trim().parse::<u32>() {
+            return Ok(Some(timeout));
+        }
+    }
+
     // Check current preemption timeout from kernel parameters
     if let Ok(cmdline) = fs::read_to_string("/proc/cmdline") {
         for param in cmdline.split_whitespace() {
@@ -248,29 +278,20 @@ fn get_preemption_timeout() -> NvResult<Option<u32>> {
     Ok(None)
 }
 
+/// Set the preemption timeout at runtime. Returns false when the module
+/// parameter is missing or read-only, i.e. a reboot is needed.
 fn set_preemption_timeout(timeout_us: u32) -> NvResult<bool> {
-    // This requires modifying kernel parameters or NVIDIA module parameters
-    // For runtime changes, we can try the sysfs approach
+    use std::os::unix::fs::PermissionsExt;
 
-    let timeout_path = "/sys/module/nvidia/parameters/NVreg_PreemptionTimeout";
-    if Path::new(timeout_path).exists() {
-        let result = Command::new("sudo")
-            .args(&["tee", timeout_path])
-            .arg(&timeout_us.to_string())
-            .output();
-
-        return Ok(result.is_ok());
+    // Most nvidia builds expose this parameter read-only (0444)
+    let writable = fs::metadata(PREEMPTION_TIMEOUT_PARAM)
+        .map(|meta| meta.permissions().mode() & 0o200 != 0)
+        .unwrap_or(false);
+    if !writable {
+        return Ok(false);
     }
 
-    // Alternative: suggest kernel parameter addition
-    println!("To set preemption timeout permanently, add to kernel parameters:");
-    println!("nvidia.NVreg_PreemptionTimeout={}", timeout_us);
-
-    Ok(false)
-}
-
-fn reset_preemption_timeout() -> NvResult<bool> {
-    set_preemption_timeout(5000) // Default 5ms
+    Ok(crate::gpu_safe::write_sysfs(PREEMPTION_TIMEOUT_PARAM, timeout_us.to_string()).is_ok())
 }
 
 // CPU Scheduler Functions
@@ -694,3 +715,28 @@ fn optimize_numa_settings() -> NvResult<()> {
         Ok(()) // NUMA tools not available
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_mode_parse() {
+        assert!(matches!(
+            LatencyMode::parse("Competitive"),
+            Some(LatencyMode::Competitive)
+        ));
+        assert!(matches!(
+            LatencyMode::parse("power-saver"),
+            Some(LatencyMode::PowerSaver)
+        ));
+        assert!(LatencyMode::parse("turbo").is_none());
+    }
+
+    #[test]
+    fn test_reboot_hint_has_modprobe_and_kernel_param() {
+        let hint = preemption_timeout_reboot_hint(500);
+        assert!(hint.contains("options nvidia NVreg_PreemptionTimeout=500"));
+        assert!(hint.contains("nvidia.NVreg_PreemptionTimeout=500"));
+    }
+}
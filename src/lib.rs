@@ -64,6 +64,9 @@ pub enum NvControlError {
     )]
     UnsupportedFeature(String),
 
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     #[error("Runtime error: {0}")]
     RuntimeError(String),
 
@@ -83,7 +86,9 @@ pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
 
+pub mod api_server;
 pub mod benchmark;
+pub mod cli_hint;
 pub mod companion;
 pub mod config;
 pub mod display;
@@ -91,6 +96,7 @@ pub mod display;
 pub mod display_controls;
 pub mod display_info;
 pub mod fan;
+pub mod fan_daemon;
 pub mod game_detection;
 pub mod game_scanner;
 pub mod gpu;
@@ -100,6 +106,7 @@ pub mod notifications;
 pub mod nvkms_bindings;
 pub mod osd;
 pub mod profiles;
+pub mod renderer;
 /// Safe environment variable wrappers (replaces scattered unsafe blocks)
 pub mod safe_env;
 pub mod setup;
@@ -131,6 +138,7 @@ pub mod cuda;
 pub mod error_recovery;
 pub mod gpu_safe;
 pub mod hardware_safety;
+pub mod logging;
 
 // Phase 2: Wayland-First Experience
 pub mod wayland_integration;
@@ -159,7 +167,10 @@ pub mod asus_gpu_tweak;
 pub mod asus_power_detector;
 
 // Monitoring and TUI modules
+pub mod graph_export;
 pub mod monitoring;
+pub mod throttle_log;
+pub mod tray;
 pub mod tui;
 
 // Arch KDE Wayland optimization modules
@@ -220,6 +231,9 @@ pub mod interactive_cli;
 // Enhanced error messages with solutions
 pub mod error_messages;
 
+// Markdown diagnostics report for bug reports
+pub mod report;
+
 // NVML backend abstraction for testability
 pub mod nvml_backend;
 
@@ -0,0 +1,244 @@
+//! Diagnostic logging to a size-capped file
+//!
+//! The apply paths (overclock, fan, RGB, vibrance) report through the `log`
+//! macros so failures can be diagnosed after the fact. Records are appended
+//! to `~/.local/share/nvcontrol/logs/nvcontrol.log`, which rotates once it
+//! reaches [`MAX_FILE_BYTES`]. Only [`KEEP_ROTATED`] older files are kept, so
+//! the directory never grows past roughly 10 MB.
+//!
+//! The CLI echoes records to the terminal as well; the GUI writes to the
+//! file only.
+
+use crate::{NvControlError, NvResult};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Size at which the active log file is rotated
+pub const MAX_FILE_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Rotated files kept next to the active one (`nvcontrol.log.1` ..)
+pub const KEEP_ROTATED: usize = 4;
+
+/// Verbosity when neither `--log-level` nor the config sets one
+pub const DEFAULT_LEVEL: LevelFilter = LevelFilter::Info;
+
+const LOG_FILE_NAME: &str = "nvcontrol.log";
+
+/// Where log records go besides the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Console {
+    /// Print records to the terminal too (CLI)
+    Echo,
+    /// Write to the log file only (GUI)
+    FileOnly,
+}
+
+/// Directory holding the active and rotated log files
+pub fn log_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("nvcontrol")
+        .join("logs")
+}
+
+/// Path of the active log file
+pub fn log_path() -> PathBuf {
+    log_dir().join(LOG_FILE_NAME)
+}
+
+/// Parse a level name (`off`, `error`, `warn`, `info`, `debug`, `trace`)
+pub fn parse_level(name: &str) -> NvResult<LevelFilter> {
+    name.trim().parse().map_err(|_| {
+        NvControlError::ConfigError(format!(
+            "Unknown log level '{name}' (use off, error, warn, info, debug or trace)"
+        ))
+    })
+}
+
+/// Level named by `NVCONTROL_LOG` or `RUST_LOG`, if set to a plain level
+/// such as `debug`
+pub fn env_level() -> Option<LevelFilter> {
+    ["NVCONTROL_LOG", "RUST_LOG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find_map(|value| parse_level(&value).ok())
+}
+
+/// Pick the verbosity: an explicit level (flag or environment) wins, then the config file, then
+/// [`DEFAULT_LEVEL`]. An unparseable config value falls back to the default.
+pub fn resolve_level(flag: Option<LevelFilter>, config_level: &str) -> LevelFilter {
+    if let Some(level) = flag {
+        return level;
+    }
+    if config_level.trim().is_empty() {
+        return DEFAULT_LEVEL;
+    }
+    parse_level(config_level).unwrap_or(DEFAULT_LEVEL)
+}
+
+/// Install the global logger. Only the first call has any effect.
+///
+/// If the log directory can't be created, records still reach the console
+/// (for [`Console::Echo`]) and are otherwise dropped.
+pub fn init(level: LevelFilter, console: Console) {
+    let file = match RotatingFile::open(log_path(), MAX_FILE_BYTES, KEEP_ROTATED) {
+        Ok(file) => Some(Mutex::new(file)),
+        Err(e) => {
+            if console == Console::Echo && level >= LevelFilter::Debug {
+                eprintln!("Warning: cannot open log file in {:?}: {e}", log_dir());
+            }
+            None
+        }
+    };
+
+    let logger = FileLogger { file, console };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+struct FileLogger {
+    file: Option<Mutex<RotatingFile>>,
+    console: Console,
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        if let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            let line = format!(
+                "{} {:<5} {}: {}\n",
+                chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f"),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+            let _ = file.write_line(&line);
+        }
+
+        if self.console == Console::Echo {
+            match record.level() {
+                Level::Error => eprintln!("Error: {}", record.args()),
+                Level::Warn => eprintln!("Warning: {}", record.args()),
+                _ => println!("{}", record.args()),
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file
+            && let Ok(mut file) = file.lock()
+        {
+            let _ = file.file.flush();
+        }
+    }
+}
+
+/// Append-only file that rotates to `<path>.1`, `<path>.2`, .. when full
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            size,
+            max_bytes,
+            keep,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let _ = fs::remove_file(rotated_path(&self.path, self.keep));
+        for n in (1..self.keep).rev() {
+            let _ = fs::rename(rotated_path(&self.path, n), rotated_path(&self.path, n + 1));
+        }
+        if self.keep > 0 {
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_level_precedence() {
+        assert_eq!(
+            resolve_level(Some(LevelFilter::Debug), "error"),
+            LevelFilter::Debug
+        );
+        assert_eq!(resolve_level(None, "warn"), LevelFilter::Warn);
+        assert_eq!(resolve_level(None, ""), DEFAULT_LEVEL);
+        assert_eq!(resolve_level(None, "chatty"), DEFAULT_LEVEL);
+        assert!(parse_level("chatty").is_err());
+    }
+
+    #[test]
+    fn test_rotation_caps_total_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOG_FILE_NAME);
+        let mut file = RotatingFile::open(path.clone(), 100, 2).unwrap();
+
+        let line = "0123456789012345678901234567890123456789\n";
+        for _ in 0..20 {
+            file.write_line(line).unwrap();
+        }
+
+        assert!(rotated_path(&path, 1).exists());
+        assert!(rotated_path(&path, 2).exists());
+        assert!(!rotated_path(&path, 3).exists());
+
+        let total: u64 = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().metadata().unwrap().len())
+            .sum();
+        assert!(total <= 300, "logs grew to {total} bytes");
+    }
+}
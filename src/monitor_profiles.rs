@@ -1,7 +1,9 @@
-use crate::NvResult;
+use crate::display_info::ConnectorIdentity;
 use crate::vibrance::EnhancedVibranceSettings;
+use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 /// Per-monitor profiles optimized for different display types
 /// Perfect for mixed setups like OLED + IPS, 4K + 1440p, etc.
@@ -358,6 +360,301 @@ pub fn detect_and_suggest_layout() -> NvResult<Vec<String>> {
     Ok(suggestions)
 }
 
+/// Settings applied to one physical monitor, wherever it is enumerated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorProfile {
+    /// Vibrance percentage (0-200, 100 = default)
+    pub vibrance: u32,
+    #[serde(default)]
+    pub resolution: Option<Resolution>,
+    #[serde(default)]
+    pub refresh_rate: Option<u32>,
+    #[serde(default)]
+    pub rotation: Option<crate::display::Orientation>,
+    /// Output scale factor, e.g. 1.25
+    #[serde(default)]
+    pub scale: Option<f32>,
+}
+
+impl Default for ConnectorProfile {
+    fn default() -> Self {
+        Self {
+            vibrance: 100,
+            resolution: None,
+            refresh_rate: None,
+            rotation: None,
+            scale: None,
+        }
+    }
+}
+
+/// Profiles keyed by connector name ("DP-2") or EDID serial ("edid:ABC123").
+/// An EDID serial match wins over a connector name match.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConnectorProfileMap {
+    #[serde(default)]
+    pub profiles: HashMap<String, ConnectorProfile>,
+}
+
+impl ConnectorProfileMap {
+    fn config_path() -> NvResult<PathBuf> {
+        let dir = dirs::config_dir()
+            .ok_or_else(|| NvControlError::ConfigError("No config directory".into()))?
+            .join("nvcontrol");
+        std::fs::create_dir_all(&dir).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to create config dir: {}", e))
+        })?;
+        Ok(dir.join("connector_profiles.toml"))
+    }
+
+    /// Load saved connector profiles (empty if none saved)
+    pub fn load() -> NvResult<Self> {
+        let path = Self::config_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to read: {}", e)))?;
+        toml::from_str(&contents)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to parse: {}", e)))
+    }
+
+    pub fn save(&self) -> NvResult<()> {
+        let contents = toml::to_string_pretty(self)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to serialize: {}", e)))?;
+        std::fs::write(Self::config_path()?, contents)
+            .map_err(|e| NvControlError::ConfigError(format!("Failed to write: {}", e)))
+    }
+
+    /// Store a mode in the profile under `key`, keeping its vibrance (or
+    /// the 100% default for a new profile)
+    pub fn set_mode(&mut self, key: &str, mode: &crate::display_info::CurrentMode) {
+        let profile = self.profiles.entry(key.to_string()).or_default();
+        profile.resolution = Some(Resolution {
+            width: mode.width,
+            height: mode.height,
+        });
+        profile.refresh_rate = Some(mode.refresh_hz.round() as u32);
+    }
+
+    /// Record the rotation for a connector, creating its profile if needed
+    pub fn set_rotation(&mut self, key: &str, orientation: crate::display::Orientation) {
+        self.profiles.entry(key.to_string()).or_default().rotation = Some(orientation);
+    }
+
+    /// Record the scale factor for a connector, creating its profile if needed
+    pub fn set_scale(&mut self, key: &str, factor: f32) {
+        self.profiles.entry(key.to_string()).or_default().scale = Some(factor);
+    }
+
+    /// Key for an EDID serial
+    pub fn edid_key(serial: &str) -> String {
+        format!("edid:{}", serial)
+    }
+
+    /// Find the profile for a connected output
+    pub fn resolve(&self, identity: &ConnectorIdentity) -> Option<&ConnectorProfile> {
+        identity
+            .edid_serial
+            .as_ref()
+            .and_then(|serial| self.profiles.get(&Self::edid_key(serial)))
+            .or_else(|| self.profiles.get(&identity.connector))
+    }
+
+    /// Apply profiles to the currently connected outputs
+    pub fn apply(&self) -> NvResult<Vec<String>> {
+        self.apply_to(&crate::display_info::get_connected_connectors())
+    }
+
+    /// Apply profiles to the given outputs, returning the connectors changed
+    pub fn apply_to(&self, connectors: &[ConnectorIdentity]) -> NvResult<Vec<String>> {
+        let mut applied = Vec::new();
+
+        for identity in connectors {
+            let Some(profile) = self.resolve(identity) else {
+                continue;
+            };
+
+            if let (Some(res), Some(rate)) = (&profile.resolution, profile.refresh_rate) {
+                set_connector_mode(&identity.connector, res, rate)?;
+            }
+            if let Some(orientation) = profile.rotation {
+                crate::display::set_rotation(&identity.connector, orientation)?;
+            }
+            if let Some(factor) = profile.scale {
+                crate::display::set_scale(&identity.connector, factor)?;
+            }
+
+            let index = identity.vibrance_index.ok_or_else(|| {
+                NvControlError::VibranceControlFailed(format!(
+                    "No NVKMS connector found for {}",
+                    identity.connector
+                ))
+            })?;
+            crate::vibrance_native::set_display_vibrance_native(0, index as u32, profile.vibrance)?;
+            applied.push(identity.connector.clone());
+        }
+
+        Ok(applied)
+    }
+}
+
+fn set_connector_mode(connector: &str, resolution: &Resolution, refresh_rate: u32) -> NvResult<()> {
+    crate::display::set_mode(
+        connector,
+        resolution.width,
+        resolution.height,
+        Some(refresh_rate as f32),
+    )
+    .map(|_| ())
+}
+
+/// Tracks connected outputs between polls so profiles can be re-applied
+/// when a known monitor reappears
+#[derive(Debug, Default)]
+pub struct ConnectorWatcher {
+    seen: HashSet<String>,
+}
+
+impl ConnectorWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Outputs that were not connected at the previous poll
+    pub fn poll(&mut self, current: &[ConnectorIdentity]) -> Vec<ConnectorIdentity> {
+        let appeared = current
+            .iter()
+            .filter(|c| !self.seen.contains(&c.connector))
+            .cloned()
+            .collect();
+        self.seen = current.iter().map(|c| c.connector.clone()).collect();
+        appeared
+    }
+}
+
+/// Quiet time after the last hotplug event before profiles are re-applied
+pub const HOTPLUG_DEBOUNCE: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Re-applies connector profiles when monitors are plugged in, woken up or
+/// switched back to through a KVM
+pub struct HotplugReapplier {
+    listener: crate::display::HotplugListener,
+    debouncer: crate::display::HotplugDebouncer,
+    watcher: ConnectorWatcher,
+}
+
+impl HotplugReapplier {
+    pub fn new(debounce: std::time::Duration) -> Self {
+        let listener = crate::display::HotplugListener::open();
+        // Polling already waits a full interval between checks
+        let quiet = if listener.source() == crate::display::HotplugSource::Poll {
+            std::time::Duration::ZERO
+        } else {
+            debounce
+        };
+        let mut debouncer = crate::display::HotplugDebouncer::new(quiet);
+        // Treat startup as a hotplug so connected monitors get their profiles
+        debouncer.event(std::time::Instant::now());
+        Self {
+            listener,
+            debouncer,
+            watcher: ConnectorWatcher::new(),
+        }
+    }
+
+    pub fn source(&self) -> crate::display::HotplugSource {
+        self.listener.source()
+    }
+
+    /// Handle hotplug events for up to `budget`, re-applying profiles once each
+    /// burst settles. Returns the connectors that were re-applied.
+    pub fn run_for(&mut self, budget: std::time::Duration) -> Vec<String> {
+        let deadline = std::time::Instant::now() + budget;
+        let mut applied = Vec::new();
+
+        loop {
+            let now = std::time::Instant::now();
+            if self.debouncer.take_ready(now) {
+                applied.extend(self.reapply());
+            }
+            let Some(left) = deadline
+                .checked_duration_since(now)
+                .filter(|d| !d.is_zero())
+            else {
+                return applied;
+            };
+            let wait = self
+                .debouncer
+                .remaining(now)
+                .map_or(left, |settle| settle.min(left));
+            if self.listener.wait(wait) {
+                self.debouncer.event(std::time::Instant::now());
+            }
+        }
+    }
+
+    fn reapply(&mut self) -> Vec<String> {
+        let profiles = match ConnectorProfileMap::load() {
+            Ok(profiles) if profiles.profiles.is_empty() => return Vec::new(),
+            Ok(profiles) => profiles,
+            Err(e) => {
+                log_hotplug(&format!(
+                    "Hotplug: failed to load connector profiles: {}",
+                    e
+                ));
+                return Vec::new();
+            }
+        };
+        let appeared = self
+            .watcher
+            .poll(&crate::display_info::get_connected_connectors());
+        if appeared.is_empty() {
+            return Vec::new();
+        }
+
+        match profiles.apply_to(&appeared) {
+            Ok(applied) => {
+                if !applied.is_empty() {
+                    log_hotplug(&format!(
+                        "Hotplug ({}): re-applied profiles to {}",
+                        self.source().label(),
+                        applied.join(", ")
+                    ));
+                }
+                applied
+            }
+            Err(e) => {
+                log_hotplug(&format!("Hotplug: failed to re-apply profiles: {}", e));
+                Vec::new()
+            }
+        }
+    }
+}
+
+fn log_hotplug(message: &str) {
+    println!(
+        "[{}] {}",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        message
+    );
+}
+
+/// Apply connector profiles whenever a known monitor is (re)connected.
+/// `interval` only matters when no hotplug event source is available.
+/// Blocks forever.
+pub fn watch_connector_profiles(interval: std::time::Duration) -> NvResult<()> {
+    let mut reapplier = HotplugReapplier::new(HOTPLUG_DEBOUNCE);
+    log_hotplug(&format!(
+        "Listening for display hotplug via {}",
+        reapplier.source().label()
+    ));
+
+    loop {
+        reapplier.run_for(interval);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,6 +676,50 @@ mod tests {
         assert_eq!(layout.monitors[1].display_type, DisplayType::IPS);
     }
 
+    fn identity(connector: &str, serial: Option<&str>) -> ConnectorIdentity {
+        ConnectorIdentity {
+            connector: connector.to_string(),
+            edid_serial: serial.map(str::to_string),
+            vibrance_index: Some(0),
+        }
+    }
+
+    #[test]
+    fn test_connector_profile_resolve_prefers_edid() {
+        let mut map = ConnectorProfileMap::default();
+        let profile = |vibrance| ConnectorProfile {
+            vibrance,
+            ..Default::default()
+        };
+        map.profiles.insert("DP-2".to_string(), profile(120));
+        map.profiles
+            .insert(ConnectorProfileMap::edid_key("MAIN01"), profile(150));
+
+        // Main monitor moved to a different port keeps its profile
+        let main = identity("DP-1", Some("MAIN01"));
+        assert_eq!(map.resolve(&main).map(|p| p.vibrance), Some(150));
+
+        let other = identity("DP-2", Some("OTHER"));
+        assert_eq!(map.resolve(&other).map(|p| p.vibrance), Some(120));
+
+        assert!(map.resolve(&identity("HDMI-A-1", None)).is_none());
+    }
+
+    #[test]
+    fn test_connector_watcher_reports_reconnects() {
+        let mut watcher = ConnectorWatcher::new();
+        let both = [identity("DP-1", None), identity("DP-2", None)];
+
+        assert_eq!(watcher.poll(&both).len(), 2);
+        assert!(watcher.poll(&both).is_empty());
+
+        // DP-2 unplugged then plugged back in
+        assert!(watcher.poll(&both[..1]).is_empty());
+        let appeared = watcher.poll(&both);
+        assert_eq!(appeared.len(), 1);
+        assert_eq!(appeared[0].connector, "DP-2");
+    }
+
     #[test]
     fn test_preset_layouts() {
         let layouts = get_preset_layouts();
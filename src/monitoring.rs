@@ -38,6 +38,104 @@ pub struct MetricSnapshot {
     pub memory_total_mb: f64,
 }
 
+/// Placeholders accepted by [`format_status_line`] and what they expand to
+pub const STATUS_PLACEHOLDERS: &[(&str, &str)] = &[
+    ("index", "GPU index"),
+    ("name", "GPU name"),
+    ("temp", "Temperature in °C"),
+    ("util", "GPU utilization %"),
+    ("mem_util", "Memory controller utilization %"),
+    ("power", "Power draw in W"),
+    ("power_limit", "Power limit in W"),
+    ("fan", "Fan speed %"),
+    ("gpu_clock", "Graphics clock in MHz"),
+    ("mem_clock", "Memory clock in MHz"),
+    ("vram_used", "VRAM in use in MiB"),
+    ("vram_total", "Total VRAM in MiB"),
+    ("vram_pct", "VRAM in use %"),
+];
+
+/// Expand a one-line status template such as `{temp}°C {util}% {power}W`
+/// for shell prompts and status bars. `{{` and `}}` are literal braces;
+/// unknown placeholders are an error rather than a blank.
+pub fn format_status_line(
+    template: &str,
+    info: &crate::nvml_backend::GpuDeviceInfo,
+    metrics: &crate::nvml_backend::GpuMetrics,
+    power_limit_mw: Option<u32>,
+) -> NvResult<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => {
+                            return Err(NvControlError::RuntimeError(format!(
+                                "Unclosed placeholder {{{} in status template",
+                                name
+                            )));
+                        }
+                    }
+                }
+                let value = match name.as_str() {
+                    "index" => info.index.to_string(),
+                    "name" => info.name.clone(),
+                    "temp" => metrics.temperature.to_string(),
+                    "util" => metrics.gpu_utilization.to_string(),
+                    "mem_util" => metrics.memory_controller_utilization.to_string(),
+                    "power" => (metrics.power_draw_mw / 1000).to_string(),
+                    "power_limit" => power_limit_mw
+                        .map(|mw| (mw / 1000).to_string())
+                        .unwrap_or_else(|| "?".to_string()),
+                    "fan" => metrics.fan_speed.to_string(),
+                    "gpu_clock" => metrics.gpu_clock_mhz.to_string(),
+                    "mem_clock" => metrics.memory_clock_mhz.to_string(),
+                    "vram_used" => (metrics.memory_used_bytes / 1024 / 1024).to_string(),
+                    "vram_total" => (metrics.memory_total_bytes / 1024 / 1024).to_string(),
+                    "vram_pct" => metrics.vram_used_percent().to_string(),
+                    _ => {
+                        let known: Vec<&str> =
+                            STATUS_PLACEHOLDERS.iter().map(|(name, _)| *name).collect();
+                        return Err(NvControlError::RuntimeError(format!(
+                            "Unknown placeholder {{{}}} in status template (available: {})",
+                            name,
+                            known.join(", ")
+                        )));
+                    }
+                };
+                out.push_str(&value);
+            }
+            '}' => {
+                return Err(NvControlError::RuntimeError(
+                    "Unmatched '}' in status template; use '}}' for a literal brace".to_string(),
+                ));
+            }
+            c => out.push(c),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Check a status template's placeholders without querying a GPU
+pub fn validate_status_template(template: &str) -> NvResult<()> {
+    format_status_line(template, &Default::default(), &Default::default(), None).map(|_| ())
+}
+
 /// Live GPU monitoring with text output (like htop but for GPU)
 pub fn live_gpu_watch(
     interval_seconds: u64,
@@ -228,7 +326,11 @@ pub fn export_gpu_metrics(
     Ok(())
 }
 
-fn collect_device_metrics(backend: &SharedNvmlBackend, gpu_id: u32) -> NvResult<MetricSnapshot> {
+/// Read one GPU's current metrics; fields NVML can't report are 0
+pub fn collect_device_metrics(
+    backend: &SharedNvmlBackend,
+    gpu_id: u32,
+) -> NvResult<MetricSnapshot> {
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
@@ -550,3 +652,746 @@ pub fn show_monitoring_status() {
     println!("   Interval: 1s");
     println!("   Output: live terminal session");
 }
+
+/// Parse a look-back window like "30m", "12h", "7d" or "2w"
+pub fn parse_since(spec: &str) -> Option<Duration> {
+    let spec = spec.trim();
+    let split = spec.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = spec.split_at(split);
+    let amount: u64 = amount.parse().ok()?;
+    let unit_secs = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount * unit_secs))
+}
+
+/// Min/avg/max of one metric over a period
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricStats {
+    pub min: f64,
+    pub avg: f64,
+    pub max: f64,
+}
+
+/// Per-day aggregate of logged samples for one GPU
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailySummary {
+    pub day: String,
+    pub gpu_index: u32,
+    pub samples: u64,
+    pub temperature: MetricStats,
+    pub power_draw: MetricStats,
+    pub gpu_clock: MetricStats,
+    pub fan_speed: MetricStats,
+    pub gpu_utilization: MetricStats,
+}
+
+/// Default location for the sample database
+pub fn default_sample_db_path() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("nvcontrol")
+        .join("samples.db")
+}
+
+/// Appends periodic GPU samples to a SQLite file for long-term trends.
+/// Writes happen on a dedicated thread so callers never block on disk.
+#[cfg(feature = "logging-sqlite")]
+pub struct SqliteLogger {
+    sender: Option<std::sync::mpsc::Sender<(u32, MetricSnapshot)>>,
+    writer: Option<thread::JoinHandle<NvResult<()>>>,
+}
+
+#[cfg(feature = "logging-sqlite")]
+const SAMPLES_SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS samples (
+        ts INTEGER NOT NULL,
+        gpu_index INTEGER NOT NULL,
+        temp REAL,
+        power REAL,
+        gpu_clock REAL,
+        mem_clock REAL,
+        fan REAL,
+        gpu_util REAL,
+        mem_util REAL,
+        mem_used_mb REAL
+    );
+    CREATE INDEX IF NOT EXISTS samples_ts ON samples (ts);
+";
+
+#[cfg(feature = "logging-sqlite")]
+fn sqlite_error(e: rusqlite::Error) -> NvControlError {
+    NvControlError::IoError(format!("SQLite: {}", e))
+}
+
+#[cfg(feature = "logging-sqlite")]
+impl SqliteLogger {
+    /// Open (creating if needed) the database and start the writer thread
+    pub fn open(path: &std::path::Path) -> NvResult<Self> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = rusqlite::Connection::open(path).map_err(sqlite_error)?;
+        conn.execute_batch(SAMPLES_SCHEMA).map_err(sqlite_error)?;
+
+        let (sender, receiver) = std::sync::mpsc::channel::<(u32, MetricSnapshot)>();
+        let writer = thread::spawn(move || {
+            for (gpu_index, sample) in receiver {
+                conn.execute(
+                    "INSERT INTO samples (ts, gpu_index, temp, power, gpu_clock, mem_clock, fan, gpu_util, mem_util, mem_used_mb)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                    rusqlite::params![
+                        sample.timestamp as i64,
+                        gpu_index,
+                        sample.temperature,
+                        sample.power_draw,
+                        sample.gpu_clock,
+                        sample.memory_clock,
+                        sample.fan_speed,
+                        sample.gpu_utilization,
+                        sample.memory_utilization,
+                        sample.memory_used_mb,
+                    ],
+                )
+                .map_err(sqlite_error)?;
+            }
+            Ok(())
+        });
+
+        Ok(Self {
+            sender: Some(sender),
+            writer: Some(writer),
+        })
+    }
+
+    /// Queue a sample for writing
+    pub fn log(&self, gpu_index: u32, sample: MetricSnapshot) -> NvResult<()> {
+        self.sender
+            .as_ref()
+            .and_then(|sender| sender.send((gpu_index, sample)).ok())
+            .ok_or_else(|| NvControlError::IoError("SQLite writer thread stopped".into()))
+    }
+
+    /// Flush pending samples and surface any write error
+    pub fn finish(mut self) -> NvResult<()> {
+        self.sender.take();
+        match self.writer.take().map(|writer| writer.join()) {
+            Some(Ok(result)) => result,
+            Some(Err(_)) => Err(NvControlError::RuntimeError(
+                "SQLite writer thread panicked".into(),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "logging-sqlite")]
+impl Drop for SqliteLogger {
+    fn drop(&mut self) {
+        self.sender.take();
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+/// Sample every GPU at `interval_seconds` and append to the database at `path`.
+/// Runs until `max_count` rounds have been logged (0 = forever).
+#[cfg(feature = "logging-sqlite")]
+pub fn log_samples_to_db(
+    path: &std::path::Path,
+    interval_seconds: u64,
+    max_count: u64,
+    backend: &SharedNvmlBackend,
+) -> NvResult<()> {
+    let device_count = backend.device_count()?;
+    if device_count == 0 {
+        return Err(NvControlError::DisplayDetectionFailed(
+            "No NVIDIA GPUs found".to_string(),
+        ));
+    }
+
+    let logger = SqliteLogger::open(path)?;
+    println!(
+        "💾 Logging {} GPU(s) to {} every {}s (Ctrl+C to stop)",
+        device_count,
+        path.display(),
+        interval_seconds
+    );
+
+    let mut count = 0;
+    while max_count == 0 || count < max_count {
+        for gpu_id in 0..device_count {
+            if let Ok(sample) = collect_device_metrics(backend, gpu_id) {
+                logger.log(gpu_id, sample)?;
+            }
+        }
+        count += 1;
+        thread::sleep(Duration::from_secs(interval_seconds.max(1)));
+    }
+
+    logger.finish()
+}
+
+/// Daily min/avg/max per GPU for samples newer than `since`
+#[cfg(feature = "logging-sqlite")]
+pub fn query_daily_summary(path: &std::path::Path, since: Duration) -> NvResult<Vec<DailySummary>> {
+    if !path.exists() {
+        return Err(NvControlError::IoError(format!(
+            "No sample database at {}",
+            path.display()
+        )));
+    }
+
+    let conn =
+        rusqlite::Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(sqlite_error)?;
+    let cutoff = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .saturating_sub(since)
+        .as_secs() as i64;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT date(ts, 'unixepoch', 'localtime') AS day, gpu_index, COUNT(*),
+                    MIN(temp), AVG(temp), MAX(temp),
+                    MIN(power), AVG(power), MAX(power),
+                    MIN(gpu_clock), AVG(gpu_clock), MAX(gpu_clock),
+                    MIN(fan), AVG(fan), MAX(fan),
+                    MIN(gpu_util), AVG(gpu_util), MAX(gpu_util)
+             FROM samples WHERE ts >= ?1
+             GROUP BY day, gpu_index ORDER BY day, gpu_index",
+        )
+        .map_err(sqlite_error)?;
+
+    let stats = |row: &rusqlite::Row, first: usize| -> rusqlite::Result<MetricStats> {
+        Ok(MetricStats {
+            min: row.get::<_, Option<f64>>(first)?.unwrap_or_default(),
+            avg: row.get::<_, Option<f64>>(first + 1)?.unwrap_or_default(),
+            max: row.get::<_, Option<f64>>(first + 2)?.unwrap_or_default(),
+        })
+    };
+
+    let rows = stmt
+        .query_map([cutoff], |row| {
+            Ok(DailySummary {
+                day: row.get(0)?,
+                gpu_index: row.get(1)?,
+                samples: row.get::<_, i64>(2)? as u64,
+                temperature: stats(row, 3)?,
+                power_draw: stats(row, 6)?,
+                gpu_clock: stats(row, 9)?,
+                fan_speed: stats(row, 12)?,
+                gpu_utilization: stats(row, 15)?,
+            })
+        })
+        .map_err(sqlite_error)?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(sqlite_error)
+}
+
+/// Metric an [`AlertRule`] watches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertMetric {
+    /// GPU core temperature in °C
+    Temperature,
+    /// Board power draw in watts
+    Power,
+    /// VRAM in use, percent of total
+    Vram,
+}
+
+impl AlertMetric {
+    pub const ALL: [AlertMetric; 3] = [Self::Temperature, Self::Power, Self::Vram];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Temperature => "Temperature",
+            Self::Power => "Power",
+            Self::Vram => "VRAM",
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::Temperature => "°C",
+            Self::Power => "W",
+            Self::Vram => "%",
+        }
+    }
+
+    /// Read this metric from an NVML sample
+    pub fn read(&self, metrics: &crate::nvml_backend::GpuMetrics) -> f64 {
+        match self {
+            Self::Temperature => metrics.temperature as f64,
+            Self::Power => metrics.power_draw_mw as f64 / 1000.0,
+            Self::Vram => metrics.vram_used_percent() as f64,
+        }
+    }
+}
+
+/// Which side of the threshold trips an [`AlertRule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparator {
+    Above,
+    Below,
+}
+
+impl Comparator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Above => ">",
+            Self::Below => "<",
+        }
+    }
+}
+
+fn default_alert_cooldown() -> u64 {
+    300
+}
+
+fn default_alert_hysteresis() -> f64 {
+    3.0
+}
+
+/// A user-defined threshold alert, stored in `config.toml` as `[[alert_rules]]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub metric: AlertMetric,
+    pub comparator: Comparator,
+    pub threshold: f64,
+    /// Minimum seconds between two notifications for the same rule and GPU
+    #[serde(default = "default_alert_cooldown")]
+    pub cooldown_secs: u64,
+    /// How far back past the threshold the metric must go before the rule
+    /// can fire again
+    #[serde(default = "default_alert_hysteresis")]
+    pub hysteresis: f64,
+    /// Only watch this GPU; `None` watches all of them
+    #[serde(default)]
+    pub gpu: Option<u32>,
+    /// Shell command run when the rule fires, with `NVCONTROL_ALERT_*` set
+    #[serde(default)]
+    pub command: Option<String>,
+}
+
+impl AlertRule {
+    pub fn new(metric: AlertMetric, comparator: Comparator, threshold: f64) -> Self {
+        Self {
+            metric,
+            comparator,
+            threshold,
+            cooldown_secs: default_alert_cooldown(),
+            hysteresis: default_alert_hysteresis(),
+            gpu: None,
+            command: None,
+        }
+    }
+
+    /// e.g. "Temperature > 85°C"
+    pub fn describe(&self) -> String {
+        format!(
+            "{} {} {}{}",
+            self.metric.label(),
+            self.comparator.symbol(),
+            self.threshold,
+            self.metric.unit()
+        )
+    }
+
+    fn tripped(&self, value: f64) -> bool {
+        match self.comparator {
+            Comparator::Above => value > self.threshold,
+            Comparator::Below => value < self.threshold,
+        }
+    }
+
+    fn cleared(&self, value: f64) -> bool {
+        match self.comparator {
+            Comparator::Above => value <= self.threshold - self.hysteresis,
+            Comparator::Below => value >= self.threshold + self.hysteresis,
+        }
+    }
+
+    /// Run the command hook in the background
+    pub fn run_hook(&self, firing: &AlertFiring) -> NvResult<()> {
+        let Some(command) = self.command.as_deref().filter(|c| !c.trim().is_empty()) else {
+            return Ok(());
+        };
+        Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .env(
+                "NVCONTROL_ALERT_METRIC",
+                format!("{:?}", self.metric).to_lowercase(),
+            )
+            .env("NVCONTROL_ALERT_VALUE", format!("{:.1}", firing.value))
+            .env("NVCONTROL_ALERT_THRESHOLD", self.threshold.to_string())
+            .env("NVCONTROL_ALERT_GPU", firing.gpu.to_string())
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| NvControlError::CommandFailed(format!("Alert hook failed: {}", e)))
+    }
+}
+
+/// Lowest "above" threshold watching `metric` on `gpu`: the first level a
+/// rising reading would trip, used to mark gauges
+pub fn upper_threshold(rules: &[AlertRule], metric: AlertMetric, gpu: u32) -> Option<f64> {
+    rules
+        .iter()
+        .filter(|rule| {
+            rule.metric == metric
+                && rule.comparator == Comparator::Above
+                && rule.gpu.is_none_or(|only| only == gpu)
+        })
+        .map(|rule| rule.threshold)
+        .min_by(f64::total_cmp)
+}
+
+/// A rule that just tripped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlertFiring {
+    /// Index into the rule list
+    pub rule: usize,
+    pub gpu: u32,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct AlertState {
+    /// Tripped and not yet back past the hysteresis band
+    active: bool,
+    last_fired: Option<Instant>,
+}
+
+/// Tracks fired state per rule and GPU between polls
+#[derive(Debug, Clone, Default)]
+pub struct AlertEvaluator {
+    states: std::collections::HashMap<(usize, u32), AlertState>,
+}
+
+impl AlertEvaluator {
+    /// Check one GPU's sample against every rule; returns the rules that fire
+    pub fn evaluate(
+        &mut self,
+        rules: &[AlertRule],
+        gpu: u32,
+        read: impl Fn(AlertMetric) -> Option<f64>,
+    ) -> Vec<AlertFiring> {
+        self.evaluate_at(rules, gpu, read, Instant::now())
+    }
+
+    fn evaluate_at(
+        &mut self,
+        rules: &[AlertRule],
+        gpu: u32,
+        read: impl Fn(AlertMetric) -> Option<f64>,
+        now: Instant,
+    ) -> Vec<AlertFiring> {
+        // Edited rule lists shift indices; drop state for rules that are gone
+        self.states.retain(|(rule, _), _| *rule < rules.len());
+
+        let mut firings = Vec::new();
+        for (index, rule) in rules.iter().enumerate() {
+            if rule.gpu.is_some_and(|only| only != gpu) {
+                continue;
+            }
+            let Some(value) = read(rule.metric) else {
+                continue;
+            };
+            let state = self.states.entry((index, gpu)).or_default();
+            if state.active {
+                if rule.cleared(value) {
+                    state.active = false;
+                }
+                continue;
+            }
+            if !rule.tripped(value) {
+                continue;
+            }
+            let cooling_down = state.last_fired.is_some_and(|last| {
+                now.duration_since(last) < Duration::from_secs(rule.cooldown_secs)
+            });
+            if cooling_down {
+                continue;
+            }
+            state.active = true;
+            state.last_fired = Some(now);
+            firings.push(AlertFiring {
+                rule: index,
+                gpu,
+                value,
+            });
+        }
+        firings
+    }
+}
+
+/// Default width of one clock histogram bin in MHz
+pub const CLOCK_BIN_MHZ: u32 = 50;
+
+/// One bin of a [`ClockHistogram`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ClockBin {
+    pub start_mhz: u32,
+    pub end_mhz: u32,
+    pub samples: u64,
+    /// Share of all samples, 0.0-1.0
+    pub fraction: f64,
+    /// Session time attributed to this bin
+    pub time: Duration,
+}
+
+/// How much of a session the GPU clock spent in each frequency bin.
+/// A card sustaining boost piles its samples into the top bins; one bouncing
+/// off a power or thermal limit spreads them out below the peak.
+#[derive(Debug, Clone)]
+pub struct ClockHistogram {
+    bin_mhz: u32,
+    bins: std::collections::BTreeMap<u32, u64>,
+    samples: u64,
+    since: Instant,
+}
+
+impl Default for ClockHistogram {
+    fn default() -> Self {
+        Self::new(CLOCK_BIN_MHZ)
+    }
+}
+
+impl ClockHistogram {
+    pub fn new(bin_mhz: u32) -> Self {
+        Self {
+            bin_mhz: bin_mhz.max(1),
+            bins: Default::default(),
+            samples: 0,
+            since: Instant::now(),
+        }
+    }
+
+    /// Add one sampled `gpu_clock` reading
+    pub fn record(&mut self, clock_mhz: u32) {
+        let start = clock_mhz / self.bin_mhz * self.bin_mhz;
+        *self.bins.entry(start).or_default() += 1;
+        self.samples += 1;
+    }
+
+    /// Forget every sample and restart the session clock
+    pub fn reset(&mut self) {
+        self.bins.clear();
+        self.samples = 0;
+        self.since = Instant::now();
+    }
+
+    pub fn total_samples(&self) -> u64 {
+        self.samples
+    }
+
+    pub fn session_duration(&self) -> Duration {
+        self.since.elapsed()
+    }
+
+    /// Non-empty bins, highest clock first
+    pub fn bins(&self) -> Vec<ClockBin> {
+        let session = self.session_duration();
+        self.bins
+            .iter()
+            .rev()
+            .map(|(&start, &samples)| {
+                let fraction = samples as f64 / self.samples as f64;
+                ClockBin {
+                    start_mhz: start,
+                    end_mhz: start + self.bin_mhz,
+                    samples,
+                    fraction,
+                    time: session.mul_f64(fraction),
+                }
+            })
+            .collect()
+    }
+
+    /// Peak bin start and the share of samples within `window_mhz` of it
+    pub fn share_near_peak(&self, window_mhz: u32) -> Option<(u32, f64)> {
+        let (&peak, _) = self.bins.last_key_value()?;
+        let floor = peak.saturating_sub(window_mhz);
+        let near: u64 = self.bins.range(floor..).map(|(_, n)| n).sum();
+        Some((peak, near as f64 / self.samples as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_status_line() {
+        let info = crate::nvml_backend::GpuDeviceInfo {
+            index: 1,
+            name: "RTX 5090".to_string(),
+            ..Default::default()
+        };
+        let metrics = crate::nvml_backend::GpuMetrics {
+            temperature: 64,
+            gpu_utilization: 97,
+            power_draw_mw: 451_800,
+            memory_used_bytes: 8 * 1024 * 1024 * 1024,
+            memory_total_bytes: 32 * 1024 * 1024 * 1024,
+            ..Default::default()
+        };
+        let line = |t: &str| format_status_line(t, &info, &metrics, Some(575_000));
+
+        assert_eq!(line("{temp}°C {util}% {power}W").unwrap(), "64°C 97% 451W");
+        assert_eq!(
+            line("{{{index}}} {name} {vram_used}/{vram_total} {vram_pct}% of {power_limit}W")
+                .unwrap(),
+            "{1} RTX 5090 8192/32768 25% of 575W"
+        );
+        assert_eq!(
+            format_status_line("{power_limit}", &info, &metrics, None).unwrap(),
+            "?"
+        );
+
+        let err = line("{temp} {tmep}").unwrap_err().to_string();
+        assert!(err.contains("{tmep}"), "{}", err);
+        assert!(line("{temp").is_err());
+        assert!(line("temp}").is_err());
+        assert!(validate_status_template("{fan}% {gpu_clock}/{mem_clock}").is_ok());
+    }
+
+    #[test]
+    fn test_alert_rule_hysteresis_and_cooldown() {
+        let mut rule = AlertRule::new(AlertMetric::Temperature, Comparator::Above, 85.0);
+        rule.cooldown_secs = 60;
+        let rules = vec![rule];
+        let mut evaluator = AlertEvaluator::default();
+        let t0 = Instant::now();
+        let mut check = |temp: f64, secs: u64| {
+            evaluator
+                .evaluate_at(&rules, 0, |_| Some(temp), t0 + Duration::from_secs(secs))
+                .len()
+        };
+
+        assert_eq!(check(80.0, 0), 0);
+        assert_eq!(check(87.0, 1), 1);
+        // Still hot: no repeat
+        assert_eq!(check(88.0, 2), 0);
+        // Dipping inside the hysteresis band keeps it fired
+        assert_eq!(check(84.0, 3), 0);
+        assert_eq!(check(86.0, 4), 0);
+        // Cleared, but re-tripping inside the cooldown stays quiet
+        assert_eq!(check(81.0, 5), 0);
+        assert_eq!(check(86.0, 10), 0);
+        assert_eq!(check(86.0, 70), 1);
+
+        assert_eq!(rules[0].describe(), "Temperature > 85°C");
+    }
+
+    #[test]
+    fn test_upper_threshold_picks_lowest_matching_rule() {
+        let mut other_gpu = AlertRule::new(AlertMetric::Temperature, Comparator::Above, 70.0);
+        other_gpu.gpu = Some(1);
+        let rules = vec![
+            AlertRule::new(AlertMetric::Temperature, Comparator::Above, 88.0),
+            AlertRule::new(AlertMetric::Temperature, Comparator::Above, 83.0),
+            AlertRule::new(AlertMetric::Temperature, Comparator::Below, 30.0),
+            AlertRule::new(AlertMetric::Power, Comparator::Above, 400.0),
+            other_gpu,
+        ];
+
+        assert_eq!(
+            upper_threshold(&rules, AlertMetric::Temperature, 0),
+            Some(83.0)
+        );
+        assert_eq!(
+            upper_threshold(&rules, AlertMetric::Temperature, 1),
+            Some(70.0)
+        );
+        assert_eq!(upper_threshold(&rules, AlertMetric::Power, 0), Some(400.0));
+        assert_eq!(upper_threshold(&rules, AlertMetric::Vram, 0), None);
+    }
+
+    #[test]
+    fn test_clock_histogram_bins() {
+        let mut histogram = ClockHistogram::new(50);
+        assert!(histogram.share_near_peak(100).is_none());
+
+        for clock in [2745, 2760, 2790, 2700, 2520, 2745, 210, 2755] {
+            histogram.record(clock);
+        }
+        let bins = histogram.bins();
+        assert_eq!(histogram.total_samples(), 8);
+        assert_eq!(bins[0].start_mhz, 2750);
+        assert_eq!(bins[0].samples, 3);
+        assert_eq!(bins[1].start_mhz, 2700);
+        assert_eq!(bins[1].samples, 3);
+        assert_eq!(bins.last().unwrap().start_mhz, 200);
+        assert!((bins.iter().map(|b| b.fraction).sum::<f64>() - 1.0).abs() < 1e-9);
+
+        // Six of eight samples sit within 100 MHz of the 2750 MHz peak bin
+        let (peak, share) = histogram.share_near_peak(100).unwrap();
+        assert_eq!(peak, 2750);
+        assert!((share - 0.75).abs() < 1e-9);
+
+        histogram.reset();
+        assert_eq!(histogram.total_samples(), 0);
+        assert!(histogram.bins().is_empty());
+    }
+
+    #[test]
+    fn test_parse_since() {
+        assert_eq!(parse_since("7d"), Some(Duration::from_secs(7 * 86_400)));
+        assert_eq!(parse_since("12h"), Some(Duration::from_secs(12 * 3600)));
+        assert_eq!(parse_since("30m"), Some(Duration::from_secs(1800)));
+        assert_eq!(parse_since("7"), None);
+        assert_eq!(parse_since("d"), None);
+        assert_eq!(parse_since("3y"), None);
+    }
+
+    #[cfg(feature = "logging-sqlite")]
+    #[test]
+    fn test_sqlite_logger_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("samples.db");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let logger = SqliteLogger::open(&path).unwrap();
+        for (temp, power) in [(40.0, 100.0), (60.0, 300.0)] {
+            logger
+                .log(
+                    0,
+                    MetricSnapshot {
+                        timestamp: now,
+                        gpu_utilization: 50.0,
+                        memory_utilization: 10.0,
+                        temperature: temp,
+                        power_draw: power,
+                        fan_speed: 30.0,
+                        gpu_clock: 1800.0,
+                        memory_clock: 10000.0,
+                        memory_used_mb: 1024.0,
+                        memory_total_mb: 8192.0,
+                    },
+                )
+                .unwrap();
+        }
+        logger.finish().unwrap();
+
+        let days = query_daily_summary(&path, Duration::from_secs(86_400)).unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].samples, 2);
+        assert_eq!(days[0].temperature.min, 40.0);
+        assert_eq!(days[0].temperature.avg, 50.0);
+        assert_eq!(days[0].power_draw.max, 300.0);
+    }
+}
@@ -4,6 +4,7 @@
 use crate::nvml_backend::SharedNvmlBackend;
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpuInfo {
@@ -22,6 +23,66 @@ pub struct GpuInfo {
     pub is_primary: bool,
     pub sli_enabled: bool,
     pub nvlink_enabled: bool,
+    /// Nickname from the `[gpu_labels]` config, matched on `pci_bus_id`
+    #[serde(default)]
+    pub nickname: Option<String>,
+}
+
+/// Nickname and accent color for one GPU, configured per PCI bus id:
+///
+/// ```toml
+/// [gpu_labels."01:00.0"]
+/// nickname = "Render"
+/// color = "#7aa2f7"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GpuLabel {
+    pub nickname: Option<String>,
+    /// Accent color as `#rrggbb`
+    pub color: Option<String>,
+}
+
+impl GpuLabel {
+    /// Accent color, if set and valid
+    pub fn accent(&self) -> Option<crate::themes::Color> {
+        self.color
+            .as_deref()
+            .and_then(crate::themes::Color::from_hex_str)
+    }
+}
+
+/// Label configured for a GPU. Keys match with or without the PCI domain and
+/// in any case, so "01:00.0" matches NVML's "00000000:01:00.0".
+pub fn gpu_label<'a>(
+    labels: &'a BTreeMap<String, GpuLabel>,
+    pci_bus_id: &str,
+) -> Option<&'a GpuLabel> {
+    let bus_and_device = |id: &str| {
+        id.trim()
+            .rsplitn(3, ':')
+            .take(2)
+            .collect::<Vec<_>>()
+            .join(":")
+            .to_lowercase()
+    };
+    let wanted = bus_and_device(pci_bus_id);
+    labels
+        .iter()
+        .find(|(key, _)| bus_and_device(key) == wanted)
+        .map(|(_, label)| label)
+}
+
+/// A GPU's nickname, falling back to "GPU N" (1-based, as in the TUI)
+pub fn gpu_display_name(nickname: Option<&str>, index: u32) -> String {
+    match nickname.map(str::trim) {
+        Some(nickname) if !nickname.is_empty() => nickname.to_string(),
+        _ => format!("GPU {}", index + 1),
+    }
+}
+
+fn configured_nickname(labels: &BTreeMap<String, GpuLabel>, pci_bus_id: &str) -> Option<String> {
+    gpu_label(labels, pci_bus_id).and_then(|label| label.nickname.clone())
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -31,8 +92,12 @@ pub struct MultiGpuConfig {
     pub per_gpu_profiles: std::collections::HashMap<u32, String>, // GPU index -> profile name
 }
 
-/// Detect all NVIDIA GPUs in the system (using backend)
-pub fn detect_gpus_with_backend(backend: &SharedNvmlBackend) -> NvResult<Vec<GpuInfo>> {
+/// Detect all NVIDIA GPUs in the system (using backend), taking nicknames
+/// from the caller's `gpu_labels` config
+pub fn detect_gpus_with_backend(
+    backend: &SharedNvmlBackend,
+    labels: &BTreeMap<String, GpuLabel>,
+) -> NvResult<Vec<GpuInfo>> {
     let device_count = backend.device_count()?;
     let driver_version = backend
         .get_driver_version()
@@ -75,6 +140,7 @@ pub fn detect_gpus_with_backend(backend: &SharedNvmlBackend) -> NvResult<Vec<Gpu
             index: i,
             name,
             uuid,
+            nickname: configured_nickname(labels, &pci_bus_id),
             pci_bus_id,
             driver_version: driver_version.clone(),
             vram_total,
@@ -96,13 +162,17 @@ pub fn detect_gpus_with_backend(backend: &SharedNvmlBackend) -> NvResult<Vec<Gpu
 }
 
 /// Detect all NVIDIA GPUs in the system (legacy - creates own backend)
-pub fn detect_gpus() -> NvResult<Vec<GpuInfo>> {
+pub fn detect_gpus(labels: &BTreeMap<String, GpuLabel>) -> NvResult<Vec<GpuInfo>> {
     let backend = crate::nvml_backend::create_real_backend();
-    detect_gpus_with_backend(&backend)
+    detect_gpus_with_backend(&backend, labels)
 }
 
 /// Get detailed information for a specific GPU (using backend)
-pub fn get_gpu_info_with_backend(index: u32, backend: &SharedNvmlBackend) -> NvResult<GpuInfo> {
+pub fn get_gpu_info_with_backend(
+    index: u32,
+    backend: &SharedNvmlBackend,
+    labels: &BTreeMap<String, GpuLabel>,
+) -> NvResult<GpuInfo> {
     let device_count = backend.device_count()?;
     if index >= device_count {
         return Err(NvControlError::GpuQueryFailed(format!(
@@ -152,6 +222,7 @@ pub fn get_gpu_info_with_backend(index: u32, backend: &SharedNvmlBackend) -> NvR
         index,
         name,
         uuid,
+        nickname: configured_nickname(labels, &pci_bus_id),
         pci_bus_id,
         driver_version,
         vram_total,
@@ -168,9 +239,9 @@ pub fn get_gpu_info_with_backend(index: u32, backend: &SharedNvmlBackend) -> NvR
 }
 
 /// Get detailed information for a specific GPU (legacy - creates own backend)
-pub fn get_gpu_info(index: u32) -> NvResult<GpuInfo> {
+pub fn get_gpu_info(index: u32, labels: &BTreeMap<String, GpuLabel>) -> NvResult<GpuInfo> {
     let backend = crate::nvml_backend::create_real_backend();
-    get_gpu_info_with_backend(index, &backend)
+    get_gpu_info_with_backend(index, &backend, labels)
 }
 
 /// Get GPU count (using backend)
@@ -247,7 +318,10 @@ pub fn get_primary_gpu() -> u32 {
 /// Print GPU information
 pub fn print_gpu_info(gpu: &GpuInfo) {
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-    println!("🎮 GPU {} - {}", gpu.index, gpu.name);
+    match &gpu.nickname {
+        Some(nickname) => println!("🎮 GPU {} - {} ({})", gpu.index, nickname, gpu.name),
+        None => println!("🎮 GPU {} - {}", gpu.index, gpu.name),
+    }
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!("  UUID:         {}", gpu.uuid);
     println!("  PCI Bus ID:   {}", gpu.pci_bus_id);
@@ -273,8 +347,8 @@ pub fn print_gpu_info(gpu: &GpuInfo) {
 }
 
 /// List all GPUs
-pub fn list_gpus_cli() -> NvResult<()> {
-    let gpus = detect_gpus()?;
+pub fn list_gpus_cli(labels: &BTreeMap<String, GpuLabel>) -> NvResult<()> {
+    let gpus = detect_gpus(labels)?;
 
     if gpus.is_empty() {
         println!("❌ No NVIDIA GPUs detected");
@@ -290,10 +364,191 @@ pub fn list_gpus_cli() -> NvResult<()> {
     Ok(())
 }
 
+/// What happened on one GPU when a setting was broadcast to all of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GpuApplyOutcome {
+    Applied,
+    /// The GPU doesn't support this control
+    Skipped(String),
+    Failed(String),
+}
+
+/// Per-GPU results of applying one setting to every GPU
+#[derive(Debug, Clone, Default)]
+pub struct BatchApplyReport {
+    pub results: Vec<(u32, GpuApplyOutcome)>,
+}
+
+impl BatchApplyReport {
+    fn count(&self, matches: impl Fn(&GpuApplyOutcome) -> bool) -> usize {
+        self.results.iter().filter(|(_, o)| matches(o)).count()
+    }
+
+    pub fn applied(&self) -> usize {
+        self.count(|o| *o == GpuApplyOutcome::Applied)
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.count(|o| matches!(o, GpuApplyOutcome::Skipped(_)))
+    }
+
+    pub fn failed(&self) -> usize {
+        self.count(|o| matches!(o, GpuApplyOutcome::Failed(_)))
+    }
+
+    /// One-line summary, e.g. "Applied to 3 of 4 GPUs (1 skipped)"
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "Applied to {} of {} GPUs",
+            self.applied(),
+            self.results.len()
+        );
+        let extra: Vec<String> = [(self.skipped(), "skipped"), (self.failed(), "failed")]
+            .into_iter()
+            .filter(|(n, _)| *n > 0)
+            .map(|(n, label)| format!("{} {}", n, label))
+            .collect();
+        if !extra.is_empty() {
+            summary.push_str(&format!(" ({})", extra.join(", ")));
+        }
+        summary
+    }
+}
+
+/// Run `apply` on every GPU and collect a result per device.
+///
+/// GPUs that report the control as unsupported are skipped and other errors
+/// are recorded, so one device never aborts the rest of the batch.
+pub fn apply_to_all_gpus(
+    backend: &SharedNvmlBackend,
+    mut apply: impl FnMut(u32) -> NvResult<()>,
+) -> NvResult<BatchApplyReport> {
+    let mut report = BatchApplyReport::default();
+    for index in 0..backend.device_count()? {
+        let outcome = match apply(index) {
+            Ok(()) => GpuApplyOutcome::Applied,
+            Err(NvControlError::FanControlNotSupported) => {
+                GpuApplyOutcome::Skipped("fan control not supported".to_string())
+            }
+            Err(NvControlError::UnsupportedFeature(reason)) => {
+                GpuApplyOutcome::Skipped(reason.lines().next().unwrap_or_default().to_string())
+            }
+            Err(e) => GpuApplyOutcome::Failed(
+                e.to_string().lines().next().unwrap_or_default().to_string(),
+            ),
+        };
+        report.results.push((index, outcome));
+    }
+    Ok(report)
+}
+
+/// Apply clock offsets from `profile` to every GPU
+pub fn apply_overclock_all(
+    backend: &SharedNvmlBackend,
+    profile: &crate::overclocking::OverclockProfile,
+) -> NvResult<BatchApplyReport> {
+    apply_to_all_gpus(backend, |index| {
+        crate::overclocking::apply_overclock_profile_for_gpu(profile, index)
+    })
+}
+
+/// Set the same power limit on every GPU, clamped to each board's range
+pub fn set_power_limit_all(
+    backend: &SharedNvmlBackend,
+    target: crate::power::PowerLimitTarget,
+) -> NvResult<BatchApplyReport> {
+    use crate::power::PowerLimitTarget;
+
+    apply_to_all_gpus(backend, |index| {
+        match target {
+            PowerLimitTarget::Watts(watts) => {
+                crate::power::set_power_limit_watts(backend, index, watts)
+            }
+            PowerLimitTarget::Percent(percent) => {
+                crate::power::set_power_limit_percent(backend, index, percent)
+            }
+        }
+        .map(|_| ())
+    })
+}
+
+/// Apply a fan curve to every fan of every GPU that allows fan control.
+///
+/// Each fan's curve is also saved for `nvctl fan daemon`.
+pub fn apply_fan_curve_all(
+    backend: &SharedNvmlBackend,
+    curve: &crate::fan::FanCurve,
+) -> NvResult<BatchApplyReport> {
+    apply_to_all_gpus(backend, |index| {
+        if !backend.is_fan_control_supported(index) {
+            return Err(NvControlError::FanControlNotSupported);
+        }
+        let temp = backend.get_temperature(index)?.min(100) as u8;
+        for fan in 0..backend.get_fan_count(index).unwrap_or(1) {
+            // Fan ids follow fan::list_fans: GPU index * 10 + fan index
+            let fan_id = (index * 10 + fan) as usize;
+            crate::fan::apply_fan_curve(fan_id, curve, temp, None)?;
+            if let Err(e) = crate::fan_daemon::persist_curve(fan_id, curve) {
+                log::warn!("Fan {} curve not saved for the fan daemon: {}", fan_id, e);
+            }
+        }
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_gpu_label_matches_bus_id() {
+        let mut labels = BTreeMap::new();
+        labels.insert(
+            "01:00.0".to_string(),
+            GpuLabel {
+                nickname: Some("Render".to_string()),
+                color: Some("#7aa2f7".to_string()),
+            },
+        );
+        labels.insert("0000:0A:00.0".to_string(), GpuLabel::default());
+
+        let label = gpu_label(&labels, "00000000:01:00.0").unwrap();
+        assert_eq!(
+            configured_nickname(&labels, "00000000:01:00.0").as_deref(),
+            Some("Render")
+        );
+        let accent = label.accent().unwrap();
+        assert_eq!((accent.r, accent.g, accent.b), (0x7a, 0xa2, 0xf7));
+        assert!(gpu_label(&labels, "00000000:0a:00.0").is_some());
+        assert!(gpu_label(&labels, "00000000:02:00.0").is_none());
+
+        assert_eq!(gpu_display_name(Some("Render"), 0), "Render");
+        assert_eq!(gpu_display_name(Some("  "), 1), "GPU 2");
+        assert_eq!(gpu_display_name(None, 2), "GPU 3");
+    }
+
+    #[test]
+    fn test_batch_apply_skips_unsupported() {
+        let backend: SharedNvmlBackend =
+            std::sync::Arc::new(crate::nvml_backend::MockNvmlBackend::multi_gpu(3));
+        let report = apply_to_all_gpus(&backend, |index| match index {
+            0 => Ok(()),
+            1 => Err(NvControlError::FanControlNotSupported),
+            _ => Err(NvControlError::RuntimeError("busy".to_string())),
+        })
+        .unwrap();
+
+        assert_eq!(report.results.len(), 3);
+        assert_eq!(
+            (report.applied(), report.skipped(), report.failed()),
+            (1, 1, 1)
+        );
+        assert_eq!(
+            report.summary(),
+            "Applied to 1 of 3 GPUs (1 skipped, 1 failed)"
+        );
+    }
+
     #[test]
     fn test_gpu_count() {
         // This test may fail if no GPUs are present
@@ -43,6 +43,9 @@ pub struct AlertConfig {
     pub power_warning: u32,         // Watts
     pub fan_failure_threshold: u32, // RPM (if below this, alert)
     pub cooldown_seconds: u64,      // Minimum time between same alerts
+    /// Notify when the GPU starts thermal throttling (opt-in)
+    #[serde(default)]
+    pub thermal_throttle_alert: bool,
 }
 
 impl Default for AlertConfig {
@@ -54,6 +57,7 @@ impl Default for AlertConfig {
             power_warning: 350,
             fan_failure_threshold: 500,
             cooldown_seconds: 300, // 5 minutes
+            thermal_throttle_alert: false,
         }
     }
 }
@@ -67,6 +71,10 @@ pub enum AlertType {
     GpuError,
     ProfileApplied,
     OverclockApplied,
+    WatchdogReset,
+    DriverChanged,
+    DriverUpdateAvailable,
+    ThermalThrottle,
 }
 
 pub struct NotificationManager {
@@ -294,6 +302,98 @@ impl NotificationManager {
         Ok(())
     }
 
+    /// Notify that a GPU began thermal throttling, if enabled in alerts.toml
+    pub fn notify_thermal_throttle(
+        &mut self,
+        gpu_index: u32,
+        reasons: crate::nvml_backend::ThrottleReasons,
+    ) -> NvResult<()> {
+        if self.config.thermal_throttle_alert && self.should_alert(AlertType::ThermalThrottle) {
+            self.send_notification(
+                "🔥 GPU Thermal Throttling",
+                &format!(
+                    "GPU {} is lowering clocks: {}",
+                    gpu_index,
+                    reasons.summary()
+                ),
+                Urgency::Normal,
+                Timeout::Milliseconds(5000),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Notify that a user alert rule fired and run its command hook. The rule
+    /// carries its own cooldown, so the per-type cooldown doesn't apply.
+    pub fn notify_alert_rule(
+        &self,
+        rule: &crate::monitoring::AlertRule,
+        firing: &crate::monitoring::AlertFiring,
+    ) -> NvResult<()> {
+        rule.run_hook(firing)?;
+        if !self.config.enabled {
+            return Ok(());
+        }
+        self.send_notification(
+            "🔔 GPU Alert",
+            &format!(
+                "GPU {}: {} (now {:.1}{})",
+                firing.gpu,
+                rule.describe(),
+                firing.value,
+                rule.metric.unit()
+            ),
+            Urgency::Normal,
+            Timeout::Milliseconds(8000),
+        )
+    }
+
+    /// Notify that the overclock watchdog returned the GPU to stock
+    pub fn notify_watchdog_reset(&mut self, reason: &str) -> NvResult<()> {
+        if self.should_alert(AlertType::WatchdogReset) {
+            self.send_notification(
+                "🛡️ Overclock Reset to Stock",
+                &format!("The overclock watchdog reverted your GPU:\n{}", reason),
+                Urgency::Critical,
+                Timeout::Never,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Notify that the installed driver changed since the last start
+    pub fn notify_driver_changed(
+        &mut self,
+        change: &crate::drivers::DriverVersionChange,
+    ) -> NvResult<()> {
+        if self.should_alert(AlertType::DriverChanged) {
+            let mut body = format!("{} → {}", change.previous_version, change.current_version);
+            for line in &change.changes {
+                body.push_str(&format!("\n• {}", line));
+            }
+            self.send_notification(
+                "🔄 NVIDIA Driver Updated",
+                &body,
+                Urgency::Normal,
+                Timeout::Milliseconds(8000),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Notify that NVIDIA has published a newer driver than the installed one
+    pub fn notify_driver_available(&mut self, current: &str, latest: &str) -> NvResult<()> {
+        if self.should_alert(AlertType::DriverUpdateAvailable) {
+            self.send_notification(
+                "📦 New NVIDIA Driver Available",
+                &format!("Driver {} is available (installed: {})", latest, current),
+                Urgency::Low,
+                Timeout::Milliseconds(8000),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Check all GPU metrics at once
     pub fn check_all_metrics(&mut self, temp: f32, power: f32, fan_rpm: u32) -> NvResult<()> {
         self.check_temperature(temp)?;
@@ -394,6 +494,26 @@ impl NotificationManager {
     }
 }
 
+/// Startup check: notify when the driver changed since the last run and,
+/// when `[driver] check_latest_online` is set, when NVIDIA publishes a newer one
+pub fn check_driver_on_startup() -> NvResult<()> {
+    let mut manager = NotificationManager::new()?;
+
+    if let Some(change) = crate::drivers::check_driver_version_change()? {
+        manager.notify_driver_changed(&change)?;
+    }
+
+    if crate::config::Config::load().driver.check_latest_online {
+        let current = crate::drivers::get_driver_status()?.current_version;
+        let latest = crate::drivers::fetch_latest_published_version()?;
+        if crate::drivers::is_newer_driver_version(&latest, &current) {
+            manager.notify_driver_available(&current, &latest)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Background monitoring thread that continuously checks GPU metrics
 pub struct AlertMonitorThread {
     notification_manager: NotificationManager,
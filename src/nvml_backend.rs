@@ -4,6 +4,7 @@
 //! Real implementation wraps nvml-wrapper, mock implementation returns configurable data.
 
 use crate::{NvControlError, NvResult};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// GPU device information snapshot
@@ -28,14 +29,45 @@ pub struct ProcessInfo {
 #[derive(Debug, Clone, Default)]
 pub struct GpuMetrics {
     pub gpu_utilization: u32,
-    pub memory_utilization: u32,
+    /// Memory controller (bandwidth) activity, not how much VRAM is allocated
+    pub memory_controller_utilization: u32,
     pub temperature: u32,
     pub power_draw_mw: u32,
     pub fan_speed: u32,
     pub gpu_clock_mhz: u32,
     pub memory_clock_mhz: u32,
+    /// VRAM capacity in use
     pub memory_used_bytes: u64,
     pub memory_total_bytes: u64,
+    /// Memory bus width, when the driver reports it
+    pub memory_bus_width_bits: Option<u32>,
+}
+
+impl GpuMetrics {
+    /// VRAM capacity in use as a percentage of total
+    pub fn vram_used_percent(&self) -> u32 {
+        (self.memory_used_bytes * 100)
+            .checked_div(self.memory_total_bytes)
+            .unwrap_or(0) as u32
+    }
+
+    /// Theoretical bandwidth at the current memory clock in GB/s.
+    ///
+    /// NVML reports GDDR memory clocks at half the effective data rate, so
+    /// each bus line moves two bits per reported clock.
+    pub fn peak_bandwidth_gbps(&self) -> Option<f64> {
+        let bus_width = self.memory_bus_width_bits.filter(|w| *w > 0)?;
+        if self.memory_clock_mhz == 0 {
+            return None;
+        }
+        Some(self.memory_clock_mhz as f64 * 2.0 * bus_width as f64 / 8.0 / 1000.0)
+    }
+
+    /// Rough bandwidth in use, scaling the peak by controller activity
+    pub fn bandwidth_in_use_gbps(&self) -> Option<f64> {
+        self.peak_bandwidth_gbps()
+            .map(|peak| peak * self.memory_controller_utilization as f64 / 100.0)
+    }
 }
 
 /// NVML backend trait for GPU operations
@@ -52,15 +84,25 @@ pub trait NvmlBackend: Send + Sync {
     /// Get temperature for a device
     fn get_temperature(&self, index: u32) -> NvResult<u32>;
 
-    /// Get utilization rates (gpu%, memory%)
+    /// Get the temperature target in °C the driver boosts against
+    fn get_temperature_target(&self, index: u32) -> NvResult<u32>;
+
+    /// Get utilization rates (gpu%, memory controller%)
     fn get_utilization(&self, index: u32) -> NvResult<(u32, u32)>;
 
-    /// Get power usage in milliwatts
+    /// Get power usage in milliwatts. On most cards this is the whole board
     fn get_power_usage(&self, index: u32) -> NvResult<u32>;
 
+    /// Get power drawn by the GPU chip alone in milliwatts, where the
+    /// driver reports it separately from board power
+    fn get_chip_power_usage(&self, index: u32) -> NvResult<u32>;
+
     /// Get fan speed percentage for a specific fan
     fn get_fan_speed(&self, index: u32, fan_index: u32) -> NvResult<u32>;
 
+    /// Get the measured fan speed in RPM; some boards only report duty cycle
+    fn get_fan_rpm(&self, index: u32, fan_index: u32) -> NvResult<u32>;
+
     /// Get memory info (used, total) in bytes
     fn get_memory_info(&self, index: u32) -> NvResult<(u64, u64)>;
 
@@ -83,6 +125,9 @@ pub trait NvmlBackend: Send + Sync {
     /// Get driver version string
     fn get_driver_version(&self) -> NvResult<String>;
 
+    /// Get the highest CUDA version (major, minor) the driver supports
+    fn get_cuda_driver_version(&self) -> NvResult<(u32, u32)>;
+
     /// Get power management limit in milliwatts
     fn get_power_limit(&self, index: u32) -> NvResult<u32>;
 
@@ -101,6 +146,15 @@ pub trait NvmlBackend: Send + Sync {
     /// Get PCI bus ID string
     fn get_pci_bus_id(&self, index: u32) -> NvResult<String>;
 
+    /// Get the VBIOS version string
+    fn get_vbios_version(&self, index: u32) -> NvResult<String>;
+
+    /// Get the board part number; `UnsupportedFeature` when not programmed
+    fn get_board_part_number(&self, index: u32) -> NvResult<String>;
+
+    /// Get the board serial number; most consumer cards report `UnsupportedFeature`
+    fn get_board_serial(&self, index: u32) -> NvResult<String>;
+
     /// Get number of fans on device
     fn get_fan_count(&self, index: u32) -> NvResult<u32>;
 
@@ -136,6 +190,298 @@ pub trait NvmlBackend: Send + Sync {
 
     /// Get max memory clock in MHz
     fn get_max_memory_clock(&self, index: u32) -> NvResult<u32>;
+
+    // =========================================================================
+    // ECC methods (workstation/data-center GPUs)
+    // =========================================================================
+
+    /// Get current and pending ECC mode
+    fn get_ecc_mode(&self, index: u32) -> NvResult<EccState>;
+
+    /// Set the pending ECC mode; takes effect after a GPU reset or reboot (requires root)
+    fn set_ecc_mode(&self, index: u32, enabled: bool) -> NvResult<()>;
+
+    /// Get corrected/uncorrected ECC error totals since driver load and lifetime
+    fn get_ecc_errors(&self, index: u32) -> NvResult<EccErrorCounts>;
+
+    // =========================================================================
+    // MIG methods (A100/H100-class GPUs)
+    // =========================================================================
+
+    /// List MIG instances; empty when MIG is unsupported or disabled
+    fn get_mig_instances(&self, index: u32) -> NvResult<Vec<MigInstance>>;
+
+    // =========================================================================
+    // Video engine methods (NVENC/NVDEC)
+    // =========================================================================
+
+    /// Encoder/decoder utilization and active NVENC sessions
+    fn get_video_engine_stats(&self, index: u32) -> NvResult<VideoEngineStats>;
+
+    /// Busy percentage of the graphics, compute and copy engines, or the
+    /// overall figure where the driver has no per-engine counters
+    fn get_engine_utilization(&self, index: u32) -> NvResult<EngineUtilization>;
+
+    /// Why clocks are currently held below their maximum
+    fn get_throttle_reasons(&self, index: u32) -> NvResult<ThrottleReasons>;
+
+    /// Core and memory clock offsets in MHz as the driver currently has them set
+    fn get_clock_offsets(&self, index: u32) -> NvResult<(i32, i32)>;
+
+    /// Current and maximum PCIe link generation and width
+    fn get_pcie_link(&self, index: u32) -> NvResult<PcieLink>;
+
+    /// Whether a display is initialized and being driven by this GPU
+    fn is_display_active(&self, index: u32) -> NvResult<bool>;
+
+    /// Whether the driver stays loaded with no clients (legacy persistence mode)
+    fn get_persistence_mode(&self, index: u32) -> NvResult<bool>;
+
+    /// Turn legacy persistence mode on or off (requires root)
+    fn set_persistence_mode(&self, index: u32, enabled: bool) -> NvResult<()>;
+
+    /// Memory clocks the GPU can run at, and graphics clocks at its top memory clock
+    fn get_supported_clocks(&self, index: u32) -> NvResult<SupportedClocks>;
+
+    /// Pin graphics and/or memory clocks to fixed values in MHz (requires root)
+    fn set_locked_clocks(
+        &self,
+        index: u32,
+        gpu_mhz: Option<u32>,
+        mem_mhz: Option<u32>,
+    ) -> NvResult<()>;
+
+    /// Release graphics and memory clock locks (requires root)
+    fn reset_locked_clocks(&self, index: u32) -> NvResult<()>;
+}
+
+const THROTTLE_REASONS_UNSUPPORTED: &str = "This driver does not report clock throttle reasons";
+const CLOCK_OFFSETS_UNSUPPORTED: &str = "This driver does not report clock offsets through NVML";
+const FAN_RPM_UNSUPPORTED: &str = "This GPU only reports fan duty cycle, not RPM";
+const PCIE_LINK_UNSUPPORTED: &str = "This GPU does not report its PCIe link through NVML";
+const PERSISTENCE_UNSUPPORTED: &str = "Persistence mode is only available on Linux";
+const CLOCK_LOCK_UNSUPPORTED: &str =
+    "This GPU does not support locking clocks (Volta or newer required, memory locks need Ampere)";
+
+/// Clock steps the driver accepts, ascending, in MHz
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SupportedClocks {
+    pub graphics_mhz: Vec<u32>,
+    pub memory_mhz: Vec<u32>,
+}
+
+impl SupportedClocks {
+    pub fn graphics_range(&self) -> Option<(u32, u32)> {
+        Some((*self.graphics_mhz.first()?, *self.graphics_mhz.last()?))
+    }
+
+    pub fn memory_range(&self) -> Option<(u32, u32)> {
+        Some((*self.memory_mhz.first()?, *self.memory_mhz.last()?))
+    }
+}
+
+/// PCIe link of a device: what it is running at and what it is capable of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PcieLink {
+    pub current_gen: u32,
+    pub max_gen: u32,
+    pub current_width: u32,
+    pub max_width: u32,
+}
+
+impl PcieLink {
+    /// Fewer lanes than the card supports; never a power-saving state
+    pub fn is_width_degraded(&self) -> bool {
+        self.current_width < self.max_width
+    }
+
+    /// Lower generation than the card supports. The driver drops the link
+    /// speed when idle, so this only indicates a problem under load.
+    pub fn is_gen_degraded(&self) -> bool {
+        self.current_gen < self.max_gen
+    }
+
+    /// Bottleneck warning, if the link is running below capability.
+    /// `under_load` says whether a lower generation can be blamed on idling.
+    pub fn bottleneck_warning(&self, under_load: bool) -> Option<String> {
+        if self.is_width_degraded() || (under_load && self.is_gen_degraded()) {
+            Some(format!(
+                "PCIe link running at Gen{} x{} on a Gen{} x{} card - check the slot, riser and BIOS lane settings",
+                self.current_gen, self.current_width, self.max_gen, self.max_width
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for PcieLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Gen{} x{} (max Gen{} x{})",
+            self.current_gen, self.current_width, self.max_gen, self.max_width
+        )
+    }
+}
+
+/// Clock throttle reasons, the `nvmlClocksThrottleReason*` bitmask
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThrottleReasons(pub u64);
+
+impl ThrottleReasons {
+    pub const GPU_IDLE: u64 = 0x1;
+    pub const APPLICATIONS_CLOCKS: u64 = 0x2;
+    pub const SW_POWER_CAP: u64 = 0x4;
+    pub const HW_SLOWDOWN: u64 = 0x8;
+    pub const SYNC_BOOST: u64 = 0x10;
+    pub const SW_THERMAL: u64 = 0x20;
+    pub const HW_THERMAL: u64 = 0x40;
+    pub const HW_POWER_BRAKE: u64 = 0x80;
+    pub const DISPLAY_CLOCKS: u64 = 0x100;
+
+    const LABELS: [(u64, &'static str); 9] = [
+        (Self::SW_THERMAL, "thermal (software)"),
+        (Self::HW_THERMAL, "thermal (hardware)"),
+        (Self::SW_POWER_CAP, "power cap"),
+        (Self::HW_POWER_BRAKE, "power brake"),
+        (Self::HW_SLOWDOWN, "hardware slowdown"),
+        (Self::SYNC_BOOST, "sync boost"),
+        (Self::APPLICATIONS_CLOCKS, "application clocks"),
+        (Self::DISPLAY_CLOCKS, "display clocks"),
+        (Self::GPU_IDLE, "idle"),
+    ];
+
+    /// Whether clocks are being held back; an idle GPU isn't throttling
+    pub fn is_throttling(&self) -> bool {
+        self.0 & !Self::GPU_IDLE != 0
+    }
+
+    pub fn is_thermal(&self) -> bool {
+        self.0 & (Self::SW_THERMAL | Self::HW_THERMAL) != 0
+    }
+
+    pub fn is_power(&self) -> bool {
+        self.0 & (Self::SW_POWER_CAP | Self::HW_POWER_BRAKE) != 0
+    }
+
+    /// Human-readable names of the set reasons, most significant first
+    pub fn labels(&self) -> Vec<&'static str> {
+        Self::LABELS
+            .iter()
+            .filter(|(bit, _)| self.0 & bit != 0)
+            .map(|(_, label)| *label)
+            .collect()
+    }
+
+    /// Comma-separated reasons, or "none"
+    pub fn summary(&self) -> String {
+        if self.is_throttling() {
+            self.labels()
+                .into_iter()
+                .filter(|l| *l != "idle")
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            "none".to_string()
+        }
+    }
+}
+
+const NVENC_UNSUPPORTED: &str = "This GPU has no NVENC encoder";
+const CHIP_POWER_UNSUPPORTED: &str = "This GPU only reports one power reading";
+
+/// NVENC/NVDEC activity of a device
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VideoEngineStats {
+    pub encoder_utilization: u32,
+    pub decoder_utilization: u32,
+    /// Active NVENC sessions; `None` when the driver doesn't report them
+    pub session_count: Option<u32>,
+    pub average_fps: Option<u32>,
+    pub average_latency_us: Option<u32>,
+}
+
+/// Time between the two GPM samples a per-engine reading is computed from
+const GPM_SAMPLE_WINDOW: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// Graphics, compute and copy engine load of a device, in percent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EngineUtilization {
+    pub graphics: u32,
+    pub compute: u32,
+    /// Copy-engine traffic, read as DRAM bandwidth utilization
+    pub copy: u32,
+    /// `false` when only overall utilization is available; every field
+    /// then holds that single figure
+    pub per_engine: bool,
+}
+
+impl EngineUtilization {
+    /// Coarse reading from overall GPU utilization
+    pub fn overall(utilization: u32) -> Self {
+        Self {
+            graphics: utilization,
+            compute: utilization,
+            copy: utilization,
+            per_engine: false,
+        }
+    }
+}
+
+/// `nvmlDeviceGetMigMode` value for an enabled mode
+const NVML_DEVICE_MIG_ENABLE: u32 = 1;
+
+const ECC_UNSUPPORTED: &str = "ECC memory is not available on this GPU (GeForce cards lack ECC; use a workstation or data-center GPU)";
+
+/// ECC mode of a device: what is active now and what takes effect after the next reset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EccState {
+    pub current_enabled: bool,
+    pub pending_enabled: bool,
+}
+
+/// ECC error totals of a device.
+///
+/// Volatile counts reset when the driver loads; aggregate counts persist for
+/// the lifetime of the board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EccErrorCounts {
+    pub volatile_corrected: u64,
+    pub volatile_uncorrected: u64,
+    pub aggregate_corrected: u64,
+    pub aggregate_uncorrected: u64,
+}
+
+impl EccErrorCounts {
+    /// Uncorrected (double-bit) errors mean data was actually lost
+    pub fn has_uncorrected(&self) -> bool {
+        self.volatile_uncorrected > 0 || self.aggregate_uncorrected > 0
+    }
+}
+
+/// One MIG (Multi-Instance GPU) slice of a physical device
+#[derive(Debug, Clone, Default)]
+pub struct MigInstance {
+    pub parent_index: u32,
+    pub index: u32,
+    pub name: String,
+    pub uuid: String,
+    pub sm_count: Option<u32>,
+    pub memory_used_bytes: u64,
+    pub memory_total_bytes: u64,
+    /// NVML reports utilization per instance only on some drivers
+    pub gpu_utilization: Option<u32>,
+}
+
+impl MigInstance {
+    /// Profile part of the device name, e.g. "1g.10gb" from "NVIDIA A100 MIG 1g.10gb"
+    pub fn profile_name(&self) -> &str {
+        self.name
+            .rsplit_once("MIG ")
+            .map(|(_, profile)| profile.trim())
+            .unwrap_or(&self.name)
+    }
 }
 
 /// Real NVML backend using nvml-wrapper
@@ -222,9 +568,11 @@ impl NvmlBackend for RealNvmlBackend {
             .map(|m| (m.used, m.total))
             .unwrap_or((0, 0));
 
+        let memory_bus_width_bits = device.memory_bus_width().ok();
+
         Ok(GpuMetrics {
             gpu_utilization: gpu_util,
-            memory_utilization: mem_util,
+            memory_controller_utilization: mem_util,
             temperature,
             power_draw_mw,
             fan_speed,
@@ -232,6 +580,7 @@ impl NvmlBackend for RealNvmlBackend {
             memory_clock_mhz: memory_clock,
             memory_used_bytes: memory_used,
             memory_total_bytes: memory_total,
+            memory_bus_width_bits,
         })
     }
 
@@ -244,6 +593,26 @@ impl NvmlBackend for RealNvmlBackend {
             })
     }
 
+    fn get_temperature_target(&self, index: u32) -> NvResult<u32> {
+        use nvml_wrapper::enum_wrappers::device::TemperatureThreshold;
+
+        let device = self.get_device(index)?;
+        // The acoustic threshold is the user-adjustable target; cards
+        // without one only report the fixed slowdown point
+        device
+            .temperature_threshold(TemperatureThreshold::AcousticCurr)
+            .or_else(|_| device.temperature_threshold(TemperatureThreshold::GpuMax))
+            .map_err(|e| match e {
+                nvml_wrapper::error::NvmlError::NotSupported => NvControlError::UnsupportedFeature(
+                    "This GPU does not report a temperature target".to_string(),
+                ),
+                e => NvControlError::GpuQueryFailed(format!(
+                    "Failed to get temperature target: {}",
+                    e
+                )),
+            })
+    }
+
     fn get_utilization(&self, index: u32) -> NvResult<(u32, u32)> {
         let device = self.get_device(index)?;
         device
@@ -261,6 +630,11 @@ impl NvmlBackend for RealNvmlBackend {
         })
     }
 
+    fn get_chip_power_usage(&self, index: u32) -> NvResult<u32> {
+        let device = self.get_device(index)?;
+        chip_power_usage(&device)
+    }
+
     fn get_fan_speed(&self, index: u32, fan_index: u32) -> NvResult<u32> {
         let device = self.get_device(index)?;
         device
@@ -268,6 +642,23 @@ impl NvmlBackend for RealNvmlBackend {
             .map_err(|e| NvControlError::GpuQueryFailed(format!("Failed to get fan speed: {}", e)))
     }
 
+    fn get_fan_rpm(&self, index: u32, fan_index: u32) -> NvResult<u32> {
+        let device = self.get_device(index)?;
+        match device.fan_speed_rpm(fan_index) {
+            Ok(rpm) => Ok(rpm),
+            Err(
+                nvml_wrapper::error::NvmlError::NotSupported
+                | nvml_wrapper::error::NvmlError::FailedToLoadSymbol(_),
+            ) => Err(NvControlError::UnsupportedFeature(
+                FAN_RPM_UNSUPPORTED.to_string(),
+            )),
+            Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+                "Failed to get fan RPM: {}",
+                e
+            ))),
+        }
+    }
+
     fn get_memory_info(&self, index: u32) -> NvResult<(u64, u64)> {
         let device = self.get_device(index)?;
         device
@@ -315,6 +706,20 @@ impl NvmlBackend for RealNvmlBackend {
         })
     }
 
+    fn get_cuda_driver_version(&self) -> NvResult<(u32, u32)> {
+        let nvml = self
+            .nvml
+            .as_ref()
+            .ok_or_else(|| NvControlError::NvmlNotAvailable("NVML not initialized".to_string()))?;
+        let version = nvml.sys_cuda_driver_version().map_err(|e| {
+            NvControlError::GpuQueryFailed(format!("Failed to get CUDA driver version: {}", e))
+        })?;
+        Ok((
+            nvml_wrapper::cuda_driver_version_major(version) as u32,
+            nvml_wrapper::cuda_driver_version_minor(version) as u32,
+        ))
+    }
+
     fn get_power_limit(&self, index: u32) -> NvResult<u32> {
         let device = self.get_device(index)?;
         device.power_management_limit().map_err(|e| {
@@ -364,6 +769,21 @@ impl NvmlBackend for RealNvmlBackend {
             .map_err(|e| NvControlError::GpuQueryFailed(format!("Failed to get PCI bus ID: {}", e)))
     }
 
+    fn get_vbios_version(&self, index: u32) -> NvResult<String> {
+        let device = self.get_device(index)?;
+        board_identity(device.vbios_version(), "VBIOS version")
+    }
+
+    fn get_board_part_number(&self, index: u32) -> NvResult<String> {
+        let device = self.get_device(index)?;
+        board_identity(device.board_part_number(), "board part number")
+    }
+
+    fn get_board_serial(&self, index: u32) -> NvResult<String> {
+        let device = self.get_device(index)?;
+        board_identity(device.serial(), "serial number")
+    }
+
     fn get_fan_count(&self, index: u32) -> NvResult<u32> {
         let device = self.get_device(index)?;
         device
@@ -464,6 +884,432 @@ impl NvmlBackend for RealNvmlBackend {
                 NvControlError::GpuQueryFailed(format!("Failed to get max memory clock: {}", e))
             })
     }
+
+    fn get_ecc_mode(&self, index: u32) -> NvResult<EccState> {
+        let device = self.get_device(index)?;
+        match device.is_ecc_enabled() {
+            Ok(state) => Ok(EccState {
+                current_enabled: state.currently_enabled,
+                pending_enabled: state.pending_enabled,
+            }),
+            Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+                NvControlError::UnsupportedFeature(ECC_UNSUPPORTED.to_string()),
+            ),
+            Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+                "Failed to get ECC mode: {}",
+                e
+            ))),
+        }
+    }
+
+    fn set_ecc_mode(&self, index: u32, enabled: bool) -> NvResult<()> {
+        let nvml = self
+            .nvml
+            .as_ref()
+            .ok_or_else(|| NvControlError::NvmlNotAvailable("NVML not initialized".to_string()))?;
+
+        let mut device = nvml.device_by_index(index).map_err(|e| {
+            NvControlError::GpuQueryFailed(format!("Failed to get device {}: {}", index, e))
+        })?;
+
+        match device.set_ecc(enabled) {
+            Ok(()) => Ok(()),
+            Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+                NvControlError::UnsupportedFeature(ECC_UNSUPPORTED.to_string()),
+            ),
+            Err(nvml_wrapper::error::NvmlError::NoPermission) => Err(NvControlError::RuntimeError(
+                "Changing ECC mode requires root".to_string(),
+            )),
+            Err(e) => Err(NvControlError::RuntimeError(format!(
+                "Failed to set ECC mode: {}",
+                e
+            ))),
+        }
+    }
+
+    fn get_ecc_errors(&self, index: u32) -> NvResult<EccErrorCounts> {
+        let device = self.get_device(index)?;
+        ecc_error_counts(&device)
+    }
+
+    fn get_mig_instances(&self, index: u32) -> NvResult<Vec<MigInstance>> {
+        let device = self.get_device(index)?;
+        let mig_enabled = device
+            .mig_mode()
+            .map(|mode| mode.current == NVML_DEVICE_MIG_ENABLE)
+            .unwrap_or(false);
+        if !mig_enabled {
+            return Ok(Vec::new());
+        }
+
+        let max_instances = device.mig_device_count().unwrap_or(0);
+        let instances = (0..max_instances)
+            .filter_map(|mig_index| {
+                // Unpopulated slots report NotFound
+                let mig = device.mig_device_by_index(mig_index).ok()?;
+                let (memory_used_bytes, memory_total_bytes) = mig
+                    .memory_info()
+                    .map(|mem| (mem.used, mem.total))
+                    .unwrap_or((0, 0));
+                Some(MigInstance {
+                    parent_index: index,
+                    index: mig_index,
+                    name: mig.name().unwrap_or_default(),
+                    uuid: mig.uuid().unwrap_or_default(),
+                    sm_count: mig.attributes().ok().map(|a| a.multiprocessor_count),
+                    memory_used_bytes,
+                    memory_total_bytes,
+                    gpu_utilization: mig.utilization_rates().ok().map(|u| u.gpu),
+                })
+            })
+            .collect();
+
+        Ok(instances)
+    }
+
+    fn get_video_engine_stats(&self, index: u32) -> NvResult<VideoEngineStats> {
+        let device = self.get_device(index)?;
+        video_engine_stats(&device)
+    }
+
+    fn get_engine_utilization(&self, index: u32) -> NvResult<EngineUtilization> {
+        let device = self.get_device(index)?;
+        engine_utilization(&device)
+    }
+
+    fn get_throttle_reasons(&self, index: u32) -> NvResult<ThrottleReasons> {
+        let device = self.get_device(index)?;
+        match device.current_throttle_reasons() {
+            Ok(reasons) => Ok(ThrottleReasons(reasons.bits())),
+            Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+                NvControlError::UnsupportedFeature(THROTTLE_REASONS_UNSUPPORTED.to_string()),
+            ),
+            Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+                "Failed to get throttle reasons: {}",
+                e
+            ))),
+        }
+    }
+
+    fn get_clock_offsets(&self, index: u32) -> NvResult<(i32, i32)> {
+        let device = self.get_device(index)?;
+        let read = |result: Result<i32, nvml_wrapper::error::NvmlError>| match result {
+            Ok(offset) => Ok(offset),
+            Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+                NvControlError::UnsupportedFeature(CLOCK_OFFSETS_UNSUPPORTED.to_string()),
+            ),
+            Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+                "Failed to get clock offsets: {}",
+                e
+            ))),
+        };
+        Ok((
+            read(device.gpc_clock_vf_offset())?,
+            read(device.mem_clock_vf_offset())?,
+        ))
+    }
+
+    fn get_pcie_link(&self, index: u32) -> NvResult<PcieLink> {
+        let device = self.get_device(index)?;
+        pcie_link(&device)
+    }
+
+    fn is_display_active(&self, index: u32) -> NvResult<bool> {
+        let device = self.get_device(index)?;
+        device.is_display_active().map_err(|e| match e {
+            nvml_wrapper::error::NvmlError::NotSupported => NvControlError::UnsupportedFeature(
+                "This GPU does not report whether it drives a display".to_string(),
+            ),
+            e => NvControlError::GpuQueryFailed(format!("Failed to get display state: {}", e)),
+        })
+    }
+
+    fn get_persistence_mode(&self, index: u32) -> NvResult<bool> {
+        let device = self.get_device(index)?;
+        device.is_in_persistent_mode().map_err(|e| match e {
+            nvml_wrapper::error::NvmlError::NotSupported => {
+                NvControlError::UnsupportedFeature(PERSISTENCE_UNSUPPORTED.to_string())
+            }
+            e => NvControlError::GpuQueryFailed(format!("Failed to get persistence mode: {}", e)),
+        })
+    }
+
+    fn set_persistence_mode(&self, index: u32, enabled: bool) -> NvResult<()> {
+        let nvml = self
+            .nvml
+            .as_ref()
+            .ok_or_else(|| NvControlError::NvmlNotAvailable("NVML not initialized".to_string()))?;
+
+        let mut device = nvml.device_by_index(index).map_err(|e| {
+            NvControlError::GpuQueryFailed(format!("Failed to get device {}: {}", index, e))
+        })?;
+
+        match device.set_persistent(enabled) {
+            Ok(()) => Ok(()),
+            Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+                NvControlError::UnsupportedFeature(PERSISTENCE_UNSUPPORTED.to_string()),
+            ),
+            Err(nvml_wrapper::error::NvmlError::NoPermission) => Err(NvControlError::RuntimeError(
+                "Changing persistence mode requires root".to_string(),
+            )),
+            Err(e) => Err(NvControlError::RuntimeError(format!(
+                "Failed to set persistence mode: {}",
+                e
+            ))),
+        }
+    }
+
+    fn get_supported_clocks(&self, index: u32) -> NvResult<SupportedClocks> {
+        let device = self.get_device(index)?;
+        let map_err = |e| match e {
+            nvml_wrapper::error::NvmlError::NotSupported => {
+                NvControlError::UnsupportedFeature(CLOCK_LOCK_UNSUPPORTED.to_string())
+            }
+            e => NvControlError::GpuQueryFailed(format!("Failed to get supported clocks: {}", e)),
+        };
+
+        let mut memory_mhz = device.supported_memory_clocks().map_err(map_err)?;
+        memory_mhz.sort_unstable();
+        memory_mhz.dedup();
+        let mut graphics_mhz = match memory_mhz.last() {
+            Some(&top) => device.supported_graphics_clocks(top).map_err(map_err)?,
+            None => Vec::new(),
+        };
+        graphics_mhz.sort_unstable();
+        graphics_mhz.dedup();
+
+        Ok(SupportedClocks {
+            graphics_mhz,
+            memory_mhz,
+        })
+    }
+
+    fn set_locked_clocks(
+        &self,
+        index: u32,
+        gpu_mhz: Option<u32>,
+        mem_mhz: Option<u32>,
+    ) -> NvResult<()> {
+        let nvml = self
+            .nvml
+            .as_ref()
+            .ok_or_else(|| NvControlError::NvmlNotAvailable("NVML not initialized".to_string()))?;
+
+        let mut device = nvml.device_by_index(index).map_err(|e| {
+            NvControlError::GpuQueryFailed(format!("Failed to get device {}: {}", index, e))
+        })?;
+
+        if let Some(mhz) = gpu_mhz {
+            device
+                .set_gpu_locked_clocks(
+                    nvml_wrapper::enums::device::GpuLockedClocksSetting::Numeric {
+                        min_clock_mhz: mhz,
+                        max_clock_mhz: mhz,
+                    },
+                )
+                .map_err(|e| clock_lock_error("GPU", e))?;
+        }
+        if let Some(mhz) = mem_mhz {
+            device
+                .set_mem_locked_clocks(mhz, mhz)
+                .map_err(|e| clock_lock_error("memory", e))?;
+        }
+        Ok(())
+    }
+
+    fn reset_locked_clocks(&self, index: u32) -> NvResult<()> {
+        let nvml = self
+            .nvml
+            .as_ref()
+            .ok_or_else(|| NvControlError::NvmlNotAvailable("NVML not initialized".to_string()))?;
+
+        let mut device = nvml.device_by_index(index).map_err(|e| {
+            NvControlError::GpuQueryFailed(format!("Failed to get device {}: {}", index, e))
+        })?;
+
+        device
+            .reset_gpu_locked_clocks()
+            .map_err(|e| clock_lock_error("GPU", e))?;
+        // Pre-Ampere boards never had a memory lock to release
+        match device.reset_mem_locked_clocks() {
+            Ok(()) | Err(nvml_wrapper::error::NvmlError::NotSupported) => Ok(()),
+            Err(e) => Err(clock_lock_error("memory", e)),
+        }
+    }
+}
+
+fn clock_lock_error(which: &str, e: nvml_wrapper::error::NvmlError) -> NvControlError {
+    match e {
+        nvml_wrapper::error::NvmlError::NotSupported => {
+            NvControlError::UnsupportedFeature(CLOCK_LOCK_UNSUPPORTED.to_string())
+        }
+        nvml_wrapper::error::NvmlError::NoPermission => {
+            NvControlError::RuntimeError(format!("Locking {} clocks requires root", which))
+        }
+        e => NvControlError::RuntimeError(format!("Failed to lock {} clocks: {}", which, e)),
+    }
+}
+
+/// Read the PCIe link straight from an NVML device handle
+pub(crate) fn pcie_link(device: &nvml_wrapper::Device) -> NvResult<PcieLink> {
+    let read = |result: Result<u32, nvml_wrapper::error::NvmlError>| match result {
+        Ok(value) => Ok(value),
+        Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+            NvControlError::UnsupportedFeature(PCIE_LINK_UNSUPPORTED.to_string()),
+        ),
+        Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+            "Failed to get PCIe link: {}",
+            e
+        ))),
+    };
+    Ok(PcieLink {
+        current_gen: read(device.current_pcie_link_gen())?,
+        max_gen: read(device.max_pcie_link_gen())?,
+        current_width: read(device.current_pcie_link_width())?,
+        max_width: read(device.max_pcie_link_width())?,
+    })
+}
+
+/// Read GPU-chip power in milliwatts straight from an NVML device handle.
+/// Field values default to the GPU power scope, as opposed to
+/// `power_usage`, which covers the whole board on most cards.
+pub(crate) fn chip_power_usage(device: &nvml_wrapper::Device) -> NvResult<u32> {
+    use nvml_wrapper::enums::device::SampleValue;
+    use nvml_wrapper::structs::device::FieldId;
+    use nvml_wrapper::sys_exports::field_id::NVML_FI_DEV_POWER_INSTANT;
+
+    let unsupported = || NvControlError::UnsupportedFeature(CHIP_POWER_UNSUPPORTED.to_string());
+    let sample = device
+        .field_values_for(&[FieldId(NVML_FI_DEV_POWER_INSTANT)])
+        .map_err(|_| unsupported())?
+        .into_iter()
+        .next()
+        .ok_or_else(unsupported)?
+        .map_err(|_| unsupported())?;
+    match sample.value.map_err(|_| unsupported())? {
+        SampleValue::U32(mw) => Ok(mw),
+        SampleValue::U64(mw) => Ok(mw as u32),
+        SampleValue::I64(mw) => Ok(mw.max(0) as u32),
+        SampleValue::F64(mw) => Ok(mw.max(0.0) as u32),
+    }
+}
+
+/// Read NVENC/NVDEC activity straight from an NVML device handle
+pub(crate) fn video_engine_stats(device: &nvml_wrapper::Device) -> NvResult<VideoEngineStats> {
+    let encoder_utilization = match device.encoder_utilization() {
+        Ok(info) => info.utilization,
+        Err(nvml_wrapper::error::NvmlError::NotSupported) => {
+            return Err(NvControlError::UnsupportedFeature(
+                NVENC_UNSUPPORTED.to_string(),
+            ));
+        }
+        Err(e) => {
+            return Err(NvControlError::GpuQueryFailed(format!(
+                "Failed to get encoder utilization: {}",
+                e
+            )));
+        }
+    };
+    let decoder_utilization = device
+        .decoder_utilization()
+        .map(|info| info.utilization)
+        .unwrap_or(0);
+    let stats = device.encoder_stats().ok();
+
+    Ok(VideoEngineStats {
+        encoder_utilization,
+        decoder_utilization,
+        session_count: stats.as_ref().map(|s| s.session_count),
+        average_fps: stats.as_ref().map(|s| s.average_fps),
+        average_latency_us: stats.as_ref().map(|s| s.average_latency),
+    })
+}
+
+/// Read per-engine load from GPM (Hopper and newer), falling back to overall
+/// utilization on GPUs and drivers without it
+/// Trim an identity string from NVML. Consumer boards often return
+/// `NotSupported` or an empty string for fields that were never programmed;
+/// both become `UnsupportedFeature`.
+fn board_identity(
+    result: Result<String, nvml_wrapper::error::NvmlError>,
+    what: &str,
+) -> NvResult<String> {
+    match result {
+        Ok(value) if !value.trim().is_empty() => Ok(value.trim().to_string()),
+        Ok(_) | Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+            NvControlError::UnsupportedFeature(format!("This GPU does not report its {}", what)),
+        ),
+        Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+            "Failed to get {}: {}",
+            what, e
+        ))),
+    }
+}
+
+pub(crate) fn engine_utilization(device: &nvml_wrapper::Device) -> NvResult<EngineUtilization> {
+    if device.gpm_support().unwrap_or(false)
+        && let Some(engines) = gpm_engine_utilization(device)
+    {
+        return Ok(engines);
+    }
+    device
+        .utilization_rates()
+        .map(|u| EngineUtilization::overall(u.gpu))
+        .map_err(|e| NvControlError::GpuQueryFailed(format!("Failed to get utilization: {}", e)))
+}
+
+fn gpm_engine_utilization(device: &nvml_wrapper::Device) -> Option<EngineUtilization> {
+    use nvml_wrapper::enums::gpm::GpmMetricId;
+
+    let first = device.gpm_sample().ok()?;
+    std::thread::sleep(GPM_SAMPLE_WINDOW);
+    let second = device.gpm_sample().ok()?;
+    let metrics = nvml_wrapper::gpm::gpm_metrics_get(
+        device.nvml(),
+        &first,
+        &second,
+        &[
+            GpmMetricId::GraphicsUtil,
+            GpmMetricId::SmUtil,
+            GpmMetricId::DramBwUtil,
+        ],
+    )
+    .ok()?;
+    let percent = |i: usize| -> Option<u32> {
+        let value = metrics.get(i)?.as_ref().ok()?.value;
+        Some(value.round().clamp(0.0, 100.0) as u32)
+    };
+
+    Some(EngineUtilization {
+        graphics: percent(0)?,
+        compute: percent(1)?,
+        copy: percent(2)?,
+        per_engine: true,
+    })
+}
+
+/// Read ECC error totals straight from an NVML device handle
+pub(crate) fn ecc_error_counts(device: &nvml_wrapper::Device) -> NvResult<EccErrorCounts> {
+    use nvml_wrapper::enum_wrappers::device::{EccCounter, MemoryError};
+
+    let read =
+        |error: MemoryError, counter: EccCounter| match device.total_ecc_errors(error, counter) {
+            Ok(count) => Ok(count),
+            Err(nvml_wrapper::error::NvmlError::NotSupported) => Err(
+                NvControlError::UnsupportedFeature(ECC_UNSUPPORTED.to_string()),
+            ),
+            Err(e) => Err(NvControlError::GpuQueryFailed(format!(
+                "Failed to get ECC error counts: {}",
+                e
+            ))),
+        };
+
+    Ok(EccErrorCounts {
+        volatile_corrected: read(MemoryError::Corrected, EccCounter::Volatile)?,
+        volatile_uncorrected: read(MemoryError::Uncorrected, EccCounter::Volatile)?,
+        aggregate_corrected: read(MemoryError::Corrected, EccCounter::Aggregate)?,
+        aggregate_uncorrected: read(MemoryError::Uncorrected, EccCounter::Aggregate)?,
+    })
 }
 
 /// Mock NVML backend for testing
@@ -472,6 +1318,7 @@ pub struct MockNvmlBackend {
     pub device_count: u32,
     pub devices: Vec<MockGpuDevice>,
     pub driver_version: String,
+    pub cuda_driver_version: Option<(u32, u32)>,
 }
 
 #[derive(Debug, Clone)]
@@ -482,6 +1329,7 @@ pub struct MockGpuDevice {
     pub power_limit_default_mw: u32,
     pub power_limit_min_mw: u32,
     pub power_limit_max_mw: u32,
+    pub temperature_target: u32,
     pub cuda_cores: u32,
     pub compute_major: u32,
     pub compute_minor: u32,
@@ -491,6 +1339,34 @@ pub struct MockGpuDevice {
     pub compute_processes: Vec<ProcessInfo>,
     pub max_gpu_clock_mhz: u32,
     pub max_memory_clock_mhz: u32,
+    /// `None` models a consumer GPU without ECC
+    pub ecc: Option<EccState>,
+    /// Reported only when `ecc` is set
+    pub ecc_errors: EccErrorCounts,
+    pub mig_instances: Vec<MigInstance>,
+    /// `None` models a GPU without NVENC
+    pub video_engines: Option<VideoEngineStats>,
+    /// `None` models a driver that only reports overall utilization
+    pub engine_utilization: Option<EngineUtilization>,
+    pub vbios_version: String,
+    /// `None` models a board without a programmed part number
+    pub board_part_number: Option<String>,
+    /// `None` models a consumer card that doesn't report its serial
+    pub board_serial: Option<String>,
+    /// `None` when the driver doesn't expose throttle reasons
+    pub throttle_reasons: Option<u64>,
+    /// `None` models a board that only reports duty cycle
+    pub fan_rpm: Option<u32>,
+    /// `None` models a card that only reports board power
+    pub chip_power_mw: Option<u32>,
+    /// `None` models a driver without NVML offset read-back
+    pub clock_offsets: Option<(i32, i32)>,
+    /// `None` models a GPU that doesn't report its PCIe link
+    pub pcie_link: Option<PcieLink>,
+    pub display_active: bool,
+    pub persistence_mode: bool,
+    /// `None` models a GPU that can't lock clocks
+    pub supported_clocks: Option<SupportedClocks>,
 }
 
 impl MockNvmlBackend {
@@ -500,6 +1376,7 @@ impl MockNvmlBackend {
             device_count: 1,
             devices: vec![MockGpuDevice::default()],
             driver_version: "565.57.01".to_string(),
+            cuda_driver_version: Some((12, 7)),
         }
     }
 
@@ -515,7 +1392,7 @@ impl MockNvmlBackend {
                 },
                 metrics: GpuMetrics {
                     gpu_utilization: 50 + i * 10,
-                    memory_utilization: 40 + i * 5,
+                    memory_controller_utilization: 40 + i * 5,
                     temperature: 55 + i * 3,
                     power_draw_mw: 150_000 + i * 20_000,
                     fan_speed: 45 + i * 5,
@@ -523,11 +1400,13 @@ impl MockNvmlBackend {
                     memory_clock_mhz: 7000 + i * 500,
                     memory_used_bytes: 4_000_000_000 + (i as u64) * 1_000_000_000,
                     memory_total_bytes: 8_000_000_000,
+                    memory_bus_width_bits: Some(256),
                 },
                 power_limit_mw: 320_000,
                 power_limit_default_mw: 320_000,
                 power_limit_min_mw: 200_000,
                 power_limit_max_mw: 450_000,
+                temperature_target: 83,
                 cuda_cores: 9728,
                 compute_major: 8,
                 compute_minor: 9,
@@ -549,6 +1428,31 @@ impl MockNvmlBackend {
                 }],
                 max_gpu_clock_mhz: 2520,
                 max_memory_clock_mhz: 10501,
+                ecc: None,
+                ecc_errors: EccErrorCounts::default(),
+                mig_instances: Vec::new(),
+                video_engines: Some(VideoEngineStats::default()),
+                engine_utilization: None,
+                vbios_version: format!("94.02.42.00.{:02X}", i),
+                board_part_number: Some("900-2G133-0000-000".to_string()),
+                board_serial: Some(format!("1324021{:06}", i)),
+                throttle_reasons: Some(ThrottleReasons::GPU_IDLE),
+                fan_rpm: Some(1200 + i * 100),
+                chip_power_mw: None,
+                clock_offsets: Some((0, 0)),
+                pcie_link: Some(PcieLink {
+                    current_gen: 4,
+                    max_gen: 4,
+                    current_width: 16,
+                    max_width: 16,
+                }),
+                // The first GPU drives the desktop
+                display_active: i == 0,
+                persistence_mode: false,
+                supported_clocks: Some(SupportedClocks {
+                    graphics_mhz: (210..=2520).step_by(15).collect(),
+                    memory_mhz: vec![405, 810, 5001, 10501],
+                }),
             })
             .collect();
 
@@ -556,6 +1460,7 @@ impl MockNvmlBackend {
             device_count: count,
             devices,
             driver_version: "565.57.01".to_string(),
+            cuda_driver_version: Some((12, 7)),
         }
     }
 
@@ -565,6 +1470,7 @@ impl MockNvmlBackend {
             device_count: 0,
             devices: vec![],
             driver_version: "Unknown".to_string(),
+            cuda_driver_version: None,
         }
     }
 
@@ -586,7 +1492,7 @@ impl Default for MockGpuDevice {
             },
             metrics: GpuMetrics {
                 gpu_utilization: 45,
-                memory_utilization: 35,
+                memory_controller_utilization: 35,
                 temperature: 58,
                 power_draw_mw: 180_000,
                 fan_speed: 40,
@@ -594,11 +1500,13 @@ impl Default for MockGpuDevice {
                 memory_clock_mhz: 11200,
                 memory_used_bytes: 6_000_000_000,
                 memory_total_bytes: 16_000_000_000,
+                memory_bus_width_bits: Some(256),
             },
             power_limit_mw: 320_000,
             power_limit_default_mw: 320_000,
             power_limit_min_mw: 200_000,
             power_limit_max_mw: 450_000,
+            temperature_target: 83,
             cuda_cores: 9728,
             compute_major: 8,
             compute_minor: 9,
@@ -620,6 +1528,41 @@ impl Default for MockGpuDevice {
             }],
             max_gpu_clock_mhz: 2520,
             max_memory_clock_mhz: 11200,
+            ecc: None,
+            ecc_errors: EccErrorCounts::default(),
+            mig_instances: Vec::new(),
+            video_engines: Some(VideoEngineStats {
+                encoder_utilization: 12,
+                decoder_utilization: 0,
+                session_count: Some(1),
+                average_fps: Some(60),
+                average_latency_us: Some(2500),
+            }),
+            vbios_version: "95.03.1E.00.4B".to_string(),
+            board_part_number: None,
+            board_serial: None,
+            engine_utilization: Some(EngineUtilization {
+                graphics: 28,
+                compute: 14,
+                copy: 6,
+                per_engine: true,
+            }),
+            throttle_reasons: Some(ThrottleReasons::GPU_IDLE),
+            fan_rpm: Some(1450),
+            chip_power_mw: Some(142_000),
+            clock_offsets: Some((0, 0)),
+            pcie_link: Some(PcieLink {
+                current_gen: 4,
+                max_gen: 4,
+                current_width: 16,
+                max_width: 16,
+            }),
+            display_active: true,
+            persistence_mode: false,
+            supported_clocks: Some(SupportedClocks {
+                graphics_mhz: (210..=2520).step_by(15).collect(),
+                memory_mhz: vec![405, 810, 5601, 11200],
+            }),
         }
     }
 }
@@ -641,19 +1584,35 @@ impl NvmlBackend for MockNvmlBackend {
         Ok(self.get_device(index)?.metrics.temperature)
     }
 
+    fn get_temperature_target(&self, index: u32) -> NvResult<u32> {
+        Ok(self.get_device(index)?.temperature_target)
+    }
+
     fn get_utilization(&self, index: u32) -> NvResult<(u32, u32)> {
         let m = &self.get_device(index)?.metrics;
-        Ok((m.gpu_utilization, m.memory_utilization))
+        Ok((m.gpu_utilization, m.memory_controller_utilization))
     }
 
     fn get_power_usage(&self, index: u32) -> NvResult<u32> {
         Ok(self.get_device(index)?.metrics.power_draw_mw)
     }
 
+    fn get_chip_power_usage(&self, index: u32) -> NvResult<u32> {
+        self.get_device(index)?
+            .chip_power_mw
+            .ok_or_else(|| NvControlError::UnsupportedFeature(CHIP_POWER_UNSUPPORTED.to_string()))
+    }
+
     fn get_fan_speed(&self, index: u32, _fan_index: u32) -> NvResult<u32> {
         Ok(self.get_device(index)?.metrics.fan_speed)
     }
 
+    fn get_fan_rpm(&self, index: u32, _fan_index: u32) -> NvResult<u32> {
+        self.get_device(index)?
+            .fan_rpm
+            .ok_or_else(|| NvControlError::UnsupportedFeature(FAN_RPM_UNSUPPORTED.to_string()))
+    }
+
     fn get_memory_info(&self, index: u32) -> NvResult<(u64, u64)> {
         let m = &self.get_device(index)?.metrics;
         Ok((m.memory_used_bytes, m.memory_total_bytes))
@@ -679,6 +1638,11 @@ impl NvmlBackend for MockNvmlBackend {
         Ok(self.driver_version.clone())
     }
 
+    fn get_cuda_driver_version(&self) -> NvResult<(u32, u32)> {
+        self.cuda_driver_version
+            .ok_or_else(|| NvControlError::NvmlNotAvailable("No CUDA driver".to_string()))
+    }
+
     fn get_power_limit(&self, index: u32) -> NvResult<u32> {
         Ok(self.get_device(index)?.power_limit_mw)
     }
@@ -705,6 +1669,29 @@ impl NvmlBackend for MockNvmlBackend {
         Ok(self.get_device(index)?.info.pci_bus_id.clone())
     }
 
+    fn get_vbios_version(&self, index: u32) -> NvResult<String> {
+        Ok(self.get_device(index)?.vbios_version.clone())
+    }
+
+    fn get_board_part_number(&self, index: u32) -> NvResult<String> {
+        self.get_device(index)?
+            .board_part_number
+            .clone()
+            .ok_or_else(|| {
+                NvControlError::UnsupportedFeature(
+                    "This GPU does not report its board part number".to_string(),
+                )
+            })
+    }
+
+    fn get_board_serial(&self, index: u32) -> NvResult<String> {
+        self.get_device(index)?.board_serial.clone().ok_or_else(|| {
+            NvControlError::UnsupportedFeature(
+                "This GPU does not report its serial number".to_string(),
+            )
+        })
+    }
+
     fn get_fan_count(&self, index: u32) -> NvResult<u32> {
         Ok(self.get_device(index)?.fan_count)
     }
@@ -740,6 +1727,93 @@ impl NvmlBackend for MockNvmlBackend {
     fn get_max_memory_clock(&self, index: u32) -> NvResult<u32> {
         Ok(self.get_device(index)?.max_memory_clock_mhz)
     }
+
+    fn get_ecc_mode(&self, index: u32) -> NvResult<EccState> {
+        self.get_device(index)?
+            .ecc
+            .ok_or_else(|| NvControlError::UnsupportedFeature(ECC_UNSUPPORTED.to_string()))
+    }
+
+    fn set_ecc_mode(&self, index: u32, _enabled: bool) -> NvResult<()> {
+        // Mock only checks support; the pending state is not tracked
+        self.get_ecc_mode(index).map(|_| ())
+    }
+
+    fn get_ecc_errors(&self, index: u32) -> NvResult<EccErrorCounts> {
+        self.get_ecc_mode(index)?;
+        Ok(self.get_device(index)?.ecc_errors)
+    }
+
+    fn get_mig_instances(&self, index: u32) -> NvResult<Vec<MigInstance>> {
+        Ok(self.get_device(index)?.mig_instances.clone())
+    }
+
+    fn get_video_engine_stats(&self, index: u32) -> NvResult<VideoEngineStats> {
+        self.get_device(index)?
+            .video_engines
+            .ok_or_else(|| NvControlError::UnsupportedFeature(NVENC_UNSUPPORTED.to_string()))
+    }
+
+    fn get_engine_utilization(&self, index: u32) -> NvResult<EngineUtilization> {
+        let device = self.get_device(index)?;
+        Ok(device
+            .engine_utilization
+            .unwrap_or_else(|| EngineUtilization::overall(device.metrics.gpu_utilization)))
+    }
+
+    fn get_throttle_reasons(&self, index: u32) -> NvResult<ThrottleReasons> {
+        self.get_device(index)?
+            .throttle_reasons
+            .map(ThrottleReasons)
+            .ok_or_else(|| {
+                NvControlError::UnsupportedFeature(THROTTLE_REASONS_UNSUPPORTED.to_string())
+            })
+    }
+
+    fn get_clock_offsets(&self, index: u32) -> NvResult<(i32, i32)> {
+        self.get_device(index)?.clock_offsets.ok_or_else(|| {
+            NvControlError::UnsupportedFeature(CLOCK_OFFSETS_UNSUPPORTED.to_string())
+        })
+    }
+
+    fn get_pcie_link(&self, index: u32) -> NvResult<PcieLink> {
+        self.get_device(index)?
+            .pcie_link
+            .ok_or_else(|| NvControlError::UnsupportedFeature(PCIE_LINK_UNSUPPORTED.to_string()))
+    }
+
+    fn is_display_active(&self, index: u32) -> NvResult<bool> {
+        Ok(self.get_device(index)?.display_active)
+    }
+
+    fn get_persistence_mode(&self, index: u32) -> NvResult<bool> {
+        Ok(self.get_device(index)?.persistence_mode)
+    }
+
+    fn set_persistence_mode(&self, index: u32, _enabled: bool) -> NvResult<()> {
+        // Mock only checks the device exists; the mode is not tracked
+        self.get_device(index).map(|_| ())
+    }
+
+    fn get_supported_clocks(&self, index: u32) -> NvResult<SupportedClocks> {
+        self.get_device(index)?
+            .supported_clocks
+            .clone()
+            .ok_or_else(|| NvControlError::UnsupportedFeature(CLOCK_LOCK_UNSUPPORTED.to_string()))
+    }
+
+    fn set_locked_clocks(
+        &self,
+        index: u32,
+        _gpu_mhz: Option<u32>,
+        _mem_mhz: Option<u32>,
+    ) -> NvResult<()> {
+        self.get_supported_clocks(index).map(|_| ())
+    }
+
+    fn reset_locked_clocks(&self, index: u32) -> NvResult<()> {
+        self.get_supported_clocks(index).map(|_| ())
+    }
 }
 
 /// Shared backend type for use across modules
@@ -755,6 +1829,172 @@ pub fn create_mock_backend() -> SharedNvmlBackend {
     Arc::new(MockNvmlBackend::single_gpu())
 }
 
+// =============================================================================
+// Non-NVIDIA GPUs (read-only, via sysfs)
+// =============================================================================
+
+/// GPU vendor as reported by the PCI vendor ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GpuVendor {
+    #[default]
+    Nvidia,
+    Amd,
+    Intel,
+    Unknown,
+}
+
+impl GpuVendor {
+    pub fn from_pci_vendor_id(id: u16) -> Self {
+        match id {
+            0x10de => GpuVendor::Nvidia,
+            0x1002 => GpuVendor::Amd,
+            0x8086 => GpuVendor::Intel,
+            _ => GpuVendor::Unknown,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GpuVendor::Nvidia => "NVIDIA",
+            GpuVendor::Amd => "AMD",
+            GpuVendor::Intel => "Intel",
+            GpuVendor::Unknown => "Unknown",
+        }
+    }
+
+    /// nvcontrol only controls NVIDIA GPUs; others are monitor-only
+    pub fn supports_control(&self) -> bool {
+        matches!(self, GpuVendor::Nvidia)
+    }
+}
+
+/// A non-NVIDIA GPU monitored through `/sys/class/drm/cardN/device`
+#[derive(Debug, Clone)]
+pub struct SysfsGpu {
+    /// DRM card name, e.g. `card1`
+    pub card: String,
+    pub vendor: GpuVendor,
+    pub pci_bus_id: String,
+    device_dir: PathBuf,
+}
+
+impl SysfsGpu {
+    pub fn name(&self) -> String {
+        format!("{} GPU ({})", self.vendor.name(), self.card)
+    }
+
+    pub fn device_info(&self, index: u32) -> GpuDeviceInfo {
+        GpuDeviceInfo {
+            index,
+            name: self.name(),
+            uuid: String::new(),
+            pci_bus_id: self.pci_bus_id.clone(),
+        }
+    }
+
+    /// Read whatever metrics the kernel driver exposes; missing values are 0
+    pub fn metrics(&self) -> GpuMetrics {
+        let read_u64 = |path: &Path| -> Option<u64> {
+            std::fs::read_to_string(path).ok()?.trim().parse().ok()
+        };
+
+        let hwmon = std::fs::read_dir(self.device_dir.join("hwmon"))
+            .ok()
+            .and_then(|mut entries| entries.next())
+            .and_then(|entry| entry.ok())
+            .map(|entry| entry.path());
+
+        let mut metrics = GpuMetrics {
+            gpu_utilization: read_u64(&self.device_dir.join("gpu_busy_percent")).unwrap_or(0)
+                as u32,
+            memory_used_bytes: read_u64(&self.device_dir.join("mem_info_vram_used")).unwrap_or(0),
+            memory_total_bytes: read_u64(&self.device_dir.join("mem_info_vram_total")).unwrap_or(0),
+            ..GpuMetrics::default()
+        };
+
+        // amdgpu exposes controller activity separately from VRAM usage
+        metrics.memory_controller_utilization =
+            read_u64(&self.device_dir.join("mem_busy_percent")).unwrap_or(0) as u32;
+
+        if let Some(hwmon) = hwmon {
+            // temp1_input is millidegrees, power1_* microwatts, pwm1 0-255
+            metrics.temperature = read_u64(&hwmon.join("temp1_input"))
+                .map(|t| (t / 1000) as u32)
+                .unwrap_or(0);
+            metrics.power_draw_mw = read_u64(&hwmon.join("power1_average"))
+                .or_else(|| read_u64(&hwmon.join("power1_input")))
+                .map(|p| (p / 1000) as u32)
+                .unwrap_or(0);
+            metrics.fan_speed = read_u64(&hwmon.join("pwm1"))
+                .map(|pwm| (pwm * 100 / 255) as u32)
+                .unwrap_or(0);
+            metrics.gpu_clock_mhz = read_u64(&hwmon.join("freq1_input"))
+                .map(|hz| (hz / 1_000_000) as u32)
+                .unwrap_or(0);
+            metrics.memory_clock_mhz = read_u64(&hwmon.join("freq2_input"))
+                .map(|hz| (hz / 1_000_000) as u32)
+                .unwrap_or(0);
+        }
+
+        // Intel exposes the current GT frequency on the card itself
+        if metrics.gpu_clock_mhz == 0 {
+            if let Some(card_dir) = self.device_dir.parent() {
+                metrics.gpu_clock_mhz =
+                    read_u64(&card_dir.join("gt_cur_freq_mhz")).unwrap_or(0) as u32;
+            }
+        }
+
+        metrics
+    }
+}
+
+/// Find non-NVIDIA GPUs under `/sys/class/drm`
+pub fn detect_sysfs_gpus() -> Vec<SysfsGpu> {
+    detect_sysfs_gpus_in(Path::new("/sys/class/drm"))
+}
+
+/// Find non-NVIDIA GPUs under the given DRM class directory
+pub fn detect_sysfs_gpus_in(drm_root: &Path) -> Vec<SysfsGpu> {
+    let Ok(entries) = std::fs::read_dir(drm_root) else {
+        return Vec::new();
+    };
+
+    let mut gpus: Vec<SysfsGpu> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let card = entry.file_name().to_string_lossy().to_string();
+            // Skip connectors like card0-DP-1 and render nodes
+            if !card.starts_with("card") || card.contains('-') {
+                return None;
+            }
+
+            let device_dir = entry.path().join("device");
+            let vendor_id = std::fs::read_to_string(device_dir.join("vendor")).ok()?;
+            let vendor = u16::from_str_radix(vendor_id.trim().trim_start_matches("0x"), 16)
+                .map(GpuVendor::from_pci_vendor_id)
+                .ok()?;
+            if vendor == GpuVendor::Nvidia {
+                return None;
+            }
+
+            let pci_bus_id = std::fs::read_link(&device_dir)
+                .ok()
+                .and_then(|target| target.file_name().map(|n| n.to_string_lossy().to_string()))
+                .unwrap_or_default();
+
+            Some(SysfsGpu {
+                card,
+                vendor,
+                pci_bus_id,
+                device_dir,
+            })
+        })
+        .collect();
+
+    gpus.sort_by(|a, b| a.card.cmp(&b.card));
+    gpus
+}
+
 // =============================================================================
 // GUI/TUI Backend Context
 // =============================================================================
@@ -902,8 +2142,12 @@ pub struct GuiBackendContext {
     pub nvml: SharedNvmlBackend,
     /// Shared display command runner for HDR/VRR/vibrance
     pub display: SharedDisplayRunner,
-    /// Cached device count (avoids repeated queries)
+    /// Cached device count across all vendors (avoids repeated queries)
     pub device_count: u32,
+    /// Number of NVIDIA GPUs; indices at or past this are `sysfs_gpus`
+    pub nvidia_count: u32,
+    /// Non-NVIDIA GPUs, monitored read-only
+    pub sysfs_gpus: Vec<SysfsGpu>,
     /// Cached driver version
     pub driver_version: String,
     /// Backend availability status (debounced)
@@ -920,7 +2164,9 @@ impl GuiBackendContext {
         let nvml = create_real_backend();
         let display = crate::display_backend::create_real_runner();
 
-        let device_count = nvml.device_count().unwrap_or(0);
+        let nvidia_count = nvml.device_count().unwrap_or(0);
+        let sysfs_gpus = detect_sysfs_gpus();
+        let device_count = nvidia_count + sysfs_gpus.len() as u32;
         let driver_version = nvml.get_driver_version().unwrap_or_default();
 
         // Determine backend status
@@ -944,6 +2190,8 @@ impl GuiBackendContext {
             nvml,
             display,
             device_count,
+            nvidia_count,
+            sysfs_gpus,
             driver_version,
             status,
             metrics_cache,
@@ -956,7 +2204,8 @@ impl GuiBackendContext {
         let nvml = create_mock_backend();
         let display = crate::display_backend::create_mock_runner_x11();
 
-        let device_count = nvml.device_count().unwrap_or(0);
+        let nvidia_count = nvml.device_count().unwrap_or(0);
+        let device_count = nvidia_count;
         let driver_version = nvml.get_driver_version().unwrap_or_default();
 
         let metrics_cache = Arc::new(RwLock::new(vec![None; device_count as usize]));
@@ -967,6 +2216,8 @@ impl GuiBackendContext {
             nvml,
             display,
             device_count,
+            nvidia_count,
+            sysfs_gpus: Vec::new(),
             driver_version,
             status,
             metrics_cache,
@@ -976,7 +2227,8 @@ impl GuiBackendContext {
 
     /// Create a context with custom backends
     pub fn with_backends(nvml: SharedNvmlBackend, display: SharedDisplayRunner) -> Self {
-        let device_count = nvml.device_count().unwrap_or(0);
+        let nvidia_count = nvml.device_count().unwrap_or(0);
+        let device_count = nvidia_count;
         let driver_version = nvml.get_driver_version().unwrap_or_default();
 
         let nvml_available = nvml.is_available();
@@ -998,6 +2250,8 @@ impl GuiBackendContext {
             nvml,
             display,
             device_count,
+            nvidia_count,
+            sysfs_gpus: Vec::new(),
             driver_version,
             status,
             metrics_cache,
@@ -1015,6 +2269,10 @@ impl GuiBackendContext {
     /// If the query fails, returns the last cached value if available.
     /// Call `get_cached_metrics_age()` to check staleness.
     pub fn get_metrics(&self, gpu_index: u32) -> NvResult<GpuMetrics> {
+        if let Some(gpu) = self.sysfs_gpu(gpu_index) {
+            return Ok(gpu.metrics());
+        }
+
         match self.nvml.get_metrics(gpu_index) {
             Ok(metrics) => {
                 // Update cache with fresh data
@@ -1058,9 +2316,46 @@ impl GuiBackendContext {
 
     /// Get device info (convenience wrapper)
     pub fn get_device_info(&self, gpu_index: u32) -> NvResult<GpuDeviceInfo> {
+        if let Some(gpu) = self.sysfs_gpu(gpu_index) {
+            return Ok(gpu.device_info(gpu_index));
+        }
         self.nvml.get_device_info(gpu_index)
     }
 
+    /// Get device name for any vendor
+    pub fn get_name(&self, gpu_index: u32) -> NvResult<String> {
+        if let Some(gpu) = self.sysfs_gpu(gpu_index) {
+            return Ok(gpu.name());
+        }
+        self.nvml.get_name(gpu_index)
+    }
+
+    /// PCI bus id for any vendor
+    pub fn get_pci_bus_id(&self, gpu_index: u32) -> NvResult<String> {
+        if let Some(gpu) = self.sysfs_gpu(gpu_index) {
+            return Ok(gpu.pci_bus_id.clone());
+        }
+        self.nvml.get_pci_bus_id(gpu_index)
+    }
+
+    /// Vendor of the GPU at this index
+    pub fn vendor(&self, gpu_index: u32) -> GpuVendor {
+        self.sysfs_gpu(gpu_index)
+            .map(|gpu| gpu.vendor)
+            .unwrap_or(GpuVendor::Nvidia)
+    }
+
+    /// Whether control actions (OC, fans, processes) apply to this GPU
+    pub fn supports_control(&self, gpu_index: u32) -> bool {
+        self.vendor(gpu_index).supports_control()
+    }
+
+    fn sysfs_gpu(&self, gpu_index: u32) -> Option<&SysfsGpu> {
+        gpu_index
+            .checked_sub(self.nvidia_count)
+            .and_then(|i| self.sysfs_gpus.get(i as usize))
+    }
+
     /// Refresh backend status with debouncing.
     ///
     /// Call this periodically (e.g., each frame or poll cycle) to check for
@@ -1132,6 +2427,43 @@ mod tests {
         assert!(metrics.memory_total_bytes > 0);
     }
 
+    #[test]
+    fn test_mock_ecc_support() {
+        let mut backend = MockNvmlBackend::single_gpu();
+        assert!(matches!(
+            backend.get_ecc_mode(0),
+            Err(NvControlError::UnsupportedFeature(_))
+        ));
+        assert!(backend.set_ecc_mode(0, true).is_err());
+
+        backend.devices[0].ecc = Some(EccState {
+            current_enabled: true,
+            pending_enabled: false,
+        });
+        let state = backend.get_ecc_mode(0).unwrap();
+        assert!(state.current_enabled && !state.pending_enabled);
+        assert!(backend.set_ecc_mode(0, false).is_ok());
+    }
+
+    #[test]
+    fn test_mock_mig_instances() {
+        let mut backend = MockNvmlBackend::single_gpu();
+        assert!(backend.get_mig_instances(0).unwrap().is_empty());
+
+        backend.devices[0].mig_instances = vec![MigInstance {
+            parent_index: 0,
+            index: 0,
+            name: "NVIDIA A100-SXM4-40GB MIG 3g.20gb".to_string(),
+            sm_count: Some(42),
+            memory_total_bytes: 20 << 30,
+            ..Default::default()
+        }];
+        let instances = backend.get_mig_instances(0).unwrap();
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].profile_name(), "3g.20gb");
+        assert!(backend.get_mig_instances(1).is_err());
+    }
+
     #[test]
     fn test_mock_multi_gpu() {
         let backend = MockNvmlBackend::multi_gpu(4);
@@ -1188,6 +2520,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gpu_vendor_from_pci_id() {
+        assert_eq!(GpuVendor::from_pci_vendor_id(0x10de), GpuVendor::Nvidia);
+        assert_eq!(GpuVendor::from_pci_vendor_id(0x1002), GpuVendor::Amd);
+        assert_eq!(GpuVendor::from_pci_vendor_id(0x8086), GpuVendor::Intel);
+        assert!(!GpuVendor::Amd.supports_control());
+    }
+
+    #[test]
+    fn test_detect_sysfs_gpus_skips_nvidia() {
+        let root = tempfile::tempdir().unwrap();
+        for (card, vendor) in [("card0", "0x10de"), ("card1", "0x1002")] {
+            let hwmon = root.path().join(card).join("device/hwmon/hwmon0");
+            std::fs::create_dir_all(&hwmon).unwrap();
+            std::fs::write(root.path().join(card).join("device/vendor"), vendor).unwrap();
+            std::fs::write(hwmon.join("temp1_input"), "61000").unwrap();
+        }
+        std::fs::write(root.path().join("card1/device/gpu_busy_percent"), "37").unwrap();
+        std::fs::create_dir_all(root.path().join("card1-DP-1")).unwrap();
+
+        let gpus = detect_sysfs_gpus_in(root.path());
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].vendor, GpuVendor::Amd);
+
+        let metrics = gpus[0].metrics();
+        assert_eq!(metrics.temperature, 61);
+        assert_eq!(metrics.gpu_utilization, 37);
+    }
+
     #[test]
     fn test_gui_backend_context_mock() {
         let ctx = GuiBackendContext::mock();
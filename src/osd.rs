@@ -69,6 +69,324 @@ impl Default for OsdConfig {
     }
 }
 
+/// Values MangoHud accepts for `position`
+pub const MANGOHUD_POSITIONS: &[&str] = &[
+    "top-left",
+    "top-center",
+    "top-right",
+    "middle-left",
+    "middle-right",
+    "bottom-left",
+    "bottom-center",
+    "bottom-right",
+];
+
+/// MangoHud metric keys with display names, in HUD order
+pub const MANGOHUD_METRICS: &[(&str, &str)] = &[
+    ("fps", "FPS Counter"),
+    ("frametime", "Frame Time (ms)"),
+    ("gpu_name", "GPU Name"),
+    ("gpu_stats", "GPU Utilization"),
+    ("gpu_temp", "GPU Temperature"),
+    ("gpu_core_clock", "GPU Core Clock"),
+    ("gpu_mem_clock", "GPU Memory Clock"),
+    ("gpu_power", "GPU Power Draw"),
+    ("vram", "VRAM Usage"),
+    ("fan", "Fan Speed"),
+    ("throttling_status", "Throttling Status"),
+    ("cpu_stats", "CPU Utilization"),
+    ("cpu_temp", "CPU Temperature"),
+    ("cpu_power", "CPU Power Draw"),
+    ("cpu_mhz", "CPU Clock"),
+    ("ram", "RAM Usage"),
+    ("vulkan_driver", "Vulkan Driver"),
+    ("wine", "Wine/Proton Version"),
+    ("gamemode", "GameMode Status"),
+    ("time", "Clock"),
+];
+
+/// Values MangoHud accepts in the `graphs` list
+pub const MANGOHUD_GRAPHS: &[(&str, &str)] = &[
+    ("gpu_load", "GPU Load"),
+    ("cpu_load", "CPU Load"),
+    ("gpu_core_clock", "GPU Core Clock"),
+    ("gpu_mem_clock", "GPU Memory Clock"),
+    ("gpu_temp", "GPU Temperature"),
+    ("cpu_temp", "CPU Temperature"),
+    ("vram", "VRAM"),
+    ("ram", "RAM"),
+];
+
+/// A MangoHud.conf covering the commonly used options.
+///
+/// Options nvcontrol doesn't model are kept in `extra` and written back
+/// verbatim, so editing an existing config never drops the user's settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MangoHudConfig {
+    /// One of [`MANGOHUD_POSITIONS`]
+    pub position: String,
+    pub offset_x: i32,
+    pub offset_y: i32,
+    /// Lay the HUD out in one row instead of a column
+    pub horizontal: bool,
+    /// Enabled keys from [`MANGOHUD_METRICS`], in HUD order
+    pub metrics: Vec<String>,
+    /// The frame timing graph, on unless turned off
+    pub frame_timing: bool,
+    /// Draw the frame timing graph as bars
+    pub histogram: bool,
+    /// Enabled keys from [`MANGOHUD_GRAPHS`]
+    pub graphs: Vec<String>,
+    pub font_size: u32,
+    pub background_alpha: f32,
+    pub text_color: [u8; 3],
+    pub gpu_color: [u8; 3],
+    pub cpu_color: [u8; 3],
+    pub frametime_color: [u8; 3],
+    pub background_color: [u8; 3],
+    pub fps_limit: Option<u32>,
+    pub toggle_hud: String,
+    /// Benchmark logging; `None` leaves logging off
+    pub output_folder: Option<String>,
+    /// Seconds per log, 0 for until stopped
+    pub log_duration: u32,
+    pub toggle_logging: String,
+    pub extra: Vec<String>,
+}
+
+impl Default for MangoHudConfig {
+    fn default() -> Self {
+        Self {
+            position: "top-left".to_string(),
+            offset_x: 0,
+            offset_y: 0,
+            horizontal: false,
+            metrics: [
+                "fps",
+                "frametime",
+                "gpu_stats",
+                "gpu_temp",
+                "cpu_stats",
+                "vram",
+            ]
+            .map(String::from)
+            .to_vec(),
+            frame_timing: true,
+            histogram: false,
+            graphs: Vec::new(),
+            font_size: 24,
+            background_alpha: 0.5,
+            // MangoHud's own defaults
+            text_color: [0xff, 0xff, 0xff],
+            gpu_color: [0x2e, 0x97, 0x62],
+            cpu_color: [0x2e, 0x97, 0xcb],
+            frametime_color: [0x00, 0xff, 0x00],
+            background_color: [0x02, 0x02, 0x02],
+            fps_limit: None,
+            toggle_hud: "Shift_R+F12".to_string(),
+            output_folder: None,
+            log_duration: 0,
+            toggle_logging: "Shift_L+F2".to_string(),
+            extra: Vec::new(),
+        }
+    }
+}
+
+impl MangoHudConfig {
+    /// `~/.config/MangoHud/MangoHud.conf`
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("MangoHud").join("MangoHud.conf"))
+    }
+
+    /// Read the user's MangoHud.conf; `None` when there isn't one yet
+    pub fn load() -> Option<Self> {
+        Self::default_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| Self::parse(&text))
+    }
+
+    /// Parse MangoHud.conf syntax: `key=value` or bare `key` lines, `#` comments
+    pub fn parse(text: &str) -> Self {
+        let mut config = Self {
+            metrics: Vec::new(),
+            ..Self::default()
+        };
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim())),
+                None => (line, None),
+            };
+            // Bare keys and `key=1` switch an option on, `key=0` off
+            let on = value != Some("0");
+
+            match key {
+                "position" => config.position = value.unwrap_or_default().to_string(),
+                "offset_x" => config.offset_x = parse_or(value, config.offset_x),
+                "offset_y" => config.offset_y = parse_or(value, config.offset_y),
+                "horizontal" => config.horizontal = on,
+                "frame_timing" => config.frame_timing = on,
+                "histogram" => config.histogram = on,
+                "graphs" => {
+                    config.graphs = value
+                        .unwrap_or_default()
+                        .split(',')
+                        .map(|g| g.trim().to_string())
+                        .filter(|g| !g.is_empty())
+                        .collect()
+                }
+                "font_size" => config.font_size = parse_or(value, config.font_size),
+                "background_alpha" => {
+                    config.background_alpha = parse_or(value, config.background_alpha)
+                }
+                "text_color" => parse_color(value, &mut config.text_color),
+                "gpu_color" => parse_color(value, &mut config.gpu_color),
+                "cpu_color" => parse_color(value, &mut config.cpu_color),
+                "frametime_color" => parse_color(value, &mut config.frametime_color),
+                "background_color" => parse_color(value, &mut config.background_color),
+                "fps_limit" => {
+                    config.fps_limit = value
+                        .and_then(|v| v.split(',').next())
+                        .and_then(|v| v.trim().parse().ok())
+                        .filter(|limit| *limit > 0)
+                }
+                "toggle_hud" => config.toggle_hud = value.unwrap_or_default().to_string(),
+                "output_folder" => config.output_folder = value.map(str::to_string),
+                "log_duration" => config.log_duration = parse_or(value, config.log_duration),
+                "toggle_logging" => config.toggle_logging = value.unwrap_or_default().to_string(),
+                _ if MANGOHUD_METRICS.iter().any(|(k, _)| *k == key) => {
+                    if on && !config.metrics.iter().any(|m| m == key) {
+                        config.metrics.push(key.to_string());
+                    }
+                }
+                _ => config.extra.push(line.to_string()),
+            }
+        }
+        config
+    }
+
+    /// Check values MangoHud would reject or silently ignore
+    pub fn validate(&self) -> NvResult<()> {
+        if !MANGOHUD_POSITIONS.contains(&self.position.as_str()) {
+            return Err(NvControlError::ConfigError(format!(
+                "Invalid MangoHud position '{}': expected one of {}",
+                self.position,
+                MANGOHUD_POSITIONS.join(", ")
+            )));
+        }
+        if let Some(graph) = self
+            .graphs
+            .iter()
+            .find(|g| !MANGOHUD_GRAPHS.iter().any(|(k, _)| k == g))
+        {
+            return Err(NvControlError::ConfigError(format!(
+                "Unknown MangoHud graph '{}'",
+                graph
+            )));
+        }
+        if !(0.0..=1.0).contains(&self.background_alpha) {
+            return Err(NvControlError::ConfigError(
+                "background_alpha must be between 0 and 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Render as MangoHud.conf text
+    pub fn to_conf_string(&self) -> String {
+        let mut lines = vec![
+            "# nvcontrol MangoHud configuration".to_string(),
+            "# Options nvcontrol doesn't edit are kept at the end".to_string(),
+            String::new(),
+            format!("position={}", self.position),
+        ];
+        if self.offset_x != 0 || self.offset_y != 0 {
+            lines.push(format!("offset_x={}", self.offset_x));
+            lines.push(format!("offset_y={}", self.offset_y));
+        }
+        if self.horizontal {
+            lines.push("horizontal".to_string());
+        }
+
+        lines.push(String::new());
+        lines.extend(self.metrics.iter().cloned());
+        lines.push(format!("frame_timing={}", u8::from(self.frame_timing)));
+        if self.histogram {
+            lines.push("histogram".to_string());
+        }
+        if !self.graphs.is_empty() {
+            lines.push(format!("graphs={}", self.graphs.join(",")));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("font_size={}", self.font_size));
+        lines.push(format!("background_alpha={:.2}", self.background_alpha));
+        for (key, color) in [
+            ("text_color", self.text_color),
+            ("gpu_color", self.gpu_color),
+            ("cpu_color", self.cpu_color),
+            ("frametime_color", self.frametime_color),
+            ("background_color", self.background_color),
+        ] {
+            lines.push(format!(
+                "{}={:02X}{:02X}{:02X}",
+                key, color[0], color[1], color[2]
+            ));
+        }
+
+        lines.push(String::new());
+        if let Some(limit) = self.fps_limit {
+            lines.push(format!("fps_limit={}", limit));
+        }
+        lines.push(format!("toggle_hud={}", self.toggle_hud));
+        if let Some(folder) = &self.output_folder {
+            lines.push(format!("output_folder={}", folder));
+            lines.push(format!("log_duration={}", self.log_duration));
+            lines.push(format!("toggle_logging={}", self.toggle_logging));
+        }
+
+        if !self.extra.is_empty() {
+            lines.push(String::new());
+            lines.extend(self.extra.iter().cloned());
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Validate and write to `~/.config/MangoHud/MangoHud.conf`
+    pub fn save(&self) -> NvResult<PathBuf> {
+        self.validate()?;
+        let path = Self::default_path()
+            .ok_or_else(|| NvControlError::ConfigError("Could not find config directory".into()))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, self.to_conf_string())?;
+        Ok(path)
+    }
+}
+
+fn parse_or<T: std::str::FromStr>(value: Option<&str>, fallback: T) -> T {
+    value.and_then(|v| v.parse().ok()).unwrap_or(fallback)
+}
+
+/// `RRGGBB`, with or without a leading `#`
+fn parse_color(value: Option<&str>, color: &mut [u8; 3]) {
+    let Some(hex) = value.map(|v| v.trim_start_matches('#')) else {
+        return;
+    };
+    if hex.len() != 6 {
+        return;
+    }
+    if let Ok(rgb) = u32::from_str_radix(hex, 16) {
+        *color = [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8];
+    }
+}
+
 pub struct OsdManager {
     config: OsdConfig,
     config_path: PathBuf,
@@ -265,3 +583,45 @@ impl PartialEq for OsdMetric {
         std::mem::discriminant(self) == std::mem::discriminant(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mangohud_config_round_trip() {
+        let text = "\
+# user config
+position=bottom-right
+fps
+gpu_temp=1
+cpu_temp=0
+frame_timing=0
+graphs=gpu_load,vram
+font_size=18
+text_color=#AABBCC
+fps_limit=144,60
+output_folder=/tmp/logs
+log_duration=30
+toggle_fps_limit=Shift_L+F1
+";
+        let config = MangoHudConfig::parse(text);
+        assert_eq!(config.position, "bottom-right");
+        assert_eq!(config.metrics, vec!["fps", "gpu_temp"]);
+        assert!(!config.frame_timing);
+        assert_eq!(config.graphs, vec!["gpu_load", "vram"]);
+        assert_eq!(config.text_color, [0xaa, 0xbb, 0xcc]);
+        assert_eq!(config.fps_limit, Some(144));
+        assert_eq!(config.extra, vec!["toggle_fps_limit=Shift_L+F1"]);
+        config.validate().unwrap();
+
+        // Writing and re-reading loses nothing
+        assert_eq!(MangoHudConfig::parse(&config.to_conf_string()), config);
+
+        let bad = MangoHudConfig {
+            position: "top".to_string(),
+            ..config
+        };
+        assert!(bad.validate().is_err());
+    }
+}
@@ -1,6 +1,13 @@
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Number of applied profiles kept for `revert_last()`
+pub const OVERCLOCK_HISTORY_CAPACITY: usize = 16;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverclockProfile {
@@ -35,6 +42,27 @@ impl Default for OverclockProfile {
     }
 }
 
+impl OverclockProfile {
+    /// Moderate offsets most boards hold at stock voltage
+    pub fn performance() -> Self {
+        Self {
+            name: "Performance".to_string(),
+            gpu_clock_offset: 75,
+            memory_clock_offset: 400,
+            ..Self::default()
+        }
+    }
+
+    /// Whether offsets or power limit differ from stock
+    pub fn is_overclocked(&self) -> bool {
+        let stock = Self::default();
+        self.gpu_clock_offset != stock.gpu_clock_offset
+            || self.memory_clock_offset != stock.memory_clock_offset
+            || self.voltage_offset != stock.voltage_offset
+            || self.power_limit != stock.power_limit
+    }
+}
+
 #[derive(Debug)]
 pub struct GpuCapabilities {
     pub max_gpu_clock_offset: i32,
@@ -90,50 +118,497 @@ pub fn get_gpu_capabilities() -> NvResult<GpuCapabilities> {
 pub fn get_current_offsets(gpu_index: u32) -> NvResult<(i32, i32)> {
     // Try nvidia-settings first (X11)
     if std::env::var("DISPLAY").is_ok() {
-        let gpu_cmd = format!(
-            "nvidia-settings -q '[gpu:{}]/GPUGraphicsClockOffset[3]' -t",
-            gpu_index
-        );
-        let mem_cmd = format!(
-            "nvidia-settings -q '[gpu:{}]/GPUMemoryTransferRateOffset[3]' -t",
-            gpu_index
-        );
+        let gpu_offset = query_settings_offset(gpu_index, "GPUGraphicsClockOffset").unwrap_or(0);
+        let mem_offset =
+            query_settings_offset(gpu_index, "GPUMemoryTransferRateOffset").unwrap_or(0);
+        return Ok((gpu_offset, mem_offset));
+    }
+
+    // Default to 0 offsets if nvidia-settings not available
+    Ok((0, 0))
+}
+
+/// Read a performance-level 3 offset attribute through nvidia-settings
+fn query_settings_offset(gpu_index: u32, attribute: &str) -> Option<i32> {
+    let output = std::process::Command::new("nvidia-settings")
+        .arg("-q")
+        .arg(format!("[gpu:{}]/{}[3]", gpu_index, attribute))
+        .arg("-t")
+        .output()
+        .ok()?;
+    String::from_utf8(output.stdout)
+        .ok()?
+        .trim()
+        .parse::<i32>()
+        .ok()
+}
 
-        let gpu_output = std::process::Command::new("sh")
-            .arg("-c")
-            .arg(&gpu_cmd)
-            .output();
+/// Offsets and power limit as the driver reports them, `None` where unreadable
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AppliedState {
+    pub gpu_clock_offset: Option<i32>,
+    /// Transfer-rate offset, the unit profiles and nvidia-settings use
+    pub memory_clock_offset: Option<i32>,
+    /// Percent of the default power limit
+    pub power_limit: Option<i32>,
+}
 
-        let mem_output = std::process::Command::new("sh")
-            .arg("-c")
-            .arg(&mem_cmd)
-            .output();
+/// One requested setting next to its read-back value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldCheck {
+    pub field: &'static str,
+    pub requested: i32,
+    pub actual: Option<i32>,
+    pub unit: &'static str,
+}
 
-        let gpu_offset = gpu_output
+impl FieldCheck {
+    /// `None` when the value couldn't be read back
+    pub fn applied(&self) -> Option<bool> {
+        // Power limits are set in whole watts, so the percentage can drift by one
+        let tolerance = if self.unit == "%" { 1 } else { 0 };
+        self.actual
+            .map(|actual| (actual - self.requested).abs() <= tolerance)
+    }
+}
+
+impl AppliedState {
+    /// Compare against the profile that was just applied
+    pub fn verify(&self, profile: &OverclockProfile) -> Vec<FieldCheck> {
+        vec![
+            FieldCheck {
+                field: "GPU offset",
+                requested: profile.gpu_clock_offset,
+                actual: self.gpu_clock_offset,
+                unit: " MHz",
+            },
+            FieldCheck {
+                field: "Memory offset",
+                requested: profile.memory_clock_offset,
+                actual: self.memory_clock_offset,
+                unit: " MHz",
+            },
+            FieldCheck {
+                field: "Power limit",
+                requested: profile.power_limit as i32,
+                actual: self.power_limit,
+                unit: "%",
+            },
+        ]
+    }
+}
+
+/// Read back what the driver actually has set on `gpu_index`
+pub fn read_applied_state(gpu_index: u32) -> AppliedState {
+    read_applied_state_with(
+        crate::nvml_backend::create_real_backend().as_ref(),
+        gpu_index,
+    )
+}
+
+pub fn read_applied_state_with(
+    backend: &dyn crate::nvml_backend::NvmlBackend,
+    gpu_index: u32,
+) -> AppliedState {
+    let (gpu_clock_offset, memory_clock_offset) = match backend.get_clock_offsets(gpu_index) {
+        // NVML reports the memory clock offset, half the transfer-rate offset
+        Ok((gpu, memory)) => (Some(gpu), Some(memory * 2)),
+        // Older drivers only expose offsets through nvidia-settings on X11
+        Err(_) if std::env::var("DISPLAY").is_ok() => (
+            query_settings_offset(gpu_index, "GPUGraphicsClockOffset"),
+            query_settings_offset(gpu_index, "GPUMemoryTransferRateOffset"),
+        ),
+        Err(_) => (None, None),
+    };
+
+    let power_limit = backend
+        .get_power_limit(gpu_index)
+        .ok()
+        .zip(backend.get_power_limit_default(gpu_index).ok())
+        .filter(|(_, default)| *default > 0)
+        .map(|(limit, default)| (limit as f64 * 100.0 / default as f64).round() as i32);
+
+    AppliedState {
+        gpu_clock_offset,
+        memory_clock_offset,
+        power_limit,
+    }
+}
+
+/// A profile that was applied, with the time it was applied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverclockHistoryEntry {
+    pub profile: OverclockProfile,
+    /// GPU the profile was applied to (entries written before this was
+    /// tracked were always GPU 0)
+    #[serde(default)]
+    pub gpu_index: u32,
+    pub applied_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Ring buffer of applied overclock profiles, newest last
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverclockHistory {
+    entries: VecDeque<OverclockHistoryEntry>,
+}
+
+impl OverclockHistory {
+    /// Load history from disk, starting empty if missing or unreadable
+    pub fn load() -> Self {
+        fs::read_to_string(Self::history_file_path())
             .ok()
-            .and_then(|o| String::from_utf8(o.stdout).ok())
-            .and_then(|s| s.trim().parse::<i32>().ok())
-            .unwrap_or(0);
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save history to disk
+    pub fn save(&self) -> NvResult<()> {
+        // Nothing was applied, so there's nothing to record for revert
+        if crate::gpu_safe::is_dry_run() {
+            return Ok(());
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to serialize OC history: {}", e))
+        })?;
+        fs::write(Self::history_file_path(), json).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to write OC history: {}", e))
+        })?;
+        Ok(())
+    }
+
+    fn history_file_path() -> PathBuf {
+        let config_dir = dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol");
+        fs::create_dir_all(&config_dir).ok();
+        config_dir.join("oc_history.json")
+    }
+
+    /// Record a profile applied to `gpu_index`, dropping the oldest entry when full
+    pub fn record(&mut self, profile: &OverclockProfile, gpu_index: u32) {
+        if self.entries.len() >= OVERCLOCK_HISTORY_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(OverclockHistoryEntry {
+            profile: profile.clone(),
+            gpu_index,
+            applied_at: chrono::Utc::now(),
+        });
+    }
+
+    /// Drop the most recent entry and return its GPU together with the
+    /// profile that GPU had before it (stock when there is none)
+    pub fn take_revert_target(&mut self) -> Option<(u32, OverclockProfile)> {
+        let undone = self.entries.pop_back()?;
+        let previous = self
+            .last_for_gpu(undone.gpu_index)
+            .map(|entry| entry.profile.clone())
+            .unwrap_or_default();
+        Some((undone.gpu_index, previous))
+    }
 
-        let mem_offset = mem_output
+    /// Most recently applied profile on `gpu_index`
+    pub fn last_for_gpu(&self, gpu_index: u32) -> Option<&OverclockHistoryEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.gpu_index == gpu_index)
+    }
+
+    /// GPUs whose most recent entry is overclocked
+    pub fn overclocked_gpus(&self) -> Vec<u32> {
+        let mut gpus: Vec<u32> = self.entries.iter().map(|entry| entry.gpu_index).collect();
+        gpus.sort_unstable();
+        gpus.dedup();
+        gpus.retain(|&gpu| {
+            self.last_for_gpu(gpu)
+                .is_some_and(|entry| entry.profile.is_overclocked())
+        });
+        gpus
+    }
+
+    /// Remove and return the most recently applied profile
+    pub fn pop(&mut self) -> Option<OverclockHistoryEntry> {
+        self.entries.pop_back()
+    }
+
+    /// Most recently applied profile
+    pub fn last(&self) -> Option<&OverclockHistoryEntry> {
+        self.entries.back()
+    }
+
+    /// Entries from oldest to newest
+    pub fn entries(&self) -> std::collections::vec_deque::Iter<'_, OverclockHistoryEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Clocks pinned with `lock_clocks`, remembered so the UIs can show "LOCKED".
+/// The driver drops locks on reboot, so records from an earlier boot are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedClocks {
+    pub gpu_index: u32,
+    pub gpu_mhz: Option<u32>,
+    pub mem_mhz: Option<u32>,
+    pub locked_at: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    boot_id: Option<String>,
+}
+
+impl LockedClocks {
+    /// Short form for headers, e.g. "2400 MHz core / 10501 MHz mem"
+    pub fn label(&self) -> String {
+        let parts: Vec<String> = [(self.gpu_mhz, "core"), (self.mem_mhz, "mem")]
+            .into_iter()
+            .filter_map(|(mhz, name)| mhz.map(|mhz| format!("{mhz} MHz {name}")))
+            .collect();
+        parts.join(" / ")
+    }
+
+    /// Lock currently held on `gpu_index`, if nvcontrol set one this boot
+    pub fn load(gpu_index: u32) -> Option<Self> {
+        let boot_id = current_boot_id();
+        Self::load_all()
+            .into_iter()
+            .find(|lock| lock.gpu_index == gpu_index && lock.boot_id == boot_id)
+    }
+
+    fn load_all() -> Vec<Self> {
+        fs::read_to_string(Self::file_path())
             .ok()
-            .and_then(|o| String::from_utf8(o.stdout).ok())
-            .and_then(|s| s.trim().parse::<i32>().ok())
-            .unwrap_or(0);
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
 
-        return Ok((gpu_offset, mem_offset));
+    /// Replace the record for `gpu_index`; `None` clears it
+    fn store(gpu_index: u32, lock: Option<Self>) -> NvResult<()> {
+        if crate::gpu_safe::is_dry_run() {
+            return Ok(());
+        }
+        let mut locks = Self::load_all();
+        locks.retain(|l| l.gpu_index != gpu_index);
+        locks.extend(lock);
+
+        let path = Self::file_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&locks).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to serialize clock locks: {e}"))
+        })?;
+        fs::write(path, json)?;
+        Ok(())
     }
 
-    // Default to 0 offsets if nvidia-settings not available
-    Ok((0, 0))
+    fn file_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol")
+            .join("locked_clocks.json")
+    }
+}
+
+fn current_boot_id() -> Option<String> {
+    fs::read_to_string("/proc/sys/kernel/random/boot_id")
+        .ok()
+        .map(|id| id.trim().to_string())
+}
+
+/// Check requested lock clocks against the steps the GPU reports
+pub fn validate_clock_lock(
+    supported: &crate::nvml_backend::SupportedClocks,
+    gpu_mhz: Option<u32>,
+    mem_mhz: Option<u32>,
+) -> NvResult<()> {
+    let check = |mhz: Option<u32>, range: Option<(u32, u32)>, name: &str| match (mhz, range) {
+        (None, _) => Ok(()),
+        (Some(_), None) => Err(NvControlError::UnsupportedFeature(format!(
+            "This GPU reports no supported {name} clocks"
+        ))),
+        (Some(mhz), Some((min, max))) if mhz < min || mhz > max => {
+            Err(NvControlError::RuntimeError(format!(
+                "{name} clock {mhz} MHz is outside the supported range {min}-{max} MHz"
+            )))
+        }
+        _ => Ok(()),
+    };
+
+    if gpu_mhz.is_none() && mem_mhz.is_none() {
+        return Err(NvControlError::RuntimeError(
+            "Specify a GPU and/or memory clock to lock".to_string(),
+        ));
+    }
+    check(gpu_mhz, supported.graphics_range(), "GPU")?;
+    check(mem_mhz, supported.memory_range(), "Memory")
+}
+
+/// Pin GPU and/or memory clocks to fixed values so benchmark runs are comparable
+pub fn lock_clocks(
+    backend: &dyn crate::nvml_backend::NvmlBackend,
+    gpu_index: u32,
+    gpu_mhz: Option<u32>,
+    mem_mhz: Option<u32>,
+) -> NvResult<LockedClocks> {
+    let supported = backend.get_supported_clocks(gpu_index)?;
+    validate_clock_lock(&supported, gpu_mhz, mem_mhz)?;
+
+    let lock = LockedClocks {
+        gpu_index,
+        gpu_mhz,
+        mem_mhz,
+        locked_at: chrono::Utc::now(),
+        boot_id: current_boot_id(),
+    };
+    if crate::gpu_safe::should_apply(&format!(
+        "lock GPU {} clocks to {}",
+        gpu_index,
+        lock.label()
+    )) {
+        backend.set_locked_clocks(gpu_index, gpu_mhz, mem_mhz)?;
+    }
+    LockedClocks::store(gpu_index, Some(lock.clone()))?;
+    Ok(lock)
+}
+
+/// Release clock locks and let the GPU boost normally again
+pub fn reset_locked_clocks(
+    backend: &dyn crate::nvml_backend::NvmlBackend,
+    gpu_index: u32,
+) -> NvResult<()> {
+    if crate::gpu_safe::should_apply(&format!("reset locked clocks on GPU {}", gpu_index)) {
+        backend.reset_locked_clocks(gpu_index)?;
+    }
+    LockedClocks::store(gpu_index, None)
 }
 
 pub fn apply_overclock_profile(profile: &OverclockProfile) -> NvResult<()> {
-    println!("Applying overclock profile: {}", profile.name);
+    apply_overclock_profile_for_gpu(profile, 0)
+}
+
+/// Apply `profile`'s clock offsets to one GPU and record it in the history
+pub fn apply_overclock_profile_for_gpu(profile: &OverclockProfile, gpu_index: u32) -> NvResult<()> {
+    log::info!(
+        "Applying overclock profile: {} (GPU {})",
+        profile.name,
+        gpu_index
+    );
+
+    let mut history = OverclockHistory::load();
+    apply_and_record(profile, gpu_index, &mut history, apply_offsets)?;
+    if let Err(e) = history.save() {
+        log::warn!("failed to record overclock history: {e}");
+    }
+
+    Ok(())
+}
+
+/// Apply `profile` to `gpu_index` with `apply` and record it against that GPU
+fn apply_and_record(
+    profile: &OverclockProfile,
+    gpu_index: u32,
+    history: &mut OverclockHistory,
+    apply: impl FnOnce(&OverclockProfile, u32) -> NvResult<()>,
+) -> NvResult<()> {
+    apply(profile, gpu_index)?;
+    history.record(profile, gpu_index);
+    Ok(())
+}
+
+/// Apply a clock offset preset scaled to `gpu_name` (see
+/// [`crate::gpu_profiles::preset_offsets`]). The power limit is left alone.
+pub fn apply_oc_preset(
+    preset: crate::gpu_profiles::OcPreset,
+    gpu_index: u32,
+    gpu_name: &str,
+) -> NvResult<crate::gpu_profiles::PresetOffsets> {
+    let offsets = crate::gpu_profiles::preset_offsets(gpu_name, preset);
+    let profile = OverclockProfile {
+        name: format!("{} preset", preset.label()),
+        gpu_clock_offset: offsets.gpu_offset,
+        memory_clock_offset: offsets.memory_offset,
+        ..Default::default()
+    };
+    apply_overclock_profile_for_gpu(&profile, gpu_index)?;
+    Ok(offsets)
+}
+
+/// Undo the last applied profile on the GPU it was applied to and restore
+/// that GPU's previous profile. Returns the GPU and the restored profile.
+///
+/// Offsets and power limit are restored exactly. When there is nothing
+/// earlier to go back to, the GPU is returned to stock settings.
+pub fn revert_last() -> NvResult<(u32, OverclockProfile)> {
+    let mut history = OverclockHistory::load();
+    let (gpu_index, target) = history
+        .take_revert_target()
+        .unwrap_or((0, OverclockProfile::default()));
+
+    restore_profile(&target, gpu_index)?;
+    history.save()?;
+
+    Ok((gpu_index, target))
+}
+
+/// Return offsets and power limit to stock on every GPU that is currently
+/// overclocked (GPU 0 when none is), recording stock as their current state
+pub fn reset_to_stock() -> NvResult<OverclockProfile> {
+    let stock = OverclockProfile::default();
+    let mut history = OverclockHistory::load();
+    let mut gpus = history.overclocked_gpus();
+    if gpus.is_empty() {
+        gpus.push(0);
+    }
+
+    for gpu_index in gpus {
+        restore_profile(&stock, gpu_index)?;
+        history.record(&stock, gpu_index);
+    }
+    history.save()?;
+
+    Ok(stock)
+}
 
+/// Apply a profile's offsets and power limit to `gpu_index` again without
+/// adding it to the history, e.g. to restore the last profile after a reboot
+pub fn reapply_profile(profile: &OverclockProfile, gpu_index: u32) -> NvResult<()> {
+    restore_profile(profile, gpu_index)
+}
+
+/// Whether any GPU's last applied profile differs from stock offsets/power
+pub fn is_overclock_active() -> bool {
+    !OverclockHistory::load().overclocked_gpus().is_empty()
+}
+
+fn restore_profile(profile: &OverclockProfile, gpu_index: u32) -> NvResult<()> {
+    log::info!(
+        "Restoring overclock profile: {} (GPU {})",
+        profile.name,
+        gpu_index
+    );
+
+    apply_offsets(profile, gpu_index)?;
+    crate::power::set_power_limit_percent(
+        &crate::nvml_backend::create_real_backend(),
+        gpu_index,
+        profile.power_limit as f32,
+    )?;
+
+    Ok(())
+}
+
+/// Set `profile`'s clock offsets on one GPU without touching the history
+pub(crate) fn apply_offsets(profile: &OverclockProfile, gpu_index: u32) -> NvResult<()> {
     // Try nvidia-settings first (X11)
     if std::env::var("DISPLAY").is_ok() {
-        apply_overclock_x11(profile)?;
+        apply_overclock_x11(profile, gpu_index)?;
     } else {
         // Try direct sysfs manipulation for Wayland
         apply_overclock_sysfs(profile)?;
@@ -142,19 +617,19 @@ pub fn apply_overclock_profile(profile: &OverclockProfile) -> NvResult<()> {
     Ok(())
 }
 
-fn apply_overclock_x11(profile: &OverclockProfile) -> NvResult<()> {
+fn apply_overclock_x11(profile: &OverclockProfile, gpu_index: u32) -> NvResult<()> {
     let commands = vec![
         format!(
-            "nvidia-settings -a '[gpu:0]/GPUGraphicsClockOffset[3]={}'",
+            "nvidia-settings -a '[gpu:{gpu_index}]/GPUGraphicsClockOffset[3]={}'",
             profile.gpu_clock_offset
         ),
         format!(
-            "nvidia-settings -a '[gpu:0]/GPUMemoryTransferRateOffset[3]={}'",
+            "nvidia-settings -a '[gpu:{gpu_index}]/GPUMemoryTransferRateOffset[3]={}'",
             profile.memory_clock_offset
         ),
-        format!("nvidia-settings -a '[gpu:0]/GPUPowerMizerMode=1'"), // Performance mode
+        format!("nvidia-settings -a '[gpu:{gpu_index}]/GPUPowerMizerMode=1'"), // Performance mode
         format!(
-            "nvidia-settings -a '[gpu:0]/GPUTargetFanSpeed={}'",
+            "nvidia-settings -a '[gpu:{gpu_index}]/GPUTargetFanSpeed={}'",
             profile
                 .fan_curve
                 .last()
@@ -164,10 +639,7 @@ fn apply_overclock_x11(profile: &OverclockProfile) -> NvResult<()> {
     ];
 
     for cmd in commands {
-        let output = std::process::Command::new("sh")
-            .arg("-c")
-            .arg(&cmd)
-            .output()
+        let output = crate::gpu_safe::execute(std::process::Command::new("sh").arg("-c").arg(&cmd))
             .map_err(|e| {
                 NvControlError::DisplayDetectionFailed(format!(
                     "Failed to execute nvidia-settings: {e}"
@@ -176,7 +648,7 @@ fn apply_overclock_x11(profile: &OverclockProfile) -> NvResult<()> {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            eprintln!("Warning: nvidia-settings command failed: {stderr}");
+            log::warn!("nvidia-settings command failed: {}", stderr.trim());
         }
     }
 
@@ -193,56 +665,456 @@ fn apply_overclock_sysfs(profile: &OverclockProfile) -> NvResult<()> {
 
     // Note: This is a placeholder - actual implementation would need
     // proper detection of GPU vendor and appropriate sysfs paths
-    println!("Wayland overclock via sysfs not yet implemented");
-    println!(
+    log::warn!("Wayland overclock via sysfs not yet implemented");
+    log::info!(
         "Would apply: GPU +{}MHz, Memory +{}MHz, Power {}%",
-        profile.gpu_clock_offset, profile.memory_clock_offset, profile.power_limit
+        profile.gpu_clock_offset,
+        profile.memory_clock_offset,
+        profile.power_limit
     );
 
     Ok(())
 }
 
-pub fn create_stress_test(duration_minutes: u32) -> NvResult<()> {
-    println!("Starting GPU stress test for {} minutes", duration_minutes);
+/// Core offset increment used by `apply_staged` when the caller has no preference
+pub const DEFAULT_STAGED_STEP_MHZ: u32 = 25;
+/// Stability probe length per staged step when the caller has no preference
+pub const DEFAULT_PROBE_DURATION: Duration = Duration::from_secs(10);
 
-    // Pre-format the duration string to avoid borrowing issues
-    let duration_arg = format!("--time={}", duration_minutes * 60);
+/// Core temperature at which the stability probe gives up on a step
+const PROBE_TEMP_LIMIT_C: u32 = 90;
 
-    // Try different stress testing tools
-    let stress_tools = vec![
-        ("glxgears", vec!["-fullscreen"]),
-        ("glmark2", vec!["--fullscreen"]),
-        ("vkmark", vec!["--fullscreen"]),
+/// Offsets visited when ramping to `target` in `step` MHz increments; the
+/// last entry is always `target` itself
+pub fn staged_offsets(target: i32, step: u32) -> Vec<i32> {
+    let step = step.max(1) as i32;
+    let mut offsets = Vec::new();
+    let mut current = 0;
+    while current != target {
+        current = if target > current {
+            (current + step).min(target)
+        } else {
+            (current - step).max(target)
+        };
+        offsets.push(current);
+    }
+    offsets
+}
+
+/// Verdict of a single stability probe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProbeOutcome {
+    Stable,
+    Unstable(String),
+    /// No GPU load could be started, so stability could not be checked
+    Unverified(String),
+    Cancelled,
+}
+
+/// Short stability check run between staged steps.
+///
+/// Much lighter than `create_stress_test`: a GPU load from
+/// `spawn_stress_load` runs for `duration` while the GPU is polled. The step
+/// fails if the driver stops answering, the core runs past 90°C, the load
+/// crashes or a new Xid error appears in the kernel log. Without a load tool
+/// installed the step is `Unverified`, never `Stable`.
+pub fn stability_probe(
+    backend: &dyn crate::nvml_backend::NvmlBackend,
+    gpu_index: u32,
+    duration: Duration,
+    cancel: &AtomicBool,
+) -> NvResult<ProbeOutcome> {
+    let xid_before = xid_error_count();
+    let mut load = match spawn_stress_load(duration) {
+        Ok((_, child)) => child,
+        Err(e) => return Ok(ProbeOutcome::Unverified(e.to_string())),
+    };
+
+    let started = Instant::now();
+    let outcome = loop {
+        if cancel.load(Ordering::SeqCst) {
+            break ProbeOutcome::Cancelled;
+        }
+        match backend.get_temperature(gpu_index) {
+            Ok(temp) if temp >= PROBE_TEMP_LIMIT_C => {
+                break ProbeOutcome::Unstable(format!("core reached {temp}°C"));
+            }
+            Ok(_) => {}
+            Err(e) => break ProbeOutcome::Unstable(format!("GPU stopped responding: {e}")),
+        }
+        if let Ok(Some(status)) = load.try_wait()
+            && !status.success()
+        {
+            break ProbeOutcome::Unstable(format!("load generator exited with {status}"));
+        }
+        if started.elapsed() >= duration {
+            break ProbeOutcome::Stable;
+        }
+        std::thread::sleep(Duration::from_millis(250));
+    };
+
+    let _ = load.kill();
+    let _ = load.wait();
+
+    if outcome == ProbeOutcome::Stable
+        && let (Some(before), Some(after)) = (xid_before, xid_error_count())
+        && after > before
+    {
+        return Ok(ProbeOutcome::Unstable(format!(
+            "{} new Xid error(s) in the kernel log",
+            after - before
+        )));
+    }
+    Ok(outcome)
+}
+
+/// Number of NVRM Xid lines in the kernel log, or `None` if it can't be read
+pub(crate) fn xid_error_count() -> Option<usize> {
+    let output = std::process::Command::new("dmesg").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| line.contains("NVRM: Xid"))
+            .count(),
+    )
+}
+
+/// A staged step as it is reported to the caller
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StagedStep {
+    Applying { offset_mhz: i32 },
+    Stable { offset_mhz: i32 },
+    Unstable { offset_mhz: i32, reason: String },
+}
+
+/// Outcome of `apply_staged`
+#[derive(Debug, Clone)]
+pub struct StagedApplyResult {
+    pub target_offset: i32,
+    /// Core offset left applied: the last step that passed its probe
+    pub achieved_offset: i32,
+    pub steps: Vec<StagedStep>,
+    pub cancelled: bool,
+    /// Why the ramp stopped short of the target, if it did
+    pub stop_reason: Option<String>,
+}
+
+impl StagedApplyResult {
+    pub fn reached_target(&self) -> bool {
+        self.achieved_offset == self.target_offset
+    }
+}
+
+/// Walk the offsets, probing after each one, and fall back to the last
+/// stable offset when a probe fails or the ramp is cancelled
+fn run_staged<A, P>(
+    target: i32,
+    step: u32,
+    mut apply: A,
+    mut probe: P,
+    on_step: &mut dyn FnMut(&StagedStep),
+) -> NvResult<StagedApplyResult>
+where
+    A: FnMut(i32) -> NvResult<()>,
+    P: FnMut(i32) -> NvResult<ProbeOutcome>,
+{
+    let mut result = StagedApplyResult {
+        target_offset: target,
+        achieved_offset: 0,
+        steps: Vec::new(),
+        cancelled: false,
+        stop_reason: None,
+    };
+    let mut report = |result: &mut StagedApplyResult, step: StagedStep| {
+        on_step(&step);
+        result.steps.push(step);
+    };
+
+    for offset in staged_offsets(target, step) {
+        report(&mut result, StagedStep::Applying { offset_mhz: offset });
+        apply(offset)?;
+
+        match probe(offset)? {
+            ProbeOutcome::Stable => {
+                result.achieved_offset = offset;
+                report(&mut result, StagedStep::Stable { offset_mhz: offset });
+            }
+            ProbeOutcome::Unstable(reason) => {
+                report(
+                    &mut result,
+                    StagedStep::Unstable {
+                        offset_mhz: offset,
+                        reason: reason.clone(),
+                    },
+                );
+                result.stop_reason = Some(reason);
+                break;
+            }
+            ProbeOutcome::Unverified(reason) => {
+                result.stop_reason = Some(format!("stability not verified: {reason}"));
+                break;
+            }
+            ProbeOutcome::Cancelled => {
+                result.cancelled = true;
+                result.stop_reason = Some("cancelled".to_string());
+                break;
+            }
+        }
+    }
+
+    if !result.reached_target() {
+        apply(result.achieved_offset)?;
+    }
+    Ok(result)
+}
+
+/// Apply `profile` with its core offset ramped up in `step` MHz increments,
+/// running `stability_probe` for `probe_duration` after each step.
+///
+/// The power limit is applied to `gpu_index` before the first step and the
+/// memory offset with it. If a probe fails, can't run a GPU load or `cancel`
+/// is raised the core offset is put back to the last stable step, and that is
+/// what gets recorded in the overclock history.
+pub fn apply_staged(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+    gpu_index: u32,
+    profile: &OverclockProfile,
+    step: u32,
+    probe_duration: Duration,
+    cancel: &AtomicBool,
+    mut on_step: impl FnMut(&StagedStep),
+) -> NvResult<StagedApplyResult> {
+    if step == 0 {
+        return Err(NvControlError::ConfigError(
+            "Staged apply step must be at least 1 MHz".to_string(),
+        ));
+    }
+
+    log::info!(
+        "Applying overclock profile: {} (staged, {step} MHz steps)",
+        profile.name
+    );
+    crate::power::set_power_limit_percent(backend, gpu_index, profile.power_limit as f32)?;
+
+    let mut staged = profile.clone();
+    let result = run_staged(
+        profile.gpu_clock_offset,
+        step,
+        |offset| {
+            staged.gpu_clock_offset = offset;
+            apply_offsets(&staged, gpu_index)
+        },
+        |_| stability_probe(backend.as_ref(), gpu_index, probe_duration, cancel),
+        &mut on_step,
+    )?;
+
+    let mut history = OverclockHistory::load();
+    history.record(
+        &OverclockProfile {
+            gpu_clock_offset: result.achieved_offset,
+            ..profile.clone()
+        },
+        gpu_index,
+    );
+    if let Err(e) = history.save() {
+        log::warn!("failed to record overclock history: {e}");
+    }
+
+    Ok(result)
+}
+
+/// GPU state sampled once a second during a stress test
+#[derive(Debug, Clone, Copy)]
+pub struct StressSample {
+    pub temperature_c: u32,
+    pub gpu_clock_mhz: u32,
+    pub utilization: u32,
+}
+
+/// Verdict of `create_stress_test`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StressResult {
+    pub passed: bool,
+    pub duration_secs: u64,
+    pub max_temp: u32,
+    /// Core clock fell well below its loaded peak while still under load
+    pub throttled: bool,
+    /// New NVRM Xid errors logged during the run
+    pub xid_errors: usize,
+    /// The load generator died or the GPU stopped answering
+    pub crashed: bool,
+    pub failure_reason: Option<String>,
+    pub finished_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Utilization at which a sample counts as "under sustained load"
+const STRESS_LOAD_UTILIZATION: u32 = 90;
+/// A loaded clock this far below the loaded peak counts as throttling
+const STRESS_THROTTLE_DROP_PERCENT: u32 = 10;
+
+impl StressResult {
+    /// Judge a run from its samples, the Xid delta and any crash reason
+    pub fn evaluate(samples: &[StressSample], xid_errors: usize, crash: Option<String>) -> Self {
+        let max_temp = samples.iter().map(|s| s.temperature_c).max().unwrap_or(0);
+
+        let loaded: Vec<&StressSample> = samples
+            .iter()
+            .filter(|s| s.utilization >= STRESS_LOAD_UTILIZATION)
+            .collect();
+        let peak_clock = loaded.iter().map(|s| s.gpu_clock_mhz).max().unwrap_or(0);
+        let floor = peak_clock * (100 - STRESS_THROTTLE_DROP_PERCENT) / 100;
+        let throttled = loaded.iter().any(|s| s.gpu_clock_mhz < floor);
+
+        let crashed = crash.is_some();
+        let failure_reason = crash
+            .or_else(|| (xid_errors > 0).then(|| format!("{xid_errors} Xid error(s) logged")))
+            .or_else(|| {
+                throttled.then(|| {
+                    format!(
+                        "core clock dropped more than {STRESS_THROTTLE_DROP_PERCENT}% below {peak_clock} MHz under load"
+                    )
+                })
+            });
+
+        Self {
+            passed: failure_reason.is_none(),
+            duration_secs: samples.len() as u64,
+            max_temp,
+            throttled,
+            xid_errors,
+            crashed,
+            failure_reason,
+            finished_at: chrono::Utc::now(),
+        }
+    }
+
+    /// Last stress test result, if one was run
+    pub fn load_last() -> Option<Self> {
+        fs::read_to_string(Self::file_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+    }
+
+    pub fn save(&self) -> NvResult<()> {
+        let path = Self::file_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to serialize stress result: {e}"))
+        })?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn file_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol")
+            .join("last_stress_result.json")
+    }
+}
+
+/// Run a GPU load for `duration_minutes` and decide whether the current
+/// clocks survived it: no crash, no new Xid errors, no throttling.
+pub fn create_stress_test(duration_minutes: u32) -> NvResult<StressResult> {
+    let backend = crate::nvml_backend::create_real_backend();
+    run_stress_test(
+        backend.as_ref(),
+        0,
+        Duration::from_secs(duration_minutes as u64 * 60),
+        &AtomicBool::new(false),
+    )
+}
+
+/// Start the first installed GPU load generator. The loads keep running
+/// until killed, so an early exit means a crash.
+pub(crate) fn spawn_stress_load(
+    duration: Duration,
+) -> NvResult<(&'static str, std::process::Child)> {
+    let duration_arg = format!("--time={}", duration.as_secs());
+    let stress_tools = [
+        ("glmark2", vec!["--off-screen", "--run-forever"]),
+        ("vkmark", vec!["--run-forever"]),
         ("furmark", vec!["--fullscreen", &duration_arg]),
+        ("glxgears", vec!["-fullscreen"]),
     ];
 
     for (tool, args) in stress_tools {
-        if std::process::Command::new(tool)
-            .arg("--help")
-            .output()
-            .is_ok()
+        match std::process::Command::new(tool)
+            .args(args)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
         {
-            println!("Using {} for stress testing", tool);
-            let mut cmd = std::process::Command::new(tool);
-            cmd.args(args);
-
-            match cmd.spawn() {
-                Ok(mut child) => {
-                    println!("Stress test started. Monitor temperatures!");
-                    // Don't wait for completion in this example
-                    let _ = child.wait();
-                    return Ok(());
-                }
-                Err(e) => eprintln!("Failed to start {}: {}", tool, e),
-            }
+            Ok(child) => return Ok((tool, child)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => eprintln!("Failed to start {}: {}", tool, e),
         }
     }
-
     Err(NvControlError::DisplayDetectionFailed(
         "No stress testing tools found. Install glmark2, vkmark, or furmark".to_string(),
     ))
 }
 
+/// Stress test with an explicit backend, GPU and cancel flag. A cancelled
+/// run is judged on what was sampled so far.
+pub fn run_stress_test(
+    backend: &dyn crate::nvml_backend::NvmlBackend,
+    gpu_index: u32,
+    duration: Duration,
+    cancel: &AtomicBool,
+) -> NvResult<StressResult> {
+    let (tool, mut child) = spawn_stress_load(duration)?;
+    println!("Using {} for stress testing", tool);
+
+    let xid_before = xid_error_count();
+    let started = Instant::now();
+    let mut samples = Vec::new();
+    let mut crash = None;
+
+    while started.elapsed() < duration && !cancel.load(Ordering::SeqCst) {
+        std::thread::sleep(Duration::from_secs(1));
+
+        if let Ok(Some(status)) = child.try_wait() {
+            crash = Some(format!("{tool} exited early ({status})"));
+            break;
+        }
+        let sample = backend
+            .get_temperature(gpu_index)
+            .and_then(|temperature_c| {
+                Ok(StressSample {
+                    temperature_c,
+                    gpu_clock_mhz: backend.get_gpu_clock(gpu_index)?,
+                    utilization: backend.get_utilization(gpu_index)?.0,
+                })
+            });
+        match sample {
+            Ok(sample) => samples.push(sample),
+            Err(e) => {
+                crash = Some(format!("GPU stopped responding: {e}"));
+                break;
+            }
+        }
+    }
+
+    let _ = child.kill();
+    let _ = child.wait();
+
+    let xid_errors = match (xid_before, xid_error_count()) {
+        (Some(before), Some(after)) => after.saturating_sub(before),
+        _ => 0,
+    };
+    let result = StressResult::evaluate(&samples, xid_errors, crash);
+    if let Err(e) = result.save() {
+        eprintln!("Warning: failed to save stress result: {e}");
+    }
+    Ok(result)
+}
+
 pub fn get_memory_timings() -> NvResult<HashMap<String, String>> {
     let mut timings = HashMap::new();
 
@@ -289,6 +1161,233 @@ mod tests {
         assert_eq!(profile.name, "Default");
         assert_eq!(profile.gpu_clock_offset, 0);
         assert_eq!(profile.power_limit, 100);
+        assert!(!profile.is_overclocked());
+    }
+
+    #[test]
+    fn test_history_revert_order() {
+        let mut history = OverclockHistory::default();
+        let stock = OverclockProfile::default();
+        let aggressive = OverclockProfile {
+            name: "Aggressive".to_string(),
+            gpu_clock_offset: 200,
+            memory_clock_offset: 1000,
+            power_limit: 115,
+            ..OverclockProfile::default()
+        };
+
+        history.record(&stock, 0);
+        history.record(&aggressive, 0);
+        assert_eq!(history.len(), 2);
+
+        let popped = history.pop().unwrap();
+        assert_eq!(popped.profile.name, "Aggressive");
+        let previous = &history.last().unwrap().profile;
+        assert_eq!(previous.gpu_clock_offset, 0);
+        assert_eq!(previous.power_limit, 100);
+    }
+
+    #[test]
+    fn test_history_revert_targets_the_applied_gpu() {
+        let mut history = OverclockHistory::default();
+        let mild = OverclockProfile {
+            gpu_clock_offset: 50,
+            ..OverclockProfile::default()
+        };
+        let aggressive = OverclockProfile {
+            gpu_clock_offset: 200,
+            ..OverclockProfile::default()
+        };
+
+        history.record(&mild, 0);
+        history.record(&mild, 1);
+        history.record(&aggressive, 1);
+        assert_eq!(history.overclocked_gpus(), vec![0, 1]);
+
+        // Undoing GPU 1's overclock goes back to GPU 1's previous profile
+        let (gpu, target) = history.take_revert_target().unwrap();
+        assert_eq!(gpu, 1);
+        assert_eq!(target.gpu_clock_offset, 50);
+
+        // With no earlier entry for that GPU, revert falls back to stock
+        let (gpu, target) = history.take_revert_target().unwrap();
+        assert_eq!(gpu, 1);
+        assert!(!target.is_overclocked());
+        assert_eq!(
+            history.last_for_gpu(0).unwrap().profile.gpu_clock_offset,
+            50
+        );
+    }
+
+    #[test]
+    fn test_apply_targets_selected_gpu() {
+        use crate::nvml_backend::{MockNvmlBackend, NvmlBackend};
+
+        let backend = MockNvmlBackend::multi_gpu(2);
+        let selected_gpu = 1;
+        let profile = OverclockProfile {
+            name: "TUI Applied".to_string(),
+            gpu_clock_offset: 150,
+            memory_clock_offset: 500,
+            ..OverclockProfile::default()
+        };
+
+        let mut history = OverclockHistory::default();
+        let mut written = Vec::new();
+        apply_and_record(&profile, selected_gpu, &mut history, |p, gpu| {
+            backend.get_name(gpu)?;
+            written.push((gpu, p.gpu_clock_offset, p.memory_clock_offset));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(written, vec![(1, 150, 500)]);
+        assert!(history.last_for_gpu(0).is_none());
+        assert_eq!(history.overclocked_gpus(), vec![1]);
+
+        // Reverting goes back to stock on the GPU that was overclocked
+        let (gpu, target) = history.take_revert_target().unwrap();
+        assert_eq!(gpu, selected_gpu);
+        assert!(!target.is_overclocked());
+
+        // A GPU the backend doesn't have is never recorded
+        let missing = apply_and_record(&profile, 5, &mut history, |_, gpu| {
+            backend.get_name(gpu).map(|_| ())
+        });
+        assert!(missing.is_err());
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_history_capacity() {
+        let mut history = OverclockHistory::default();
+        for offset in 0..(OVERCLOCK_HISTORY_CAPACITY as i32 + 4) {
+            history.record(
+                &OverclockProfile {
+                    gpu_clock_offset: offset,
+                    ..OverclockProfile::default()
+                },
+                0,
+            );
+        }
+        assert_eq!(history.len(), OVERCLOCK_HISTORY_CAPACITY);
+        assert_eq!(
+            history.entries().next().unwrap().profile.gpu_clock_offset,
+            4
+        );
+    }
+
+    #[test]
+    fn test_staged_offsets() {
+        assert_eq!(staged_offsets(100, 25), vec![25, 50, 75, 100]);
+        assert_eq!(staged_offsets(60, 25), vec![25, 50, 60]);
+        assert_eq!(staged_offsets(-30, 25), vec![-25, -30]);
+        assert!(staged_offsets(0, 25).is_empty());
+    }
+
+    #[test]
+    fn test_staged_stops_at_last_stable_step() {
+        let mut applied = Vec::new();
+        let result = run_staged(
+            100,
+            25,
+            |offset| {
+                applied.push(offset);
+                Ok(())
+            },
+            |offset| {
+                Ok(if offset > 50 {
+                    ProbeOutcome::Unstable("Xid 79".to_string())
+                } else {
+                    ProbeOutcome::Stable
+                })
+            },
+            &mut |_| {},
+        )
+        .unwrap();
+
+        assert_eq!(result.achieved_offset, 50);
+        assert!(!result.reached_target());
+        assert_eq!(result.stop_reason.as_deref(), Some("Xid 79"));
+        // Ramp 25, 50, 75 then roll back to 50
+        assert_eq!(applied, vec![25, 50, 75, 50]);
+    }
+
+    #[test]
+    fn test_staged_cancel_rolls_back() {
+        let mut applied = Vec::new();
+        let result = run_staged(
+            50,
+            25,
+            |offset| {
+                applied.push(offset);
+                Ok(())
+            },
+            |_| Ok(ProbeOutcome::Cancelled),
+            &mut |_| {},
+        )
+        .unwrap();
+
+        assert!(result.cancelled);
+        assert_eq!(result.achieved_offset, 0);
+        assert_eq!(applied, vec![25, 0]);
+    }
+
+    #[test]
+    fn test_staged_unverified_step_is_not_kept() {
+        let mut applied = Vec::new();
+        let result = run_staged(
+            50,
+            25,
+            |offset| {
+                applied.push(offset);
+                Ok(())
+            },
+            |_| Ok(ProbeOutcome::Unverified("no load tool".to_string())),
+            &mut |_| {},
+        )
+        .unwrap();
+
+        assert!(!result.cancelled);
+        assert_eq!(result.achieved_offset, 0);
+        assert!(result.stop_reason.unwrap().contains("not verified"));
+        assert_eq!(applied, vec![25, 0]);
+    }
+
+    #[test]
+    fn test_stress_result_evaluation() {
+        let sample = |temperature_c, gpu_clock_mhz, utilization| StressSample {
+            temperature_c,
+            gpu_clock_mhz,
+            utilization,
+        };
+
+        // Clock sags at idle do not count, only under load
+        let steady = [
+            sample(60, 2500, 99),
+            sample(72, 2490, 98),
+            sample(40, 300, 5),
+        ];
+        let result = StressResult::evaluate(&steady, 0, None);
+        assert!(result.passed);
+        assert_eq!(result.max_temp, 72);
+        assert!(!result.throttled);
+
+        let throttling = [sample(80, 2500, 99), sample(88, 2100, 99)];
+        let result = StressResult::evaluate(&throttling, 0, None);
+        assert!(!result.passed);
+        assert!(result.throttled);
+
+        let result = StressResult::evaluate(&steady, 2, None);
+        assert!(!result.passed);
+        assert_eq!(result.xid_errors, 2);
+
+        let result = StressResult::evaluate(&steady, 0, Some("glmark2 exited early".into()));
+        assert!(result.crashed);
+        assert_eq!(
+            result.failure_reason.as_deref(),
+            Some("glmark2 exited early")
+        );
     }
 
     #[test]
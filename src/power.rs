@@ -1,7 +1,9 @@
-use crate::{NvControlError, NvResult};
+use crate::nvml_backend::SharedNvmlBackend;
+use crate::{NvControlError, NvResult, gpu_safe};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -182,12 +184,11 @@ pub fn set_power_limit_percentage(percentage: u32) -> NvResult<()> {
     let percentage = percentage.min(100);
 
     // Use nvidia-ml-py approach via nvidia-smi
-    let output = Command::new("nvidia-smi")
-        .args(&["-pl", &format!("{}%", percentage)])
-        .output()
-        .map_err(|e| {
-            NvControlError::PowerManagementFailed(format!("Failed to set power limit: {}", e))
-        })?;
+    let output =
+        gpu_safe::execute(Command::new("nvidia-smi").args(["-pl", &format!("{}%", percentage)]))
+            .map_err(|e| {
+                NvControlError::PowerManagementFailed(format!("Failed to set power limit: {}", e))
+            })?;
 
     if !output.status.success() {
         // Try alternative approach with direct sysfs on Wayland
@@ -197,6 +198,334 @@ pub fn set_power_limit_percentage(percentage: u32) -> NvResult<()> {
     Ok(())
 }
 
+/// A power limit as typed by the user: `320W` or `90%`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PowerLimitTarget {
+    Watts(f32),
+    /// Percent of the board's default limit, the way vendor tools present it
+    Percent(f32),
+}
+
+impl std::str::FromStr for PowerLimitTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (number, target): (&str, fn(f32) -> Self) = if let Some(n) = s.strip_suffix('%') {
+            (n, Self::Percent)
+        } else if let Some(n) = s.strip_suffix(['W', 'w']) {
+            (n, Self::Watts)
+        } else {
+            return Err(format!(
+                "'{}' needs a unit: watts like 320W or a percentage like 90%",
+                s
+            ));
+        };
+        match number.trim().parse::<f32>() {
+            Ok(value) if value.is_finite() && value > 0.0 => Ok(target(value)),
+            _ => Err(format!("'{}' is not a valid power limit", s)),
+        }
+    }
+}
+
+/// Power limit bounds reported by NVML, in watts
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PowerLimitRange {
+    pub min_w: f32,
+    pub max_w: f32,
+    pub default_w: f32,
+    pub current_w: f32,
+}
+
+impl PowerLimitRange {
+    /// Query the limits of one GPU
+    pub fn query(backend: &SharedNvmlBackend, gpu_index: u32) -> NvResult<Self> {
+        let (min_mw, max_mw) = backend.get_power_limit_constraints(gpu_index)?;
+        Ok(Self {
+            min_w: min_mw as f32 / 1000.0,
+            max_w: max_mw as f32 / 1000.0,
+            default_w: backend.get_power_limit_default(gpu_index)? as f32 / 1000.0,
+            current_w: backend.get_power_limit(gpu_index)? as f32 / 1000.0,
+        })
+    }
+
+    /// Express a wattage as a percentage of the default limit
+    pub fn percent_of_default(&self, watts: f32) -> f32 {
+        if self.default_w > 0.0 {
+            watts / self.default_w * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Outcome of a power limit change
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AppliedPowerLimit {
+    pub requested_w: f32,
+    /// The requested value clamped into the allowed range
+    pub applied_w: f32,
+    pub range: PowerLimitRange,
+}
+
+impl AppliedPowerLimit {
+    pub fn was_clamped(&self) -> bool {
+        (self.requested_w - self.applied_w).abs() >= 0.5
+    }
+}
+
+/// Total board power in watts: chip, memory, VRMs and fans. This is what
+/// to size a PSU against.
+pub fn get_board_power(backend: &SharedNvmlBackend, gpu_index: u32) -> NvResult<f32> {
+    Ok(backend.get_power_usage(gpu_index)? as f32 / 1000.0)
+}
+
+/// Power drawn by the GPU chip alone in watts. Fails with
+/// `UnsupportedFeature` on cards that only report board power.
+pub fn get_chip_power(backend: &SharedNvmlBackend, gpu_index: u32) -> NvResult<f32> {
+    Ok(backend.get_chip_power_usage(gpu_index)? as f32 / 1000.0)
+}
+
+/// Shown in place of chip power on cards that report a single value
+pub const BOARD_POWER_ONLY_NOTE: &str = "This card reports board power only";
+
+/// Set one GPU's power limit in watts, clamped to the range NVML allows
+pub fn set_power_limit_watts(
+    backend: &SharedNvmlBackend,
+    gpu_index: u32,
+    watts: f32,
+) -> NvResult<AppliedPowerLimit> {
+    let range = PowerLimitRange::query(backend, gpu_index)?;
+    let applied_w = watts.clamp(range.min_w, range.max_w);
+
+    if gpu_safe::should_apply(&format!(
+        "set GPU {} power limit to {:.0} W",
+        gpu_index, applied_w
+    )) {
+        backend.set_power_limit(gpu_index, (applied_w * 1000.0).round() as u32)?;
+    }
+
+    Ok(AppliedPowerLimit {
+        requested_w: watts,
+        applied_w,
+        range,
+    })
+}
+
+/// Set one GPU's power limit as a percentage of its default limit
+pub fn set_power_limit_percent(
+    backend: &SharedNvmlBackend,
+    gpu_index: u32,
+    percent: f32,
+) -> NvResult<AppliedPowerLimit> {
+    let default_mw = backend.get_power_limit_default(gpu_index)?;
+    set_power_limit_watts(
+        backend,
+        gpu_index,
+        default_mw as f32 / 1000.0 * percent / 100.0,
+    )
+}
+
+/// Settings quiet mode replaced, kept on disk so `nvctl quiet off` can
+/// restore them from any process
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuietModeState {
+    pub enabled_at: chrono::DateTime<chrono::Utc>,
+    /// Power limit in watts per GPU before quiet mode
+    pub power_limits_w: BTreeMap<u32, f32>,
+    /// Fan curves the daemon was applying before quiet mode
+    pub fan_curves: crate::fan_daemon::ActiveFanCurves,
+    /// GPUs whose clocks quiet mode locked, with the lock they held before
+    #[serde(default)]
+    pub clock_locks: BTreeMap<u32, Option<crate::overclocking::LockedClocks>>,
+}
+
+impl QuietModeState {
+    fn path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol")
+            .join("quiet_mode.json")
+    }
+
+    /// State of the active quiet mode, if it is on
+    pub fn load() -> Option<Self> {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+    }
+
+    fn save(&self) -> NvResult<()> {
+        if gpu_safe::is_dry_run() {
+            return Ok(());
+        }
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to serialize quiet mode state: {e}"))
+        })?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Whether quiet mode is currently on
+pub fn is_quiet_mode() -> bool {
+    QuietModeState::path().exists()
+}
+
+/// Drop the quiet mode record without restoring anything, e.g. after
+/// `nvctl reset` already put everything back to stock
+pub fn forget_quiet_mode() {
+    if !gpu_safe::is_dry_run() {
+        let _ = fs::remove_file(QuietModeState::path());
+    }
+}
+
+/// Fan curve that never exceeds `max_pct`
+pub fn quiet_fan_curve(max_pct: u8) -> crate::fan::FanCurve {
+    let max = max_pct.min(100);
+    crate::fan_daemon::curve_from_points(
+        "Quiet",
+        &[(40, max / 2), (60, max / 2 + max / 4), (75, max)],
+    )
+}
+
+/// Quiet power limit: `percent` of the default, but never above the current
+/// limit, so a GPU already running lower stays there
+pub fn quiet_power_limit_w(range: &PowerLimitRange, percent: u32) -> f32 {
+    (range.default_w * percent as f32 / 100.0).min(range.current_w)
+}
+
+/// Turn quiet mode on or off for every GPU.
+///
+/// Enabling caps the power limit, replaces the active fan curves with one
+/// topping out at `quiet_mode.fan_max_pct` and, if configured, locks the
+/// core clock lower. The previous limits, curves and locks are saved first
+/// and put back when disabling. Steps that fail don't stop the others; they
+/// are returned as warnings.
+pub fn set_quiet_mode(backend: &SharedNvmlBackend, enabled: bool) -> NvResult<Vec<String>> {
+    if enabled {
+        enable_quiet_mode(backend)
+    } else {
+        disable_quiet_mode(backend)
+    }
+}
+
+fn enable_quiet_mode(backend: &SharedNvmlBackend) -> NvResult<Vec<String>> {
+    if is_quiet_mode() {
+        return Ok(Vec::new());
+    }
+    let config = crate::config::Config::load().quiet_mode;
+    let gpu_count = backend.device_count()?;
+    let curves_path = crate::fan_daemon::ActiveFanCurves::default_path();
+
+    let mut state = QuietModeState {
+        enabled_at: chrono::Utc::now(),
+        power_limits_w: BTreeMap::new(),
+        fan_curves: crate::fan_daemon::ActiveFanCurves::load(&curves_path)?,
+        clock_locks: BTreeMap::new(),
+    };
+    let mut ranges = BTreeMap::new();
+    for gpu in 0..gpu_count {
+        if let Ok(range) = PowerLimitRange::query(backend, gpu) {
+            state.power_limits_w.insert(gpu, range.current_w);
+            ranges.insert(gpu, range);
+        }
+        if config.max_gpu_clock_mhz.is_some() {
+            state
+                .clock_locks
+                .insert(gpu, crate::overclocking::LockedClocks::load(gpu));
+        }
+    }
+    // Saved before touching anything so a failure midway can still be undone
+    state.save()?;
+
+    let mut warnings = Vec::new();
+    for (gpu, range) in &ranges {
+        let watts = quiet_power_limit_w(range, config.power_limit_pct);
+        if let Err(e) = set_power_limit_watts(backend, *gpu, watts) {
+            warnings.push(format!("GPU {gpu} power limit: {e}"));
+        }
+    }
+
+    if !gpu_safe::is_dry_run() {
+        // Per-fan curves would override the quiet one, so start from none
+        let cleared = crate::fan_daemon::ActiveFanCurves {
+            shared: None,
+            curves: BTreeMap::new(),
+            ..state.fan_curves.clone()
+        };
+        cleared.save(&curves_path)?;
+    }
+    match crate::multi_gpu::apply_fan_curve_all(backend, &quiet_fan_curve(config.fan_max_pct)) {
+        Ok(report) if report.applied() == 0 => {
+            warnings.push("Fans: no GPU accepted the quiet curve".to_string())
+        }
+        Ok(_) => {}
+        Err(e) => warnings.push(format!("Fans: {e}")),
+    }
+
+    if let Some(mhz) = config.max_gpu_clock_mhz {
+        for gpu in state.clock_locks.keys() {
+            if let Err(e) =
+                crate::overclocking::lock_clocks(backend.as_ref(), *gpu, Some(mhz), None)
+            {
+                warnings.push(format!("GPU {gpu} clock lock: {e}"));
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+fn disable_quiet_mode(backend: &SharedNvmlBackend) -> NvResult<Vec<String>> {
+    let Some(state) = QuietModeState::load() else {
+        return Ok(Vec::new());
+    };
+    let mut warnings = Vec::new();
+
+    for (gpu, watts) in &state.power_limits_w {
+        if let Err(e) = set_power_limit_watts(backend, *gpu, *watts) {
+            warnings.push(format!("GPU {gpu} power limit: {e}"));
+        }
+    }
+
+    for (gpu, previous) in &state.clock_locks {
+        let result = match previous {
+            Some(lock) => {
+                crate::overclocking::lock_clocks(backend.as_ref(), *gpu, lock.gpu_mhz, lock.mem_mhz)
+                    .map(|_| ())
+            }
+            None => crate::overclocking::reset_locked_clocks(backend.as_ref(), *gpu),
+        };
+        if let Err(e) = result {
+            warnings.push(format!("GPU {gpu} clocks: {e}"));
+        }
+    }
+
+    // Fans the quiet curve drove but the previous curves don't go back to auto
+    let curves_path = crate::fan_daemon::ActiveFanCurves::default_path();
+    let quiet = crate::fan_daemon::ActiveFanCurves::load(&curves_path)?;
+    let fans = crate::fan_daemon::gpu_fan_ids(backend, state.fan_curves.gpu_index);
+    let restored = state.fan_curves.driven_fans(&fans);
+    for fan_id in quiet.curves.keys() {
+        if !restored.contains_key(fan_id)
+            && let Err(e) = crate::fan::reset_fan_to_auto(*fan_id)
+        {
+            warnings.push(format!("Fan {fan_id}: {e}"));
+        }
+    }
+    if !gpu_safe::is_dry_run() {
+        state.fan_curves.save(&curves_path)?;
+    }
+
+    forget_quiet_mode();
+    Ok(warnings)
+}
+
 /// Set power limit via sysfs (Wayland-friendly)
 fn set_power_limit_sysfs(percentage: u32) -> NvResult<()> {
     let gpu_count = get_gpu_count()?;
@@ -210,10 +539,11 @@ fn set_power_limit_sysfs(percentage: u32) -> NvResult<()> {
                 if let Ok(max_power) = max_power_str.trim().parse::<f32>() {
                     let target_power = (max_power * percentage as f32 / 100.0) as u32;
 
-                    let write_result = Command::new("sudo")
-                        .args(&["tee", &power_limit_path])
-                        .arg(&format!("{}", target_power))
-                        .output();
+                    let write_result = gpu_safe::execute(
+                        Command::new("sudo")
+                            .args(["tee", &power_limit_path])
+                            .arg(format!("{}", target_power)),
+                    );
 
                     if write_result.is_err() {
                         return Err(NvControlError::PowerManagementFailed(
@@ -232,10 +562,8 @@ fn set_power_limit_sysfs(percentage: u32) -> NvResult<()> {
 pub fn set_persistence_mode(enabled: bool) -> NvResult<()> {
     let status = if enabled { "1" } else { "0" };
 
-    let output = Command::new("nvidia-smi")
-        .args(&["-pm", status])
-        .output()
-        .map_err(|e| {
+    let output =
+        gpu_safe::execute(Command::new("nvidia-smi").args(["-pm", status])).map_err(|e| {
             NvControlError::PowerManagementFailed(format!("Failed to set persistence mode: {}", e))
         })?;
 
@@ -258,9 +586,9 @@ fn set_performance_level(level: &str) -> NvResult<()> {
         _ => "1",
     };
 
-    let output = Command::new("nvidia-settings")
-        .args(&["-a", &format!("GPUPowerMizerMode={}", perf_level)])
-        .output();
+    let output = gpu_safe::execute(
+        Command::new("nvidia-settings").args(["-a", &format!("GPUPowerMizerMode={}", perf_level)]),
+    );
 
     if output.is_err() {
         // Fallback: try via sysfs
@@ -287,13 +615,123 @@ fn set_performance_level_sysfs(level: &str) -> NvResult<()> {
                 _ => "auto",
             };
 
-            let _ = Command::new("sudo")
-                .args(&["tee", &perf_path])
-                .arg(perf_value)
-                .output();
+            let _ = gpu_safe::execute(
+                Command::new("sudo")
+                    .args(["tee", &perf_path])
+                    .arg(perf_value),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Driver PowerMizer preference, as nvidia-settings' `GPUPowerMizerMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerMizerMode {
+    /// Clock down when idle (driver default on most desktops)
+    Adaptive,
+    /// Hold high clocks whenever an application is running
+    #[value(name = "max-performance", alias = "prefer-max-performance")]
+    PreferMaxPerformance,
+    /// Let the driver pick between the two
+    Auto,
+}
+
+impl PowerMizerMode {
+    pub const ALL: [Self; 3] = [Self::Adaptive, Self::PreferMaxPerformance, Self::Auto];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Adaptive => "Adaptive",
+            Self::PreferMaxPerformance => "Prefer Maximum Performance",
+            Self::Auto => "Auto",
+        }
+    }
+
+    /// Value of the `GPUPowerMizerMode` attribute
+    pub fn attribute_value(&self) -> u8 {
+        match self {
+            Self::Adaptive => 0,
+            Self::PreferMaxPerformance => 1,
+            Self::Auto => 2,
         }
     }
 
+    pub fn from_attribute_value(value: u8) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.attribute_value() == value)
+    }
+}
+
+/// nvidia-settings talks to the driver through the NV-CONTROL X extension,
+/// which XWayland only provides from driver 590 on
+fn powermizer_wayland_error() -> Option<NvControlError> {
+    let wayland =
+        crate::wayland_nvidia::detect_compositor() != crate::wayland_nvidia::WaylandCompositor::X11;
+    let fixed = crate::drivers::DriverCapabilities::detect()
+        .map(|caps| caps.has_powermizer_wayland_fix)
+        .unwrap_or(false);
+    (wayland && !fixed).then(|| {
+        NvControlError::UnsupportedFeature(
+            "nvidia-settings can't change PowerMizer on Wayland before driver 590. Update the driver, set it from an X11 session, or hold max clocks with `nvctl gpu lock-clocks`".to_string(),
+        )
+    })
+}
+
+/// Current PowerMizer mode of one GPU, read through nvidia-settings
+pub fn get_powermizer_mode(gpu_index: u32) -> NvResult<PowerMizerMode> {
+    let output = Command::new("nvidia-settings")
+        .args([
+            "-t",
+            "-q",
+            &format!("[gpu:{}]/GPUPowerMizerMode", gpu_index),
+        ])
+        .output()
+        .map_err(|e| {
+            NvControlError::PowerManagementFailed(format!("Failed to run nvidia-settings: {}", e))
+        })?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match stdout.trim().parse::<u8>().ok() {
+        Some(value) if output.status.success() => PowerMizerMode::from_attribute_value(value)
+            .ok_or_else(|| {
+                NvControlError::PowerManagementFailed(format!("Unknown PowerMizer mode {}", value))
+            }),
+        _ => Err(powermizer_wayland_error().unwrap_or_else(|| {
+            NvControlError::PowerManagementFailed(
+                "nvidia-settings did not report GPUPowerMizerMode".to_string(),
+            )
+        })),
+    }
+}
+
+/// Set the PowerMizer mode of one GPU through nvidia-settings
+pub fn set_powermizer_mode(gpu_index: u32, mode: PowerMizerMode) -> NvResult<()> {
+    let output = gpu_safe::execute(Command::new("nvidia-settings").args([
+        "-a",
+        &format!(
+            "[gpu:{}]/GPUPowerMizerMode={}",
+            gpu_index,
+            mode.attribute_value()
+        ),
+    ]))
+    .map_err(|e| {
+        NvControlError::PowerManagementFailed(format!("Failed to run nvidia-settings: {}", e))
+    })?;
+
+    // nvidia-settings exits 0 on some assignment failures, so check its output too
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() || stderr.contains("ERROR") {
+        return Err(powermizer_wayland_error().unwrap_or_else(|| {
+            NvControlError::PowerManagementFailed(format!(
+                "nvidia-settings could not set PowerMizer mode: {}",
+                stderr.trim()
+            ))
+        }));
+    }
     Ok(())
 }
 
@@ -306,15 +744,13 @@ pub fn set_gpu_clock_boost(enabled: bool) -> NvResult<()> {
 
     // Try nvidia-settings approach
     if std::env::var("DISPLAY").is_ok() {
-        let output = Command::new("nvidia-settings")
-            .args(&[
-                "-a",
-                &format!(
-                    "GPUGraphicsClockOffset[3]={}",
-                    if enabled { "100" } else { "0" }
-                ),
-            ])
-            .output();
+        let output = gpu_safe::execute(Command::new("nvidia-settings").args(&[
+            "-a",
+            &format!(
+                "GPUGraphicsClockOffset[3]={}",
+                if enabled { "100" } else { "0" }
+            ),
+        ]));
 
         if output.is_ok() {
             return Ok(());
@@ -333,7 +769,7 @@ fn set_boost_via_sysfs(enabled: bool) -> NvResult<()> {
         let boost_path = format!("/sys/class/drm/card{}/device/power_dpm_boost", gpu_id);
         if Path::new(&boost_path).exists() {
             let boost_value = if enabled { "1" } else { "0" };
-            if fs::write(&boost_path, boost_value).is_ok() {
+            if gpu_safe::write_sysfs(&boost_path, boost_value).is_ok() {
                 println!(
                     "✅ GPU {} boost {}",
                     gpu_id,
@@ -382,12 +818,9 @@ pub fn disable_power_throttling() -> NvResult<()> {
             .is_ok_and(|o| o.status.success())
         {
             let result = if cmd == "echo" {
-                Command::new("sudo").arg("sh").arg("-c").arg(args).output()
+                gpu_safe::execute(Command::new("sudo").arg("sh").arg("-c").arg(args))
             } else {
-                Command::new("sudo")
-                    .arg(cmd)
-                    .args(args.split_whitespace())
-                    .output()
+                gpu_safe::execute(Command::new("sudo").arg(cmd).args(args.split_whitespace()))
             };
 
             if result.is_ok_and(|o| o.status.success()) {
@@ -405,11 +838,12 @@ pub fn disable_power_throttling() -> NvResult<()> {
 
     for path in cpu_throttle_paths {
         if Path::new(path).exists()
-            && Command::new("sudo")
-                .args(["tee", path])
-                .arg("0")
-                .output()
-                .is_ok()
+            && gpu_safe::execute(
+                Command::new("sudo")
+                    .args(["sh", "-c"])
+                    .arg(format!("echo 0 > {}", path)),
+            )
+            .is_ok_and(|o| o.status.success())
         {
             success_count += 1;
             println!("  ✓ Disabled CPU throttling at {}", path);
@@ -676,7 +1110,7 @@ fn set_thermal_policy(policy: &str) -> NvResult<()> {
         ];
 
         for path in thermal_paths {
-            if Path::new(&path).exists() && fs::write(&path, policy_value).is_ok() {
+            if Path::new(&path).exists() && gpu_safe::write_sysfs(&path, policy_value).is_ok() {
                 println!("✅ Set thermal policy '{}' for GPU {}", policy, gpu_id);
                 break;
             }
@@ -693,7 +1127,9 @@ fn enable_gpu_power_gating() -> NvResult<()> {
 
     for gpu_id in 0..gpu_count {
         let power_gate_path = format!("/sys/class/drm/card{}/device/power_gate", gpu_id);
-        if Path::new(&power_gate_path).exists() && fs::write(&power_gate_path, "1").is_ok() {
+        if Path::new(&power_gate_path).exists()
+            && gpu_safe::write_sysfs(&power_gate_path, "1").is_ok()
+        {
             println!("✅ Enabled power gating for GPU {}", gpu_id);
         }
     }
@@ -705,13 +1141,11 @@ fn enable_gpu_power_gating() -> NvResult<()> {
 #[allow(dead_code)]
 fn enable_idle_power_gating() -> NvResult<()> {
     // Enable runtime power management
-    let output = Command::new("sudo")
-        .args(&[
-            "sh",
-            "-c",
-            "echo auto > /sys/bus/pci/devices/*/power/control",
-        ])
-        .output();
+    let output = gpu_safe::execute(Command::new("sudo").args([
+        "sh",
+        "-c",
+        "echo auto > /sys/bus/pci/devices/*/power/control",
+    ]));
 
     if output.is_ok() {
         println!("✅ Enabled idle power gating");
@@ -745,7 +1179,7 @@ fn optimize_display_power() -> NvResult<()> {
 
     for (cmd, description) in display_optimizations {
         if std::env::var("DISPLAY").is_ok() {
-            let _ = Command::new("sh").arg("-c").arg(cmd).output();
+            let _ = gpu_safe::execute(Command::new("sh").arg("-c").arg(cmd));
             println!("🔆 {}", description);
         }
     }
@@ -1011,7 +1445,9 @@ fn set_cpu_governor(governor: &str) -> NvResult<bool> {
             "/sys/devices/system/cpu/cpu{}/cpufreq/scaling_governor",
             cpu
         );
-        if Path::new(&governor_path).exists() && fs::write(&governor_path, governor).is_ok() {
+        if Path::new(&governor_path).exists()
+            && gpu_safe::write_sysfs(&governor_path, governor).is_ok()
+        {
             success_count += 1;
         }
     }
@@ -1024,9 +1460,12 @@ fn set_cpu_governor(governor: &str) -> NvResult<bool> {
         Ok(true)
     } else {
         // Try using cpupower as fallback
-        let output = Command::new("sudo")
-            .args(&["cpupower", "frequency-set", "-g", governor])
-            .output();
+        let output = gpu_safe::execute(Command::new("sudo").args([
+            "cpupower",
+            "frequency-set",
+            "-g",
+            governor,
+        ]));
 
         if output.is_ok() {
             println!("✅ Set CPU governor to '{}' via cpupower", governor);
@@ -1190,13 +1629,11 @@ fn get_persistence_mode(gpu_id: usize) -> NvResult<bool> {
 /// Enable CPU idle states for power saving
 fn enable_cpu_idle_states() -> NvResult<()> {
     // Re-enable CPU idle states (C-states) for power saving
-    let result = Command::new("sudo")
-        .args(&[
-            "sh",
-            "-c",
-            "echo 0 > /sys/devices/system/cpu/cpu*/cpuidle/state*/disable",
-        ])
-        .output();
+    let result = gpu_safe::execute(Command::new("sudo").args([
+        "sh",
+        "-c",
+        "echo 0 > /sys/devices/system/cpu/cpu*/cpuidle/state*/disable",
+    ]));
 
     if result.is_ok() {
         println!("✅ CPU idle states enabled for power saving");
@@ -1210,13 +1647,11 @@ fn enable_cpu_idle_states() -> NvResult<()> {
 
 /// Enable runtime power management for PCI devices
 fn enable_runtime_pm() -> NvResult<()> {
-    let result = Command::new("sudo")
-        .args(&[
-            "sh",
-            "-c",
-            "echo auto > /sys/bus/pci/devices/*/power/control",
-        ])
-        .output();
+    let result = gpu_safe::execute(Command::new("sudo").args([
+        "sh",
+        "-c",
+        "echo auto > /sys/bus/pci/devices/*/power/control",
+    ]));
 
     if result.is_ok() {
         println!("✅ Runtime power management enabled");
@@ -1227,3 +1662,35 @@ fn enable_runtime_pm() -> NvResult<()> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiet_mode_caps() {
+        let curve = quiet_fan_curve(45);
+        assert!(curve.points.iter().all(|p| p.duty_cycle <= 45));
+        assert_eq!(crate::fan::curve_speed(&curve, 90, None), 45);
+        assert!(
+            quiet_fan_curve(200)
+                .points
+                .iter()
+                .all(|p| p.duty_cycle <= 100)
+        );
+
+        let range = PowerLimitRange {
+            min_w: 100.0,
+            max_w: 400.0,
+            default_w: 300.0,
+            current_w: 320.0,
+        };
+        assert_eq!(quiet_power_limit_w(&range, 70), 210.0);
+        // Never raises a limit the user already set lower
+        let lowered = PowerLimitRange {
+            current_w: 180.0,
+            ..range
+        };
+        assert_eq!(quiet_power_limit_w(&lowered, 70), 180.0);
+    }
+}
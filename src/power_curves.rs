@@ -2,7 +2,9 @@
 // Dynamic power limiting based on temperature, time, and workload
 
 use crate::gui_widgets::CurvePoint;
+use crate::nvml_backend::SharedNvmlBackend;
 use crate::{NvControlError, NvResult};
+use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -96,17 +98,84 @@ impl PowerCurve {
 pub struct PowerSchedule {
     pub enabled: bool,
     pub schedules: Vec<ScheduleEntry>,
+    /// When the power limit was last set by hand
+    #[serde(default)]
+    pub last_manual_override: Option<DateTime<Utc>>,
+    /// Minutes the schedule leaves a manual power limit alone
+    #[serde(default = "default_override_grace_minutes")]
+    pub override_grace_minutes: u32,
+}
+
+fn default_override_grace_minutes() -> u32 {
+    30
+}
+
+/// Hour range within a day, end exclusive. A range that ends at or before its
+/// start wraps past midnight, so 22..7 covers 22:00-06:59.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start_hour: u8,
+    pub end_hour: u8,
+}
+
+impl TimeRange {
+    pub fn new(start_hour: u8, end_hour: u8) -> Self {
+        Self {
+            start_hour,
+            end_hour,
+        }
+    }
+
+    pub fn wraps_midnight(&self) -> bool {
+        self.end_hour <= self.start_hour
+    }
+
+    pub fn contains(&self, hour: u8) -> bool {
+        if self.wraps_midnight() {
+            hour >= self.start_hour || hour < self.end_hour
+        } else {
+            self.start_hour <= hour && hour < self.end_hour
+        }
+    }
+}
+
+impl std::fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:00-{:02}:00", self.start_hour, self.end_hour)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleEntry {
     pub start_hour: u8,   // 0-23
-    pub end_hour: u8,     // 0-23
+    pub end_hour: u8,     // 0-23, wraps past midnight when <= start_hour
     pub power_limit: u32, // Percentage of TDP
     pub days: Vec<Weekday>,
     pub name: String,
 }
 
+impl ScheduleEntry {
+    pub fn time_range(&self) -> TimeRange {
+        TimeRange::new(self.start_hour, self.end_hour)
+    }
+
+    /// Whether this entry covers `hour` on `weekday`. The hours after midnight
+    /// of a wrapping range belong to the day the range started on.
+    pub fn is_active(&self, weekday: Weekday, hour: u8) -> bool {
+        let range = self.time_range();
+        if !range.contains(hour) {
+            return false;
+        }
+
+        let day = if range.wraps_midnight() && hour < range.end_hour {
+            weekday.previous()
+        } else {
+            weekday
+        };
+        self.days.contains(&day)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Weekday {
     Monday,
@@ -119,6 +188,16 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
     pub fn from_chrono(wd: chrono::Weekday) -> Self {
         match wd {
             chrono::Weekday::Mon => Weekday::Monday,
@@ -130,6 +209,45 @@ impl Weekday {
             chrono::Weekday::Sun => Weekday::Sunday,
         }
     }
+
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap_or(0);
+        Self::ALL[(index + 6) % 7]
+    }
+
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "mon",
+            Weekday::Tuesday => "tue",
+            Weekday::Wednesday => "wed",
+            Weekday::Thursday => "thu",
+            Weekday::Friday => "fri",
+            Weekday::Saturday => "sat",
+            Weekday::Sunday => "sun",
+        }
+    }
+
+    /// Parse "all", "weekdays", "weekends" or a comma-separated list like "mon,wed,fri"
+    pub fn parse_list(s: &str) -> Option<Vec<Weekday>> {
+        match s.trim().to_lowercase().as_str() {
+            "all" => return Some(Self::ALL.to_vec()),
+            "weekdays" => return Some(Self::ALL[..5].to_vec()),
+            "weekends" => return Some(Self::ALL[5..].to_vec()),
+            _ => {}
+        }
+
+        let mut days = Vec::new();
+        for part in s.split(',') {
+            let part = part.trim().to_lowercase();
+            let day = Self::ALL.into_iter().find(|d| {
+                part.len() >= 3 && format!("{:?}", d).to_lowercase().starts_with(&part)
+            })?;
+            if !days.contains(&day) {
+                days.push(day);
+            }
+        }
+        Some(days)
+    }
 }
 
 impl Default for PowerSchedule {
@@ -166,6 +284,8 @@ impl Default for PowerSchedule {
                     name: "Gaming Hours (Full Power)".to_string(),
                 },
             ],
+            last_manual_override: None,
+            override_grace_minutes: default_override_grace_minutes(),
         }
     }
 }
@@ -175,28 +295,52 @@ impl PowerSchedule {
         Self::default()
     }
 
+    /// Entry covering the given day and hour. Later entries win where ranges overlap,
+    /// so a narrow slot can be layered over a broad one.
+    pub fn active_entry_at(&self, weekday: Weekday, hour: u8) -> Option<&ScheduleEntry> {
+        self.active_index_at(weekday, hour)
+            .map(|index| &self.schedules[index])
+    }
+
+    /// Index of the entry covering the given day and hour
+    pub fn active_index_at(&self, weekday: Weekday, hour: u8) -> Option<usize> {
+        self.schedules
+            .iter()
+            .rposition(|entry| entry.is_active(weekday, hour))
+    }
+
+    /// Index of the entry covering the current local time
+    pub fn active_index(&self) -> Option<usize> {
+        use chrono::Datelike;
+        use chrono::Timelike;
+
+        let now = Local::now();
+        self.active_index_at(Weekday::from_chrono(now.weekday()), now.hour() as u8)
+    }
+
+    /// Entry covering the current local time
+    pub fn active_entry(&self) -> Option<&ScheduleEntry> {
+        self.active_index().map(|index| &self.schedules[index])
+    }
+
     /// Get active power limit for current time
     pub fn get_active_power_limit(&self) -> Option<u32> {
         if !self.enabled {
             return None;
         }
 
-        use chrono::Datelike;
-        use chrono::Timelike;
-
-        let now = chrono::Local::now();
-        let current_hour = now.hour() as u8;
-        let current_weekday = Weekday::from_chrono(now.weekday());
+        self.active_entry().map(|entry| entry.power_limit)
+    }
 
-        for schedule in &self.schedules {
-            if schedule.days.contains(&current_weekday) {
-                if schedule.start_hour <= current_hour && current_hour < schedule.end_hour {
-                    return Some(schedule.power_limit);
-                }
-            }
-        }
+    /// When a recent manual override stops holding the schedule back
+    pub fn override_expires_at(&self) -> Option<DateTime<Utc>> {
+        self.last_manual_override
+            .map(|at| at + chrono::Duration::minutes(self.override_grace_minutes as i64))
+    }
 
-        None
+    /// Whether a manual power limit change is still within its grace period
+    pub fn override_active(&self, now: DateTime<Utc>) -> bool {
+        self.override_expires_at().is_some_and(|until| now < until)
     }
 
     /// Add a new schedule entry
@@ -212,6 +356,79 @@ impl PowerSchedule {
     }
 }
 
+/// Applies the scheduled power limit from a daemon loop, only touching the GPU
+/// when the active slot changes or a manual override has run out
+#[derive(Debug, Default)]
+pub struct PowerScheduler {
+    last_applied: Option<(u32, DateTime<Utc>)>,
+}
+
+impl PowerScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget what was last applied, e.g. after something else changed the limit
+    pub fn reset(&mut self) {
+        self.last_applied = None;
+    }
+
+    /// Limit that should be applied at `now`, or `None` if nothing needs to change
+    pub fn due_limit(&self, schedule: &PowerSchedule, now: DateTime<Local>) -> Option<u32> {
+        use chrono::Datelike;
+        use chrono::Timelike;
+
+        if !schedule.enabled || schedule.override_active(now.with_timezone(&Utc)) {
+            return None;
+        }
+
+        let entry =
+            schedule.active_entry_at(Weekday::from_chrono(now.weekday()), now.hour() as u8)?;
+
+        match self.last_applied {
+            Some((limit, applied_at))
+                if limit == entry.power_limit
+                    && schedule
+                        .last_manual_override
+                        .is_none_or(|at| at < applied_at) =>
+            {
+                None
+            }
+            _ => Some(entry.power_limit),
+        }
+    }
+
+    /// Reload the schedule and apply the due limit to every GPU, returning it if one was set
+    pub fn tick(&mut self, backend: &SharedNvmlBackend) -> NvResult<Option<u32>> {
+        let config = load_power_config()?;
+        if !config.schedule_enabled {
+            return Ok(None);
+        }
+
+        let now = Local::now();
+        let Some(limit) = self.due_limit(&config.schedule, now) else {
+            return Ok(None);
+        };
+
+        for gpu in 0..backend.device_count()? {
+            crate::power::set_power_limit_percent(backend, gpu, limit as f32)?;
+        }
+        self.last_applied = Some((limit, now.with_timezone(&Utc)));
+        Ok(Some(limit))
+    }
+}
+
+/// Remember a hand-set power limit so the schedule doesn't undo it right away
+pub fn record_manual_override() -> NvResult<()> {
+    let mut config = load_power_config()?;
+    if !config.schedule_enabled || crate::gpu_safe::is_dry_run() {
+        return Ok(());
+    }
+
+    config.schedule.last_manual_override = Some(Utc::now());
+    save_power_config(&config)
+}
+
 /// Per-game power profiles
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GamePowerProfiles {
@@ -402,4 +619,84 @@ mod tests {
         // This test depends on current time, so just ensure it doesn't crash
         let _ = schedule.get_active_power_limit();
     }
+
+    fn entry(start: u8, end: u8, power_limit: u32, days: Vec<Weekday>) -> ScheduleEntry {
+        ScheduleEntry {
+            start_hour: start,
+            end_hour: end,
+            power_limit,
+            days,
+            name: format!("{}-{}", start, end),
+        }
+    }
+
+    #[test]
+    fn test_schedule_wraps_midnight() {
+        let night = entry(22, 7, 60, vec![Weekday::Friday]);
+        assert!(night.is_active(Weekday::Friday, 23));
+        // 03:00 Saturday is still Friday night's slot
+        assert!(night.is_active(Weekday::Saturday, 3));
+        assert!(!night.is_active(Weekday::Friday, 3));
+        assert!(!night.is_active(Weekday::Saturday, 7));
+        assert!(!night.is_active(Weekday::Friday, 12));
+    }
+
+    #[test]
+    fn test_schedule_overlap_last_wins() {
+        let mut schedule = PowerSchedule {
+            enabled: true,
+            schedules: vec![entry(8, 23, 100, Weekday::ALL.to_vec())],
+            ..PowerSchedule::default()
+        };
+        schedule.add_schedule(entry(12, 14, 70, Weekday::ALL.to_vec()));
+
+        let at = |hour| {
+            schedule
+                .active_entry_at(Weekday::Monday, hour)
+                .map(|e| e.power_limit)
+        };
+        assert_eq!(at(10), Some(100));
+        assert_eq!(at(13), Some(70));
+        assert_eq!(at(3), None);
+    }
+
+    #[test]
+    fn test_scheduler_respects_manual_override() {
+        use chrono::TimeZone;
+
+        let now = Local.with_ymd_and_hms(2024, 6, 3, 23, 30, 0).unwrap();
+        let mut schedule = PowerSchedule {
+            enabled: true,
+            schedules: vec![entry(22, 7, 60, Weekday::ALL.to_vec())],
+            ..PowerSchedule::default()
+        };
+        let mut scheduler = PowerScheduler::new();
+        assert_eq!(scheduler.due_limit(&schedule, now), Some(60));
+
+        scheduler.last_applied = Some((60, (now - chrono::Duration::hours(1)).to_utc()));
+        assert_eq!(scheduler.due_limit(&schedule, now), None);
+
+        // Overridden 10 minutes ago: hold off
+        schedule.last_manual_override = Some((now - chrono::Duration::minutes(10)).to_utc());
+        assert_eq!(scheduler.due_limit(&schedule, now), None);
+
+        // Grace period over: the schedule takes back control
+        let later = now + chrono::Duration::minutes(25);
+        assert_eq!(scheduler.due_limit(&schedule, later), Some(60));
+    }
+
+    #[test]
+    fn test_weekday_parse_list() {
+        assert_eq!(Weekday::parse_list("all").unwrap().len(), 7);
+        assert_eq!(
+            Weekday::parse_list("mon, Wed,friday"),
+            Some(vec![Weekday::Monday, Weekday::Wednesday, Weekday::Friday])
+        );
+        assert_eq!(
+            Weekday::parse_list("weekends").unwrap()[0],
+            Weekday::Saturday
+        );
+        assert!(Weekday::parse_list("someday").is_none());
+        assert_eq!(Weekday::Monday.previous(), Weekday::Sunday);
+    }
 }
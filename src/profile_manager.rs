@@ -22,6 +22,27 @@ pub struct ProfileBundle {
     pub overclock: Option<OverclockProfile>,
     pub game_profiles: Vec<GameProfile>,
     pub vibrance_settings: Option<VibranceSettings>,
+    /// GPU and driver the profile was tuned on, recorded when it is captured or exported
+    #[serde(default)]
+    pub tuned_on: Option<TunedOn>,
+}
+
+/// GPU model and driver version a profile was tuned on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TunedOn {
+    pub gpu_model: String,
+    pub driver_version: String,
+}
+
+impl TunedOn {
+    /// Describe the first GPU, or `None` when NVML has no device
+    pub fn detect() -> Option<Self> {
+        let backend = crate::nvml_backend::create_real_backend();
+        crate::gpu::get_gpu_info(&backend).ok().map(|info| Self {
+            gpu_model: info.name,
+            driver_version: info.driver_version,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +55,251 @@ pub struct ProfileManager {
     profiles_dir: PathBuf,
 }
 
+/// How much a single profile change can hurt if it is unexpected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeRisk {
+    Low,
+    Medium,
+    High,
+}
+
+impl ChangeRisk {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+/// One field that differs between the current state and a profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeItem {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+    pub risk: ChangeRisk,
+}
+
+impl ChangeItem {
+    fn new(field: &'static str, old: impl ToString, new: impl ToString, risk: ChangeRisk) -> Self {
+        Self {
+            field,
+            old: old.to_string(),
+            new: new.to_string(),
+            risk,
+        }
+    }
+}
+
+impl std::fmt::Display for ChangeItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {} [{} risk]",
+            self.field,
+            self.old,
+            self.new,
+            self.risk.label()
+        )
+    }
+}
+
+/// Raising a limit or offset is riskier than lowering it
+fn raise_risk<T: PartialOrd>(old: T, new: T, raised: ChangeRisk) -> ChangeRisk {
+    if new > old { raised } else { ChangeRisk::Low }
+}
+
+/// List every field `target` would change relative to `current`.
+///
+/// A section missing from `target` is left alone when applying, so it only
+/// shows up as an informational low-risk item. An overclock section missing
+/// from `current` is compared against stock settings.
+pub fn diff(current: &ProfileBundle, target: &ProfileBundle) -> Vec<ChangeItem> {
+    let mut changes = Vec::new();
+
+    if current.description != target.description {
+        changes.push(ChangeItem::new(
+            "description",
+            format!("{:?}", current.description),
+            format!("{:?}", target.description),
+            ChangeRisk::Low,
+        ));
+    }
+
+    let connectors = |layout: &DisplayLayout| {
+        layout
+            .displays
+            .iter()
+            .map(|d| d.connector.clone())
+            .collect::<Vec<_>>()
+    };
+    match (&current.display_layout, &target.display_layout) {
+        (Some(left), Some(right)) if connectors(left) != connectors(right) => {
+            changes.push(ChangeItem::new(
+                "display_layout",
+                format!("{:?}", connectors(left)),
+                format!("{:?}", connectors(right)),
+                ChangeRisk::Low,
+            ));
+        }
+        (None, Some(right)) => changes.push(ChangeItem::new(
+            "display_layout",
+            "none",
+            format!("{:?}", connectors(right)),
+            ChangeRisk::Low,
+        )),
+        (Some(_), None) => changes.push(ChangeItem::new(
+            "display_layout",
+            "configured",
+            "none",
+            ChangeRisk::Low,
+        )),
+        _ => {}
+    }
+
+    let fan_points = |curve: &Option<FanCurve>| curve.as_ref().map(fan_curve_to_point_pairs);
+    match (
+        fan_points(&current.fan_curve),
+        fan_points(&target.fan_curve),
+    ) {
+        (left, Some(right)) if left.as_ref() != Some(&right) => {
+            changes.push(ChangeItem::new(
+                "fan_curve",
+                left.map_or("none".to_string(), |p| format!("{:?}", p)),
+                format!("{:?}", right),
+                ChangeRisk::Medium,
+            ));
+        }
+        (Some(_), None) => changes.push(ChangeItem::new(
+            "fan_curve",
+            "configured",
+            "none",
+            ChangeRisk::Low,
+        )),
+        _ => {}
+    }
+
+    if current.voltage_curve.is_some() != target.voltage_curve.is_some() {
+        changes.push(ChangeItem::new(
+            "voltage_curve",
+            if current.voltage_curve.is_some() {
+                "configured"
+            } else {
+                "none"
+            },
+            if target.voltage_curve.is_some() {
+                "configured"
+            } else {
+                "none"
+            },
+            ChangeRisk::High,
+        ));
+    }
+
+    match (&current.overclock, &target.overclock) {
+        (left, Some(right)) => {
+            let stock = OverclockProfile::default();
+            let left = left.as_ref().unwrap_or(&stock);
+            if left.gpu_clock_offset != right.gpu_clock_offset {
+                changes.push(ChangeItem::new(
+                    "overclock.gpu_clock_offset",
+                    format!("{:+} MHz", left.gpu_clock_offset),
+                    format!("{:+} MHz", right.gpu_clock_offset),
+                    raise_risk(
+                        left.gpu_clock_offset,
+                        right.gpu_clock_offset,
+                        ChangeRisk::Medium,
+                    ),
+                ));
+            }
+            if left.memory_clock_offset != right.memory_clock_offset {
+                changes.push(ChangeItem::new(
+                    "overclock.memory_clock_offset",
+                    format!("{:+} MHz", left.memory_clock_offset),
+                    format!("{:+} MHz", right.memory_clock_offset),
+                    raise_risk(
+                        left.memory_clock_offset,
+                        right.memory_clock_offset,
+                        ChangeRisk::Medium,
+                    ),
+                ));
+            }
+            if left.voltage_offset != right.voltage_offset {
+                changes.push(ChangeItem::new(
+                    "overclock.voltage_offset",
+                    format!("{:+} mV", left.voltage_offset),
+                    format!("{:+} mV", right.voltage_offset),
+                    ChangeRisk::High,
+                ));
+            }
+            if left.power_limit != right.power_limit {
+                changes.push(ChangeItem::new(
+                    "overclock.power_limit",
+                    format!("{}%", left.power_limit),
+                    format!("{}%", right.power_limit),
+                    raise_risk(left.power_limit, right.power_limit, ChangeRisk::High),
+                ));
+            }
+            if left.temp_limit != right.temp_limit {
+                changes.push(ChangeItem::new(
+                    "overclock.temp_limit",
+                    format!("{}°C", left.temp_limit),
+                    format!("{}°C", right.temp_limit),
+                    raise_risk(left.temp_limit, right.temp_limit, ChangeRisk::High),
+                ));
+            }
+        }
+        (Some(_), None) => changes.push(ChangeItem::new(
+            "overclock",
+            "configured",
+            "none",
+            ChangeRisk::Low,
+        )),
+        (None, None) => {}
+    }
+
+    match (&current.vibrance_settings, &target.vibrance_settings) {
+        (left, Some(right))
+            if left.as_ref().map(|l| &l.display_levels) != Some(&right.display_levels) =>
+        {
+            changes.push(ChangeItem::new(
+                "vibrance.display_levels",
+                left.as_ref()
+                    .map_or("none".to_string(), |l| format!("{:?}", l.display_levels)),
+                format!("{:?}", right.display_levels),
+                ChangeRisk::Low,
+            ));
+        }
+        (Some(_), None) => changes.push(ChangeItem::new(
+            "vibrance",
+            "configured",
+            "none",
+            ChangeRisk::Low,
+        )),
+        _ => {}
+    }
+
+    let executables = |bundle: &ProfileBundle| {
+        bundle
+            .game_profiles
+            .iter()
+            .map(|profile| profile.executable.clone())
+            .collect::<Vec<_>>()
+    };
+    if executables(current) != executables(target) {
+        changes.push(ChangeItem::new(
+            "game_profiles",
+            format!("{:?}", executables(current)),
+            format!("{:?}", executables(target)),
+            ChangeRisk::Low,
+        ));
+    }
+
+    changes
+}
+
 fn point_pairs_to_fan_curve(points: &[(u8, u8)]) -> FanCurve {
     FanCurve {
         points: points
@@ -41,9 +307,38 @@ fn point_pairs_to_fan_curve(points: &[(u8, u8)]) -> FanCurve {
             .map(|(temp, speed)| crate::gui_widgets::CurvePoint::new(*temp as f64, *speed as f64))
             .collect(),
         selected_point: None,
+        zero_rpm_below: None,
     }
 }
 
+/// The curve the fan daemon drives `gpu_index`'s first fan with, or `None`
+/// when its fans are on the VBIOS curve
+fn live_fan_curve(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+    gpu_index: u32,
+) -> Option<FanCurve> {
+    use crate::fan_daemon::{ActiveFanCurves, gpu_fan_ids};
+
+    let active = ActiveFanCurves::load(&ActiveFanCurves::default_path()).ok()?;
+    let fan_id = *gpu_fan_ids(backend, gpu_index).first()?;
+    let curve = active.curves.get(&fan_id).or_else(|| {
+        active
+            .shared
+            .as_ref()
+            .filter(|_| active.gpu_index == gpu_index)
+    })?;
+
+    let points: Vec<(u8, u8)> = curve
+        .points
+        .iter()
+        .map(|point| (point.temperature, point.duty_cycle))
+        .collect();
+    Some(FanCurve {
+        zero_rpm_below: curve.zero_rpm_below,
+        ..point_pairs_to_fan_curve(&points)
+    })
+}
+
 fn fan_curve_to_point_pairs(curve: &FanCurve) -> Vec<(u8, u8)> {
     curve
         .points
@@ -128,11 +423,14 @@ impl ProfileManager {
             NvControlError::ConfigError(format!("Failed to create export directory: {}", e))
         })?;
 
-        let json = serde_json::to_string_pretty(bundle).map_err(|e| {
-            NvControlError::ConfigError(format!("Failed to serialize profile: {}", e))
-        })?;
+        let text = if is_toml(path) {
+            toml::to_string_pretty(bundle).map_err(|e| e.to_string())
+        } else {
+            serde_json::to_string_pretty(bundle).map_err(|e| e.to_string())
+        }
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to serialize profile: {}", e)))?;
 
-        fs::write(path, json)
+        fs::write(path, text)
             .map_err(|e| NvControlError::ConfigError(format!("Failed to write profile: {}", e)))?;
 
         Ok(())
@@ -147,20 +445,32 @@ impl ProfileManager {
             )));
         }
 
-        let json = fs::read_to_string(path)
+        let text = fs::read_to_string(path)
             .map_err(|e| NvControlError::ConfigError(format!("Failed to read profile: {}", e)))?;
 
-        let bundle: ProfileBundle = serde_json::from_str(&json)
-            .map_err(|e| NvControlError::ConfigError(format!("Failed to parse profile: {}", e)))?;
+        let bundle: ProfileBundle = if is_toml(path) {
+            toml::from_str(&text).map_err(|e| e.to_string())
+        } else {
+            serde_json::from_str(&text).map_err(|e| e.to_string())
+        }
+        .map_err(|e| NvControlError::ConfigError(format!("Failed to parse profile: {}", e)))?;
 
         println!("✅ Profile imported: {}", bundle.name);
         Ok(bundle)
     }
 
     pub fn load_profile_by_name(&self, name: &str) -> NvResult<ProfileBundle> {
+        self.import_profile(&self.profile_path(name))
+    }
+
+    /// Whether a saved profile called `name` exists, readable or not
+    pub fn has_profile(&self, name: &str) -> bool {
+        self.profile_path(name).exists()
+    }
+
+    fn profile_path(&self, name: &str) -> PathBuf {
         let safe_filename = self.sanitize_filename(name);
-        let path = self.profiles_dir.join(format!("{}.json", safe_filename));
-        self.import_profile(&path)
+        self.profiles_dir.join(format!("{}.json", safe_filename))
     }
 
     pub fn save_imported_profile(
@@ -232,6 +542,7 @@ impl ProfileManager {
             overclock: None,
             game_profiles: vec![],
             vibrance_settings: None,
+            tuned_on: None,
         };
 
         self.export_profile(&bundle, Some(name))
@@ -249,6 +560,7 @@ impl ProfileManager {
             overclock: None,
             game_profiles: vec![],
             vibrance_settings: None,
+            tuned_on: None,
         };
 
         self.export_profile(&bundle, Some(name))
@@ -266,6 +578,7 @@ impl ProfileManager {
             overclock: Some(oc.clone()),
             game_profiles: vec![],
             vibrance_settings: None,
+            tuned_on: None,
         };
 
         self.export_profile(&bundle, Some(name))
@@ -292,27 +605,32 @@ impl ProfileManager {
             overclock,
             game_profiles: vec![],
             vibrance_settings: None,
+            tuned_on: None,
         };
 
         self.export_profile(&bundle, Some(name))
     }
 
-    pub fn capture_live_bundle(name: impl Into<String>) -> NvResult<ProfileBundle> {
+    /// Snapshot the live state of `gpu_index` as a bundle
+    pub fn capture_live_bundle(name: impl Into<String>, gpu_index: u32) -> NvResult<ProfileBundle> {
         let name = name.into();
+        let backend = crate::nvml_backend::create_real_backend();
 
         let (gpu_clock_offset, memory_clock_offset) =
-            crate::overclocking::get_current_offsets(0).unwrap_or((0, 0));
-        let power_limit = crate::power::get_power_info()
+            crate::overclocking::get_current_offsets(gpu_index).unwrap_or((0, 0));
+        // Stored as a percentage of the default limit, the unit profiles apply
+        let power_limit = crate::power::PowerLimitRange::query(&backend, gpu_index)
             .ok()
-            .and_then(|infos| infos.into_iter().next())
-            .and_then(|info| info.power_limit)
-            .map(|limit| limit.round().clamp(0.0, 255.0) as u8)
+            .map(|range| {
+                range
+                    .percent_of_default(range.current_w)
+                    .round()
+                    .clamp(0.0, 255.0) as u8
+            })
             .unwrap_or(100);
-        let temp_limit = crate::power::get_power_info()
-            .ok()
-            .and_then(|infos| infos.into_iter().next())
-            .and_then(|info| info.temperature)
-            .map(|temp| temp.round().clamp(0.0, 125.0) as u8)
+        let temp_limit = backend
+            .get_temperature_target(gpu_index)
+            .map(|temp| temp.min(125) as u8)
             .unwrap_or(83);
 
         let vibrance_settings = crate::vibrance_native::get_vibrance_status_native()
@@ -341,9 +659,7 @@ impl ProfileManager {
             ),
             created_at: chrono::Utc::now(),
             display_layout: Some(display_layout),
-            fan_curve: Some(point_pairs_to_fan_curve(
-                &OverclockProfile::default().fan_curve,
-            )),
+            fan_curve: live_fan_curve(&backend, gpu_index),
             voltage_curve: None,
             overclock: Some(OverclockProfile {
                 name: format!("{}-live", name),
@@ -356,32 +672,112 @@ impl ProfileManager {
             }),
             game_profiles: vec![],
             vibrance_settings,
+            tuned_on: TunedOn::detect(),
+        })
+    }
+
+    /// Reject bundles whose overclock or power limit exceed hardware safety limits
+    pub fn validate_bundle_safety(bundle: &ProfileBundle) -> NvResult<()> {
+        use crate::hardware_safety::{
+            OverclockValidation, PowerValidation, SafetyMonitor, SafetyThresholds,
+        };
+
+        let Some(overclock) = &bundle.overclock else {
+            return Ok(());
+        };
+
+        let monitor = SafetyMonitor::new(0, SafetyThresholds::default());
+        if let Ok(OverclockValidation::Unsafe { reason }) = monitor
+            .validate_overclock_safe(overclock.gpu_clock_offset, overclock.memory_clock_offset)
+        {
+            return Err(NvControlError::UnsupportedFeature(format!(
+                "Overclock rejected: {}",
+                reason
+            )));
+        }
+        if let Ok(PowerValidation::Unsafe { reason }) =
+            monitor.validate_power_limit_safe(overclock.power_limit)
+        {
+            return Err(NvControlError::PowerManagementFailed(format!(
+                "Power limit rejected: {}",
+                reason
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Apply every section of `bundle` to `gpu_index`
+    pub fn apply_bundle(bundle: &ProfileBundle, gpu_index: u32) -> NvResult<Vec<String>> {
+        Self::apply_sections(bundle, gpu_index, |_| true)
+    }
+
+    /// Apply only the fields listed in `changes`, as produced by [`diff`]
+    pub fn apply_changes(
+        target: &ProfileBundle,
+        changes: &[ChangeItem],
+        gpu_index: u32,
+    ) -> NvResult<Vec<String>> {
+        Self::apply_sections(target, gpu_index, |field| {
+            changes.iter().any(|c| c.field == field)
         })
     }
 
-    pub fn apply_bundle(bundle: &ProfileBundle) -> NvResult<Vec<String>> {
+    fn apply_sections(
+        bundle: &ProfileBundle,
+        gpu_index: u32,
+        wants: impl Fn(&str) -> bool,
+    ) -> NvResult<Vec<String>> {
+        Self::validate_bundle_safety(bundle)?;
+
+        let backend = crate::nvml_backend::create_real_backend();
         let mut applied = Vec::new();
 
         if let Some(overclock) = &bundle.overclock {
-            crate::overclocking::apply_overclock_profile(overclock)?;
-            applied.push(format!(
-                "overclock gpu {:+} mem {:+} power {}",
-                overclock.gpu_clock_offset, overclock.memory_clock_offset, overclock.power_limit
-            ));
+            if wants("overclock.gpu_clock_offset")
+                || wants("overclock.memory_clock_offset")
+                || wants("overclock.voltage_offset")
+            {
+                crate::overclocking::apply_overclock_profile_for_gpu(overclock, gpu_index)?;
+                applied.push(format!(
+                    "overclock gpu {:+} mem {:+}",
+                    overclock.gpu_clock_offset, overclock.memory_clock_offset
+                ));
+            }
 
-            crate::power::set_power_limit_percentage(overclock.power_limit as u32)?;
-            applied.push(format!("power limit {}%", overclock.power_limit));
+            if wants("overclock.power_limit") {
+                let limit = crate::power::set_power_limit_percent(
+                    &backend,
+                    gpu_index,
+                    overclock.power_limit as f32,
+                )?;
+                applied.push(format!(
+                    "power limit {}% ({:.0} W)",
+                    overclock.power_limit, limit.applied_w
+                ));
+            }
         }
 
-        if let Some(fan_curve) = &bundle.fan_curve {
+        if let Some(fan_curve) = bundle.fan_curve.as_ref().filter(|_| wants("fan_curve")) {
             let points = fan_curve_to_point_pairs(fan_curve);
             if !points.is_empty() {
-                crate::fan::set_fan_curve(0, &points)?;
+                let mut curve = crate::fan_daemon::curve_from_points("Profile", &points);
+                curve.zero_rpm_below = fan_curve.zero_rpm_below;
+                let temp = backend.get_temperature(gpu_index)?.min(100) as u8;
+                // Set the fans now and hand the curve to the daemon to follow
+                for fan_id in crate::fan_daemon::gpu_fan_ids(&backend, gpu_index) {
+                    crate::fan::apply_fan_curve(fan_id, &curve, temp, None)?;
+                    crate::fan_daemon::persist_curve(fan_id, &curve)?;
+                }
                 applied.push(format!("fan curve ({} points)", points.len()));
             }
         }
 
-        if let Some(display_layout) = &bundle.display_layout {
+        if let Some(display_layout) = bundle
+            .display_layout
+            .as_ref()
+            .filter(|_| wants("display_layout"))
+        {
             let mut manager = MultiMonitorManager::new()?;
             manager.apply_live_layout(display_layout)?;
             applied.push(format!(
@@ -390,7 +786,11 @@ impl ProfileManager {
             ));
         }
 
-        if let Some(vibrance) = &bundle.vibrance_settings {
+        if let Some(vibrance) = bundle
+            .vibrance_settings
+            .as_ref()
+            .filter(|_| wants("vibrance.display_levels"))
+        {
             for (display_id, level) in vibrance.display_levels.iter().enumerate() {
                 let percentage = crate::vibrance_native::vibrance_to_percentage(*level as i64);
                 crate::vibrance_native::set_display_vibrance_native(
@@ -409,7 +809,7 @@ impl ProfileManager {
     }
 
     pub fn preview_live_bundle(name: impl Into<String>) -> NvResult<String> {
-        let bundle = Self::capture_live_bundle(name)?;
+        let bundle = Self::capture_live_bundle(name, 0)?;
         Ok(Self::summarize_bundle(&bundle))
     }
 
@@ -497,125 +897,238 @@ impl ProfileManager {
     }
 
     pub fn diff_bundles(current: &ProfileBundle, target: &ProfileBundle) -> Vec<String> {
-        let mut changes = Vec::new();
-
-        if current.description != target.description {
-            changes.push(format!(
-                "description: {:?} -> {:?}",
-                current.description, target.description
-            ));
-        }
+        diff(current, target)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
 
-        match (&current.display_layout, &target.display_layout) {
-            (Some(left), Some(right)) => {
-                let left_connectors: Vec<_> = left.displays.iter().map(|d| &d.connector).collect();
-                let right_connectors: Vec<_> =
-                    right.displays.iter().map(|d| &d.connector).collect();
-                if left_connectors != right_connectors
-                    || left.displays.len() != right.displays.len()
-                {
-                    changes.push(format!(
-                        "display_layout: {:?} -> {:?}",
-                        left_connectors, right_connectors
-                    ));
-                }
+    pub fn resolve_bundle_reference(&self, value: &str) -> NvResult<ProfileBundle> {
+        if value.eq_ignore_ascii_case("live") {
+            Self::capture_live_bundle("live", 0)
+        } else {
+            let path = Path::new(value);
+            if path.exists() {
+                self.import_profile(path)
+            } else {
+                self.load_profile_by_name(value)
             }
-            (None, Some(_)) => changes.push("display_layout: none -> configured".to_string()),
-            (Some(_), None) => changes.push("display_layout: configured -> none".to_string()),
-            _ => {}
         }
+    }
+}
+
+impl Default for ProfileManager {
+    fn default() -> Self {
+        Self::new().expect("Failed to create ProfileManager")
+    }
+}
+
+/// Profile files are TOML when the path ends in `.toml`, JSON otherwise
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
 
-        if current.fan_curve.is_some() != target.fan_curve.is_some() {
-            changes.push(format!(
-                "fan_curve: {} -> {}",
-                current.fan_curve.is_some(),
-                target.fan_curve.is_some()
+/// Offsets in a shared profile may be at most this many times the card's
+/// known-safe offset before import refuses them
+const IMPORT_OFFSET_HEADROOM: i32 = 2;
+
+/// Largest voltage offset (mV) a shared profile may carry
+const MAX_IMPORT_VOLTAGE_OFFSET: i32 = 100;
+
+/// Write the saved profile `name` to `path` as a self-contained file to
+/// share. TOML is used for `.toml` paths, JSON otherwise. The GPU model and
+/// driver are recorded so whoever imports it can tell what it was tuned on.
+pub fn export(name: &str, path: &Path) -> NvResult<ProfileBundle> {
+    let manager = ProfileManager::new()?;
+    let mut bundle = manager.load_profile_by_name(name)?;
+    if bundle.tuned_on.is_none() {
+        bundle.tuned_on = TunedOn::detect();
+    }
+    manager.export_profile_to_path(&bundle, path)?;
+    Ok(bundle)
+}
+
+/// A shared profile that passed validation and was saved locally
+#[derive(Debug, Clone)]
+pub struct ImportedProfile {
+    pub bundle: ProfileBundle,
+    pub path: PathBuf,
+    /// Things to review before applying, e.g. a different GPU model
+    pub warnings: Vec<String>,
+}
+
+/// Load a shared profile file, check it against the current GPU and save
+/// it with the other profiles. Clearly unsafe values are refused, as is a
+/// name that is already taken unless `overwrite` is set.
+pub fn import(path: &Path, overwrite: bool) -> NvResult<ImportedProfile> {
+    let manager = ProfileManager::new()?;
+    let bundle = manager.import_profile(path)?;
+    let warnings = check_shared_profile(&bundle, TunedOn::detect().as_ref())?;
+    let path = manager
+        .profiles_dir
+        .join(format!("{}.json", manager.sanitize_filename(&bundle.name)));
+    if path.exists() && !overwrite {
+        return Err(NvControlError::ConfigError(format!(
+            "A profile named '{}' already exists",
+            bundle.name
+        )));
+    }
+    manager.export_profile_to_path(&bundle, &path)?;
+    Ok(ImportedProfile {
+        bundle,
+        path,
+        warnings,
+    })
+}
+
+/// Validate a shared profile for the GPU in `current`. Values outside the
+/// card's limits (or the generic safety limits when the card is unknown)
+/// are errors; a different or unrecorded GPU model is a warning.
+pub fn check_shared_profile(
+    bundle: &ProfileBundle,
+    current: Option<&TunedOn>,
+) -> NvResult<Vec<String>> {
+    let thresholds = crate::hardware_safety::SafetyThresholds::default();
+    let spec = current.and_then(|gpu| crate::gpu_profiles::get_gpu_spec(&gpu.gpu_model));
+    let card = current.map_or("this GPU", |gpu| gpu.gpu_model.as_str());
+
+    let max_gpu_offset = spec.as_ref().map_or(thresholds.max_clock_offset_mhz, |s| {
+        (s.safe_gpu_offset * IMPORT_OFFSET_HEADROOM).min(thresholds.max_clock_offset_mhz)
+    });
+    let max_memory_offset = spec.as_ref().map_or(thresholds.max_memory_offset_mhz, |s| {
+        (s.safe_memory_offset * IMPORT_OFFSET_HEADROOM).min(thresholds.max_memory_offset_mhz)
+    });
+    let max_power = spec.as_ref().filter(|s| s.default_tdp > 0).map_or(
+        thresholds.max_power_limit_percent,
+        |s| {
+            ((s.max_tdp * 100 / s.default_tdp).min(thresholds.max_power_limit_percent as u32)) as u8
+        },
+    );
+    let max_temp = spec
+        .as_ref()
+        .map_or(thresholds.temp_critical as u8, |s| s.max_temp_limit);
+
+    let mut problems = Vec::new();
+    let mut check_offsets = |what: &str, gpu: Option<i32>, memory: Option<i32>| {
+        if let Some(gpu) = gpu
+            && gpu.abs() > max_gpu_offset
+        {
+            problems.push(format!(
+                "{what} core offset {gpu:+} MHz exceeds ±{max_gpu_offset} MHz for {card}"
             ));
         }
-
-        if current.voltage_curve.is_some() != target.voltage_curve.is_some() {
-            changes.push(format!(
-                "voltage_curve: {} -> {}",
-                current.voltage_curve.is_some(),
-                target.voltage_curve.is_some()
+        if let Some(memory) = memory
+            && memory.abs() > max_memory_offset
+        {
+            problems.push(format!(
+                "{what} memory offset {memory:+} MHz exceeds ±{max_memory_offset} MHz for {card}"
             ));
         }
+    };
 
-        match (&current.overclock, &target.overclock) {
-            (Some(left), Some(right))
-                if left.gpu_clock_offset != right.gpu_clock_offset
-                    || left.memory_clock_offset != right.memory_clock_offset
-                    || left.power_limit != right.power_limit
-                    || left.temp_limit != right.temp_limit
-                    || left.voltage_offset != right.voltage_offset
-                    || left.fan_curve != right.fan_curve =>
-            {
-                changes.push(format!(
-                    "overclock: gpu {:+} -> {:+}, mem {:+} -> {:+}, power {} -> {}",
-                    left.gpu_clock_offset,
-                    right.gpu_clock_offset,
-                    left.memory_clock_offset,
-                    right.memory_clock_offset,
-                    left.power_limit,
-                    right.power_limit
-                ))
-            }
-            (None, Some(_)) => changes.push("overclock: none -> configured".to_string()),
-            (Some(_), None) => changes.push("overclock: configured -> none".to_string()),
-            _ => {}
-        }
+    if let Some(oc) = &bundle.overclock {
+        check_offsets(
+            "Overclock",
+            Some(oc.gpu_clock_offset),
+            Some(oc.memory_clock_offset),
+        );
+    }
+    for game in &bundle.game_profiles {
+        check_offsets(
+            &format!("Game profile '{}'", game.name),
+            game.gpu_clock_offset,
+            game.mem_clock_offset,
+        );
+    }
 
-        match (&current.vibrance_settings, &target.vibrance_settings) {
-            (Some(left), Some(right)) if left.display_levels != right.display_levels => {
-                changes.push(format!(
-                    "vibrance.display_levels: {:?} -> {:?}",
-                    left.display_levels, right.display_levels
-                ));
-            }
-            (None, Some(_)) => changes.push("vibrance: none -> configured".to_string()),
-            (Some(_), None) => changes.push("vibrance: configured -> none".to_string()),
-            _ => {}
+    let power_limits = bundle
+        .overclock
+        .iter()
+        .map(|oc| ("Overclock".to_string(), u32::from(oc.power_limit)))
+        .chain(bundle.game_profiles.iter().filter_map(|game| {
+            game.power_limit
+                .map(|limit| (format!("Game profile '{}'", game.name), limit))
+        }));
+    for (what, limit) in power_limits {
+        if limit == 0 || limit > u32::from(max_power) {
+            problems.push(format!(
+                "{what} power limit {limit}% is outside 1-{max_power}% for {card}"
+            ));
         }
+    }
 
-        let current_games: Vec<_> = current
-            .game_profiles
-            .iter()
-            .map(|profile| profile.executable.as_str())
-            .collect();
-        let target_games: Vec<_> = target
-            .game_profiles
+    if let Some(oc) = &bundle.overclock {
+        if oc.temp_limit > max_temp {
+            problems.push(format!(
+                "Temperature limit {}°C exceeds {}°C for {card}",
+                oc.temp_limit, max_temp
+            ));
+        }
+        if oc.voltage_offset.abs() > MAX_IMPORT_VOLTAGE_OFFSET {
+            problems.push(format!(
+                "Voltage offset {:+} mV exceeds ±{} mV",
+                oc.voltage_offset, MAX_IMPORT_VOLTAGE_OFFSET
+            ));
+        }
+        if let Some((temp, speed)) = oc
+            .fan_curve
             .iter()
-            .map(|profile| profile.executable.as_str())
-            .collect();
-
-        if current_games != target_games {
-            changes.push(format!(
-                "game_profiles: {:?} -> {:?}",
-                current_games, target_games
+            .find(|(temp, speed)| *speed > 100 || *temp > 120)
+        {
+            problems.push(format!(
+                "Fan curve point {temp}°C → {speed}% is out of range"
             ));
         }
+    }
+    if let Some(curve) = &bundle.fan_curve
+        && let Some(point) = curve
+            .points
+            .iter()
+            .find(|p| !(0.0..=100.0).contains(&p.y) || !(0.0..=120.0).contains(&p.x))
+    {
+        problems.push(format!(
+            "Fan curve point {:.0}°C → {:.0}% is out of range",
+            point.x, point.y
+        ));
+    }
 
-        changes
+    if !problems.is_empty() {
+        return Err(NvControlError::ConfigError(format!(
+            "Refusing to import '{}': {}",
+            bundle.name,
+            problems.join("; ")
+        )));
     }
 
-    pub fn resolve_bundle_reference(&self, value: &str) -> NvResult<ProfileBundle> {
-        if value.eq_ignore_ascii_case("live") {
-            Self::capture_live_bundle("live")
-        } else {
-            let path = Path::new(value);
-            if path.exists() {
-                self.import_profile(path)
-            } else {
-                self.load_profile_by_name(value)
-            }
+    let mut warnings = Vec::new();
+    match (&bundle.tuned_on, current) {
+        (Some(tuned), Some(current))
+            if !tuned.gpu_model.eq_ignore_ascii_case(&current.gpu_model) =>
+        {
+            warnings.push(format!(
+                "Tuned on {} but this GPU is {}; offsets rarely carry over between models, \
+                 review them before applying",
+                tuned.gpu_model, current.gpu_model
+            ));
         }
+        (None, _) => warnings.push(
+            "The profile doesn't say which GPU it was tuned on; review it before applying"
+                .to_string(),
+        ),
+        _ => {}
     }
-}
-
-impl Default for ProfileManager {
-    fn default() -> Self {
-        Self::new().expect("Failed to create ProfileManager")
+    if let Some(oc) = &bundle.overclock
+        && oc.power_limit < 50
+    {
+        warnings.push(format!(
+            "Power limit {}% is below 50% and may cause instability",
+            oc.power_limit
+        ));
     }
+
+    Ok(warnings)
 }
 
 #[cfg(test)]
@@ -670,6 +1183,7 @@ mod tests {
                 display_levels: vec![32, 64],
                 per_game_vibrance: false,
             }),
+            tuned_on: None,
         }
     }
 
@@ -707,6 +1221,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_diff_rates_risk_and_skips_unchanged_fields() {
+        let current = sample_bundle("current", 100);
+        let mut target = sample_bundle("current", 100);
+        assert!(diff(&current, &target).is_empty());
+
+        let oc = target.overclock.as_mut().unwrap();
+        oc.power_limit = 110;
+        oc.gpu_clock_offset = 50;
+        let changes = diff(&current, &target);
+        assert_eq!(changes.len(), 2);
+
+        let power = changes
+            .iter()
+            .find(|c| c.field == "overclock.power_limit")
+            .unwrap();
+        assert_eq!((power.old.as_str(), power.new.as_str()), ("95%", "110%"));
+        assert_eq!(power.risk, ChangeRisk::High);
+
+        // Lowering an offset is a safe direction
+        let core = changes
+            .iter()
+            .find(|c| c.field == "overclock.gpu_clock_offset")
+            .unwrap();
+        assert_eq!(core.risk, ChangeRisk::Low);
+    }
+
+    #[test]
+    fn test_bundle_safety_rejects_extreme_offsets() {
+        assert!(ProfileManager::validate_bundle_safety(&sample_bundle("ok", 100)).is_ok());
+        assert!(ProfileManager::validate_bundle_safety(&sample_bundle("hot", 900)).is_err());
+    }
+
+    #[test]
+    fn test_shared_profile_checks_card_limits_and_model() {
+        let rtx_4090 = TunedOn {
+            gpu_model: "NVIDIA GeForce RTX 4090".to_string(),
+            driver_version: "580.95".to_string(),
+        };
+        let mut bundle = sample_bundle("shared", 100);
+        bundle.tuned_on = Some(rtx_4090.clone());
+        // Within the RTX 3060's 80°C maximum too
+        bundle.overclock.as_mut().unwrap().temp_limit = 80;
+        assert!(
+            check_shared_profile(&bundle, Some(&rtx_4090))
+                .unwrap()
+                .is_empty()
+        );
+
+        // Same values on another card only warn
+        let other = TunedOn {
+            gpu_model: "NVIDIA GeForce RTX 3060".to_string(),
+            ..rtx_4090.clone()
+        };
+        let warnings = check_shared_profile(&bundle, Some(&other)).unwrap();
+        assert!(warnings[0].contains("Tuned on NVIDIA GeForce RTX 4090"));
+
+        bundle.tuned_on = None;
+        assert_eq!(check_shared_profile(&bundle, None).unwrap().len(), 1);
+
+        let too_hot = sample_bundle("hot", 600);
+        let err = check_shared_profile(&too_hot, Some(&rtx_4090))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("core offset +600 MHz"), "{err}");
+
+        let mut fans = sample_bundle("fans", 100);
+        fans.overclock.as_mut().unwrap().fan_curve = vec![(60, 150)];
+        assert!(check_shared_profile(&fans, None).is_err());
+
+        let mut fans = sample_bundle("fans", 100);
+        fans.fan_curve = Some(FanCurve {
+            points: vec![
+                crate::gui_widgets::CurvePoint::new(40.0, 30.0),
+                crate::gui_widgets::CurvePoint::new(70.0, 250.0),
+            ],
+            ..FanCurve::default()
+        });
+        let err = check_shared_profile(&fans, None).unwrap_err().to_string();
+        assert!(err.contains("70°C → 250%"), "{err}");
+    }
+
+    #[test]
+    fn test_export_round_trips_as_toml_and_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = ProfileManager {
+            profiles_dir: dir.path().to_path_buf(),
+        };
+        let mut bundle = sample_bundle("shared", 100);
+        bundle.tuned_on = Some(TunedOn {
+            gpu_model: "NVIDIA GeForce RTX 4080".to_string(),
+            driver_version: "580.95".to_string(),
+        });
+
+        for file in ["shared.toml", "shared.json"] {
+            let path = dir.path().join(file);
+            manager.export_profile_to_path(&bundle, &path).unwrap();
+            let loaded = manager.import_profile(&path).unwrap();
+            assert_eq!(loaded.tuned_on, bundle.tuned_on);
+            assert_eq!(loaded.overclock.map(|oc| oc.gpu_clock_offset), Some(100));
+        }
+        assert!(
+            fs::read_to_string(dir.path().join("shared.toml"))
+                .unwrap()
+                .contains("gpu_model = \"NVIDIA GeForce RTX 4080\"")
+        );
+    }
+
     #[test]
     fn resolve_live_reference_returns_live_bundle() {
         let manager = ProfileManager::new().unwrap();
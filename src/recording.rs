@@ -585,3 +585,287 @@ pub fn save_recording_presets(presets: &[RecordingSettings]) -> NvResult<()> {
     println!("Saved {} recording presets", presets.len());
     Ok(())
 }
+
+// ===== Frame-time capture =====
+
+/// Frames slower than this multiple of the rolling median count as stutter
+const STUTTER_FACTOR: f32 = 1.5;
+/// Frames in the rolling median window
+const STUTTER_WINDOW: usize = 30;
+
+/// Percentile and stutter statistics for a frame-time capture
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrametimeStats {
+    pub frames: usize,
+    pub avg_ms: f32,
+    pub p50_ms: f32,
+    pub p95_ms: f32,
+    pub p99_ms: f32,
+    pub max_ms: f32,
+    /// Frames exceeding 1.5x the median of the preceding frames
+    pub stutter_count: usize,
+}
+
+/// One captured frame
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrametimeSample {
+    pub timestamp_ms: i64,
+    pub frametime_ms: f32,
+    pub stutter: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrametimeReport {
+    pub pid: u32,
+    pub frames: Vec<FrametimeSample>,
+    pub stats: FrametimeStats,
+}
+
+impl FrametimeReport {
+    pub fn write_csv(&self, path: &std::path::Path) -> NvResult<()> {
+        let mut csv = String::from("frame,timestamp_ms,frametime_ms,stutter\n");
+        for (i, frame) in self.frames.iter().enumerate() {
+            csv.push_str(&format!(
+                "{},{},{:.3},{}\n",
+                i,
+                frame.timestamp_ms,
+                frame.frametime_ms,
+                u8::from(frame.stutter)
+            ));
+        }
+        fs::write(path, csv)?;
+        Ok(())
+    }
+}
+
+fn percentile(sorted: &[f32], pct: f32) -> f32 {
+    let rank = ((pct / 100.0) * sorted.len() as f32).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Flag each frame slower than 1.5x the median of the frames before it
+pub fn detect_stutter(frametimes: &[f32]) -> Vec<bool> {
+    let mut window: Vec<f32> = Vec::with_capacity(STUTTER_WINDOW);
+    frametimes
+        .iter()
+        .enumerate()
+        .map(|(i, &ft)| {
+            let start = i.saturating_sub(STUTTER_WINDOW);
+            window.clear();
+            window.extend_from_slice(&frametimes[start..i]);
+            if window.is_empty() {
+                return false;
+            }
+            window.sort_by(f32::total_cmp);
+            let median = window[window.len() / 2];
+            ft > median * STUTTER_FACTOR
+        })
+        .collect()
+}
+
+pub fn frametime_stats(frametimes: &[f32]) -> Option<FrametimeStats> {
+    if frametimes.is_empty() {
+        return None;
+    }
+    let mut sorted = frametimes.to_vec();
+    sorted.sort_by(f32::total_cmp);
+
+    Some(FrametimeStats {
+        frames: frametimes.len(),
+        avg_ms: frametimes.iter().sum::<f32>() / frametimes.len() as f32,
+        p50_ms: percentile(&sorted, 50.0),
+        p95_ms: percentile(&sorted, 95.0),
+        p99_ms: percentile(&sorted, 99.0),
+        max_ms: sorted[sorted.len() - 1],
+        stutter_count: detect_stutter(frametimes).iter().filter(|s| **s).count(),
+    })
+}
+
+/// A MangoHud log being captured for a running game
+#[derive(Debug, Clone)]
+pub struct FrametimeCapture {
+    pub pid: u32,
+    pub log_dir: PathBuf,
+    started_ms: i64,
+}
+
+/// `MANGOHUD_CONFIG` / `MANGOHUD` from a process environment block
+fn mangohud_env(environ: &[u8]) -> (bool, Option<String>) {
+    let mut enabled = false;
+    let mut config = None;
+    for var in environ.split(|b| *b == 0) {
+        let var = String::from_utf8_lossy(var);
+        if let Some(value) = var.strip_prefix("MANGOHUD_CONFIG=") {
+            config = Some(value.to_string());
+        } else if var == "MANGOHUD=1" {
+            enabled = true;
+        }
+    }
+    (enabled, config)
+}
+
+/// `output_folder` from a MangoHud config string (`a=1,b=2`) or file (`a=1` lines)
+fn mangohud_output_folder(config: &str) -> Option<PathBuf> {
+    config
+        .split([',', '\n'])
+        .filter_map(|entry| entry.trim().split_once('='))
+        .find(|(key, _)| key.trim() == "output_folder")
+        .map(|(_, value)| PathBuf::from(value.trim()))
+        .filter(|path| !path.as_os_str().is_empty())
+}
+
+/// Start capturing frame times from a game already running under MangoHud.
+/// Reads from MangoHud's CSV logging, so the game needs an `output_folder`
+/// and logging active (`autostart_log` or the log hotkey).
+pub fn start_frametime_capture(game_pid: u32) -> NvResult<FrametimeCapture> {
+    let proc_dir = PathBuf::from(format!("/proc/{}", game_pid));
+    if !proc_dir.exists() {
+        return Err(NvControlError::RuntimeError(format!(
+            "No running process with PID {}",
+            game_pid
+        )));
+    }
+
+    let maps = fs::read_to_string(proc_dir.join("maps")).unwrap_or_default();
+    let (env_enabled, env_config) = fs::read(proc_dir.join("environ"))
+        .map(|environ| mangohud_env(&environ))
+        .unwrap_or((false, None));
+    if !env_enabled && !maps.contains("MangoHud") {
+        return Err(NvControlError::UnsupportedFeature(format!(
+            "PID {} is not running under MangoHud; launch it with `mangohud <game>` to capture frame times",
+            game_pid
+        )));
+    }
+
+    let file_config = dirs::config_dir()
+        .map(|dir| dir.join("MangoHud/MangoHud.conf"))
+        .and_then(|path| fs::read_to_string(path).ok());
+    let log_dir = env_config
+        .as_deref()
+        .and_then(mangohud_output_folder)
+        .or_else(|| file_config.as_deref().and_then(mangohud_output_folder))
+        .ok_or_else(|| {
+            NvControlError::UnsupportedFeature(
+                "MangoHud logging is not configured; set output_folder=<dir> (and autostart_log=1) in MangoHud.conf"
+                    .to_string(),
+            )
+        })?;
+
+    Ok(FrametimeCapture {
+        pid: game_pid,
+        log_dir,
+        started_ms: chrono::Utc::now().timestamp_millis(),
+    })
+}
+
+impl FrametimeCapture {
+    /// Collect the frames MangoHud logged since the capture started
+    pub fn finish(self) -> NvResult<FrametimeReport> {
+        let finished_ms = chrono::Utc::now().timestamp_millis();
+        let entries = fs::read_dir(&self.log_dir).map_err(|e| {
+            NvControlError::UnsupportedFeature(format!(
+                "MangoHud output folder {} unreadable: {}",
+                self.log_dir.display(),
+                e
+            ))
+        })?;
+
+        let mut frames: Vec<crate::benchmark::FrameSample> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("csv"))
+            .filter(|p| !p.to_string_lossy().ends_with("_summary.csv"))
+            .filter_map(|path| {
+                let start = crate::benchmark::mangohud_log_start_ms(&path)?;
+                let contents = fs::read_to_string(&path).ok()?;
+                Some(crate::benchmark::parse_mangohud_log(&contents, start))
+            })
+            .flatten()
+            .filter(|f| (self.started_ms..=finished_ms).contains(&f.timestamp_ms))
+            .collect();
+        frames.sort_by_key(|f| f.timestamp_ms);
+
+        let frametimes: Vec<f32> = frames.iter().map(|f| f.frametime_ms).collect();
+        let stats = frametime_stats(&frametimes).ok_or_else(|| {
+            NvControlError::UnsupportedFeature(format!(
+                "MangoHud logged no frames to {} during the capture; enable autostart_log=1 or press the log hotkey (Shift_L+F2) in game",
+                self.log_dir.display()
+            ))
+        })?;
+        let stutter = detect_stutter(&frametimes);
+
+        Ok(FrametimeReport {
+            pid: self.pid,
+            frames: frames
+                .iter()
+                .zip(stutter)
+                .map(|(f, stutter)| FrametimeSample {
+                    timestamp_ms: f.timestamp_ms,
+                    frametime_ms: f.frametime_ms,
+                    stutter,
+                })
+                .collect(),
+            stats,
+        })
+    }
+}
+
+/// Find a running process that has MangoHud loaded
+pub fn find_mangohud_process() -> Option<u32> {
+    let own_pid = std::process::id();
+    fs::read_dir("/proc")
+        .ok()?
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|pid| *pid != own_pid)
+        .find(|pid| {
+            fs::read_to_string(format!("/proc/{}/maps", pid))
+                .is_ok_and(|maps| maps.contains("libMangoHud"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frametime_stats_percentiles() {
+        let frametimes: Vec<f32> = (1..=100).map(|i| i as f32).collect();
+        let stats = frametime_stats(&frametimes).unwrap();
+        assert_eq!(stats.frames, 100);
+        assert_eq!(stats.p50_ms, 50.0);
+        assert_eq!(stats.p95_ms, 95.0);
+        assert_eq!(stats.p99_ms, 99.0);
+        assert_eq!(stats.max_ms, 100.0);
+        assert!(frametime_stats(&[]).is_none());
+    }
+
+    #[test]
+    fn test_detect_stutter_against_rolling_median() {
+        let mut frametimes = vec![16.7; 40];
+        frametimes[20] = 40.0;
+        frametimes[30] = 24.0; // under 1.5x the median
+        let stutter = detect_stutter(&frametimes);
+        assert_eq!(stutter.iter().filter(|s| **s).count(), 1);
+        assert!(stutter[20]);
+        assert!(!stutter[0]);
+    }
+
+    #[test]
+    fn test_mangohud_config_parsing() {
+        let environ = b"HOME=/home/me\0MANGOHUD=1\0MANGOHUD_CONFIG=fps,output_folder=/tmp/mh,autostart_log=1\0";
+        let (enabled, config) = mangohud_env(environ);
+        assert!(enabled);
+        assert_eq!(
+            mangohud_output_folder(&config.unwrap()),
+            Some(PathBuf::from("/tmp/mh"))
+        );
+
+        let file = "fps\n# logging\noutput_folder = /home/me/mangologs\nlog_duration=30\n";
+        assert_eq!(
+            mangohud_output_folder(file),
+            Some(PathBuf::from("/home/me/mangologs"))
+        );
+        assert_eq!(mangohud_output_folder("fps,frametime"), None);
+    }
+}
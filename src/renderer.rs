@@ -0,0 +1,215 @@
+//! Which GPU OpenGL and Vulkan render on
+//!
+//! On hybrid-graphics laptops the iGPU usually renders by default and the
+//! NVIDIA GPU is only used through PRIME render offload. This reads the
+//! Vulkan device list from `vulkaninfo --summary` and the GL renderer from
+//! `glxinfo -B`, both as an app would see them and with the offload
+//! environment set, so users can confirm the dGPU is actually picked up.
+
+use serde::Serialize;
+use std::process::Command;
+
+/// PCI vendor id of NVIDIA
+const NVIDIA_VENDOR_ID: u32 = 0x10de;
+
+/// Environment that sends an app to the NVIDIA GPU under PRIME offload
+pub const PRIME_OFFLOAD_ENV: [(&str, &str); 3] = [
+    ("__NV_PRIME_RENDER_OFFLOAD", "1"),
+    ("__GLX_VENDOR_LIBRARY_NAME", "nvidia"),
+    ("__VK_LAYER_NV_optimus", "NVIDIA_only"),
+];
+
+/// One physical device from `vulkaninfo --summary`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct VulkanDevice {
+    pub name: String,
+    /// e.g. "discrete", "integrated", "cpu"
+    pub device_type: String,
+    pub vendor_id: Option<u32>,
+    pub driver_name: Option<String>,
+}
+
+impl VulkanDevice {
+    pub fn is_nvidia(&self) -> bool {
+        self.vendor_id == Some(NVIDIA_VENDOR_ID)
+    }
+}
+
+/// Vendor and renderer strings from `glxinfo -B`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GlRenderer {
+    pub vendor: String,
+    pub renderer: String,
+}
+
+impl GlRenderer {
+    pub fn is_nvidia(&self) -> bool {
+        self.vendor.contains("NVIDIA")
+    }
+}
+
+/// Renderers visible to applications
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RendererReport {
+    /// Devices in loader order; apps without a preference take the first
+    pub vulkan_devices: Vec<VulkanDevice>,
+    /// GL renderer an app gets with no extra environment
+    pub gl_default: Option<GlRenderer>,
+    /// GL renderer with [`PRIME_OFFLOAD_ENV`] set
+    pub gl_offload: Option<GlRenderer>,
+}
+
+impl RendererReport {
+    /// Name of the renderer apps get by default: the GL renderer, or the
+    /// first Vulkan device when glxinfo isn't available
+    pub fn default_renderer(&self) -> Option<&str> {
+        self.gl_default
+            .as_ref()
+            .map(|gl| gl.renderer.as_str())
+            .or_else(|| self.vulkan_devices.first().map(|d| d.name.as_str()))
+    }
+
+    /// Whether apps land on the NVIDIA GPU without offload variables
+    pub fn nvidia_is_default(&self) -> Option<bool> {
+        self.gl_default
+            .as_ref()
+            .map(GlRenderer::is_nvidia)
+            .or_else(|| self.vulkan_devices.first().map(VulkanDevice::is_nvidia))
+    }
+
+    /// `VAR=value ...` prefix for running a command on the NVIDIA GPU
+    pub fn offload_prefix() -> String {
+        PRIME_OFFLOAD_ENV
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Query Vulkan devices and GL renderers. Missing tools leave their part empty.
+pub fn detect() -> RendererReport {
+    RendererReport {
+        vulkan_devices: crate::drivers::overlay_safe_vulkaninfo_command()
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| parse_vulkan_summary(&String::from_utf8_lossy(&o.stdout)))
+            .unwrap_or_default(),
+        gl_default: glxinfo(&[]),
+        gl_offload: glxinfo(&PRIME_OFFLOAD_ENV),
+    }
+}
+
+fn glxinfo(env: &[(&str, &str)]) -> Option<GlRenderer> {
+    let output = Command::new("glxinfo")
+        .arg("-B")
+        .envs(env.iter().copied())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    parse_glxinfo(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the `Devices:` section of `vulkaninfo --summary`
+pub fn parse_vulkan_summary(output: &str) -> Vec<VulkanDevice> {
+    let mut devices = Vec::new();
+    let mut current: Option<VulkanDevice> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("GPU") && trimmed.ends_with(':') {
+            devices.extend(current.take());
+            current = Some(VulkanDevice::default());
+            continue;
+        }
+        let (Some(device), Some((key, value))) = (current.as_mut(), trimmed.split_once('=')) else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "deviceName" => device.name = value.to_string(),
+            "deviceType" => {
+                device.device_type = value
+                    .trim_start_matches("PHYSICAL_DEVICE_TYPE_")
+                    .trim_end_matches("_GPU")
+                    .replace('_', " ")
+                    .to_lowercase()
+            }
+            "vendorID" => {
+                device.vendor_id = u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+            }
+            "driverName" => device.driver_name = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    devices.extend(current);
+    devices.retain(|d| !d.name.is_empty());
+    devices
+}
+
+/// Parse the vendor and renderer strings from `glxinfo -B`
+pub fn parse_glxinfo(output: &str) -> Option<GlRenderer> {
+    let field = |prefix: &str| {
+        output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(prefix))
+            .map(|value| value.trim().to_string())
+    };
+    Some(GlRenderer {
+        vendor: field("OpenGL vendor string:")?,
+        renderer: field("OpenGL renderer string:")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vulkan_summary_and_glxinfo() {
+        let summary = "\
+Devices:
+========
+GPU0:
+\tapiVersion         = 1.3.289
+\tvendorID           = 0x8086
+\tdeviceType         = PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU
+\tdeviceName         = Intel(R) Graphics (RPL-P)
+\tdriverName         = Intel open-source Mesa driver
+GPU1:
+\tvendorID           = 0x10de
+\tdeviceType         = PHYSICAL_DEVICE_TYPE_DISCRETE_GPU
+\tdeviceName         = NVIDIA GeForce RTX 4070 Laptop GPU
+\tdriverName         = NVIDIA
+";
+        let devices = parse_vulkan_summary(summary);
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].device_type, "integrated");
+        assert!(!devices[0].is_nvidia());
+        assert_eq!(devices[1].name, "NVIDIA GeForce RTX 4070 Laptop GPU");
+        assert_eq!(devices[1].device_type, "discrete");
+        assert!(devices[1].is_nvidia());
+
+        let gl = parse_glxinfo(
+            "name of display: :0\n\
+             OpenGL vendor string: Intel\n\
+             OpenGL renderer string: Mesa Intel(R) Graphics (RPL-P)\n",
+        )
+        .unwrap();
+        assert!(!gl.is_nvidia());
+        assert!(parse_glxinfo("Error: unable to open display").is_none());
+
+        let report = RendererReport {
+            vulkan_devices: devices,
+            gl_default: Some(gl),
+            gl_offload: None,
+        };
+        assert_eq!(
+            report.default_renderer(),
+            Some("Mesa Intel(R) Graphics (RPL-P)")
+        );
+        assert_eq!(report.nvidia_is_default(), Some(false));
+        assert!(RendererReport::offload_prefix().starts_with("__NV_PRIME_RENDER_OFFLOAD=1 "));
+    }
+}
@@ -0,0 +1,243 @@
+//! Diagnostics report for bug reports
+//!
+//! Gathers GPU stats, driver capabilities, kernel driver details, the running
+//! compositor and system validation results into one markdown document, with
+//! the hostname, user name and home directory redacted.
+
+use crate::drivers::DriverCapabilities;
+use crate::kernel_driver::KernelDriverInfo;
+use crate::nvml_backend::NvmlBackend;
+use crate::system_validation::SystemValidation;
+use crate::wayland_nvidia;
+use crate::{NvControlError, NvResult};
+use std::fmt::Write as _;
+
+/// Build the full markdown report and redact identifying details
+pub fn build_report(backend: &dyn NvmlBackend) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# nvcontrol diagnostics report");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "- nvcontrol: v{}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        out,
+        "- Generated: {}",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M UTC")
+    );
+    let _ = writeln!(
+        out,
+        "- Compositor: {}",
+        wayland_nvidia::detect_compositor().name()
+    );
+
+    write_gpu_section(&mut out, backend);
+    write_driver_section(&mut out);
+    write_kernel_section(&mut out);
+    write_validation_section(&mut out);
+
+    redact(&out)
+}
+
+fn write_gpu_section(out: &mut String, backend: &dyn NvmlBackend) {
+    let _ = writeln!(out, "\n## GPUs\n");
+    let count = match backend.device_count() {
+        Ok(count) if count > 0 => count,
+        Ok(_) => {
+            let _ = writeln!(out, "No NVIDIA GPUs detected");
+            return;
+        }
+        Err(e) => {
+            let _ = writeln!(out, "NVML unavailable: {}", first_line(&e.to_string()));
+            return;
+        }
+    };
+
+    let _ = writeln!(
+        out,
+        "| # | Name | Temp | Power | Core | Memory | Util | VRAM |"
+    );
+    let _ = writeln!(out, "|---|---|---|---|---|---|---|---|");
+    for index in 0..count {
+        let name = backend
+            .get_name(index)
+            .unwrap_or_else(|_| "unknown".to_string());
+        let temp = opt(backend.get_temperature(index).ok(), "°C");
+        let power = opt(
+            backend.get_power_usage(index).ok().map(|mw| mw / 1000),
+            " W",
+        );
+        let core = opt(backend.get_gpu_clock(index).ok(), " MHz");
+        let memory = opt(backend.get_memory_clock(index).ok(), " MHz");
+        let util = opt(backend.get_utilization(index).ok().map(|(gpu, _)| gpu), "%");
+        let vram = backend
+            .get_memory_info(index)
+            .map(|(used, total)| format!("{} / {} MiB", used >> 20, total >> 20))
+            .unwrap_or_else(|_| "n/a".to_string());
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {} | {} |",
+            index, name, temp, power, core, memory, util, vram
+        );
+    }
+}
+
+fn write_driver_section(out: &mut String) {
+    let _ = writeln!(out, "\n## Driver\n");
+    match DriverCapabilities::detect() {
+        Ok(caps) => {
+            let _ = writeln!(
+                out,
+                "- Version: {}{}",
+                caps.version,
+                if caps.is_beta { " (beta)" } else { "" }
+            );
+            for (label, present) in [
+                ("Vulkan swapchain perf", caps.has_vulkan_swapchain_perf),
+                ("USB4 DisplayPort", caps.has_usb4_dp_support),
+                ("PREEMPT_RT", caps.supports_preempt_rt),
+                ("PowerMizer Wayland fix", caps.has_powermizer_wayland_fix),
+                ("DLSS preset override", caps.has_dlss_preset_override),
+                ("Vulkan device groups", caps.has_vulkan_device_group),
+                ("FP16 EGL on Wayland", caps.has_fp16_egl_wayland),
+                ("dma-buf mmap", caps.has_dmabuf_mmap),
+                ("DRM color pipeline", caps.has_drm_color_pipeline),
+            ] {
+                let _ = writeln!(out, "- {}: {}", label, yes_no(present));
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(
+                out,
+                "Driver detection failed: {}",
+                first_line(&e.to_string())
+            );
+        }
+    }
+}
+
+fn write_kernel_section(out: &mut String) {
+    let _ = writeln!(out, "\n## Kernel driver\n");
+    match KernelDriverInfo::detect() {
+        Ok(info) => {
+            let _ = writeln!(out, "- Kernel: {}", info.kernel_version);
+            let _ = writeln!(out, "- Module type: {:?}", info.driver_type);
+            let _ = writeln!(
+                out,
+                "- GSP firmware: {}",
+                info.gsp_firmware_version.as_deref().unwrap_or("not loaded")
+            );
+            let _ = writeln!(out, "- Loaded modules: {}", info.loaded_modules.join(", "));
+        }
+        Err(e) => {
+            let _ = writeln!(
+                out,
+                "Kernel driver detection failed: {}",
+                first_line(&e.to_string())
+            );
+        }
+    }
+}
+
+fn write_validation_section(out: &mut String) {
+    let _ = writeln!(out, "\n## System validation\n");
+    match SystemValidation::validate_for_rtx_50() {
+        Ok(v) => {
+            let _ = writeln!(
+                out,
+                "- Resizable BAR: {} ({} GB)",
+                yes_no(v.rebar_enabled),
+                v.rebar_size_gb
+            );
+            let _ = writeln!(out, "- Above 4G decoding: {}", yes_no(v.above_4g_decoding));
+            let _ = writeln!(
+                out,
+                "- PCIe: Gen {} x{} ({:.1} GT/s)",
+                v.pcie_generation, v.pcie_lanes, v.pcie_speed_gts
+            );
+            let _ = writeln!(out, "- IOMMU: {}", yes_no(v.iommu_enabled));
+            let _ = writeln!(out, "- Secure Boot: {}", yes_no(v.secure_boot_enabled));
+            for warning in &v.warnings {
+                let _ = writeln!(out, "- ⚠️ {}", warning);
+            }
+            for error in &v.errors {
+                let _ = writeln!(out, "- ❌ {}", error);
+            }
+        }
+        Err(e) => {
+            let _ = writeln!(out, "Validation failed: {}", first_line(&e.to_string()));
+        }
+    }
+}
+
+fn opt(value: Option<u32>, unit: &str) -> String {
+    value
+        .map(|v| format!("{}{}", v, unit))
+        .unwrap_or_else(|| "n/a".to_string())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+/// Error messages carry multi-line hints; the report only needs the cause
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or(text)
+}
+
+/// Redact this machine's hostname, user name and home directory
+pub fn redact(text: &str) -> String {
+    let hostname = std::fs::read_to_string("/etc/hostname")
+        .map(|h| h.trim().to_string())
+        .unwrap_or_default();
+    let user = std::env::var("USER").unwrap_or_default();
+    let home = dirs::home_dir()
+        .map(|h| h.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    redact_with(text, &hostname, &user, &home)
+}
+
+fn redact_with(text: &str, hostname: &str, user: &str, home: &str) -> String {
+    let mut out = text.to_string();
+    // Home first so "/home/<user>" collapses to "~" rather than "/home/<user>"
+    if home.len() > 1 {
+        out = out.replace(home, "~");
+    }
+    // Very short names would match inside ordinary words
+    if hostname.len() >= 3 {
+        out = out.replace(hostname, "<hostname>");
+    }
+    if user.len() >= 3 {
+        out = out.replace(user, "<user>");
+    }
+    out
+}
+
+/// Put the report on the system clipboard.
+///
+/// X11 and Wayland clipboards are served by the owning process, so this
+/// waits briefly for a clipboard manager to take over before returning.
+pub fn copy_to_clipboard(text: &str) -> NvResult<()> {
+    use arboard::SetExtLinux;
+
+    let mut clipboard = arboard::Clipboard::new()
+        .map_err(|e| NvControlError::RuntimeError(format!("Clipboard unavailable: {}", e)))?;
+    clipboard
+        .set()
+        .wait_until(std::time::Instant::now() + std::time::Duration::from_secs(2))
+        .text(text)
+        .map_err(|e| NvControlError::RuntimeError(format!("Clipboard copy failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_identifying_details() {
+        let text = "host gamerig user alice at /home/alice/.config, ok";
+        let redacted = redact_with(text, "gamerig", "alice", "/home/alice");
+        assert_eq!(redacted, "host <hostname> user <user> at ~/.config, ok");
+
+        // Short names are left alone rather than mangling unrelated words
+        assert_eq!(redact_with("ok bob", "ok", "", ""), "ok bob");
+    }
+}
@@ -1,6 +1,9 @@
-use crate::NvResult;
+use crate::nvml_backend::SharedNvmlBackend;
+use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 /// RGB/ARGB LED control for ASUS Aura Sync, OpenRGB, and other RGB systems
 /// Supports ASUS ROG Astral and other RGB-enabled GPUs
@@ -52,13 +55,32 @@ pub enum RgbMode {
     Off,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RgbColor {
     pub r: u8,
     pub g: u8,
     pub b: u8,
 }
 
+/// GPU lighting a game profile switches to while the game runs
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RgbSettings {
+    pub mode: RgbMode,
+    pub color: RgbColor,
+}
+
+/// Best-effort RGB commands don't fail the caller but leave a trace in the log
+fn log_if_failed(tool: &str, result: std::io::Result<std::process::Output>) {
+    match result {
+        Ok(output) if !output.status.success() => log::warn!(
+            "{tool} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => log::warn!("{tool} could not be run: {e}"),
+        Ok(_) => {}
+    }
+}
+
 impl RgbController {
     /// Detect available RGB control backend
     pub fn new() -> NvResult<Self> {
@@ -159,7 +181,7 @@ impl RgbController {
             RgbBackend::OpenRGB => self.set_openrgb_mode(mode, color),
             RgbBackend::AsusAuraCore => self.set_asus_mode(mode, color),
             _ => {
-                println!("No RGB backend available");
+                log::warn!("No RGB backend available");
                 Ok(())
             }
         }
@@ -178,12 +200,13 @@ impl RgbController {
 
         let color_str = format!("{:02X}{:02X}{:02X}", color.r, color.g, color.b);
 
-        let _ = Command::new("openrgb")
-            .args([
+        log_if_failed(
+            "openrgb",
+            crate::gpu_safe::execute(Command::new("openrgb").args([
                 "--mode", mode_str, "--color", &color_str, "--device",
                 "0", // GPU typically device 0
-            ])
-            .output();
+            ])),
+        );
 
         // Update internal state
         for device in &mut self.devices {
@@ -206,24 +229,36 @@ impl RgbController {
             _ => "static",
         };
 
-        let _ = Command::new("asusctl")
-            .args([
+        log_if_failed(
+            "asusctl",
+            crate::gpu_safe::execute(Command::new("asusctl").args([
                 "led-mode",
                 "-m",
                 mode_str,
                 "-c",
                 &format!("{},{},{}", color.r, color.g, color.b),
-            ])
-            .output();
+            ])),
+        );
 
         Ok(())
     }
 
+    /// Mode and color the GPU lighting was last set to
+    pub fn gpu_state(&self) -> Option<RgbSettings> {
+        self.devices
+            .iter()
+            .find(|device| device.device_type == DeviceType::GPU)
+            .map(|device| RgbSettings {
+                mode: device.current_mode.clone(),
+                color: device.current_color,
+            })
+    }
+
     /// Set GPU temperature reactive colors
     pub fn set_temp_reactive(&mut self) -> NvResult<()> {
         // This would need to be implemented as a background service
         // that monitors GPU temp and adjusts colors accordingly
-        println!("Temperature reactive mode set (requires background service)");
+        log::info!("Temperature reactive mode set (requires background service)");
 
         for device in &mut self.devices {
             if device.device_type == DeviceType::GPU {
@@ -289,6 +324,325 @@ impl RgbController {
     }
 }
 
+/// GPU lighting switched for a running game, holding what to put back
+pub struct RgbOverride {
+    controller: RgbController,
+    previous: RgbSettings,
+}
+
+impl RgbOverride {
+    /// Switch GPU lighting to `settings`, remembering the current state.
+    /// Returns `None` without touching anything when no RGB controller or
+    /// GPU device is available.
+    pub fn begin(settings: &RgbSettings) -> Option<Self> {
+        match RgbController::new() {
+            Ok(controller) => Self::begin_with(controller, settings),
+            Err(e) => {
+                log::debug!("No RGB controller for game lighting: {e}");
+                None
+            }
+        }
+    }
+
+    fn begin_with(controller: RgbController, settings: &RgbSettings) -> Option<Self> {
+        let previous = controller.gpu_state()?;
+        let mut session = Self {
+            controller,
+            previous,
+        };
+        session.set(settings);
+        Some(session)
+    }
+
+    /// Lighting captured before the game started
+    pub fn previous(&self) -> &RgbSettings {
+        &self.previous
+    }
+
+    /// Move a running override to another game's lighting, keeping the
+    /// original pre-game state for the final restore. Without new settings
+    /// the lighting is restored now.
+    pub fn switch(current: Option<Self>, settings: Option<&RgbSettings>) -> Option<Self> {
+        match (current, settings) {
+            (Some(mut session), Some(settings)) => {
+                session.set(settings);
+                Some(session)
+            }
+            (Some(session), None) => {
+                session.restore();
+                None
+            }
+            (None, Some(settings)) => Self::begin(settings),
+            (None, None) => None,
+        }
+    }
+
+    fn set(&mut self, settings: &RgbSettings) {
+        if let Err(e) = self
+            .controller
+            .set_gpu_mode(settings.mode.clone(), settings.color)
+        {
+            log::warn!("Failed to set game RGB lighting: {e}");
+        }
+    }
+
+    /// Put the GPU lighting back to its pre-game state
+    pub fn restore(mut self) {
+        let previous = self.previous.clone();
+        self.set(&previous);
+    }
+}
+
+/// Cold→hot color gradient used by system-wide temperature sync
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TempGradient {
+    pub cold: RgbColor,
+    pub hot: RgbColor,
+    /// At or below this temperature (°C) zones show `cold`
+    pub cold_temp: u32,
+    /// At or above this temperature (°C) zones show `hot`
+    pub hot_temp: u32,
+}
+
+impl Default for TempGradient {
+    fn default() -> Self {
+        Self {
+            cold: RgbColor {
+                r: 0,
+                g: 150,
+                b: 255,
+            },
+            hot: RgbColor { r: 255, g: 0, b: 0 },
+            cold_temp: 40,
+            hot_temp: 85,
+        }
+    }
+}
+
+impl TempGradient {
+    /// Linearly interpolate between the endpoints
+    pub fn color_at(&self, temp_celsius: u32) -> RgbColor {
+        if self.hot_temp <= self.cold_temp {
+            return if temp_celsius >= self.hot_temp {
+                self.hot
+            } else {
+                self.cold
+            };
+        }
+
+        let t = (temp_celsius.clamp(self.cold_temp, self.hot_temp) - self.cold_temp) as f32
+            / (self.hot_temp - self.cold_temp) as f32;
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        RgbColor {
+            r: mix(self.cold.r, self.hot.r),
+            g: mix(self.cold.g, self.hot.g),
+            b: mix(self.cold.b, self.hot.b),
+        }
+    }
+}
+
+/// Where a sync zone's color is sent
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncTarget {
+    /// OpenRGB device index (covers Aura motherboard/RAM/GPU zones on Linux)
+    OpenRgb(u32),
+    /// asusctl keyboard/chassis lighting
+    AsusCtl,
+}
+
+/// A lighting zone found during system sync discovery
+#[derive(Debug, Clone)]
+pub struct SyncZone {
+    pub name: String,
+    pub device_type: DeviceType,
+    pub target: SyncTarget,
+}
+
+/// Zones that will follow GPU temperature, and those that can't
+#[derive(Debug, Clone, Default)]
+pub struct SystemSyncReport {
+    pub synced: Vec<SyncZone>,
+    /// Zone name and why it was skipped
+    pub skipped: Vec<(String, String)>,
+}
+
+/// One device from `openrgb --list-devices`
+#[derive(Debug, Clone, PartialEq)]
+struct OpenRgbListing {
+    index: u32,
+    name: String,
+    device_type: DeviceType,
+    modes: Vec<String>,
+}
+
+/// Parse the device blocks printed by `openrgb --list-devices`
+fn parse_openrgb_listing(output: &str) -> Vec<OpenRgbListing> {
+    let mut devices: Vec<OpenRgbListing> = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if let Some((index, name)) = trimmed
+            .split_once(':')
+            .filter(|_| !line.starts_with(char::is_whitespace))
+            .and_then(|(i, n)| i.trim().parse::<u32>().ok().map(|i| (i, n.trim())))
+        {
+            devices.push(OpenRgbListing {
+                index,
+                name: name.to_string(),
+                device_type: DeviceType::Unknown,
+                modes: Vec::new(),
+            });
+            continue;
+        }
+
+        let Some(device) = devices.last_mut() else {
+            continue;
+        };
+        if let Some(kind) = trimmed.strip_prefix("Type:") {
+            device.device_type = match kind.trim().to_lowercase().as_str() {
+                "gpu" => DeviceType::GPU,
+                "motherboard" => DeviceType::Motherboard,
+                "dram" => DeviceType::Ram,
+                "cooler" | "fan" => DeviceType::Fan,
+                "ledstrip" | "led strip" => DeviceType::Strip,
+                _ => DeviceType::Unknown,
+            };
+        } else if let Some(modes) = trimmed.strip_prefix("Modes:") {
+            // The active mode is shown in brackets, e.g. [Static]
+            device.modes = modes
+                .split_whitespace()
+                .map(|m| {
+                    m.trim_matches(|c| c == '[' || c == ']' || c == '\'')
+                        .to_string()
+                })
+                .collect();
+        }
+    }
+
+    devices
+}
+
+impl SystemSyncReport {
+    /// Find every zone across OpenRGB and asusctl that can take a direct color
+    pub fn discover() -> Self {
+        let mut report = Self::default();
+
+        if let Ok(output) = Command::new("openrgb").arg("--list-devices").output() {
+            for device in parse_openrgb_listing(&String::from_utf8_lossy(&output.stdout)) {
+                if device
+                    .modes
+                    .iter()
+                    .any(|m| m.eq_ignore_ascii_case("direct") || m.eq_ignore_ascii_case("static"))
+                {
+                    report.synced.push(SyncZone {
+                        name: device.name,
+                        device_type: device.device_type,
+                        target: SyncTarget::OpenRgb(device.index),
+                    });
+                } else {
+                    report
+                        .skipped
+                        .push((device.name, "no direct/static color mode".to_string()));
+                }
+            }
+        }
+
+        if Command::new("asusctl")
+            .args(["led-mode", "-l"])
+            .output()
+            .is_ok_and(|o| o.status.success())
+        {
+            report.synced.push(SyncZone {
+                name: "asusctl Aura".to_string(),
+                device_type: DeviceType::Unknown,
+                target: SyncTarget::AsusCtl,
+            });
+        }
+
+        report
+    }
+}
+
+fn apply_zone_color(zone: &SyncZone, color: RgbColor) -> NvResult<()> {
+    let hex = format!("{:02X}{:02X}{:02X}", color.r, color.g, color.b);
+    let mut cmd = match zone.target {
+        SyncTarget::OpenRgb(index) => {
+            let mut cmd = Command::new("openrgb");
+            cmd.args(["--device", &index.to_string(), "--mode", "static"])
+                .args(["--color", &hex]);
+            cmd
+        }
+        SyncTarget::AsusCtl => {
+            let mut cmd = Command::new("asusctl");
+            cmd.args(["led-mode", "static", "-c", &hex]);
+            cmd
+        }
+    };
+
+    let output = crate::gpu_safe::execute(&mut cmd)
+        .map_err(|e| NvControlError::CommandFailed(format!("{}: {}", zone.name, e)))?;
+    if !output.status.success() {
+        return Err(NvControlError::CommandFailed(format!(
+            "{}: {}",
+            zone.name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    log::debug!("RGB zone {} set to #{hex}", zone.name);
+    Ok(())
+}
+
+/// Drive every controllable RGB zone (Aura motherboard/RAM via OpenRGB,
+/// OpenRGB devices, asusctl) from a single GPU temperature poll until `stop`
+/// is raised. Zones are only rewritten when the gradient color changes.
+pub fn set_system_temp_reactive(
+    backend: &SharedNvmlBackend,
+    gpu_index: u32,
+    gradient: TempGradient,
+    interval: Duration,
+    stop: &AtomicBool,
+    on_discovered: impl FnOnce(&SystemSyncReport),
+) -> NvResult<SystemSyncReport> {
+    let mut report = SystemSyncReport::discover();
+    if report.synced.is_empty() {
+        return Err(NvControlError::UnsupportedFeature(
+            "No RGB zones support direct color (install OpenRGB or asusctl)".to_string(),
+        ));
+    }
+    on_discovered(&report);
+
+    let mut last_color: Option<RgbColor> = None;
+    while !stop.load(Ordering::SeqCst) {
+        let temp = backend.get_temperature(gpu_index)?;
+        let color = gradient.color_at(temp);
+
+        if last_color.is_none_or(|c| (c.r, c.g, c.b) != (color.r, color.g, color.b)) {
+            // A zone that stops responding is dropped rather than retried every tick
+            let mut failed = Vec::new();
+            report
+                .synced
+                .retain(|zone| match apply_zone_color(zone, color) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        failed.push((zone.name.clone(), e.to_string()));
+                        false
+                    }
+                });
+            report.skipped.extend(failed);
+            if report.synced.is_empty() {
+                return Err(NvControlError::CommandFailed(
+                    "All RGB zones failed to accept a color".to_string(),
+                ));
+            }
+            last_color = Some(color);
+        }
+
+        std::thread::sleep(interval);
+    }
+
+    Ok(report)
+}
+
 /// Preset RGB profiles for ASUS ROG Astral
 pub fn get_astral_presets() -> Vec<(String, RgbMode, RgbColor)> {
     vec![
@@ -346,6 +700,76 @@ mod tests {
         assert_eq!(hot.r, 255); // Should be red
     }
 
+    #[test]
+    fn test_temp_gradient_interpolates() {
+        let gradient = TempGradient {
+            cold: RgbColor { r: 0, g: 0, b: 200 },
+            hot: RgbColor { r: 200, g: 0, b: 0 },
+            cold_temp: 40,
+            hot_temp: 80,
+        };
+        assert_eq!(gradient.color_at(20).b, 200);
+        assert_eq!(gradient.color_at(95).r, 200);
+
+        let mid = gradient.color_at(60);
+        assert_eq!((mid.r, mid.b), (100, 100));
+    }
+
+    #[test]
+    fn test_parse_openrgb_listing() {
+        let output = "\
+0: ASUS ROG STRIX Z790-E
+  Type:           Motherboard
+  Description:    ASUS Aura Motherboard Device
+  Modes: Direct [Static] Breathing 'Spectrum Cycle'
+1: Corsair Vengeance Pro RGB
+  Type:           DRAM
+  Modes: [Direct] Static Rainbow
+2: Logitech G502
+  Type:           Mouse
+  Modes: [Off]
+";
+        let devices = parse_openrgb_listing(output);
+        assert_eq!(devices.len(), 3);
+        assert_eq!(devices[0].device_type, DeviceType::Motherboard);
+        assert!(devices[0].modes.contains(&"Static".to_string()));
+        assert_eq!(devices[1].index, 1);
+        assert_eq!(devices[1].device_type, DeviceType::Ram);
+        assert_eq!(devices[2].modes, vec!["Off".to_string()]);
+    }
+
+    #[test]
+    fn test_rgb_override_captures_prior_state() {
+        let blue = RgbSettings {
+            mode: RgbMode::Breathing,
+            color: RgbColor { r: 0, g: 0, b: 255 },
+        };
+        let red = RgbSettings {
+            mode: RgbMode::Static,
+            color: RgbColor { r: 255, g: 0, b: 0 },
+        };
+        let controller = |devices: Vec<RgbDevice>| RgbController {
+            backend: RgbBackend::None,
+            devices,
+        };
+        let gpu = RgbDevice {
+            name: "GPU".to_string(),
+            device_type: DeviceType::GPU,
+            num_leds: 1,
+            supports_modes: vec![RgbMode::Static, RgbMode::Breathing],
+            current_mode: blue.mode.clone(),
+            current_color: blue.color,
+        };
+
+        let session = RgbOverride::begin_with(controller(vec![gpu]), &red).unwrap();
+        assert_eq!(session.previous(), &blue);
+
+        // Nothing to drive: skipped rather than failing
+        assert!(RgbOverride::begin_with(controller(Vec::new()), &red).is_none());
+        assert!(RgbOverride::switch(None, None).is_none());
+        assert!(RgbOverride::switch(Some(session), None).is_none());
+    }
+
     #[test]
     fn test_astral_presets() {
         let presets = get_astral_presets();
@@ -356,6 +356,380 @@ pub fn run_readiness_check() -> NvResult<()> {
     Ok(())
 }
 
+const MODESET_PARAM_PATH: &str = "/sys/module/nvidia_drm/parameters/modeset";
+const MODESET_CONF_PATH: &str = "/etc/modprobe.d/nvidia-drm-modeset.conf";
+
+/// Result of one setup wizard check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+        }
+    }
+}
+
+/// One step of the `nvctl setup` wizard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WizardStep {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// Exact command that resolves the issue, or an instruction when
+    /// `fixable` is false (e.g. a firmware setting)
+    pub remediation: Option<String>,
+    /// Whether the wizard can run `remediation` itself
+    pub fixable: bool,
+}
+
+impl WizardStep {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            remediation: None,
+            fixable: false,
+        }
+    }
+
+    fn issue(
+        name: &str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        remediation: impl Into<String>,
+        fixable: bool,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+            remediation: Some(remediation.into()),
+            fixable,
+        }
+    }
+}
+
+/// Run every wizard check against the live system
+pub fn collect_wizard_steps() -> Vec<WizardStep> {
+    let session = std::env::var("XDG_SESSION_TYPE").unwrap_or_default();
+    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+    let devices = [
+        collect_device_access("/dev/nvidiactl", "NVIDIA control device"),
+        collect_device_access("/dev/nvidia0", "Primary GPU device"),
+        collect_device_access("/dev/nvidia-modeset", "NVKMS vibrance/display control"),
+    ];
+
+    vec![
+        driver_step(drivers::get_driver_status().ok().as_ref()),
+        modeset_step(
+            fs::read_to_string(MODESET_PARAM_PATH).ok().as_deref(),
+            Path::new(MODESET_CONF_PATH).exists(),
+            drivers::detect_initramfs_tool().as_deref(),
+        ),
+        device_access_step(&devices),
+        video_group_step(),
+        compositor_step(&session, &desktop),
+        rebar_step(
+            crate::system_validation::SystemValidation::detect()
+                .ok()
+                .as_ref(),
+        ),
+    ]
+}
+
+fn driver_step(status: Option<&drivers::DriverStatus>) -> WizardStep {
+    const NAME: &str = "Driver";
+    match status {
+        Some(status) if status.current_version != "Unknown" => {
+            if status.kernel_modules.is_empty() {
+                WizardStep::issue(
+                    NAME,
+                    CheckStatus::Fail,
+                    format!(
+                        "Driver {} is installed but no NVIDIA kernel module is loaded",
+                        status.current_version
+                    ),
+                    "sudo modprobe nvidia",
+                    true,
+                )
+            } else {
+                WizardStep::pass(
+                    NAME,
+                    format!("{} ({})", status.current_version, status.driver_type),
+                )
+            }
+        }
+        _ => WizardStep::issue(
+            NAME,
+            CheckStatus::Fail,
+            "nvidia-smi did not report a driver; the NVIDIA driver is missing or not working",
+            "sudo nvctl driver install open",
+            true,
+        ),
+    }
+}
+
+fn initramfs_rebuild_command(tool: Option<&str>) -> Option<&'static str> {
+    match tool? {
+        "mkinitcpio" => Some("sudo mkinitcpio -P"),
+        "dracut" => Some("sudo dracut --regenerate-all --force"),
+        "update-initramfs" => Some("sudo update-initramfs -u -k all"),
+        _ => None,
+    }
+}
+
+fn modeset_step(param: Option<&str>, configured: bool, initramfs: Option<&str>) -> WizardStep {
+    const NAME: &str = "Kernel modeset";
+    let rebuild = initramfs_rebuild_command(initramfs);
+
+    if param.map(str::trim) == Some("Y") {
+        return WizardStep::pass(NAME, "nvidia_drm.modeset=1 is active");
+    }
+
+    if configured {
+        return match rebuild {
+            Some(command) => WizardStep::issue(
+                NAME,
+                CheckStatus::Warn,
+                format!(
+                    "modeset=1 is set in {MODESET_CONF_PATH} but not active yet; rebuild the initramfs and reboot"
+                ),
+                command,
+                true,
+            ),
+            None => WizardStep::issue(
+                NAME,
+                CheckStatus::Warn,
+                format!("modeset=1 is set in {MODESET_CONF_PATH} but not active yet"),
+                "Reboot to load nvidia_drm with the new options",
+                false,
+            ),
+        };
+    }
+
+    let write_config = format!(
+        "echo 'options nvidia_drm modeset=1 fbdev=1' | sudo tee {MODESET_CONF_PATH} > /dev/null"
+    );
+    let command = match rebuild {
+        Some(rebuild) => format!("{write_config} && {rebuild}"),
+        None => write_config,
+    };
+    let (status, detail) = match param {
+        Some(_) => (
+            CheckStatus::Fail,
+            "nvidia_drm.modeset is off; Wayland, vibrance and VRR need it (reboot after fixing)",
+        ),
+        None => (
+            CheckStatus::Warn,
+            "nvidia_drm is not loaded, so modeset can't be checked (reboot after fixing)",
+        ),
+    };
+    WizardStep::issue(NAME, status, detail, command, true)
+}
+
+fn device_access_step(devices: &[DeviceAccessReport]) -> WizardStep {
+    const NAME: &str = "Device permissions";
+    let missing: Vec<&str> = devices
+        .iter()
+        .filter(|device| device.status == "missing")
+        .map(|device| device.path.as_str())
+        .collect();
+    let limited: Vec<&str> = devices
+        .iter()
+        .filter(|device| device.status != "missing" && device.status != "read/write")
+        .map(|device| device.path.as_str())
+        .collect();
+
+    if !missing.is_empty() {
+        WizardStep::issue(
+            NAME,
+            CheckStatus::Fail,
+            format!("Missing device nodes: {}", missing.join(", ")),
+            "sudo modprobe -a nvidia nvidia_modeset",
+            true,
+        )
+    } else if !limited.is_empty() {
+        WizardStep::issue(
+            NAME,
+            CheckStatus::Warn,
+            format!("Limited access to {}", limited.join(", ")),
+            "sudo nvctl setup permissions",
+            true,
+        )
+    } else {
+        WizardStep::pass(
+            NAME,
+            format!("{} device nodes are read/write", devices.len()),
+        )
+    }
+}
+
+fn video_group_step() -> WizardStep {
+    let user = nix::unistd::User::from_uid(nix::unistd::getuid())
+        .ok()
+        .flatten()
+        .map(|user| user.name)
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_default();
+    let group = nix::unistd::Group::from_name("video").ok().flatten();
+
+    let (active, listed) = match &group {
+        Some(group) => (
+            nix::unistd::getgroups()
+                .map(|gids| gids.contains(&group.gid))
+                .unwrap_or(false),
+            group.mem.contains(&user),
+        ),
+        None => (false, false),
+    };
+    video_group_status(&user, group.is_some(), active, listed)
+}
+
+fn video_group_status(user: &str, group_exists: bool, active: bool, listed: bool) -> WizardStep {
+    const NAME: &str = "Video group";
+    if !group_exists {
+        WizardStep::pass(NAME, "No 'video' group on this system; not needed")
+    } else if active {
+        WizardStep::pass(NAME, format!("{user} is in the 'video' group"))
+    } else if listed {
+        WizardStep::issue(
+            NAME,
+            CheckStatus::Warn,
+            format!("{user} was added to 'video' but this session predates it"),
+            "Log out and back in to pick up the new group",
+            false,
+        )
+    } else {
+        WizardStep::issue(
+            NAME,
+            CheckStatus::Warn,
+            format!("{user} is not in the 'video' group"),
+            format!("sudo usermod -aG video {user}"),
+            true,
+        )
+    }
+}
+
+fn compositor_step(session: &str, desktop: &str) -> WizardStep {
+    const NAME: &str = "Compositor";
+    let desktop = if desktop.is_empty() {
+        "unknown desktop"
+    } else {
+        desktop
+    };
+    match session.to_lowercase().as_str() {
+        "wayland" => WizardStep::pass(NAME, format!("{desktop} on Wayland")),
+        "x11" => WizardStep::pass(NAME, format!("{desktop} on X11")),
+        _ => WizardStep::issue(
+            NAME,
+            CheckStatus::Warn,
+            "No graphical session detected; display features need Wayland or X11",
+            "Run nvctl setup from a terminal inside your desktop session",
+            false,
+        ),
+    }
+}
+
+fn rebar_step(validation: Option<&crate::system_validation::SystemValidation>) -> WizardStep {
+    const NAME: &str = "Resizable BAR";
+    match validation {
+        Some(validation) if validation.rebar_enabled => WizardStep::pass(
+            NAME,
+            format!("Active ({} GB BAR)", validation.rebar_size_gb),
+        ),
+        Some(validation) if !validation.gpu_bar_regions.is_empty() => WizardStep::issue(
+            NAME,
+            CheckStatus::Warn,
+            "Resizable BAR is off; RTX 50-series cards require it",
+            "Enable Above 4G Decoding and Resizable BAR in your firmware (BIOS/UEFI) setup",
+            false,
+        ),
+        _ => WizardStep::issue(
+            NAME,
+            CheckStatus::Warn,
+            "Could not read the GPU's BAR layout",
+            "Install pciutils (lspci) and re-run nvctl setup",
+            false,
+        ),
+    }
+}
+
+/// Guided first-run setup: print every check with its remediation, then
+/// offer to run each fix. `confirm` is asked once per fix; pass `None` to
+/// only report. Fixes respect `--dry-run`.
+pub fn run_wizard(mut confirm: Option<&mut dyn FnMut(&str) -> bool>) -> NvResult<()> {
+    println!("nvcontrol setup wizard");
+    println!("======================");
+    println!();
+
+    let steps = collect_wizard_steps();
+    for step in &steps {
+        println!("[{}] {}: {}", step.status.label(), step.name, step.detail);
+        if let Some(remediation) = &step.remediation {
+            let prefix = if step.fixable { "Fix" } else { "Action" };
+            println!("       {prefix}: {remediation}");
+        }
+    }
+
+    let issues = steps
+        .iter()
+        .filter(|step| step.status != CheckStatus::Pass)
+        .count();
+    println!();
+    if issues == 0 {
+        println!("All checks passed. Next: nvctl gpu info");
+        return Ok(());
+    }
+    println!("{issues} issue(s) found.");
+
+    let Some(confirm) = confirm.as_mut() else {
+        println!("Run `nvctl setup` in a terminal to apply the fixes interactively.");
+        return Ok(());
+    };
+
+    let mut applied = 0;
+    for step in steps.iter().filter(|step| step.fixable) {
+        let Some(command) = &step.remediation else {
+            continue;
+        };
+        println!();
+        println!("{}: {}", step.name, command);
+        if !confirm(&format!("Run this fix for '{}'?", step.name)) {
+            println!("  Skipped");
+            continue;
+        }
+        if !crate::gpu_safe::should_apply(&format!("run: {command}")) {
+            continue;
+        }
+        match Command::new("sh").arg("-c").arg(command).status() {
+            Ok(status) if status.success() => {
+                applied += 1;
+                println!("  Done");
+            }
+            Ok(status) => println!("  Failed ({status})"),
+            Err(e) => println!("  Failed to run: {e}"),
+        }
+    }
+
+    if applied > 0 {
+        println!();
+        println!(
+            "Applied {applied} fix(es). Reboot after driver or modeset changes, then re-run `nvctl setup` to confirm."
+        );
+    }
+
+    Ok(())
+}
+
 fn command_exists(command: &str) -> bool {
     std::env::var_os("PATH")
         .map(|paths| std::env::split_paths(&paths).any(|path| path.join(command).is_file()))
@@ -446,3 +820,58 @@ pub fn remove_setup() -> NvResult<()> {
     println!("✅ Cleanup complete");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modeset_step_builds_fix_command() {
+        assert_eq!(
+            modeset_step(Some("Y\n"), false, None).status,
+            CheckStatus::Pass
+        );
+
+        let off = modeset_step(Some("N\n"), false, Some("mkinitcpio"));
+        assert_eq!(off.status, CheckStatus::Fail);
+        assert!(off.fixable);
+        let command = off.remediation.unwrap();
+        assert!(command.contains("options nvidia_drm modeset=1"));
+        assert!(command.ends_with("&& sudo mkinitcpio -P"));
+
+        let pending = modeset_step(Some("N\n"), true, None);
+        assert_eq!(pending.status, CheckStatus::Warn);
+        assert!(!pending.fixable);
+    }
+
+    #[test]
+    fn test_device_and_group_steps() {
+        let device = |path: &str, status: &str| DeviceAccessReport {
+            path: path.to_string(),
+            purpose: String::new(),
+            status: status.to_string(),
+            mode: None,
+        };
+
+        let ok = device_access_step(&[device("/dev/nvidiactl", "read/write")]);
+        assert_eq!(ok.status, CheckStatus::Pass);
+
+        let limited = device_access_step(&[
+            device("/dev/nvidiactl", "read/write"),
+            device("/dev/nvidia-modeset", "read-only"),
+        ]);
+        assert_eq!(limited.status, CheckStatus::Warn);
+        assert_eq!(
+            limited.remediation.as_deref(),
+            Some("sudo nvctl setup permissions")
+        );
+
+        let group = video_group_status("alex", true, false, false);
+        assert_eq!(
+            group.remediation.as_deref(),
+            Some("sudo usermod -aG video alex")
+        );
+        assert!(!video_group_status("alex", true, false, true).fixable);
+        assert_eq!(compositor_step("tty", "").status, CheckStatus::Warn);
+    }
+}
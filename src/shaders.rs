@@ -41,90 +41,188 @@ impl ShaderCacheType {
     }
 }
 
-/// Get all shader cache locations and their information
-pub fn get_shader_caches() -> NvResult<Vec<ShaderCacheInfo>> {
-    let mut caches = Vec::new();
+/// Steam's shader cache for one game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameShaderCache {
+    pub app_id: String,
+    /// Name from the Steam app manifest, or the app id when it isn't installed
+    pub name: String,
+    pub cache: ShaderCacheInfo,
+}
 
-    if let Some(user_dirs) = UserDirs::new() {
-        let home = user_dirs.home_dir();
+/// Sizes of every cache location nvcontrol knows how to clear
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShaderCacheSummary {
+    /// Driver and translation-layer caches shared by all games
+    pub locations: Vec<ShaderCacheInfo>,
+    /// Per-game Steam caches, largest first; Proton keeps DXVK/VKD3D state here
+    pub games: Vec<GameShaderCache>,
+}
 
-        // NVIDIA shader cache locations
-        let nvidia_cache_paths = vec![
-            home.join(".nv/GLCache"),
-            home.join(".cache/nvidia/GLCache"),
-            PathBuf::from("/tmp/nvidia-cache"),
-        ];
+impl ShaderCacheSummary {
+    /// Every location, shared caches first
+    pub fn all(&self) -> impl Iterator<Item = &ShaderCacheInfo> {
+        self.locations
+            .iter()
+            .chain(self.games.iter().map(|game| &game.cache))
+    }
 
-        for path in nvidia_cache_paths {
-            if let Ok(info) = get_cache_info(&path, ShaderCacheType::Nvidia) {
-                caches.push(info);
-            }
-        }
+    pub fn total_mb(&self) -> f64 {
+        self.all().map(|cache| cache.size_mb).sum()
+    }
+}
 
-        // Vulkan pipeline cache
-        let vulkan_cache = home.join(".cache/mesa_shader_cache");
-        if let Ok(info) = get_cache_info(&vulkan_cache, ShaderCacheType::Vulkan) {
-            caches.push(info);
-        }
+/// Outcome of clearing one cache location
+#[derive(Debug, Clone, Default)]
+pub struct ClearReport {
+    pub freed_bytes: u64,
+    pub removed_files: usize,
+    /// Entries that couldn't be removed, usually for lack of permission
+    pub skipped: Vec<PathBuf>,
+}
 
-        // Steam shader cache
-        let steam_cache = home.join(".steam/steam/steamapps/shadercache");
-        if let Ok(info) = get_cache_info(&steam_cache, ShaderCacheType::Steam) {
-            caches.push(info);
-        }
+impl ClearReport {
+    pub fn freed_mb(&self) -> f64 {
+        self.freed_bytes as f64 / (1024.0 * 1024.0)
+    }
+}
 
-        // DXVK cache
-        let dxvk_cache = home.join(".cache/dxvk");
-        if let Ok(info) = get_cache_info(&dxvk_cache, ShaderCacheType::Dxvk) {
-            caches.push(info);
-        }
+/// Size up the driver, Mesa, DXVK/VKD3D and per-game Steam shader caches
+pub fn get_cache_summary() -> ShaderCacheSummary {
+    UserDirs::new()
+        .map(|dirs| summary_for_home(dirs.home_dir()))
+        .unwrap_or_default()
+}
 
-        // VKD3D cache
-        let vkd3d_cache = home.join(".cache/vkd3d");
-        if let Ok(info) = get_cache_info(&vkd3d_cache, ShaderCacheType::Vkd3d) {
-            caches.push(info);
+fn summary_for_home(home: &Path) -> ShaderCacheSummary {
+    let shared = [
+        (home.join(".cache/nvidia"), ShaderCacheType::Nvidia),
+        (home.join(".nv/GLCache"), ShaderCacheType::Nvidia),
+        (home.join(".nv/ComputeCache"), ShaderCacheType::Nvidia),
+        (
+            home.join(".cache/mesa_shader_cache"),
+            ShaderCacheType::Vulkan,
+        ),
+        (
+            home.join(".cache/mesa_shader_cache_db"),
+            ShaderCacheType::Vulkan,
+        ),
+        (home.join(".cache/dxvk"), ShaderCacheType::Dxvk),
+        (home.join(".cache/vkd3d"), ShaderCacheType::Vkd3d),
+        (home.join(".cache/vkd3d-proton"), ShaderCacheType::Vkd3d),
+    ];
+    let locations = shared
+        .into_iter()
+        .filter_map(|(path, cache_type)| get_cache_info(&path, cache_type).ok())
+        .collect();
+
+    // ~/.steam/steam is usually a symlink to ~/.local/share/Steam
+    let mut seen = std::collections::HashSet::new();
+    let mut games = Vec::new();
+    for steamapps in [
+        home.join(".steam/steam/steamapps"),
+        home.join(".local/share/Steam/steamapps"),
+    ] {
+        let Ok(real) = steamapps.canonicalize() else {
+            continue;
+        };
+        if !seen.insert(real) {
+            continue;
         }
-
-        // Game-specific caches (common locations)
-        let game_cache_patterns = vec![
-            (home.join(".cache"), "game cache"),
-            (home.join(".local/share"), "local game data"),
-        ];
-
-        for (base_path, _desc) in game_cache_patterns {
-            if let Ok(entries) = fs::read_dir(&base_path) {
-                for entry in entries.flatten() {
-                    let path = entry.path();
-                    if path.is_dir() {
-                        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-                        // Check for common game shader cache indicators
-                        if name.contains("shader") || name.contains("cache") {
-                            if let Ok(info) =
-                                get_cache_info(&path, ShaderCacheType::Game(name.to_string()))
-                            {
-                                caches.push(info);
-                            }
-                        }
-                    }
-                }
+        let Ok(entries) = fs::read_dir(steamapps.join("shadercache")) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let app_id = entry.file_name().to_string_lossy().into_owned();
+            if !app_id.chars().all(|c| c.is_ascii_digit()) {
+                continue;
+            }
+            if let Ok(cache) = get_cache_info(&entry.path(), ShaderCacheType::Steam) {
+                games.push(GameShaderCache {
+                    name: steam_app_name(&steamapps, &app_id).unwrap_or_else(|| app_id.clone()),
+                    app_id,
+                    cache,
+                });
             }
         }
     }
+    games.sort_by(|a, b| b.cache.size_mb.total_cmp(&a.cache.size_mb));
 
-    // System-wide caches
-    let system_caches = vec![
-        (PathBuf::from("/var/cache/nvidia"), ShaderCacheType::Nvidia),
-        (PathBuf::from("/tmp/.nvidia_cache"), ShaderCacheType::Nvidia),
-    ];
+    ShaderCacheSummary { locations, games }
+}
 
-    for (path, cache_type) in system_caches {
-        if let Ok(info) = get_cache_info(&path, cache_type) {
-            caches.push(info);
+/// Game name from `appmanifest_<id>.acf`
+fn steam_app_name(steamapps: &Path, app_id: &str) -> Option<String> {
+    let manifest =
+        fs::read_to_string(steamapps.join(format!("appmanifest_{}.acf", app_id))).ok()?;
+    manifest.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("\"name\"")?;
+        Some(rest.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Delete the contents of one cache location from [`get_cache_summary`].
+///
+/// Any other path is refused, so a typo can never empty an unrelated
+/// directory. Entries that can't be removed are skipped and listed in the
+/// report rather than aborting the rest of the clear.
+pub fn clear_cache(location: &Path) -> NvResult<ClearReport> {
+    clear_known_cache(&get_cache_summary(), location)
+}
+
+fn clear_known_cache(summary: &ShaderCacheSummary, location: &Path) -> NvResult<ClearReport> {
+    let known = summary.all().any(|cache| cache.path == location);
+    if !known {
+        return Err(NvControlError::ConfigError(format!(
+            "{} is not a known shader cache location",
+            location.display()
+        )));
+    }
+
+    // Never follow a link at the cache root, it could point anywhere
+    let is_symlink = fs::symlink_metadata(location)
+        .map(|meta| meta.file_type().is_symlink())
+        .unwrap_or(true);
+    if is_symlink {
+        return Err(NvControlError::ConfigError(format!(
+            "{} is a symlink, refusing to clear it",
+            location.display()
+        )));
+    }
+
+    let mut report = ClearReport::default();
+    clear_dir_contents(location, &mut report)?;
+    Ok(report)
+}
+
+fn clear_dir_contents(dir: &Path, report: &mut ClearReport) -> NvResult<()> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| NvControlError::IoError(format!("Cannot read {}: {}", dir.display(), e)))?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        // symlink_metadata so a link is removed without touching its target
+        let Ok(metadata) = fs::symlink_metadata(&path) else {
+            report.skipped.push(path);
+            continue;
+        };
+        if metadata.is_dir() {
+            if clear_dir_contents(&path, report).is_err() || fs::remove_dir(&path).is_err() {
+                report.skipped.push(path);
+            }
+        } else if fs::remove_file(&path).is_ok() {
+            report.freed_bytes += metadata.len();
+            report.removed_files += 1;
+        } else {
+            report.skipped.push(path);
         }
     }
+    Ok(())
+}
 
-    Ok(caches)
+/// Get all shader cache locations and their information
+pub fn get_shader_caches() -> NvResult<Vec<ShaderCacheInfo>> {
+    Ok(get_cache_summary().all().cloned().collect())
 }
 
 /// Get information about a specific cache directory
@@ -194,21 +292,41 @@ fn calculate_directory_size(path: &Path) -> NvResult<(u64, usize)> {
 
 /// Clear all shader caches
 pub fn clear_shader_cache() -> NvResult<()> {
-    let caches = get_shader_caches()?;
+    clear_matching(|_| true, "shader")
+}
+
+/// Clear specific shader cache by type
+pub fn clear_shader_cache_by_type(cache_type: ShaderCacheType) -> NvResult<()> {
+    let wanted = std::mem::discriminant(&cache_type);
+    clear_matching(
+        |cache| std::mem::discriminant(&cache.cache_type) == wanted,
+        cache_type.as_str(),
+    )
+}
+
+fn clear_matching(filter: impl Fn(&ShaderCacheInfo) -> bool, label: &str) -> NvResult<()> {
+    let summary = get_cache_summary();
     let mut cleared_count = 0;
     let mut total_size_cleared = 0.0;
 
-    for cache in caches {
-        match clear_cache_directory(&cache.path) {
-            Ok(()) => {
+    for cache in summary.all().filter(|cache| filter(cache)) {
+        match clear_known_cache(&summary, &cache.path) {
+            Ok(report) => {
                 cleared_count += 1;
-                total_size_cleared += cache.size_mb;
+                total_size_cleared += report.freed_mb();
                 println!(
                     "Cleared {} cache: {} ({:.1} MB)",
                     cache.cache_type.as_str(),
                     cache.path.display(),
-                    cache.size_mb
+                    report.freed_mb()
                 );
+                if !report.skipped.is_empty() {
+                    eprintln!(
+                        "Warning: {} entries in {} could not be removed (permission denied?)",
+                        report.skipped.len(),
+                        cache.path.display()
+                    );
+                }
             }
             Err(e) => {
                 eprintln!("Warning: Failed to clear {}: {}", cache.path.display(), e);
@@ -216,101 +334,18 @@ pub fn clear_shader_cache() -> NvResult<()> {
         }
     }
 
-    println!(
-        "\nCleared {} shader caches, freed {:.1} MB",
-        cleared_count, total_size_cleared
-    );
-    Ok(())
-}
-
-/// Clear specific shader cache by type
-pub fn clear_shader_cache_by_type(cache_type: ShaderCacheType) -> NvResult<()> {
-    let caches = get_shader_caches()?;
-    let mut cleared_count = 0;
-    let mut total_size_cleared = 0.0;
-
-    for cache in caches {
-        if std::mem::discriminant(&cache.cache_type) == std::mem::discriminant(&cache_type) {
-            match clear_cache_directory(&cache.path) {
-                Ok(()) => {
-                    cleared_count += 1;
-                    total_size_cleared += cache.size_mb;
-                    println!(
-                        "Cleared {} cache: {} ({:.1} MB)",
-                        cache.cache_type.as_str(),
-                        cache.path.display(),
-                        cache.size_mb
-                    );
-                }
-                Err(e) => {
-                    eprintln!("Warning: Failed to clear {}: {}", cache.path.display(), e);
-                }
-            }
-        }
-    }
-
     if cleared_count == 0 {
-        println!("No {} caches found to clear", cache_type.as_str());
+        println!("No {} caches found to clear", label);
     } else {
         println!(
             "Cleared {} {} caches, freed {:.1} MB",
-            cleared_count,
-            cache_type.as_str(),
-            total_size_cleared
+            cleared_count, label, total_size_cleared
         );
     }
 
     Ok(())
 }
 
-/// Clear a specific cache directory
-fn clear_cache_directory(path: &Path) -> NvResult<()> {
-    if !path.exists() {
-        return Ok(());
-    }
-
-    // For safety, only clear directories that look like caches
-    let path_str = path.to_string_lossy().to_lowercase();
-    if !path_str.contains("cache") && !path_str.contains("shader") && !path_str.contains("dxvk") {
-        return Err(NvControlError::DisplayDetectionFailed(
-            "Path doesn't appear to be a cache directory".to_string(),
-        ));
-    }
-
-    if path.is_file() {
-        fs::remove_file(path).map_err(|e| {
-            NvControlError::DisplayDetectionFailed(format!("Failed to remove file: {}", e))
-        })?;
-    } else {
-        // Remove contents but keep the directory structure
-        let entries = fs::read_dir(path).map_err(|e| {
-            NvControlError::DisplayDetectionFailed(format!("Failed to read directory: {}", e))
-        })?;
-
-        for entry in entries {
-            let entry = entry.map_err(|e| {
-                NvControlError::DisplayDetectionFailed(format!("Failed to read entry: {}", e))
-            })?;
-
-            let entry_path = entry.path();
-            if entry_path.is_dir() {
-                fs::remove_dir_all(&entry_path).map_err(|e| {
-                    NvControlError::DisplayDetectionFailed(format!(
-                        "Failed to remove directory: {}",
-                        e
-                    ))
-                })?;
-            } else {
-                fs::remove_file(&entry_path).map_err(|e| {
-                    NvControlError::DisplayDetectionFailed(format!("Failed to remove file: {}", e))
-                })?;
-            }
-        }
-    }
-
-    Ok(())
-}
-
 /// Precompile shaders for Steam games (if available)
 pub fn precompile_shaders(game_path: &str) -> NvResult<()> {
     // Try to trigger Steam's shader pre-caching
@@ -464,3 +499,55 @@ pub fn get_shader_stats() -> NvResult<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_only_known_cache_locations() {
+        let home = tempfile::tempdir().unwrap();
+        let nvidia = home.path().join(".cache/nvidia/GLCache");
+        fs::create_dir_all(&nvidia).unwrap();
+        fs::write(nvidia.join("blob"), vec![0u8; 2048]).unwrap();
+
+        let steamapps = home.path().join(".local/share/Steam/steamapps");
+        fs::create_dir_all(steamapps.join("shadercache/570/DXVK_state_cache")).unwrap();
+        fs::write(
+            steamapps.join("shadercache/570/DXVK_state_cache/dota.dxvk-cache"),
+            b"state",
+        )
+        .unwrap();
+        fs::write(
+            steamapps.join("appmanifest_570.acf"),
+            "\"AppState\"\n{\n\t\"appid\"\t\t\"570\"\n\t\"name\"\t\t\"Dota 2\"\n}\n",
+        )
+        .unwrap();
+
+        // A look-alike directory that must never be touched
+        let unrelated = home.path().join(".cache/ccache");
+        fs::create_dir_all(&unrelated).unwrap();
+        fs::write(unrelated.join("keep"), b"x").unwrap();
+
+        let summary = summary_for_home(home.path());
+        assert_eq!(summary.locations.len(), 1);
+        assert_eq!(summary.games[0].name, "Dota 2");
+        assert!(summary.all().all(|cache| cache.path != unrelated));
+        assert!(clear_known_cache(&summary, &unrelated).is_err());
+
+        // A cache root swapped for a symlink is refused, not followed
+        let dxvk = home.path().join(".cache/dxvk");
+        std::os::unix::fs::symlink(&unrelated, &dxvk).unwrap();
+        let linked = summary_for_home(home.path());
+        assert!(clear_known_cache(&linked, &dxvk).is_err());
+        assert!(unrelated.join("keep").exists());
+        fs::remove_file(&dxvk).unwrap();
+
+        let report = clear_known_cache(&summary, &home.path().join(".cache/nvidia")).unwrap();
+        assert_eq!(report.freed_bytes, 2048);
+        assert!(report.skipped.is_empty());
+        assert!(!nvidia.exists());
+        assert!(home.path().join(".cache/nvidia").exists());
+        assert!(unrelated.join("keep").exists());
+    }
+}
@@ -4,8 +4,9 @@
 /// Saves to ~/.config/nvcontrol/state.json
 use crate::NvResult;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Global application state
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -175,6 +176,517 @@ impl DriverValidationState {
     }
 }
 
+/// Driver version seen on the previous start, for update notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DriverSeenState {
+    pub last_seen_version: String,
+    pub recorded_at: std::time::SystemTime,
+}
+
+impl DriverSeenState {
+    /// Load the last recorded driver version from disk
+    pub fn load() -> Option<Self> {
+        fs::read_to_string(Self::state_file_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+    }
+
+    /// Record `version` as the last seen driver
+    pub fn record(version: &str) -> crate::NvResult<()> {
+        let state = Self {
+            last_seen_version: version.to_string(),
+            recorded_at: std::time::SystemTime::now(),
+        };
+        let json = serde_json::to_string_pretty(&state).map_err(|e| {
+            crate::NvControlError::ConfigError(format!("Failed to serialize state: {}", e))
+        })?;
+        fs::write(Self::state_file_path(), json).map_err(|e| {
+            crate::NvControlError::ConfigError(format!("Failed to write state: {}", e))
+        })?;
+        Ok(())
+    }
+
+    fn state_file_path() -> PathBuf {
+        let config_dir = dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol");
+        fs::create_dir_all(&config_dir).ok();
+        config_dir.join("driver_seen.json")
+    }
+}
+
+/// Settings most recently applied from the CLI or GUI, replayed by
+/// `nvctl apply-saved`. Overclock offsets are tracked in `OverclockHistory`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LastApplied {
+    /// Vibrance percent set for all displays at once
+    pub vibrance_all: Option<u32>,
+    /// Vibrance percent per display index, applied after `vibrance_all`
+    pub vibrance_displays: BTreeMap<usize, u32>,
+    /// Power limit in watts per GPU index
+    pub power_limits_w: BTreeMap<u32, f32>,
+    /// Fixed fan speed percent per fan; fans on automatic control have no entry
+    pub fan_speeds: BTreeMap<usize, u8>,
+}
+
+impl LastApplied {
+    /// Load the record from disk, starting empty if missing or unreadable
+    pub fn load() -> Self {
+        fs::read_to_string(Self::state_file_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the record to disk
+    pub fn save(&self) -> NvResult<()> {
+        // Nothing was applied, so there's nothing to replay later
+        if crate::gpu_safe::is_dry_run() {
+            return Ok(());
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            crate::NvControlError::ConfigError(format!("Failed to serialize state: {}", e))
+        })?;
+        fs::write(Self::state_file_path(), json).map_err(|e| {
+            crate::NvControlError::ConfigError(format!("Failed to write state: {}", e))
+        })?;
+        Ok(())
+    }
+
+    /// Load, modify and save the record. Failing to save only warns, since
+    /// the setting itself was applied.
+    pub fn update(modify: impl FnOnce(&mut Self)) {
+        let mut state = Self::load();
+        modify(&mut state);
+        if let Err(e) = state.save() {
+            log::warn!("failed to record applied settings: {e}");
+        }
+    }
+
+    /// Remember a power limit the user set, so `apply_saved` restores it
+    pub fn record_power_limit(gpu_index: u32, watts: f32) {
+        Self::update(|state| {
+            state.power_limits_w.insert(gpu_index, watts);
+        });
+    }
+
+    /// Remember the limit each GPU ended up with after a batch power limit
+    /// apply, so `apply_saved` restores it
+    pub fn record_power_limits(
+        backend: &crate::nvml_backend::SharedNvmlBackend,
+        report: &crate::multi_gpu::BatchApplyReport,
+    ) {
+        use crate::multi_gpu::GpuApplyOutcome;
+
+        Self::update(|state| {
+            for (gpu, outcome) in &report.results {
+                if *outcome == GpuApplyOutcome::Applied
+                    && let Ok(mw) = backend.get_power_limit(*gpu)
+                {
+                    state.power_limits_w.insert(*gpu, mw as f32 / 1000.0);
+                }
+            }
+        });
+    }
+
+    /// Remember a fixed fan speed the user set, so `apply_saved` restores
+    /// it. Fan curve output is never recorded.
+    pub fn record_fan_speed(fan_id: usize, percent: u8) {
+        Self::update(|state| {
+            state.fan_speeds.insert(fan_id, percent);
+        });
+    }
+
+    /// Record a vibrance change. Setting every display replaces any
+    /// per-display values.
+    pub fn record_vibrance(&mut self, display: Option<usize>, percentage: u32) {
+        match display {
+            Some(index) => {
+                self.vibrance_displays.insert(index, percentage);
+            }
+            None => {
+                self.vibrance_all = Some(percentage);
+                self.vibrance_displays.clear();
+            }
+        }
+    }
+
+    fn state_file_path() -> PathBuf {
+        let config_dir = dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol");
+        fs::create_dir_all(&config_dir).ok();
+        config_dir.join("last_applied.json")
+    }
+}
+
+/// Result of replaying one subsystem in `apply_saved`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApplyStatus {
+    Applied(String),
+    /// Nothing saved for this subsystem
+    Skipped,
+    Failed(String),
+}
+
+/// Per-subsystem outcome of `apply_saved`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplyOutcome {
+    pub subsystem: &'static str,
+    /// Whether a failure here should fail the whole run
+    pub critical: bool,
+    pub status: ApplyStatus,
+}
+
+impl ApplyOutcome {
+    fn new(subsystem: &'static str, critical: bool, status: ApplyStatus) -> Self {
+        Self {
+            subsystem,
+            critical,
+            status,
+        }
+    }
+
+    /// Whether this is a failed critical subsystem
+    pub fn is_critical_failure(&self) -> bool {
+        self.critical && matches!(self.status, ApplyStatus::Failed(_))
+    }
+}
+
+/// Apply the last saved overclock, power limit, fan speeds and vibrance once.
+///
+/// Subsystems with nothing saved are skipped and every subsystem is attempted
+/// even if an earlier one fails. Overclock, power and fan are critical;
+/// vibrance is not, since it depends on the display stack being up.
+pub fn apply_saved(backend: &crate::nvml_backend::SharedNvmlBackend) -> Vec<ApplyOutcome> {
+    let saved = LastApplied::load();
+    vec![
+        ApplyOutcome::new("Overclock", true, apply_saved_overclock()),
+        ApplyOutcome::new("Power limit", true, apply_saved_power(backend, &saved)),
+        ApplyOutcome::new("Fan speed", true, apply_saved_fans(&saved)),
+        ApplyOutcome::new("Vibrance", false, apply_saved_vibrance(&saved)),
+    ]
+}
+
+fn apply_saved_overclock() -> ApplyStatus {
+    let history = crate::overclocking::OverclockHistory::load();
+    let gpus = history.overclocked_gpus();
+    if gpus.is_empty() {
+        return ApplyStatus::Skipped;
+    }
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for gpu in gpus {
+        let Some(entry) = history.last_for_gpu(gpu) else {
+            continue;
+        };
+        let profile = &entry.profile;
+        match crate::overclocking::reapply_profile(profile, gpu) {
+            Ok(()) => applied.push(format!(
+                "GPU {}: {} (core {:+} MHz, memory {:+} MHz)",
+                gpu, profile.name, profile.gpu_clock_offset, profile.memory_clock_offset
+            )),
+            Err(e) => errors.push(format!("GPU {}: {}", gpu, e)),
+        }
+    }
+    collect_status(applied, errors)
+}
+
+fn apply_saved_power(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+    saved: &LastApplied,
+) -> ApplyStatus {
+    if saved.power_limits_w.is_empty() {
+        return ApplyStatus::Skipped;
+    }
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for (&gpu, &watts) in &saved.power_limits_w {
+        match crate::power::set_power_limit_watts(backend, gpu, watts) {
+            Ok(limit) => applied.push(format!("GPU {} {:.0} W", gpu, limit.applied_w)),
+            Err(e) => errors.push(format!("GPU {}: {}", gpu, e)),
+        }
+    }
+    collect_status(applied, errors)
+}
+
+fn apply_saved_fans(saved: &LastApplied) -> ApplyStatus {
+    if saved.fan_speeds.is_empty() {
+        return ApplyStatus::Skipped;
+    }
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for (&fan, &speed) in &saved.fan_speeds {
+        match crate::fan::set_fan_speed(fan, speed) {
+            Ok(()) => applied.push(format!("fan {} {}%", fan, speed)),
+            Err(e) => errors.push(format!("fan {}: {}", fan, e)),
+        }
+    }
+    collect_status(applied, errors)
+}
+
+fn apply_saved_vibrance(saved: &LastApplied) -> ApplyStatus {
+    use crate::vibrance::DisplayTarget;
+
+    let targets: Vec<(DisplayTarget, u32)> = saved
+        .vibrance_all
+        .map(|p| (DisplayTarget::All, p))
+        .into_iter()
+        .chain(
+            saved
+                .vibrance_displays
+                .iter()
+                .map(|(&display, &p)| (DisplayTarget::Index(display), p)),
+        )
+        .collect();
+    if targets.is_empty() {
+        return ApplyStatus::Skipped;
+    }
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for (target, percentage) in targets {
+        let scope = match target {
+            DisplayTarget::All => "all displays".to_string(),
+            DisplayTarget::Index(index) => format!("display {}", index),
+        };
+        match crate::vibrance::set_vibrance_percent(target, percentage) {
+            Ok(_) => applied.push(format!("{} {}%", scope, percentage)),
+            Err(e) => errors.push(format!("{}: {}", scope, e)),
+        }
+    }
+    collect_status(applied, errors)
+}
+
+/// Result of a guarded startup apply
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartupApply {
+    Applied(Vec<ApplyOutcome>),
+    /// The crash flag from an earlier startup apply is still set
+    SkippedAfterCrash,
+    /// NVML couldn't be read once the delay was over
+    NvmlUnavailable(String),
+}
+
+/// Flag that exists only while a startup apply is in flight. Finding it at
+/// the next start means the machine went down mid-apply.
+pub fn startup_apply_flag_path() -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("nvcontrol");
+    fs::create_dir_all(&config_dir).ok();
+    config_dir.join("startup_applying")
+}
+
+/// Whether the last startup apply never finished
+pub fn startup_apply_crashed() -> bool {
+    startup_apply_flag_path().exists()
+}
+
+/// Clear the crash flag, e.g. after the user re-applied by hand
+pub fn clear_startup_apply_flag() {
+    let _ = fs::remove_file(startup_apply_flag_path());
+}
+
+/// Apply saved settings at login without risking a boot loop: wait
+/// `delay_secs` for the desktop to settle, require a successful NVML read,
+/// and refuse while the crash flag from a previous attempt is still set.
+pub fn apply_at_startup(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+    delay_secs: u64,
+) -> StartupApply {
+    guarded_startup_apply(
+        &startup_apply_flag_path(),
+        std::time::Duration::from_secs(delay_secs),
+        || backend.get_temperature(0).map(|_| ()),
+        || apply_saved(backend),
+    )
+}
+
+fn guarded_startup_apply(
+    flag: &Path,
+    delay: std::time::Duration,
+    probe: impl FnOnce() -> NvResult<()>,
+    apply: impl FnOnce() -> Vec<ApplyOutcome>,
+) -> StartupApply {
+    if flag.exists() {
+        return StartupApply::SkippedAfterCrash;
+    }
+    std::thread::sleep(delay);
+    if let Err(e) = probe() {
+        return StartupApply::NvmlUnavailable(e.to_string());
+    }
+
+    if let Err(e) = write_flag_durably(flag, &chrono::Local::now().to_rfc3339()) {
+        log::warn!("failed to write startup apply flag: {e}");
+    }
+    let outcomes = apply();
+    // Still running, so the apply didn't take the machine down
+    let _ = fs::remove_file(flag);
+    StartupApply::Applied(outcomes)
+}
+
+/// Write the crash flag and sync it to disk, so a hang during the apply that
+/// follows still leaves the flag behind after a hard reset
+fn write_flag_durably(flag: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut file = fs::File::create(flag)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    if let Some(dir) = flag.parent() {
+        fs::File::open(dir)?.sync_all()?;
+    }
+    Ok(())
+}
+
+/// Revert everything nvcontrol applies to stock: clock offsets, power
+/// limits, locked clocks, fans, vibrance and GPU RGB.
+///
+/// Each subsystem is reset even if an earlier one fails. Display modes and
+/// compositor settings are deliberately left alone so the screen can't be lost.
+pub fn reset_all_to_defaults(
+    backend: &crate::nvml_backend::SharedNvmlBackend,
+) -> Vec<ApplyOutcome> {
+    let gpus = backend.device_count().unwrap_or(0);
+    vec![
+        ApplyOutcome::new("Clock offsets", true, reset_offsets(gpus)),
+        ApplyOutcome::new("Power limit", true, reset_power(backend, gpus)),
+        ApplyOutcome::new("Locked clocks", true, reset_clock_locks(backend, gpus)),
+        ApplyOutcome::new("Fans", true, reset_fans(backend, gpus)),
+        ApplyOutcome::new("Vibrance", false, reset_vibrance()),
+        ApplyOutcome::new("RGB", false, reset_rgb()),
+    ]
+}
+
+fn reset_offsets(gpus: u32) -> ApplyStatus {
+    if gpus == 0 {
+        return ApplyStatus::Skipped;
+    }
+    let stock = crate::overclocking::OverclockProfile::default();
+    let mut history = crate::overclocking::OverclockHistory::load();
+    let overclocked = history.overclocked_gpus();
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for gpu in 0..gpus {
+        match crate::overclocking::reapply_profile(&stock, gpu) {
+            Ok(()) => {
+                applied.push(format!("GPU {} +0/+0 MHz", gpu));
+                // Only GPUs the history still shows as overclocked need a stock entry
+                if overclocked.contains(&gpu) {
+                    history.record(&stock, gpu);
+                }
+            }
+            Err(e) => errors.push(format!("GPU {}: {}", gpu, e)),
+        }
+    }
+    if !overclocked.is_empty()
+        && let Err(e) = history.save()
+    {
+        errors.push(format!("overclock history: {}", e));
+    }
+    collect_status(applied, errors)
+}
+
+fn reset_power(backend: &crate::nvml_backend::SharedNvmlBackend, gpus: u32) -> ApplyStatus {
+    if gpus == 0 {
+        return ApplyStatus::Skipped;
+    }
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    let mut reset = Vec::new();
+    for gpu in 0..gpus {
+        let result = crate::power::PowerLimitRange::query(backend, gpu)
+            .and_then(|range| crate::power::set_power_limit_watts(backend, gpu, range.default_w));
+        match result {
+            Ok(limit) => {
+                applied.push(format!("GPU {} {:.0} W", gpu, limit.applied_w));
+                reset.push(gpu);
+            }
+            Err(e) => errors.push(format!("GPU {}: {}", gpu, e)),
+        }
+    }
+    // The default limit needs no replaying
+    if !reset.is_empty() {
+        LastApplied::update(|state| {
+            for gpu in &reset {
+                state.power_limits_w.remove(gpu);
+            }
+        });
+    }
+    collect_status(applied, errors)
+}
+
+fn reset_clock_locks(backend: &crate::nvml_backend::SharedNvmlBackend, gpus: u32) -> ApplyStatus {
+    if gpus == 0 {
+        return ApplyStatus::Skipped;
+    }
+    let mut applied = Vec::new();
+    let mut errors = Vec::new();
+    for gpu in 0..gpus {
+        match crate::overclocking::reset_locked_clocks(backend.as_ref(), gpu) {
+            Ok(()) => applied.push(format!("GPU {} unlocked", gpu)),
+            Err(e) => errors.push(format!("GPU {}: {}", gpu, e)),
+        }
+    }
+    collect_status(applied, errors)
+}
+
+fn reset_fans(backend: &crate::nvml_backend::SharedNvmlBackend, gpus: u32) -> ApplyStatus {
+    let fans: u32 = (0..gpus)
+        .map(|gpu| backend.get_fan_count(gpu).unwrap_or(0))
+        .sum();
+    if fans == 0 {
+        return ApplyStatus::Skipped;
+    }
+    let mut errors = Vec::new();
+    for fan in 0..fans as usize {
+        // Drop any daemon-driven curve first so it doesn't take the fan back
+        if let Err(e) = crate::fan_daemon::clear_curve(backend, fan) {
+            errors.push(format!("fan {} curve: {}", fan, e));
+        }
+        if let Err(e) = crate::fan::reset_fan_to_auto(fan) {
+            errors.push(format!("fan {}: {}", fan, e));
+        }
+    }
+    collect_status(
+        vec![format!("{} fan(s) on automatic control", fans)],
+        errors,
+    )
+}
+
+fn reset_vibrance() -> ApplyStatus {
+    match crate::vibrance::set_vibrance_percent(crate::vibrance::DisplayTarget::All, 100) {
+        Ok(_) => ApplyStatus::Applied("all displays 100%".to_string()),
+        Err(e) => ApplyStatus::Failed(e.to_string()),
+    }
+}
+
+fn reset_rgb() -> ApplyStatus {
+    use crate::rgb_control::{RgbColor, RgbController, RgbMode};
+
+    let mut controller = match RgbController::new() {
+        Ok(controller) if !controller.devices.is_empty() => controller,
+        Ok(_) => return ApplyStatus::Skipped,
+        Err(e) => return ApplyStatus::Failed(e.to_string()),
+    };
+    if !crate::gpu_safe::should_apply("turn GPU RGB off") {
+        return ApplyStatus::Applied("dry run".to_string());
+    }
+    match controller.set_gpu_mode(RgbMode::Off, RgbColor { r: 0, g: 0, b: 0 }) {
+        Ok(()) => ApplyStatus::Applied(format!("{} device(s) off", controller.devices.len())),
+        Err(e) => ApplyStatus::Failed(e.to_string()),
+    }
+}
+
+fn collect_status(applied: Vec<String>, errors: Vec<String>) -> ApplyStatus {
+    if errors.is_empty() {
+        ApplyStatus::Applied(applied.join(", "))
+    } else {
+        ApplyStatus::Failed(errors.join("; "))
+    }
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self {
@@ -376,6 +888,30 @@ mod tests {
         assert_eq!(state.profiles.len(), 0);
     }
 
+    #[test]
+    fn test_last_applied_vibrance_and_outcomes() {
+        let mut state = LastApplied::default();
+        state.record_vibrance(Some(1), 150);
+        state.record_vibrance(None, 120);
+        assert_eq!(state.vibrance_all, Some(120));
+        assert!(state.vibrance_displays.is_empty());
+        state.record_vibrance(Some(0), 80);
+        assert_eq!(state.vibrance_displays.get(&0), Some(&80));
+
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<LastApplied>(&json).unwrap(), state);
+        // Older or partial files still load
+        assert_eq!(
+            serde_json::from_str::<LastApplied>("{}").unwrap(),
+            LastApplied::default()
+        );
+
+        let failed = ApplyStatus::Failed("no permission".to_string());
+        assert!(ApplyOutcome::new("Power limit", true, failed.clone()).is_critical_failure());
+        assert!(!ApplyOutcome::new("Vibrance", false, failed).is_critical_failure());
+        assert!(!ApplyOutcome::new("Fan speed", true, ApplyStatus::Skipped).is_critical_failure());
+    }
+
     #[test]
     fn test_state_persistence() {
         let state = AppState::default();
@@ -387,4 +923,44 @@ mod tests {
         let loaded = AppState::load().unwrap();
         assert_eq!(loaded.version, state.version);
     }
+
+    #[test]
+    fn test_startup_apply_skips_after_crash() {
+        use std::time::Duration;
+
+        let dir = tempfile::tempdir().unwrap();
+        let flag = dir.path().join("startup_applying");
+
+        let result = guarded_startup_apply(
+            &flag,
+            Duration::ZERO,
+            || Ok(()),
+            || {
+                // The flag is up for the duration of the apply
+                assert!(flag.exists());
+                vec![ApplyOutcome::new("Fan speed", true, ApplyStatus::Skipped)]
+            },
+        );
+        assert!(matches!(result, StartupApply::Applied(ref o) if o.len() == 1));
+        assert!(!flag.exists());
+
+        let result = guarded_startup_apply(
+            &flag,
+            Duration::ZERO,
+            || Err(crate::NvControlError::NvmlNotAvailable("no driver".into())),
+            || panic!("applied without a successful NVML read"),
+        );
+        assert!(matches!(result, StartupApply::NvmlUnavailable(_)));
+
+        // A flag left behind means the previous attempt never finished
+        fs::write(&flag, "").unwrap();
+        let result = guarded_startup_apply(
+            &flag,
+            Duration::ZERO,
+            || Ok(()),
+            || panic!("applied after a crash"),
+        );
+        assert_eq!(result, StartupApply::SkippedAfterCrash);
+        assert!(flag.exists());
+    }
 }
@@ -15,6 +15,10 @@ pub struct SystemValidation {
     pub pcie_speed_gts: f32,
     pub iommu_enabled: bool,
     pub secure_boot_enabled: bool,
+    /// Laptop or other portable chassis, where the dGPU is soldered down
+    /// and usually wired with fewer than 16 lanes
+    #[serde(default)]
+    pub portable_chassis: bool,
     pub gpu_bar_regions: Vec<BarRegion>,
     pub warnings: Vec<String>,
     pub errors: Vec<String>,
@@ -43,6 +47,12 @@ impl SystemValidation {
         Ok(validation)
     }
 
+    /// Detect BAR, PCIe, IOMMU and Secure Boot state without any
+    /// generation-specific validation
+    pub fn detect() -> NvResult<Self> {
+        Self::detect_system_config()
+    }
+
     /// Detect current system configuration
     fn detect_system_config() -> NvResult<Self> {
         let mut validation = SystemValidation {
@@ -54,6 +64,7 @@ impl SystemValidation {
             pcie_speed_gts: 0.0,
             iommu_enabled: false,
             secure_boot_enabled: false,
+            portable_chassis: crate::gpu::is_portable_chassis(),
             gpu_bar_regions: Vec::new(),
             warnings: Vec::new(),
             errors: Vec::new(),
@@ -76,7 +87,11 @@ impl SystemValidation {
         if let Ok(output) = Command::new("lspci").output() {
             let output_str = String::from_utf8_lossy(&output.stdout);
             for line in output_str.lines() {
-                if line.contains("NVIDIA") && line.contains("VGA") {
+                // Laptop dGPUs behind a MUX or in hybrid mode list as
+                // "3D controller" rather than "VGA compatible controller"
+                if line.contains("NVIDIA")
+                    && (line.contains("VGA") || line.contains("3D controller"))
+                {
                     if let Some(addr) = line.split_whitespace().next() {
                         return Some(addr.to_string());
                     }
@@ -290,7 +305,7 @@ impl SystemValidation {
             );
         }
 
-        if self.pcie_lanes < 16 {
+        if self.pcie_lanes < 16 && !self.portable_chassis {
             self.warnings.push(format!(
                 "Only x{} PCIe lanes detected. Recommend x16 for RTX 5090",
                 self.pcie_lanes
@@ -346,6 +361,14 @@ impl SystemValidation {
         println!("  Generation: PCIe Gen {}", self.pcie_generation);
         println!("  Speed: {} GT/s", self.pcie_speed_gts);
         println!("  Lanes: x{}", self.pcie_lanes);
+        println!(
+            "  Form factor: {}",
+            if self.portable_chassis {
+                "Laptop"
+            } else {
+                "Desktop"
+            }
+        );
 
         // BAR Regions
         if !self.gpu_bar_regions.is_empty() {
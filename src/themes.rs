@@ -10,9 +10,11 @@ pub enum ThemeVariant {
     TokyoNightStorm,
     TokyoNightMoon,
     Dracula,
-    RogRed,      // ASUS ROG red theme
-    MatrixGreen, // Classic green on black
-    Cyberpunk,   // Pink/cyan neon
+    RogRed,       // ASUS ROG red theme
+    MatrixGreen,  // Classic green on black
+    Cyberpunk,    // Pink/cyan neon
+    Deuteranopia, // Red/green colorblind friendly
+    HighContrast, // Black/white, brightness-coded states
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +102,15 @@ impl Color {
         }
     }
 
+    /// Parse `#rrggbb` (the `#` is optional)
+    pub fn from_hex_str(s: &str) -> Option<Self> {
+        let digits = s.trim().trim_start_matches('#');
+        if digits.len() != 6 {
+            return None;
+        }
+        u32::from_str_radix(digits, 16).ok().map(Self::hex)
+    }
+
     /// Convert to ratatui::Color
     pub fn to_ratatui(&self) -> ratatui::style::Color {
         ratatui::style::Color::Rgb(self.r, self.g, self.b)
@@ -547,6 +558,122 @@ impl Theme {
         }
     }
 
+    /// Deuteranopia-friendly theme (blue/orange, states step up in brightness)
+    pub fn deuteranopia() -> Self {
+        Self {
+            name: "Deuteranopia".to_string(),
+            variant: ThemeVariant::Deuteranopia,
+            colors: ColorPalette {
+                bg: Color::hex(0x1c1f26),
+                bg_dark: Color::hex(0x14161b),
+                bg_highlight: Color::hex(0x2a2e38),
+                bg_popup: Color::hex(0x1c1f26),
+                bg_statusline: Color::hex(0x2a2e38),
+
+                fg: Color::hex(0xe8e8e8),
+                fg_dark: Color::hex(0xb0b4bc),
+                fg_gutter: Color::hex(0x4a4f5a),
+
+                blue: Color::hex(0x0072b2),
+                cyan: Color::hex(0x56b4e9),
+                green: Color::hex(0x56b4e9),
+                teal: Color::hex(0x7fb8d8),
+                magenta: Color::hex(0xcc79a7),
+                purple: Color::hex(0xa08cd0),
+                red: Color::hex(0xe69f00),
+                orange: Color::hex(0xe69f00),
+                yellow: Color::hex(0xf0e442),
+
+                // Dark blue -> sky blue -> amber -> pale yellow: each step is brighter
+                temp_cold: Color::hex(0x2f6ea8),
+                temp_normal: Color::hex(0x56b4e9),
+                temp_warm: Color::hex(0xe69f00),
+                temp_hot: Color::hex(0xfff27a),
+
+                usage_low: Color::hex(0x2f6ea8),
+                usage_medium: Color::hex(0x56b4e9),
+                usage_high: Color::hex(0xfff27a),
+
+                power_efficient: Color::hex(0x2f6ea8),
+                power_normal: Color::hex(0x56b4e9),
+                power_high: Color::hex(0xe69f00),
+
+                border: Color::hex(0x4a4f5a),
+                border_highlight: Color::hex(0x56b4e9),
+                selection: Color::hex(0x34495e),
+                comment: Color::hex(0x7a808c),
+
+                button_accent: Color::hex(0x0072b2),
+                button_accent_hover: Color::hex(0x1f8ac8),
+                button_accent_active: Color::hex(0x005a8c),
+
+                graph_line1: Color::hex(0x56b4e9),
+                graph_line2: Color::hex(0xe69f00),
+                graph_line3: Color::hex(0xf0e442),
+                graph_fill: Color::hex(0x2a2e38),
+            },
+            nerd_font: true,
+        }
+    }
+
+    /// High-contrast theme (black/white, states differ by brightness only)
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High Contrast".to_string(),
+            variant: ThemeVariant::HighContrast,
+            colors: ColorPalette {
+                bg: Color::hex(0x000000),
+                bg_dark: Color::hex(0x000000),
+                bg_highlight: Color::hex(0x1a1a1a),
+                bg_popup: Color::hex(0x000000),
+                bg_statusline: Color::hex(0x1a1a1a),
+
+                fg: Color::hex(0xffffff),
+                fg_dark: Color::hex(0xd0d0d0),
+                fg_gutter: Color::hex(0x808080),
+
+                blue: Color::hex(0x66ccff),
+                cyan: Color::hex(0x00ffff),
+                green: Color::hex(0xffffff),
+                teal: Color::hex(0xc0c0c0),
+                magenta: Color::hex(0xff66ff),
+                purple: Color::hex(0xcc99ff),
+                red: Color::hex(0xffff00),
+                orange: Color::hex(0xffcc00),
+                yellow: Color::hex(0xffff00),
+
+                // Grey -> light grey -> near white -> yellow (brightest on black)
+                temp_cold: Color::hex(0x808080),
+                temp_normal: Color::hex(0xb0b0b0),
+                temp_warm: Color::hex(0xd8d8d8),
+                temp_hot: Color::hex(0xffff00),
+
+                usage_low: Color::hex(0x808080),
+                usage_medium: Color::hex(0xb0b0b0),
+                usage_high: Color::hex(0xffff00),
+
+                power_efficient: Color::hex(0x808080),
+                power_normal: Color::hex(0xb0b0b0),
+                power_high: Color::hex(0xffff00),
+
+                border: Color::hex(0xffffff),
+                border_highlight: Color::hex(0xffff00),
+                selection: Color::hex(0x3a3a3a),
+                comment: Color::hex(0xa0a0a0),
+
+                button_accent: Color::hex(0x0050a0),
+                button_accent_hover: Color::hex(0x0066cc),
+                button_accent_active: Color::hex(0x003c78),
+
+                graph_line1: Color::hex(0xffffff),
+                graph_line2: Color::hex(0xffff00),
+                graph_line3: Color::hex(0x00ffff),
+                graph_fill: Color::hex(0x1a1a1a),
+            },
+            nerd_font: true,
+        }
+    }
+
     /// Get theme by variant
     pub fn from_variant(variant: ThemeVariant) -> Self {
         match variant {
@@ -557,6 +684,8 @@ impl Theme {
             ThemeVariant::RogRed => Self::rog_red(),
             ThemeVariant::MatrixGreen => Self::matrix_green(),
             ThemeVariant::Cyberpunk => Self::cyberpunk(),
+            ThemeVariant::Deuteranopia => Self::deuteranopia(),
+            ThemeVariant::HighContrast => Self::high_contrast(),
         }
     }
 
@@ -570,6 +699,8 @@ impl Theme {
             Self::rog_red(),
             Self::matrix_green(),
             Self::cyberpunk(),
+            Self::deuteranopia(),
+            Self::high_contrast(),
         ]
     }
 }
@@ -585,6 +716,8 @@ impl ThemeVariant {
             ThemeVariant::RogRed => "ROG Red",
             ThemeVariant::MatrixGreen => "Matrix Green",
             ThemeVariant::Cyberpunk => "Cyberpunk",
+            ThemeVariant::Deuteranopia => "Deuteranopia",
+            ThemeVariant::HighContrast => "High Contrast",
         }
     }
 
@@ -598,6 +731,8 @@ impl ThemeVariant {
             ThemeVariant::RogRed => "rog_red",
             ThemeVariant::MatrixGreen => "matrix_green",
             ThemeVariant::Cyberpunk => "cyberpunk",
+            ThemeVariant::Deuteranopia => "deuteranopia",
+            ThemeVariant::HighContrast => "high_contrast",
         }
     }
 
@@ -611,6 +746,8 @@ impl ThemeVariant {
             "rog_red" => Some(ThemeVariant::RogRed),
             "matrix_green" => Some(ThemeVariant::MatrixGreen),
             "cyberpunk" => Some(ThemeVariant::Cyberpunk),
+            "deuteranopia" => Some(ThemeVariant::Deuteranopia),
+            "high_contrast" => Some(ThemeVariant::HighContrast),
             _ => None,
         }
     }
@@ -625,6 +762,8 @@ impl ThemeVariant {
             ThemeVariant::RogRed,
             ThemeVariant::MatrixGreen,
             ThemeVariant::Cyberpunk,
+            ThemeVariant::Deuteranopia,
+            ThemeVariant::HighContrast,
         ]
     }
 
@@ -637,7 +776,9 @@ impl ThemeVariant {
             ThemeVariant::Dracula => ThemeVariant::RogRed,
             ThemeVariant::RogRed => ThemeVariant::MatrixGreen,
             ThemeVariant::MatrixGreen => ThemeVariant::Cyberpunk,
-            ThemeVariant::Cyberpunk => ThemeVariant::TokyoNightNight,
+            ThemeVariant::Cyberpunk => ThemeVariant::Deuteranopia,
+            ThemeVariant::Deuteranopia => ThemeVariant::HighContrast,
+            ThemeVariant::HighContrast => ThemeVariant::TokyoNightNight,
         }
     }
 }
@@ -653,6 +794,8 @@ impl ColorPalette {
             ThemeVariant::RogRed => Theme::rog_red().colors,
             ThemeVariant::MatrixGreen => Theme::matrix_green().colors,
             ThemeVariant::Cyberpunk => Theme::cyberpunk().colors,
+            ThemeVariant::Deuteranopia => Theme::deuteranopia().colors,
+            ThemeVariant::HighContrast => Theme::high_contrast().colors,
         }
     }
 
@@ -684,6 +827,41 @@ impl ColorPalette {
     pub fn error(&self) -> &Color {
         &self.red
     }
+
+    /// Color for a GPU temperature in °C
+    pub fn temp_color(&self, celsius: f32) -> &Color {
+        if celsius > 80.0 {
+            &self.temp_hot
+        } else if celsius > 65.0 {
+            &self.temp_warm
+        } else if celsius >= 50.0 {
+            &self.temp_normal
+        } else {
+            &self.temp_cold
+        }
+    }
+
+    /// Color for a utilization percentage
+    pub fn usage_color(&self, percent: f32) -> &Color {
+        if percent > 70.0 {
+            &self.usage_high
+        } else if percent >= 30.0 {
+            &self.usage_medium
+        } else {
+            &self.usage_low
+        }
+    }
+
+    /// Color for power draw as a percentage of the limit
+    pub fn power_color(&self, percent: f32) -> &Color {
+        if percent > 85.0 {
+            &self.power_high
+        } else if percent >= 50.0 {
+            &self.power_normal
+        } else {
+            &self.power_efficient
+        }
+    }
 }
 
 /// Nerd Font icons for GPU dashboard
@@ -733,7 +911,44 @@ mod tests {
     #[test]
     fn test_all_themes() {
         let themes = Theme::all_themes();
-        assert_eq!(themes.len(), 7);
+        assert_eq!(themes.len(), 9);
+        assert_eq!(themes.len(), ThemeVariant::all().len());
+    }
+
+    #[test]
+    fn test_cycle_visits_every_variant() {
+        let mut variant = ThemeVariant::TokyoNightNight;
+        for expected in ThemeVariant::all() {
+            assert_eq!(variant, *expected);
+            assert_eq!(
+                ThemeVariant::from_config_key(variant.config_key()),
+                Some(variant)
+            );
+            variant = variant.next();
+        }
+        assert_eq!(variant, ThemeVariant::TokyoNightNight);
+    }
+
+    #[test]
+    fn test_accessible_states_step_up_in_brightness() {
+        fn luma(c: &Color) -> u32 {
+            (c.r as u32 * 299 + c.g as u32 * 587 + c.b as u32 * 114) / 1000
+        }
+
+        for variant in [ThemeVariant::Deuteranopia, ThemeVariant::HighContrast] {
+            let p = ColorPalette::from_variant(variant);
+            let temps: Vec<u32> = [40.0, 60.0, 75.0, 90.0]
+                .iter()
+                .map(|t| luma(p.temp_color(*t)))
+                .collect();
+            assert!(temps.windows(2).all(|w| w[0] < w[1]), "{:?}", temps);
+
+            let usage: Vec<u32> = [10.0, 50.0, 90.0]
+                .iter()
+                .map(|u| luma(p.usage_color(*u)))
+                .collect();
+            assert!(usage.windows(2).all(|w| w[0] < w[1]), "{:?}", usage);
+        }
     }
 
     #[test]
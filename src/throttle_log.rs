@@ -0,0 +1,194 @@
+//! Clock throttle event log
+//!
+//! Follows a GPU's throttle reasons between polls and records when each
+//! throttling episode starts and ends, keeping the most recent episodes in a
+//! ring buffer.
+
+use crate::nvml_backend::ThrottleReasons;
+use crate::{NvControlError, NvResult};
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+
+/// Episodes kept per GPU
+pub const THROTTLE_LOG_CAPACITY: usize = 50;
+
+/// One throttling episode
+#[derive(Debug, Clone)]
+pub struct ThrottleEvent {
+    pub started_at: DateTime<Local>,
+    /// `None` while the GPU is still throttling
+    pub ended_at: Option<DateTime<Local>>,
+    /// Every reason seen during the episode
+    pub reasons: ThrottleReasons,
+}
+
+impl ThrottleEvent {
+    pub fn duration_secs(&self) -> i64 {
+        (self.ended_at.unwrap_or_else(Local::now) - self.started_at).num_seconds()
+    }
+}
+
+/// What changed since the previous reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleTransition {
+    Started(ThrottleReasons),
+    /// Still throttling, with reasons that weren't set before
+    Added(ThrottleReasons),
+    Ended,
+}
+
+impl ThrottleTransition {
+    /// Whether thermal throttling began with this transition
+    pub fn is_thermal_onset(&self) -> bool {
+        match self {
+            Self::Started(reasons) | Self::Added(reasons) => reasons.is_thermal(),
+            Self::Ended => false,
+        }
+    }
+}
+
+/// Throttle history of one GPU
+#[derive(Debug, Clone)]
+pub struct ThrottleLog {
+    events: VecDeque<ThrottleEvent>,
+    current: Option<ThrottleReasons>,
+    available: bool,
+}
+
+impl Default for ThrottleLog {
+    fn default() -> Self {
+        Self {
+            events: VecDeque::with_capacity(THROTTLE_LOG_CAPACITY),
+            current: None,
+            // Assume support until the driver says otherwise
+            available: true,
+        }
+    }
+}
+
+impl ThrottleLog {
+    /// Feed the latest `get_throttle_reasons` result
+    pub fn record(&mut self, reading: NvResult<ThrottleReasons>) -> Option<ThrottleTransition> {
+        self.record_at(reading, Local::now())
+    }
+
+    fn record_at(
+        &mut self,
+        reading: NvResult<ThrottleReasons>,
+        now: DateTime<Local>,
+    ) -> Option<ThrottleTransition> {
+        let reasons = match reading {
+            Ok(reasons) => reasons,
+            Err(NvControlError::UnsupportedFeature(_)) => {
+                self.available = false;
+                self.current = None;
+                return None;
+            }
+            // Transient query failures keep the last known state
+            Err(_) => return None,
+        };
+        self.available = true;
+        self.current = Some(reasons);
+
+        let open = self.events.back_mut().filter(|e| e.ended_at.is_none());
+        match (open, reasons.is_throttling()) {
+            (None, false) => None,
+            (None, true) => {
+                if self.events.len() >= THROTTLE_LOG_CAPACITY {
+                    self.events.pop_front();
+                }
+                self.events.push_back(ThrottleEvent {
+                    started_at: now,
+                    ended_at: None,
+                    reasons,
+                });
+                Some(ThrottleTransition::Started(reasons))
+            }
+            (Some(event), true) => {
+                let added = ThrottleReasons(reasons.0 & !event.reasons.0);
+                event.reasons = ThrottleReasons(event.reasons.0 | reasons.0);
+                added
+                    .is_throttling()
+                    .then_some(ThrottleTransition::Added(added))
+            }
+            (Some(event), false) => {
+                event.ended_at = Some(now);
+                Some(ThrottleTransition::Ended)
+            }
+        }
+    }
+
+    /// Latest reasons; `None` before the first reading or when unsupported
+    pub fn current(&self) -> Option<ThrottleReasons> {
+        self.current
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.available
+    }
+
+    /// Short status for headers: the active reasons, "none" or "unavailable"
+    pub fn status_label(&self) -> String {
+        if !self.available {
+            return "unavailable".to_string();
+        }
+        self.current
+            .map(|r| r.summary())
+            .unwrap_or_else(|| "…".to_string())
+    }
+
+    /// Recorded episodes, oldest first
+    pub fn events(&self) -> impl DoubleEndedIterator<Item = &ThrottleEvent> {
+        self.events.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle_episodes() {
+        let mut log = ThrottleLog::default();
+        let t0 = Local::now();
+        let at = |secs| t0 + chrono::Duration::seconds(secs);
+
+        assert_eq!(
+            log.record_at(Ok(ThrottleReasons(ThrottleReasons::GPU_IDLE)), at(0)),
+            None
+        );
+        assert_eq!(log.status_label(), "none");
+
+        let power = ThrottleReasons(ThrottleReasons::SW_POWER_CAP);
+        let started = log.record_at(Ok(power), at(1)).unwrap();
+        assert_eq!(started, ThrottleTransition::Started(power));
+        assert!(!started.is_thermal_onset());
+
+        let both = ThrottleReasons(ThrottleReasons::SW_POWER_CAP | ThrottleReasons::SW_THERMAL);
+        let added = log.record_at(Ok(both), at(2)).unwrap();
+        assert!(added.is_thermal_onset());
+        assert_eq!(log.record_at(Ok(both), at(3)), None);
+        assert_eq!(log.status_label(), "thermal (software), power cap");
+
+        assert_eq!(
+            log.record_at(Ok(ThrottleReasons::default()), at(10)),
+            Some(ThrottleTransition::Ended)
+        );
+        let event = log.events().next().unwrap();
+        assert_eq!(event.reasons, both);
+        assert_eq!(event.duration_secs(), 9);
+
+        // A transient error keeps state; an unsupported driver marks it unavailable
+        log.record_at(Err(NvControlError::GpuQueryFailed("busy".into())), at(11));
+        assert!(log.is_available());
+        log.record_at(
+            Err(NvControlError::UnsupportedFeature("old".into())),
+            at(12),
+        );
+        assert_eq!(log.status_label(), "unavailable");
+    }
+}
@@ -0,0 +1,575 @@
+//! System tray icon
+//!
+//! Serves a StatusNotifierItem and its menu straight over D-Bus, without the
+//! GTK/libappindicator stack the old tray needed. KDE Plasma, waybar, the
+//! sway/Hyprland bars and GNOME with the AppIndicator extension show it on
+//! both X11 and Wayland. Panels that only speak the legacy XEmbed protocol
+//! have no StatusNotifierWatcher, in which case [`run`] returns an error
+//! instead of hanging around invisibly.
+
+use crate::nvml_backend::SharedNvmlBackend;
+use crate::overclocking::OverclockProfile;
+use crate::{NvControlError, NvResult};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use zbus::zvariant::{OwnedObjectPath, OwnedValue, Structure, Type, Value};
+
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const MENU_PATH: &str = "/MenuBar";
+const ITEM_INTERFACE: &str = "org.kde.StatusNotifierItem";
+const MENU_INTERFACE: &str = "com.canonical.dbusmenu";
+
+/// Edge length of the generated status icon in pixels
+const ICON_SIZE: i32 = 32;
+
+/// Fan profile the menu toggle applies to every fan
+const FAN_PRESET: &str = "Performance";
+
+/// Temperature band shown by the icon colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempLevel {
+    Cool,
+    Warm,
+    Hot,
+    Unknown,
+}
+
+impl TempLevel {
+    /// Same bands as the GUI's temperature colours
+    pub fn from_temp(celsius: Option<u32>) -> Self {
+        match celsius {
+            None => Self::Unknown,
+            Some(t) if t > 80 => Self::Hot,
+            Some(t) if t > 65 => Self::Warm,
+            Some(_) => Self::Cool,
+        }
+    }
+
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Self::Cool => [0x9e, 0xce, 0x6a],
+            Self::Warm => [0xe0, 0xaf, 0x68],
+            Self::Hot => [0xf7, 0x76, 0x8e],
+            Self::Unknown => [0x56, 0x5f, 0x89],
+        }
+    }
+}
+
+/// Filled disc in the level's colour, as StatusNotifierItem ARGB32 pixels
+pub fn icon_pixmap(level: TempLevel, size: i32) -> (i32, i32, Vec<u8>) {
+    let [r, g, b] = level.rgb();
+    let center = (size as f32 - 1.0) / 2.0;
+    let radius = size as f32 / 2.0 - 1.0;
+
+    let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let dist = ((x as f32 - center).powi(2) + (y as f32 - center).powi(2)).sqrt();
+            // One pixel of falloff keeps the edge from looking jagged
+            let alpha = (radius + 0.5 - dist).clamp(0.0, 1.0);
+            pixels.extend_from_slice(&[(alpha * 255.0) as u8, r, g, b]);
+        }
+    }
+    (size, size, pixels)
+}
+
+/// Something picked from the tray menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayAction {
+    StockClocks,
+    PerformanceOc,
+    ToggleFanPreset,
+    ToggleQuietMode,
+    OpenGui,
+    Quit,
+}
+
+impl TrayAction {
+    fn from_menu_id(id: i32) -> Option<Self> {
+        match id {
+            3 => Some(Self::StockClocks),
+            4 => Some(Self::PerformanceOc),
+            5 => Some(Self::ToggleFanPreset),
+            7 => Some(Self::OpenGui),
+            8 => Some(Self::Quit),
+            9 => Some(Self::ToggleQuietMode),
+            _ => None,
+        }
+    }
+}
+
+/// Live values shared between the poll loop and the D-Bus handlers
+#[derive(Debug, Clone, Default)]
+struct TrayStatus {
+    gpu_index: u32,
+    gpu_name: String,
+    temperature: Option<u32>,
+    fan_preset_active: bool,
+    quiet_mode: bool,
+}
+
+impl TrayStatus {
+    fn temperature_label(&self) -> String {
+        match self.temperature {
+            Some(t) => format!("GPU {}: {}°C", self.gpu_index, t),
+            None => format!("GPU {}: temperature unavailable", self.gpu_index),
+        }
+    }
+}
+
+type SharedStatus = Arc<Mutex<TrayStatus>>;
+
+fn read_status(status: &SharedStatus) -> TrayStatus {
+    status.lock().map(|s| s.clone()).unwrap_or_default()
+}
+
+type ToolTip = (String, Vec<(i32, i32, Vec<u8>)>, String, String);
+
+struct StatusNotifierItem {
+    status: SharedStatus,
+    actions: Sender<TrayAction>,
+}
+
+#[zbus::interface(name = "org.kde.StatusNotifierItem")]
+impl StatusNotifierItem {
+    #[zbus(property)]
+    fn category(&self) -> &str {
+        "Hardware"
+    }
+
+    #[zbus(property)]
+    fn id(&self) -> &str {
+        "nvcontrol"
+    }
+
+    #[zbus(property)]
+    fn title(&self) -> &str {
+        "nvcontrol"
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> &str {
+        "Active"
+    }
+
+    #[zbus(property)]
+    fn icon_name(&self) -> &str {
+        ""
+    }
+
+    #[zbus(property)]
+    fn icon_pixmap(&self) -> Vec<(i32, i32, Vec<u8>)> {
+        let level = TempLevel::from_temp(read_status(&self.status).temperature);
+        vec![icon_pixmap(level, ICON_SIZE)]
+    }
+
+    #[zbus(property)]
+    fn tool_tip(&self) -> ToolTip {
+        let status = read_status(&self.status);
+        (
+            String::new(),
+            Vec::new(),
+            status.gpu_name.clone(),
+            if status.quiet_mode {
+                format!("{} · quiet mode on", status.temperature_label())
+            } else {
+                status.temperature_label()
+            },
+        )
+    }
+
+    #[zbus(property)]
+    fn item_is_menu(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn menu(&self) -> OwnedObjectPath {
+        OwnedObjectPath::try_from(MENU_PATH).unwrap_or_default()
+    }
+
+    /// Left click opens the GUI
+    fn activate(&self, _x: i32, _y: i32) {
+        let _ = self.actions.send(TrayAction::OpenGui);
+    }
+
+    fn secondary_activate(&self, _x: i32, _y: i32) {}
+
+    fn context_menu(&self, _x: i32, _y: i32) {}
+
+    fn scroll(&self, _delta: i32, _orientation: String) {}
+}
+
+/// One dbusmenu node: id, properties and child nodes
+#[derive(Debug, Serialize, Type)]
+struct MenuLayout {
+    id: i32,
+    properties: HashMap<String, Value<'static>>,
+    children: Vec<Value<'static>>,
+}
+
+fn menu_item(label: &str) -> HashMap<String, Value<'static>> {
+    HashMap::from([("label".to_string(), Value::from(label.to_string()))])
+}
+
+fn separator() -> HashMap<String, Value<'static>> {
+    HashMap::from([("type".to_string(), Value::from("separator"))])
+}
+
+/// Menu entries in display order, keyed by the ids `TrayAction` maps from
+fn menu_entries(status: &TrayStatus) -> Vec<(i32, HashMap<String, Value<'static>>)> {
+    let mut temperature = menu_item(&status.temperature_label());
+    temperature.insert("enabled".to_string(), Value::from(false));
+
+    let mut fan = menu_item(&format!("{} fan curve", FAN_PRESET));
+    fan.insert("toggle-type".to_string(), Value::from("checkmark"));
+    fan.insert(
+        "toggle-state".to_string(),
+        Value::from(i32::from(status.fan_preset_active)),
+    );
+
+    let mut quiet = menu_item("Quiet mode");
+    quiet.insert("toggle-type".to_string(), Value::from("checkmark"));
+    quiet.insert(
+        "toggle-state".to_string(),
+        Value::from(i32::from(status.quiet_mode)),
+    );
+
+    vec![
+        (1, temperature),
+        (2, separator()),
+        (3, menu_item("Stock clocks")),
+        (4, menu_item("Performance overclock")),
+        (5, fan),
+        (9, quiet),
+        (6, separator()),
+        (7, menu_item("Open nvcontrol")),
+        (8, menu_item("Quit")),
+    ]
+}
+
+struct DbusMenu {
+    status: SharedStatus,
+    actions: Sender<TrayAction>,
+    revision: Arc<Mutex<u32>>,
+}
+
+impl DbusMenu {
+    fn entry_properties(&self, id: i32) -> Option<HashMap<String, Value<'static>>> {
+        menu_entries(&read_status(&self.status))
+            .into_iter()
+            .find(|(entry_id, _)| *entry_id == id)
+            .map(|(_, properties)| properties)
+    }
+
+    fn handle_event(&self, id: i32, event_id: &str) {
+        if event_id != "clicked" {
+            return;
+        }
+        if let Some(action) = TrayAction::from_menu_id(id) {
+            let _ = self.actions.send(action);
+        }
+    }
+}
+
+#[zbus::interface(name = "com.canonical.dbusmenu")]
+impl DbusMenu {
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        3
+    }
+
+    #[zbus(property)]
+    fn text_direction(&self) -> &str {
+        "ltr"
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> &str {
+        "normal"
+    }
+
+    #[zbus(property)]
+    fn icon_theme_path(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn get_layout(
+        &self,
+        _parent_id: i32,
+        _recursion_depth: i32,
+        _property_names: Vec<String>,
+    ) -> (u32, MenuLayout) {
+        let children = menu_entries(&read_status(&self.status))
+            .into_iter()
+            .map(|(id, properties)| {
+                Value::from(Structure::from((
+                    id,
+                    properties,
+                    Vec::<Value<'static>>::new(),
+                )))
+            })
+            .collect();
+        let root = MenuLayout {
+            id: 0,
+            properties: HashMap::from([("children-display".to_string(), Value::from("submenu"))]),
+            children,
+        };
+        (self.revision.lock().map(|r| *r).unwrap_or(0), root)
+    }
+
+    fn get_group_properties(
+        &self,
+        ids: Vec<i32>,
+        _property_names: Vec<String>,
+    ) -> Vec<(i32, HashMap<String, Value<'static>>)> {
+        menu_entries(&read_status(&self.status))
+            .into_iter()
+            .filter(|(id, _)| ids.is_empty() || ids.contains(id))
+            .collect()
+    }
+
+    fn get_property(&self, id: i32, name: String) -> zbus::fdo::Result<OwnedValue> {
+        self.entry_properties(id)
+            .and_then(|mut properties| properties.remove(&name))
+            .and_then(|value| OwnedValue::try_from(value).ok())
+            .ok_or_else(|| zbus::fdo::Error::InvalidArgs(format!("No property {name} on {id}")))
+    }
+
+    fn event(&self, id: i32, event_id: String, _data: OwnedValue, _timestamp: u32) {
+        self.handle_event(id, &event_id);
+    }
+
+    fn event_group(&self, events: Vec<(i32, String, OwnedValue, u32)>) -> Vec<i32> {
+        for (id, event_id, _, _) in &events {
+            self.handle_event(*id, event_id);
+        }
+        Vec::new()
+    }
+
+    fn about_to_show(&self, _id: i32) -> bool {
+        false
+    }
+
+    fn about_to_show_group(&self, _ids: Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+        (Vec::new(), Vec::new())
+    }
+}
+
+fn dbus_error(e: zbus::Error) -> NvControlError {
+    NvControlError::RuntimeError(format!("D-Bus error: {}", e))
+}
+
+/// Show the tray icon for `gpu_index` until Quit is picked from its menu
+pub fn run(backend: &SharedNvmlBackend, gpu_index: u32, interval: Duration) -> NvResult<()> {
+    let status: SharedStatus = Arc::new(Mutex::new(TrayStatus {
+        gpu_index,
+        gpu_name: backend
+            .get_name(gpu_index)
+            .unwrap_or_else(|_| "NVIDIA GPU".to_string()),
+        temperature: backend.get_temperature(gpu_index).ok(),
+        fan_preset_active: false,
+        quiet_mode: crate::power::is_quiet_mode(),
+    }));
+    let revision = Arc::new(Mutex::new(1u32));
+    let (tx, rx) = mpsc::channel();
+
+    let bus_name = format!("org.kde.StatusNotifierItem-{}-1", std::process::id());
+    let connection = zbus::blocking::connection::Builder::session()
+        .and_then(|b| b.name(bus_name.as_str()))
+        .and_then(|b| {
+            b.serve_at(
+                ITEM_PATH,
+                StatusNotifierItem {
+                    status: status.clone(),
+                    actions: tx.clone(),
+                },
+            )
+        })
+        .and_then(|b| {
+            b.serve_at(
+                MENU_PATH,
+                DbusMenu {
+                    status: status.clone(),
+                    actions: tx,
+                    revision: revision.clone(),
+                },
+            )
+        })
+        .and_then(|b| b.build())
+        .map_err(dbus_error)?;
+
+    connection
+        .call_method(
+            Some("org.kde.StatusNotifierWatcher"),
+            "/StatusNotifierWatcher",
+            Some("org.kde.StatusNotifierWatcher"),
+            "RegisterStatusNotifierItem",
+            &(bus_name.as_str(),),
+        )
+        .map_err(|e| {
+            NvControlError::RuntimeError(format!(
+                "No StatusNotifier tray on this desktop ({}). KDE Plasma, waybar and \
+                 GNOME with the AppIndicator extension provide one; legacy XEmbed \
+                 trays are not supported",
+                e
+            ))
+        })?;
+
+    event_loop(&connection, backend, &status, &revision, &rx, interval)
+}
+
+fn event_loop(
+    connection: &zbus::blocking::Connection,
+    backend: &SharedNvmlBackend,
+    status: &SharedStatus,
+    revision: &Mutex<u32>,
+    actions: &Receiver<TrayAction>,
+    interval: Duration,
+) -> NvResult<()> {
+    let mut shown = read_status(status);
+    loop {
+        match actions.recv_timeout(interval) {
+            Ok(TrayAction::Quit) | Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            Ok(action) => run_action(backend, status, action),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+
+        let temperature = backend.get_temperature(shown.gpu_index).ok();
+        if let Ok(mut current) = status.lock() {
+            current.temperature = temperature;
+            // Also picks up `nvctl quiet` and the GUI toggle
+            current.quiet_mode = crate::power::is_quiet_mode();
+        }
+        let latest = read_status(status);
+        if latest.temperature == shown.temperature
+            && latest.fan_preset_active == shown.fan_preset_active
+            && latest.quiet_mode == shown.quiet_mode
+        {
+            continue;
+        }
+        shown = latest;
+
+        // Panels re-read the icon, tooltip and menu layout on these signals
+        for signal in ["NewIcon", "NewToolTip"] {
+            connection
+                .emit_signal(None::<&str>, ITEM_PATH, ITEM_INTERFACE, signal, &())
+                .map_err(dbus_error)?;
+        }
+        let next = revision
+            .lock()
+            .map(|mut r| {
+                *r += 1;
+                *r
+            })
+            .unwrap_or(1);
+        connection
+            .emit_signal(
+                None::<&str>,
+                MENU_PATH,
+                MENU_INTERFACE,
+                "LayoutUpdated",
+                &(next, 0i32),
+            )
+            .map_err(dbus_error)?;
+    }
+}
+
+fn run_action(backend: &SharedNvmlBackend, status: &SharedStatus, action: TrayAction) {
+    let result = match action {
+        TrayAction::StockClocks => crate::overclocking::reset_to_stock().map(|_| ()),
+        TrayAction::PerformanceOc => {
+            let profile = OverclockProfile::performance();
+            crate::overclocking::apply_overclock_profile(&profile).map(|()| {
+                if let Ok(mut notifier) = crate::notifications::NotificationManager::new() {
+                    let _ = notifier.notify_overclock_applied(
+                        profile.gpu_clock_offset,
+                        profile.memory_clock_offset,
+                    );
+                }
+            })
+        }
+        TrayAction::ToggleFanPreset => toggle_fan_preset(backend, status),
+        TrayAction::ToggleQuietMode => {
+            let enable = !read_status(status).quiet_mode;
+            crate::power::set_quiet_mode(backend, enable).map(|warnings| {
+                for warning in warnings {
+                    log::warn!("Quiet mode: {}", warning);
+                }
+            })
+        }
+        TrayAction::OpenGui => std::process::Command::new("nvcontrol")
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| {
+                NvControlError::CommandFailed(format!("Failed to launch nvcontrol: {}", e))
+            }),
+        TrayAction::Quit => Ok(()),
+    };
+
+    if let Err(e) = result
+        && let Ok(mut notifier) = crate::notifications::NotificationManager::new()
+    {
+        let _ = notifier.notify_gpu_error(&e.to_string());
+    }
+}
+
+/// Switch every fan between the preset curve and automatic control
+fn toggle_fan_preset(backend: &SharedNvmlBackend, status: &SharedStatus) -> NvResult<()> {
+    let active = read_status(status).fan_preset_active;
+    if active {
+        for fan in crate::fan::list_fans_with_backend(backend) {
+            crate::fan::reset_fan_to_auto(fan.id)?;
+            let _ = crate::fan_daemon::clear_curve(backend, fan.id);
+        }
+    } else {
+        let profile = crate::fan::get_predefined_profiles()
+            .into_iter()
+            .find(|p| p.name == FAN_PRESET)
+            .ok_or_else(|| NvControlError::ConfigError(format!("No {} fan profile", FAN_PRESET)))?;
+        let curve = profile.curves.values().next().cloned().unwrap_or_default();
+        let report = crate::multi_gpu::apply_fan_curve_all(backend, &curve)?;
+        if report.applied() == 0 {
+            return Err(NvControlError::FanControlNotSupported);
+        }
+    }
+
+    if let Ok(mut current) = status.lock() {
+        current.fan_preset_active = !active;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_colour_and_menu_ids() {
+        assert_eq!(TempLevel::from_temp(Some(45)), TempLevel::Cool);
+        assert_eq!(TempLevel::from_temp(Some(70)), TempLevel::Warm);
+        assert_eq!(TempLevel::from_temp(Some(85)), TempLevel::Hot);
+        assert_eq!(TempLevel::from_temp(None), TempLevel::Unknown);
+
+        let (w, h, pixels) = icon_pixmap(TempLevel::Hot, 16);
+        assert_eq!((w, h), (16, 16));
+        assert_eq!(pixels.len(), 16 * 16 * 4);
+        // Corners are transparent, the centre is opaque red
+        assert_eq!(pixels[0], 0);
+        let center = ((8 * 16 + 8) * 4) as usize;
+        assert_eq!(&pixels[center..center + 4], &[255, 0xf7, 0x76, 0x8e]);
+
+        // Every clickable entry maps to an action, labels and separators don't
+        let entries = menu_entries(&TrayStatus::default());
+        let clickable: Vec<_> = entries
+            .iter()
+            .filter_map(|(id, _)| TrayAction::from_menu_id(*id))
+            .collect();
+        assert_eq!(clickable.len(), 6);
+        assert_eq!(TrayAction::from_menu_id(1), None);
+        assert_eq!(TrayAction::from_menu_id(8), Some(TrayAction::Quit));
+    }
+}
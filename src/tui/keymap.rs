@@ -0,0 +1,562 @@
+//! Remappable TUI key bindings
+//!
+//! Actions map to one or more key sequences. The defaults match the original
+//! hardcoded keys; the `[keybindings]` config section replaces the keys of
+//! any action it names. Multi-key sequences such as `gg` wait for the next
+//! key, falling back to the shorter binding after a short timeout.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// How long a sequence prefix such as the first `g` of `gg` waits
+const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// A single key press
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyPress {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyPress {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            ctrl: modifiers.contains(KeyModifiers::CONTROL),
+        }
+    }
+
+    fn plain(code: KeyCode) -> Self {
+        Self { code, ctrl: false }
+    }
+}
+
+/// Something a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Action {
+    Quit,
+    Help,
+    CycleTheme,
+    NextTab,
+    PrevTab,
+    NextGpu,
+    PrevGpu,
+    HottestGpu,
+    BusiestGpu,
+    Menu,
+    NvtopView,
+    DashboardView,
+    Pause,
+    RevertOc,
+    ToggleSettings,
+    Up,
+    Down,
+    Top,
+    Bottom,
+    Filter,
+    Sort,
+    ProcessTree,
+    ToggleGraphs,
+    ClockHistogram,
+    ResetHistogram,
+    EditOc,
+    EditFanCurve,
+    Increase,
+    Decrease,
+    MemoryUp,
+    MemoryDown,
+    SupportBundle,
+    WorkflowHint,
+    Refresh,
+}
+
+/// Action, help section, config name, description, default keys
+type ActionEntry = (
+    Action,
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static [&'static str],
+);
+
+impl Action {
+    /// Every action in help-overlay order
+    const TABLE: &'static [ActionEntry] = &[
+        (Action::Quit, "Global", "quit", "Quit", &["q"]),
+        (Action::Help, "Global", "help", "Toggle help", &["?", "F1"]),
+        (Action::CycleTheme, "Global", "theme", "Cycle theme", &["t"]),
+        (
+            Action::NextTab,
+            "Navigation",
+            "next_tab",
+            "Next tab/GPU",
+            &["Tab"],
+        ),
+        (
+            Action::PrevTab,
+            "Navigation",
+            "prev_tab",
+            "Previous tab/GPU",
+            &["Shift+Tab"],
+        ),
+        (
+            Action::NextGpu,
+            "Navigation",
+            "next_gpu",
+            "Next GPU",
+            &["Right"],
+        ),
+        (
+            Action::PrevGpu,
+            "Navigation",
+            "prev_gpu",
+            "Previous GPU",
+            &["Left"],
+        ),
+        (
+            Action::HottestGpu,
+            "Navigation",
+            "hottest_gpu",
+            "Jump to hottest GPU",
+            &["H"],
+        ),
+        (
+            Action::BusiestGpu,
+            "Navigation",
+            "busiest_gpu",
+            "Jump to busiest GPU",
+            &["U"],
+        ),
+        (Action::Menu, "Navigation", "menu", "Main menu", &["m"]),
+        (
+            Action::NvtopView,
+            "Navigation",
+            "nvtop",
+            "Nvtop view",
+            &["n"],
+        ),
+        (
+            Action::DashboardView,
+            "Navigation",
+            "dashboard",
+            "Dashboard view",
+            &["d"],
+        ),
+        (
+            Action::Pause,
+            "Controls",
+            "pause",
+            "Pause updates",
+            &["Space", "p"],
+        ),
+        (
+            Action::RevertOc,
+            "Controls",
+            "revert_oc",
+            "Revert last overclock",
+            &["u"],
+        ),
+        (
+            Action::ToggleSettings,
+            "Controls",
+            "settings",
+            "Settings overlay",
+            &["s"],
+        ),
+        (Action::Up, "Lists", "up", "Previous row", &["Up", "k"]),
+        (Action::Down, "Lists", "down", "Next row", &["Down", "j"]),
+        (Action::Top, "Lists", "top", "First row", &["gg", "Home"]),
+        (Action::Bottom, "Lists", "bottom", "Last row", &["G", "End"]),
+        (
+            Action::Filter,
+            "Lists",
+            "filter",
+            "Filter processes",
+            &["f", "/"],
+        ),
+        (Action::Sort, "Lists", "sort", "Sort by column", &["s"]),
+        (
+            Action::ProcessTree,
+            "Lists",
+            "process_tree",
+            "Group processes by parent",
+            &["T"],
+        ),
+        (
+            Action::ToggleGraphs,
+            "Lists",
+            "graphs",
+            "Toggle graphs",
+            &["g"],
+        ),
+        (
+            Action::ClockHistogram,
+            "Performance tab",
+            "clock_histogram",
+            "Clock histogram / graphs",
+            &["c"],
+        ),
+        (
+            Action::ResetHistogram,
+            "Performance tab",
+            "reset_histogram",
+            "Reset clock histogram",
+            &["z"],
+        ),
+        (
+            Action::EditOc,
+            "Overclock & Fan tabs",
+            "edit_oc",
+            "Edit offsets",
+            &["o"],
+        ),
+        (
+            Action::EditFanCurve,
+            "Overclock & Fan tabs",
+            "edit_fan_curve",
+            "Edit fan curve",
+            &["f"],
+        ),
+        (
+            Action::Increase,
+            "Overclock & Fan tabs",
+            "increase",
+            "Power limit / fan speed up",
+            &["+", "="],
+        ),
+        (
+            Action::Decrease,
+            "Overclock & Fan tabs",
+            "decrease",
+            "Power limit / fan speed down",
+            &["-"],
+        ),
+        (
+            Action::MemoryUp,
+            "Overclock & Fan tabs",
+            "memory_up",
+            "Memory offset up",
+            &["]"],
+        ),
+        (
+            Action::MemoryDown,
+            "Overclock & Fan tabs",
+            "memory_down",
+            "Memory offset down",
+            &["["],
+        ),
+        (
+            Action::SupportBundle,
+            "Drivers & CUDA/AI tabs",
+            "support_bundle",
+            "Create support bundle",
+            &["b"],
+        ),
+        (
+            Action::WorkflowHint,
+            "Drivers & CUDA/AI tabs",
+            "workflow_hint",
+            "Support workflow hint",
+            &["x"],
+        ),
+        (
+            Action::Refresh,
+            "Drivers & CUDA/AI tabs",
+            "refresh",
+            "Refresh diagnostics",
+            &["r"],
+        ),
+    ];
+
+    fn entry(self) -> &'static ActionEntry {
+        Self::TABLE
+            .iter()
+            .find(|entry| entry.0 == self)
+            .expect("every action has a table entry")
+    }
+
+    /// Name used in the `[keybindings]` config section
+    pub fn config_name(self) -> &'static str {
+        self.entry().2
+    }
+
+    pub fn from_config_name(name: &str) -> Option<Self> {
+        Self::TABLE
+            .iter()
+            .find(|entry| entry.2 == name)
+            .map(|entry| entry.0)
+    }
+}
+
+/// Parse a key spec: a named key (`Tab`, `F1`, `Space`, `Shift+Tab`),
+/// `Ctrl+<key>`, or a run of characters typed in sequence (`gg`)
+pub fn parse_keys(spec: &str) -> Option<Vec<KeyPress>> {
+    if spec.is_empty() {
+        return None;
+    }
+    if let Some(rest) = spec
+        .strip_prefix("Ctrl+")
+        .or_else(|| spec.strip_prefix("ctrl+"))
+    {
+        let key = parse_named(rest).or_else(|| {
+            let mut chars = rest.chars();
+            let c = chars.next()?;
+            chars.next().is_none().then_some(KeyCode::Char(c))
+        })?;
+        return Some(vec![KeyPress {
+            code: key,
+            ctrl: true,
+        }]);
+    }
+    if let Some(code) = parse_named(spec) {
+        return Some(vec![KeyPress::plain(code)]);
+    }
+    Some(
+        spec.chars()
+            .map(|c| KeyPress::plain(KeyCode::Char(c)))
+            .collect(),
+    )
+}
+
+fn parse_named(name: &str) -> Option<KeyCode> {
+    let code = match name.to_ascii_lowercase().as_str() {
+        "tab" => KeyCode::Tab,
+        "shift+tab" | "backtab" => KeyCode::BackTab,
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "space" => KeyCode::Char(' '),
+        "backspace" => KeyCode::Backspace,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        f if f.len() > 1 && f.starts_with('f') => KeyCode::F(f[1..].parse().ok()?),
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// Render a key sequence the way it would be written in config
+pub fn format_keys(keys: &[KeyPress]) -> String {
+    keys.iter()
+        .map(|key| {
+            let name = match key.code {
+                KeyCode::Char(' ') => "Space".to_string(),
+                KeyCode::Char(c) => c.to_string(),
+                KeyCode::BackTab => "Shift+Tab".to_string(),
+                KeyCode::F(n) => format!("F{}", n),
+                KeyCode::PageUp => "PageUp".to_string(),
+                KeyCode::PageDown => "PageDown".to_string(),
+                other => format!("{:?}", other),
+            };
+            if key.ctrl {
+                format!("Ctrl+{}", name)
+            } else {
+                name
+            }
+        })
+        .collect()
+}
+
+/// Action bindings plus the state of a partly typed sequence
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(Action, Vec<KeyPress>)>,
+    pending: Vec<KeyPress>,
+    pending_since: Option<Instant>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&BTreeMap::new()).0
+    }
+}
+
+impl Keymap {
+    /// Build from the `[keybindings]` config section, returning problems
+    /// with it alongside; bad entries keep the action's default keys
+    pub fn from_config(overrides: &BTreeMap<String, Vec<String>>) -> (Self, Vec<String>) {
+        let mut problems = Vec::new();
+        for name in overrides.keys() {
+            if Action::from_config_name(name).is_none() {
+                problems.push(format!("Unknown keybinding action '{}'", name));
+            }
+        }
+
+        let mut bindings = Vec::new();
+        for (action, _, name, _, defaults) in Action::TABLE {
+            let specs: Vec<&str> = match overrides.get(*name) {
+                Some(keys) => keys.iter().map(String::as_str).collect(),
+                None => defaults.to_vec(),
+            };
+            let parsed: Option<Vec<_>> = specs.iter().map(|spec| parse_keys(spec)).collect();
+            let keys = parsed.unwrap_or_else(|| {
+                problems.push(format!("Invalid keys for '{}': {:?}", name, specs));
+                defaults
+                    .iter()
+                    .filter_map(|spec| parse_keys(spec))
+                    .collect()
+            });
+            bindings.extend(keys.into_iter().map(|keys| (*action, keys)));
+        }
+
+        (
+            Self {
+                bindings,
+                pending: Vec::new(),
+                pending_since: None,
+            },
+            problems,
+        )
+    }
+
+    /// Whether `keys` is bound to `action`
+    pub fn binds(&self, action: Action, keys: &[KeyPress]) -> bool {
+        self.bindings
+            .iter()
+            .any(|(bound, seq)| *bound == action && seq == keys)
+    }
+
+    /// Every action bound to `keys`; the current view decides which applies
+    pub fn actions_for(&self, keys: &[KeyPress]) -> Vec<Action> {
+        self.bindings
+            .iter()
+            .filter(|(_, seq)| seq == keys)
+            .map(|(action, _)| *action)
+            .collect()
+    }
+
+    /// Feed a key press. Returns the completed sequences to dispatch: none
+    /// while a multi-key sequence is in progress, and possibly two when a
+    /// started sequence is abandoned.
+    pub fn press(&mut self, key: KeyPress, now: Instant) -> Vec<Vec<KeyPress>> {
+        let mut seq = std::mem::take(&mut self.pending);
+        seq.push(key);
+        self.pending_since = None;
+
+        if self.is_strict_prefix(&seq) {
+            self.pending = seq;
+            self.pending_since = Some(now);
+            return Vec::new();
+        }
+        if seq.len() == 1 || self.is_bound(&seq) {
+            return vec![seq];
+        }
+
+        // The typed prefix led nowhere: it stands on its own, then the new key
+        seq.pop();
+        let mut dispatched = vec![seq];
+        dispatched.extend(self.press(key, now));
+        dispatched
+    }
+
+    /// A sequence prefix that has waited too long, to dispatch on its own
+    pub fn expire(&mut self, now: Instant) -> Option<Vec<KeyPress>> {
+        let since = self.pending_since?;
+        if now.duration_since(since) < SEQUENCE_TIMEOUT {
+            return None;
+        }
+        self.pending_since = None;
+        Some(std::mem::take(&mut self.pending))
+    }
+
+    fn is_bound(&self, seq: &[KeyPress]) -> bool {
+        self.bindings.iter().any(|(_, keys)| keys == seq)
+    }
+
+    fn is_strict_prefix(&self, seq: &[KeyPress]) -> bool {
+        self.bindings
+            .iter()
+            .any(|(_, keys)| keys.len() > seq.len() && keys.starts_with(seq))
+    }
+
+    /// Help-overlay lines listing the effective bindings by section
+    pub fn help_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut section = "";
+        for (action, action_section, _, description, _) in Action::TABLE {
+            if *action_section != section {
+                if !section.is_empty() {
+                    lines.push(String::new());
+                }
+                section = action_section;
+                lines.push(format!(" {}:", section));
+            }
+            let keys: Vec<String> = self
+                .bindings
+                .iter()
+                .filter(|(bound, _)| bound == action)
+                .map(|(_, keys)| format_keys(keys))
+                .collect();
+            let keys = if keys.is_empty() {
+                "(unbound)".to_string()
+            } else {
+                keys.join(", ")
+            };
+            lines.push(format!("   {:<14} {}", keys, description));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyPress {
+        KeyPress::plain(KeyCode::Char(c))
+    }
+
+    #[test]
+    fn test_sequences_and_overrides() {
+        let mut keymap = Keymap::default();
+        let t0 = Instant::now();
+
+        // `g` waits for a possible `gg`
+        assert!(keymap.press(key('g'), t0).is_empty());
+        let top = keymap.press(key('g'), t0);
+        assert!(keymap.binds(Action::Top, &top[0]));
+
+        // A lone `g` toggles graphs once the sequence times out...
+        assert!(keymap.press(key('g'), t0).is_empty());
+        assert_eq!(keymap.expire(t0), None);
+        let lone = keymap.expire(t0 + SEQUENCE_TIMEOUT).unwrap();
+        assert!(keymap.binds(Action::ToggleGraphs, &lone));
+
+        // ...or when another key follows it
+        keymap.press(key('g'), t0);
+        let both = keymap.press(key('j'), t0);
+        assert!(keymap.binds(Action::ToggleGraphs, &both[0]));
+        assert!(keymap.binds(Action::Down, &both[1]));
+
+        let mut overrides = BTreeMap::new();
+        overrides.insert("quit".to_string(), vec!["Ctrl+q".to_string()]);
+        overrides.insert("bogus".to_string(), vec!["z".to_string()]);
+        let (keymap, problems) = Keymap::from_config(&overrides);
+        assert_eq!(problems.len(), 1);
+        assert!(!keymap.binds(Action::Quit, &[key('q')]));
+        assert!(keymap.binds(
+            Action::Quit,
+            &[KeyPress::new(KeyCode::Char('q'), KeyModifiers::CONTROL)]
+        ));
+        assert!(
+            keymap
+                .help_lines()
+                .iter()
+                .any(|line| line.contains("Ctrl+q") && line.contains("Quit"))
+        );
+        assert!(
+            keymap
+                .help_lines()
+                .iter()
+                .any(|line| line.contains("Shift+Tab"))
+        );
+    }
+}
@@ -1,14 +1,17 @@
 //! TUI module for nvcontrol
 
 mod event;
+mod keymap;
 mod terminal;
 
+pub use crate::gpu_profiles::OcPreset;
 pub use event::{Event, EventHandler};
+pub use keymap::{Action, KeyPress, Keymap};
 pub use terminal::Tui;
 
 use crate::config::TuiSessionState;
 use crate::dlss::{self, DlssController};
-use crate::nvml_backend::GuiBackendContext;
+use crate::nvml_backend::{EngineUtilization, GuiBackendContext, MigInstance, VideoEngineStats};
 use crate::{NvResult, gui_tuner, nvidia_profiler, themes};
 use crossterm::event::{KeyCode, KeyModifiers, MouseEvent, MouseEventKind};
 use ratatui::{
@@ -21,11 +24,13 @@ use ratatui::{
         ScrollbarState, Sparkline, Table, TableState, Tabs,
     },
 };
-use std::collections::VecDeque;
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
 use std::fs;
 use std::time::{Duration, Instant};
 
-const MAX_HISTORY: usize = 120; // 2 minutes at 1Hz
+/// Bounds for the `sparkline_samples` config (10 s to 1 h at 1Hz)
+const HISTORY_RANGE: std::ops::RangeInclusive<usize> = 10..=3600;
 
 /// GPU metrics snapshot
 #[derive(Clone)]
@@ -33,9 +38,14 @@ pub struct GpuMetrics {
     pub timestamp: Instant,
     pub temperature: f64,
     pub gpu_utilization: f64,
-    pub memory_utilization: f64,
+    /// Memory controller (bandwidth) activity
+    pub memory_controller_utilization: f64,
+    /// VRAM capacity in use
+    pub vram_used_percent: f64,
     pub power_draw: f64,
     pub fan_speed: f64,
+    /// First fan's RPM; `None` when the board only reports duty cycle
+    pub fan_rpm: Option<u32>,
     pub gpu_clock: f64,
     pub memory_clock: f64,
 }
@@ -73,60 +83,99 @@ pub enum Tab {
 }
 
 impl Tab {
-    fn titles() -> Vec<&'static str> {
-        vec![
-            "Overview",
-            "Performance",
-            "Memory",
-            "Temperature",
-            "Power",
-            "Processes",
-            "Overclock",
-            "Fan Control",
-            "Profiles",
-            "Tuner",
-            "Profiler",
-            "OSD",
-            "Drivers",
-            "DLSS",
-            "CUDA/AI",
-            "Settings",
-        ]
+    const ALL: [Tab; 16] = [
+        Tab::Overview,
+        Tab::Performance,
+        Tab::Memory,
+        Tab::Temperature,
+        Tab::Power,
+        Tab::Processes,
+        Tab::Overclocking,
+        Tab::FanControl,
+        Tab::Profiles,
+        Tab::Tuner,
+        Tab::Profiler,
+        Tab::Osd,
+        Tab::Drivers,
+        Tab::Dlss,
+        Tab::CudaAi,
+        Tab::Settings,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Tab::Overview => "Overview",
+            Tab::Performance => "Performance",
+            Tab::Memory => "Memory",
+            Tab::Temperature => "Temperature",
+            Tab::Power => "Power",
+            Tab::Processes => "Processes",
+            Tab::Overclocking => "Overclock",
+            Tab::FanControl => "Fan Control",
+            Tab::Profiles => "Profiles",
+            Tab::Tuner => "Tuner",
+            Tab::Profiler => "Profiler",
+            Tab::Osd => "OSD",
+            Tab::Drivers => "Drivers",
+            Tab::Dlss => "DLSS",
+            Tab::CudaAi => "CUDA/AI",
+            Tab::Settings => "Settings",
+        }
     }
 
-    fn from_index(index: usize) -> Self {
-        match index {
-            0 => Tab::Overview,
-            1 => Tab::Performance,
-            2 => Tab::Memory,
-            3 => Tab::Temperature,
-            4 => Tab::Power,
-            5 => Tab::Processes,
-            6 => Tab::Overclocking,
-            7 => Tab::FanControl,
-            8 => Tab::Profiles,
-            9 => Tab::Tuner,
-            10 => Tab::Profiler,
-            11 => Tab::Osd,
-            12 => Tab::Drivers,
-            13 => Tab::Dlss,
-            14 => Tab::CudaAi,
-            _ => Tab::Settings,
+    /// Name used in the `visible_tabs` config list
+    fn config_name(self) -> &'static str {
+        match self {
+            Tab::Overview => "overview",
+            Tab::Performance => "performance",
+            Tab::Memory => "memory",
+            Tab::Temperature => "temperature",
+            Tab::Power => "power",
+            Tab::Processes => "processes",
+            Tab::Overclocking => "overclock",
+            Tab::FanControl => "fan-control",
+            Tab::Profiles => "profiles",
+            Tab::Tuner => "tuner",
+            Tab::Profiler => "profiler",
+            Tab::Osd => "osd",
+            Tab::Drivers => "drivers",
+            Tab::Dlss => "dlss",
+            Tab::CudaAi => "cuda-ai",
+            Tab::Settings => "settings",
         }
     }
 
-    fn count() -> usize {
-        16
+    /// Tabs enabled by the `visible_tabs` config, in dashboard order. An
+    /// empty list shows everything; Settings is always kept so hidden tabs
+    /// can be turned back on.
+    fn visible(names: &[String]) -> Vec<Tab> {
+        if names.is_empty() {
+            return Self::ALL.to_vec();
+        }
+        Self::ALL
+            .into_iter()
+            .filter(|tab| {
+                *tab == Tab::Settings
+                    || names.iter().any(|name| {
+                        name.eq_ignore_ascii_case(tab.config_name())
+                            || name.eq_ignore_ascii_case(tab.title())
+                    })
+            })
+            .collect()
     }
-}
 
-/// OC preset levels
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub enum OcPreset {
-    Stock,
-    MildOc,
-    Performance,
-    Extreme,
+    fn titles(visible: &[Tab]) -> Vec<&'static str> {
+        visible.iter().map(|tab| tab.title()).collect()
+    }
+
+    /// Tab at `index` within the visible set (Settings when out of range)
+    fn from_index(visible: &[Tab], index: usize) -> Self {
+        visible.get(index).copied().unwrap_or(Tab::Settings)
+    }
+
+    fn count(visible: &[Tab]) -> usize {
+        visible.len()
+    }
 }
 
 /// Input mode for the TUI
@@ -209,6 +258,20 @@ impl SortColumn {
                 | SortColumn::VramDesc
         )
     }
+
+    fn compare(&self, a: &ProcessEntry, b: &ProcessEntry) -> Ordering {
+        match self {
+            SortColumn::None => Ordering::Equal,
+            SortColumn::PidAsc => a.pid.cmp(&b.pid),
+            SortColumn::PidDesc => b.pid.cmp(&a.pid),
+            SortColumn::NameAsc => a.name.cmp(&b.name),
+            SortColumn::NameDesc => b.name.cmp(&a.name),
+            SortColumn::TypeAsc => a.process_type.as_str().cmp(b.process_type.as_str()),
+            SortColumn::TypeDesc => b.process_type.as_str().cmp(a.process_type.as_str()),
+            SortColumn::VramAsc => a.vram_mb.cmp(&b.vram_mb),
+            SortColumn::VramDesc => b.vram_mb.cmp(&a.vram_mb),
+        }
+    }
 }
 
 /// GPU process entry for display
@@ -218,6 +281,142 @@ pub struct ProcessEntry {
     pub name: String,
     pub process_type: ProcessType,
     pub vram_mb: u64,
+    /// 1 for members listed under a group row
+    pub depth: u8,
+    /// Member count when this row stands for a process group
+    pub children: usize,
+    pub expanded: bool,
+}
+
+impl ProcessEntry {
+    fn new(pid: u32, process_type: ProcessType, vram_bytes: Option<u64>) -> Self {
+        Self {
+            pid,
+            name: TuiApp::get_process_name(pid),
+            process_type,
+            vram_mb: vram_bytes.unwrap_or(0) / (1024 * 1024),
+            depth: 0,
+            children: 0,
+            expanded: false,
+        }
+    }
+
+    /// Name column text, with tree markers in tree view
+    fn label(&self) -> String {
+        if self.children > 0 {
+            let marker = if self.expanded { "▾" } else { "▸" };
+            format!("{} {} ({} procs)", marker, self.name, self.children)
+        } else if self.depth > 0 {
+            format!("  └ {}", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+}
+
+/// Parents that end the walk up the process tree: init, session and
+/// launcher processes that host many unrelated children
+const PROCESS_TREE_BOUNDARIES: &[&str] = &[
+    "systemd",
+    "init",
+    "steam",
+    "reaper",
+    "bash",
+    "zsh",
+    "fish",
+    "sh",
+    "gnome-shell",
+    "plasmashell",
+    "kwin_wayland",
+    "kwin_x11",
+    "sway",
+    "Hyprland",
+];
+
+/// `(comm, ppid)` from `/proc/<pid>/stat`
+fn read_proc_stat(pid: u32) -> Option<(String, u32)> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // comm may contain spaces and parentheses, so split on the last ')'
+    let open = stat.find('(')?;
+    let close = stat.rfind(')')?;
+    let comm = stat.get(open + 1..close)?.to_string();
+    let ppid = stat[close + 1..].split_whitespace().nth(1)?.parse().ok()?;
+    Some((comm, ppid))
+}
+
+/// Top-level ancestor a GPU process is grouped under. The walk stops below
+/// init, shells and launchers such as Steam's `reaper`, so a Proton game and
+/// its helpers share one root. A process whose parent has exited is its own
+/// root.
+fn process_group_root(pid: u32, stat: impl Fn(u32) -> Option<(String, u32)>) -> u32 {
+    let mut current = pid;
+    // Guard against PID reuse producing a cycle
+    for _ in 0..64 {
+        let Some((_, ppid)) = stat(current) else {
+            break;
+        };
+        if ppid <= 1 {
+            break;
+        }
+        match stat(ppid) {
+            Some((comm, _)) if !PROCESS_TREE_BOUNDARIES.contains(&comm.as_str()) => current = ppid,
+            _ => break,
+        }
+    }
+    current
+}
+
+/// Collapse sorted processes into one row per group root, summing VRAM.
+/// Groups keep the sort order; members follow their group row when expanded.
+fn group_process_tree(
+    entries: Vec<ProcessEntry>,
+    root_of: impl Fn(u32) -> u32,
+    sort: SortColumn,
+    expanded: &HashSet<u32>,
+) -> Vec<ProcessEntry> {
+    let mut groups: Vec<(u32, Vec<ProcessEntry>)> = Vec::new();
+    for entry in entries {
+        let root = root_of(entry.pid);
+        match groups.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, members)) => members.push(entry),
+            None => groups.push((root, vec![entry])),
+        }
+    }
+
+    let mut rows: Vec<(ProcessEntry, Vec<ProcessEntry>)> = groups
+        .into_iter()
+        .map(|(root, mut members)| {
+            if members.len() == 1 {
+                return (members.remove(0), Vec::new());
+            }
+            // The heaviest member names the group, usually the game itself
+            let lead = members.iter().max_by_key(|m| m.vram_mb).expect("non-empty");
+            let head = ProcessEntry {
+                pid: root,
+                name: lead.name.clone(),
+                process_type: lead.process_type,
+                vram_mb: members.iter().map(|m| m.vram_mb).sum(),
+                depth: 0,
+                children: members.len(),
+                expanded: expanded.contains(&root),
+            };
+            for member in &mut members {
+                member.depth = 1;
+            }
+            (head, members)
+        })
+        .collect();
+    rows.sort_by(|a, b| sort.compare(&a.0, &b.0));
+
+    let mut out = Vec::new();
+    for (head, members) in rows {
+        let show_members = head.expanded;
+        out.push(head);
+        if show_members {
+            out.extend(members);
+        }
+    }
+    out
 }
 
 /// Type of GPU process
@@ -250,12 +449,24 @@ pub struct TuiApp {
     device_count: u32,
     /// Metrics history per GPU
     metrics_history: Vec<VecDeque<GpuMetrics>>,
-    /// Current tab (dashboard mode)
+    /// Samples kept per GPU, from the `sparkline_samples` config
+    history_len: usize,
+    /// Current tab (dashboard mode), as a `Tab` discriminant
     current_tab: usize,
+    /// Tabs shown in the dashboard, from the `visible_tabs` config
+    visible_tabs: Vec<Tab>,
+    /// Row highlighted in the Settings tab's tab list
+    settings_tab_cursor: usize,
     /// Selected GPU
     selected_gpu: usize,
+    /// The `gpu_labels` config, keyed by PCI bus id
+    gpu_label_config: std::collections::BTreeMap<String, crate::multi_gpu::GpuLabel>,
+    /// Nickname and accent per GPU index, resolved once the backend is up
+    gpu_labels: Vec<crate::multi_gpu::GpuLabel>,
     /// Show help overlay
     show_help: bool,
+    /// Key bindings, from the `keybindings` config over the defaults
+    keymap: Keymap,
     /// Show settings overlay
     show_settings: bool,
     /// Paused state
@@ -287,7 +498,13 @@ pub struct TuiApp {
     oc_preset: OcPreset,
     /// Fan curve
     fan_curve_points: Vec<(u32, u32)>,
+    /// (percent, RPM) per fan of the selected GPU
+    fan_readings: Vec<(Option<u32>, Option<u32>)>,
     selected_curve_point: usize,
+    /// OC or fan curve edits not yet applied to `selected_gpu`
+    control_edits_dirty: bool,
+    /// GPU the user asked to switch to while edits were unapplied
+    pending_gpu_switch: Option<usize>,
     /// Tuner states
     tuner_states: Vec<gui_tuner::TunerState>,
     /// Profiler
@@ -299,6 +516,8 @@ pub struct TuiApp {
     /// Driver state
     driver_validation: Option<crate::state::DriverValidationState>,
     driver_capabilities: Option<crate::drivers::DriverCapabilities>,
+    /// Open vs proprietary kernel module, read once at startup
+    kernel_module: Option<crate::kernel_driver::KernelModuleInfo>,
     /// Menu selection (for menu mode)
     menu_selection: usize,
     // === Process table state (v0.8.0) ===
@@ -320,6 +539,16 @@ pub struct TuiApp {
     sort_selected_column: usize,
     /// Show sparkline graphs (toggle with 'g')
     show_graphs: bool,
+    /// Performance tab shows the clock histogram instead of sparklines
+    show_clock_histogram: bool,
+    /// Session clock histogram per GPU
+    clock_histograms: Vec<crate::monitoring::ClockHistogram>,
+    /// Clocks pinned on the selected GPU with `nvctl gpu lock-clocks`
+    locked_clocks: Option<crate::overclocking::LockedClocks>,
+    /// Group processes under their top-level parent
+    process_tree: bool,
+    /// Group roots whose members are shown in tree view
+    expanded_process_groups: HashSet<u32>,
     // === DLSS cache (to avoid blocking) ===
     /// Cached DLSS controller
     dlss_controller: Option<DlssController>,
@@ -343,6 +572,27 @@ pub struct TuiApp {
     asus_power_history: crate::asus_power_detector::PowerHistory,
     /// Last ASUS power update time
     asus_power_last_update: Instant,
+    // === MIG ===
+    /// MIG instances of the selected GPU (empty without MIG)
+    mig_instances: Vec<MigInstance>,
+    /// NVENC/NVDEC activity of the selected GPU (`None` without NVENC)
+    video_engines: Option<VideoEngineStats>,
+    /// Graphics/compute/copy engine load, read while the Performance tab is shown
+    engine_utilization: Option<EngineUtilization>,
+    /// Chip power of the selected GPU in watts (`None` when only board power is reported)
+    chip_power: Option<f32>,
+    /// Most recent stress test verdict, shown on the Profiler tab
+    last_stress_result: Option<crate::overclocking::StressResult>,
+    /// Throttle episodes per GPU
+    throttle_logs: Vec<crate::throttle_log::ThrottleLog>,
+    /// Kept across ticks so the alert cooldown applies
+    throttle_notifier: Option<crate::notifications::NotificationManager>,
+    /// Threshold alerts from config.toml
+    alert_rules: Vec<crate::monitoring::AlertRule>,
+    alert_evaluator: crate::monitoring::AlertEvaluator,
+    /// Notification warning levels (alerts.toml), marked on gauges when no
+    /// alert rule covers the metric
+    alert_config: crate::notifications::AlertConfig,
 }
 
 impl TuiApp {
@@ -359,6 +609,9 @@ impl TuiApp {
             .unwrap_or(themes::ThemeVariant::TokyoNightStorm);
         let theme = themes::ColorPalette::from_variant(current_theme);
 
+        let visible_tabs = Tab::visible(&config.visible_tabs);
+        let (keymap, keymap_problems) = Keymap::from_config(&config.keybindings);
+
         // Load saved session (fast - just file read)
         let saved_state = TuiSessionState::load();
 
@@ -387,17 +640,30 @@ impl TuiApp {
             backend_initialized: false,
             device_count: 0,
             metrics_history: Vec::new(),
-            current_tab: saved_state.current_tab.min(Tab::count() - 1),
+            current_tab: visible_tabs
+                .iter()
+                .find(|tab| **tab as usize == saved_state.current_tab)
+                .or(visible_tabs.first())
+                .map(|tab| *tab as usize)
+                .unwrap_or(Tab::Settings as usize),
+            visible_tabs,
+            history_len: config
+                .sparkline_samples
+                .clamp(*HISTORY_RANGE.start(), *HISTORY_RANGE.end()),
+            settings_tab_cursor: 0,
             selected_gpu: saved_state.selected_gpu,
+            gpu_label_config: config.gpu_labels.clone(),
+            gpu_labels: Vec::new(),
             show_help: false,
+            keymap,
             show_settings: false,
             paused: false,
             update_interval: Duration::from_secs(1),
             start_time: Instant::now(),
             _vrr_enabled: false,
             _gaming_mode_enabled: false,
-            status_message: None,
-            status_message_time: None,
+            status_message: keymap_problems.first().cloned(),
+            status_message_time: keymap_problems.first().map(|_| Instant::now()),
             fan_control_mode: false,
             oc_control_mode: false,
             fan_speed_target: 50,
@@ -408,7 +674,10 @@ impl TuiApp {
             power_limit_percent: u32::from(saved_state.power_limit_percent),
             oc_preset,
             fan_curve_points,
+            fan_readings: Vec::new(),
             selected_curve_point: 0,
+            control_edits_dirty: false,
+            pending_gpu_switch: None,
             tuner_states: Vec::new(),
             profiler: None,
             profiler_recording: false,
@@ -416,6 +685,7 @@ impl TuiApp {
             osd_selected_metric: 0,
             driver_validation: None,
             driver_capabilities: None,
+            kernel_module: None,
             menu_selection: 0,
             // Process table state (v0.8.0)
             input_mode: InputMode::Normal,
@@ -429,6 +699,11 @@ impl TuiApp {
             sort_column: SortColumn::VramDesc,
             sort_selected_column: 0,
             show_graphs: true,
+            show_clock_histogram: false,
+            clock_histograms: Vec::new(),
+            locked_clocks: None,
+            process_tree: false,
+            expanded_process_groups: HashSet::new(),
             // DLSS cache - initialized lazily on first access
             dlss_controller: None,
             dlss_doctor_result: None,
@@ -445,6 +720,18 @@ impl TuiApp {
             asus_power_status: None,
             asus_power_history: crate::asus_power_detector::PowerHistory::new(),
             asus_power_last_update: Instant::now(),
+            mig_instances: Vec::new(),
+            video_engines: None,
+            engine_utilization: None,
+            chip_power: None,
+            last_stress_result: crate::overclocking::StressResult::load_last(),
+            throttle_logs: Vec::new(),
+            throttle_notifier: None,
+            alert_rules: config.alert_rules.clone(),
+            alert_evaluator: Default::default(),
+            alert_config: crate::notifications::NotificationManager::new()
+                .map(|notifier| notifier.get_config().clone())
+                .unwrap_or_default(),
         }
     }
 
@@ -455,14 +742,28 @@ impl TuiApp {
         }
         self.backend_initialized = true;
 
+        let mut subsystems = crate::error_recovery::SubsystemReport::new();
+
         // Initialize NVML backend (this is the slow part)
-        let backend_ctx = GuiBackendContext::new();
-        self.device_count = backend_ctx.device_count;
+        let backend_ctx = subsystems.init("NVML", false, GuiBackendContext::new);
+        self.device_count = backend_ctx.as_ref().map_or(0, |ctx| ctx.device_count);
+        self.gpu_labels = (0..self.device_count)
+            .map(|gpu| {
+                backend_ctx
+                    .as_ref()
+                    .and_then(|ctx| ctx.get_pci_bus_id(gpu).ok())
+                    .and_then(|bus| crate::multi_gpu::gpu_label(&self.gpu_label_config, &bus))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect();
 
         // Setup metrics history for each GPU
         self.metrics_history = (0..self.device_count)
-            .map(|_| VecDeque::with_capacity(MAX_HISTORY))
+            .map(|_| VecDeque::with_capacity(self.history_len))
             .collect();
+        self.throttle_logs = (0..self.device_count).map(|_| Default::default()).collect();
+        self.clock_histograms = (0..self.device_count).map(|_| Default::default()).collect();
 
         // Validate selected GPU
         if self.selected_gpu >= self.device_count as usize {
@@ -485,21 +786,32 @@ impl TuiApp {
 
         // Load driver info (fast after NVML init)
         self.driver_validation = crate::state::DriverValidationState::load();
-        self.driver_capabilities = crate::drivers::get_driver_capabilities().ok();
+        self.driver_capabilities = subsystems
+            .init("driver capabilities", true, || {
+                crate::drivers::get_driver_capabilities().ok()
+            })
+            .flatten();
+        self.kernel_module =
+            subsystems.init("kernel module", true, crate::kernel_driver::module_type);
         self.osd_enabled = crate::osd::OsdManager::check_mangohud_installed();
 
         // Initialize ASUS Power Monitor+ if applicable
-        self.asus_power_detector = {
-            let gpus = crate::asus_power_detector::detect_asus_gpus();
-            gpus.into_iter()
-                .find(|(_, model)| model.supports_power_detector())
-                .and_then(|(pci_id, _)| {
-                    crate::asus_power_detector::AsusPowerDetector::new(&pci_id).ok()
-                })
-                .filter(|d| d.is_supported())
-        };
+        self.asus_power_detector = subsystems
+            .init("ASUS/RGB", true, || {
+                let gpus = crate::asus_power_detector::detect_asus_gpus();
+                gpus.into_iter()
+                    .find(|(_, model)| model.supports_power_detector())
+                    .and_then(|(pci_id, _)| {
+                        crate::asus_power_detector::AsusPowerDetector::new(&pci_id).ok()
+                    })
+                    .filter(|d| d.is_supported())
+            })
+            .flatten();
 
-        self.backend_ctx = Some(backend_ctx);
+        self.backend_ctx = backend_ctx;
+        if let Some(banner) = subsystems.banner() {
+            self.set_status_message(banner);
+        }
     }
 
     /// Get process name from PID by reading /proc/{pid}/comm
@@ -519,19 +831,22 @@ impl TuiApp {
 
         let mut entries = Vec::new();
 
-        if let Some(ref ctx) = self.backend_ctx {
+        if let Some(ctx) = self
+            .backend_ctx
+            .as_ref()
+            .filter(|ctx| ctx.supports_control(self.selected_gpu as u32))
+        {
             // Get graphics processes
             if let Ok(procs) = ctx
                 .nvml
                 .get_running_graphics_processes(self.selected_gpu as u32)
             {
                 for p in procs {
-                    entries.push(ProcessEntry {
-                        pid: p.pid,
-                        name: Self::get_process_name(p.pid),
-                        process_type: ProcessType::Graphics,
-                        vram_mb: p.used_gpu_memory_bytes.unwrap_or(0) / (1024 * 1024),
-                    });
+                    entries.push(ProcessEntry::new(
+                        p.pid,
+                        ProcessType::Graphics,
+                        p.used_gpu_memory_bytes,
+                    ));
                 }
             }
 
@@ -543,12 +858,11 @@ impl TuiApp {
                 for p in procs {
                     // Avoid duplicates (some processes may be both)
                     if !entries.iter().any(|e| e.pid == p.pid) {
-                        entries.push(ProcessEntry {
-                            pid: p.pid,
-                            name: Self::get_process_name(p.pid),
-                            process_type: ProcessType::Compute,
-                            vram_mb: p.used_gpu_memory_bytes.unwrap_or(0) / (1024 * 1024),
-                        });
+                        entries.push(ProcessEntry::new(
+                            p.pid,
+                            ProcessType::Compute,
+                            p.used_gpu_memory_bytes,
+                        ));
                     }
                 }
             }
@@ -568,20 +882,15 @@ impl TuiApp {
         }
 
         // Apply sort
-        match self.sort_column {
-            SortColumn::None => {}
-            SortColumn::PidAsc => entries.sort_by_key(|e| e.pid),
-            SortColumn::PidDesc => entries.sort_by_key(|e| std::cmp::Reverse(e.pid)),
-            SortColumn::NameAsc => entries.sort_by(|a, b| a.name.cmp(&b.name)),
-            SortColumn::NameDesc => entries.sort_by(|a, b| b.name.cmp(&a.name)),
-            SortColumn::TypeAsc => {
-                entries.sort_by(|a, b| a.process_type.as_str().cmp(b.process_type.as_str()))
-            }
-            SortColumn::TypeDesc => {
-                entries.sort_by(|a, b| b.process_type.as_str().cmp(a.process_type.as_str()))
-            }
-            SortColumn::VramAsc => entries.sort_by_key(|e| e.vram_mb),
-            SortColumn::VramDesc => entries.sort_by_key(|e| std::cmp::Reverse(e.vram_mb)),
+        entries.sort_by(|a, b| self.sort_column.compare(a, b));
+
+        if self.process_tree {
+            entries = group_process_tree(
+                entries,
+                |pid| process_group_root(pid, read_proc_stat),
+                self.sort_column,
+                &self.expanded_process_groups,
+            );
         }
 
         self.processes = entries;
@@ -592,6 +901,39 @@ impl TuiApp {
             .content_length(self.processes.len());
     }
 
+    /// Re-query processes on the next draw instead of waiting out the rate limit
+    fn invalidate_processes(&mut self) {
+        self.processes_last_update = Instant::now()
+            .checked_sub(Duration::from_secs(10))
+            .unwrap_or_else(Instant::now);
+    }
+
+    /// Switch between the flat process list and the grouped tree
+    fn toggle_process_tree(&mut self) {
+        self.process_tree = !self.process_tree;
+        self.invalidate_processes();
+        self.refresh_processes();
+        self.select_first_process();
+    }
+
+    /// Expand or collapse the selected process group
+    fn toggle_process_group(&mut self) {
+        let Some(entry) = self
+            .process_table_state
+            .selected()
+            .and_then(|i| self.processes.get(i))
+            .filter(|e| e.children > 0)
+        else {
+            return;
+        };
+        let pid = entry.pid;
+        if !self.expanded_process_groups.remove(&pid) {
+            self.expanded_process_groups.insert(pid);
+        }
+        self.invalidate_processes();
+        self.refresh_processes();
+    }
+
     /// Select next process in table
     fn select_next_process(&mut self) {
         if self.processes.is_empty() {
@@ -605,6 +947,24 @@ impl TuiApp {
         self.process_scrollbar_state = self.process_scrollbar_state.position(i);
     }
 
+    /// Select the first process in the table
+    fn select_first_process(&mut self) {
+        if self.processes.is_empty() {
+            return;
+        }
+        self.process_table_state.select(Some(0));
+        self.process_scrollbar_state = self.process_scrollbar_state.position(0);
+    }
+
+    /// Select the last process in the table
+    fn select_last_process(&mut self) {
+        let Some(last) = self.processes.len().checked_sub(1) else {
+            return;
+        };
+        self.process_table_state.select(Some(last));
+        self.process_scrollbar_state = self.process_scrollbar_state.position(last);
+    }
+
     /// Select previous process in table
     fn select_prev_process(&mut self) {
         if self.processes.is_empty() {
@@ -646,6 +1006,7 @@ impl TuiApp {
                     Event::Key(key) => self.handle_key(key.code, key.modifiers),
                     Event::Mouse(mouse) => self.handle_mouse(mouse),
                     Event::Tick => {
+                        self.expire_pending_keys();
                         if !self.paused {
                             self.update_metrics();
                         }
@@ -683,25 +1044,60 @@ impl TuiApp {
             InputMode::Normal => {}
         }
 
+        let key = KeyPress::new(code, modifiers);
+        for keys in self.keymap.press(key, Instant::now()) {
+            self.dispatch_keys(&keys);
+        }
+    }
+
+    /// Dispatch a sequence prefix (the `g` of `gg`) once it has waited long enough
+    fn expire_pending_keys(&mut self) {
+        if let Some(keys) = self.keymap.expire(Instant::now()) {
+            self.dispatch_keys(&keys);
+        }
+    }
+
+    /// Act on a completed key sequence
+    fn dispatch_keys(&mut self, keys: &[KeyPress]) {
+        let actions = self.keymap.actions_for(keys);
+        let is = |action| actions.contains(&action);
+        // Enter, Esc and digits aren't remappable and arrive as single keys
+        let code = match keys {
+            [key] if !key.ctrl => Some(key.code),
+            _ => None,
+        };
+
         // Global keys (only in Normal mode)
-        match code {
-            KeyCode::Char('q') => self.running = false,
-            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
-                self.running = false;
-            }
-            KeyCode::Char('?') | KeyCode::F(1) => self.show_help = !self.show_help,
-            _ => {}
+        let ctrl_c = matches!(
+            keys,
+            [KeyPress {
+                code: KeyCode::Char('c'),
+                ctrl: true
+            }]
+        );
+        if is(Action::Quit) || ctrl_c {
+            self.running = false;
+        }
+        if is(Action::Help) {
+            self.show_help = !self.show_help;
         }
 
         if self.show_help {
             return;
         }
 
+        if let Some(target) = self.pending_gpu_switch.take() {
+            if let Some(last) = keys.last() {
+                self.resolve_pending_gpu_switch(last.code, target);
+            }
+            return;
+        }
+
         // View-specific handling
         match self.view_mode {
-            ViewMode::Menu => self.handle_menu_key(code),
-            ViewMode::Nvtop => self.handle_nvtop_key(code, modifiers),
-            ViewMode::Dashboard => self.handle_dashboard_key(code, modifiers),
+            ViewMode::Menu => self.handle_menu_key(&is, code),
+            ViewMode::Nvtop => self.handle_nvtop_key(&is, code),
+            ViewMode::Dashboard => self.handle_dashboard_key(&is, code),
         }
     }
 
@@ -764,15 +1160,15 @@ impl TuiApp {
         }
     }
 
-    fn handle_menu_key(&mut self, code: KeyCode) {
+    fn handle_menu_key(&mut self, is: &dyn Fn(Action) -> bool, code: Option<KeyCode>) {
         match code {
-            KeyCode::Up | KeyCode::Char('k') if self.menu_selection > 0 => {
+            _ if is(Action::Up) && self.menu_selection > 0 => {
                 self.menu_selection -= 1;
             }
-            KeyCode::Down | KeyCode::Char('j') if self.menu_selection < 3 => {
+            _ if is(Action::Down) && self.menu_selection < 3 => {
                 self.menu_selection += 1;
             }
-            KeyCode::Enter => {
+            Some(KeyCode::Enter) => {
                 match self.menu_selection {
                     0 => self.view_mode = ViewMode::Nvtop,
                     1 => self.view_mode = ViewMode::Dashboard,
@@ -781,92 +1177,138 @@ impl TuiApp {
                     _ => {}
                 }
             }
-            KeyCode::Char('1') => self.view_mode = ViewMode::Nvtop,
-            KeyCode::Char('2') => self.view_mode = ViewMode::Dashboard,
+            Some(KeyCode::Char('1')) => self.view_mode = ViewMode::Nvtop,
+            Some(KeyCode::Char('2')) => self.view_mode = ViewMode::Dashboard,
             _ => {}
         }
     }
 
-    fn handle_nvtop_key(&mut self, code: KeyCode, _modifiers: KeyModifiers) {
+    fn handle_nvtop_key(&mut self, is: &dyn Fn(Action) -> bool, code: Option<KeyCode>) {
         match code {
-            KeyCode::Tab => self.next_gpu(),
-            KeyCode::BackTab => self.prev_gpu(),
-            KeyCode::Left => self.prev_gpu(),
-            KeyCode::Right => self.next_gpu(),
-            KeyCode::Char(' ') | KeyCode::Char('p') => self.paused = !self.paused,
-            KeyCode::Char('m') => self.view_mode = ViewMode::Menu,
-            KeyCode::Char('d') => self.view_mode = ViewMode::Dashboard,
-            KeyCode::Char('t') => self.cycle_theme(),
+            _ if is(Action::NextTab) || is(Action::NextGpu) => self.next_gpu(),
+            _ if is(Action::PrevTab) || is(Action::PrevGpu) => self.prev_gpu(),
+            _ if is(Action::HottestGpu) => self.jump_to_hottest_gpu(),
+            _ if is(Action::BusiestGpu) => self.jump_to_busiest_gpu(),
+            _ if is(Action::Pause) => self.paused = !self.paused,
+            _ if is(Action::Menu) => self.view_mode = ViewMode::Menu,
+            _ if is(Action::DashboardView) => self.view_mode = ViewMode::Dashboard,
+            _ if is(Action::CycleTheme) => self.cycle_theme(),
             // Process navigation
-            KeyCode::Up | KeyCode::Char('k') => self.select_prev_process(),
-            KeyCode::Down | KeyCode::Char('j') => self.select_next_process(),
+            _ if is(Action::Up) => self.select_prev_process(),
+            _ if is(Action::Down) => self.select_next_process(),
+            _ if is(Action::Top) => self.select_first_process(),
+            _ if is(Action::Bottom) => self.select_last_process(),
             // Filter mode
-            KeyCode::Char('f') | KeyCode::Char('/') => {
+            _ if is(Action::Filter) => {
                 self.input_mode = InputMode::Filter;
             }
             // Sort mode
-            KeyCode::Char('s') => {
+            _ if is(Action::Sort) => {
                 self.input_mode = InputMode::Sort;
                 // Set sort column to current sort if any
                 self.sort_selected_column = self.sort_column.column_index().unwrap_or(0);
             }
+            _ if is(Action::ProcessTree) => self.toggle_process_tree(),
+            Some(KeyCode::Enter) => self.toggle_process_group(),
             // Clear filter
-            KeyCode::Esc if !self.filter_text.is_empty() => {
+            Some(KeyCode::Esc) if !self.filter_text.is_empty() => {
                 self.filter_text.clear();
                 self.processes_last_update = Instant::now()
                     .checked_sub(Duration::from_secs(10))
                     .unwrap_or_else(Instant::now);
             }
             // Toggle sparkline graphs
-            KeyCode::Char('g') => self.show_graphs = !self.show_graphs,
+            _ if is(Action::ToggleGraphs) => self.show_graphs = !self.show_graphs,
             _ => {}
         }
     }
 
-    fn handle_dashboard_key(&mut self, code: KeyCode, _modifiers: KeyModifiers) {
+    fn handle_dashboard_key(&mut self, is: &dyn Fn(Action) -> bool, code: Option<KeyCode>) {
         // Check if we're on Processes tab (index 5)
         let on_processes_tab = self.current_tab == 5;
         let on_drivers_tab = self.current_tab == 12;
         let on_cuda_ai_tab = self.current_tab == 14;
+        let on_performance_tab = self.current_tab == Tab::Performance as usize;
+        let on_settings_tab = self.current_tab == Tab::Settings as usize;
+        let on_oc_tab = self.current_tab == Tab::Overclocking as usize;
+        let on_fan_tab = self.current_tab == Tab::FanControl as usize;
+        let oc_editing = on_oc_tab && self.oc_control_mode;
+        let fan_editing = on_fan_tab && self.fan_control_mode;
 
         match code {
-            KeyCode::Tab => self.next_tab(),
-            KeyCode::BackTab => self.prev_tab(),
-            KeyCode::Left if !on_processes_tab => self.prev_gpu(),
-            KeyCode::Right if !on_processes_tab => self.next_gpu(),
-            KeyCode::Char(' ') | KeyCode::Char('p') => self.paused = !self.paused,
-            KeyCode::Char('m') => self.view_mode = ViewMode::Menu,
-            KeyCode::Char('n') => self.view_mode = ViewMode::Nvtop,
-            KeyCode::Char('t') => self.cycle_theme(),
+            _ if is(Action::NextTab) => self.next_tab(),
+            _ if is(Action::PrevTab) => self.prev_tab(),
+            _ if is(Action::PrevGpu) && !on_processes_tab => self.prev_gpu(),
+            _ if is(Action::NextGpu) && !on_processes_tab => self.next_gpu(),
+            _ if is(Action::HottestGpu) => self.jump_to_hottest_gpu(),
+            _ if is(Action::BusiestGpu) => self.jump_to_busiest_gpu(),
+            _ if is(Action::Pause) => self.paused = !self.paused,
+            _ if is(Action::Menu) => self.view_mode = ViewMode::Menu,
+            _ if is(Action::NvtopView) => self.view_mode = ViewMode::Nvtop,
+            _ if is(Action::CycleTheme) => self.cycle_theme(),
             // Number keys to switch tabs (except in filter/sort mode or on processes tab with filter)
-            KeyCode::Char('1') if !on_processes_tab => self.current_tab = 0,
-            KeyCode::Char('2') if !on_processes_tab => self.current_tab = 1,
-            KeyCode::Char('3') if !on_processes_tab => self.current_tab = 2,
-            KeyCode::Char('4') if !on_processes_tab => self.current_tab = 3,
-            KeyCode::Char('5') if !on_processes_tab => self.current_tab = 4,
-            KeyCode::Char('6') if !on_processes_tab => self.current_tab = 5,
-            KeyCode::Char('7') if !on_processes_tab => self.current_tab = 6,
-            KeyCode::Char('8') if !on_processes_tab => self.current_tab = 7,
-            KeyCode::Char('9') if !on_processes_tab => self.current_tab = 8,
+            Some(KeyCode::Char(c @ '1'..='9')) if !on_processes_tab => {
+                let position = c as usize - '1' as usize;
+                if position < Tab::count(&self.visible_tabs) {
+                    self.current_tab = Tab::from_index(&self.visible_tabs, position) as usize;
+                }
+            }
+            // Settings tab: choose which tabs the dashboard shows
+            _ if is(Action::Up) && on_settings_tab => {
+                self.settings_tab_cursor = self.settings_tab_cursor.saturating_sub(1);
+            }
+            _ if is(Action::Down) && on_settings_tab => {
+                self.settings_tab_cursor = (self.settings_tab_cursor + 1).min(Tab::ALL.len() - 1);
+            }
+            Some(KeyCode::Enter) if on_settings_tab => self.toggle_tab_visibility(),
+            // Overclock tab: edit offsets for the selected GPU, Enter applies
+            _ if is(Action::EditOc) && on_oc_tab => self.oc_control_mode = !self.oc_control_mode,
+            _ if is(Action::Up) && oc_editing => self.edit_oc(15, 0, 0),
+            _ if is(Action::Down) && oc_editing => self.edit_oc(-15, 0, 0),
+            _ if is(Action::MemoryUp) && oc_editing => self.edit_oc(0, 50, 0),
+            _ if is(Action::MemoryDown) && oc_editing => self.edit_oc(0, -50, 0),
+            _ if is(Action::Increase) && oc_editing => self.edit_oc(0, 0, 5),
+            _ if is(Action::Decrease) && oc_editing => self.edit_oc(0, 0, -5),
+            Some(KeyCode::Enter) if oc_editing => self.apply_oc_to_selected_gpu(),
+            // Fan tab: edit the curve for the selected GPU, Enter applies
+            _ if is(Action::EditFanCurve) && on_fan_tab => {
+                self.fan_control_mode = !self.fan_control_mode
+            }
+            _ if is(Action::Up) && fan_editing => {
+                self.selected_curve_point = self.selected_curve_point.saturating_sub(1);
+            }
+            _ if is(Action::Down) && fan_editing => {
+                self.selected_curve_point = (self.selected_curve_point + 1)
+                    .min(self.fan_curve_points.len().saturating_sub(1));
+            }
+            _ if is(Action::Increase) && fan_editing => self.edit_fan_point(5),
+            _ if is(Action::Decrease) && fan_editing => self.edit_fan_point(-5),
+            Some(KeyCode::Enter) if fan_editing => self.apply_fan_curve_to_selected_gpu(),
             // Processes tab navigation
-            KeyCode::Up | KeyCode::Char('k') if on_processes_tab => self.select_prev_process(),
-            KeyCode::Down | KeyCode::Char('j') if on_processes_tab => self.select_next_process(),
-            KeyCode::Char('f') | KeyCode::Char('/') if on_processes_tab => {
+            _ if is(Action::Up) && on_processes_tab => self.select_prev_process(),
+            _ if is(Action::Down) && on_processes_tab => self.select_next_process(),
+            _ if is(Action::Top) && on_processes_tab => self.select_first_process(),
+            _ if is(Action::Bottom) && on_processes_tab => self.select_last_process(),
+            _ if is(Action::Filter) && on_processes_tab => {
                 self.input_mode = InputMode::Filter;
             }
-            KeyCode::Char('s') if on_processes_tab => {
+            _ if is(Action::Sort) && on_processes_tab => {
                 self.input_mode = InputMode::Sort;
                 self.sort_selected_column = self.sort_column.column_index().unwrap_or(0);
             }
-            KeyCode::Esc if on_processes_tab && !self.filter_text.is_empty() => {
+            _ if is(Action::ProcessTree) && on_processes_tab => self.toggle_process_tree(),
+            Some(KeyCode::Enter) if on_processes_tab => self.toggle_process_group(),
+            Some(KeyCode::Esc) if on_processes_tab && !self.filter_text.is_empty() => {
                 self.filter_text.clear();
                 self.processes_last_update = Instant::now()
                     .checked_sub(Duration::from_secs(10))
                     .unwrap_or_else(Instant::now);
             }
             // Settings toggle (not on processes tab to avoid conflict with sort)
-            KeyCode::Char('s') if !on_processes_tab => self.show_settings = !self.show_settings,
-            KeyCode::Char('b') if on_drivers_tab => {
+            _ if is(Action::ToggleSettings) && !on_processes_tab => {
+                self.show_settings = !self.show_settings
+            }
+            _ if is(Action::SupportBundle) && on_drivers_tab => {
                 let support_path =
                     crate::drivers::default_support_bundle_path("tui-support.tar.gz");
                 match crate::drivers::write_support_bundle_with_options(
@@ -884,13 +1326,23 @@ impl TuiApp {
                     Err(e) => self.set_status_message(format!("Support bundle failed: {}", e)),
                 }
             }
-            KeyCode::Char('x') if on_drivers_tab => {
+            _ if is(Action::WorkflowHint) && on_drivers_tab => {
                 self.set_status_message(
                     "Workflow: diagnose-release -> driver check -> support-bundle --tarball"
                         .to_string(),
                 );
             }
-            KeyCode::Char('r') if on_cuda_ai_tab => {
+            _ if is(Action::ClockHistogram) && on_performance_tab => {
+                self.show_clock_histogram = !self.show_clock_histogram
+            }
+            _ if is(Action::ResetHistogram) && on_performance_tab => {
+                if let Some(histogram) = self.clock_histograms.get_mut(self.selected_gpu) {
+                    histogram.reset();
+                    self.set_status_message("Clock histogram reset".to_string());
+                }
+            }
+            _ if is(Action::RevertOc) => self.revert_overclock(),
+            _ if is(Action::Refresh) && on_cuda_ai_tab => {
                 self.refresh_cuda_ai_cache_now();
                 if let Some(error) = &self.cuda_last_error {
                     self.set_status_message(format!("CUDA/AI refresh failed: {error}"));
@@ -920,40 +1372,318 @@ impl TuiApp {
         }
     }
 
+    /// Position of the current tab within the visible set
+    fn visible_tab_position(&self) -> usize {
+        self.visible_tabs
+            .iter()
+            .position(|tab| *tab as usize == self.current_tab)
+            .unwrap_or(0)
+    }
+
     fn next_tab(&mut self) {
-        self.current_tab = (self.current_tab + 1) % Tab::count();
+        let count = Tab::count(&self.visible_tabs);
+        let next = (self.visible_tab_position() + 1) % count;
+        self.current_tab = Tab::from_index(&self.visible_tabs, next) as usize;
     }
 
     fn prev_tab(&mut self) {
-        self.current_tab = if self.current_tab == 0 {
-            Tab::count() - 1
+        let count = Tab::count(&self.visible_tabs);
+        let prev = (self.visible_tab_position() + count - 1) % count;
+        self.current_tab = Tab::from_index(&self.visible_tabs, prev) as usize;
+    }
+
+    /// Show or hide the tab under the Settings cursor and save it to config
+    fn toggle_tab_visibility(&mut self) {
+        let tab = Tab::ALL[self.settings_tab_cursor];
+        if tab == Tab::Settings {
+            self.set_status_message("The Settings tab is always shown".to_string());
+            return;
+        }
+
+        if self.visible_tabs.contains(&tab) {
+            self.visible_tabs.retain(|t| *t != tab);
         } else {
-            self.current_tab - 1
+            self.visible_tabs.push(tab);
+            self.visible_tabs.sort_by_key(|t| *t as usize);
+        }
+
+        let mut config = crate::config::Config::load();
+        config.visible_tabs = if self.visible_tabs.len() == Tab::ALL.len() {
+            Vec::new()
+        } else {
+            self.visible_tabs
+                .iter()
+                .map(|t| t.config_name().to_string())
+                .collect()
         };
+        config.save();
     }
 
     fn next_gpu(&mut self) {
         if self.device_count > 1 {
-            self.selected_gpu = (self.selected_gpu + 1) % self.device_count as usize;
+            self.switch_gpu((self.selected_gpu + 1) % self.device_count as usize);
         }
     }
 
     fn prev_gpu(&mut self) {
         if self.device_count > 1 {
-            self.selected_gpu = if self.selected_gpu == 0 {
+            self.switch_gpu(if self.selected_gpu == 0 {
                 self.device_count as usize - 1
             } else {
                 self.selected_gpu - 1
-            };
+            });
         }
     }
 
+    fn jump_to_hottest_gpu(&mut self) {
+        self.jump_to_gpu_by("hottest", "°C", |m| m.temperature);
+    }
+
+    fn jump_to_busiest_gpu(&mut self) {
+        self.jump_to_gpu_by("busiest", "%", |m| m.gpu_utilization);
+    }
+
+    /// Select the GPU whose latest sample ranks highest on `metric`
+    fn jump_to_gpu_by(&mut self, label: &str, unit: &str, metric: fn(&GpuMetrics) -> f64) {
+        let Some((target, value)) = gpu_with_highest(&self.metrics_history, metric) else {
+            self.set_status_message("No GPU readings yet".to_string());
+            return;
+        };
+        if target == self.selected_gpu {
+            self.set_status_message(format!(
+                "GPU {} is already the {} ({:.0}{})",
+                target, label, value, unit
+            ));
+            return;
+        }
+        self.switch_gpu(target);
+        if self.pending_gpu_switch.is_none() {
+            self.set_status_message(format!(
+                "Jumped to GPU {}, the {} ({:.0}{})",
+                target, label, value, unit
+            ));
+        }
+    }
+
+    /// Change GPU, first asking what to do with unapplied control edits
+    fn switch_gpu(&mut self, target: usize) {
+        if self.control_edits_dirty {
+            self.pending_gpu_switch = Some(target);
+            self.set_status_message(format!(
+                "Unapplied edits for GPU {}: Enter apply · d discard · any other key stay",
+                self.selected_gpu
+            ));
+            return;
+        }
+        self.selected_gpu = target;
+    }
+
+    fn resolve_pending_gpu_switch(&mut self, code: KeyCode, target: usize) {
+        match code {
+            KeyCode::Enter => {
+                if self.current_tab == Tab::FanControl as usize {
+                    self.apply_fan_curve_to_selected_gpu();
+                } else {
+                    self.apply_oc_to_selected_gpu();
+                }
+                if !self.control_edits_dirty {
+                    self.selected_gpu = target;
+                }
+            }
+            KeyCode::Char('d') | KeyCode::Esc => {
+                self.discard_control_edits();
+                self.selected_gpu = target;
+                self.set_status_message(format!("Edits discarded, now on GPU {}", target));
+            }
+            _ => self.set_status_message(format!("Staying on GPU {}", self.selected_gpu)),
+        }
+    }
+
+    /// Return the OC and fan curve editors to their last saved values
+    fn discard_control_edits(&mut self) {
+        let saved = TuiSessionState::load();
+        self.gpu_offset = saved.gpu_offset;
+        self.memory_offset = saved.memory_offset;
+        self.power_limit_percent = u32::from(saved.power_limit_percent);
+        if !saved.fan_curve_points.is_empty() {
+            self.fan_curve_points = saved
+                .fan_curve_points
+                .iter()
+                .map(|(t, f)| (*t as u32, *f as u32))
+                .collect();
+        }
+        self.control_edits_dirty = false;
+    }
+
+    fn edit_oc(&mut self, core_delta: i32, memory_delta: i32, power_delta: i32) {
+        self.gpu_offset = (self.gpu_offset + core_delta).clamp(-200, 300);
+        self.memory_offset = (self.memory_offset + memory_delta).clamp(-500, 1500);
+        self.power_limit_percent =
+            (self.power_limit_percent as i32 + power_delta).clamp(50, 100) as u32;
+        self.control_edits_dirty = true;
+    }
+
+    fn edit_fan_point(&mut self, delta: i32) {
+        if let Some((_, speed)) = self.fan_curve_points.get_mut(self.selected_curve_point) {
+            *speed = (*speed as i32 + delta).clamp(0, 100) as u32;
+            self.control_edits_dirty = true;
+        }
+    }
+
+    /// Apply the edited offsets and power limit to `selected_gpu` only
+    fn apply_oc_to_selected_gpu(&mut self) {
+        if !self.selected_gpu_controllable() {
+            self.set_status_message("Control unavailable on this vendor".to_string());
+            return;
+        }
+
+        let gpu = self.selected_gpu as u32;
+        let profile = crate::overclocking::OverclockProfile {
+            name: "TUI Applied".to_string(),
+            gpu_clock_offset: self.gpu_offset,
+            memory_clock_offset: self.memory_offset,
+            power_limit: self.power_limit_percent as u8,
+            ..Default::default()
+        };
+        if let Err(e) = crate::overclocking::apply_overclock_profile_for_gpu(&profile, gpu) {
+            self.set_status_message(format!("GPU {}: overclock failed: {}", gpu, e));
+            return;
+        }
+
+        if let Some(ctx) = &self.backend_ctx {
+            let power = crate::power::set_power_limit_percent(
+                &ctx.nvml,
+                gpu,
+                self.power_limit_percent as f32,
+            );
+            match power {
+                Ok(applied) => {
+                    crate::state::LastApplied::record_power_limit(gpu, applied.applied_w)
+                }
+                Err(e) => {
+                    self.set_status_message(format!(
+                        "GPU {}: offsets applied, power limit failed: {}",
+                        gpu, e
+                    ));
+                    self.control_edits_dirty = false;
+                    return;
+                }
+            }
+        }
+
+        self.control_edits_dirty = false;
+        self.set_status_message(format!(
+            "GPU {}: {:+}MHz core, {:+}MHz mem, {}% power",
+            gpu, self.gpu_offset, self.memory_offset, self.power_limit_percent
+        ));
+    }
+
+    /// Apply the edited fan curve to every fan on `selected_gpu`
+    fn apply_fan_curve_to_selected_gpu(&mut self) {
+        if !self.selected_gpu_controllable() {
+            self.set_status_message("Control unavailable on this vendor".to_string());
+            return;
+        }
+
+        let gpu = self.selected_gpu;
+        let points: Vec<(u8, u8)> = self
+            .fan_curve_points
+            .iter()
+            .map(|(t, s)| (*t as u8, *s as u8))
+            .collect();
+        let fan_count = self
+            .backend_ctx
+            .as_ref()
+            .and_then(|ctx| ctx.nvml.get_fan_count(gpu as u32).ok())
+            .unwrap_or(1) as usize;
+        let curve = crate::fan_daemon::curve_from_points("TUI", &points);
+
+        // Fan ids are numbered gpu * 10 + fan, as in `fan::list_fans`
+        for fan in 0..fan_count {
+            let fan_id = gpu * 10 + fan;
+            if let Err(e) = crate::fan::set_fan_curve(fan_id, &points) {
+                self.set_status_message(format!("GPU {}: fan {} failed: {}", gpu, fan, e));
+                return;
+            }
+            let _ = crate::fan_daemon::persist_curve(fan_id, &curve);
+        }
+
+        self.control_edits_dirty = false;
+        self.set_status_message(format!(
+            "GPU {}: fan curve applied to {} fan(s)",
+            gpu, fan_count
+        ));
+    }
+
     fn cycle_theme(&mut self) {
         self.current_theme = self.current_theme.next();
         self.theme = themes::ColorPalette::from_variant(self.current_theme);
         self.set_status_message(format!("Theme: {}", self.current_theme.name()));
     }
 
+    fn revert_overclock(&mut self) {
+        if !self.selected_gpu_controllable() {
+            self.set_status_message("Control unavailable on this vendor".to_string());
+            return;
+        }
+
+        match crate::overclocking::revert_last() {
+            Ok((gpu, profile)) => {
+                if gpu as usize == self.selected_gpu {
+                    self.gpu_offset = profile.gpu_clock_offset;
+                    self.memory_offset = profile.memory_clock_offset;
+                    self.power_limit_percent = u32::from(profile.power_limit);
+                }
+                self.set_status_message(format!(
+                    "GPU {}: reverted to {}: {:+}MHz core, {:+}MHz mem, {}%",
+                    gpu,
+                    profile.name,
+                    profile.gpu_clock_offset,
+                    profile.memory_clock_offset,
+                    profile.power_limit
+                ));
+            }
+            Err(e) => self.set_status_message(format!("Overclock revert failed: {}", e)),
+        }
+    }
+
+    /// Whether control actions apply to the selected GPU (NVIDIA only)
+    fn selected_gpu_controllable(&self) -> bool {
+        self.backend_ctx
+            .as_ref()
+            .map(|ctx| ctx.supports_control(self.selected_gpu as u32))
+            .unwrap_or(true)
+    }
+
+    /// Draw a notice in place of a control panel for non-NVIDIA GPUs.
+    /// Returns true if the notice was drawn.
+    fn draw_control_unavailable(&self, f: &mut Frame, area: Rect) -> bool {
+        if self.selected_gpu_controllable() {
+            return false;
+        }
+
+        let vendor = self
+            .backend_ctx
+            .as_ref()
+            .map(|ctx| ctx.vendor(self.selected_gpu as u32).name())
+            .unwrap_or("Unknown");
+        let para = Paragraph::new(vec![
+            Line::from(Span::styled(
+                format!("Control unavailable on this vendor ({})", vendor),
+                Style::default()
+                    .fg(self.theme.yellow.to_ratatui())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                "Monitoring only: utilization and temperature are read from sysfs",
+                Style::default().fg(self.theme.comment.to_ratatui()),
+            )),
+        ])
+        .alignment(Alignment::Center);
+        f.render_widget(para, area);
+        true
+    }
+
     fn set_status_message(&mut self, msg: String) {
         self.status_message = Some(msg);
         self.status_message_time = Some(Instant::now());
@@ -972,32 +1702,125 @@ impl TuiApp {
             None => return,
         };
 
-        if !backend_ctx.is_nvml_available() {
+        let nvml_available = backend_ctx.is_nvml_available();
+        if !nvml_available && backend_ctx.sysfs_gpus.is_empty() {
             return;
         }
 
         for gpu_id in 0..self.device_count {
+            if !nvml_available && backend_ctx.supports_control(gpu_id) {
+                continue;
+            }
             if let Ok(metrics) = backend_ctx.get_metrics(gpu_id) {
                 let gpu_metrics = GpuMetrics {
                     timestamp: Instant::now(),
                     temperature: metrics.temperature as f64,
                     gpu_utilization: metrics.gpu_utilization as f64,
-                    memory_utilization: metrics.memory_utilization as f64,
+                    memory_controller_utilization: metrics.memory_controller_utilization as f64,
+                    vram_used_percent: metrics.vram_used_percent() as f64,
                     power_draw: metrics.power_draw_mw as f64 / 1000.0,
                     fan_speed: metrics.fan_speed as f64,
+                    fan_rpm: backend_ctx.nvml.get_fan_rpm(gpu_id, 0).ok(),
                     gpu_clock: metrics.gpu_clock_mhz as f64,
                     memory_clock: metrics.memory_clock_mhz as f64,
                 };
 
+                if let Some(histogram) = self.clock_histograms.get_mut(gpu_id as usize) {
+                    histogram.record(metrics.gpu_clock_mhz);
+                }
+                for firing in self
+                    .alert_evaluator
+                    .evaluate(&self.alert_rules, gpu_id, |m| Some(m.read(&metrics)))
+                {
+                    let rule = &self.alert_rules[firing.rule];
+                    self.status_message = Some(format!(
+                        "Alert: GPU {} {} (now {:.1}{})",
+                        gpu_id,
+                        rule.describe(),
+                        firing.value,
+                        rule.metric.unit()
+                    ));
+                    self.status_message_time = Some(Instant::now());
+                    if self.throttle_notifier.is_none() {
+                        self.throttle_notifier =
+                            crate::notifications::NotificationManager::new().ok();
+                    }
+                    if let Some(notifier) = &self.throttle_notifier {
+                        let _ = notifier.notify_alert_rule(rule, &firing);
+                    }
+                }
                 if let Some(history) = self.metrics_history.get_mut(gpu_id as usize) {
                     history.push_back(gpu_metrics);
-                    if history.len() > MAX_HISTORY {
+                    if history.len() > self.history_len {
                         history.pop_front();
                     }
                 }
             }
         }
 
+        let gpu = self.selected_gpu as u32;
+        self.fan_readings = if backend_ctx.supports_control(gpu) {
+            let fan_count = backend_ctx.nvml.get_fan_count(gpu).unwrap_or(0);
+            (0..fan_count)
+                .map(|fan| {
+                    (
+                        backend_ctx.nvml.get_fan_speed(gpu, fan).ok(),
+                        backend_ctx.nvml.get_fan_rpm(gpu, fan).ok(),
+                    )
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        if nvml_available {
+            for gpu_id in 0..self.device_count {
+                let reading = backend_ctx.nvml.get_throttle_reasons(gpu_id);
+                let Some(log) = self.throttle_logs.get_mut(gpu_id as usize) else {
+                    continue;
+                };
+                let transition = log.record(reading);
+                if let (Some(transition), Some(reasons)) = (transition, log.current()) {
+                    if transition.is_thermal_onset() {
+                        if self.throttle_notifier.is_none() {
+                            self.throttle_notifier =
+                                crate::notifications::NotificationManager::new().ok();
+                        }
+                        if let Some(notifier) = self.throttle_notifier.as_mut() {
+                            let _ = notifier.notify_thermal_throttle(gpu_id, reasons);
+                        }
+                    }
+                }
+            }
+        }
+
+        self.video_engines = backend_ctx
+            .nvml
+            .get_video_engine_stats(self.selected_gpu as u32)
+            .ok();
+        // GPM readings sample over a short window, so skip them off-tab
+        self.engine_utilization = if self.current_tab == Tab::Performance as usize {
+            backend_ctx
+                .nvml
+                .get_engine_utilization(self.selected_gpu as u32)
+                .ok()
+                .filter(|_| nvml_available)
+        } else {
+            None
+        };
+        self.chip_power = crate::power::get_chip_power(&backend_ctx.nvml, self.selected_gpu as u32)
+            .ok()
+            .filter(|_| nvml_available);
+
+        self.mig_instances = backend_ctx
+            .nvml
+            .get_mig_instances(self.selected_gpu as u32)
+            .ok()
+            .filter(|_| nvml_available && backend_ctx.supports_control(self.selected_gpu as u32))
+            .unwrap_or_default();
+
+        self.locked_clocks = crate::overclocking::LockedClocks::load(self.selected_gpu as u32);
+
         // Refresh process list (rate-limited internally)
         self.refresh_processes();
 
@@ -1291,13 +2114,21 @@ impl TuiApp {
 
         // Process table block
         let block = Block::default()
-            .title(" Processes ")
+            .title(if self.process_tree {
+                " Processes (tree) "
+            } else {
+                " Processes "
+            })
             .borders(Borders::ALL)
             .border_style(Style::default().fg(accent));
 
         let inner = block.inner(area);
         f.render_widget(block, area);
 
+        if self.draw_control_unavailable(f, inner) {
+            return;
+        }
+
         if self.processes.is_empty() {
             let msg = if self.filter_text.is_empty() {
                 "No GPU processes running"
@@ -1348,7 +2179,7 @@ impl TuiApp {
                 };
                 Row::new(vec![
                     Cell::from(format!("{:>6}", p.pid)).style(Style::default().fg(fg)),
-                    Cell::from(p.name.clone()).style(Style::default().fg(fg)),
+                    Cell::from(p.label()).style(Style::default().fg(fg)),
                     Cell::from(p.process_type.as_str()).style(type_style),
                     Cell::from(format!("{:>6} MB", p.vram_mb)).style(Style::default().fg(fg)),
                 ])
@@ -1379,12 +2210,55 @@ impl TuiApp {
         }
     }
 
+    /// Right-aligned " throttle: … " title for the selected GPU
+    fn throttle_title(&self) -> Line<'static> {
+        let (label, color) = match self.throttle_logs.get(self.selected_gpu) {
+            Some(log) => match log.current() {
+                Some(r) if r.is_thermal() => (log.status_label(), self.theme.red),
+                Some(r) if r.is_throttling() => (log.status_label(), self.theme.yellow),
+                _ => (log.status_label(), self.theme.comment),
+            },
+            None => ("unavailable".to_string(), self.theme.comment),
+        };
+        Line::from(Span::styled(
+            format!(" throttle: {} ", label),
+            Style::default().fg(color.to_ratatui()),
+        ))
+        .right_aligned()
+    }
+
+    /// Selected GPU's accent color from `gpu_labels`, else the theme's
+    fn gpu_accent(&self) -> ratatui::style::Color {
+        self.gpu_labels
+            .get(self.selected_gpu)
+            .and_then(|label| label.accent())
+            .unwrap_or(self.theme.teal)
+            .to_ratatui()
+    }
+
+    /// "Render 1/3" for a nicknamed GPU, else "GPU 1/3"
+    fn gpu_indicator(&self) -> String {
+        let nickname = self
+            .gpu_labels
+            .get(self.selected_gpu)
+            .and_then(|label| label.nickname.as_deref());
+        let total = self.device_count.max(1);
+        match nickname {
+            Some(_) => format!(
+                "{} {}/{}",
+                crate::multi_gpu::gpu_display_name(nickname, self.selected_gpu as u32),
+                self.selected_gpu + 1,
+                total
+            ),
+            None => format!("GPU {}/{}", self.selected_gpu + 1, total),
+        }
+    }
+
     fn draw_nvtop_header(&self, f: &mut Frame, area: Rect) {
-        let accent = self.theme.teal.to_ratatui();
+        let accent = self.gpu_accent();
 
         let (gpu_name, driver) = if let Some(ref ctx) = self.backend_ctx {
             let name = ctx
-                .nvml
                 .get_name(self.selected_gpu as u32)
                 .unwrap_or_else(|_| "Unknown GPU".to_string());
             (name, ctx.driver_version.clone())
@@ -1393,15 +2267,15 @@ impl TuiApp {
         };
 
         let header_text = format!(
-            " {} | Driver: {} | GPU {}/{} ",
+            " {} | Driver: {} | {} ",
             gpu_name,
             driver,
-            self.selected_gpu + 1,
-            self.device_count.max(1)
+            self.gpu_indicator()
         );
 
         let block = Block::default()
             .title(header_text)
+            .title_top(self.throttle_title())
             .borders(Borders::ALL)
             .border_style(Style::default().fg(accent));
 
@@ -1409,10 +2283,11 @@ impl TuiApp {
         if let Some(history) = self.metrics_history.get(self.selected_gpu) {
             if let Some(metrics) = history.back() {
                 let status = format!(
-                    "Temp: {}°C | GPU: {}% | Mem: {}% | Power: {:.0}W | Fan: {}% | Clock: {} MHz",
+                    "Temp: {}°C | GPU: {}% | VRAM: {}% | MemCtl: {}% | Power: {:.0}W | Fan: {}% | Clock: {} MHz",
                     metrics.temperature as u32,
                     metrics.gpu_utilization as u32,
-                    metrics.memory_utilization as u32,
+                    metrics.vram_used_percent as u32,
+                    metrics.memory_controller_utilization as u32,
                     metrics.power_draw,
                     metrics.fan_speed as u32,
                     metrics.gpu_clock as u32,
@@ -1431,13 +2306,52 @@ impl TuiApp {
         f.render_widget(para, area);
     }
 
+    /// Alert level to mark on the selected GPU's gauge for `metric`
+    fn gauge_threshold(&self, metric: crate::monitoring::AlertMetric) -> Option<f64> {
+        use crate::monitoring::AlertMetric;
+
+        crate::monitoring::upper_threshold(&self.alert_rules, metric, self.selected_gpu as u32)
+            .or_else(|| {
+                if !self.alert_config.enabled {
+                    return None;
+                }
+                match metric {
+                    AlertMetric::Temperature => Some(self.alert_config.temp_warning as f64),
+                    AlertMetric::Power => Some(self.alert_config.power_warning as f64),
+                    AlertMetric::Vram => None,
+                }
+            })
+    }
+
+    /// Overlay a thin line on a titled gauge at the alert threshold for
+    /// `metric`; `full_scale` is the value the gauge shows as 100%
+    fn draw_gauge_threshold(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        metric: crate::monitoring::AlertMetric,
+        full_scale: f64,
+    ) {
+        let Some(threshold) = self.gauge_threshold(metric) else {
+            return;
+        };
+        let bar = Block::default().title(" ").inner(area);
+        let Some(column) = marker_column(bar.width, threshold / full_scale) else {
+            return;
+        };
+        let color = self.theme.red.to_ratatui();
+        let buffer = f.buffer_mut();
+        for y in bar.top()..bar.bottom() {
+            if let Some(cell) = buffer.cell_mut((bar.x + column, y)) {
+                cell.set_symbol("│").set_fg(color);
+            }
+        }
+    }
+
     fn draw_nvtop_gauges(&self, f: &mut Frame, area: Rect) {
         let accent = self.theme.teal.to_ratatui();
-        let green = self.theme.green.to_ratatui();
         let yellow = self.theme.yellow.to_ratatui();
-        let red = self.theme.red.to_ratatui();
         let cyan = self.theme.cyan.to_ratatui();
-        let purple = self.theme.purple.to_ratatui();
         let fg = self.theme.fg.to_ratatui();
 
         // Two-column layout like htop
@@ -1465,15 +2379,18 @@ impl TuiApp {
                     .block(Block::default().title(gpu_label))
                     .gauge_style(
                         Style::default()
-                            .fg(green)
+                            .fg(self
+                                .theme
+                                .usage_color(m.gpu_utilization as f32)
+                                .to_ratatui())
                             .bg(self.theme.bg_dark.to_ratatui()),
                     )
                     .percent(m.gpu_utilization as u16)
                     .label("");
                 f.render_widget(gpu_gauge, left_gauges[0]);
 
-                // Memory utilization
-                let mem_label = format!("Mem [{:>3}%]", m.memory_utilization as u32);
+                // Memory controller (bandwidth) activity, not VRAM capacity
+                let mem_label = format!("MCtl [{:>3}%]", m.memory_controller_utilization as u32);
                 let mem_gauge = Gauge::default()
                     .block(Block::default().title(mem_label))
                     .gauge_style(
@@ -1481,7 +2398,7 @@ impl TuiApp {
                             .fg(yellow)
                             .bg(self.theme.bg_dark.to_ratatui()),
                     )
-                    .percent(m.memory_utilization as u16)
+                    .percent(m.memory_controller_utilization as u16)
                     .label("");
                 f.render_widget(mem_gauge, left_gauges[1]);
 
@@ -1492,12 +2409,18 @@ impl TuiApp {
                     .block(Block::default().title(pwr_label))
                     .gauge_style(
                         Style::default()
-                            .fg(purple)
+                            .fg(self.theme.power_color(power_pct as f32).to_ratatui())
                             .bg(self.theme.bg_dark.to_ratatui()),
                     )
                     .percent(power_pct)
                     .label("");
                 f.render_widget(pwr_gauge, left_gauges[2]);
+                self.draw_gauge_threshold(
+                    f,
+                    left_gauges[2],
+                    crate::monitoring::AlertMetric::Power,
+                    450.0,
+                );
 
                 // Right column: Temp, Fan, Clocks
                 let right_gauges = Layout::default()
@@ -1509,16 +2432,10 @@ impl TuiApp {
                         Constraint::Min(0),
                     ])
                     .split(cols[1]);
-
-                // Temperature
-                let temp_pct = (m.temperature / 100.0 * 100.0).min(100.0) as u16;
-                let temp_color = if m.temperature > 80.0 {
-                    red
-                } else if m.temperature > 60.0 {
-                    yellow
-                } else {
-                    green
-                };
+
+                // Temperature
+                let temp_pct = (m.temperature / 100.0 * 100.0).min(100.0) as u16;
+                let temp_color = self.theme.temp_color(m.temperature as f32).to_ratatui();
                 let tmp_label = format!("Tmp [{:>3}°C]", m.temperature as u32);
                 let tmp_gauge = Gauge::default()
                     .block(Block::default().title(tmp_label))
@@ -1530,9 +2447,19 @@ impl TuiApp {
                     .percent(temp_pct)
                     .label("");
                 f.render_widget(tmp_gauge, right_gauges[0]);
+                self.draw_gauge_threshold(
+                    f,
+                    right_gauges[0],
+                    crate::monitoring::AlertMetric::Temperature,
+                    100.0,
+                );
 
                 // Fan
-                let fan_label = format!("Fan [{:>3}%]", m.fan_speed as u32);
+                let fan_label = format!(
+                    "Fan [{:>3}% · {}]",
+                    m.fan_speed as u32,
+                    crate::fan::format_rpm(m.fan_rpm)
+                );
                 let fan_gauge = Gauge::default()
                     .block(Block::default().title(fan_label))
                     .gauge_style(
@@ -1579,21 +2506,22 @@ impl TuiApp {
             .split(area);
 
         // Tabs
-        let titles: Vec<&str> = Tab::titles();
+        let titles: Vec<&str> = Tab::titles(&self.visible_tabs);
         let tabs = Tabs::new(titles.clone())
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" nvctl dashboard ")
-                    .border_style(Style::default().fg(accent)),
+                    .title(format!(" nvctl dashboard · {} ", self.gpu_indicator()))
+                    .title_top(self.throttle_title())
+                    .border_style(Style::default().fg(self.gpu_accent())),
             )
-            .select(self.current_tab)
+            .select(self.visible_tab_position())
             .style(Style::default().fg(fg))
             .highlight_style(Style::default().fg(accent).add_modifier(Modifier::BOLD));
         f.render_widget(tabs, chunks[0]);
 
         // Content based on current tab
-        let current_tab = Tab::from_index(self.current_tab);
+        let current_tab = Tab::from_index(&self.visible_tabs, self.visible_tab_position());
         self.draw_tab_content(f, chunks[1], current_tab);
 
         // Footer
@@ -1610,9 +2538,13 @@ impl TuiApp {
     }
 
     fn draw_tab_content(&self, f: &mut Frame, area: Rect, tab: Tab) {
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(format!(" {} ", Tab::titles()[self.current_tab]));
+        let title = match tab {
+            Tab::Processes | Tab::Overclocking | Tab::FanControl | Tab::Tuner => {
+                format!(" {} · {} ", tab.title(), self.gpu_indicator())
+            }
+            _ => format!(" {} ", tab.title()),
+        };
+        let block = Block::default().borders(Borders::ALL).title(title);
 
         let inner = block.inner(area);
         f.render_widget(block, area);
@@ -1644,20 +2576,96 @@ impl TuiApp {
             if let Some(m) = history.back() {
                 lines.push(format!("GPU Utilization:    {}%", m.gpu_utilization as u32));
                 lines.push(format!(
-                    "Memory Utilization: {}%",
-                    m.memory_utilization as u32
+                    "VRAM Used:          {}%",
+                    m.vram_used_percent as u32
+                ));
+                lines.push(format!(
+                    "Memory Controller:  {}%",
+                    m.memory_controller_utilization as u32
                 ));
                 lines.push(format!("Temperature:        {}°C", m.temperature as u32));
-                lines.push(format!("Fan Speed:          {}%", m.fan_speed as u32));
+                lines.push(format!(
+                    "Fan Speed:          {}% ({})",
+                    m.fan_speed as u32,
+                    crate::fan::format_rpm(m.fan_rpm)
+                ));
                 lines.push(format!("Power Draw:         {:.1}W", m.power_draw));
                 lines.push(format!("GPU Clock:          {} MHz", m.gpu_clock as u32));
                 lines.push(format!("Memory Clock:       {} MHz", m.memory_clock as u32));
             }
         }
 
-        let text = lines.join("\n");
-        let para = Paragraph::new(text).alignment(Alignment::Left);
-        f.render_widget(para, area);
+        if self.mig_instances.is_empty() {
+            let text = lines.join("\n");
+            let para = Paragraph::new(text).alignment(Alignment::Left);
+            f.render_widget(para, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(lines.len() as u16 + 1),
+                Constraint::Min(3),
+            ])
+            .split(area);
+        let para = Paragraph::new(lines.join("\n")).alignment(Alignment::Left);
+        f.render_widget(para, chunks[0]);
+        self.draw_mig_instances(f, chunks[1]);
+    }
+
+    /// One memory gauge per MIG slice, labelled with its profile, SMs and utilization
+    fn draw_mig_instances(&self, f: &mut Frame, area: Rect) {
+        let accent = self.theme.teal.to_ratatui();
+        let cyan = self.theme.cyan.to_ratatui();
+        let bg_dark = self.theme.bg_dark.to_ratatui();
+
+        let block = Block::default()
+            .title(format!(" MIG Instances ({}) ", self.mig_instances.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(accent));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                self.mig_instances
+                    .iter()
+                    .map(|_| Constraint::Length(1))
+                    .collect::<Vec<_>>(),
+            )
+            .split(inner);
+
+        for (instance, row) in self.mig_instances.iter().zip(rows.iter()) {
+            let used_mb = instance.memory_used_bytes / (1024 * 1024);
+            let total_mb = instance.memory_total_bytes / (1024 * 1024);
+            let ratio = if total_mb > 0 {
+                (used_mb as f64 / total_mb as f64).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            let mut label = format!(
+                "{} {}  {}/{} MB",
+                instance.index,
+                instance.profile_name(),
+                used_mb,
+                total_mb
+            );
+            if let Some(sms) = instance.sm_count {
+                label.push_str(&format!("  {} SMs", sms));
+            }
+            if let Some(util) = instance.gpu_utilization {
+                label.push_str(&format!("  util {}%", util));
+            }
+
+            let gauge = Gauge::default()
+                .gauge_style(Style::default().fg(cyan).bg(bg_dark))
+                .ratio(ratio)
+                .label(label);
+            f.render_widget(gauge, *row);
+        }
     }
 
     fn draw_performance_tab(&self, f: &mut Frame, area: Rect) {
@@ -1668,13 +2676,21 @@ impl TuiApp {
         let fg = self.theme.fg.to_ratatui();
         let bg_dark = self.theme.bg_dark.to_ratatui();
 
+        // The video engine row only exists on GPUs with NVENC
+        let video_rows = if self.video_engines.is_some() { 3 } else { 0 };
+        let engine_rows = match &self.engine_utilization {
+            Some(engines) if engines.per_engine => 3,
+            _ => 0,
+        };
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // GPU Clock gauge
-                Constraint::Length(3), // Memory Clock gauge
-                Constraint::Length(3), // GPU Utilization gauge
-                Constraint::Min(3),    // Info section
+                Constraint::Length(3),           // GPU Clock gauge
+                Constraint::Length(3),           // Memory Clock gauge
+                Constraint::Length(3),           // GPU Utilization gauge
+                Constraint::Length(engine_rows), // Graphics / Compute / Copy gauges
+                Constraint::Length(video_rows),  // NVENC / NVDEC gauges
+                Constraint::Min(3),              // Info section
             ])
             .split(area);
 
@@ -1682,10 +2698,17 @@ impl TuiApp {
             if let Some(m) = history.back() {
                 // GPU Clock (assume max ~3000 MHz for modern GPUs)
                 let gpu_clock_pct = ((m.gpu_clock / 3000.0) * 100.0).min(100.0) as u16;
+                let lock = self.locked_clocks.as_ref();
+                let locked_tag = |mhz: Option<u32>| {
+                    mhz.map(|mhz| format!("[LOCKED {} MHz] ", mhz))
+                        .unwrap_or_default()
+                };
                 let gpu_clock_gauge = Gauge::default()
-                    .block(
-                        Block::default().title(format!(" GPU Clock: {} MHz ", m.gpu_clock as u32)),
-                    )
+                    .block(Block::default().title(format!(
+                        " GPU Clock: {} MHz {}",
+                        m.gpu_clock as u32,
+                        locked_tag(lock.and_then(|l| l.gpu_mhz))
+                    )))
                     .gauge_style(Style::default().fg(green).bg(bg_dark))
                     .percent(gpu_clock_pct)
                     .label("");
@@ -1694,32 +2717,92 @@ impl TuiApp {
                 // Memory Clock (assume max ~12000 MHz for GDDR6X)
                 let mem_clock_pct = ((m.memory_clock / 12000.0) * 100.0).min(100.0) as u16;
                 let mem_clock_gauge = Gauge::default()
-                    .block(
-                        Block::default()
-                            .title(format!(" Memory Clock: {} MHz ", m.memory_clock as u32)),
-                    )
+                    .block(Block::default().title(format!(
+                        " Memory Clock: {} MHz {}",
+                        m.memory_clock as u32,
+                        locked_tag(lock.and_then(|l| l.mem_mhz))
+                    )))
                     .gauge_style(Style::default().fg(yellow).bg(bg_dark))
                     .percent(mem_clock_pct)
                     .label("");
                 f.render_widget(mem_clock_gauge, chunks[1]);
 
-                // GPU Utilization
+                // GPU Utilization; flag when no per-engine breakdown exists
+                let coarse = match &self.engine_utilization {
+                    Some(engines) if !engines.per_engine => "(overall only, no per-engine data) ",
+                    _ => "",
+                };
                 let gpu_util_gauge = Gauge::default()
-                    .block(
-                        Block::default()
-                            .title(format!(" GPU Utilization: {}% ", m.gpu_utilization as u32)),
-                    )
+                    .block(Block::default().title(format!(
+                        " GPU Utilization: {}% {}",
+                        m.gpu_utilization as u32, coarse
+                    )))
                     .gauge_style(Style::default().fg(purple).bg(bg_dark))
                     .percent(m.gpu_utilization as u16)
                     .label("");
                 f.render_widget(gpu_util_gauge, chunks[2]);
 
-                // Info section with sparkline history (toggle with 'g')
-                if self.show_graphs {
+                if let Some(engines) = self.engine_utilization.filter(|e| e.per_engine) {
+                    let engine_chunks = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([
+                            Constraint::Ratio(1, 3),
+                            Constraint::Ratio(1, 3),
+                            Constraint::Ratio(1, 3),
+                        ])
+                        .split(chunks[3]);
+                    let readings = [
+                        ("Graphics", engines.graphics),
+                        ("Compute", engines.compute),
+                        ("Copy", engines.copy),
+                    ];
+                    for ((name, percent), area) in readings.into_iter().zip(engine_chunks.iter()) {
+                        let gauge = Gauge::default()
+                            .block(Block::default().title(format!(" {}: {}% ", name, percent)))
+                            .gauge_style(Style::default().fg(purple).bg(bg_dark))
+                            .percent(percent.min(100) as u16)
+                            .label("");
+                        f.render_widget(gauge, *area);
+                    }
+                }
+
+                if let Some(video) = &self.video_engines {
+                    let video_chunks = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .split(chunks[4]);
+                    let sessions = video
+                        .session_count
+                        .map(|n| format!(" · {} session(s)", n))
+                        .unwrap_or_default();
+                    let encoder_gauge = Gauge::default()
+                        .block(Block::default().title(format!(
+                            " NVENC: {}%{} ",
+                            video.encoder_utilization, sessions
+                        )))
+                        .gauge_style(Style::default().fg(accent).bg(bg_dark))
+                        .percent(video.encoder_utilization.min(100) as u16)
+                        .label("");
+                    f.render_widget(encoder_gauge, video_chunks[0]);
+                    let decoder_gauge = Gauge::default()
+                        .block(
+                            Block::default()
+                                .title(format!(" NVDEC: {}% ", video.decoder_utilization)),
+                        )
+                        .gauge_style(Style::default().fg(accent).bg(bg_dark))
+                        .percent(video.decoder_utilization.min(100) as u16)
+                        .label("");
+                    f.render_widget(decoder_gauge, video_chunks[1]);
+                }
+
+                // Info section: clock histogram ('c') or sparkline history ('g')
+                if self.show_clock_histogram {
+                    self.draw_clock_histogram(f, chunks[5]);
+                } else if self.show_graphs {
                     let info_chunks = Layout::default()
                         .direction(Direction::Horizontal)
                         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                        .split(chunks[3]);
+                        .split(chunks[5]);
 
                     // GPU utilization history sparkline
                     let gpu_history: Vec<u64> =
@@ -1727,7 +2810,7 @@ impl TuiApp {
                     let sparkline = Sparkline::default()
                         .block(
                             Block::default()
-                                .title(" GPU History ")
+                                .title(sparkline_title("GPU History", &gpu_history, "%"))
                                 .borders(Borders::ALL)
                                 .border_style(Style::default().fg(accent)),
                         )
@@ -1741,7 +2824,7 @@ impl TuiApp {
                     let clock_sparkline = Sparkline::default()
                         .block(
                             Block::default()
-                                .title(" Clock History ")
+                                .title(sparkline_title("Clock History", &clock_history, " MHz"))
                                 .borders(Borders::ALL)
                                 .border_style(Style::default().fg(accent)),
                         )
@@ -1758,6 +2841,69 @@ impl TuiApp {
         }
     }
 
+    /// Time spent in each GPU clock bin this session, highest clocks first
+    fn draw_clock_histogram(&self, f: &mut Frame, area: Rect) {
+        let accent = self.theme.teal.to_ratatui();
+        let green = self.theme.green.to_ratatui();
+        let yellow = self.theme.yellow.to_ratatui();
+        let comment = self.theme.comment.to_ratatui();
+
+        let Some(histogram) = self.clock_histograms.get(self.selected_gpu) else {
+            return;
+        };
+        let summary = match histogram.share_near_peak(100) {
+            Some((peak, share)) => format!(
+                " Clock Histogram · {:.0}% within 100 MHz of {} MHz · {}s · z reset ",
+                share * 100.0,
+                peak,
+                histogram.session_duration().as_secs()
+            ),
+            None => " Clock Histogram · z reset ".to_string(),
+        };
+        let block = Block::default()
+            .title(summary)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(accent));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let bins = histogram.bins();
+        if bins.is_empty() {
+            f.render_widget(
+                Paragraph::new("Collecting clock samples…").style(Style::default().fg(comment)),
+                inner,
+            );
+            return;
+        }
+
+        // "2700-2750 MHz " + " 100.0%  9999s" around the bar
+        let bar_width = (inner.width as usize).saturating_sub(30).max(1);
+        let max_fraction = bins.iter().map(|b| b.fraction).fold(0.0, f64::max);
+        let lines: Vec<Line> = bins
+            .iter()
+            .take(inner.height as usize)
+            .map(|bin| {
+                let len = ((bin.fraction / max_fraction) * bar_width as f64).round() as usize;
+                // Highlight where the card actually spends most of its time
+                let color = if bin.fraction >= max_fraction * 0.5 {
+                    green
+                } else {
+                    yellow
+                };
+                Line::from(vec![
+                    Span::raw(format!("{:>4}-{:<4} MHz ", bin.start_mhz, bin.end_mhz)),
+                    Span::styled("█".repeat(len.max(1)), Style::default().fg(color)),
+                    Span::raw(format!(
+                        " {:>5.1}% {:>5}s",
+                        bin.fraction * 100.0,
+                        bin.time.as_secs()
+                    )),
+                ])
+            })
+            .collect();
+        f.render_widget(Paragraph::new(lines), inner);
+    }
+
     fn draw_memory_tab(&self, f: &mut Frame, area: Rect) {
         let accent = self.theme.teal.to_ratatui();
         let cyan = self.theme.cyan.to_ratatui();
@@ -1767,24 +2913,35 @@ impl TuiApp {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // Memory utilization gauge
-                Constraint::Length(3), // Spacer
+                Constraint::Length(3), // VRAM capacity gauge
+                Constraint::Length(3), // Memory controller gauge
                 Constraint::Min(5),    // Memory breakdown
             ])
             .split(area);
 
         if let Some(history) = self.metrics_history.get(self.selected_gpu) {
             if let Some(m) = history.back() {
-                // Memory utilization gauge
+                // Capacity: how much VRAM is allocated
+                let vram_gauge = Gauge::default()
+                    .block(Block::default().title(format!(
+                        " VRAM Used (capacity): {}% ",
+                        m.vram_used_percent as u32
+                    )))
+                    .gauge_style(Style::default().fg(accent).bg(bg_dark))
+                    .percent(m.vram_used_percent.min(100.0) as u16)
+                    .label("");
+                f.render_widget(vram_gauge, chunks[0]);
+
+                // Bandwidth: how busy the memory controller is
                 let mem_gauge = Gauge::default()
                     .block(Block::default().title(format!(
-                        " Memory Utilization: {}% ",
-                        m.memory_utilization as u32
+                        " Memory Controller (bandwidth activity): {}% ",
+                        m.memory_controller_utilization as u32
                     )))
                     .gauge_style(Style::default().fg(cyan).bg(bg_dark))
-                    .percent(m.memory_utilization as u16)
+                    .percent(m.memory_controller_utilization.min(100.0) as u16)
                     .label("");
-                f.render_widget(mem_gauge, chunks[0]);
+                f.render_widget(mem_gauge, chunks[1]);
 
                 // Memory breakdown info
                 if let Some(ref ctx) = self.backend_ctx {
@@ -1793,9 +2950,23 @@ impl TuiApp {
                         let total_mb = metrics.memory_total_bytes / (1024 * 1024);
                         let free_mb = total_mb.saturating_sub(used_mb);
 
+                        let bus = metrics
+                            .memory_bus_width_bits
+                            .map(|w| format!("{}-bit", w))
+                            .unwrap_or_else(|| "unknown".to_string());
+                        let bandwidth = match (
+                            metrics.bandwidth_in_use_gbps(),
+                            metrics.peak_bandwidth_gbps(),
+                        ) {
+                            (Some(used), Some(peak)) => {
+                                format!("~{:.0} / {:.0} GB/s (estimate)", used, peak)
+                            }
+                            _ => "n/a (bus width unknown)".to_string(),
+                        };
+
                         let info = format!(
-                            "VRAM Usage:\n\n  Used:  {:>6} MB\n  Free:  {:>6} MB\n  Total: {:>6} MB\n\n  Memory Bus: {} MHz",
-                            used_mb, free_mb, total_mb, m.memory_clock as u32
+                            "VRAM Capacity:\n\n  Used:  {:>6} MB\n  Free:  {:>6} MB\n  Total: {:>6} MB\n\nBandwidth:\n\n  Memory Clock: {} MHz\n  Bus Width:    {}\n  In Use:       {}",
+                            used_mb, free_mb, total_mb, m.memory_clock as u32, bus, bandwidth
                         );
                         let info_para = Paragraph::new(info)
                             .block(
@@ -1819,9 +2990,6 @@ impl TuiApp {
 
     fn draw_temperature_tab(&self, f: &mut Frame, area: Rect) {
         let accent = self.theme.teal.to_ratatui();
-        let green = self.theme.green.to_ratatui();
-        let yellow = self.theme.yellow.to_ratatui();
-        let red = self.theme.red.to_ratatui();
         let fg = self.theme.fg.to_ratatui();
         let bg_dark = self.theme.bg_dark.to_ratatui();
 
@@ -1838,13 +3006,7 @@ impl TuiApp {
             if let Some(m) = history.back() {
                 // Temperature gauge with color coding
                 let temp = m.temperature as u32;
-                let temp_color = if temp >= 80 {
-                    red
-                } else if temp >= 65 {
-                    yellow
-                } else {
-                    green
-                };
+                let temp_color = self.theme.temp_color(m.temperature as f32).to_ratatui();
                 let temp_pct = ((temp as f64 / 100.0) * 100.0).min(100.0) as u16;
                 let temp_status = if temp >= 80 {
                     "HOT!"
@@ -1863,29 +3025,73 @@ impl TuiApp {
                     .percent(temp_pct)
                     .label("");
                 f.render_widget(temp_gauge, chunks[0]);
+                self.draw_gauge_threshold(
+                    f,
+                    chunks[0],
+                    crate::monitoring::AlertMetric::Temperature,
+                    100.0,
+                );
 
                 // Fan speed gauge
                 let fan_gauge = Gauge::default()
-                    .block(Block::default().title(format!(" Fan Speed: {}% ", m.fan_speed as u32)))
+                    .block(Block::default().title(format!(
+                        " Fan Speed: {}% · {} ",
+                        m.fan_speed as u32,
+                        crate::fan::format_rpm(m.fan_rpm)
+                    )))
                     .gauge_style(Style::default().fg(accent).bg(bg_dark))
                     .percent(m.fan_speed as u16)
                     .label("");
                 f.render_widget(fan_gauge, chunks[1]);
 
-                // Temperature history sparkline (toggle with 'g')
+                // Temperature and fan RPM history sparklines (toggle with 'g')
                 if self.show_graphs {
+                    let has_rpm = history.iter().any(|m| m.fan_rpm.is_some());
+                    let graphs = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints(if has_rpm {
+                            [Constraint::Percentage(50), Constraint::Percentage(50)]
+                        } else {
+                            [Constraint::Percentage(100), Constraint::Percentage(0)]
+                        })
+                        .split(chunks[2]);
+
                     let temp_history: Vec<u64> =
                         history.iter().map(|m| m.temperature as u64).collect();
                     let sparkline = Sparkline::default()
                         .block(
                             Block::default()
-                                .title(" Temperature History ")
+                                .title(sparkline_title("Temp History", &temp_history, "°C"))
                                 .borders(Borders::ALL)
                                 .border_style(Style::default().fg(accent)),
                         )
                         .data(&temp_history)
                         .style(Style::default().fg(temp_color));
-                    f.render_widget(sparkline, chunks[2]);
+                    f.render_widget(sparkline, graphs[0]);
+
+                    if has_rpm {
+                        let rpm_history: Vec<u64> = history
+                            .iter()
+                            .map(|m| m.fan_rpm.unwrap_or(0) as u64)
+                            .collect();
+                        let sparkline = Sparkline::default()
+                            .block(
+                                Block::default()
+                                    .title(sparkline_title(
+                                        &format!(
+                                            "Fan RPM History ({})",
+                                            crate::fan::format_rpm(m.fan_rpm)
+                                        ),
+                                        &rpm_history,
+                                        " RPM",
+                                    ))
+                                    .borders(Borders::ALL)
+                                    .border_style(Style::default().fg(accent)),
+                            )
+                            .data(&rpm_history)
+                            .style(Style::default().fg(accent));
+                        f.render_widget(sparkline, graphs[1]);
+                    }
                 }
             }
         } else {
@@ -1896,6 +3102,61 @@ impl TuiApp {
         }
     }
 
+    fn draw_throttle_log(&self, f: &mut Frame, area: Rect) {
+        let fg = self.theme.fg.to_ratatui();
+        let fg_dark = self.theme.fg_dark.to_ratatui();
+        let yellow = self.theme.yellow.to_ratatui();
+        let red = self.theme.red.to_ratatui();
+
+        let block = Block::default()
+            .title(" Throttle Log ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.teal.to_ratatui()));
+
+        let lines: Vec<Line> = match self.throttle_logs.get(self.selected_gpu) {
+            Some(log) if !log.is_available() => vec![Line::styled(
+                "  Throttle reasons unavailable on this driver",
+                Style::default().fg(fg_dark),
+            )],
+            Some(log) if !log.is_empty() => {
+                let rows = area.height.saturating_sub(2) as usize;
+                log.events()
+                    .rev()
+                    .take(rows)
+                    .map(|event| {
+                        let color = if event.reasons.is_thermal() {
+                            red
+                        } else {
+                            yellow
+                        };
+                        let span = match event.ended_at {
+                            Some(end) => format!(
+                                "{} → {} ({}s)",
+                                event.started_at.format("%H:%M:%S"),
+                                end.format("%H:%M:%S"),
+                                event.duration_secs()
+                            ),
+                            None => format!(
+                                "{} → now ({}s)",
+                                event.started_at.format("%H:%M:%S"),
+                                event.duration_secs()
+                            ),
+                        };
+                        Line::from(vec![
+                            Span::styled(format!("  {:<32}", span), Style::default().fg(fg)),
+                            Span::styled(event.reasons.summary(), Style::default().fg(color)),
+                        ])
+                    })
+                    .collect()
+            }
+            _ => vec![Line::styled(
+                "  No throttling recorded this session",
+                Style::default().fg(fg_dark),
+            )],
+        };
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
     fn draw_power_tab(&self, f: &mut Frame, area: Rect) {
         let accent = self.theme.teal.to_ratatui();
         let green = self.theme.green.to_ratatui();
@@ -1910,12 +3171,20 @@ impl TuiApp {
         // Check if we have ASUS Power Monitor+ data
         let has_asus_power = self.asus_power_detector.is_some() && self.asus_power_status.is_some();
 
+        // Throttle log sits at the bottom of the tab
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(8), Constraint::Length(7)])
+            .split(area);
+        self.draw_throttle_log(f, sections[1]);
+        let area = sections[0];
+
         let chunks = if has_asus_power {
             Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
                     Constraint::Length(3),  // Power draw gauge
-                    Constraint::Length(5),  // Power info
+                    Constraint::Length(6),  // Power info
                     Constraint::Length(10), // ASUS Power Monitor+
                     Constraint::Min(3),     // History graph
                 ])
@@ -1925,7 +3194,7 @@ impl TuiApp {
                 .direction(Direction::Vertical)
                 .constraints([
                     Constraint::Length(3), // Power draw gauge
-                    Constraint::Length(5), // Power info
+                    Constraint::Length(6), // Power info
                     Constraint::Min(5),    // History graph
                 ])
                 .split(area)
@@ -1936,25 +3205,25 @@ impl TuiApp {
                 // Estimate power limit (assume 450W max for high-end cards)
                 let power_limit = 450.0;
                 let power_pct = ((m.power_draw / power_limit) * 100.0).min(100.0) as u16;
-                let power_color = if power_pct >= 90 {
-                    orange
-                } else if power_pct >= 70 {
-                    yellow
-                } else {
-                    green
-                };
+                let power_color = self.theme.power_color(power_pct as f32).to_ratatui();
 
                 let power_gauge = Gauge::default()
-                    .block(Block::default().title(format!(" Power Draw: {:.1}W ", m.power_draw)))
+                    .block(Block::default().title(format!(" Board Power: {:.1}W ", m.power_draw)))
                     .gauge_style(Style::default().fg(power_color).bg(bg_dark))
                     .percent(power_pct)
                     .label("");
                 f.render_widget(power_gauge, chunks[0]);
 
                 // Power info
+                // Board power is what matters for PSU sizing; chip power excludes
+                // memory, VRMs and fans
+                let chip = match self.chip_power {
+                    Some(chip) => format!("{:.1}W", chip),
+                    None => crate::power::BOARD_POWER_ONLY_NOTE.to_string(),
+                };
                 let power_info = format!(
-                    "  Current Draw:  {:.1}W\n  Est. TDP:      ~{}W\n  Efficiency:    {}%",
-                    m.power_draw, power_limit as u32, power_pct
+                    "  Board Power:   {:.1}W (whole card)\n  Chip Power:    {}\n  Est. TDP:      ~{}W\n  Efficiency:    {}%",
+                    m.power_draw, chip, power_limit as u32, power_pct
                 );
                 let info_para = Paragraph::new(power_info)
                     .block(
@@ -2090,7 +3359,7 @@ impl TuiApp {
                     let sparkline = Sparkline::default()
                         .block(
                             Block::default()
-                                .title(" Power History ")
+                                .title(sparkline_title("Power History", &power_history, " W"))
                                 .borders(Borders::ALL)
                                 .border_style(Style::default().fg(accent)),
                         )
@@ -2108,6 +3377,10 @@ impl TuiApp {
     }
 
     fn draw_processes_tab(&self, f: &mut Frame, area: Rect) {
+        if self.draw_control_unavailable(f, area) {
+            return;
+        }
+
         let accent = self.theme.teal.to_ratatui();
         let fg = self.theme.fg.to_ratatui();
         let yellow = self.theme.yellow.to_ratatui();
@@ -2169,7 +3442,7 @@ impl TuiApp {
 
                 Row::new(vec![
                     Cell::from(p.pid.to_string()).style(Style::default().fg(fg)),
-                    Cell::from(p.name.clone()).style(Style::default().fg(fg)),
+                    Cell::from(p.label()).style(Style::default().fg(fg)),
                     Cell::from(p.process_type.as_str()).style(type_style),
                     Cell::from(p.vram_mb.to_string()).style(Style::default().fg(fg)),
                 ])
@@ -2245,9 +3518,16 @@ impl TuiApp {
                 } else {
                     format!(" | Filter: \"{}\" (Esc to clear)", self.filter_text)
                 };
+                let view = if self.process_tree {
+                    "(T) flat | Enter expand"
+                } else {
+                    "(T)ree"
+                };
                 let footer = format!(
-                    " (f)ilter | (s)ort | (↑/↓) navigate | {} processes{}",
+                    " (f)ilter | (s)ort | {} | (↑/↓) navigate | {} rows on GPU {} (per-device NVML query){}",
+                    view,
                     self.processes.len(),
+                    self.selected_gpu,
                     filter_indicator
                 );
                 let footer_para = Paragraph::new(footer)
@@ -2259,8 +3539,12 @@ impl TuiApp {
     }
 
     fn draw_fan_tab(&self, f: &mut Frame, area: Rect) {
+        if self.draw_control_unavailable(f, area) {
+            return;
+        }
+
         let mode_str = if self.fan_control_mode {
-            "MANUAL"
+            "EDITING"
         } else {
             "AUTO"
         };
@@ -2268,8 +3552,28 @@ impl TuiApp {
             format!("Fan Mode: {}", mode_str),
             format!("Target Speed: {}%", self.fan_speed_target),
             String::new(),
-            "Fan Curve:".to_string(),
         ];
+        for (fan, (percent, rpm)) in self.fan_readings.iter().enumerate() {
+            lines.push(format!(
+                "Fan {}: {} · {}",
+                fan,
+                percent
+                    .map(|p| format!("{}%", p))
+                    .unwrap_or_else(|| "—%".to_string()),
+                crate::fan::format_rpm(*rpm)
+            ));
+        }
+        if !self.fan_readings.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend([format!(
+            "Fan Curve:{}",
+            if self.control_edits_dirty {
+                " (unapplied)"
+            } else {
+                ""
+            }
+        )]);
 
         for (i, (temp, speed)) in self.fan_curve_points.iter().enumerate() {
             let marker = if i == self.selected_curve_point {
@@ -2280,12 +3584,19 @@ impl TuiApp {
             lines.push(format!("{} {}°C -> {}%", marker, temp, speed));
         }
 
+        lines.push(String::new());
+        lines.push("Press 'f' to edit, ↑/↓ select point, +/- speed, Enter apply".to_string());
+
         let text = lines.join("\n");
         let para = Paragraph::new(text);
         f.render_widget(para, area);
     }
 
     fn draw_oc_tab(&self, f: &mut Frame, area: Rect) {
+        if self.draw_control_unavailable(f, area) {
+            return;
+        }
+
         let accent = self.theme.teal.to_ratatui();
         let yellow = self.theme.yellow.to_ratatui();
         let fg = self.theme.fg.to_ratatui();
@@ -2352,7 +3663,7 @@ impl TuiApp {
             ]),
             Line::from(vec![
                 Span::styled("Preset: ", Style::default().fg(fg_dark)),
-                Span::styled(format!("{:?}", self.oc_preset), Style::default().fg(accent)),
+                Span::styled(self.oc_preset.label(), Style::default().fg(accent)),
             ]),
             Line::from(""),
             Line::from(vec![
@@ -2378,7 +3689,7 @@ impl TuiApp {
             ]),
             Line::from(""),
             Line::from(Span::styled(
-                "Press 'o' to toggle OC mode, 1-4 for presets",
+                "'o' edit · ↑/↓ core · [/] memory · +/- power · Enter apply · 'u' revert",
                 Style::default().fg(fg_dark),
             )),
         ];
@@ -2422,15 +3733,50 @@ impl TuiApp {
             "Not available"
         };
 
-        let lines = [
-            format!("Profiler Status: {}", status),
-            String::new(),
-            "Press 'r' to start/stop recording".to_string(),
+        let mut lines = vec![
+            Line::from(format!("Profiler Status: {}", status)),
+            Line::from(""),
+            Line::from("Press 'r' to start/stop recording"),
+            Line::from(""),
         ];
 
-        let text = lines.join("\n");
-        let para = Paragraph::new(text);
-        f.render_widget(para, area);
+        match &self.last_stress_result {
+            Some(result) => {
+                let (badge, color) = if result.passed {
+                    (" PASS ", self.theme.green.to_ratatui())
+                } else {
+                    (" FAIL ", self.theme.red.to_ratatui())
+                };
+                lines.push(Line::from(vec![
+                    Span::raw("Last stress test: "),
+                    Span::styled(
+                        badge,
+                        Style::default()
+                            .fg(self.theme.bg.to_ratatui())
+                            .bg(color)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(format!(
+                        " {}s · max {}°C · throttled: {} · Xid: {}",
+                        result.duration_secs,
+                        result.max_temp,
+                        if result.throttled { "yes" } else { "no" },
+                        result.xid_errors
+                    )),
+                ]));
+                if let Some(reason) = &result.failure_reason {
+                    lines.push(Line::styled(
+                        format!("  {}", reason),
+                        Style::default().fg(color),
+                    ));
+                }
+            }
+            None => lines.push(Line::from(
+                "No stress test yet: nvctl overclock stress-test",
+            )),
+        }
+
+        f.render_widget(Paragraph::new(lines), area);
     }
 
     fn draw_osd_tab(&self, f: &mut Frame, area: Rect) {
@@ -2471,7 +3817,7 @@ impl TuiApp {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(8), // Driver info
+                Constraint::Length(9), // Driver info
                 Constraint::Length(7), // GSP status
                 Constraint::Length(6), // DKMS status
                 Constraint::Min(7),    // Diagnostics
@@ -2481,12 +3827,15 @@ impl TuiApp {
         // Driver Info section
         let mut driver_lines = vec![];
         if let Some(ref caps) = self.driver_capabilities {
-            let driver_type = if GspManager::is_nvidia_open() {
+            let driver_type = if self.kernel_module.as_ref().is_some_and(|m| m.is_open()) {
                 "nvidia-open"
             } else {
                 "proprietary"
             };
             driver_lines.push(format!("Driver:     {} ({})", caps.version, driver_type));
+            if let Some(module) = &self.kernel_module {
+                driver_lines.push(format!("Module:     {}", module.summary()));
+            }
             driver_lines.push(format!(
                 "Major:      {}{}",
                 caps.major_version,
@@ -3152,14 +4501,34 @@ impl TuiApp {
     }
 
     fn draw_settings_tab(&self, f: &mut Frame, area: Rect) {
-        let lines = [
+        let mut lines = vec![
             format!("Theme: {}", self.current_theme.name()),
             format!("Update Interval: {}ms", self.update_interval.as_millis()),
             format!("Show Settings Overlay: {}", self.show_settings),
             String::new(),
             "Press 't' to cycle themes".to_string(),
             "Press 's' to toggle settings overlay".to_string(),
+            String::new(),
+            "Visible tabs (↑/↓ select, Enter toggle):".to_string(),
         ];
+        for (i, tab) in Tab::ALL.iter().enumerate() {
+            let cursor = if i == self.settings_tab_cursor {
+                '>'
+            } else {
+                ' '
+            };
+            let check = if self.visible_tabs.contains(tab) {
+                'x'
+            } else {
+                ' '
+            };
+            let note = if *tab == Tab::Settings {
+                " (always shown)"
+            } else {
+                ""
+            };
+            lines.push(format!("{cursor} [{check}] {}{note}", tab.title()));
+        }
 
         let text = lines.join("\n");
         let para = Paragraph::new(text);
@@ -3178,9 +4547,10 @@ impl TuiApp {
                         lines.push(format!("GPU Utilization: {}%", m.gpu_utilization as u32));
                     }
                     Tab::Memory => {
+                        lines.push(format!("VRAM Used: {}%", m.vram_used_percent as u32));
                         lines.push(format!(
-                            "Memory Utilization: {}%",
-                            m.memory_utilization as u32
+                            "Memory Controller: {}%",
+                            m.memory_controller_utilization as u32
                         ));
                     }
                     Tab::Temperature => {
@@ -3243,9 +4613,33 @@ impl TuiApp {
         let accent = self.theme.teal.to_ratatui();
         let fg = self.theme.fg.to_ratatui();
 
-        // Center popup
-        let popup_width = 60.min(area.width - 4);
-        let popup_height = 28.min(area.height - 4);
+        let mut help_lines = vec![String::new(), " Keyboard Shortcuts".to_string()];
+        help_lines.push(String::new());
+        help_lines.extend(self.keymap.help_lines());
+        help_lines.extend(
+            [
+                "",
+                " Fixed keys:",
+                "   Ctrl+C         Quit",
+                "   1-9            Jump to tab",
+                "   Enter          Apply edits / expand process group",
+                "   Esc            Clear filter",
+                "   Mouse wheel    Scroll process list",
+                "",
+                " Remap keys in the [keybindings] section of config.toml",
+                "",
+                " Commands:",
+                "   doctors        nvctl driver dkms doctor | nvctl driver source doctor",
+                "   cuda           nvctl cuda doctor | nvctl ai workloads",
+                "   profiles       nvctl config diff --current live --target <profile>",
+            ]
+            .map(String::from),
+        );
+        let help_text = help_lines.join("\n");
+
+        // Center popup, as tall as the bindings need
+        let popup_width = 72.min(area.width.saturating_sub(4));
+        let popup_height = (help_lines.len() as u16 + 2).min(area.height.saturating_sub(2));
         let popup_x = (area.width - popup_width) / 2;
         let popup_y = (area.height - popup_height) / 2;
         let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
@@ -3253,51 +4647,6 @@ impl TuiApp {
         // Clear background
         f.render_widget(Clear, popup_area);
 
-        let help_text = r#"
- Keyboard Shortcuts
-
- Global:
-   q, Ctrl+C    Quit
-   ?/F1         Toggle help
-   t            Cycle theme
-
- Navigation:
-   Tab          Next tab/GPU
-   Shift+Tab    Previous tab/GPU
-   1-9          Jump to tab
-   m            Main menu
-   n            Nvtop view
-   d            Dashboard view
-
-  Controls:
-    Space/p      Pause updates
-
-  Drivers tab:
-   b            Create support bundle
-   x            Show support workflow hint
-    doctors      nvctl driver dkms doctor | nvctl driver source doctor
-    runtime      nvctl container runtime doctor --runtime docker
-
-  CUDA/AI tab:
-   r            Refresh CUDA/Ollama diagnostics now
-   read-only    CUDA, Ollama, container runtime, and VRAM fit
-   commands     nvctl cuda doctor | nvctl cuda ollama | nvctl ai workloads
-
-  Process Table (nvtop/Processes tab):
-   j/↓          Select next process
-   k/↑          Select previous process
-   Mouse wheel  Scroll process list
-   f or /       Filter processes
-   s            Sort by column
-   Esc          Clear filter
-   g            Toggle graphs
-
-  Profiles:
-    preview      nvctl config preview --input live
-    diff         nvctl config diff --current live --target <profile>
-    apply        nvctl config apply --input <profile>
-"#;
-
         let help = Paragraph::new(help_text)
             .block(
                 Block::default()
@@ -3335,3 +4684,166 @@ pub fn launch_dashboard() -> NvResult<()> {
     let mut app = TuiApp::with_view(ViewMode::Dashboard);
     app.run()
 }
+
+/// Index and value of the GPU whose latest sample is highest on `metric`;
+/// the lower index wins a tie
+fn gpu_with_highest(
+    history: &[VecDeque<GpuMetrics>],
+    metric: fn(&GpuMetrics) -> f64,
+) -> Option<(usize, f64)> {
+    history
+        .iter()
+        .enumerate()
+        .filter_map(|(index, samples)| samples.back().map(|latest| (index, metric(latest))))
+        .fold(None, |best, (index, value)| match best {
+            Some((_, best_value)) if best_value >= value => best,
+            _ => Some((index, value)),
+        })
+}
+
+/// Column of a `width`-cell gauge bar at `fraction` of full scale; `None`
+/// when the threshold is off the gauge
+fn marker_column(width: u16, fraction: f64) -> Option<u16> {
+    if width == 0 || !(0.0..=1.0).contains(&fraction) {
+        return None;
+    }
+    Some(((fraction * width as f64) as u16).min(width - 1))
+}
+
+/// Block title with the data's range, e.g. " Temp History [45–82°C] "
+fn sparkline_title(label: &str, data: &[u64], unit: &str) -> String {
+    match (data.iter().min(), data.iter().max()) {
+        (Some(min), Some(max)) => format!(" {} [{}–{}{}] ", label, min, max, unit),
+        _ => format!(" {} ", label),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_title_range() {
+        assert_eq!(
+            sparkline_title("Temp History", &[60, 45, 82], "°C"),
+            " Temp History [45–82°C] "
+        );
+        assert_eq!(
+            sparkline_title("Power History", &[], " W"),
+            " Power History "
+        );
+    }
+
+    #[test]
+    fn test_marker_column_scales_to_gauge_width() {
+        // 83°C on a 0–100°C gauge 50 cells wide
+        assert_eq!(marker_column(50, 0.83), Some(41));
+        assert_eq!(marker_column(50, 1.0), Some(49));
+        assert_eq!(marker_column(50, 0.0), Some(0));
+        assert_eq!(marker_column(50, 1.2), None);
+        assert_eq!(marker_column(0, 0.5), None);
+    }
+
+    #[test]
+    fn test_gpu_with_highest_uses_latest_sample() {
+        let sample = |temperature: f64, gpu_utilization: f64| GpuMetrics {
+            timestamp: Instant::now(),
+            temperature,
+            gpu_utilization,
+            memory_controller_utilization: 0.0,
+            vram_used_percent: 0.0,
+            power_draw: 0.0,
+            fan_speed: 0.0,
+            fan_rpm: None,
+            gpu_clock: 0.0,
+            memory_clock: 0.0,
+        };
+        let history = vec![
+            // Was hottest, has since cooled
+            VecDeque::from([sample(90.0, 10.0), sample(50.0, 10.0)]),
+            VecDeque::from([sample(60.0, 99.0), sample(72.0, 99.0)]),
+            VecDeque::new(),
+            VecDeque::from([sample(72.0, 40.0)]),
+        ];
+        assert_eq!(
+            gpu_with_highest(&history, |m| m.temperature),
+            Some((1, 72.0))
+        );
+        assert_eq!(
+            gpu_with_highest(&history, |m| m.gpu_utilization),
+            Some((1, 99.0))
+        );
+        assert_eq!(gpu_with_highest(&[], |m| m.temperature), None);
+    }
+
+    #[test]
+    fn test_process_tree_grouping() {
+        // 1 <- 900 steam <- 1000 reaper <- 1001 proton <- {1002 game, 1003 helper}
+        let procs: std::collections::HashMap<u32, (&str, u32)> = [
+            (900, ("steam", 1)),
+            (1000, ("reaper", 900)),
+            (1001, ("python3", 1000)),
+            (1002, ("game.exe", 1001)),
+            (1003, ("wine64", 1001)),
+            (2000, ("firefox", 1)),
+            (3000, ("orphan", 4242)),
+        ]
+        .into();
+        let stat = |pid| procs.get(&pid).map(|(c, p)| (c.to_string(), *p));
+        assert_eq!(process_group_root(1002, stat), 1001);
+        assert_eq!(process_group_root(2000, stat), 2000);
+        // Parent 4242 has exited: stays at top level
+        assert_eq!(process_group_root(3000, stat), 3000);
+
+        let entry = |pid, vram_mb| ProcessEntry {
+            pid,
+            name: procs[&pid].0.to_string(),
+            process_type: ProcessType::Graphics,
+            vram_mb,
+            depth: 0,
+            children: 0,
+            expanded: false,
+        };
+        let entries = vec![entry(1002, 3000), entry(2000, 500), entry(1003, 200)];
+        let root_of = |pid| process_group_root(pid, stat);
+
+        let collapsed = group_process_tree(
+            entries.clone(),
+            root_of,
+            SortColumn::VramDesc,
+            &HashSet::new(),
+        );
+        assert_eq!(collapsed.len(), 2);
+        assert_eq!(collapsed[0].pid, 1001);
+        assert_eq!(collapsed[0].vram_mb, 3200);
+        assert_eq!(collapsed[0].label(), "▸ game.exe (2 procs)");
+        assert_eq!(collapsed[1].label(), "firefox");
+
+        let expanded = group_process_tree(
+            entries,
+            root_of,
+            SortColumn::VramAsc,
+            &HashSet::from([1001]),
+        );
+        let pids: Vec<u32> = expanded.iter().map(|e| e.pid).collect();
+        assert_eq!(pids, vec![2000, 1001, 1002, 1003]);
+        assert_eq!(expanded[2].depth, 1);
+    }
+
+    #[test]
+    fn test_visible_tabs_keep_settings() {
+        assert_eq!(Tab::count(&Tab::visible(&[])), Tab::ALL.len());
+
+        let visible = Tab::visible(&[
+            "fan-control".to_string(),
+            "Overview".to_string(),
+            "not-a-tab".to_string(),
+        ]);
+        assert_eq!(visible, vec![Tab::Overview, Tab::FanControl, Tab::Settings]);
+        assert_eq!(
+            Tab::titles(&visible),
+            vec!["Overview", "Fan Control", "Settings"]
+        );
+        assert_eq!(Tab::from_index(&visible, 1), Tab::FanControl);
+    }
+}
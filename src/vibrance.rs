@@ -249,6 +249,7 @@ fn extract_vibrance_from_line(line: &str) -> Option<i32> {
 
 /// Set vibrance for specific displays
 pub fn set_vibrance(display_values: &[(usize, i32)]) -> NvResult<()> {
+    cancel_vibrance_fade();
     let nvibrant_path = get_nvibrant_path();
 
     // Find the maximum display index to know how many args to pass
@@ -270,10 +271,8 @@ pub fn set_vibrance(display_values: &[(usize, i32)]) -> NvResult<()> {
     // Convert to strings
     let str_args: Vec<String> = args.iter().map(|v| v.to_string()).collect();
 
-    let output = Command::new(&nvibrant_path)
-        .args(&str_args)
-        .output()
-        .map_err(|e| {
+    let output =
+        crate::gpu_safe::execute(Command::new(&nvibrant_path).args(&str_args)).map_err(|e| {
             NvControlError::VibranceControlFailed(format!("Failed to set vibrance: {}", e))
         })?;
 
@@ -285,7 +284,7 @@ pub fn set_vibrance(display_values: &[(usize, i32)]) -> NvResult<()> {
         )));
     }
 
-    println!("Vibrance applied successfully");
+    log::info!("Vibrance applied successfully");
     Ok(())
 }
 
@@ -343,6 +342,366 @@ pub fn get_display_name(display_id: usize) -> NvResult<String> {
     }
 }
 
+/// Named vibrance levels shared by the CLI and GUI: (name, percent, description)
+pub const VIBRANCE_PRESETS: &[(&str, u32, &str)] = &[
+    ("default", 100, "Stock saturation"),
+    ("vibrant", 150, "Enhanced colors for gaming"),
+    ("competitive", 175, "High saturation for spotting targets"),
+    ("max", 200, "Maximum saturation"),
+    ("muted", 50, "Reduced saturation"),
+    ("grayscale", 0, "No color"),
+];
+
+/// Percentage for a preset name (case-insensitive)
+pub fn preset_percentage(name: &str) -> Option<u32> {
+    VIBRANCE_PRESETS
+        .iter()
+        .find(|(preset, _, _)| preset.eq_ignore_ascii_case(name))
+        .map(|(_, percent, _)| *percent)
+}
+
+/// Which implementation handled a vibrance request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VibranceBackend {
+    /// NVKMS ioctls on /dev/nvidia-modeset
+    Native,
+    /// The bundled or system nvibrant binary
+    Nvibrant,
+}
+
+impl VibranceBackend {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Native => "native NVKMS",
+            Self::Nvibrant => "nvibrant",
+        }
+    }
+}
+
+/// Which displays a vibrance change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayTarget {
+    All,
+    Index(usize),
+}
+
+impl std::str::FromStr for DisplayTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+        s.parse()
+            .map(Self::Index)
+            .map_err(|_| format!("'{}' is not a display index or 'all'", s))
+    }
+}
+
+/// Set vibrance as a percentage (0-200, 100 = stock), preferring the native
+/// NVKMS controller and falling back to nvibrant.
+pub fn set_vibrance_percent(target: DisplayTarget, percentage: u32) -> NvResult<VibranceBackend> {
+    let backend = apply_vibrance_percent(target, percentage)?;
+    record_vibrance(target, percentage.min(200));
+    Ok(backend)
+}
+
+/// [`set_vibrance_percent`] without remembering the value for `apply-saved`
+fn apply_vibrance_percent(target: DisplayTarget, percentage: u32) -> NvResult<VibranceBackend> {
+    let percentage = percentage.min(200);
+    let native = match target {
+        DisplayTarget::All => crate::vibrance_native::set_vibrance_all_native(percentage),
+        DisplayTarget::Index(display) => {
+            crate::vibrance_native::set_display_vibrance_native(0, display as u32, percentage)
+        }
+    };
+    let native_err = match native {
+        Ok(()) => return Ok(VibranceBackend::Native),
+        Err(e) => e,
+    };
+
+    let value = percentage_to_vibrance(percentage);
+    let fallback = match target {
+        DisplayTarget::All => set_vibrance_all(value),
+        DisplayTarget::Index(display) => set_display_vibrance(display, value),
+    };
+    fallback.map(|()| VibranceBackend::Nvibrant).map_err(|e| {
+        NvControlError::VibranceControlFailed(format!("native: {}; nvibrant: {}", native_err, e))
+    })
+}
+
+/// Remember a vibrance change for `nvctl apply-saved`
+fn record_vibrance(target: DisplayTarget, percentage: u32) {
+    let display = match target {
+        DisplayTarget::All => None,
+        DisplayTarget::Index(display) => Some(display),
+    };
+    crate::state::LastApplied::update(|state| state.record_vibrance(display, percentage));
+}
+
+/// Interval between steps of a smooth vibrance transition
+const FADE_STEP: Duration = Duration::from_millis(25);
+
+/// Percentages for each step of a fade from `start` to `target`, eased in
+/// and out and always ending exactly on `target`
+fn fade_steps(start: u32, target: u32, steps: u32) -> Vec<u32> {
+    let steps = steps.max(1);
+    (1..=steps)
+        .map(|step| {
+            let t = step as f32 / steps as f32;
+            let eased = t * t * (3.0 - 2.0 * t);
+            (start as f32 + (target as f32 - start as f32) * eased).round() as u32
+        })
+        .collect()
+}
+
+/// A vibrance transition running on a background thread
+pub struct VibranceFade {
+    handle: std::thread::JoinHandle<NvResult<VibranceBackend>>,
+}
+
+impl VibranceFade {
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Wait for the fade to end. A fade superseded by another change ends
+    /// early and still counts as success.
+    pub fn join(self) -> NvResult<VibranceBackend> {
+        self.handle.join().unwrap_or_else(|_| {
+            Err(NvControlError::VibranceControlFailed(
+                "Vibrance fade thread panicked".to_string(),
+            ))
+        })
+    }
+}
+
+/// Stop a running fade where it is
+pub fn cancel_vibrance_fade() {
+    crate::vibrance_native::next_vibrance_generation();
+}
+
+/// Fade displays from their current vibrance to `targets` (display index,
+/// percent) over `duration_ms` without blocking the caller. Any vibrance
+/// change made meanwhile, including another fade, cancels this one. Without
+/// the native controller the targets are set instantly through nvibrant.
+pub fn set_vibrance_smooth(targets: &[(usize, u32)], duration_ms: u64) -> VibranceFade {
+    fade_vibrance(targets, duration_ms, true)
+}
+
+/// Fade to `targets`, remembering them for `apply-saved` only if `record`
+fn fade_vibrance(targets: &[(usize, u32)], duration_ms: u64, record: bool) -> VibranceFade {
+    let generation = crate::vibrance_native::next_vibrance_generation();
+    let targets: Vec<(usize, u32)> = targets.iter().map(|&(d, p)| (d, p.min(200))).collect();
+
+    let handle = std::thread::spawn(move || {
+        let set_instantly = |targets: &[(usize, u32)]| {
+            let mut backend = VibranceBackend::Native;
+            for &(display, percentage) in targets {
+                let target = DisplayTarget::Index(display);
+                backend = if record {
+                    set_vibrance_percent(target, percentage)?
+                } else {
+                    apply_vibrance_percent(target, percentage)?
+                };
+            }
+            Ok(backend)
+        };
+
+        if duration_ms == 0 || crate::gpu_safe::is_dry_run() {
+            return set_instantly(&targets);
+        }
+        let Ok(current) = crate::vibrance_native::get_display_vibrance_native() else {
+            return set_instantly(&targets);
+        };
+
+        let steps = (duration_ms / FADE_STEP.as_millis() as u64).max(1) as u32;
+        let paths: Vec<(usize, Vec<u32>)> = targets
+            .iter()
+            .map(|&(display, target)| {
+                let start = current
+                    .iter()
+                    .find(|(index, _, _)| *index as usize == display)
+                    .map(|(_, _, percent)| *percent)
+                    .unwrap_or(target);
+                (display, fade_steps(start, target, steps))
+            })
+            .collect();
+
+        for step in 0..steps as usize {
+            {
+                let mut guard = crate::vibrance_native::get_vibrance_controller()?;
+                // Other changes bump the generation before taking the lock,
+                // so checking under it means a newer value is never overwritten
+                if !crate::vibrance_native::is_current_generation(generation) {
+                    break;
+                }
+                let controller = guard.as_mut().ok_or_else(|| {
+                    NvControlError::VibranceControlFailed("Controller not initialized".to_string())
+                })?;
+                for (display, path) in &paths {
+                    let value = crate::vibrance_native::percentage_to_vibrance(path[step]);
+                    controller.set_vibrance(*display, value)?;
+                }
+            }
+            std::thread::sleep(FADE_STEP);
+        }
+        if record && crate::vibrance_native::is_current_generation(generation) {
+            for &(display, target) in &targets {
+                record_vibrance(DisplayTarget::Index(display), target);
+            }
+        }
+        Ok(VibranceBackend::Native)
+    });
+
+    VibranceFade { handle }
+}
+
+/// Load-reactive vibrance settings, off by default since they change the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoadReactiveSettings {
+    pub enabled: bool,
+    /// Vibrance percent at an idle GPU
+    pub min_pct: u32,
+    /// Vibrance percent at full GPU load
+    pub max_pct: u32,
+}
+
+impl Default for LoadReactiveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_pct: 100,
+            max_pct: 130,
+        }
+    }
+}
+
+/// Smallest change in percent worth writing to the displays
+const LOAD_REACTIVE_STEP: u32 = 4;
+
+/// Fade length for each load-driven change
+const LOAD_REACTIVE_FADE_MS: u64 = 400;
+
+struct LoadReactive {
+    min_pct: u32,
+    max_pct: u32,
+    /// Vibrance each display had before load-reactive mode took over
+    base: Vec<(usize, u32)>,
+    last_pct: Option<u32>,
+}
+
+static LOAD_REACTIVE: std::sync::Mutex<Option<LoadReactive>> = std::sync::Mutex::new(None);
+
+/// Turn load-reactive vibrance on or off. Enabling remembers each display's
+/// current vibrance; disabling puts it back. Changing the range while
+/// enabled keeps the original base.
+pub fn set_load_reactive(enabled: bool, min_pct: u32, max_pct: u32) -> NvResult<()> {
+    let mut slot = LOAD_REACTIVE.lock().unwrap_or_else(|e| e.into_inner());
+    if !enabled {
+        let Some(active) = slot.take() else {
+            return Ok(());
+        };
+        for (display, percentage) in active.base {
+            set_vibrance_percent(DisplayTarget::Index(display), percentage)?;
+        }
+        return Ok(());
+    }
+
+    let base = match slot.take() {
+        Some(active) => active.base,
+        None => get_vibrance_percent()?
+            .1
+            .into_iter()
+            .map(|(index, _, percent)| (index, percent))
+            .collect(),
+    };
+    let (low, high) = (min_pct.min(max_pct), max_pct.max(min_pct));
+    *slot = Some(LoadReactive {
+        min_pct: low.min(200),
+        max_pct: high.min(200),
+        base,
+        last_pct: None,
+    });
+    Ok(())
+}
+
+/// Whether vibrance currently follows GPU load
+pub fn is_load_reactive() -> bool {
+    LOAD_REACTIVE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_some()
+}
+
+/// Feed one GPU utilization sample from the monitoring poll. Displays are
+/// only touched when the mapped vibrance moved by a noticeable step.
+pub fn update_load_reactive(gpu_utilization: u32) {
+    let mut slot = LOAD_REACTIVE.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(active) = slot.as_mut() else {
+        return;
+    };
+    let target = load_reactive_percent(gpu_utilization, active.min_pct, active.max_pct);
+    if !load_reactive_changed(active.last_pct, target, active.min_pct, active.max_pct) {
+        return;
+    }
+    active.last_pct = Some(target);
+    let targets: Vec<(usize, u32)> = active.base.iter().map(|&(d, _)| (d, target)).collect();
+    // Transient values aren't recorded, so apply-saved keeps the base
+    drop(fade_vibrance(&targets, LOAD_REACTIVE_FADE_MS, false));
+}
+
+/// Vibrance for a utilization sample, linear between `min_pct` and `max_pct`
+fn load_reactive_percent(gpu_utilization: u32, min_pct: u32, max_pct: u32) -> u32 {
+    let load = gpu_utilization.min(100);
+    min_pct + (max_pct.saturating_sub(min_pct) * load + 50) / 100
+}
+
+/// Whether `target` differs enough from what was last set. Reaching either
+/// end of the range always counts, so idle settles exactly on `min_pct`.
+fn load_reactive_changed(last: Option<u32>, target: u32, min_pct: u32, max_pct: u32) -> bool {
+    match last {
+        None => true,
+        Some(last) if last == target => false,
+        Some(last) => {
+            last.abs_diff(target) >= LOAD_REACTIVE_STEP || target == min_pct || target == max_pct
+        }
+    }
+}
+
+/// A display's (index, name, vibrance percent)
+pub type DisplayVibrance = (usize, String, u32);
+
+/// Current vibrance of each display
+pub fn get_vibrance_percent() -> NvResult<(VibranceBackend, Vec<DisplayVibrance>)> {
+    let native_err = match crate::vibrance_native::get_display_vibrance_native() {
+        Ok(displays) => {
+            let displays = displays
+                .into_iter()
+                .map(|(index, name, percent)| (index as usize, name, percent))
+                .collect();
+            return Ok((VibranceBackend::Native, displays));
+        }
+        Err(e) => e,
+    };
+
+    let names = get_displays().map_err(|e| {
+        NvControlError::VibranceControlFailed(format!("native: {}; nvibrant: {}", native_err, e))
+    })?;
+    let displays = names
+        .into_iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let percent = get_display_vibrance(index)
+                .map(vibrance_to_percentage)
+                .unwrap_or(100);
+            (index, name, percent)
+        })
+        .collect();
+    Ok((VibranceBackend::Nvibrant, displays))
+}
+
 /// Check if vibrance control is available
 pub fn is_available() -> bool {
     let nvibrant_path = get_nvibrant_path();
@@ -466,3 +825,61 @@ pub fn preview_vibrance_changes(
     // Restore original vibrance
     set_display_vibrance(display_id, original_vibrance)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_steps_ease_to_target() {
+        let up = fade_steps(100, 200, 16);
+        assert_eq!(up.len(), 16);
+        assert_eq!(*up.last().unwrap(), 200);
+        assert!(up.windows(2).all(|w| w[0] <= w[1]));
+        // Eased: the first step moves less than a linear 1/16th would
+        assert!(up[0] - 100 < 100 / 16);
+
+        let down = fade_steps(150, 50, 4);
+        assert_eq!(*down.last().unwrap(), 50);
+        assert!(down.windows(2).all(|w| w[0] >= w[1]));
+
+        assert_eq!(fade_steps(120, 120, 0), vec![120]);
+    }
+
+    #[test]
+    fn test_new_change_supersedes_fade() {
+        let fade = crate::vibrance_native::next_vibrance_generation();
+        cancel_vibrance_fade();
+        assert!(!crate::vibrance_native::is_current_generation(fade));
+    }
+
+    #[test]
+    fn test_presets_and_display_target() {
+        assert_eq!(preset_percentage("Competitive"), Some(175));
+        assert_eq!(preset_percentage("default"), Some(100));
+        assert_eq!(preset_percentage("neon"), None);
+
+        assert_eq!("all".parse::<DisplayTarget>(), Ok(DisplayTarget::All));
+        assert_eq!("2".parse::<DisplayTarget>(), Ok(DisplayTarget::Index(2)));
+        assert!("left".parse::<DisplayTarget>().is_err());
+    }
+
+    #[test]
+    fn test_load_reactive_maps_load_and_skips_small_changes() {
+        assert_eq!(load_reactive_percent(0, 100, 130), 100);
+        assert_eq!(load_reactive_percent(50, 100, 130), 115);
+        assert_eq!(load_reactive_percent(250, 100, 130), 130);
+
+        assert!(load_reactive_changed(None, 100, 100, 130));
+        assert!(!load_reactive_changed(Some(115), 115, 100, 130));
+        assert!(!load_reactive_changed(Some(115), 117, 100, 130));
+        assert!(load_reactive_changed(Some(115), 120, 100, 130));
+        // Small moves onto either end still land
+        assert!(load_reactive_changed(Some(102), 100, 100, 130));
+        assert!(load_reactive_changed(Some(128), 130, 100, 130));
+
+        // Disabling when never enabled is a no-op
+        assert!(set_load_reactive(false, 100, 130).is_ok());
+        assert!(!is_load_reactive());
+    }
+}
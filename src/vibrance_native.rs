@@ -327,6 +327,44 @@ impl NativeVibranceController {
         Ok(())
     }
 
+    /// Read the driver's current vibrance for a connector
+    pub fn read_vibrance(&mut self, connector_index: usize) -> NvResult<i64> {
+        let connector = self.connectors.get(connector_index).ok_or_else(|| {
+            NvControlError::VibranceControlFailed(format!(
+                "Connector {} not found",
+                connector_index
+            ))
+        })?;
+
+        let mut params = NvKmsGetDpyAttributeParams {
+            request: NvKmsGetDpyAttributeRequest {
+                device_handle: self.device_handle,
+                disp_handle: self.disp_handle,
+                dpy_id: connector.dpy_id,
+                attribute: NvKmsDpyAttribute::DigitalVibrance,
+            },
+            reply: Zeroable::zeroed(),
+        };
+
+        // SAFETY: modeset_fd is valid for the controller's lifetime, params matches GetDpyAttribute
+        unsafe {
+            nvkms_ioctl(
+                self.modeset_fd,
+                NvKmsIoctlCommand::GetDpyAttribute,
+                &mut params,
+            )
+            .map_err(|e| {
+                NvControlError::VibranceControlFailed(format!("Failed to read vibrance: {}", e))
+            })?;
+        }
+
+        let value = params.reply.value;
+        if let Some(conn) = self.connectors.get_mut(connector_index) {
+            conn.current_vibrance = value;
+        }
+        Ok(value)
+    }
+
     /// Set vibrance for all connected displays
     pub fn set_vibrance_all(&mut self, vibrance_percentage: u32) -> NvResult<()> {
         let vibrance_value = percentage_to_vibrance(vibrance_percentage);
@@ -344,9 +382,10 @@ impl NativeVibranceController {
         }
 
         if success_count > 0 {
-            println!(
-                "✅ Set {} displays to {}% vibrance",
-                success_count, vibrance_percentage
+            log::info!(
+                "Set {} displays to {}% vibrance",
+                success_count,
+                vibrance_percentage
             );
             Ok(())
         } else if let Some(e) = last_error {
@@ -454,6 +493,20 @@ use std::sync::Mutex;
 
 static VIBRANCE_CONTROLLER: Mutex<Option<NativeVibranceController>> = Mutex::new(None);
 
+/// Bumped by every vibrance change so a fade in progress stops at its next step
+static VIBRANCE_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Start a new vibrance change, superseding any running fade. Returns the
+/// generation the caller owns.
+pub fn next_vibrance_generation() -> u64 {
+    VIBRANCE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1
+}
+
+/// Whether no vibrance change has been made since `generation` was taken
+pub fn is_current_generation(generation: u64) -> bool {
+    VIBRANCE_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation
+}
+
 /// Get or initialize the vibrance controller
 pub fn get_vibrance_controller()
 -> NvResult<std::sync::MutexGuard<'static, Option<NativeVibranceController>>> {
@@ -471,6 +524,13 @@ pub fn get_vibrance_controller()
 // ===== CLI interface functions =====
 
 pub fn set_vibrance_all_native(percentage: u32) -> NvResult<()> {
+    next_vibrance_generation();
+    if !crate::gpu_safe::should_apply(&format!(
+        "set digital vibrance to {}% on all displays",
+        percentage
+    )) {
+        return Ok(());
+    }
     let mut guard = get_vibrance_controller()?;
     let controller = guard.as_mut().ok_or_else(|| {
         NvControlError::VibranceControlFailed("Controller not initialized".to_string())
@@ -484,6 +544,13 @@ pub fn set_display_vibrance_native(
     percentage: u32,
 ) -> NvResult<()> {
     let _ = device_id; // Ignored for now, single GPU support
+    next_vibrance_generation();
+    if !crate::gpu_safe::should_apply(&format!(
+        "set digital vibrance to {}% on display {}",
+        percentage, display_id
+    )) {
+        return Ok(());
+    }
     let mut guard = get_vibrance_controller()?;
     let controller = guard.as_mut().ok_or_else(|| {
         NvControlError::VibranceControlFailed("Controller not initialized".to_string())
@@ -492,6 +559,29 @@ pub fn set_display_vibrance_native(
     controller.set_vibrance(display_id as usize, vibrance_value)
 }
 
+/// Current vibrance percentage of each connected display as (connector, name, %)
+pub fn get_display_vibrance_native() -> NvResult<Vec<(u32, String, u32)>> {
+    let mut guard = get_vibrance_controller()?;
+    let controller = guard.as_mut().ok_or_else(|| {
+        NvControlError::VibranceControlFailed("Controller not initialized".to_string())
+    })?;
+
+    let connected: Vec<(usize, String)> = controller
+        .connectors
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.connected)
+        .map(|(i, c)| (i, c.connector_type.clone()))
+        .collect();
+    connected
+        .into_iter()
+        .map(|(i, name)| {
+            let value = controller.read_vibrance(i)?;
+            Ok((i as u32, name, vibrance_to_percentage(value)))
+        })
+        .collect()
+}
+
 pub fn get_vibrance_status_native() -> NvResult<HashMap<String, serde_json::Value>> {
     let guard = get_vibrance_controller()?;
     let controller = guard.as_ref().ok_or_else(|| {
@@ -509,6 +599,7 @@ pub fn list_displays_native() -> NvResult<Vec<(u32, u32, String, bool)>> {
 }
 
 pub fn reset_vibrance_native() -> NvResult<()> {
+    next_vibrance_generation();
     let mut guard = get_vibrance_controller()?;
     let controller = guard.as_mut().ok_or_else(|| {
         NvControlError::VibranceControlFailed("Controller not initialized".to_string())
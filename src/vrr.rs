@@ -1,4 +1,5 @@
 use crate::display_backend::SharedDisplayRunner;
+use crate::wayland_nvidia::WaylandCompositor;
 use crate::{NvControlError, NvResult};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -367,28 +368,152 @@ pub fn apply_vrr_settings(display_name: &str, settings: &VrrSettings) -> NvResul
     apply_vrr_settings_with_backend(display_name, settings, &runner)
 }
 
-/// Apply VRR settings using the provided display backend
+/// Apply VRR settings using the provided display backend.
+///
+/// The compositor decides the mechanism: kscreen-doctor on KDE, mutter
+/// settings on GNOME, hyprctl/swaymsg on Hyprland and Sway, and wlr-randr
+/// (wlr-output-management) on other wlroots compositors. The state is read
+/// back afterwards so a change the compositor silently ignored is reported.
 pub fn apply_vrr_settings_with_backend(
     display_name: &str,
     settings: &VrrSettings,
     runner: &SharedDisplayRunner,
 ) -> NvResult<()> {
-    let desktop = std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+    let compositor = crate::wayland_nvidia::detect_compositor();
+    apply_vrr_for_compositor(&compositor, display_name, settings, runner)
+}
+
+fn apply_vrr_for_compositor(
+    compositor: &WaylandCompositor,
+    display_name: &str,
+    settings: &VrrSettings,
+    runner: &SharedDisplayRunner,
+) -> NvResult<()> {
+    let live = match compositor {
+        WaylandCompositor::Kde => apply_vrr_kde_with_backend(display_name, settings, runner)?,
+        WaylandCompositor::Gnome => {
+            apply_vrr_gnome_with_backend(settings, runner)?;
+            true
+        }
+        WaylandCompositor::Hyprland => {
+            apply_vrr_hyprland_with_backend(display_name, settings, runner)?;
+            true
+        }
+        WaylandCompositor::Sway => {
+            apply_vrr_sway_with_backend(display_name, settings, runner)?;
+            true
+        }
+        WaylandCompositor::Wlroots(_) => {
+            apply_vrr_wlr_with_backend(display_name, settings, runner)?;
+            true
+        }
+        WaylandCompositor::Other(_) => {
+            return Err(NvControlError::UnsupportedFeature(format!(
+                "{} has no known interface for setting VRR programmatically. Set it manually in {}",
+                compositor.name(),
+                compositor.manual_vrr_setting()
+            )));
+        }
+        WaylandCompositor::X11 => {
+            apply_vrr_x11_with_backend(display_name, settings, runner)?;
+            false
+        }
+    };
+
+    if !live {
+        return Ok(());
+    }
+
+    match query_vrr_enabled(compositor, display_name, runner) {
+        Some(enabled) if enabled == settings.enabled => Ok(()),
+        Some(_) => Err(NvControlError::DisplayDetectionFailed(format!(
+            "{} accepted the VRR change for {} but still reports it {}. Set it manually in {}",
+            compositor.name(),
+            display_name,
+            if settings.enabled {
+                "disabled"
+            } else {
+                "enabled"
+            },
+            compositor.manual_vrr_setting()
+        ))),
+        None => {
+            println!(
+                "Could not read back VRR state for {}; confirm in {}",
+                display_name,
+                compositor.manual_vrr_setting()
+            );
+            Ok(())
+        }
+    }
+}
 
-    match desktop.as_str() {
-        "KDE" => apply_vrr_kde_with_backend(display_name, settings, runner),
-        "GNOME" => apply_vrr_gnome_with_backend(settings, runner),
-        "Hyprland" => apply_vrr_hyprland_with_backend(display_name, settings, runner),
-        "sway" => apply_vrr_sway_with_backend(display_name, settings, runner),
-        _ => apply_vrr_x11_with_backend(display_name, settings, runner),
+/// Re-query whether VRR is on for `display_name`, or `None` if the
+/// compositor can't tell us
+fn query_vrr_enabled(
+    compositor: &WaylandCompositor,
+    display_name: &str,
+    runner: &SharedDisplayRunner,
+) -> Option<bool> {
+    let find_output = |json: &str, list_key: Option<&str>| -> Option<serde_json::Value> {
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        let outputs = match list_key {
+            Some(key) => value.get(key)?.as_array()?.clone(),
+            None => value.as_array()?.clone(),
+        };
+        outputs
+            .into_iter()
+            .find(|output| output.get("name").and_then(|n| n.as_str()) == Some(display_name))
+    };
+
+    match compositor {
+        WaylandCompositor::Kde => {
+            let json = runner.run_command("kscreen-doctor", &["-j"]).ok()?;
+            let output = find_output(&json, Some("outputs"))?;
+            Some(output.get("vrrPolicy")?.as_i64()? > 0)
+        }
+        WaylandCompositor::Gnome => {
+            let features = runner
+                .run_command(
+                    "gsettings",
+                    &["get", "org.gnome.mutter", "experimental-features"],
+                )
+                .ok()?;
+            Some(features.contains("variable-refresh-rate"))
+        }
+        WaylandCompositor::Hyprland => {
+            let json = runner.run_command("hyprctl", &["monitors", "-j"]).ok()?;
+            match find_output(&json, None)?.get("vrr")? {
+                serde_json::Value::Bool(b) => Some(*b),
+                serde_json::Value::Number(n) => Some(n.as_i64()? > 0),
+                _ => None,
+            }
+        }
+        WaylandCompositor::Sway => {
+            let json = runner
+                .run_command("swaymsg", &["-t", "get_outputs", "-r"])
+                .ok()?;
+            let status = find_output(&json, None)?
+                .get("adaptive_sync_status")?
+                .as_str()?
+                .to_string();
+            Some(status == "enabled")
+        }
+        WaylandCompositor::Wlroots(_) => {
+            let json = runner.run_command("wlr-randr", &["--json"]).ok()?;
+            find_output(&json, None)?.get("adaptive_sync")?.as_bool()
+        }
+        WaylandCompositor::Other(_) | WaylandCompositor::X11 => None,
     }
 }
 
+/// Returns whether the change was made live (kscreen-doctor) rather than
+/// written to the kscreen config for the next session
 fn apply_vrr_kde_with_backend(
     display_name: &str,
     settings: &VrrSettings,
     runner: &SharedDisplayRunner,
-) -> NvResult<()> {
+) -> NvResult<bool> {
     // VRR policy values: 0 = Never, 1 = Always, 2 = Automatic
     let vrr_policy = if settings.enabled {
         if settings.adaptive_sync { "2" } else { "1" }
@@ -407,11 +532,13 @@ fn apply_vrr_kde_with_backend(
                 _ => "unknown",
             };
             println!("VRR {} for display {}", vrr_state, display_name);
-            Ok(())
+            Ok(true)
         }
         Err(_) => {
             // If kscreen-doctor fails, try direct config file modification
-            apply_vrr_kde_config(display_name, settings)
+            apply_vrr_kde_config(display_name, settings)?;
+            println!("kscreen config updated; the change applies on next login");
+            Ok(false)
         }
     }
 }
@@ -564,6 +691,29 @@ fn apply_vrr_sway_with_backend(
     Ok(())
 }
 
+/// Other wlroots compositors: wlr-randr speaks wlr-output-management
+fn apply_vrr_wlr_with_backend(
+    display_name: &str,
+    settings: &VrrSettings,
+    runner: &SharedDisplayRunner,
+) -> NvResult<()> {
+    let adaptive_sync = if settings.enabled {
+        "enabled"
+    } else {
+        "disabled"
+    };
+
+    runner
+        .run_command(
+            "wlr-randr",
+            &["--output", display_name, "--adaptive-sync", adaptive_sync],
+        )
+        .map_err(|e| NvControlError::DisplayDetectionFailed(format!("wlr-randr failed: {e}")))?;
+
+    println!("Adaptive sync {} for {}", adaptive_sync, display_name);
+    Ok(())
+}
+
 fn apply_vrr_x11_with_backend(
     display_name: &str,
     settings: &VrrSettings,
@@ -613,6 +763,369 @@ fn apply_vrr_nvidia_settings_with_backend(
     Ok(())
 }
 
+// ==================== Live Refresh Rate ====================
+
+/// Where a refresh reading came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshSource {
+    /// Measured from the DRM vblank counter, so VRR shows up as a lower rate
+    Vblank,
+    /// The mode's nominal rate from RandR/wlr-randr; VRR can't be seen
+    Mode,
+}
+
+/// Refresh rate a connector is being driven at right now
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurrentRefresh {
+    pub hz: f32,
+    /// Rate of the mode that is set; VRR runs below it
+    pub nominal_hz: Option<f32>,
+    pub source: RefreshSource,
+}
+
+/// Whether variable refresh is engaging, judged from one reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrrActivity {
+    /// Refreshing inside the VRR window, below the mode's rate
+    Active,
+    /// Pinned at the mode's rate
+    Idle,
+    /// Only the nominal rate is known
+    Unknown,
+}
+
+impl VrrActivity {
+    pub fn classify(refresh: &CurrentRefresh, min_hz: u32, max_hz: u32) -> Self {
+        let Some(nominal) = refresh
+            .nominal_hz
+            .filter(|_| refresh.source == RefreshSource::Vblank)
+        else {
+            return Self::Unknown;
+        };
+        // Allow for measurement jitter before calling it variable
+        let below_nominal = nominal - refresh.hz > (nominal * 0.03).max(2.0);
+        let in_window = refresh.hz >= min_hz as f32 * 0.9 && refresh.hz <= max_hz as f32 + 2.0;
+        if below_nominal && in_window {
+            Self::Active
+        } else {
+            Self::Idle
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Active => "VRR active",
+            Self::Idle => "VRR idle/disabled",
+            Self::Unknown => "VRR state unknown",
+        }
+    }
+}
+
+/// How long vblanks are counted for one reading
+const REFRESH_SAMPLE_WINDOW: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Read the refresh rate a connector is running at. With DRM modesetting
+/// the vblank counter is sampled over a short window, which follows VRR;
+/// otherwise the current mode's nominal rate from RandR is returned.
+pub fn get_current_refresh(connector: &str) -> NvResult<CurrentRefresh> {
+    if let Ok(refresh) = drm_refresh::measure(connector, REFRESH_SAMPLE_WINDOW) {
+        return Ok(refresh);
+    }
+
+    // X11 output names don't always match DRM's ("DP-0" vs "DP-1")
+    let listing = crate::display_info::list_connected_displays();
+    let display = listing
+        .displays
+        .iter()
+        .find(|d| d.connector == connector || d.drm_connector.as_deref() == Some(connector))
+        .ok_or_else(|| {
+            NvControlError::DisplayDetectionFailed(format!("No connected display on {}", connector))
+        })?;
+    if let Some(drm) = display
+        .drm_connector
+        .as_deref()
+        .filter(|drm| *drm != connector)
+        && let Ok(refresh) = drm_refresh::measure(drm, REFRESH_SAMPLE_WINDOW)
+    {
+        return Ok(refresh);
+    }
+
+    let mode = display.current_mode.as_ref().ok_or_else(|| {
+        NvControlError::DisplayDetectionFailed(format!("No active mode on {}", connector))
+    })?;
+    Ok(CurrentRefresh {
+        hz: mode.refresh_hz,
+        nominal_hz: Some(mode.refresh_hz),
+        source: RefreshSource::Mode,
+    })
+}
+
+/// Refresh rate from two DRM vblank counter readings
+fn refresh_from_vblanks(first: (u32, f64), second: (u32, f64)) -> Option<f32> {
+    let frames = second.0.wrapping_sub(first.0);
+    let elapsed = second.1 - first.1;
+    (frames > 0 && elapsed > 0.0).then(|| (frames as f64 / elapsed) as f32)
+}
+
+/// DRM name prefix for a `DRM_MODE_CONNECTOR_*` type
+fn drm_connector_type_name(kind: u32) -> &'static str {
+    match kind {
+        1 => "VGA",
+        2 => "DVI-I",
+        3 => "DVI-D",
+        4 => "DVI-A",
+        7 => "LVDS",
+        10 => "DP",
+        11 => "HDMI-A",
+        12 => "HDMI-B",
+        14 => "eDP",
+        15 => "Virtual",
+        16 => "DSI",
+        17 => "DPI",
+        20 => "USB",
+        _ => "Unknown",
+    }
+}
+
+/// Minimal DRM KMS queries: connector -> encoder -> CRTC, the CRTC's mode
+/// and its vblank counter. None of these need DRM master.
+mod drm_refresh {
+    use super::{CurrentRefresh, RefreshSource, drm_connector_type_name, refresh_from_vblanks};
+    use crate::{NvControlError, NvResult};
+    use std::os::fd::AsRawFd;
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ModeCardRes {
+        fb_id_ptr: u64,
+        crtc_id_ptr: u64,
+        connector_id_ptr: u64,
+        encoder_id_ptr: u64,
+        count_fbs: u32,
+        count_crtcs: u32,
+        count_connectors: u32,
+        count_encoders: u32,
+        min_width: u32,
+        max_width: u32,
+        min_height: u32,
+        max_height: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ModeGetConnector {
+        encoders_ptr: u64,
+        modes_ptr: u64,
+        props_ptr: u64,
+        prop_values_ptr: u64,
+        count_modes: u32,
+        count_props: u32,
+        count_encoders: u32,
+        encoder_id: u32,
+        connector_id: u32,
+        connector_type: u32,
+        connector_type_id: u32,
+        connection: u32,
+        mm_width: u32,
+        mm_height: u32,
+        subpixel: u32,
+        pad: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ModeGetEncoder {
+        encoder_id: u32,
+        encoder_type: u32,
+        crtc_id: u32,
+        possible_crtcs: u32,
+        possible_clones: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ModeInfo {
+        clock: u32,
+        hdisplay: u16,
+        hsync_start: u16,
+        hsync_end: u16,
+        htotal: u16,
+        hskew: u16,
+        vdisplay: u16,
+        vsync_start: u16,
+        vsync_end: u16,
+        vtotal: u16,
+        vscan: u16,
+        vrefresh: u32,
+        flags: u32,
+        kind: u32,
+        name: [u8; 32],
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct ModeCrtc {
+        set_connectors_ptr: u64,
+        count_connectors: u32,
+        crtc_id: u32,
+        fb_id: u32,
+        x: u32,
+        y: u32,
+        gamma_size: u32,
+        mode_valid: u32,
+        mode: ModeInfo,
+    }
+
+    /// `union drm_wait_vblank`, laid out as its reply
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct WaitVblank {
+        kind: u32,
+        sequence: u32,
+        tval_sec: libc::c_long,
+        tval_usec: libc::c_long,
+    }
+
+    nix::ioctl_readwrite!(wait_vblank, b'd', 0x3a, WaitVblank);
+    nix::ioctl_readwrite!(get_resources, b'd', 0xa0, ModeCardRes);
+    nix::ioctl_readwrite!(get_crtc, b'd', 0xa1, ModeCrtc);
+    nix::ioctl_readwrite!(get_encoder, b'd', 0xa6, ModeGetEncoder);
+    nix::ioctl_readwrite!(get_connector, b'd', 0xa7, ModeGetConnector);
+
+    const VBLANK_RELATIVE: u32 = 0x1;
+    const VBLANK_HIGH_CRTC_SHIFT: u32 = 1;
+    const VBLANK_HIGH_CRTC_MASK: u32 = 0x3e;
+
+    fn drm_error(what: &str, e: impl std::fmt::Display) -> NvControlError {
+        NvControlError::DisplayDetectionFailed(format!("DRM {} failed: {}", what, e))
+    }
+
+    /// The CRTC driving `connector` on one card: (index, id)
+    fn find_crtc(fd: i32, connector: &str) -> NvResult<Option<(u32, u32)>> {
+        let mut res = ModeCardRes::default();
+        // SAFETY: fd is an open DRM device and every array pointer is null
+        unsafe { get_resources(fd, &mut res) }.map_err(|e| drm_error("GETRESOURCES", e))?;
+        let mut crtcs = vec![0u32; res.count_crtcs as usize];
+        let mut connectors = vec![0u32; res.count_connectors as usize];
+        let mut res = ModeCardRes {
+            crtc_id_ptr: crtcs.as_mut_ptr() as u64,
+            connector_id_ptr: connectors.as_mut_ptr() as u64,
+            count_crtcs: crtcs.len() as u32,
+            count_connectors: connectors.len() as u32,
+            ..Default::default()
+        };
+        // SAFETY: the pointers reference vectors of the counts passed in
+        unsafe { get_resources(fd, &mut res) }.map_err(|e| drm_error("GETRESOURCES", e))?;
+        crtcs.truncate(res.count_crtcs as usize);
+        connectors.truncate(res.count_connectors as usize);
+
+        for connector_id in connectors {
+            let mut conn = ModeGetConnector {
+                connector_id,
+                ..Default::default()
+            };
+            // SAFETY: all counts are zero, so the kernel writes no arrays
+            if unsafe { get_connector(fd, &mut conn) }.is_err() {
+                continue;
+            }
+            let name = format!(
+                "{}-{}",
+                drm_connector_type_name(conn.connector_type),
+                conn.connector_type_id
+            );
+            if name != connector {
+                continue;
+            }
+            if conn.encoder_id == 0 {
+                return Ok(None);
+            }
+            let mut encoder = ModeGetEncoder {
+                encoder_id: conn.encoder_id,
+                ..Default::default()
+            };
+            // SAFETY: plain struct in/out
+            unsafe { get_encoder(fd, &mut encoder) }.map_err(|e| drm_error("GETENCODER", e))?;
+            return Ok(crtcs
+                .iter()
+                .position(|&id| id == encoder.crtc_id && id != 0)
+                .map(|index| (index as u32, encoder.crtc_id)));
+        }
+        Ok(None)
+    }
+
+    fn nominal_refresh(fd: i32, crtc_id: u32) -> Option<f32> {
+        let mut crtc = ModeCrtc {
+            crtc_id,
+            ..Default::default()
+        };
+        // SAFETY: count_connectors is zero, so no connector array is written
+        unsafe { get_crtc(fd, &mut crtc) }.ok()?;
+        let mode = &crtc.mode;
+        let pixels = mode.htotal as u64 * mode.vtotal as u64;
+        (crtc.mode_valid != 0 && pixels > 0)
+            .then(|| (mode.clock as f64 * 1000.0 / pixels as f64) as f32)
+    }
+
+    /// Latest vblank (sequence, timestamp) without waiting for the next one
+    fn vblank_now(fd: i32, crtc_index: u32) -> NvResult<(u32, f64)> {
+        let mut vbl = WaitVblank {
+            kind: VBLANK_RELATIVE
+                | ((crtc_index << VBLANK_HIGH_CRTC_SHIFT) & VBLANK_HIGH_CRTC_MASK),
+            ..Default::default()
+        };
+        // SAFETY: plain struct in/out; a relative wait of 0 returns at once
+        unsafe { wait_vblank(fd, &mut vbl) }.map_err(|e| drm_error("WAIT_VBLANK", e))?;
+        Ok((
+            vbl.sequence,
+            vbl.tval_sec as f64 + vbl.tval_usec as f64 / 1_000_000.0,
+        ))
+    }
+
+    /// Sample the vblank counter of the CRTC driving `connector`
+    pub fn measure(connector: &str, window: std::time::Duration) -> NvResult<CurrentRefresh> {
+        let mut cards: Vec<_> = std::fs::read_dir("/dev/dri")
+            .map_err(|e| drm_error("open /dev/dri", e))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("card"))
+            })
+            .collect();
+        cards.sort();
+
+        for card in cards {
+            let Ok(file) = std::fs::File::open(&card) else {
+                continue;
+            };
+            let fd = file.as_raw_fd();
+            let Ok(Some((index, crtc_id))) = find_crtc(fd, connector) else {
+                continue;
+            };
+            let first = vblank_now(fd, index)?;
+            std::thread::sleep(window);
+            let second = vblank_now(fd, index)?;
+            let hz = refresh_from_vblanks(first, second).ok_or_else(|| {
+                NvControlError::DisplayDetectionFailed(format!(
+                    "No vblanks on {} (display off?)",
+                    connector
+                ))
+            })?;
+            return Ok(CurrentRefresh {
+                hz,
+                nominal_hz: nominal_refresh(fd, crtc_id),
+                source: RefreshSource::Vblank,
+            });
+        }
+
+        Err(NvControlError::DisplayDetectionFailed(format!(
+            "No active DRM connector named {}",
+            connector
+        )))
+    }
+}
+
 pub fn get_per_app_vrr_settings() -> HashMap<String, VrrSettings> {
     // Load per-application VRR settings
     // This would typically be stored in config files
@@ -664,6 +1177,88 @@ mod tests {
         assert_eq!(settings.max_refresh_rate, 144);
     }
 
+    fn mock_runner(runner: crate::display_backend::MockDisplayRunner) -> SharedDisplayRunner {
+        std::sync::Arc::new(runner)
+    }
+
+    #[test]
+    fn test_compositor_from_desktop() {
+        assert_eq!(
+            WaylandCompositor::from_desktop("KDE"),
+            WaylandCompositor::Kde
+        );
+        assert_eq!(
+            WaylandCompositor::from_desktop("ubuntu:GNOME"),
+            WaylandCompositor::Gnome
+        );
+        assert_eq!(
+            WaylandCompositor::from_desktop("river"),
+            WaylandCompositor::Wlroots("river".to_string())
+        );
+        assert!(matches!(
+            WaylandCompositor::from_desktop("weston"),
+            WaylandCompositor::Other(_)
+        ));
+    }
+
+    #[test]
+    fn test_apply_vrr_verifies_state() {
+        let runner = mock_runner(
+            crate::display_backend::MockDisplayRunner::wayland()
+                .with_command_output("hyprctl", r#"[{"name":"DP-1","vrr":true}]"#),
+        );
+        let enable = VrrSettings {
+            enabled: true,
+            ..VrrSettings::default()
+        };
+        assert!(
+            apply_vrr_for_compositor(&WaylandCompositor::Hyprland, "DP-1", &enable, &runner)
+                .is_ok()
+        );
+
+        // Compositor still reports VRR on after a disable request
+        let disable = VrrSettings::default();
+        let err = apply_vrr_for_compositor(&WaylandCompositor::Hyprland, "DP-1", &disable, &runner)
+            .unwrap_err();
+        assert!(err.to_string().contains("hyprland.conf"));
+    }
+
+    #[test]
+    fn test_apply_vrr_unknown_compositor_names_manual_path() {
+        let runner = mock_runner(crate::display_backend::MockDisplayRunner::wayland());
+        let compositor = WaylandCompositor::Other("weston".to_string());
+        let err = apply_vrr_for_compositor(&compositor, "DP-1", &VrrSettings::default(), &runner)
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("weston"));
+        assert!(message.contains(compositor.manual_vrr_setting()));
+    }
+
+    #[test]
+    fn test_live_refresh_classification() {
+        // 33 vblanks in 0.25s
+        assert_eq!(refresh_from_vblanks((100, 10.0), (133, 10.25)), Some(132.0));
+        assert_eq!(
+            refresh_from_vblanks((u32::MAX, 10.0), (35, 10.25)),
+            Some(144.0)
+        );
+        assert_eq!(refresh_from_vblanks((100, 10.0), (100, 10.25)), None);
+        assert_eq!(drm_connector_type_name(10), "DP");
+        assert_eq!(drm_connector_type_name(11), "HDMI-A");
+
+        let reading = |hz, source| CurrentRefresh {
+            hz,
+            nominal_hz: Some(143.9),
+            source,
+        };
+        let classify = |hz, source| VrrActivity::classify(&reading(hz, source), 48, 144);
+        assert_eq!(classify(97.0, RefreshSource::Vblank), VrrActivity::Active);
+        assert_eq!(classify(143.5, RefreshSource::Vblank), VrrActivity::Idle);
+        // Below the VRR window means a fixed low mode, not VRR
+        assert_eq!(classify(30.0, RefreshSource::Vblank), VrrActivity::Idle);
+        assert_eq!(classify(143.9, RefreshSource::Mode), VrrActivity::Unknown);
+    }
+
     #[test]
     #[ignore] // Requires VRR-capable display - run with: cargo test -- --ignored
     fn test_detect_vrr_displays() {
@@ -427,6 +427,95 @@ impl WaylandNvidiaManager {
     }
 }
 
+// ==================== Compositor Detection ====================
+
+/// Session compositor, as far as it matters for output configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaylandCompositor {
+    Kde,
+    Gnome,
+    Hyprland,
+    Sway,
+    /// Other compositor speaking wlr-output-management (river, wayfire, labwc, ...)
+    Wlroots(String),
+    /// Wayland compositor without a known control interface
+    Other(String),
+    X11,
+}
+
+impl WaylandCompositor {
+    /// Classify an `XDG_CURRENT_DESKTOP` value from a Wayland session
+    pub fn from_desktop(desktop: &str) -> Self {
+        let lower = desktop.to_lowercase();
+        if lower.contains("kde") || lower.contains("plasma") {
+            Self::Kde
+        } else if lower.contains("gnome") {
+            Self::Gnome
+        } else if lower.contains("hyprland") {
+            Self::Hyprland
+        } else if lower.contains("sway") {
+            Self::Sway
+        } else if ["river", "wayfire", "labwc", "niri", "dwl", "qtile"]
+            .iter()
+            .any(|name| lower.contains(name))
+        {
+            Self::Wlroots(desktop.to_string())
+        } else {
+            Self::Other(desktop.to_string())
+        }
+    }
+
+    /// Display name for messages
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Kde => "KDE Plasma",
+            Self::Gnome => "GNOME",
+            Self::Hyprland => "Hyprland",
+            Self::Sway => "Sway",
+            Self::Wlroots(name) | Self::Other(name) if !name.is_empty() => name,
+            Self::Wlroots(_) | Self::Other(_) => "unknown compositor",
+            Self::X11 => "X11",
+        }
+    }
+
+    /// Where a user toggles VRR by hand on this compositor
+    pub fn manual_vrr_setting(&self) -> &'static str {
+        match self {
+            Self::Kde => "System Settings → Display & Monitor → Adaptive Sync",
+            Self::Gnome => "Settings → Displays → Variable Refresh Rate",
+            Self::Hyprland => "hyprland.conf: `misc { vrr = 1 }` or `monitor = <output>,...,vrr,1`",
+            Self::Sway => "sway config: `output <output> adaptive_sync on`",
+            Self::Wlroots(_) => "wlr-randr --output <output> --adaptive-sync enabled",
+            Self::Other(_) => "your compositor's display settings",
+            Self::X11 => "nvidia-settings → X Server Display Configuration → Allow G-SYNC",
+        }
+    }
+}
+
+/// Detect the running compositor from the session environment
+pub fn detect_compositor() -> WaylandCompositor {
+    let wayland = std::env::var("XDG_SESSION_TYPE")
+        .map(|s| s == "wayland")
+        .unwrap_or(false)
+        || std::env::var("WAYLAND_DISPLAY").is_ok();
+    if !wayland {
+        return WaylandCompositor::X11;
+    }
+
+    // Compositor sockets are more reliable than the desktop name
+    if std::env::var("HYPRLAND_INSTANCE_SIGNATURE").is_ok() {
+        return WaylandCompositor::Hyprland;
+    }
+    if std::env::var("SWAYSOCK").is_ok() {
+        return WaylandCompositor::Sway;
+    }
+    if std::env::var("KDE_SESSION_VERSION").is_ok() {
+        return WaylandCompositor::Kde;
+    }
+
+    WaylandCompositor::from_desktop(&std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default())
+}
+
 // ==================== Explicit Sync Support ====================
 
 /// Status of explicit sync support
@@ -834,3 +923,595 @@ pub fn enable_explicit_sync() -> NvResult<()> {
         )))
     }
 }
+
+// ==================== Optimization Presets ====================
+
+/// Environment file read by systemd at login, before the compositor starts
+const SESSION_ENV_FILE: &str = "environment.d/90-nvcontrol-wayland.conf";
+/// Module options written by `nvctl wayland optimize --kernel`
+const MODPROBE_TWEAK_FILE: &str = "/etc/modprobe.d/nvcontrol-wayland.conf";
+
+/// When an optimization takes effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TweakScope {
+    /// Log out and back in
+    Session,
+    /// Restart or reconfigure the compositor
+    Compositor,
+    /// Reboot (kernel module option)
+    Reboot,
+}
+
+impl TweakScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Session => "next login",
+            Self::Compositor => "compositor restart",
+            Self::Reboot => "reboot",
+        }
+    }
+}
+
+/// What an optimization changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TweakTarget {
+    /// `KEY=value` in the user's environment.d file
+    SessionEnv { key: String, value: String },
+    /// Key in `~/.config/kwinrc`
+    Kwin {
+        group: String,
+        key: String,
+        value: String,
+    },
+    /// `options <module> <param>=<value>` in /etc/modprobe.d (root)
+    ModuleOption {
+        module: String,
+        param: String,
+        value: String,
+    },
+}
+
+impl TweakTarget {
+    fn env(key: &str, value: &str) -> Self {
+        Self::SessionEnv {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    fn scope(&self) -> TweakScope {
+        match self {
+            Self::SessionEnv { .. } => TweakScope::Session,
+            Self::Kwin { .. } => TweakScope::Compositor,
+            Self::ModuleOption { .. } => TweakScope::Reboot,
+        }
+    }
+
+    /// Human-readable form of the change, e.g. `__GL_GSYNC_ALLOWED=1`
+    pub fn describe(&self) -> String {
+        match self {
+            Self::SessionEnv { key, value } => format!("{key}={value}"),
+            Self::Kwin { group, key, value } => format!("kwinrc [{group}] {key}={value}"),
+            Self::ModuleOption {
+                module,
+                param,
+                value,
+            } => format!("options {module} {param}={value}"),
+        }
+    }
+
+    /// File, section and key this target lives under
+    fn location(&self) -> (PathBuf, Option<String>, String, String) {
+        let config = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        match self {
+            Self::SessionEnv { key, value } => (
+                config.join(SESSION_ENV_FILE),
+                None,
+                key.clone(),
+                value.clone(),
+            ),
+            Self::Kwin { group, key, value } => (
+                config.join("kwinrc"),
+                Some(group.clone()),
+                key.clone(),
+                value.clone(),
+            ),
+            Self::ModuleOption {
+                module,
+                param,
+                value,
+            } => (
+                PathBuf::from(MODPROBE_TWEAK_FILE),
+                None,
+                format!("options {module} {param}"),
+                value.clone(),
+            ),
+        }
+    }
+
+    /// Value currently configured for this target, if any
+    fn configured_value(&self) -> Option<String> {
+        let (path, section, key, _) = self.location();
+        let content = fs::read_to_string(path).ok()?;
+        read_config_value(&content, section.as_deref(), &key)
+    }
+}
+
+/// One optimization that applies to the detected setup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaylandTweak {
+    pub id: String,
+    pub description: String,
+    pub target: TweakTarget,
+    pub scope: TweakScope,
+    /// Already configured with the recommended value
+    pub applied: bool,
+}
+
+impl WaylandTweak {
+    fn new(id: &str, description: &str, target: TweakTarget) -> Self {
+        Self {
+            id: id.to_string(),
+            description: description.to_string(),
+            scope: target.scope(),
+            target,
+            applied: false,
+        }
+    }
+}
+
+/// Everything `nvctl wayland status` reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaylandStatus {
+    pub compositor: WaylandCompositor,
+    /// `nvidia_drm.modeset` of the loaded module; `None` when not loaded
+    pub modeset: Option<bool>,
+    /// `nvidia_drm.fbdev` of the loaded module; `None` on drivers without it
+    pub fbdev: Option<bool>,
+    /// GSP firmware in use; `None` when it can't be determined
+    pub gsp_firmware: Option<bool>,
+    pub nvidia_open: bool,
+    pub tweaks: Vec<WaylandTweak>,
+}
+
+impl WaylandStatus {
+    pub fn detect() -> Self {
+        let compositor = detect_compositor();
+        let modeset = read_module_param("nvidia_drm", "modeset");
+        let fbdev = read_module_param("nvidia_drm", "fbdev");
+        let mut tweaks = recommended_tweaks(&compositor, modeset, fbdev);
+        for tweak in &mut tweaks {
+            let (_, _, _, value) = tweak.target.location();
+            tweak.applied = tweak.target.configured_value().as_deref() == Some(value.as_str());
+        }
+
+        Self {
+            compositor,
+            modeset,
+            fbdev,
+            gsp_firmware: crate::gsp_firmware::GspManager::is_gsp_enabled().ok(),
+            nvidia_open: crate::gsp_firmware::GspManager::is_nvidia_open(),
+            tweaks,
+        }
+    }
+
+    /// Tweaks still to apply, split into (session/compositor, reboot-required)
+    pub fn pending(&self) -> (Vec<&WaylandTweak>, Vec<&WaylandTweak>) {
+        self.tweaks
+            .iter()
+            .filter(|t| !t.applied)
+            .partition(|t| t.scope != TweakScope::Reboot)
+    }
+}
+
+/// Read a boolean kernel module parameter ("Y"/"N" or "1"/"0")
+fn read_module_param(module: &str, param: &str) -> Option<bool> {
+    let value = fs::read_to_string(format!("/sys/module/{module}/parameters/{param}")).ok()?;
+    match value.trim() {
+        "Y" | "1" => Some(true),
+        "N" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Optimizations that apply to `compositor`, given the running module options
+pub fn recommended_tweaks(
+    compositor: &WaylandCompositor,
+    modeset: Option<bool>,
+    fbdev: Option<bool>,
+) -> Vec<WaylandTweak> {
+    if *compositor == WaylandCompositor::X11 {
+        return Vec::new();
+    }
+
+    let mut tweaks = vec![
+        WaylandTweak::new(
+            "gsync",
+            "Allow G-SYNC/VRR for OpenGL and Vulkan",
+            TweakTarget::env("__GL_GSYNC_ALLOWED", "1"),
+        ),
+        WaylandTweak::new(
+            "vrr",
+            "Allow variable refresh for fullscreen apps",
+            TweakTarget::env("__GL_VRR_ALLOWED", "1"),
+        ),
+        WaylandTweak::new(
+            "gbm-backend",
+            "Use the NVIDIA GBM backend",
+            TweakTarget::env("GBM_BACKEND", "nvidia-drm"),
+        ),
+        WaylandTweak::new(
+            "glx-vendor",
+            "Route GLX through the NVIDIA vendor library (XWayland)",
+            TweakTarget::env("__GLX_VENDOR_LIBRARY_NAME", "nvidia"),
+        ),
+        WaylandTweak::new(
+            "vaapi",
+            "Hardware video decode through nvidia-vaapi-driver",
+            TweakTarget::env("LIBVA_DRIVER_NAME", "nvidia"),
+        ),
+    ];
+
+    match compositor {
+        WaylandCompositor::Kde => {
+            tweaks.push(WaylandTweak::new(
+                "kwin-explicit-sync",
+                "KWin explicit sync (Plasma 6.1+, driver 555+)",
+                TweakTarget::Kwin {
+                    group: "Compositing".to_string(),
+                    key: "ExplicitSync".to_string(),
+                    value: "true".to_string(),
+                },
+            ));
+            tweaks.push(WaylandTweak::new(
+                "kwin-modifiers",
+                "Let KWin use DRM format modifiers",
+                TweakTarget::env("KWIN_DRM_USE_MODIFIERS", "1"),
+            ));
+        }
+        WaylandCompositor::Sway | WaylandCompositor::Wlroots(_) => {
+            tweaks.push(WaylandTweak::new(
+                "wlr-cursors",
+                "Software cursors (avoids invisible cursor on wlroots)",
+                TweakTarget::env("WLR_NO_HARDWARE_CURSORS", "1"),
+            ));
+        }
+        _ => {}
+    }
+
+    if modeset != Some(true) {
+        tweaks.push(WaylandTweak::new(
+            "drm-modeset",
+            "Kernel modesetting, required for Wayland on NVIDIA",
+            TweakTarget::ModuleOption {
+                module: "nvidia_drm".to_string(),
+                param: "modeset".to_string(),
+                value: "1".to_string(),
+            },
+        ));
+    }
+    if fbdev == Some(false) {
+        tweaks.push(WaylandTweak::new(
+            "drm-fbdev",
+            "NVIDIA framebuffer console (smooth VT switching)",
+            TweakTarget::ModuleOption {
+                module: "nvidia_drm".to_string(),
+                param: "fbdev".to_string(),
+                value: "1".to_string(),
+            },
+        ));
+    }
+
+    tweaks
+}
+
+/// Value of `key` in an INI/env-style file; `section` `None` means top level
+fn read_config_value(content: &str, section: Option<&str>, key: &str) -> Option<String> {
+    let mut current: Option<&str> = None;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            current = Some(name);
+        } else if current == section {
+            if let Some((k, v)) = trimmed.split_once('=') {
+                if k.trim() == key {
+                    return Some(v.trim().to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Set `key` under `section` to `value`, or remove it when `value` is `None`
+fn write_config_value(
+    content: &str,
+    section: Option<&str>,
+    key: &str,
+    value: Option<&str>,
+) -> String {
+    let mut out = Vec::new();
+    let mut current: Option<&str> = None;
+    let mut section_seen = section.is_none();
+    let mut written = false;
+
+    let flush = |out: &mut Vec<String>, written: &mut bool| {
+        if let (false, Some(v)) = (*written, value) {
+            // Keep the key above trailing blank lines of the section
+            let at = out
+                .iter()
+                .rposition(|l| !l.trim().is_empty())
+                .map_or(0, |i| i + 1);
+            out.insert(at, format!("{key}={v}"));
+            *written = true;
+        }
+    };
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            if current == section && section_seen {
+                flush(&mut out, &mut written);
+            }
+            current = Some(name);
+            section_seen |= current == section;
+        } else if current == section
+            && trimmed
+                .split_once('=')
+                .is_some_and(|(k, _)| k.trim() == key)
+        {
+            if let (false, Some(v)) = (written, value) {
+                out.push(format!("{key}={v}"));
+                written = true;
+            }
+            continue;
+        }
+        out.push(line.to_string());
+    }
+
+    if current == section && section_seen {
+        flush(&mut out, &mut written);
+    }
+    if !written && let (Some(v), Some(name)) = (value, section) {
+        if out.last().is_some_and(|l| !l.trim().is_empty()) {
+            out.push(String::new());
+        }
+        out.push(format!("[{name}]"));
+        out.push(format!("{key}={v}"));
+    }
+
+    let mut result = out.join("\n");
+    result.push('\n');
+    result
+}
+
+/// A change made by `apply_tweaks`, with what it replaced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedTweak {
+    pub id: String,
+    pub target: TweakTarget,
+    /// Previous value; `None` when the key was unset
+    pub previous: Option<String>,
+    pub applied_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Log of applied tweaks, kept so `nvctl wayland revert` can undo them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TweakJournal {
+    pub entries: Vec<AppliedTweak>,
+}
+
+impl TweakJournal {
+    pub fn load() -> Self {
+        fs::read_to_string(Self::file_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> NvResult<()> {
+        if crate::gpu_safe::is_dry_run() {
+            return Ok(());
+        }
+        let path = Self::file_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to serialize Wayland tweak log: {e}"))
+        })?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn file_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nvcontrol")
+            .join("wayland_tweaks.json")
+    }
+}
+
+/// Write `value` (or remove the key) for `target`, returning the old value
+fn set_target(target: &TweakTarget, value: Option<&str>) -> NvResult<Option<String>> {
+    let (path, section, key, _) = target.location();
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    let previous = read_config_value(&content, section.as_deref(), &key);
+    let updated = write_config_value(&content, section.as_deref(), &key, value);
+
+    let action = match value {
+        Some(v) => format!("set {key}={v} in {}", path.display()),
+        None => format!("remove {key} from {}", path.display()),
+    };
+    if crate::gpu_safe::should_apply(&action) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, updated).map_err(|e| {
+            NvControlError::ConfigError(format!("Failed to write {}: {}", path.display(), e))
+        })?;
+    }
+    Ok(previous)
+}
+
+/// Apply tweaks and record each one in the journal.
+/// Module options need root; they are skipped with an error otherwise.
+pub fn apply_tweaks(tweaks: &[&WaylandTweak]) -> NvResult<Vec<AppliedTweak>> {
+    let needs_root = tweaks.iter().any(|t| t.scope == TweakScope::Reboot);
+    if needs_root && !nix::unistd::geteuid().is_root() && !crate::gpu_safe::is_dry_run() {
+        return Err(NvControlError::ConfigError(
+            "Root privileges required to write kernel module options".to_string(),
+        ));
+    }
+
+    let mut journal = TweakJournal::load();
+    let mut applied = Vec::new();
+    for tweak in tweaks {
+        let (_, _, _, value) = tweak.target.location();
+        let previous = set_target(&tweak.target, Some(&value))?;
+        let entry = AppliedTweak {
+            id: tweak.id.clone(),
+            target: tweak.target.clone(),
+            previous,
+            applied_at: chrono::Utc::now(),
+        };
+        journal.entries.push(entry.clone());
+        applied.push(entry);
+    }
+    journal.save()?;
+    Ok(applied)
+}
+
+/// Undo every logged tweak, newest first, and clear the journal.
+/// Returns the entries that were reverted.
+pub fn revert_tweaks() -> NvResult<Vec<AppliedTweak>> {
+    let mut journal = TweakJournal::load();
+    let mut reverted = Vec::new();
+    while let Some(entry) = journal.entries.pop() {
+        if let Err(e) = set_target(&entry.target, entry.previous.as_deref()) {
+            journal.entries.push(entry);
+            journal.save()?;
+            return Err(e);
+        }
+        reverted.push(entry);
+    }
+    journal.save()?;
+    Ok(reverted)
+}
+
+/// Print `nvctl wayland status`
+pub fn print_wayland_status(status: &WaylandStatus) {
+    let on_off = |value: Option<bool>| match value {
+        Some(true) => "✅ enabled",
+        Some(false) => "❌ disabled",
+        None => "unknown",
+    };
+
+    println!("🌊 Wayland NVIDIA Status\n");
+    println!("   Compositor:     {}", status.compositor.name());
+    println!(
+        "   Driver:         {}",
+        if status.nvidia_open {
+            "nvidia-open"
+        } else {
+            "proprietary"
+        }
+    );
+    println!(
+        "   DRM modeset:    {}",
+        match status.modeset {
+            None => "nvidia_drm not loaded",
+            value => on_off(value),
+        }
+    );
+    if status.fbdev.is_some() {
+        println!("   DRM fbdev:      {}", on_off(status.fbdev));
+    }
+    println!("   GSP firmware:   {}", on_off(status.gsp_firmware));
+
+    if status.compositor == WaylandCompositor::X11 {
+        println!("\n⚠️  Not a Wayland session - no Wayland optimizations apply");
+        return;
+    }
+
+    let print_group = |title: &str, reboot: bool| {
+        let tweaks: Vec<&WaylandTweak> = status
+            .tweaks
+            .iter()
+            .filter(|t| (t.scope == TweakScope::Reboot) == reboot)
+            .collect();
+        if tweaks.is_empty() {
+            return;
+        }
+        println!("\n{}", title);
+        for tweak in tweaks {
+            println!(
+                "   {} {:<20} {} ({})",
+                if tweak.applied { "✅" } else { "⬜" },
+                tweak.id,
+                tweak.description,
+                tweak.target.describe()
+            );
+        }
+    };
+    print_group(
+        "Optimizations (apply with `nvctl wayland optimize`):",
+        false,
+    );
+    print_group(
+        "Reboot required (apply with `sudo nvctl wayland optimize --kernel`):",
+        true,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_value_round_trip() {
+        let kwinrc = "[Compositing]\nBackend=OpenGL\n\n[Plugins]\nblurEnabled=false\n";
+
+        let set = write_config_value(kwinrc, Some("Compositing"), "ExplicitSync", Some("true"));
+        assert_eq!(
+            read_config_value(&set, Some("Compositing"), "ExplicitSync").as_deref(),
+            Some("true")
+        );
+        assert_eq!(
+            read_config_value(&set, Some("Plugins"), "ExplicitSync"),
+            None
+        );
+
+        let reverted = write_config_value(&set, Some("Compositing"), "ExplicitSync", None);
+        assert_eq!(reverted, kwinrc);
+
+        let env = write_config_value("", None, "__GL_GSYNC_ALLOWED", Some("1"));
+        assert_eq!(env, "__GL_GSYNC_ALLOWED=1\n");
+        let env = write_config_value(&env, None, "__GL_GSYNC_ALLOWED", Some("0"));
+        assert_eq!(env, "__GL_GSYNC_ALLOWED=0\n");
+
+        let new_section = write_config_value("", Some("Compositing"), "ExplicitSync", Some("true"));
+        assert_eq!(new_section, "[Compositing]\nExplicitSync=true\n");
+    }
+
+    #[test]
+    fn test_recommended_tweaks_per_compositor() {
+        assert!(recommended_tweaks(&WaylandCompositor::X11, Some(true), None).is_empty());
+
+        let kde = recommended_tweaks(&WaylandCompositor::Kde, Some(true), Some(true));
+        assert!(kde.iter().any(|t| t.id == "kwin-explicit-sync"));
+        assert!(kde.iter().all(|t| t.scope != TweakScope::Reboot));
+
+        let sway = recommended_tweaks(&WaylandCompositor::Sway, Some(false), Some(false));
+        assert!(sway.iter().any(|t| t.id == "wlr-cursors"));
+        assert!(!sway.iter().any(|t| t.id == "kwin-explicit-sync"));
+        let reboot: Vec<_> = sway
+            .iter()
+            .filter(|t| t.scope == TweakScope::Reboot)
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(reboot, ["drm-modeset", "drm-fbdev"]);
+    }
+}
@@ -118,6 +118,12 @@ fn test_nvctl_driver_diagnose_release_yaml() {
     assert!(stdout.contains("running_kernel:"));
 }
 
+#[test]
+fn test_nvctl_gui_help_lists_mini() {
+    let stdout = common::run_success(&["gui", "--help"]);
+    assert!(stdout.contains("--mini"));
+}
+
 #[test]
 fn test_nvctl_companion_help() {
     let stdout = common::run_success(&["companion", "--help"]);
@@ -275,3 +281,689 @@ fn test_nvctl_doctor_support_json() {
     assert!(stdout.contains("Release Diagnostics"));
     let _ = std::fs::remove_file(&output_path);
 }
+
+#[test]
+fn test_nvctl_profile_apply_missing_exits_nonzero() {
+    let output = common::nvctl_command()
+        .args(["profile", "apply", "nvctl-test-missing-profile"])
+        .output()
+        .expect("Failed to execute nvctl profile apply");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not found"));
+}
+
+#[test]
+fn test_nvctl_profile_apply_malformed_shows_parse_error() {
+    let home = tempfile::tempdir().unwrap();
+    let profiles = home.path().join("nvcontrol/profiles");
+    std::fs::create_dir_all(&profiles).unwrap();
+    std::fs::write(profiles.join("broken.json"), "{ \"name\": ").unwrap();
+
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["profile", "apply", "broken"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Failed to parse profile"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(!stderr.contains("not found"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_latency_set_unknown_mode_exits_nonzero() {
+    let output = common::nvctl_command()
+        .args(["latency", "set", "turbo"])
+        .output()
+        .expect("Failed to execute nvctl latency set");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown latency mode"));
+}
+
+#[test]
+fn test_nvctl_power_schedule_add_rejects_bad_hour() {
+    let output = common::nvctl_command()
+        .args([
+            "power", "schedule", "add", "--start", "25", "--end", "7", "--power", "60",
+        ])
+        .output()
+        .expect("Failed to execute nvctl power schedule add");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Hours must be between 0 and 23"));
+}
+
+#[cfg(not(feature = "logging-sqlite"))]
+#[test]
+fn test_nvctl_monitor_query_requires_sqlite_feature() {
+    let output = common::nvctl_command()
+        .args(["monitor", "query", "--since", "7d"])
+        .output()
+        .expect("Failed to execute nvctl monitor query");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--features logging-sqlite"));
+}
+
+#[test]
+fn test_nvctl_dry_run_power_limit_does_not_execute() {
+    let output = common::nvctl_command()
+        .args(["--dry-run", "power", "limit", "--percentage", "80"])
+        .output()
+        .expect("failed to run nvctl");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("[dry-run] would run: nvidia-smi -pl 80%"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn test_nvctl_power_limit_set_requires_unit() {
+    let output = common::nvctl_command()
+        .args(["power", "limit", "set", "320"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("320W"), "unexpected stderr: {stderr}");
+}
+
+#[test]
+fn test_nvctl_vibrance_unknown_preset_lists_presets() {
+    let output = common::nvctl_command()
+        .args(["vibrance", "preset", "neon"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("competitive"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+#[test]
+fn test_nvctl_power_limit_all_conflicts_with_gpu() {
+    let output = common::nvctl_command()
+        .args(["power", "limit", "set", "90%", "--all", "--gpu", "1"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--all"), "unexpected stderr: {stderr}");
+}
+
+#[test]
+fn test_nvctl_aura_sync_rejects_bad_gradient() {
+    let output = common::nvctl_command()
+        .args([
+            "asus",
+            "aura",
+            "sync",
+            "--cold-temp",
+            "80",
+            "--hot-temp",
+            "60",
+        ])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--hot-temp must be above --cold-temp"));
+}
+
+#[test]
+fn test_nvctl_fan_install_service_scope_flags_conflict() {
+    let output = common::nvctl_command()
+        .args(["fan", "install-service", "--system", "--user"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot be used with"));
+}
+
+#[test]
+fn test_nvctl_fan_manual_rejects_out_of_range_speed() {
+    let output = common::nvctl_command()
+        .args(["fan", "manual", "150", "--fan", "0"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("0..=100"));
+}
+
+#[test]
+fn test_nvctl_record_frametimes_rejects_missing_pid() {
+    let output = common::nvctl_command()
+        .args([
+            "record",
+            "frametimes",
+            "--pid",
+            "4294967",
+            "--duration",
+            "1",
+        ])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No running process with PID 4294967"));
+}
+
+#[test]
+fn test_nvctl_gamescope_create_preset_rejects_dlss() {
+    let output = common::nvctl_command()
+        .args([
+            "gamescope",
+            "create-preset",
+            "--name",
+            "bad",
+            "--width",
+            "1920",
+            "--height",
+            "1080",
+            "--upscaler",
+            "dlss",
+        ])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("dlss"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_dlss_preset_rejects_unknown_preset() {
+    let output = common::nvctl_command()
+        .args(["dlss", "preset", "some-game", "Z"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown DLSS preset"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_display_list_json_labels_backend() {
+    let stdout = common::run_success(&["display", "list", "--json"]);
+    let listing: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    assert!(listing["backend"].is_string());
+    assert!(listing["session"].is_string());
+    assert!(listing["displays"].is_array());
+}
+
+#[test]
+fn test_nvctl_gpu_stat_template_rejects_unknown_placeholder() {
+    let output = common::nvctl_command()
+        .args(["gpu", "stat", "--template", "{temp}°C {tmep}"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("{tmep}"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_display_set_mode_rejects_bad_spec() {
+    let output = common::nvctl_command()
+        .args(["display", "set-mode", "DP-2", "2560x1440@fast"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("WIDTHxHEIGHT"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_profile_import_refuses_unsafe_values() {
+    let home = tempfile::tempdir().unwrap();
+    let shared = home.path().join("shared.json");
+    std::fs::write(
+        &shared,
+        r#"{
+            "name": "shared",
+            "description": "too hot",
+            "created_at": "2026-01-01T00:00:00Z",
+            "display_layout": null,
+            "fan_curve": null,
+            "voltage_curve": null,
+            "overclock": {
+                "name": "shared",
+                "gpu_clock_offset": 900,
+                "memory_clock_offset": 0,
+                "voltage_offset": 0,
+                "power_limit": 100,
+                "temp_limit": 83,
+                "fan_curve": []
+            },
+            "game_profiles": [],
+            "vibrance_settings": null
+        }"#,
+    )
+    .unwrap();
+
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["profile", "import", shared.to_str().unwrap()])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Refusing to import"), "stderr: {}", stderr);
+    assert!(!home.path().join("nvcontrol/profiles/shared.json").exists());
+}
+
+#[test]
+fn test_nvctl_profile_import_keeps_existing_profile_without_overwrite() {
+    let home = tempfile::tempdir().unwrap();
+    let shared = home.path().join("shared.json");
+    let profile = |description: &str| {
+        format!(
+            r#"{{
+            "name": "shared",
+            "description": "{description}",
+            "created_at": "2026-01-01T00:00:00Z",
+            "display_layout": null,
+            "fan_curve": null,
+            "voltage_curve": null,
+            "overclock": null,
+            "game_profiles": [],
+            "vibrance_settings": null
+        }}"#
+        )
+    };
+    let import = |extra: &[&str]| {
+        common::nvctl_command()
+            .env("XDG_CONFIG_HOME", home.path())
+            .args(["profile", "import", shared.to_str().unwrap()])
+            .args(extra)
+            .output()
+            .expect("failed to run nvctl")
+    };
+    let saved = home.path().join("nvcontrol/profiles/shared.json");
+
+    std::fs::write(&shared, profile("first")).unwrap();
+    assert!(import(&[]).status.success());
+
+    std::fs::write(&shared, profile("second")).unwrap();
+    let output = import(&[]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("already exists"), "stderr: {}", stderr);
+    assert!(std::fs::read_to_string(&saved).unwrap().contains("first"));
+
+    assert!(import(&["--overwrite"]).status.success());
+    assert!(std::fs::read_to_string(&saved).unwrap().contains("second"));
+}
+
+#[test]
+fn test_nvctl_display_rotate_and_scale_reject_bad_values() {
+    let output = common::nvctl_command()
+        .args(["display", "rotate", "DP-1", "sideways"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("normal, left, right"), "stderr: {}", stderr);
+
+    let output = common::nvctl_command()
+        .args(["display", "scale", "DP-1", "1.33"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("steps of 0.05"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_benchmark_soak_requires_load() {
+    let output = common::nvctl_command()
+        .args(["benchmark", "soak", "--load", "0"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("at least 1 second"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_apply_saved_skips_when_nothing_saved() {
+    let home = tempfile::tempdir().unwrap();
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .arg("apply-saved")
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(output.status.success());
+    let stdout = common::output_text(output);
+    for subsystem in ["Overclock", "Power limit", "Fan speed", "Vibrance"] {
+        assert!(
+            stdout.contains(&format!("{}: nothing saved", subsystem)),
+            "stdout: {}",
+            stdout
+        );
+    }
+}
+
+#[test]
+fn test_nvctl_apply_saved_startup_skips_after_crash() {
+    let home = tempfile::tempdir().unwrap();
+    let flag = home.path().join("nvcontrol/startup_applying");
+    std::fs::create_dir_all(flag.parent().unwrap()).unwrap();
+    std::fs::write(&flag, "").unwrap();
+
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["apply-saved", "--startup"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("never finished"), "stdout: {}", stdout);
+    assert!(flag.exists());
+
+    // A manual run confirms the settings and clears the flag
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .arg("apply-saved")
+        .output()
+        .expect("failed to run nvctl");
+    assert!(output.status.success());
+    assert!(!flag.exists());
+}
+
+#[test]
+fn test_nvctl_quiet_status_and_off_restores() {
+    let home = tempfile::tempdir().unwrap();
+    let state = home.path().join("nvcontrol/quiet_mode.json");
+    std::fs::create_dir_all(state.parent().unwrap()).unwrap();
+    std::fs::write(
+        &state,
+        r#"{"enabled_at": "2026-01-01T22:00:00Z", "power_limits_w": {}, "fan_curves": {}}"#,
+    )
+    .unwrap();
+
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["quiet", "status"])
+        .output()
+        .expect("failed to run nvctl");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Quiet mode on since"), "stdout: {}", stdout);
+
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["quiet", "off"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(output.status.success());
+    assert!(!state.exists());
+    // The fan curves from before quiet mode are written back for the daemon
+    assert!(
+        home.path()
+            .join("nvcontrol/active_fan_curves.json")
+            .exists()
+    );
+
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["quiet", "status"])
+        .output()
+        .expect("failed to run nvctl");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Quiet mode is off"), "stdout: {}", stdout);
+}
+
+#[test]
+fn test_nvctl_log_level_creates_log_file() {
+    let home = tempfile::tempdir().unwrap();
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .env("XDG_DATA_HOME", home.path())
+        .args(["--log-level", "debug", "apply-saved"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(output.status.success());
+    assert!(home.path().join("nvcontrol/logs/nvcontrol.log").exists());
+
+    let output = common::nvctl_command()
+        .args(["--log-level", "chatty", "apply-saved"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_nvctl_setup_wizard_check_only() {
+    let output = common::nvctl_command()
+        .args(["setup", "wizard", "--check-only"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(output.status.success());
+    let stdout = common::output_text(output);
+    for step in [
+        "Driver:",
+        "Kernel modeset:",
+        "Device permissions:",
+        "Video group:",
+        "Compositor:",
+        "Resizable BAR:",
+    ] {
+        assert!(stdout.contains(step), "stdout: {}", stdout);
+    }
+}
+
+#[test]
+fn test_nvctl_overclock_preset_lists_values() {
+    let output = common::nvctl_command()
+        .args(["overclock", "preset", "--gpu", "0"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(output.status.success());
+    let stdout = common::output_text(output);
+    for preset in ["Stock", "Mild OC", "Performance", "Extreme"] {
+        assert!(stdout.contains(preset), "stdout: {}", stdout);
+    }
+
+    let output = common::nvctl_command()
+        .args(["overclock", "preset", "turbo"])
+        .output()
+        .expect("failed to run nvctl");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_nvctl_serve_control_requires_token() {
+    let home = tempfile::tempdir().unwrap();
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["serve", "--bind", "127.0.0.1:0", "--allow-control"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("api_token"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_reset_dry_run_leaves_display_alone() {
+    let home = tempfile::tempdir().unwrap();
+    let output = common::nvctl_command()
+        .env("XDG_CONFIG_HOME", home.path())
+        .args(["--dry-run", "reset"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(output.status.success());
+    let stdout = common::output_text(output);
+    for subsystem in [
+        "Clock offsets",
+        "Power limit",
+        "Locked clocks",
+        "Fans",
+        "RGB",
+    ] {
+        assert!(stdout.contains(subsystem), "stdout: {}", stdout);
+    }
+    assert!(stdout.contains("Display modes and compositor settings were left unchanged"));
+}
+
+#[test]
+fn test_nvctl_auto_oc_apply_missing_profile_fails() {
+    let output = common::nvctl_command()
+        .args(["auto-oc", "apply", "no-such-efficiency-profile"])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no-such-efficiency-profile"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_nvctl_overclock_staged_rejects_zero_step() {
+    let output = common::nvctl_command()
+        .args([
+            "overclock",
+            "apply",
+            "--gpu-offset",
+            "100",
+            "--staged",
+            "--step",
+            "0",
+        ])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--step"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_nvctl_report_writes_markdown() {
+    let path = std::env::temp_dir().join(format!("nvctl-report-{}.md", std::process::id()));
+    let output = common::nvctl_command()
+        .args(["report", "--output", path.to_str().unwrap()])
+        .output()
+        .expect("failed to run nvctl");
+
+    assert!(output.status.success());
+    let report = std::fs::read_to_string(&path).expect("report not written");
+    let _ = std::fs::remove_file(&path);
+    assert!(report.starts_with("# nvcontrol diagnostics report"));
+    assert!(report.contains("## System validation"));
+}
+
+#[test]
+fn test_nvctl_alert_add_list_remove() {
+    let home = tempfile::tempdir().unwrap();
+    let run = |args: &[&str]| {
+        let output = common::nvctl_command()
+            .env("XDG_CONFIG_HOME", home.path())
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "command failed for {:?}", args);
+        common::output_text(output)
+    };
+
+    let added = run(&["alert", "add", "temperature", "--above", "85"]);
+    assert!(added.contains("Temperature > 85°C"));
+    run(&["alert", "add", "vram", "--above", "90", "--gpu", "1"]);
+
+    let listed = run(&["alert", "list"]);
+    assert!(listed.contains("1. Temperature > 85°C"));
+    assert!(listed.contains("GPU 1"));
+
+    let removed = run(&["alert", "remove", "1"]);
+    assert!(removed.contains("Temperature > 85°C"));
+    assert!(!run(&["alert", "list"]).contains("Temperature"));
+}
+
+#[test]
+fn test_nvctl_wayland_optimize_and_revert() {
+    let home = tempfile::tempdir().unwrap();
+    let env_file = home.path().join("environment.d/90-nvcontrol-wayland.conf");
+    let run = |args: &[&str]| {
+        let output = common::nvctl_command()
+            .env("XDG_CONFIG_HOME", home.path())
+            .env("XDG_SESSION_TYPE", "wayland")
+            .env("XDG_CURRENT_DESKTOP", "KDE")
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "command failed for {:?}", args);
+        common::output_text(output)
+    };
+
+    run(&["--dry-run", "wayland", "optimize"]);
+    assert!(!env_file.exists());
+
+    let optimized = run(&["wayland", "optimize"]);
+    assert!(optimized.contains("__GL_GSYNC_ALLOWED=1"));
+    assert!(optimized.contains("next login"));
+    let kwinrc = std::fs::read_to_string(home.path().join("kwinrc")).unwrap();
+    assert!(kwinrc.contains("ExplicitSync=true"));
+    assert!(run(&["wayland", "status"]).contains("✅ gsync"));
+
+    let reverted = run(&["wayland", "revert"]);
+    assert!(reverted.contains("Reverted"));
+    let env = std::fs::read_to_string(&env_file).unwrap();
+    assert!(!env.contains("__GL_GSYNC_ALLOWED"));
+}
+
+#[test]
+fn test_nvctl_power_mode_dry_run() {
+    let output = common::nvctl_command()
+        .args([
+            "--dry-run",
+            "power",
+            "mode",
+            "max-performance",
+            "--gpu",
+            "1",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let text = common::output_text(output);
+    assert!(text.contains("[gpu:1]/GPUPowerMizerMode=1"));
+    assert!(text.contains("Prefer Maximum Performance"));
+}
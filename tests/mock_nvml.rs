@@ -13,7 +13,7 @@ fn test_mock_gpu_metrics_collection() {
         let metrics = backend.get_metrics(gpu_id).unwrap();
 
         assert!(metrics.gpu_utilization <= 100);
-        assert!(metrics.memory_utilization <= 100);
+        assert!(metrics.memory_controller_utilization <= 100);
         assert!(metrics.temperature > 0);
         assert!(metrics.power_draw_mw > 0);
         assert!(metrics.fan_speed <= 100);
@@ -111,7 +111,8 @@ fn test_multi_gpu_detection_with_backend() {
     use std::sync::Arc;
     let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::multi_gpu(4));
 
-    let gpus = nvcontrol::multi_gpu::detect_gpus_with_backend(&backend).unwrap();
+    let gpus =
+        nvcontrol::multi_gpu::detect_gpus_with_backend(&backend, &Default::default()).unwrap();
     assert_eq!(gpus.len(), 4);
 
     for (i, gpu) in gpus.iter().enumerate() {
@@ -128,11 +129,24 @@ fn test_multi_gpu_single_info() {
     use std::sync::Arc;
     let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::single_gpu());
 
-    let gpu = nvcontrol::multi_gpu::get_gpu_info_with_backend(0, &backend).unwrap();
+    let gpu =
+        nvcontrol::multi_gpu::get_gpu_info_with_backend(0, &backend, &Default::default()).unwrap();
     assert_eq!(gpu.index, 0);
     assert!(gpu.is_primary);
     assert!(gpu.cuda_cores.is_some());
     assert!(gpu.compute_capability.is_some());
+    assert!(gpu.nickname.is_none());
+
+    // Nicknames come only from the labels the caller passes in
+    let labels = std::collections::BTreeMap::from([(
+        "01:00.0".to_string(),
+        nvcontrol::multi_gpu::GpuLabel {
+            nickname: Some("Render".to_string()),
+            color: None,
+        },
+    )]);
+    let gpu = nvcontrol::multi_gpu::get_gpu_info_with_backend(0, &backend, &labels).unwrap();
+    assert_eq!(gpu.nickname.as_deref(), Some("Render"));
 }
 
 #[test]
@@ -179,7 +193,7 @@ fn test_monitoring_loop_derived_values() {
             let metrics = backend.get_metrics(gpu_id).unwrap();
 
             let gpu_pct = metrics.gpu_utilization;
-            let mem_pct = metrics.memory_utilization;
+            let mem_pct = metrics.memory_controller_utilization;
             let power_w = metrics.power_draw_mw as f64 / 1000.0;
             let mem_used_gb = metrics.memory_used_bytes as f64 / 1e9;
             let mem_total_gb = metrics.memory_total_bytes as f64 / 1e9;
@@ -208,6 +222,77 @@ fn test_fan_list_with_backend() {
     assert!(fan.max_rpm.is_some());
 }
 
+#[test]
+fn test_fan_rpm_separate_from_duty_cycle() {
+    use std::sync::Arc;
+    let mut mock = MockNvmlBackend::single_gpu();
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock.clone());
+
+    let fan = &nvcontrol::fan::list_fans_with_backend(&backend)[0];
+    assert_eq!(fan.rpm, Some(1450));
+    assert_eq!(fan.percent, Some(40));
+    assert_eq!(nvcontrol::fan::format_rpm(fan.rpm), "1450 RPM");
+
+    // Boards that only report duty cycle keep the percent and show no RPM
+    mock.devices[0].fan_rpm = None;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock);
+    let fan = &nvcontrol::fan::list_fans_with_backend(&backend)[0];
+    assert_eq!(fan.rpm, None);
+    assert_eq!(fan.percent, Some(40));
+    assert_eq!(nvcontrol::fan::format_rpm(fan.rpm), "—RPM");
+}
+
+#[test]
+fn test_ecc_errors_reported_only_with_ecc() {
+    use nvcontrol::nvml_backend::{EccErrorCounts, EccState};
+    use std::sync::Arc;
+
+    let mut mock = MockNvmlBackend::single_gpu();
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock.clone());
+    assert!(nvcontrol::gpu::get_ecc_errors(&backend, 0).is_err());
+
+    mock.devices[0].ecc = Some(EccState {
+        current_enabled: true,
+        pending_enabled: true,
+    });
+    mock.devices[0].ecc_errors = EccErrorCounts {
+        volatile_corrected: 3,
+        aggregate_uncorrected: 1,
+        ..Default::default()
+    };
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock);
+    let status = nvcontrol::gpu::get_ecc_status(&backend, 0).unwrap();
+    let errors = status.errors.unwrap();
+    assert_eq!(errors.volatile_corrected, 3);
+    assert!(errors.has_uncorrected());
+}
+
+#[test]
+fn test_applied_state_flags_ignored_fields() {
+    use nvcontrol::overclocking::{OverclockProfile, read_applied_state_with};
+
+    let mut mock = MockNvmlBackend::single_gpu();
+    mock.devices[0].clock_offsets = Some((100, 250));
+    mock.devices[0].power_limit_mw = 288_000;
+
+    let state = read_applied_state_with(&mock, 0);
+    assert_eq!(state.memory_clock_offset, Some(500));
+    assert_eq!(state.power_limit, Some(90));
+
+    let profile = OverclockProfile {
+        gpu_clock_offset: 100,
+        memory_clock_offset: 1000,
+        power_limit: 90,
+        ..OverclockProfile::default()
+    };
+    let applied: Vec<_> = state
+        .verify(&profile)
+        .iter()
+        .map(|check| check.applied())
+        .collect();
+    assert_eq!(applied, vec![Some(true), Some(false), Some(true)]);
+}
+
 #[test]
 fn test_fan_list_multi_gpu() {
     use std::sync::Arc;
@@ -244,3 +329,297 @@ fn test_fan_no_gpu_graceful() {
 
     assert!(!fans.is_empty());
 }
+
+#[test]
+fn test_metrics_separate_vram_capacity_from_bandwidth() {
+    let backend = MockNvmlBackend::single_gpu();
+    let metrics = backend.get_metrics(0).unwrap();
+
+    // 6 of 16 GB allocated, independent of controller activity
+    assert_eq!(metrics.vram_used_percent(), 37);
+    assert_eq!(metrics.memory_controller_utilization, 35);
+
+    // 11200 MHz on a 256-bit bus: 11200 * 2 * 256 / 8 / 1000
+    let peak = metrics.peak_bandwidth_gbps().unwrap();
+    assert!((peak - 716.8).abs() < 0.01);
+    let in_use = metrics.bandwidth_in_use_gbps().unwrap();
+    assert!((in_use - peak * 0.35).abs() < 0.01);
+
+    let unknown_bus = nvcontrol::nvml_backend::GpuMetrics {
+        memory_bus_width_bits: None,
+        ..metrics
+    };
+    assert!(unknown_bus.peak_bandwidth_gbps().is_none());
+}
+
+#[test]
+fn test_encoder_sessions_hidden_without_nvenc() {
+    use std::sync::Arc;
+    let mut mock = MockNvmlBackend::single_gpu();
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock.clone());
+
+    let sessions = nvcontrol::gpu::get_encoder_sessions(&backend, 0).unwrap();
+    assert_eq!(sessions.encoder_utilization, 12);
+    assert_eq!(sessions.session_count, Some(1));
+
+    mock.devices[0].video_engines = None;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock);
+    assert!(matches!(
+        nvcontrol::gpu::get_encoder_sessions(&backend, 0),
+        Err(nvcontrol::NvControlError::UnsupportedFeature(_))
+    ));
+}
+
+#[test]
+fn test_power_limit_watts_and_percent() {
+    use nvcontrol::power::{self, PowerLimitTarget};
+    use std::sync::Arc;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::single_gpu());
+
+    // 200–450 W allowed, 320 W default
+    let applied = power::set_power_limit_watts(&backend, 0, 300.0).unwrap();
+    assert_eq!(applied.applied_w, 300.0);
+    assert!(!applied.was_clamped());
+
+    let applied = power::set_power_limit_watts(&backend, 0, 600.0).unwrap();
+    assert_eq!(applied.applied_w, 450.0);
+    assert!(applied.was_clamped());
+
+    let applied = power::set_power_limit_percent(&backend, 0, 90.0).unwrap();
+    assert!((applied.applied_w - 288.0).abs() < 0.01);
+    assert_eq!(applied.range.default_w, 320.0);
+
+    assert_eq!("320W".parse(), Ok(PowerLimitTarget::Watts(320.0)));
+    assert_eq!(" 90% ".parse(), Ok(PowerLimitTarget::Percent(90.0)));
+    assert!("320".parse::<PowerLimitTarget>().is_err());
+    assert!("-5W".parse::<PowerLimitTarget>().is_err());
+}
+
+#[test]
+fn test_board_and_chip_power() {
+    use nvcontrol::power;
+    use std::sync::Arc;
+
+    // The single mock reports both readings
+    let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::single_gpu());
+    assert_eq!(power::get_board_power(&backend, 0).unwrap(), 180.0);
+    assert_eq!(power::get_chip_power(&backend, 0).unwrap(), 142.0);
+
+    // The multi-GPU mocks only report board power
+    let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::multi_gpu(2));
+    assert_eq!(power::get_board_power(&backend, 1).unwrap(), 170.0);
+    assert!(matches!(
+        power::get_chip_power(&backend, 1),
+        Err(nvcontrol::NvControlError::UnsupportedFeature(_))
+    ));
+}
+
+#[test]
+fn test_pcie_status_flags_degraded_link_and_rebar() {
+    use nvcontrol::gpu::{RebarStatus, read_bar1_size_in};
+    use nvcontrol::nvml_backend::PcieLink;
+    use std::sync::Arc;
+
+    let mut mock = MockNvmlBackend::single_gpu();
+    mock.devices[0].pcie_link = Some(PcieLink {
+        current_gen: 3,
+        max_gen: 4,
+        current_width: 16,
+        max_width: 16,
+    });
+    mock.devices[0].metrics.gpu_utilization = 5;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock.clone());
+
+    // A lower generation at idle is the driver saving power
+    let mut status = nvcontrol::gpu::get_pcie_status(&backend, 0).unwrap();
+    status.rebar = None;
+    assert!(status.warnings().is_empty());
+
+    status.gpu_utilization = 95;
+    assert_eq!(status.warnings().len(), 1);
+
+    // Missing lanes are flagged regardless of load
+    status.gpu_utilization = 5;
+    status.link = Some(PcieLink {
+        current_gen: 4,
+        max_gen: 4,
+        current_width: 8,
+        max_width: 16,
+    });
+    assert!(status.warnings()[0].contains("Gen4 x8 on a Gen4 x16"));
+
+    // BAR1 read from sysfs: 256 MB without ReBAR, full VRAM with it
+    let root = tempfile::tempdir().unwrap();
+    let device = root.path().join("0000:01:00.0");
+    std::fs::create_dir(&device).unwrap();
+    std::fs::write(
+        device.join("resource"),
+        "0x00000000f6000000 0x00000000f6ffffff 0x0000000000040200\n\
+         0x00000000e0000000 0x00000000efffffff 0x000000000014220c\n",
+    )
+    .unwrap();
+    let bar1 = read_bar1_size_in(root.path(), "0000:01:00.0").unwrap();
+    assert_eq!(bar1, 256 * 1024 * 1024);
+
+    let vram_bytes = mock.devices[0].metrics.memory_total_bytes;
+    assert!(
+        !RebarStatus {
+            bar1_bytes: bar1,
+            vram_bytes
+        }
+        .enabled()
+    );
+    assert!(
+        RebarStatus {
+            bar1_bytes: 16 << 30,
+            vram_bytes
+        }
+        .enabled()
+    );
+}
+
+#[test]
+fn test_gpu_reset_check_blocks_display_gpu() {
+    use nvcontrol::error_recovery::check_gpu_reset;
+
+    let mut backend = MockNvmlBackend::multi_gpu(2);
+    backend.devices[1].compute_processes = vec![nvcontrol::nvml_backend::ProcessInfo {
+        pid: 4242,
+        used_gpu_memory_bytes: Some(1 << 30),
+    }];
+
+    let display = check_gpu_reset(&backend, 0).unwrap();
+    assert!(display.drives_display());
+
+    let compute = check_gpu_reset(&backend, 1).unwrap();
+    assert!(!compute.drives_display());
+    assert_eq!(compute.pci_bus_id, "0000:01:00.0");
+    assert!(compute.processes.iter().any(|(pid, _)| *pid == 4242));
+}
+
+#[test]
+fn test_persistence_status_recommends_daemon() {
+    use nvcontrol::gpu::{PersistenceStatus, get_persistence_status};
+    use std::sync::Arc;
+
+    let mut mock = MockNvmlBackend::single_gpu();
+    mock.devices[0].persistence_mode = true;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock);
+    assert!(get_persistence_status(&backend, 0).unwrap().enabled);
+    assert!(get_persistence_status(&backend, 3).is_err());
+
+    let mut status = PersistenceStatus {
+        index: 0,
+        enabled: false,
+        daemon_running: false,
+        daemon_installed: true,
+    };
+    assert!(
+        status
+            .recommendation()
+            .unwrap()
+            .contains("systemctl enable")
+    );
+    status.daemon_installed = false;
+    assert!(status.recommendation().unwrap().contains("deprecated"));
+    status.daemon_running = true;
+    assert!(status.recommendation().is_none());
+}
+
+#[test]
+fn test_clock_lock_validated_against_supported_range() {
+    use nvcontrol::overclocking::validate_clock_lock;
+
+    let mut backend = MockNvmlBackend::single_gpu();
+    let supported = backend.get_supported_clocks(0).unwrap();
+    assert_eq!(supported.graphics_range(), Some((210, 2520)));
+    assert_eq!(supported.memory_range(), Some((405, 11200)));
+
+    assert!(validate_clock_lock(&supported, Some(2400), Some(10500)).is_ok());
+    assert!(validate_clock_lock(&supported, Some(2400), None).is_ok());
+    assert!(validate_clock_lock(&supported, Some(3000), None).is_err());
+    assert!(validate_clock_lock(&supported, None, Some(12000)).is_err());
+    assert!(validate_clock_lock(&supported, None, None).is_err());
+
+    backend.devices[0].supported_clocks = None;
+    assert!(backend.get_supported_clocks(0).is_err());
+    assert!(backend.set_locked_clocks(0, Some(2400), None).is_err());
+}
+
+#[test]
+fn test_mobile_gpu_detected_by_name_or_chassis() {
+    use nvcontrol::gpu::{is_mobile_gpu_name, is_mobile_in, mobile_gpu_status_in};
+    use std::sync::Arc;
+
+    assert!(is_mobile_gpu_name("NVIDIA GeForce RTX 4070 Laptop GPU"));
+    assert!(is_mobile_gpu_name("Quadro T1000 with Max-Q Design"));
+    assert!(!is_mobile_gpu_name("NVIDIA GeForce RTX 4090"));
+
+    let dmi = tempfile::tempdir().unwrap();
+    std::fs::write(dmi.path().join("chassis_type"), "3\n").unwrap();
+
+    // Desktop card in a desktop chassis
+    let desktop: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::single_gpu());
+    assert!(!is_mobile_in(&desktop, 0, dmi.path()));
+    assert!(mobile_gpu_status_in(&desktop, 0, dmi.path()).is_none());
+
+    // Laptop part on hybrid graphics
+    let mut backend = MockNvmlBackend::single_gpu();
+    backend.devices[0].info.name = "NVIDIA GeForce RTX 4070 Laptop GPU".to_string();
+    backend.devices[0].display_active = false;
+    let laptop: Arc<dyn NvmlBackend> = Arc::new(backend);
+    assert!(is_mobile_in(&laptop, 0, dmi.path()));
+    let status = mobile_gpu_status_in(&laptop, 0, dmi.path()).unwrap();
+    assert_eq!(status.drives_display, Some(false));
+    assert!(status.display_mode().contains("Hybrid"));
+
+    // Generic name, but a notebook chassis
+    std::fs::write(dmi.path().join("chassis_type"), "10\n").unwrap();
+    assert!(is_mobile_in(&desktop, 0, dmi.path()));
+}
+
+#[test]
+fn test_engine_utilization_falls_back_to_overall() {
+    use std::sync::Arc;
+    let mut mock = MockNvmlBackend::single_gpu();
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock.clone());
+
+    let engines = nvcontrol::gpu::get_engine_utilization(&backend, 0).unwrap();
+    assert!(engines.per_engine);
+    assert_eq!(
+        (engines.graphics, engines.compute, engines.copy),
+        (28, 14, 6)
+    );
+
+    mock.devices[0].engine_utilization = None;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(mock);
+    let engines = nvcontrol::gpu::get_engine_utilization(&backend, 0).unwrap();
+    assert!(!engines.per_engine);
+    assert_eq!(engines.graphics, 45);
+    assert_eq!(engines.copy, 45);
+}
+
+#[test]
+fn test_board_identity_without_serial() {
+    use std::sync::Arc;
+    let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::single_gpu());
+
+    assert_eq!(
+        nvcontrol::gpu::get_vbios_version(&backend, 0).unwrap(),
+        "95.03.1E.00.4B"
+    );
+    // Consumer cards don't report a serial; that's not a query failure
+    assert!(matches!(
+        nvcontrol::gpu::get_board_serial(&backend, 0),
+        Err(nvcontrol::NvControlError::UnsupportedFeature(_))
+    ));
+
+    let info = nvcontrol::gpu::get_gpu_info(&backend).unwrap();
+    assert_eq!(info.vbios_version.as_deref(), Some("95.03.1E.00.4B"));
+    assert_eq!(info.serial, None);
+
+    let backend: Arc<dyn NvmlBackend> = Arc::new(MockNvmlBackend::multi_gpu(2));
+    let identity = nvcontrol::gpu::BoardIdentity::query(&backend, 1);
+    assert_eq!(identity.serial.as_deref(), Some("1324021000001"));
+    assert_eq!(identity.part_number.as_deref(), Some("900-2G133-0000-000"));
+}